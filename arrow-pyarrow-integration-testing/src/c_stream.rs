@@ -47,9 +47,10 @@ pub fn from_rust_iterator(py: Python) -> PyResult<PyObject> {
     let arrays = vec![array.clone(), array.clone(), array];
     let iter = Box::new(arrays.clone().into_iter().map(Ok)) as _;
 
-    // create an [`ArrowArrayStream`] based on this iterator and field
+    // create an [`ArrowArrayStream`] based on this iterator and field, which pyarrow may
+    // consume from any thread
     let mut stream = Box::new(ffi::ArrowArrayStream::empty());
-    unsafe { ffi::export_iterator(iter, field, &mut *stream) };
+    unsafe { ffi::export_iterator_send(iter, field, &mut *stream) };
 
     // call pyarrow's interface to read this stream
     let pa = py.import("pyarrow.ipc")?;
@@ -11,7 +11,13 @@ use pyo3::ffi::Py_uintptr_t;
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
 
-use arrow2::{array::Array, datatypes::Field, error::ArrowError, ffi};
+use arrow2::{
+    array::Array,
+    chunk::Chunk,
+    datatypes::{Field, Schema},
+    error::ArrowError,
+    ffi,
+};
 
 /// an error that bridges ArrowError with a Python error
 #[derive(Debug)]
@@ -134,6 +140,73 @@ fn to_py_field(field: &Field, py: Python) -> PyResult<PyObject> {
     Ok(array.to_object(py))
 }
 
+fn to_rust_schema(ob: PyObject, py: Python) -> PyResult<Schema> {
+    let schema = Box::new(ffi::ArrowSchema::empty());
+
+    let schema_ptr = &*schema as *const ffi::ArrowSchema;
+
+    ob.call_method1(py, "_export_to_c", (schema_ptr as Py_uintptr_t,))?;
+
+    let schema =
+        unsafe { ffi::import_schema_from_c(schema.as_ref()).map_err(PyO3ArrowError::from)? };
+
+    Ok(schema)
+}
+
+fn to_py_schema(schema: &Schema, py: Python) -> PyResult<PyObject> {
+    let schema_ptr = Box::new(ffi::ArrowSchema::empty());
+    let schema_ptr = Box::into_raw(schema_ptr);
+
+    unsafe {
+        ffi::export_schema_to_c(schema, schema_ptr);
+    };
+
+    let pa = py.import("pyarrow")?;
+
+    let schema = pa
+        .getattr("Schema")?
+        .call_method1("_import_from_c", (schema_ptr as Py_uintptr_t,))?;
+
+    unsafe { Box::from_raw(schema_ptr) };
+
+    Ok(schema.to_object(py))
+}
+
+fn to_rust_chunk(ob: PyObject, py: Python) -> PyResult<(Schema, Chunk<Arc<dyn Array>>)> {
+    let array = Box::new(ffi::ArrowArray::empty());
+    let schema = Box::new(ffi::ArrowSchema::empty());
+
+    let array_ptr = &*array as *const ffi::ArrowArray;
+    let schema_ptr = &*schema as *const ffi::ArrowSchema;
+
+    ob.call_method1(
+        py,
+        "_export_to_c",
+        (array_ptr as Py_uintptr_t, schema_ptr as Py_uintptr_t),
+    )?;
+
+    let chunk =
+        unsafe { ffi::import_chunk_from_c(array, schema.as_ref()).map_err(PyO3ArrowError::from)? };
+    Ok(chunk)
+}
+
+fn to_py_chunk(schema: &Schema, chunk: Chunk<Arc<dyn Array>>, py: Python) -> PyResult<PyObject> {
+    // `exported` owns the structs until pyarrow moves them out of the pointers
+    let mut exported = ffi::export_chunk_to_c(chunk, schema).map_err(PyO3ArrowError::from)?;
+
+    let pa = py.import("pyarrow")?;
+
+    let batch = pa.getattr("RecordBatch")?.call_method1(
+        "_import_from_c",
+        (
+            exported.array_ptr() as Py_uintptr_t,
+            exported.schema_ptr() as Py_uintptr_t,
+        ),
+    )?;
+
+    Ok(batch.to_object(py))
+}
+
 /// Converts to rust and back to python
 #[pyfunction]
 fn round_trip_array(array: PyObject, py: Python) -> PyResult<PyObject> {
@@ -154,6 +227,26 @@ fn round_trip_field(array: PyObject, py: Python) -> PyResult<PyObject> {
     to_py_field(&field, py)
 }
 
+/// Converts to rust and back to python
+#[pyfunction]
+fn round_trip_schema(schema: PyObject, py: Python) -> PyResult<PyObject> {
+    // import
+    let schema = to_rust_schema(schema, py)?;
+
+    // export
+    to_py_schema(&schema, py)
+}
+
+/// Converts to rust and back to python
+#[pyfunction]
+fn round_trip_record_batch(batch: PyObject, py: Python) -> PyResult<PyObject> {
+    // import
+    let (schema, chunk) = to_rust_chunk(batch, py)?;
+
+    // export
+    to_py_chunk(&schema, chunk, py)
+}
+
 #[pyfunction]
 pub fn to_rust_iterator(ob: PyObject, py: Python) -> PyResult<Vec<PyObject>> {
     c_stream::to_rust_iterator(ob, py)
@@ -168,6 +261,8 @@ pub fn from_rust_iterator(py: Python) -> PyResult<PyObject> {
 fn arrow_pyarrow_integration_testing(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(round_trip_array, m)?)?;
     m.add_function(wrap_pyfunction!(round_trip_field, m)?)?;
+    m.add_function(wrap_pyfunction!(round_trip_schema, m)?)?;
+    m.add_function(wrap_pyfunction!(round_trip_record_batch, m)?)?;
     m.add_function(wrap_pyfunction!(to_rust_iterator, m)?)?;
     m.add_function(wrap_pyfunction!(from_rust_iterator, m)?)?;
     Ok(())
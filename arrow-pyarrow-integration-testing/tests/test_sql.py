@@ -314,3 +314,43 @@ class TestCase(unittest.TestCase):
         result = arrow_pyarrow_integration_testing.round_trip_field(field)
         assert field == result
         assert field.metadata == result.metadata
+
+    def test_field_dictionary_ordered(self):
+        field = pyarrow.field(
+            "aa", pyarrow.dictionary(pyarrow.int32(), pyarrow.string(), ordered=True)
+        )
+        result = arrow_pyarrow_integration_testing.round_trip_field(field)
+        assert field == result
+        assert result.type.ordered
+
+    def test_field_nested_metadata(self):
+        child = pyarrow.field("ab", pyarrow.bool_(), metadata={"c": "d"})
+        field = pyarrow.field("aa", pyarrow.struct([child]), metadata={"a": "b"})
+        result = arrow_pyarrow_integration_testing.round_trip_field(field)
+        assert field.equals(result, check_metadata=True)
+        assert result.type[0].metadata == {b"c": b"d"}
+
+    def test_schema_metadata(self):
+        schema = pyarrow.schema(
+            [
+                pyarrow.field("aa", pyarrow.int32(), metadata={"c": "d"}),
+                pyarrow.field("ab", pyarrow.dictionary(pyarrow.int8(), pyarrow.string())),
+            ],
+            metadata={"a": "b"},
+        )
+        result = arrow_pyarrow_integration_testing.round_trip_schema(schema)
+        assert schema.equals(result, check_metadata=True)
+
+    def test_record_batch(self):
+        batch = pyarrow.record_batch(
+            [
+                pyarrow.array([1, None, 3], pyarrow.int32()),
+                pyarrow.array(["a", "b", None]),
+            ],
+            schema=pyarrow.schema(
+                [pyarrow.field("aa", pyarrow.int32()), pyarrow.field("ab", pyarrow.string())],
+                metadata={"a": "b"},
+            ),
+        )
+        result = arrow_pyarrow_integration_testing.round_trip_record_batch(batch)
+        assert batch.equals(result, check_metadata=True)
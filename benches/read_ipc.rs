@@ -0,0 +1,72 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::io::Cursor;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::Field;
+use arrow2::error::Result;
+use arrow2::io::ipc::read::{read_file_metadata, FileReader};
+use arrow2::io::ipc::write::*;
+use arrow2::util::bench_util::{
+    create_boolean_array, create_list_array, create_primitive_array, create_string_array,
+};
+
+fn write(array: &dyn Array) -> Result<Vec<u8>> {
+    let field = Field::new("c1", array.data_type().clone(), true);
+    let schema = vec![field].into();
+    let columns = Chunk::try_new(vec![clone(array).into()])?;
+
+    let writer = Cursor::new(vec![]);
+    let mut writer = FileWriter::try_new(writer, &schema, None, Default::default())?;
+
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    Ok(writer.into_inner().into_inner())
+}
+
+fn read(data: &[u8]) -> Result<()> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let reader = FileReader::new(reader, metadata, None);
+    for chunk in reader {
+        chunk?;
+    }
+    Ok(())
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    for null_density in [0.0, 0.1, 0.5] {
+        (0..=10).step_by(2).for_each(|i| {
+            let array = create_primitive_array::<i64>(1024 * 2usize.pow(i), null_density);
+            let data = write(&array).unwrap();
+            let a = format!("read i64 null {} 2^{}", null_density, 10 + i);
+            c.bench_function(&a, |b| b.iter(|| read(&data).unwrap()));
+        });
+    }
+
+    (0..=10).step_by(2).for_each(|i| {
+        let array = create_boolean_array(1024 * 2usize.pow(i), 0.1, 0.5);
+        let data = write(&array).unwrap();
+        let a = format!("read bool 2^{}", 10 + i);
+        c.bench_function(&a, |b| b.iter(|| read(&data).unwrap()));
+    });
+
+    for size in [4, 64] {
+        (0..=10).step_by(2).for_each(|i| {
+            let array = create_string_array::<i32>(1024 * 2usize.pow(i), size, 0.1, 42);
+            let data = write(&array).unwrap();
+            let a = format!("read utf8 {} 2^{}", size, 10 + i);
+            c.bench_function(&a, |b| b.iter(|| read(&data).unwrap()));
+        });
+    }
+
+    (0..=10).step_by(2).for_each(|i| {
+        let array = create_list_array::<i32>(1024 * 2usize.pow(i), 8, 0.1, 42);
+        let data = write(&array).unwrap();
+        let a = format!("read list 2^{}", 10 + i);
+        c.bench_function(&a, |b| b.iter(|| read(&data).unwrap()));
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -7,7 +7,9 @@ use arrow2::chunk::Chunk;
 use arrow2::datatypes::{Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::parquet::write::*;
-use arrow2::util::bench_util::{create_boolean_array, create_primitive_array, create_string_array};
+use arrow2::util::bench_util::{
+    create_boolean_array, create_list_array, create_primitive_array, create_string_array,
+};
 
 type ChunkArc = Chunk<Arc<dyn Array>>;
 
@@ -67,6 +69,18 @@ fn add_benchmark(c: &mut Criterion) {
             b.iter(|| write(array, Encoding::DeltaLengthByteArray).unwrap())
         });
     });
+
+    (0..=10).step_by(2).for_each(|i| {
+        let array = &create_string_array::<i32>(1024 * 2usize.pow(i), 64, 0.1, 42);
+        let a = format!("write utf8 large 2^{}", 10 + i);
+        c.bench_function(&a, |b| b.iter(|| write(array, Encoding::Plain).unwrap()));
+    });
+
+    (0..=10).step_by(2).for_each(|i| {
+        let array = &create_list_array::<i32>(1024 * 2usize.pow(i), 8, 0.1, 42);
+        let a = format!("write list 2^{}", 10 + i);
+        c.bench_function(&a, |b| b.iter(|| write(array, Encoding::Plain).unwrap()));
+    });
 }
 
 criterion_group!(benches, add_benchmark);
@@ -17,10 +17,13 @@ async fn main() -> Result<()> {
 
     let (fields, _) = infer_schema(&mut reader, None, true, &infer).await?;
 
-    let mut rows = vec![ByteRecord::default(); 100];
-    let rows_read = read_rows(&mut reader, 0, &mut rows).await?;
+    let file = File::open(file_path).await?.compat();
+    let reader = AsyncReaderBuilder::new().create_reader(file);
 
-    let columns = deserialize_batch(&rows[..rows_read], &fields, None, 0, deserialize_column)?;
-    println!("{:?}", columns.arrays()[0]);
+    // read the first column in chunks of 100 rows
+    let mut reader = ChunkReader::try_new(reader, fields, Some(vec![0]), 100)?;
+    while let Some(chunk) = reader.next_chunk().await? {
+        println!("{:?}", chunk.arrays()[0]);
+    }
     Ok(())
 }
@@ -11,12 +11,17 @@ use super::BinaryArray;
 pub struct BinaryValueIter<'a, O: Offset> {
     array: &'a BinaryArray<O>,
     index: usize,
+    end: usize,
 }
 
 impl<'a, O: Offset> BinaryValueIter<'a, O> {
     /// Creates a new [`BinaryValueIter`]
     pub fn new(array: &'a BinaryArray<O>) -> Self {
-        Self { array, index: 0 }
+        Self {
+            array,
+            index: 0,
+            end: array.len(),
+        }
     }
 }
 
@@ -25,7 +30,7 @@ impl<'a, O: Offset> Iterator for BinaryValueIter<'a, O> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.array.len() {
+        if self.index >= self.end {
             return None;
         } else {
             self.index += 1;
@@ -34,10 +39,7 @@ impl<'a, O: Offset> Iterator for BinaryValueIter<'a, O> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (
-            self.array.len() - self.index,
-            Some(self.array.len() - self.index),
-        )
+        (self.end - self.index, Some(self.end - self.index))
     }
 }
 
@@ -63,6 +65,22 @@ impl<'a, O: Offset> BinaryArray<O> {
     pub fn values_iter(&'a self) -> BinaryValueIter<'a, O> {
         BinaryValueIter::new(self)
     }
+
+    /// Returns an iterator of the `&[u8]` of the slots `[offset, offset + length)`,
+    /// without bound checks.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn values_iter_unchecked(
+        &'a self,
+        offset: usize,
+        length: usize,
+    ) -> BinaryValueIter<'a, O> {
+        BinaryValueIter {
+            array: self,
+            index: offset,
+            end: offset + length,
+        }
+    }
 }
 
 unsafe impl<O: Offset> TrustedLen for BinaryValueIter<'_, O> {}
@@ -1,3 +1,5 @@
+use either::Either;
+
 use crate::{
     bitmap::Bitmap,
     buffer::Buffer,
@@ -6,6 +8,7 @@ use crate::{
 };
 
 use super::{
+    check_slice,
     specification::{try_check_offsets, try_check_offsets_bounds},
     Array, GenericBinaryArray, Offset,
 };
@@ -214,37 +217,63 @@ impl<O: Offset> BinaryArray<O> {
 
 // must use
 impl<O: Offset> BinaryArray<O> {
-    /// Creates a new [`BinaryArray`] by slicing this [`BinaryArray`].
+    /// Returns a slice of this [`BinaryArray`].
     /// # Implementation
     /// This function is `O(1)`: all data will be shared between both arrays.
-    /// # Panics
-    /// iff `offset + length > self.len()`.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
     #[must_use]
     pub fn slice(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced(offset, length)
+    }
+
+    /// Returns a slice of this [`BinaryArray`].
+    /// # Implementation
+    /// This function is `O(1)`: all data will be shared between both arrays.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[must_use]
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced_unchecked(offset, length)
+    }
+
+    /// Returns a slice of this [`BinaryArray`].
+    /// # Implementation
+    /// This function is `O(1)`: all data will be shared between both arrays.
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
+    /// Slices this [`BinaryArray`], consuming it.
+    /// # Implementation
+    /// This function is `O(1)`: all data will be shared between both arrays.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[must_use]
+    pub fn sliced(self, offset: usize, length: usize) -> Self {
         assert!(
             offset + length <= self.len(),
-            "the offset of the new Buffer cannot exceed the existing length"
+            "offset + length may not exceed length of array"
         );
-        unsafe { self.slice_unchecked(offset, length) }
+        unsafe { self.sliced_unchecked(offset, length) }
     }
 
-    /// Creates a new [`BinaryArray`] by slicing this [`BinaryArray`].
+    /// Slices this [`BinaryArray`], consuming it.
     /// # Implementation
     /// This function is `O(1)`: all data will be shared between both arrays.
     /// # Safety
     /// The caller must ensure that `offset + length <= self.len()`.
     #[must_use]
-    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
-        let validity = self
-            .validity
-            .clone()
-            .map(|x| x.slice_unchecked(offset, length));
-        let offsets = self.offsets.clone().slice_unchecked(offset, length + 1);
+    pub unsafe fn sliced_unchecked(self, offset: usize, length: usize) -> Self {
         Self {
-            data_type: self.data_type.clone(),
-            offsets,
-            values: self.values.clone(),
-            validity,
+            data_type: self.data_type,
+            // + 1: `length == 0` implies that we take the first offset.
+            offsets: self.offsets.slice_unchecked(offset, length + 1),
+            values: self.values,
+            validity: self.validity.map(|x| x.slice_unchecked(offset, length)),
         }
     }
 
@@ -253,12 +282,56 @@ impl<O: Offset> BinaryArray<O> {
     /// Panics iff `validity.len() != self.len()`.
     #[must_use]
     pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+        let mut arr = self.clone();
+        arr.set_validity(validity);
+        arr
+    }
+
+    /// Sets the validity of this [`BinaryArray`] in place, without copying its values.
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
         if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
             panic!("validity's length must be equal to the array's length")
         }
-        let mut arr = self.clone();
-        arr.validity = validity;
-        arr
+        self.validity = validity;
+    }
+
+    /// Try to convert this [`BinaryArray`] to a [`MutableBinaryArray`], which succeeds
+    /// iff its offsets, values and validity are not shared with other arrays.
+    pub fn into_mut(self) -> Either<Self, MutableBinaryArray<O>> {
+        use Either::*;
+        let validity = match self.validity.map(|x| x.into_mut()) {
+            // Safety: invariants are preserved
+            Some(Left(bitmap)) => {
+                return Left(unsafe {
+                    Self::new_unchecked(self.data_type, self.offsets, self.values, Some(bitmap))
+                })
+            }
+            Some(Right(mutable_bitmap)) => Some(mutable_bitmap),
+            None => None,
+        };
+        match (self.values.into_mut(), self.offsets.into_mut()) {
+            (Right(mut values), Right(offsets)) => {
+                // the offsets may be a slice of the first items of the original array
+                values.truncate(offsets.last().unwrap().to_usize());
+                Right(MutableBinaryArray::from_data(
+                    self.data_type,
+                    offsets,
+                    values,
+                    validity,
+                ))
+            }
+            // Safety: invariants are preserved
+            (values, offsets) => Left(unsafe {
+                Self::new_unchecked(
+                    self.data_type,
+                    offsets.either_into(),
+                    values.either_into(),
+                    validity.map(|x| x.into()),
+                )
+            }),
+        }
     }
 }
 
@@ -78,6 +78,11 @@ impl<O: Offset> MutableBinaryArray<O> {
         BinaryArray::<O>::default_data_type()
     }
 
+    /// Extract the low-end APIs from the [`MutableBinaryArray`].
+    pub fn into_data(self) -> (DataType, Vec<O>, Vec<u8>, Option<MutableBitmap>) {
+        (self.data_type, self.offsets, self.values, self.validity)
+    }
+
     /// Creates a new [`MutableBinaryArray`] with capacity for `capacity` values.
     /// # Implementation
     /// This does not allocate the validity.
@@ -1,11 +1,14 @@
 use crate::{
-    bitmap::Bitmap,
+    bitmap::{
+        utils::{TrueIndex, TrueIndicesIter},
+        Bitmap,
+    },
     datatypes::{DataType, PhysicalType},
     error::ArrowError,
 };
 use either::Either;
 
-use super::Array;
+use super::{check_slice, Array};
 
 mod ffi;
 pub(super) mod fmt;
@@ -90,15 +93,10 @@ impl BooleanArray {
     /// # Implementation
     /// This operation is `O(1)` as it amounts to increase two ref counts.
     /// # Panic
-    /// This function panics iff `offset + length >= self.len()`.
-    #[inline]
+    /// This function panics iff `offset + length > self.len()`.
     #[must_use]
     pub fn slice(&self, offset: usize, length: usize) -> Self {
-        assert!(
-            offset + length <= self.len(),
-            "the offset of the new Buffer cannot exceed the existing length"
-        );
-        unsafe { self.slice_unchecked(offset, length) }
+        self.clone().sliced(offset, length)
     }
 
     /// Returns a slice of this [`BooleanArray`].
@@ -106,17 +104,46 @@ impl BooleanArray {
     /// This operation is `O(1)` as it amounts to increase two ref counts.
     /// # Safety
     /// The caller must ensure that `offset + length <= self.len()`.
-    #[inline]
     #[must_use]
     pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
-        let validity = self
-            .validity
-            .clone()
-            .map(|x| x.slice_unchecked(offset, length));
+        self.clone().sliced_unchecked(offset, length)
+    }
+
+    /// Returns a slice of this [`BooleanArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self, ArrowError> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
+    /// Slices this [`BooleanArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)` and, unlike [`Self::slice`], does not need to increase two ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[must_use]
+    pub fn sliced(self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+        unsafe { self.sliced_unchecked(offset, length) }
+    }
+
+    /// Slices this [`BooleanArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)` and, unlike [`Self::slice`], does not need to increase two ref counts.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[must_use]
+    pub unsafe fn sliced_unchecked(self, offset: usize, length: usize) -> Self {
         Self {
-            data_type: self.data_type.clone(),
-            values: self.values.clone().slice_unchecked(offset, length),
-            validity,
+            data_type: self.data_type,
+            values: self.values.slice_unchecked(offset, length),
+            validity: self.validity.map(|x| x.slice_unchecked(offset, length)),
         }
     }
 
@@ -125,12 +152,19 @@ impl BooleanArray {
     /// This function panics iff `validity.len() != self.len()`.
     #[must_use]
     pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+        let mut arr = self.clone();
+        arr.set_validity(validity);
+        arr
+    }
+
+    /// Sets the validity of this [`BooleanArray`] in place, without copying its values.
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
         if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
             panic!("validity should be as least as large as the array")
         }
-        let mut arr = self.clone();
-        arr.validity = validity;
-        arr
+        self.validity = validity;
     }
 
     /// Try to convert this [`BooleanArray`] to a [`MutableBooleanArray`]
@@ -201,6 +235,22 @@ impl BooleanArray {
     pub fn values(&self) -> &Bitmap {
         &self.values
     }
+
+    /// Returns an iterator over the positions of the valid `true` values of this
+    /// [`BooleanArray`], e.g. to convert a filter to the indices of a take.
+    /// # Example
+    /// ```
+    /// use arrow2::array::BooleanArray;
+    ///
+    /// let array = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+    /// let indices = array.true_indices::<usize>().collect::<Vec<_>>();
+    /// assert_eq!(indices, vec![0, 3]);
+    /// ```
+    /// # Panics
+    /// Panics iff the length of this array is larger than [`TrueIndex::MAX_LENGTH`].
+    pub fn true_indices<I: TrueIndex>(&self) -> TrueIndicesIter<'_, I> {
+        TrueIndicesIter::new(&self.values, self.validity.as_ref())
+    }
 }
 
 impl Array for BooleanArray {
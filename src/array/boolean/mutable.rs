@@ -84,6 +84,11 @@ impl MutableBooleanArray {
         }
     }
 
+    /// Extract the low-end APIs from the [`MutableBooleanArray`].
+    pub fn into_data(self) -> (DataType, MutableBitmap, Option<MutableBitmap>) {
+        (self.data_type, self.values, self.validity)
+    }
+
     /// Pushes a new entry to [`MutableBooleanArray`].
     pub fn push(&mut self, value: Option<bool>) {
         match value {
@@ -135,6 +140,16 @@ impl MutableBooleanArray {
         self.values.extend_from_trusted_len_iter_unchecked(iterator)
     }
 
+    /// Extends the [`MutableBooleanArray`] from a slice of values.
+    /// This is faster than [`MutableBooleanArray::extend_trusted_len_values`] since
+    /// it packs 8 values into a byte at once.
+    pub fn extend_from_slice(&mut self, slice: &[bool]) {
+        if let Some(validity) = self.validity.as_mut() {
+            validity.extend_constant(slice.len(), true);
+        }
+        self.values.extend_from_bool_slice(slice)
+    }
+
     /// Extends the [`MutableBooleanArray`] from an iterator of trusted len.
     #[inline]
     pub fn extend_trusted_len<I, P>(&mut self, iterator: I)
@@ -244,7 +259,7 @@ impl MutableBooleanArray {
     /// Creates a new [`MutableBooleanArray`] from a slice of `bool`.
     #[inline]
     pub fn from_slice<P: AsRef<[bool]>>(slice: P) -> Self {
-        Self::from_trusted_len_values_iter(slice.as_ref().iter().copied())
+        Self::from_data(DataType::Boolean, MutableBitmap::from(slice), None)
     }
 
     /// Creates a [`BooleanArray`] from an iterator of trusted length.
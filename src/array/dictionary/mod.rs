@@ -3,6 +3,7 @@ use std::sync::Arc;
 use crate::{
     bitmap::Bitmap,
     datatypes::{DataType, IntegerType},
+    error::Result,
     scalar::{new_scalar, Scalar},
     types::NativeType,
 };
@@ -11,10 +12,12 @@ mod ffi;
 pub(super) mod fmt;
 mod iterator;
 mod mutable;
+mod typed_iterator;
 pub use iterator::*;
 pub use mutable::*;
+pub use typed_iterator::{DictValue, DictionaryValuesIterTyped};
 
-use super::{new_empty_array, primitive::PrimitiveArray, Array};
+use super::{check_slice, new_empty_array, primitive::PrimitiveArray, Array};
 use crate::scalar::NullScalar;
 
 /// Trait denoting [`NativeType`]s that can be used as keys of a dictionary.
@@ -89,38 +92,77 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         }
     }
 
-    /// Creates a new [`DictionaryArray`] by slicing the existing [`DictionaryArray`].
-    /// # Panics
-    /// iff `offset + length > self.len()`.
+    /// Returns a slice of this [`DictionaryArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
     pub fn slice(&self, offset: usize, length: usize) -> Self {
-        Self {
-            data_type: self.data_type.clone(),
-            keys: self.keys.clone().slice(offset, length),
-            values: self.values.clone(),
-        }
+        self.clone().sliced(offset, length)
     }
 
-    /// Creates a new [`DictionaryArray`] by slicing the existing [`DictionaryArray`].
+    /// Returns a slice of this [`DictionaryArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
     /// # Safety
-    /// Safe iff `offset + length <= self.len()`.
+    /// The caller must ensure that `offset + length <= self.len()`.
     pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced_unchecked(offset, length)
+    }
+
+    /// Returns a slice of this [`DictionaryArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
+    /// Slices this [`DictionaryArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)` and, unlike [`Self::slice`], does not need to increase two ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    pub fn sliced(self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+        unsafe { self.sliced_unchecked(offset, length) }
+    }
+
+    /// Slices this [`DictionaryArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)` and, unlike [`Self::slice`], does not need to increase two ref counts.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn sliced_unchecked(self, offset: usize, length: usize) -> Self {
         Self {
-            data_type: self.data_type.clone(),
-            keys: self.keys.clone().slice_unchecked(offset, length),
-            values: self.values.clone(),
+            data_type: self.data_type,
+            keys: self.keys.sliced_unchecked(offset, length),
+            values: self.values,
         }
     }
 
-    /// Sets the validity bitmap on this [`Array`].
+    /// Sets the validity bitmap on this [`DictionaryArray`].
     /// # Panic
     /// This function panics iff `validity.len() != self.len()`.
     pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+        let mut arr = self.clone();
+        arr.set_validity(validity);
+        arr
+    }
+
+    /// Sets the validity of this [`DictionaryArray`] in place, without copying its values.
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
         if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
             panic!("validity should be as least as large as the array")
         }
-        let mut arr = self.clone();
-        arr.values = Arc::from(arr.values.with_validity(validity));
-        arr
+        self.keys.set_validity(validity);
     }
 }
 
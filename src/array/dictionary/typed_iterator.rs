@@ -0,0 +1,148 @@
+use crate::array::{Array, BinaryArray, FixedSizeBinaryArray, Offset, PrimitiveArray, Utf8Array};
+use crate::error::{ArrowError, Result};
+use crate::trusted_len::TrustedLen;
+use crate::types::NativeType;
+
+use super::{DictionaryArray, DictionaryKey};
+
+/// Trait of arrays whose values can be borrowed, so that they can be iterated as values of a
+/// [`DictionaryArray`] via [`DictionaryArray::iter_typed`].
+pub trait DictValue: Array + 'static {
+    /// The type of the values of this array (e.g. `str` for [`Utf8Array`])
+    type Value: ?Sized;
+
+    /// Returns the value at position `index`.
+    /// # Panics
+    /// Panics iff `index >= self.len()`.
+    fn value_ref(&self, index: usize) -> &Self::Value;
+}
+
+impl<O: Offset> DictValue for Utf8Array<O> {
+    type Value = str;
+
+    #[inline]
+    fn value_ref(&self, index: usize) -> &str {
+        self.value(index)
+    }
+}
+
+impl<O: Offset> DictValue for BinaryArray<O> {
+    type Value = [u8];
+
+    #[inline]
+    fn value_ref(&self, index: usize) -> &[u8] {
+        self.value(index)
+    }
+}
+
+impl DictValue for FixedSizeBinaryArray {
+    type Value = [u8];
+
+    #[inline]
+    fn value_ref(&self, index: usize) -> &[u8] {
+        self.value(index)
+    }
+}
+
+impl<T: NativeType> DictValue for PrimitiveArray<T> {
+    type Value = T;
+
+    #[inline]
+    fn value_ref(&self, index: usize) -> &T {
+        &self.values()[index]
+    }
+}
+
+/// Iterator of the logical values of a [`DictionaryArray`] whose values are of type `V`.
+/// A slot is `None` when either its key or the value it points to is null.
+pub struct DictionaryValuesIterTyped<'a, K: DictionaryKey, V: DictValue> {
+    keys: &'a PrimitiveArray<K>,
+    values: &'a V,
+    index: usize,
+    end: usize,
+}
+
+impl<'a, K: DictionaryKey, V: DictValue> DictionaryValuesIterTyped<'a, K, V> {
+    #[inline]
+    fn get(&self, index: usize) -> Option<&'a V::Value> {
+        if self.keys.is_null(index) {
+            return None;
+        }
+        let key = self.keys.value(index).to_usize().unwrap();
+        if self.values.is_null(key) {
+            None
+        } else {
+            Some(self.values.value_ref(key))
+        }
+    }
+}
+
+impl<'a, K: DictionaryKey, V: DictValue> Iterator for DictionaryValuesIterTyped<'a, K, V> {
+    type Item = Option<&'a V::Value>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            return None;
+        }
+        let old = self.index;
+        self.index += 1;
+        Some(self.get(old))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.index, Some(self.end - self.index))
+    }
+}
+
+unsafe impl<'a, K: DictionaryKey, V: DictValue> TrustedLen for DictionaryValuesIterTyped<'a, K, V> {}
+
+impl<'a, K: DictionaryKey, V: DictValue> DoubleEndedIterator
+    for DictionaryValuesIterTyped<'a, K, V>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(self.get(self.end))
+        }
+    }
+}
+
+impl<K: DictionaryKey> DictionaryArray<K> {
+    /// Returns an iterator of the logical values of this [`DictionaryArray`], resolving
+    /// each key through its values of type `V`.
+    /// # Example
+    /// ```
+    /// use arrow2::array::{
+    ///     DictionaryArray, MutableDictionaryArray, MutableUtf8Array, TryExtend, Utf8Array,
+    /// };
+    ///
+    /// let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    /// array.try_extend([Some("a"), None, Some("a")]).unwrap();
+    /// let array: DictionaryArray<i32> = array.into();
+    ///
+    /// let values = array.iter_typed::<Utf8Array<i32>>().unwrap().collect::<Vec<_>>();
+    /// assert_eq!(values, vec![Some("a"), None, Some("a")]);
+    /// ```
+    /// # Errors
+    /// Errors iff the values of this [`DictionaryArray`] are not of type `V`.
+    pub fn iter_typed<V: DictValue>(&self) -> Result<DictionaryValuesIterTyped<'_, K, V>> {
+        let values = self.values.as_any().downcast_ref::<V>().ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The values of this dictionary are of type {:?}, which does not correspond to {}",
+                self.values.data_type(),
+                std::any::type_name::<V>()
+            ))
+        })?;
+        Ok(DictionaryValuesIterTyped {
+            keys: &self.keys,
+            values,
+            index: 0,
+            end: self.len(),
+        })
+    }
+}
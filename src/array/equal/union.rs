@@ -1,5 +1,14 @@
 use crate::array::{Array, UnionArray};
 
 pub(super) fn equal(lhs: &UnionArray, rhs: &UnionArray) -> bool {
-    lhs.data_type() == rhs.data_type() && lhs.len() == rhs.len() && lhs.iter().eq(rhs.iter())
+    lhs.data_type() == rhs.data_type()
+        && lhs.len() == rhs.len()
+        // compare slots as arrays since not all types have a corresponding scalar
+        && (0..lhs.len()).all(|i| {
+            let (lhs_field, lhs_index) = lhs.index(i);
+            let (rhs_field, rhs_index) = rhs.index(i);
+            lhs_field == rhs_field
+                && lhs.fields()[lhs_field].slice(lhs_index, 1)
+                    == rhs.fields()[rhs_field].slice(rhs_index, 1)
+        })
 }
@@ -1,6 +1,6 @@
 use crate::{bitmap::Bitmap, buffer::Buffer, datatypes::DataType, error::ArrowError};
 
-use super::Array;
+use super::{check_slice, Array};
 
 mod ffi;
 pub(super) mod fmt;
@@ -95,15 +95,11 @@ impl FixedSizeBinaryArray {
     /// Returns a slice of this [`FixedSizeBinaryArray`].
     /// # Implementation
     /// This operation is `O(1)` as it amounts to increase 3 ref counts.
-    /// # Panics
-    /// panics iff `offset + length > self.len()`
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
     #[must_use]
     pub fn slice(&self, offset: usize, length: usize) -> Self {
-        assert!(
-            offset + length <= self.len(),
-            "the offset of the new Buffer cannot exceed the existing length"
-        );
-        unsafe { self.slice_unchecked(offset, length) }
+        self.clone().sliced(offset, length)
     }
 
     /// Returns a slice of this [`FixedSizeBinaryArray`].
@@ -113,19 +109,48 @@ impl FixedSizeBinaryArray {
     /// The caller must ensure that `offset + length <= self.len()`.
     #[must_use]
     pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
-        let validity = self
-            .validity
-            .clone()
-            .map(|x| x.slice_unchecked(offset, length));
+        self.clone().sliced_unchecked(offset, length)
+    }
+
+    /// Returns a slice of this [`FixedSizeBinaryArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase 3 ref counts.
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self, ArrowError> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
+    /// Slices this [`FixedSizeBinaryArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)` and, unlike [`Self::slice`], does not need to increase 3 ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[must_use]
+    pub fn sliced(self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+        unsafe { self.sliced_unchecked(offset, length) }
+    }
+
+    /// Slices this [`FixedSizeBinaryArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)` and, unlike [`Self::slice`], does not need to increase 3 ref counts.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[must_use]
+    pub unsafe fn sliced_unchecked(self, offset: usize, length: usize) -> Self {
         let values = self
             .values
-            .clone()
             .slice_unchecked(offset * self.size as usize, length * self.size as usize);
         Self {
-            data_type: self.data_type.clone(),
+            data_type: self.data_type,
             size: self.size,
             values,
-            validity,
+            validity: self.validity.map(|x| x.slice_unchecked(offset, length)),
         }
     }
 
@@ -134,12 +159,19 @@ impl FixedSizeBinaryArray {
     /// This function panics iff `validity.len() != self.len()`.
     #[must_use]
     pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+        let mut arr = self.clone();
+        arr.set_validity(validity);
+        arr
+    }
+
+    /// Sets the validity of this [`FixedSizeBinaryArray`] in place, without copying its values.
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
         if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
             panic!("validity should be as least as large as the array")
         }
-        let mut arr = self.clone();
-        arr.validity = validity;
-        arr
+        self.validity = validity;
     }
 }
 
@@ -6,7 +6,7 @@ use crate::{
     error::ArrowError,
 };
 
-use super::{new_empty_array, new_null_array, Array};
+use super::{check_slice, new_empty_array, new_null_array, Array};
 
 mod ffi;
 pub(super) mod fmt;
@@ -118,38 +118,63 @@ impl FixedSizeListArray {
     /// Returns a slice of this [`FixedSizeListArray`].
     /// # Implementation
     /// This operation is `O(1)`.
-    /// # Panics
-    /// panics iff `offset + length > self.len()`
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
     #[must_use]
     pub fn slice(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced(offset, length)
+    }
+
+    /// Returns a slice of this [`FixedSizeListArray`].
+    /// # Implementation
+    /// This operation is `O(1)`.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[must_use]
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced_unchecked(offset, length)
+    }
+
+    /// Returns a slice of this [`FixedSizeListArray`].
+    /// # Implementation
+    /// This operation is `O(1)`.
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self, ArrowError> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
+    /// Slices this [`FixedSizeListArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)`.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[must_use]
+    pub fn sliced(self, offset: usize, length: usize) -> Self {
         assert!(
             offset + length <= self.len(),
-            "the offset of the new Buffer cannot exceed the existing length"
+            "offset + length may not exceed length of array"
         );
-        unsafe { self.slice_unchecked(offset, length) }
+        unsafe { self.sliced_unchecked(offset, length) }
     }
 
-    /// Returns a slice of this [`FixedSizeListArray`].
+    /// Slices this [`FixedSizeListArray`], consuming it.
     /// # Implementation
     /// This operation is `O(1)`.
     /// # Safety
     /// The caller must ensure that `offset + length <= self.len()`.
     #[must_use]
-    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
-        let validity = self
-            .validity
-            .clone()
-            .map(|x| x.slice_unchecked(offset, length));
+    pub unsafe fn sliced_unchecked(self, offset: usize, length: usize) -> Self {
         let values = self
             .values
-            .clone()
             .slice_unchecked(offset * self.size as usize, length * self.size as usize)
             .into();
         Self {
-            data_type: self.data_type.clone(),
+            data_type: self.data_type,
             size: self.size,
             values,
-            validity,
+            validity: self.validity.map(|x| x.slice_unchecked(offset, length)),
         }
     }
 
@@ -158,12 +183,19 @@ impl FixedSizeListArray {
     /// This function panics iff `validity.len() != self.len()`.
     #[must_use]
     pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+        let mut arr = self.clone();
+        arr.set_validity(validity);
+        arr
+    }
+
+    /// Sets the validity of this [`FixedSizeListArray`] in place, without copying its values.
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
         if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
             panic!("validity should be as least as large as the array")
         }
-        let mut arr = self.clone();
-        arr.validity = validity;
-        arr
+        self.validity = validity;
     }
 }
 
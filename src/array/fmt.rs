@@ -88,7 +88,9 @@ pub fn get_value_display<'a, F: Write + 'a>(
         Union => Box::new(move |f, index| {
             super::union::fmt::write_value(array.as_any().downcast_ref().unwrap(), index, null, f)
         }),
-        Map => todo!(),
+        Map => Box::new(move |f, index| {
+            super::map::fmt::write_value(array.as_any().downcast_ref().unwrap(), index, null, f)
+        }),
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
             Box::new(move |f, index| {
                 super::dictionary::fmt::write_value::<$T,_>(array.as_any().downcast_ref().unwrap(), index, null, f)
@@ -131,6 +133,10 @@ where
     Ok(())
 }
 
+/// The number of values written at each end of an array with more than twice as many values.
+/// The values in between are elided, so that the output of large arrays remains readable.
+const EDGE_VALUES: usize = 10;
+
 fn write_list<D, F>(
     f: &mut F,
     d: D,
@@ -143,10 +149,21 @@ where
     D: Fn(&mut F, usize) -> Result,
     F: Write,
 {
-    for index in 0..len {
-        if index != 0 {
-            f.write_char(',')?;
-            f.write_char(if new_lines { '\n' } else { ' ' })?;
+    let separator = |f: &mut F| {
+        f.write_char(',')?;
+        f.write_char(if new_lines { '\n' } else { ' ' })
+    };
+
+    let tail = len.saturating_sub(EDGE_VALUES).max(EDGE_VALUES);
+    let elided = len.saturating_sub(2 * EDGE_VALUES);
+    let indices = (0..len.min(EDGE_VALUES)).chain(tail..len);
+    for (i, index) in indices.enumerate() {
+        if i != 0 {
+            separator(f)?;
+        }
+        if elided > 0 && index == tail {
+            write!(f, "...({} more)", elided)?;
+            separator(f)?;
         }
         if let Some(val) = validity {
             if val.get_bit(index) {
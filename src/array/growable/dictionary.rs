@@ -1,8 +1,14 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray},
+    array::{
+        Array, BinaryArray, DictionaryArray, DictionaryKey, FixedSizeBinaryArray, PrimitiveArray,
+        Utf8Array,
+    },
     bitmap::MutableBitmap,
+    datatypes::PhysicalType,
+    types::NativeType,
 };
 
 use super::{
@@ -13,24 +19,26 @@ use super::{
 
 /// Concrete [`Growable`] for the [`DictionaryArray`].
 /// # Implementation
-/// This growable does not perform collision checks and instead concatenates
-/// the values of each [`DictionaryArray`] one after the other.
+/// When the [`DictionaryArray`]s do not share their values, the values are merged:
+/// each distinct value is kept once and the keys are remapped to it.
+/// Values are identified by their bytes, which is supported for values of
+/// primitive, binary, utf8 and fixed size binary types. Values of other types are
+/// concatenated one after the other without collision checks.
 pub struct GrowableDictionary<'a, K: DictionaryKey> {
     keys_values: Vec<&'a [K]>,
     key_values: Vec<K>,
     key_validity: MutableBitmap,
     offsets: Vec<usize>,
+    // maps the index of a value in the concatenated values to its index in `values`
+    remap: Option<Vec<usize>>,
     values: Arc<dyn Array>,
     extend_null_bits: Vec<ExtendNullBits<'a>>,
 }
 
-fn concatenate_values<K: DictionaryKey>(
-    arrays_keys: &[&PrimitiveArray<K>],
-    arrays_values: &[&dyn Array],
-    capacity: usize,
-) -> (Arc<dyn Array>, Vec<usize>) {
+fn concatenate_values(arrays_values: &[&dyn Array]) -> (Arc<dyn Array>, Vec<usize>) {
+    let capacity = arrays_values.iter().map(|values| values.len()).sum();
     let mut mutable = make_growable(arrays_values, false, capacity);
-    let mut offsets = Vec::with_capacity(arrays_keys.len() + 1);
+    let mut offsets = Vec::with_capacity(arrays_values.len() + 1);
     offsets.push(0);
     for (i, values) in arrays_values.iter().enumerate() {
         mutable.extend(i, 0, values.len());
@@ -39,6 +47,79 @@ fn concatenate_values<K: DictionaryKey>(
     (mutable.as_arc(), offsets)
 }
 
+fn primitive_bytes<T: NativeType>(values: &dyn Array) -> Vec<Option<&[u8]>> {
+    let values = values.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let bytes: &[u8] = bytemuck::cast_slice(values.values().as_slice());
+    let size = std::mem::size_of::<T>();
+    (0..values.len())
+        .map(|i| {
+            if values.is_valid(i) {
+                Some(&bytes[i * size..(i + 1) * size])
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Returns the bytes identifying each value of `values`, or `None` if values of its
+/// type cannot be identified by their bytes.
+fn values_bytes(values: &dyn Array) -> Option<Vec<Option<&[u8]>>> {
+    use PhysicalType::*;
+    Some(match values.data_type().to_physical_type() {
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            primitive_bytes::<$T>(values)
+        }),
+        Binary => {
+            let values = values.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            values.iter().collect()
+        }
+        LargeBinary => {
+            let values = values.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            values.iter().collect()
+        }
+        Utf8 => {
+            let values = values.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            values.iter().map(|x| x.map(|x| x.as_bytes())).collect()
+        }
+        LargeUtf8 => {
+            let values = values.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            values.iter().map(|x| x.map(|x| x.as_bytes())).collect()
+        }
+        FixedSizeBinary => {
+            let values = values
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            values.iter().collect()
+        }
+        _ => return None,
+    })
+}
+
+/// Merges the concatenated `values`, returning the distinct values and the index of each
+/// value of `values` in them.
+fn merge_values(values: &dyn Array) -> Option<(Arc<dyn Array>, Vec<usize>)> {
+    let bytes = values_bytes(values)?;
+
+    let mut seen = HashMap::<Option<&[u8]>, usize>::with_capacity(bytes.len());
+    let mut distinct = vec![];
+    let remap = bytes
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| {
+            *seen.entry(value).or_insert_with(|| {
+                distinct.push(i);
+                distinct.len() - 1
+            })
+        })
+        .collect();
+
+    let mut mutable = make_growable(&[values], false, distinct.len());
+    distinct.into_iter().for_each(|i| mutable.extend(0, i, 1));
+    Some((mutable.as_arc(), remap))
+}
+
 impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
     /// Creates a new [`GrowableDictionary`] bound to `arrays` with a pre-allocated `capacity`.
     /// # Panics
@@ -61,15 +142,28 @@ impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
             .map(|array| build_extend_null_bits(array.keys(), use_validity))
             .collect();
 
-        let arrays_values = arrays
+        let first = arrays[0].values();
+        let (values, offsets, remap) = if arrays
             .iter()
-            .map(|array| array.values().as_ref())
-            .collect::<Vec<_>>();
-
-        let (values, offsets) = concatenate_values(&arrays_keys, &arrays_values, capacity);
+            .all(|array| Arc::ptr_eq(array.values(), first))
+        {
+            // the arrays share their values => the keys can be used as is
+            (first.clone(), vec![0; arrays.len()], None)
+        } else {
+            let arrays_values = arrays
+                .iter()
+                .map(|array| array.values().as_ref())
+                .collect::<Vec<_>>();
+            let (values, offsets) = concatenate_values(&arrays_values);
+            match merge_values(values.as_ref()) {
+                Some((values, remap)) => (values, offsets, Some(remap)),
+                None => (values, offsets, None),
+            }
+        };
 
         Self {
             offsets,
+            remap,
             values,
             keys_values,
             key_values: Vec::with_capacity(capacity),
@@ -97,12 +191,17 @@ impl<'a, T: DictionaryKey> Growable<'a> for GrowableDictionary<'a, T> {
 
         let values = &self.keys_values[index][start..start + len];
         let offset = self.offsets[index];
-        self.key_values.extend(
-            values
-                .iter()
-                // `.unwrap_or(0)` because this operation does not check for null values, which may contain any key.
-                .map(|x| T::from_usize(offset + x.to_usize().unwrap_or(0)).unwrap()),
-        );
+        let remap = self.remap.as_ref();
+        self.key_values.extend(values.iter().map(|x| {
+            // `.unwrap_or(0)` because this operation does not check for null values, which may contain any key.
+            let key = offset + x.to_usize().unwrap_or(0);
+            let key = match remap {
+                // null values may be out of bounds, in which case any key is valid
+                Some(remap) => remap.get(key).copied().unwrap_or(0),
+                None => key,
+            };
+            T::from_usize(key).unwrap()
+        }));
     }
 
     #[inline]
@@ -10,6 +10,8 @@ pub struct GrowableUnion<'a> {
     types: Vec<i8>,
     offsets: Option<Vec<i32>>,
     fields: Vec<Box<dyn Growable<'a> + 'a>>,
+    // the current length of each field, used to compute the offsets of a dense union
+    lengths: Vec<i32>,
 }
 
 impl<'a> GrowableUnion<'a> {
@@ -39,6 +41,7 @@ impl<'a> GrowableUnion<'a> {
 
         Self {
             arrays,
+            lengths: vec![0; fields.len()],
             fields,
             offsets: if has_offsets {
                 Some(Vec::with_capacity(capacity))
@@ -73,10 +76,13 @@ impl<'a> Growable<'a> for GrowableUnion<'a> {
         if let Some(x) = self.offsets.as_mut() {
             let offsets = &array.offsets().unwrap()[start..start + len];
 
-            x.extend(offsets);
-            // in a dense union, each slot has its own offset. We extend the fields accordingly.
+            // in a dense union, each slot has its own offset. We extend the fields accordingly
+            // and point each slot to its new position in the field.
             for (&type_, &offset) in types.iter().zip(offsets.iter()) {
-                self.fields[type_ as usize].extend(index, offset as usize, 1);
+                let field = type_ as usize;
+                self.fields[field].extend(index, offset as usize, 1);
+                x.push(self.lengths[field]);
+                self.lengths[field] += 1;
             }
         } else {
             // in a sparse union, every field has the same length => extend all fields equally
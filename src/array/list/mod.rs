@@ -8,7 +8,7 @@ use crate::{
 };
 
 use super::{
-    new_empty_array,
+    check_slice, new_empty_array,
     specification::{try_check_offsets, try_check_offsets_bounds},
     Array, Offset,
 };
@@ -196,30 +196,58 @@ impl<O: Offset> ListArray<O> {
 
 impl<O: Offset> ListArray<O> {
     /// Returns a slice of this [`ListArray`].
-    /// # Panics
-    /// panics iff `offset + length >= self.len()`
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
     pub fn slice(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced(offset, length)
+    }
+
+    /// Returns a slice of this [`ListArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced_unchecked(offset, length)
+    }
+
+    /// Returns a slice of this [`ListArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self, ArrowError> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
+    /// Slices this [`ListArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)` and, unlike [`Self::slice`], does not need to increase two ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    pub fn sliced(self, offset: usize, length: usize) -> Self {
         assert!(
             offset + length <= self.len(),
-            "the offset of the new Buffer cannot exceed the existing length"
+            "offset + length may not exceed length of array"
         );
-        unsafe { self.slice_unchecked(offset, length) }
+        unsafe { self.sliced_unchecked(offset, length) }
     }
 
-    /// Returns a slice of this [`ListArray`].
+    /// Slices this [`ListArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)` and, unlike [`Self::slice`], does not need to increase two ref counts.
     /// # Safety
-    /// The caller must ensure that `offset + length < self.len()`.
-    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
-        let validity = self
-            .validity
-            .clone()
-            .map(|x| x.slice_unchecked(offset, length));
-        let offsets = self.offsets.clone().slice_unchecked(offset, length + 1);
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn sliced_unchecked(self, offset: usize, length: usize) -> Self {
         Self {
-            data_type: self.data_type.clone(),
-            offsets,
-            values: self.values.clone(),
-            validity,
+            data_type: self.data_type,
+            // + 1: `length == 0` implies that we take the first offset.
+            offsets: self.offsets.slice_unchecked(offset, length + 1),
+            values: self.values,
+            validity: self.validity.map(|x| x.slice_unchecked(offset, length)),
         }
     }
 
@@ -227,12 +255,19 @@ impl<O: Offset> ListArray<O> {
     /// # Panic
     /// This function panics iff `validity.len() != self.len()`.
     pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+        let mut arr = self.clone();
+        arr.set_validity(validity);
+        arr
+    }
+
+    /// Sets the validity of this [`ListArray`] in place, without copying its values.
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
         if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
             panic!("validity should be as least as large as the array")
         }
-        let mut arr = self.clone();
-        arr.validity = validity;
-        arr
+        self.validity = validity;
     }
 }
 
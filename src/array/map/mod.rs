@@ -7,9 +7,10 @@ use crate::{
     error::ArrowError,
 };
 
-use super::{new_empty_array, specification::try_check_offsets, Array};
+use super::{check_slice, new_empty_array, specification::try_check_offsets, Array};
 
 mod ffi;
+pub(super) mod fmt;
 mod iterator;
 pub use iterator::*;
 
@@ -121,31 +122,78 @@ impl MapArray {
 
 impl MapArray {
     /// Returns a slice of this [`MapArray`].
-    /// # Panics
-    /// panics iff `offset + length >= self.len()`
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
     pub fn slice(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced(offset, length)
+    }
+
+    /// Returns a slice of this [`MapArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced_unchecked(offset, length)
+    }
+
+    /// Returns a slice of this [`MapArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self, ArrowError> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
+    /// Slices this [`MapArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)` and, unlike [`Self::slice`], does not need to increase two ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    pub fn sliced(self, offset: usize, length: usize) -> Self {
         assert!(
             offset + length <= self.len(),
-            "the offset of the new Buffer cannot exceed the existing length"
+            "offset + length may not exceed length of array"
         );
-        unsafe { self.slice_unchecked(offset, length) }
+        unsafe { self.sliced_unchecked(offset, length) }
     }
 
-    /// Returns a slice of this [`MapArray`].
+    /// Slices this [`MapArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)` and, unlike [`Self::slice`], does not need to increase two ref counts.
     /// # Safety
-    /// The caller must ensure that `offset + length < self.len()`.
-    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
-        let offsets = self.offsets.clone().slice_unchecked(offset, length + 1);
-        let validity = self
-            .validity
-            .clone()
-            .map(|x| x.slice_unchecked(offset, length));
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn sliced_unchecked(self, offset: usize, length: usize) -> Self {
         Self {
-            data_type: self.data_type.clone(),
-            offsets,
-            field: self.field.clone(),
-            validity,
+            data_type: self.data_type,
+            offsets: self.offsets.slice_unchecked(offset, length + 1),
+            field: self.field,
+            validity: self.validity.map(|x| x.slice_unchecked(offset, length)),
+        }
+    }
+
+    /// Sets the validity bitmap on this [`MapArray`].
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    #[must_use]
+    pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+        let mut arr = self.clone();
+        arr.set_validity(validity);
+        arr
+    }
+
+    /// Sets the validity of this [`MapArray`] in place, without copying its values.
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
+        if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
+            panic!("validity should be as least as large as the array")
         }
+        self.validity = validity;
     }
 
     pub(crate) fn try_get_field(data_type: &DataType) -> Result<&Field, ArrowError> {
@@ -238,7 +286,7 @@ impl Array for MapArray {
         Box::new(self.slice_unchecked(offset, length))
     }
 
-    fn with_validity(&self, _validity: Option<Bitmap>) -> Box<dyn Array> {
-        Box::new(self.clone())
+    fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
+        Box::new(self.with_validity(validity))
     }
 }
@@ -18,7 +18,7 @@
 //! can be operated in-place.
 use std::any::Any;
 
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::{
     bitmap::{Bitmap, MutableBitmap},
     datatypes::DataType,
@@ -99,6 +99,17 @@ pub trait Array: Send + Sync {
     /// The caller must ensure that `offset + length <= self.len()`
     unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn Array>;
 
+    /// Slices the [`Array`], returning a new `Box<dyn Array>`.
+    /// # Implementation
+    /// This operation is `O(1)` over `len`, as it amounts to increase two ref counts
+    /// and moving the struct to the heap.
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    fn try_slice(&self, offset: usize, length: usize) -> Result<Box<dyn Array>> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
     /// Sets the validity bitmap on this [`Array`].
     /// # Panic
     /// This function panics iff `validity.len() < self.len()`.
@@ -159,6 +170,18 @@ pub trait MutableArray: std::fmt::Debug + Send + Sync {
     fn shrink_to_fit(&mut self);
 }
 
+/// Errors iff `offset + length` exceeds `len` (or overflows `usize`).
+#[inline]
+pub(crate) fn check_slice(offset: usize, length: usize, len: usize) -> Result<()> {
+    match offset.checked_add(length) {
+        Some(end) if end <= len => Ok(()),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "offset ({}) + length ({}) may not exceed the length of the array ({})",
+            offset, length, len
+        ))),
+    }
+}
+
 macro_rules! general_dyn {
     ($array:expr, $ty:ty, $f:expr) => {{
         let array = $array.as_any().downcast_ref::<$ty>().unwrap();
@@ -237,7 +260,7 @@ impl std::fmt::Debug for dyn Array + '_ {
                     fmt_dyn!(self, DictionaryArray::<$T>, f)
                 })
             }
-            Map => todo!(),
+            Map => fmt_dyn!(self, MapArray, f),
         }
     }
 }
@@ -355,7 +378,7 @@ mod list;
 mod map;
 mod null;
 mod primitive;
-mod specification;
+pub(crate) mod specification;
 mod struct_;
 mod union;
 mod utf8;
@@ -372,7 +395,9 @@ pub use fmt::{get_display, get_value_display};
 pub use crate::types::Offset;
 pub use binary::{BinaryArray, BinaryValueIter, MutableBinaryArray};
 pub use boolean::{BooleanArray, MutableBooleanArray};
-pub use dictionary::{DictionaryArray, DictionaryKey, MutableDictionaryArray};
+pub use dictionary::{
+    DictValue, DictionaryArray, DictionaryKey, DictionaryValuesIterTyped, MutableDictionaryArray,
+};
 pub use fixed_size_binary::{FixedSizeBinaryArray, MutableFixedSizeBinaryArray};
 pub use fixed_size_list::{FixedSizeListArray, MutableFixedSizeListArray};
 pub use list::{ListArray, ListValuesIter, MutableListArray};
@@ -1,7 +1,7 @@
 use crate::{bitmap::Bitmap, datatypes::DataType};
 
 use crate::{
-    array::{Array, FromFfi, ToFfi},
+    array::{check_slice, Array, FromFfi, ToFfi},
     datatypes::PhysicalType,
     error::ArrowError,
     ffi,
@@ -55,9 +55,44 @@ impl NullArray {
 
 impl NullArray {
     /// Returns a slice of the [`NullArray`].
-    pub fn slice(&self, _offset: usize, length: usize) -> Self {
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced(offset, length)
+    }
+
+    /// Returns a slice of the [`NullArray`].
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced_unchecked(offset, length)
+    }
+
+    /// Returns a slice of the [`NullArray`].
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self, ArrowError> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
+    /// Slices this [`NullArray`], consuming it.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    pub fn sliced(self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+        unsafe { self.sliced_unchecked(offset, length) }
+    }
+
+    /// Slices this [`NullArray`], consuming it.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn sliced_unchecked(self, _offset: usize, length: usize) -> Self {
         Self {
-            data_type: self.data_type.clone(),
+            data_type: self.data_type,
             length,
         }
     }
@@ -93,7 +128,7 @@ impl Array for NullArray {
     }
 
     unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn Array> {
-        Box::new(self.slice(offset, length))
+        Box::new(self.slice_unchecked(offset, length))
     }
 
     fn with_validity(&self, _: Option<Bitmap>) -> Box<dyn Array> {
@@ -138,7 +138,7 @@ where
     let left_keys = left.keys().values().clone();
     let right_keys = right.keys().values().clone();
 
-    let comparator = build_compare(left.values().as_ref(), right.values().as_ref())?;
+    let comparator = build_compare_nullable(left.values().as_ref(), right.values().as_ref())?;
 
     Ok(Box::new(move |i: usize, j: usize| {
         let key_left = left_keys[i].to_usize().unwrap();
@@ -147,6 +147,94 @@ where
     }))
 }
 
+/// Returns a comparator of the values of nested arrays, where nulls are smaller than any value.
+fn build_compare_nullable(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let comparator = build_compare(left, right)?;
+    let left_validity = left.validity().cloned();
+    let right_validity = right.validity().cloned();
+    if left_validity.is_none() && right_validity.is_none() {
+        return Ok(comparator);
+    }
+    Ok(Box::new(move |i: usize, j: usize| {
+        let is_left_valid = !matches!(&left_validity, Some(x) if !x.get_bit(i));
+        let is_right_valid = !matches!(&right_validity, Some(x) if !x.get_bit(j));
+        match (is_left_valid, is_right_valid) {
+            (true, true) => (comparator)(i, j),
+            (false, true) => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (false, false) => Ordering::Equal,
+        }
+    }))
+}
+
+fn compare_struct(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<StructArray>().unwrap();
+    let right = right.as_any().downcast_ref::<StructArray>().unwrap();
+    let comparators = left
+        .values()
+        .iter()
+        .zip(right.values().iter())
+        .map(|(left, right)| build_compare_nullable(left.as_ref(), right.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Box::new(move |i: usize, j: usize| {
+        comparators
+            .iter()
+            .map(|comparator| (comparator)(i, j))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }))
+}
+
+/// Lexicographical order of the values in `left` and `right`, whose items are compared by
+/// `comparator`. A list that is a prefix of another list is smaller than it.
+#[inline]
+fn compare_ranges(
+    comparator: &DynComparator,
+    left: std::ops::Range<usize>,
+    right: std::ops::Range<usize>,
+) -> Ordering {
+    let (left_len, right_len) = (left.len(), right.len());
+    left.zip(right)
+        .map(|(i, j)| (comparator)(i, j))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or_else(|| left_len.cmp(&right_len))
+}
+
+fn compare_list<O: Offset>(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let right = right.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let comparator = build_compare_nullable(left.values().as_ref(), right.values().as_ref())?;
+    let left_offsets = left.offsets().clone();
+    let right_offsets = right.offsets().clone();
+
+    Ok(Box::new(move |i: usize, j: usize| {
+        compare_ranges(
+            &comparator,
+            left_offsets[i].to_usize()..left_offsets[i + 1].to_usize(),
+            right_offsets[j].to_usize()..right_offsets[j + 1].to_usize(),
+        )
+    }))
+}
+
+fn compare_fixed_size_list(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let size = match left.data_type().to_logical_type() {
+        DataType::FixedSizeList(_, size) => *size,
+        _ => unreachable!(),
+    };
+    let left = left.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let right = right.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let comparator = build_compare_nullable(left.values().as_ref(), right.values().as_ref())?;
+
+    Ok(Box::new(move |i: usize, j: usize| {
+        compare_ranges(
+            &comparator,
+            i * size..(i + 1) * size,
+            j * size..(j + 1) * size,
+        )
+    }))
+}
+
 macro_rules! dyn_dict {
     ($key:ty, $lhs:expr, $rhs:expr) => {{
         let lhs = $lhs.as_any().downcast_ref().unwrap();
@@ -172,6 +260,9 @@ macro_rules! dyn_dict {
 /// # Ok(())
 /// # }
 /// ```
+/// # Implementation
+/// Nulls are not considered in the slots being compared. Nested types (struct and lists) are
+/// ordered lexicographically by their children, whose nulls are smaller than any value.
 /// # Error
 /// The arrays' [`DataType`] must be equal and the types must have a natural order.
 // This is a factory of comparisons.
@@ -201,20 +292,22 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
         | (Date64, Date64)
         | (Time64(Microsecond), Time64(Microsecond))
         | (Time64(Nanosecond), Time64(Nanosecond))
-        | (Timestamp(Second, None), Timestamp(Second, None))
-        | (Timestamp(Millisecond, None), Timestamp(Millisecond, None))
-        | (Timestamp(Microsecond, None), Timestamp(Microsecond, None))
-        | (Timestamp(Nanosecond, None), Timestamp(Nanosecond, None))
+        | (Timestamp(_, _), Timestamp(_, _))
         | (Duration(Second), Duration(Second))
         | (Duration(Millisecond), Duration(Millisecond))
         | (Duration(Microsecond), Duration(Microsecond))
         | (Duration(Nanosecond), Duration(Nanosecond)) => compare_primitives::<i64>(left, right),
+        (Decimal(_, _), Decimal(_, _)) => compare_primitives::<i128>(left, right),
         (Float32, Float32) => compare_f32(left, right),
         (Float64, Float64) => compare_f64(left, right),
         (Utf8, Utf8) => compare_string::<i32>(left, right),
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
         (Binary, Binary) => compare_binary::<i32>(left, right),
         (LargeBinary, LargeBinary) => compare_binary::<i64>(left, right),
+        (Struct(_), Struct(_)) => compare_struct(left, right)?,
+        (List(_), List(_)) => compare_list::<i32>(left, right)?,
+        (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right)?,
+        (FixedSizeList(_, _), FixedSizeList(_, _)) => compare_fixed_size_list(left, right)?,
         (Dictionary(key_type_lhs, ..), Dictionary(key_type_rhs, ..)) => {
             match (key_type_lhs, key_type_rhs) {
                 (IntegerType::UInt8, IntegerType::UInt8) => dyn_dict!(u8, left, right),
@@ -1,5 +1,16 @@
 use crate::array::Offset;
 use crate::bitmap::MutableBitmap;
+use crate::error::ArrowError;
+
+/// Returns `offset` advanced by `length` bytes.
+/// # Panics
+/// Panics iff the offset does not fit in `O` (e.g. values of more than 2GB with `i32` offsets).
+#[inline]
+fn next_offset<O: Offset>(offset: O, length: usize) -> O {
+    O::from_usize(length)
+        .and_then(|length| offset.checked_add(&length))
+        .unwrap_or_else(|| panic!("{}", ArrowError::Overflow))
+}
 
 /// # Safety
 /// The caller must ensure that `iterator` is `TrustedLen`.
@@ -28,7 +39,7 @@ where
         if let Some(item) = item? {
             null.push_unchecked(true);
             let s = item.as_ref();
-            length += O::from_usize(s.len()).unwrap();
+            length = next_offset(length, s.len());
             values.extend_from_slice(s);
         } else {
             null.push_unchecked(false);
@@ -137,7 +148,7 @@ pub(crate) unsafe fn extend_from_trusted_len_values_iter<I, P, O>(
         let s = item.as_ref();
 
         // Calculate the new offset value
-        length += O::from_usize(s.len()).unwrap();
+        length = next_offset(length, s.len());
 
         // Push new entries for both `values` and `offsets` buffer
         values.extend_from_slice(s);
@@ -184,7 +195,7 @@ where
     for item in iterator {
         let s = item.as_ref();
         // Calculate the new offset value
-        length += O::from_usize(s.len()).unwrap();
+        length = next_offset(length, s.len());
 
         values.extend_from_slice(s);
         offsets.push(length);
@@ -228,7 +239,7 @@ pub(crate) unsafe fn extend_from_trusted_len_iter<O, I, P>(
             let bytes = item.as_ref();
 
             // Calculate new offset value
-            length += O::from_usize(bytes.len()).unwrap();
+            length = next_offset(length, bytes.len());
 
             // Push new values for `values` and `validity` buffer
             values.extend_from_slice(bytes);
@@ -274,7 +285,7 @@ where
 
     for item in iterator {
         let s = item.as_ref();
-        length += O::from_usize(s.len()).unwrap();
+        length = next_offset(length, s.len());
         values.extend_from_slice(s);
 
         offsets.push(length)
@@ -2,7 +2,7 @@ use std::fmt::{Debug, Formatter, Result, Write};
 
 use crate::array::Array;
 use crate::datatypes::{IntervalUnit, TimeUnit};
-use crate::types::{days_ms, months_days_ns};
+use crate::types::{days_ms, decimal, months_days_ns};
 
 use super::super::super::temporal_conversions;
 use super::super::super::types::NativeType;
@@ -106,12 +106,8 @@ pub fn get_write_value<'a, T: NativeType, F: Write>(
         Duration(TimeUnit::Nanosecond) => dyn_primitive!(array, i64, |x| format!("{}ns", x)),
         Decimal(_, scale) => {
             // The number 999.99 has a precision of 5 and scale of 2
-            let scale = *scale as u32;
-            let display = move |x| {
-                let base = x / 10i128.pow(scale);
-                let decimals = x - base * 10i128.pow(scale);
-                format!("{}.{}", base, decimals)
-            };
+            let scale = *scale;
+            let display = move |x| decimal::format_decimal(x, scale);
             dyn_primitive!(array, i128, display)
         }
         _ => unreachable!(),
@@ -37,6 +37,7 @@ impl<T: NativeType> PrimitiveArray<T> {
 
     /// Creates a (non-null) [`PrimitiveArray`] from a vector of values.
     /// This does not have memcopy and is the fastest way to create a [`PrimitiveArray`].
+    /// See [`PrimitiveArray::into_inner`] for the inverse operation.
     pub fn from_vec(array: Vec<T>) -> Self {
         Self::new(T::PRIMITIVE.into(), array.into(), None)
     }
@@ -70,4 +71,27 @@ impl<T: NativeType> PrimitiveArray<T> {
     pub unsafe fn from_trusted_len_iter_unchecked<I: Iterator<Item = Option<T>>>(iter: I) -> Self {
         MutablePrimitiveArray::<T>::from_trusted_len_iter_unchecked(iter).into()
     }
+
+    /// Creates a [`PrimitiveArray`] from a fallible [`TrustedLen`] of optional values.
+    /// # Errors
+    /// Errors with the first error of the iterator.
+    pub fn try_from_trusted_len_iter<E, I, P>(iter: I) -> Result<Self, E>
+    where
+        P: std::borrow::Borrow<T>,
+        I: TrustedLen<Item = Result<Option<P>, E>>,
+    {
+        Ok(MutablePrimitiveArray::<T>::try_from_trusted_len_iter(iter)?.into())
+    }
+
+    /// Creates a [`PrimitiveArray`] from a fallible iterator of optional values.
+    /// # Safety
+    /// The iterator must be [`TrustedLen`](https://doc.rust-lang.org/std/iter/trait.TrustedLen.html).
+    /// I.e. that `size_hint().1` correctly reports its length.
+    pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(iter: I) -> Result<Self, E>
+    where
+        P: std::borrow::Borrow<T>,
+        I: Iterator<Item = Result<Option<P>, E>>,
+    {
+        Ok(MutablePrimitiveArray::<T>::try_from_trusted_len_iter_unchecked(iter)?.into())
+    }
 }
@@ -6,7 +6,7 @@ use crate::{
     types::{days_ms, months_days_ns, NativeType},
 };
 
-use super::Array;
+use super::{check_slice, Array};
 use either::Either;
 
 mod ffi;
@@ -106,33 +106,62 @@ impl<T: NativeType> PrimitiveArray<T> {
     /// # Implementation
     /// This operation is `O(1)` as it amounts to increase two ref counts.
     /// # Panic
-    /// This function panics iff `offset + length >= self.len()`.
+    /// This function panics iff `offset + length > self.len()`.
     #[inline]
     #[must_use]
     pub fn slice(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced(offset, length)
+    }
+
+    /// Returns a slice of this [`PrimitiveArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[inline]
+    #[must_use]
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced_unchecked(offset, length)
+    }
+
+    /// Returns a slice of this [`PrimitiveArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    #[inline]
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self, ArrowError> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
+    /// Slices this [`PrimitiveArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)` and does not change any ref count.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[inline]
+    #[must_use]
+    pub fn sliced(self, offset: usize, length: usize) -> Self {
         assert!(
             offset + length <= self.len(),
             "offset + length may not exceed length of array"
         );
-        unsafe { self.slice_unchecked(offset, length) }
+        unsafe { self.sliced_unchecked(offset, length) }
     }
 
-    /// Returns a slice of this [`PrimitiveArray`].
+    /// Slices this [`PrimitiveArray`], consuming it.
     /// # Implementation
-    /// This operation is `O(1)` as it amounts to increase two ref counts.
+    /// This operation is `O(1)` and does not change any ref count.
     /// # Safety
     /// The caller must ensure that `offset + length <= self.len()`.
     #[inline]
     #[must_use]
-    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
-        let validity = self
-            .validity
-            .clone()
-            .map(|x| x.slice_unchecked(offset, length));
+    pub unsafe fn sliced_unchecked(self, offset: usize, length: usize) -> Self {
         Self {
-            data_type: self.data_type.clone(),
-            values: self.values.clone().slice_unchecked(offset, length),
-            validity,
+            data_type: self.data_type,
+            values: self.values.slice_unchecked(offset, length),
+            validity: self.validity.map(|x| x.slice_unchecked(offset, length)),
         }
     }
 
@@ -141,12 +170,19 @@ impl<T: NativeType> PrimitiveArray<T> {
     /// This function panics iff `validity.len() != self.len()`.
     #[must_use]
     pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+        let mut arr = self.clone();
+        arr.set_validity(validity);
+        arr
+    }
+
+    /// Sets the validity of this [`PrimitiveArray`] in place, without copying its values.
+    /// # Panics
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
         if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
             panic!("validity should be as least as large as the array")
         }
-        let mut arr = self.clone();
-        arr.validity = validity;
-        arr
+        self.validity = validity;
     }
 }
 
@@ -212,6 +248,30 @@ impl<T: NativeType> PrimitiveArray<T> {
             validity: self.validity,
         }
     }
+
+    /// Deconstructs this [`PrimitiveArray`] into its data type, values and validity.
+    ///
+    /// Together with [`Buffer::into_vec`], this allows moving the values out of arrow2 without
+    /// copying them.
+    /// # Example
+    /// ```
+    /// use arrow2::array::PrimitiveArray;
+    ///
+    /// let array = PrimitiveArray::<i32>::from_vec(vec![1, 2, 3]);
+    /// let (_, values, validity) = array.into_inner();
+    /// assert_eq!(values.into_vec(), Ok(vec![1, 2, 3]));
+    /// assert_eq!(validity, None);
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> (DataType, Buffer<T>, Option<Bitmap>) {
+        let Self {
+            data_type,
+            values,
+            validity,
+        } = self;
+        (data_type, values, validity)
+    }
+
     /// Try to convert this `PrimitiveArray` to a `MutablePrimitiveArray`
     pub fn into_mut(self) -> Either<Self, MutablePrimitiveArray<T>> {
         use Either::*;
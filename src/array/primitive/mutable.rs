@@ -2,7 +2,7 @@ use std::{iter::FromIterator, sync::Arc};
 
 use crate::{
     array::{Array, MutableArray, TryExtend, TryPush},
-    bitmap::MutableBitmap,
+    bitmap::{Bitmap, MutableBitmap},
     datatypes::DataType,
     error::{ArrowError, Result},
     trusted_len::TrustedLen,
@@ -219,6 +219,33 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
         self.update_all_valid();
     }
 
+    /// Extends the [`MutablePrimitiveArray`] from a slice of values and their validity,
+    /// where `None` means that all values are valid.
+    /// # Panic
+    /// This function panics iff `validity` is `Some` and its length differs from `items`'s.
+    pub fn extend_from_slice_validity(&mut self, items: &[T], validity: Option<&Bitmap>) {
+        match validity {
+            Some(bitmap) if bitmap.null_count() > 0 => {
+                assert_eq!(items.len(), bitmap.len());
+                let len = self.len();
+                let mut validity = self.validity.take().unwrap_or_else(|| {
+                    let mut validity = MutableBitmap::with_capacity(len + items.len());
+                    validity.extend_constant(len, true);
+                    validity
+                });
+                validity.extend_from_bitmap(bitmap);
+                self.values.extend_from_slice(items);
+                self.validity = Some(validity);
+            }
+            _ => {
+                if let Some(bitmap) = validity {
+                    assert_eq!(items.len(), bitmap.len());
+                }
+                self.extend_from_slice(items)
+            }
+        }
+    }
+
     fn update_all_valid(&mut self) {
         // get len before mutable borrow
         let len = self.len();
@@ -8,7 +8,7 @@ use crate::{
     scalar::{new_scalar, Scalar},
 };
 
-use super::{new_empty_array, new_null_array, Array};
+use super::{check_slice, new_empty_array, new_null_array, Array};
 
 mod ffi;
 pub(super) mod fmt;
@@ -183,20 +183,9 @@ impl UnionArray {
     /// # Implementation
     /// This operation is `O(F)` where `F` is the number of fields.
     /// # Panic
-    /// This function panics iff `offset + length >= self.len()`.
-    #[inline]
+    /// This function panics iff `offset + length > self.len()`.
     pub fn slice(&self, offset: usize, length: usize) -> Self {
-        Self {
-            data_type: self.data_type.clone(),
-            fields: self.fields.clone(),
-            fields_hash: self.fields_hash.clone(),
-            types: self.types.clone().slice(offset, length),
-            offsets: self
-                .offsets
-                .clone()
-                .map(|offsets| offsets.slice(offset, length)),
-            offset: self.offset + offset,
-        }
+        self.clone().sliced(offset, length)
     }
 
     /// Returns a slice of this [`UnionArray`].
@@ -204,16 +193,46 @@ impl UnionArray {
     /// This operation is `O(F)` where `F` is the number of fields.
     /// # Safety
     /// The caller must ensure that `offset + length <= self.len()`.
-    #[inline]
     pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced_unchecked(offset, length)
+    }
+
+    /// Returns a slice of this [`UnionArray`].
+    /// # Implementation
+    /// This operation is `O(F)` where `F` is the number of fields.
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self, ArrowError> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
+    /// Slices this [`UnionArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)`.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    pub fn sliced(self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+        unsafe { self.sliced_unchecked(offset, length) }
+    }
+
+    /// Slices this [`UnionArray`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)`.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn sliced_unchecked(self, offset: usize, length: usize) -> Self {
         Self {
-            data_type: self.data_type.clone(),
-            fields: self.fields.clone(),
-            fields_hash: self.fields_hash.clone(),
-            types: self.types.clone().slice_unchecked(offset, length),
+            data_type: self.data_type,
+            fields: self.fields,
+            fields_hash: self.fields_hash,
+            types: self.types.slice_unchecked(offset, length),
             offsets: self
                 .offsets
-                .clone()
                 .map(|offsets| offsets.slice_unchecked(offset, length)),
             offset: self.offset + offset,
         }
@@ -75,6 +75,22 @@ impl<'a, O: Offset> Utf8Array<O> {
     pub fn values_iter(&'a self) -> Utf8ValuesIter<'a, O> {
         Utf8ValuesIter::new(self)
     }
+
+    /// Returns an iterator of the `&str` of the slots `[offset, offset + length)`,
+    /// without bound checks.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn values_iter_unchecked(
+        &'a self,
+        offset: usize,
+        length: usize,
+    ) -> Utf8ValuesIter<'a, O> {
+        Utf8ValuesIter {
+            array: self,
+            index: offset,
+            end: offset + length,
+        }
+    }
 }
 
 unsafe impl<O: Offset> TrustedLen for Utf8ValuesIter<'_, O> {}
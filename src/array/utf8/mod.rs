@@ -7,6 +7,7 @@ use crate::{
 use either::Either;
 
 use super::{
+    check_slice,
     specification::{try_check_offsets_and_utf8, try_check_offsets_bounds},
     Array, GenericBinaryArray, Offset,
 };
@@ -239,33 +240,59 @@ impl<O: Offset> Utf8Array<O> {
     /// # Implementation
     /// This operation is `O(1)` as it amounts to essentially increase two ref counts.
     /// # Panic
-    /// This function panics iff `offset + length >= self.len()`.
+    /// This function panics iff `offset + length > self.len()`.
     #[must_use]
     pub fn slice(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced(offset, length)
+    }
+
+    /// Returns a slice of this [`Utf8Array`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to essentially increase two ref counts.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[must_use]
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        self.clone().sliced_unchecked(offset, length)
+    }
+
+    /// Returns a slice of this [`Utf8Array`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to essentially increase two ref counts.
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
+    /// Slices this [`Utf8Array`], consuming it.
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to essentially increase two ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[must_use]
+    pub fn sliced(self, offset: usize, length: usize) -> Self {
         assert!(
             offset + length <= self.len(),
-            "the offset of the new Buffer cannot exceed the existing length"
+            "offset + length may not exceed length of array"
         );
-        unsafe { self.slice_unchecked(offset, length) }
+        unsafe { self.sliced_unchecked(offset, length) }
     }
-    /// Returns a slice of this [`Utf8Array`].
+
+    /// Slices this [`Utf8Array`], consuming it.
     /// # Implementation
     /// This operation is `O(1)` as it amounts to essentially increase two ref counts.
     /// # Safety
     /// The caller must ensure that `offset + length <= self.len()`.
     #[must_use]
-    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
-        let validity = self
-            .validity
-            .clone()
-            .map(|x| x.slice_unchecked(offset, length));
-        // + 1: `length == 0` implies that we take the first offset.
-        let offsets = self.offsets.clone().slice_unchecked(offset, length + 1);
+    pub unsafe fn sliced_unchecked(self, offset: usize, length: usize) -> Self {
         Self {
-            data_type: self.data_type.clone(),
-            offsets,
-            values: self.values.clone(),
-            validity,
+            data_type: self.data_type,
+            // + 1: `length == 0` implies that we take the first offset.
+            offsets: self.offsets.slice_unchecked(offset, length + 1),
+            values: self.values,
+            validity: self.validity.map(|x| x.slice_unchecked(offset, length)),
         }
     }
 
@@ -273,12 +300,19 @@ impl<O: Offset> Utf8Array<O> {
     /// # Panic
     /// This function panics iff `validity.len() != self.len()`.
     pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+        let mut arr = self.clone();
+        arr.set_validity(validity);
+        arr
+    }
+
+    /// Sets the validity of this [`Utf8Array`] in place, without copying its values.
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
         if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
             panic!("validity should be as least as large as the array")
         }
-        let mut arr = self.clone();
-        arr.validity = validity;
-        arr
+        self.validity = validity;
     }
 
     /// Try to convert this `Utf8Array` to a `MutableUtf8Array`
@@ -329,7 +363,9 @@ impl<O: Offset> Utf8Array<O> {
                             )
                         })
                     }
-                    (Right(mutable_values), Right(mutable_offsets)) => {
+                    (Right(mut mutable_values), Right(mutable_offsets)) => {
+                        // the offsets may be a slice of the first items of the original array
+                        mutable_values.truncate(mutable_offsets.last().unwrap().to_usize());
                         Right(MutableUtf8Array::from_data(
                             self.data_type,
                             mutable_offsets,
@@ -365,7 +401,8 @@ impl<O: Offset> Utf8Array<O> {
                         None,
                     )
                 }),
-                (Right(mutable_values), Right(mutable_offsets)) => {
+                (Right(mut mutable_values), Right(mutable_offsets)) => {
+                    mutable_values.truncate(mutable_offsets.last().unwrap().to_usize());
                     Right(MutableUtf8Array::from_data(
                         self.data_type,
                         mutable_offsets,
@@ -430,6 +467,17 @@ impl<O: Offset> Utf8Array<O> {
     pub fn values(&self) -> &Buffer<u8> {
         &self.values
     }
+
+    /// Returns whether every value of this [`Utf8Array`] is ASCII, in which case each
+    /// character of a value is a single byte.
+    /// # Implementation
+    /// This function is `O(N)` where `N` is the number of bytes of the values in this array,
+    /// including the values of null slots.
+    pub fn is_ascii(&self) -> bool {
+        let start = self.offsets[0].to_usize();
+        let end = self.offsets[self.offsets.len() - 1].to_usize();
+        self.values[start..end].is_ascii()
+    }
 }
 
 impl<O: Offset> Array for Utf8Array<O> {
@@ -5,7 +5,7 @@ use crate::{
         specification::{check_offsets_and_utf8, check_offsets_minimal},
         Array, MutableArray, Offset, TryExtend, TryPush,
     },
-    bitmap::MutableBitmap,
+    bitmap::{Bitmap, MutableBitmap},
     datatypes::DataType,
     error::{ArrowError, Result},
     trusted_len::TrustedLen,
@@ -124,6 +124,11 @@ impl<O: Offset> MutableUtf8Array<O> {
         Utf8Array::<O>::default_data_type()
     }
 
+    /// Extract the low-end APIs from the [`MutableUtf8Array`].
+    pub fn into_data(self) -> (DataType, Vec<O>, Vec<u8>, Option<MutableBitmap>) {
+        (self.data_type, self.offsets, self.values, self.validity)
+    }
+
     /// Initializes a new [`MutableUtf8Array`] with a pre-allocated capacity of slots.
     pub fn with_capacity(capacity: usize) -> Self {
         Self::with_capacities(capacity, 0)
@@ -318,6 +323,77 @@ impl<O: Offset> MutableUtf8Array<O> {
         }
     }
 
+    /// Extends the [`MutableUtf8Array`] with `additional` copies of `value`.
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
+    pub fn extend_constant<T: AsRef<str>>(&mut self, additional: usize, value: Option<T>) {
+        match value {
+            Some(value) => {
+                let value = value.as_ref().as_bytes();
+                let total = value
+                    .len()
+                    .checked_mul(additional)
+                    .and_then(|x| x.checked_add(self.values.len()));
+                if total.and_then(O::from_usize).is_none() {
+                    panic!("{}", ArrowError::Overflow)
+                }
+                // it fits since the total length fits
+                let size = O::from_usize(value.len()).unwrap();
+                self.offsets.reserve(additional);
+                self.values.reserve(value.len() * additional);
+                let mut offset = self.last_offset();
+                for _ in 0..additional {
+                    self.values.extend_from_slice(value);
+                    offset += size;
+                    self.offsets.push(offset);
+                }
+                if let Some(validity) = &mut self.validity {
+                    validity.extend_constant(additional, true)
+                }
+            }
+            None => {
+                let offset = self.last_offset();
+                self.offsets.resize(self.offsets.len() + additional, offset);
+                let len = self.len();
+                let validity = self.validity.get_or_insert_with(|| {
+                    let mut validity = MutableBitmap::with_capacity(len);
+                    validity.extend_constant(len - additional, true);
+                    validity
+                });
+                validity.extend_constant(additional, false);
+            }
+        }
+    }
+
+    /// Extends the [`MutableUtf8Array`] from a slice of values and their validity,
+    /// where `None` means that all values are valid.
+    /// # Panic
+    /// This function panics iff `validity` is `Some` and its length differs from `values`'s,
+    /// or the length of all values (in bytes) exceeds `O` maximum value.
+    pub fn extend_from_slice_validity<P: AsRef<str>>(
+        &mut self,
+        values: &[P],
+        validity: Option<&Bitmap>,
+    ) {
+        if let Some(bitmap) = validity {
+            assert_eq!(values.len(), bitmap.len());
+        }
+        match validity {
+            Some(bitmap) if bitmap.null_count() > 0 => {
+                let len = self.len();
+                let mut validity = self.validity.take().unwrap_or_else(|| {
+                    let mut validity = MutableBitmap::with_capacity(len + values.len());
+                    validity.extend_constant(len, true);
+                    validity
+                });
+                validity.extend_from_bitmap(bitmap);
+                self.extend_trusted_len_values(values.iter());
+                self.validity = Some(validity);
+            }
+            _ => self.extend_trusted_len_values(values.iter()),
+        }
+    }
+
     /// Extends the [`MutableUtf8Array`] from an iterator of trusted len.
     #[inline]
     pub fn extend_trusted_len<I, P>(&mut self, iterator: I)
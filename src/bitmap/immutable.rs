@@ -5,7 +5,10 @@ use std::sync::Arc;
 use crate::{buffer::bytes::Bytes, trusted_len::TrustedLen};
 
 use super::{
-    utils::{count_zeros, fmt, get_bit, get_bit_unchecked, BitChunk, BitChunks, BitmapIter},
+    utils::{
+        count_zeros, fmt, get_bit, get_bit_unchecked, BitChunk, BitChunks, BitmapIter, TrueIndex,
+        TrueIndicesIter,
+    },
     MutableBitmap,
 };
 
@@ -219,6 +222,21 @@ impl Bitmap {
     pub fn chunks<T: BitChunk>(&self) -> BitChunks<T> {
         BitChunks::new(&self.bytes, self.offset, self.length)
     }
+
+    /// Returns an iterator over the positions of the set bits of this [`Bitmap`].
+    /// # Example
+    /// ```
+    /// use arrow2::bitmap::Bitmap;
+    ///
+    /// let bitmap = Bitmap::from([false, true, false, true]);
+    /// let indices = bitmap.set_bits_indices::<u32>().collect::<Vec<_>>();
+    /// assert_eq!(indices, vec![1, 3]);
+    /// ```
+    /// # Panics
+    /// Panics iff the length of this [`Bitmap`] is larger than [`TrueIndex::MAX_LENGTH`].
+    pub fn set_bits_indices<I: TrueIndex>(&self) -> TrueIndicesIter<'_, I> {
+        TrueIndicesIter::new(self, None)
+    }
 }
 
 impl Bitmap {
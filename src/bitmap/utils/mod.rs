@@ -3,6 +3,7 @@ mod chunk_iterator;
 mod fmt;
 mod iterator;
 mod slice_iterator;
+mod true_indices;
 mod zip_validity;
 
 use std::convert::TryInto;
@@ -12,6 +13,7 @@ pub use chunk_iterator::{BitChunk, BitChunkIterExact, BitChunks, BitChunksExact}
 pub use fmt::fmt;
 pub use iterator::BitmapIter;
 pub use slice_iterator::SlicesIterator;
+pub use true_indices::{TrueIndex, TrueIndicesIter};
 pub use zip_validity::{zip_validity, ZipValidity};
 
 const BIT_MASK: [u8; 8] = [1, 2, 4, 8, 16, 32, 64, 128];
@@ -137,3 +139,35 @@ pub fn count_zeros(slice: &[u8], offset: usize, len: usize) -> usize {
 
     len - set_count
 }
+
+/// Extends `buffer` with the `len` bits of `slice` starting at bit `offset`, realigned so that
+/// the first bit is the least significant bit of the first written byte. The bits of the last
+/// written byte beyond `len` are unset.
+///
+/// Contrary to collecting [`BitmapIter`] into a new bitmap, this shifts whole words.
+/// # Panics
+/// This function panics iff `offset + len > slice.len() * 8`.
+pub fn extend_aligned(buffer: &mut Vec<u8>, slice: &[u8], offset: usize, len: usize) {
+    let bytes_len = bytes_for(len);
+    buffer.reserve(bytes_len);
+
+    let bit_offset = offset % 8;
+    if bit_offset == 0 {
+        let start = offset / 8;
+        buffer.extend_from_slice(&slice[start..start + bytes_len]);
+    } else {
+        let mut chunks = BitChunks::<u64>::new(slice, offset, len);
+        let remainder = chunks.remainder();
+        chunks
+            .by_ref()
+            .for_each(|chunk| buffer.extend_from_slice(&chunk.to_ne_bytes()));
+        let remainder_len = bytes_len - len / 64 * 8;
+        buffer.extend_from_slice(&remainder.to_ne_bytes()[..remainder_len]);
+    }
+
+    let end_offset = len % 8;
+    if end_offset != 0 {
+        let last = buffer.last_mut().unwrap();
+        *last &= (1 << end_offset) - 1;
+    }
+}
@@ -0,0 +1,148 @@
+use std::marker::PhantomData;
+
+use crate::bitmap::Bitmap;
+use crate::trusted_len::TrustedLen;
+
+use super::BitChunks;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for usize {}
+    impl Sealed for u32 {}
+}
+
+/// The type of the indices returned by [`TrueIndicesIter`], implemented for `usize` and `u32`.
+pub trait TrueIndex: private::Sealed + Copy {
+    /// The maximum length of a [`Bitmap`] whose indices are representable by this type.
+    const MAX_LENGTH: usize;
+
+    /// Converts `index` to this type. `index` must be smaller than [`TrueIndex::MAX_LENGTH`].
+    fn from_usize(index: usize) -> Self;
+}
+
+impl TrueIndex for usize {
+    const MAX_LENGTH: usize = usize::MAX;
+
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        index
+    }
+}
+
+impl TrueIndex for u32 {
+    const MAX_LENGTH: usize = u32::MAX as usize;
+
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        index as u32
+    }
+}
+
+/// The 64-bit words of a [`Bitmap`], whose last word contains its remainder
+type Words<'a> = std::iter::Chain<BitChunks<'a, u64>, std::option::IntoIter<u64>>;
+
+fn words(bitmap: &Bitmap) -> Words<'_> {
+    let chunks = bitmap.chunks::<u64>();
+    let remainder_len = chunks.remainder_len();
+    // bits past the bitmap's length are not guaranteed to be unset
+    let remainder = (remainder_len > 0).then(|| chunks.remainder() & ((1 << remainder_len) - 1));
+    chunks.chain(remainder)
+}
+
+/// Iterator over the positions of the set bits of a [`Bitmap`], optionally restricted to
+/// the set bits of a validity [`Bitmap`] (e.g. the positions of the `true` values of a
+/// [`BooleanArray`](crate::array::BooleanArray)).
+///
+/// This is the most efficient method to convert a filter to indices: bits are read 64 at a
+/// time and only the set bits are visited, via `trailing_zeros`.
+/// # Example
+/// ```
+/// use arrow2::bitmap::Bitmap;
+/// use arrow2::bitmap::utils::TrueIndicesIter;
+///
+/// let values = Bitmap::from([true, false, true, true]);
+/// let validity = Bitmap::from([true, true, true, false]);
+/// let indices = TrueIndicesIter::<u32>::new(&values, Some(&validity)).collect::<Vec<_>>();
+/// assert_eq!(indices, vec![0, 2]);
+/// ```
+#[derive(Debug)]
+pub struct TrueIndicesIter<'a, I: TrueIndex = usize> {
+    values: Words<'a>,
+    validity: Option<Words<'a>>,
+    // the word being iterated, whose visited bits are unset
+    current: u64,
+    // the index of the bit past the last bit of `current`
+    end: usize,
+    remaining: usize,
+    phantom: PhantomData<I>,
+}
+
+impl<'a, I: TrueIndex> TrueIndicesIter<'a, I> {
+    /// Creates a new [`TrueIndicesIter`] over the bits set in both `values` and `validity`.
+    /// # Panics
+    /// Panics iff `values` and `validity` have different lengths or `values`'s length
+    /// is larger than [`TrueIndex::MAX_LENGTH`].
+    pub fn new(values: &'a Bitmap, validity: Option<&'a Bitmap>) -> Self {
+        assert!(
+            values.len() <= I::MAX_LENGTH,
+            "The length of the bitmap must fit in the index type"
+        );
+        let remaining = match validity {
+            Some(validity) => {
+                assert_eq!(
+                    values.len(),
+                    validity.len(),
+                    "The values and the validity must have the same length"
+                );
+                words(values)
+                    .zip(words(validity))
+                    .map(|(values, validity)| (values & validity).count_ones() as usize)
+                    .sum()
+            }
+            None => values.len() - values.null_count(),
+        };
+
+        Self {
+            values: words(values),
+            validity: validity.map(words),
+            current: 0,
+            end: 0,
+            remaining,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, I: TrueIndex> Iterator for TrueIndicesIter<'a, I> {
+    type Item = I;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        while self.current == 0 {
+            // `remaining > 0` guarantees that there are more words
+            let values = self.values.next().unwrap();
+            self.current = match &mut self.validity {
+                Some(validity) => values & validity.next().unwrap(),
+                None => values,
+            };
+            self.end += 64;
+        }
+        let index = self.end - 64 + self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        self.remaining -= 1;
+        Some(I::from_usize(index))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+// # Safety
+// a mathematical invariant of this iterator
+unsafe impl<I: TrueIndex> TrustedLen for TrueIndicesIter<'_, I> {}
@@ -144,13 +144,35 @@ impl<T: NativeType> Buffer<T> {
         } else {
             match Arc::get_mut(&mut self.data).and_then(|b| b.get_vec()) {
                 Some(v) => {
-                    let data = std::mem::take(v);
+                    let mut data = std::mem::take(v);
+                    // this buffer may be a slice of the first `length` items
+                    data.truncate(self.length);
                     Either::Right(data)
                 }
                 None => Either::Left(self),
             }
         }
     }
+
+    /// Converts this [`Buffer`] to [`Vec`] without copying, returning itself if the conversion
+    /// is not possible (see [`Buffer::into_mut`] for when it is).
+    /// # Example
+    /// ```
+    /// use arrow2::buffer::Buffer;
+    ///
+    /// let buffer: Buffer<u32> = vec![1, 2, 3].into();
+    /// assert_eq!(buffer.into_vec(), Ok(vec![1, 2, 3]));
+    ///
+    /// let buffer: Buffer<u32> = vec![1, 2, 3].into();
+    /// let shared = buffer.clone();
+    /// assert_eq!(buffer.into_vec(), Err(shared));
+    /// ```
+    pub fn into_vec(self) -> Result<Vec<T>, Self> {
+        match self.into_mut() {
+            Either::Left(buffer) => Err(buffer),
+            Either::Right(vec) => Ok(vec),
+        }
+    }
 }
 
 impl<T: NativeType> Buffer<T> {
@@ -1,12 +1,12 @@
 //! Contains [`Chunk`], a container of [`Array`] where every array has the
 //! same length.
 
-use crate::array::Array;
+use crate::array::{check_slice, growable::make_growable, Array};
 use crate::error::{ArrowError, Result};
 
 /// A vector of trait objects of [`Array`] where every item has
 /// the same length, [`Chunk::len`].
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Clone, PartialEq)]
 pub struct Chunk<A: AsRef<dyn Array>> {
     arrays: Vec<A>,
 }
@@ -25,14 +25,15 @@ impl<A: AsRef<dyn Array>> Chunk<A> {
     pub fn try_new(arrays: Vec<A>) -> Result<Self> {
         if !arrays.is_empty() {
             let len = arrays.first().unwrap().as_ref().len();
-            if arrays
+            if let Some(array) = arrays
                 .iter()
                 .map(|array| array.as_ref())
-                .any(|array| array.len() != len)
+                .find(|array| array.len() != len)
             {
-                return Err(ArrowError::InvalidArgumentError(
-                    "Chunk require all its arrays to have an equal number of rows".to_string(),
-                ));
+                return Err(ArrowError::LengthMismatch {
+                    expected: len,
+                    found: array.len(),
+                });
             }
         }
         Ok(Self { arrays })
@@ -61,6 +62,74 @@ impl<A: AsRef<dyn Array>> Chunk<A> {
         self.len() == 0
     }
 
+    /// Returns a new [`Chunk`] whose arrays are sliced.
+    /// # Implementation
+    /// This operation is `O(C)` where `C` is the number of arrays.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    pub fn slice(&self, offset: usize, length: usize) -> Chunk<Box<dyn Array>> {
+        self.try_slice(offset, length).unwrap()
+    }
+
+    /// Returns a new [`Chunk`] whose arrays are sliced.
+    /// # Implementation
+    /// This operation is `O(C)` where `C` is the number of arrays.
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Chunk<Box<dyn Array>>> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
+    /// Returns a new [`Chunk`] whose arrays are sliced.
+    /// # Implementation
+    /// This operation is `O(C)` where `C` is the number of arrays.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Chunk<Box<dyn Array>> {
+        let arrays = self
+            .arrays
+            .iter()
+            .map(|array| array.as_ref().slice_unchecked(offset, length))
+            .collect();
+        Chunk { arrays }
+    }
+
+    /// Slices this [`Chunk`], consuming it. Contrarily to [`Chunk::slice`], the
+    /// original arrays are released as soon as they are sliced.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    pub fn sliced(self, offset: usize, length: usize) -> Chunk<Box<dyn Array>> {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of chunk"
+        );
+        let arrays = self
+            .arrays
+            .into_iter()
+            .map(|array| unsafe { array.as_ref().slice_unchecked(offset, length) })
+            .collect();
+        Chunk { arrays }
+    }
+
+    /// Splits this [`Chunk`] into [`Chunk`]s of `n_rows` rows each (the last one may have
+    /// fewer rows).
+    /// # Implementation
+    /// This operation is zero-copy: every returned [`Chunk`] is a slice of this one.
+    /// # Panic
+    /// This function panics iff `n_rows == 0`.
+    pub fn split(&self, n_rows: usize) -> Vec<Chunk<Box<dyn Array>>> {
+        assert!(
+            n_rows > 0,
+            "a chunk can only be split in chunks of at least 1 row"
+        );
+        let len = self.len();
+        (0..len)
+            .step_by(n_rows)
+            .map(|offset| unsafe { self.slice_unchecked(offset, n_rows.min(len - offset)) })
+            .collect()
+    }
+
     /// Consumes [`Chunk`] into its underlying arrays.
     /// The arrays are guaranteed to have the same length
     pub fn into_arrays(self) -> Vec<A> {
@@ -68,6 +137,98 @@ impl<A: AsRef<dyn Array>> Chunk<A> {
     }
 }
 
+impl<A: AsRef<dyn Array>> std::fmt::Debug for Chunk<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arrays = self
+            .arrays
+            .iter()
+            .map(|array| array.as_ref())
+            .collect::<Vec<_>>();
+        f.debug_struct("Chunk")
+            .field("len", &self.len())
+            .field("arrays", &arrays)
+            .finish()
+    }
+}
+
+/// Rechunks `chunks` into [`Chunk`]s of `target_rows` rows each (the last one may have
+/// fewer rows).
+/// # Implementation
+/// Chunks larger than `target_rows` are split without copying their data, while consecutive
+/// chunks smaller than `target_rows` are merged, which copies their data.
+/// # Errors
+/// This function errors iff `target_rows == 0` or the chunks do not have the same number of
+/// arrays and [`DataType`](crate::datatypes::DataType)s.
+pub fn rechunk<A: AsRef<dyn Array>>(
+    chunks: &[Chunk<A>],
+    target_rows: usize,
+) -> Result<Vec<Chunk<Box<dyn Array>>>> {
+    if target_rows == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "rechunk requires a target number of rows larger than zero".to_string(),
+        ));
+    }
+    if let Some(first) = chunks.first() {
+        let data_types = first.iter().map(|array| array.as_ref().data_type());
+        if chunks.iter().any(|chunk| {
+            chunk.arrays().len() != first.arrays().len()
+                || chunk
+                    .iter()
+                    .map(|array| array.as_ref().data_type())
+                    .zip(data_types.clone())
+                    .any(|(lhs, rhs)| lhs != rhs)
+        }) {
+            return Err(ArrowError::InvalidArgumentError(
+                "rechunk requires all chunks to have the same data types".to_string(),
+            ));
+        }
+    }
+
+    let mut result = vec![];
+    let mut pending = vec![];
+    let mut pending_rows = 0;
+    for chunk in chunks {
+        let mut offset = 0;
+        while offset < chunk.len() {
+            let length = (target_rows - pending_rows).min(chunk.len() - offset);
+            pending.push(unsafe { chunk.slice_unchecked(offset, length) });
+            pending_rows += length;
+            offset += length;
+            if pending_rows == target_rows {
+                result.push(merge(std::mem::take(&mut pending)));
+                pending_rows = 0;
+            }
+        }
+    }
+    if !pending.is_empty() {
+        result.push(merge(pending));
+    }
+    Ok(result)
+}
+
+/// Merges `chunks` into a single [`Chunk`], copying data only when there is more than one.
+/// The chunks must be non-empty and have the same data types.
+pub(crate) fn merge(mut chunks: Vec<Chunk<Box<dyn Array>>>) -> Chunk<Box<dyn Array>> {
+    if chunks.len() == 1 {
+        return chunks.pop().unwrap();
+    }
+    let length = chunks.iter().map(|chunk| chunk.len()).sum();
+    let arrays = (0..chunks[0].arrays().len())
+        .map(|column| {
+            let arrays = chunks
+                .iter()
+                .map(|chunk| chunk.arrays()[column].as_ref())
+                .collect::<Vec<_>>();
+            let mut growable = make_growable(&arrays, false, length);
+            for (index, array) in arrays.iter().enumerate() {
+                growable.extend(index, 0, array.len());
+            }
+            growable.as_box()
+        })
+        .collect();
+    Chunk { arrays }
+}
+
 impl<A: AsRef<dyn Array>> From<Chunk<A>> for Vec<A> {
     fn from(c: Chunk<A>) -> Self {
         c.into_arrays()
@@ -0,0 +1,193 @@
+//! Aggregations of [`DataType::Decimal`] arrays that detect overflow.
+//!
+//! The minimum and maximum of decimal arrays are computed by [`min_primitive`](super::min_primitive)
+//! and [`max_primitive`](super::max_primitive), since they can't overflow.
+use crate::array::PrimitiveArray;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::scalar::PrimitiveScalar;
+
+/// The largest precision of [`DataType::Decimal`], i.e. the number of digits that fit in an `i128`.
+pub const MAX_DECIMAL_PRECISION: usize = 38;
+
+/// What [`sum_decimal`] does when the sum does not fit in the precision of the array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalOverflow {
+    /// Error with [`ArrowError::Overflow`]
+    Error,
+    /// Widen the precision of the result up to [`MAX_DECIMAL_PRECISION`]. Sums that do not fit
+    /// in it still error, since this crate does not support 256-bit decimals.
+    Widen,
+}
+
+fn precision_scale(data_type: &DataType) -> Result<(usize, usize)> {
+    if let DataType::Decimal(precision, scale) = data_type.to_logical_type() {
+        Ok((*precision, *scale))
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "Decimal aggregations do not support type {:?}",
+            data_type
+        )))
+    }
+}
+
+/// The largest value of `precision` digits, or [`i128::MAX`] for precisions that exceed an `i128`.
+#[inline]
+fn max_value(precision: usize) -> i128 {
+    u32::try_from(precision)
+        .ok()
+        .and_then(|precision| 10i128.checked_pow(precision))
+        .map_or(i128::MAX, |x| x - 1)
+}
+
+fn number_digits(num: i128) -> usize {
+    let mut num = num.unsigned_abs();
+    let mut digits = 0;
+    while num != 0 {
+        num /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+/// Returns the sum of the valid values of `array` and the number of values summed. The sum is
+/// accumulated in 128 bits plus a carry, so that partial sums may exceed an `i128` as long as
+/// the total does not.
+fn checked_sum(array: &PrimitiveArray<i128>) -> Result<(i128, usize)> {
+    let mut sum = 0i128;
+    let mut carry = 0i64;
+    let mut count = 0;
+    let mut add = |x: i128| {
+        let (new, overflowed) = sum.overflowing_add(x);
+        if overflowed {
+            carry += if x > 0 { 1 } else { -1 };
+        }
+        sum = new;
+        count += 1;
+    };
+    match array.validity() {
+        Some(validity) if validity.null_count() > 0 => array
+            .values()
+            .iter()
+            .zip(validity.iter())
+            .filter(|(_, is_valid)| *is_valid)
+            .for_each(|(x, _)| add(*x)),
+        _ => array.values().iter().for_each(|x| add(*x)),
+    }
+    if carry != 0 {
+        return Err(ArrowError::Overflow);
+    }
+    Ok((sum, count))
+}
+
+/// Returns the sum of the values of a [`DataType::Decimal`] array as a scalar with its scale.
+///
+/// The precision of the result is the precision of `array`, or, with [`DecimalOverflow::Widen`],
+/// the smallest precision that fits the sum. The scalar is null when all values are null.
+/// # Errors
+/// Errors iff `array` is not a decimal array or the sum does not fit in the precision of the
+/// result.
+/// # Example
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::aggregate::{sum_decimal, DecimalOverflow};
+/// use arrow2::datatypes::DataType;
+/// use arrow2::scalar::Scalar;
+///
+/// let array = PrimitiveArray::from_slice([999i128, 1]).to(DataType::Decimal(3, 2));
+/// assert!(sum_decimal(&array, DecimalOverflow::Error).is_err());
+///
+/// let result = sum_decimal(&array, DecimalOverflow::Widen).unwrap();
+/// assert_eq!(result.value(), Some(1000));
+/// assert_eq!(result.data_type(), &DataType::Decimal(4, 2));
+/// ```
+pub fn sum_decimal(
+    array: &PrimitiveArray<i128>,
+    overflow: DecimalOverflow,
+) -> Result<PrimitiveScalar<i128>> {
+    let (precision, scale) = precision_scale(array.data_type())?;
+    let (sum, count) = checked_sum(array)?;
+    if count == 0 {
+        return Ok(PrimitiveScalar::new(array.data_type().clone(), None));
+    }
+
+    let data_type = match overflow {
+        DecimalOverflow::Error => {
+            if sum.abs() > max_value(precision) {
+                return Err(ArrowError::Overflow);
+            }
+            array.data_type().clone()
+        }
+        DecimalOverflow::Widen => {
+            let digits = number_digits(sum);
+            if digits > MAX_DECIMAL_PRECISION {
+                return Err(ArrowError::Overflow);
+            }
+            DataType::Decimal(precision.max(digits), scale)
+        }
+    };
+    Ok(PrimitiveScalar::new(data_type, Some(sum)))
+}
+
+/// Returns the mean of the values of a [`DataType::Decimal`] array as a decimal with `scale`.
+///
+/// The mean is rounded half away from zero to `scale` digits, which can be larger than the scale
+/// of `array` to keep fractional digits of the division. The precision of the result is the
+/// precision of `array` widened by the additional digits of the scale, up to
+/// [`MAX_DECIMAL_PRECISION`]. The scalar is null when all values are null.
+/// # Errors
+/// Errors iff `array` is not a decimal array, `scale` exceeds [`MAX_DECIMAL_PRECISION`] or the
+/// rescaled sum does not fit in an `i128`.
+/// # Example
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::aggregate::mean_decimal;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::scalar::Scalar;
+///
+/// // [1.00, 2.00, 2.00]
+/// let array = PrimitiveArray::from_slice([100i128, 200, 200]).to(DataType::Decimal(5, 2));
+///
+/// let result = mean_decimal(&array, 4).unwrap();
+/// assert_eq!(result.value(), Some(16667));
+/// assert_eq!(result.data_type(), &DataType::Decimal(7, 4));
+/// ```
+pub fn mean_decimal(array: &PrimitiveArray<i128>, scale: usize) -> Result<PrimitiveScalar<i128>> {
+    let (precision, array_scale) = precision_scale(array.data_type())?;
+    if scale > MAX_DECIMAL_PRECISION {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The scale of a decimal can be at most {}",
+            MAX_DECIMAL_PRECISION
+        )));
+    }
+    let precision = (precision + scale)
+        .saturating_sub(array_scale)
+        .clamp(scale.max(1), MAX_DECIMAL_PRECISION);
+    let data_type = DataType::Decimal(precision, scale);
+
+    let (sum, count) = checked_sum(array)?;
+    if count == 0 {
+        return Ok(PrimitiveScalar::new(data_type, None));
+    }
+    let count = count as i128;
+
+    // rescale the sum such that the division yields `scale` digits
+    let (numerator, denominator) = if scale >= array_scale {
+        let factor = 10i128.pow((scale - array_scale) as u32);
+        let numerator = sum.checked_mul(factor).ok_or(ArrowError::Overflow)?;
+        (numerator, count)
+    } else {
+        let factor = 10i128.pow((array_scale - scale) as u32);
+        let denominator = count.checked_mul(factor).ok_or(ArrowError::Overflow)?;
+        (sum, denominator)
+    };
+
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    let mean = if remainder.unsigned_abs() * 2 >= denominator.unsigned_abs() {
+        quotient + numerator.signum()
+    } else {
+        quotient
+    };
+    Ok(PrimitiveScalar::new(data_type, Some(mean)))
+}
@@ -1,35 +1,59 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
 use crate::array::*;
 use crate::bitmap::Bitmap;
+use crate::chunk::Chunk;
 use crate::datatypes::PhysicalType;
 
-fn validity_size(validity: Option<&Bitmap>) -> usize {
-    validity.as_ref().map(|b| b.as_slice().0.len()).unwrap_or(0)
+/// The estimated (heap) allocated size in bytes of the buffers of one or more arrays,
+/// by kind of buffer. Returned by [`estimated_buffers_size`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BuffersSize {
+    /// The size of the validity bitmaps
+    pub validity: usize,
+    /// The size of the offsets (of binary, utf8, list and map arrays) and of the offsets and
+    /// types (of union arrays)
+    pub offsets: usize,
+    /// The size of the values, including the keys of dictionary arrays
+    pub values: usize,
 }
 
-macro_rules! dyn_binary {
-    ($array:expr, $ty:ty, $o:ty) => {{
-        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+impl BuffersSize {
+    /// The total size in bytes
+    pub fn total(&self) -> usize {
+        self.validity + self.offsets + self.values
+    }
+}
 
-        array.values().len()
-            + array.offsets().len() * std::mem::size_of::<$o>()
-            + validity_size(array.validity())
-    }};
+/// Returns the sum of the lengths of `regions`, counting overlapping regions once.
+fn regions_size(mut regions: Vec<(usize, usize)>) -> usize {
+    regions.sort_unstable();
+    let mut size = 0;
+    let mut end = 0;
+    for (start, length) in regions {
+        let region_end = start + length;
+        if region_end > end {
+            size += region_end - start.max(end);
+            end = region_end;
+        }
+    }
+    size
 }
 
-/// Returns the total (heap) allocated size of the array in bytes.
-/// # Implementation
-/// This estimation is the sum of the size of its buffers, validity, including nested arrays.
-/// Multiple arrays may share buffers and bitmaps. Therefore, the size of 2 arrays is not the
-/// sum of the sizes computed from this function. In particular, [`StructArray`]'s size is an upper bound.
-///
-/// When an array is sliced, its allocated size remains constant because the buffer unchanged.
-/// However, this function will yield a smaller number. This is because this function returns
-/// the visible size of the buffer, not its total capacity.
-///
-/// FFI buffers are included in this estimation.
-pub fn estimated_bytes_size(array: &dyn Array) -> usize {
+fn validity_size(validity: Option<&Bitmap>) -> usize {
+    validity.map(|b| b.as_slice().0.len()).unwrap_or(0)
+}
+
+fn binary_size<O: Offset>(values: &[u8], offsets: &[O], validity: Option<&Bitmap>) -> usize {
+    values.len() + std::mem::size_of_val(offsets) + validity_size(validity)
+}
+
+/// Returns the size of the buffers of `array` when it has no child arrays, whose buffers
+/// are distinct and thus summed without collecting their regions.
+fn flat_bytes_size(array: &dyn Array) -> Option<usize> {
     use PhysicalType::*;
-    match array.data_type().to_physical_type() {
+    Some(match array.data_type().to_physical_type() {
         Null => 0,
         Boolean => {
             let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
@@ -40,10 +64,24 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
                 .as_any()
                 .downcast_ref::<PrimitiveArray<$T>>()
                 .unwrap();
-
-            array.values().len() * std::mem::size_of::<$T>() + validity_size(array.validity())
+            std::mem::size_of_val(array.values().as_slice()) + validity_size(array.validity())
         }),
-        Binary => dyn_binary!(array, BinaryArray<i32>, i32),
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            binary_size(array.values(), array.offsets(), array.validity())
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            binary_size(array.values(), array.offsets(), array.validity())
+        }
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            binary_size(array.values(), array.offsets(), array.validity())
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            binary_size(array.values(), array.offsets(), array.validity())
+        }
         FixedSizeBinary => {
             let array = array
                 .as_any()
@@ -51,62 +89,198 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
                 .unwrap();
             array.values().len() + validity_size(array.validity())
         }
-        LargeBinary => dyn_binary!(array, BinaryArray<i64>, i64),
-        Utf8 => dyn_binary!(array, Utf8Array<i32>, i32),
-        LargeUtf8 => dyn_binary!(array, Utf8Array<i64>, i64),
-        List => {
-            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            estimated_bytes_size(array.values().as_ref())
-                + array.offsets().len() * std::mem::size_of::<i32>()
-                + validity_size(array.validity())
-        }
-        FixedSizeList => {
-            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            estimated_bytes_size(array.values().as_ref()) + validity_size(array.validity())
-        }
-        LargeList => {
-            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            estimated_bytes_size(array.values().as_ref())
-                + array.offsets().len() * std::mem::size_of::<i64>()
-                + validity_size(array.validity())
+        _ => return None,
+    })
+}
+
+/// Collects the memory regions of the buffers of arrays.
+#[derive(Default)]
+struct Regions {
+    // the address of the arrays shared via `Arc` already visited
+    arrays: HashSet<usize>,
+    // (address, length in bytes) of the buffers
+    validity: Vec<(usize, usize)>,
+    offsets: Vec<(usize, usize)>,
+    values: Vec<(usize, usize)>,
+}
+
+#[inline]
+fn region<T>(slice: &[T]) -> (usize, usize) {
+    (slice.as_ptr() as usize, std::mem::size_of_val(slice))
+}
+
+impl Regions {
+    fn validity(&mut self, validity: Option<&Bitmap>) {
+        if let Some(validity) = validity {
+            self.validity.push(region(validity.as_slice().0))
         }
-        Struct => {
-            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
-            array
-                .values()
-                .iter()
-                .map(|x| x.as_ref())
-                .map(estimated_bytes_size)
-                .sum::<usize>()
-                + validity_size(array.validity())
+    }
+
+    fn offsets<T>(&mut self, offsets: &[T]) {
+        self.offsets.push(region(offsets))
+    }
+
+    fn values<T>(&mut self, values: &[T]) {
+        self.values.push(region(values))
+    }
+
+    fn binary<O: Offset>(&mut self, values: &[u8], offsets: &[O], validity: Option<&Bitmap>) {
+        self.values(values);
+        self.offsets(offsets);
+        self.validity(validity);
+    }
+
+    /// Pushes an array shared via `Arc` (e.g. dictionary values), which is only visited once
+    fn push_shared(&mut self, array: &Arc<dyn Array>) {
+        if self.arrays.insert(Arc::as_ptr(array) as *const u8 as usize) {
+            self.push(array.as_ref())
         }
-        Union => {
-            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
-            let types = array.types().len() * std::mem::size_of::<i8>();
-            let offsets = array
-                .offsets()
-                .as_ref()
-                .map(|x| x.len() * std::mem::size_of::<i32>())
-                .unwrap_or_default();
-            let fields = array
-                .fields()
-                .iter()
-                .map(|x| x.as_ref())
-                .map(estimated_bytes_size)
-                .sum::<usize>();
-            types + offsets + fields
+    }
+
+    fn push(&mut self, array: &dyn Array) {
+        use PhysicalType::*;
+        match array.data_type().to_physical_type() {
+            Null => {}
+            Boolean => {
+                let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+                self.values(array.values().as_slice().0);
+                self.validity(array.validity());
+            }
+            Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<$T>>()
+                    .unwrap();
+                self.values(array.values().as_slice());
+                self.validity(array.validity());
+            }),
+            Binary => {
+                let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+                self.binary(array.values(), array.offsets(), array.validity())
+            }
+            LargeBinary => {
+                let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+                self.binary(array.values(), array.offsets(), array.validity())
+            }
+            Utf8 => {
+                let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+                self.binary(array.values(), array.offsets(), array.validity())
+            }
+            LargeUtf8 => {
+                let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+                self.binary(array.values(), array.offsets(), array.validity())
+            }
+            FixedSizeBinary => {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryArray>()
+                    .unwrap();
+                self.values(array.values().as_slice());
+                self.validity(array.validity());
+            }
+            List => {
+                let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+                self.push_shared(array.values());
+                self.offsets(array.offsets().as_slice());
+                self.validity(array.validity());
+            }
+            LargeList => {
+                let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+                self.push_shared(array.values());
+                self.offsets(array.offsets().as_slice());
+                self.validity(array.validity());
+            }
+            FixedSizeList => {
+                let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+                self.push_shared(array.values());
+                self.validity(array.validity());
+            }
+            Struct => {
+                let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+                array.values().iter().for_each(|x| self.push_shared(x));
+                self.validity(array.validity());
+            }
+            Union => {
+                let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+                self.offsets(array.types().as_slice());
+                if let Some(offsets) = array.offsets() {
+                    self.offsets(offsets.as_slice());
+                }
+                array.fields().iter().for_each(|x| self.push_shared(x));
+            }
+            Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap();
+                self.push(array.keys());
+                self.push_shared(array.values());
+            }),
+            Map => {
+                let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+                self.push_shared(array.field());
+                self.offsets(array.offsets().as_slice());
+                self.validity(array.validity());
+            }
         }
-        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
-            let array = array
-                .as_any()
-                .downcast_ref::<DictionaryArray<$T>>()
-                .unwrap();
-            estimated_bytes_size(array.keys()) + estimated_bytes_size(array.values().as_ref())
-        }),
-        Map => {
-            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-            let offsets = array.offsets().len() * std::mem::size_of::<i32>();
-            offsets + estimated_bytes_size(array.field().as_ref()) + validity_size(array.validity())
+    }
+
+    fn size(self) -> BuffersSize {
+        BuffersSize {
+            validity: regions_size(self.validity),
+            offsets: regions_size(self.offsets),
+            values: regions_size(self.values),
         }
     }
 }
+
+/// Returns the total (heap) allocated size of the buffers of `arrays` in bytes, by kind
+/// of buffer.
+///
+/// Buffers shared between the arrays (e.g. the values of dictionaries shared via
+/// [`std::sync::Arc`], or slices of the same array) are counted once. See
+/// [`estimated_bytes_size`] for details about the estimation.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, DictionaryArray, Int32Array, Utf8Array};
+/// use arrow2::compute::aggregate::{estimated_buffers_size, estimated_bytes_size};
+///
+/// let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b"])) as Arc<dyn Array>;
+/// let a = DictionaryArray::<i32>::from_data(Int32Array::from_slice([0, 1]), values.clone());
+/// let b = DictionaryArray::<i32>::from_data(Int32Array::from_slice([1, 0]), values);
+///
+/// let size = estimated_buffers_size([&a as &dyn Array, &b]);
+/// // the values are shared and thus only counted once
+/// assert_eq!(size.total(), 2 * estimated_bytes_size(a.keys()) + estimated_bytes_size(a.values().as_ref()));
+/// assert_eq!(size.offsets, 3 * std::mem::size_of::<i32>());
+/// ```
+pub fn estimated_buffers_size<'a, I: IntoIterator<Item = &'a dyn Array>>(arrays: I) -> BuffersSize {
+    let mut regions = Regions::default();
+    arrays.into_iter().for_each(|array| regions.push(array));
+    regions.size()
+}
+
+/// Returns the total (heap) allocated size of the array in bytes.
+/// # Implementation
+/// This estimation is the sum of the size of its buffers, validity, including nested arrays.
+/// Buffers shared within the array (e.g. by multiple fields of a [`StructArray`]) are counted
+/// once. Multiple arrays may share buffers and bitmaps. Therefore, the size of 2 arrays is
+/// not the sum of the sizes computed from this function; use [`estimated_buffers_size`] or
+/// [`estimated_chunk_bytes_size`] to estimate the size of multiple arrays.
+///
+/// When an array is sliced, its allocated size remains constant because the buffer unchanged.
+/// However, this function will yield a smaller number. This is because this function returns
+/// the visible size of the buffer, not its total capacity.
+///
+/// FFI buffers are included in this estimation.
+pub fn estimated_bytes_size(array: &dyn Array) -> usize {
+    // only nested arrays may share buffers, which requires collecting their regions
+    flat_bytes_size(array).unwrap_or_else(|| estimated_buffers_size([array]).total())
+}
+
+/// Returns the total (heap) allocated size of the arrays of `chunk` in bytes, counting
+/// buffers shared between them once. See [`estimated_buffers_size`].
+pub fn estimated_chunk_bytes_size<A: AsRef<dyn Array>>(chunk: &Chunk<A>) -> usize {
+    estimated_buffers_size(chunk.arrays().iter().map(|x| x.as_ref())).total()
+}
@@ -5,6 +5,12 @@ pub use sum::*;
 mod min_max;
 pub use min_max::*;
 
+mod decimal;
+pub use decimal::*;
+
 mod memory;
 pub use memory::*;
+
+mod quantile;
+pub use quantile::*;
 mod simd;
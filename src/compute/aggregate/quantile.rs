@@ -0,0 +1,169 @@
+use num_traits::AsPrimitive;
+
+use crate::array::{ord::total_cmp_f64, Array, PrimitiveArray};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::scalar::{PrimitiveScalar, Scalar};
+use crate::types::NativeType;
+
+/// The value returned by [`quantile_primitive`] when the quantile lies between two values,
+/// `lower` and `higher`, at a fraction `f` of the way from `lower` to `higher`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuantileInterpolation {
+    /// `lower`
+    Lower,
+    /// `higher`
+    Higher,
+    /// `lower` if `f < 0.5` and `higher` otherwise
+    Nearest,
+    /// `lower + (higher - lower) * f`
+    #[default]
+    Linear,
+}
+
+/// Returns the `quantile` of the non-null values of `array`, or `None` if all values are null.
+///
+/// The quantile is exact: the values are copied and partially sorted by selection, in `O(n)`.
+/// Values are compared in their native type, with `NaN` larger than every other value.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::{quantile_primitive, QuantileInterpolation};
+///
+/// let array = Int32Array::from([Some(4), None, Some(1), Some(2), Some(3)]);
+/// let result = quantile_primitive(&array, 0.5, QuantileInterpolation::Linear).unwrap();
+/// assert_eq!(result, Some(2.5));
+/// let result = quantile_primitive(&array, 0.5, QuantileInterpolation::Lower).unwrap();
+/// assert_eq!(result, Some(2.0));
+/// ```
+/// # Error
+/// Errors iff `quantile` is not in `[0, 1]`.
+pub fn quantile_primitive<T>(
+    array: &PrimitiveArray<T>,
+    quantile: f64,
+    interpolation: QuantileInterpolation,
+) -> Result<Option<f64>>
+where
+    T: NativeType + PartialOrd + AsPrimitive<f64>,
+{
+    if !(0.0..=1.0).contains(&quantile) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The quantile must be between 0 and 1, but it is {}",
+            quantile
+        )));
+    }
+
+    let mut values = match array.validity() {
+        Some(validity) if validity.null_count() > 0 => array
+            .values()
+            .iter()
+            .zip(validity.iter())
+            .filter(|(_, is_valid)| *is_valid)
+            .map(|(x, _)| *x)
+            .collect::<Vec<T>>(),
+        _ => array.values().to_vec(),
+    };
+    if values.is_empty() {
+        return Ok(None);
+    }
+
+    // only `NaN`s are not comparable, and are ordered as `f64` in total order
+    let cmp = |lhs: &T, rhs: &T| {
+        lhs.partial_cmp(rhs)
+            .unwrap_or_else(|| total_cmp_f64(&lhs.as_(), &rhs.as_()))
+    };
+
+    let position = quantile * (values.len() - 1) as f64;
+    let lower_index = position.floor() as usize;
+    let fraction = position - lower_index as f64;
+
+    let (_, lower, higher) = values.select_nth_unstable_by(lower_index, cmp);
+    let lower = *lower;
+    // the next value in order is the smallest of the values after `lower`
+    let higher = higher.iter().copied().min_by(cmp).unwrap_or(lower);
+    // values are only converted to `f64` to be interpolated
+    let (lower, higher): (f64, f64) = (lower.as_(), higher.as_());
+
+    Ok(Some(match interpolation {
+        _ if fraction == 0.0 => lower,
+        QuantileInterpolation::Lower => lower,
+        QuantileInterpolation::Higher => higher,
+        QuantileInterpolation::Nearest => {
+            if fraction < 0.5 {
+                lower
+            } else {
+                higher
+            }
+        }
+        QuantileInterpolation::Linear => lower + (higher - lower) * fraction,
+    }))
+}
+
+/// Returns the median of the non-null values of `array`, or `None` if all values are null.
+/// When the number of values is even, the median is the mean of the two middle values.
+/// See [`quantile_primitive`] for details.
+pub fn median_primitive<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: NativeType + PartialOrd + AsPrimitive<f64>,
+{
+    // `unwrap` is infalible because `0.5` is a valid quantile
+    quantile_primitive(array, 0.5, QuantileInterpolation::Linear).unwrap()
+}
+
+/// Returns the `quantile` of the non-null values of `array` as a [`f64`] scalar, which is
+/// null when all values are null. See [`quantile_primitive`] for details.
+/// # Error
+/// Errors iff `quantile` is not in `[0, 1]` or the type does not support this operation
+/// (see [`can_quantile`]).
+pub fn quantile(
+    array: &dyn Array,
+    quantile: f64,
+    interpolation: QuantileInterpolation,
+) -> Result<Box<dyn Scalar>> {
+    macro_rules! dyn_quantile {
+        ($T:ty) => {{
+            let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            quantile_primitive(array, quantile, interpolation)?
+        }};
+    }
+
+    let value = match array.data_type() {
+        DataType::Int8 => dyn_quantile!(i8),
+        DataType::Int16 => dyn_quantile!(i16),
+        DataType::Int32 => dyn_quantile!(i32),
+        DataType::Int64 => dyn_quantile!(i64),
+        DataType::UInt8 => dyn_quantile!(u8),
+        DataType::UInt16 => dyn_quantile!(u16),
+        DataType::UInt32 => dyn_quantile!(u32),
+        DataType::UInt64 => dyn_quantile!(u64),
+        DataType::Float32 => dyn_quantile!(f32),
+        DataType::Float64 => dyn_quantile!(f64),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The `quantile` operator does not support type `{:?}`",
+                array.data_type(),
+            )))
+        }
+    };
+    Ok(Box::new(PrimitiveScalar::<f64>::new(
+        DataType::Float64,
+        value,
+    )))
+}
+
+/// Whether [`quantile`] supports `data_type`
+pub fn can_quantile(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64
+    )
+}
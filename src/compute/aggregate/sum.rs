@@ -13,6 +13,8 @@ use crate::{
     bitmap::Bitmap,
 };
 
+use super::{sum_decimal, DecimalOverflow};
+
 /// Object that can reduce itself to a number. This is used in the context of SIMD to reduce
 /// a MD (e.g. `[f32; 16]`) into a single number (`f32`).
 pub trait Sum<T> {
@@ -142,10 +144,16 @@ macro_rules! with_match_primitive_type {(
 })}
 
 /// Returns the sum of all elements in `array` as a [`Scalar`] of the same physical
-/// and logical types as `array`.
+/// and logical types as `array`. Use [`sum_decimal`] with [`DecimalOverflow::Widen`] to
+/// widen the precision of a [`DataType::Decimal`] to fit the sum instead.
 /// # Error
-/// Errors iff the operation is not supported.
+/// Errors iff the operation is not supported or the sum of a [`DataType::Decimal`] array
+/// does not fit in its precision.
 pub fn sum(array: &dyn Array) -> Result<Box<dyn Scalar>> {
+    if let DataType::Decimal(_, _) = array.data_type().to_logical_type() {
+        let array = array.as_any().downcast_ref().unwrap();
+        return Ok(Box::new(sum_decimal(array, DecimalOverflow::Error)?));
+    }
     Ok(match array.data_type().to_physical_type() {
         PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let data_type = array.data_type().clone();
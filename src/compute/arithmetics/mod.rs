@@ -20,6 +20,7 @@ use crate::{
     array::{Array, DictionaryArray, PrimitiveArray},
     bitmap::Bitmap,
     datatypes::{DataType, IntervalUnit, TimeUnit},
+    error::Result,
     scalar::{PrimitiveScalar, Scalar},
 };
 
@@ -194,20 +195,56 @@ macro_rules! arith_scalar {
     }};
 }
 
+// Macro to evaluate a fallible temporal kernel in arithmetic function.
+macro_rules! temporal {
+    ($lhs:expr, $rhs:expr, $op:ident $(::<$type:ty>)?) => {{
+        let lhs = $lhs.as_any().downcast_ref().unwrap();
+        let rhs = $rhs.as_any().downcast_ref().unwrap();
+        time::$op$(::<$type>)?(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>)
+    }};
+}
+
 /// Adds two [`Array`]s.
+///
+/// Besides numeric types, this supports the temporal rules
+/// * Timestamp, Date or Time + Duration (and Duration + Timestamp, Date or Time) = the temporal type
+/// * Timestamp + Interval (and Interval + Timestamp) = Timestamp
+/// * Duration + Duration = Duration, in the finest of both time units
+/// * Interval + Interval = Interval
 /// # Panic
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_add`] to check)
 /// * the arrays have a different length
-/// * one of the arrays is a timestamp with timezone and the timezone is not valid.
+/// * a temporal kernel errors, e.g. because one of the arrays is a timestamp with timezone
+///   and the timezone is not valid (use [`checked_add`] to get the error instead).
 pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
-    arith!(
-        lhs,
-        rhs,
-        add,
-        duration = add_duration,
-        interval = add_interval
-    )
+    checked_add(lhs, rhs).unwrap()
+}
+
+/// Adds two [`Array`]s like [`add`], returning the errors of the temporal kernels.
+/// # Error
+/// Errors iff a temporal kernel errors, e.g. because one of the arrays is a timestamp with
+/// timezone and the timezone is not valid.
+/// # Panic
+/// This function panics iff
+/// * the opertion is not supported for the logical types (use [`can_add`] to check)
+/// * the arrays have a different length
+pub fn checked_add(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    match (lhs.data_type(), rhs.data_type()) {
+        (Duration(_), Timestamp(_, _) | Date32 | Date64 | Time32(_) | Time64(_))
+        | (Interval(IntervalUnit::MonthDayNano), Timestamp(_, _)) => checked_add(rhs, lhs),
+        (Duration(_), Duration(_)) => temporal!(lhs, rhs, add_durations),
+        (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano)) => {
+            temporal!(lhs, rhs, add_interval)
+        }
+        (Interval(IntervalUnit::MonthDayNano), Interval(IntervalUnit::MonthDayNano)) => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            Ok(Box::new(time::add_intervals(lhs, rhs)))
+        }
+        _ => Ok(arith!(lhs, rhs, add, duration = add_duration)),
+    }
 }
 
 /// Adds an [`Array`] and a [`Scalar`].
@@ -251,24 +288,75 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
             | (Time64(TimeUnit::Nanosecond), Duration(_))
             | (Timestamp(_, _), Duration(_))
             | (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano))
+            | (Duration(_), Date32)
+            | (Duration(_), Date64)
+            | (Duration(_), Time32(TimeUnit::Millisecond))
+            | (Duration(_), Time32(TimeUnit::Second))
+            | (Duration(_), Time64(TimeUnit::Microsecond))
+            | (Duration(_), Time64(TimeUnit::Nanosecond))
+            | (Duration(_), Timestamp(_, _))
+            | (Interval(IntervalUnit::MonthDayNano), Timestamp(_, _))
+            | (
+                Interval(IntervalUnit::MonthDayNano),
+                Interval(IntervalUnit::MonthDayNano)
+            )
     )
 }
 
 /// Subtracts two [`Array`]s.
+///
+/// Besides numeric types, this supports the temporal rules
+/// * Timestamp, Date or Time - Duration = the temporal type
+/// * Timestamp - Interval = Timestamp
+/// * Timestamp - Timestamp, Date - Date (of the same type) or Time - Time = Duration,
+///   in the finest of both time units (Date32 in seconds)
+/// * Duration - Duration = Duration, in the finest of both time units
+/// * Interval - Interval = Interval
 /// # Panic
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_sub`] to check)
 /// * the arrays have a different length
-/// * one of the arrays is a timestamp with timezone and the timezone is not valid.
+/// * a temporal kernel errors, e.g. because one of the arrays is a timestamp with timezone
+///   and the timezone is not valid (use [`checked_sub`] to get the error instead).
 pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
-    arith!(
-        lhs,
-        rhs,
-        sub,
-        decimal = sub,
-        duration = subtract_duration,
-        timestamp = subtract_timestamps
-    )
+    checked_sub(lhs, rhs).unwrap()
+}
+
+/// Subtracts two [`Array`]s like [`sub`], returning the errors of the temporal kernels.
+/// # Error
+/// Errors iff a temporal kernel errors, e.g. because one of the arrays is a timestamp with
+/// timezone and the timezone is not valid.
+/// # Panic
+/// This function panics iff
+/// * the opertion is not supported for the logical types (use [`can_sub`] to check)
+/// * the arrays have a different length
+pub fn checked_sub(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    match (lhs.data_type(), rhs.data_type()) {
+        (Timestamp(_, _), Timestamp(_, _)) => temporal!(lhs, rhs, subtract_timestamps),
+        (Date64, Date64) | (Time64(_), Time64(_)) => {
+            temporal!(lhs, rhs, subtract_temporals::<i64>)
+        }
+        (Date32, Date32) | (Time32(_), Time32(_)) => {
+            temporal!(lhs, rhs, subtract_temporals::<i32>)
+        }
+        (Duration(_), Duration(_)) => temporal!(lhs, rhs, subtract_durations),
+        (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano)) => {
+            temporal!(lhs, rhs, subtract_interval)
+        }
+        (Interval(IntervalUnit::MonthDayNano), Interval(IntervalUnit::MonthDayNano)) => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            Ok(Box::new(time::subtract_intervals(lhs, rhs)))
+        }
+        _ => Ok(arith!(
+            lhs,
+            rhs,
+            sub,
+            decimal = sub,
+            duration = subtract_duration
+        )),
+    }
 }
 
 /// Adds an [`Array`] and a [`Scalar`].
@@ -311,17 +399,40 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
             | (Time64(TimeUnit::Microsecond), Duration(_))
             | (Time64(TimeUnit::Nanosecond), Duration(_))
             | (Timestamp(_, _), Duration(_))
-            | (Timestamp(_, None), Timestamp(_, None))
+            | (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano))
+            | (Timestamp(_, _), Timestamp(_, _))
+            | (Date32, Date32)
+            | (Date64, Date64)
+            | (Time32(TimeUnit::Millisecond), Time32(TimeUnit::Millisecond))
+            | (Time32(TimeUnit::Millisecond), Time32(TimeUnit::Second))
+            | (Time32(TimeUnit::Second), Time32(TimeUnit::Millisecond))
+            | (Time32(TimeUnit::Second), Time32(TimeUnit::Second))
+            | (Time64(TimeUnit::Microsecond), Time64(TimeUnit::Microsecond))
+            | (Time64(TimeUnit::Microsecond), Time64(TimeUnit::Nanosecond))
+            | (Time64(TimeUnit::Nanosecond), Time64(TimeUnit::Microsecond))
+            | (Time64(TimeUnit::Nanosecond), Time64(TimeUnit::Nanosecond))
+            | (
+                Interval(IntervalUnit::MonthDayNano),
+                Interval(IntervalUnit::MonthDayNano)
+            )
     )
 }
 
 /// Multiply two [`Array`]s.
+///
+/// Besides numeric types, this supports the temporal rule
+/// * Duration * Int64 (and Int64 * Duration) = Duration, in the same time unit
 /// # Panic
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_mul`] to check)
 /// * the arrays have a different length
 pub fn mul(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
-    arith!(lhs, rhs, mul, decimal = mul)
+    use DataType::*;
+    match (lhs.data_type(), rhs.data_type()) {
+        (Int64, Duration(_)) => mul(rhs, lhs),
+        (Duration(_), Int64) => temporal!(lhs, rhs, mul_duration).unwrap(),
+        _ => arith!(lhs, rhs, mul, decimal = mul),
+    }
 }
 
 /// Multiply an [`Array`] with a [`Scalar`].
@@ -329,7 +440,12 @@ pub fn mul(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_mul`] to check)
 pub fn mul_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
-    arith_scalar!(lhs, rhs, mul_scalar, decimal = mul_scalar)
+    match (lhs.data_type(), rhs.data_type()) {
+        (DataType::Duration(_), DataType::Int64) => {
+            temporal!(lhs, rhs, mul_duration_scalar).unwrap()
+        }
+        _ => arith_scalar!(lhs, rhs, mul_scalar, decimal = mul_scalar),
+    }
 }
 
 /// Returns whether two [`DataType`]s can be multiplied by [`mul`].
@@ -348,6 +464,8 @@ pub fn can_mul(lhs: &DataType, rhs: &DataType) -> bool {
             | (Float64, Float64)
             | (Float32, Float32)
             | (Decimal(_, _), Decimal(_, _))
+            | (Duration(_), Int64)
+            | (Int64, Duration(_))
     )
 }
 
@@ -370,7 +488,8 @@ pub fn div_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
 
 /// Returns whether two [`DataType`]s can be divided by [`div`].
 pub fn can_div(lhs: &DataType, rhs: &DataType) -> bool {
-    can_mul(lhs, rhs)
+    use DataType::*;
+    !matches!((lhs, rhs), (Duration(_), Int64) | (Int64, Duration(_))) && can_mul(lhs, rhs)
 }
 
 /// Remainder of two [`Array`]s.
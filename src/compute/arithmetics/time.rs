@@ -1,5 +1,7 @@
 //! Defines the arithmetic kernels for adding a Duration to a Timestamp,
-//! Time32, Time64, Date32 and Date64.
+//! Time32, Time64, Date32 and Date64, for adding an Interval to a Timestamp,
+//! for the difference between two Timestamps, Times or Dates, and for adding
+//! Durations and Intervals together, and for multiplying Durations by integers.
 //!
 //! For the purposes of Arrow Implementations, adding this value to a Timestamp
 //! ("t1") naively (i.e. simply summing the two number) is acceptable even
@@ -15,7 +17,7 @@ use num_traits::AsPrimitive;
 
 use crate::{
     array::PrimitiveArray,
-    compute::arity::{binary, unary},
+    compute::arity::{binary, binary_checked, unary, unary_checked},
     datatypes::{DataType, TimeUnit},
     error::{ArrowError, Result},
     scalar::{PrimitiveScalar, Scalar},
@@ -257,20 +259,8 @@ pub fn subtract_timestamps(
     lhs: &PrimitiveArray<i64>,
     rhs: &PrimitiveArray<i64>,
 ) -> Result<PrimitiveArray<i64>> {
-    // Matching on both data types from both arrays.
-    // Both timestamps have a Timeunit enum in its data type.
-    // This enum is used to adjust the scale between the timestamps.
     match (lhs.data_type(), rhs.data_type()) {
-        // Naive timestamp comparison. It doesn't take into account timezones
-        // from the Timestamp timeunit.
-        (DataType::Timestamp(timeunit_a, None), DataType::Timestamp(timeunit_b, None)) => {
-            // Closure for the binary operation. The closure contains the scale
-            // required to calculate the difference between the timestamps.
-            let scale = temporal_conversions::timeunit_scale(*timeunit_a, *timeunit_b);
-            let op = move |a, b| a - (b as f64 * scale) as i64;
-
-            Ok(binary(lhs, rhs, DataType::Duration(*timeunit_a), op))
-        }
+        (DataType::Timestamp(_, _), DataType::Timestamp(_, _)) => subtract_temporals(lhs, rhs),
         _ => Err(ArrowError::InvalidArgumentError(
             "Incorrect data type for the arguments".to_string(),
         )),
@@ -432,3 +422,264 @@ pub fn add_interval_scalar(
         )),
     }
 }
+
+/// Subtracts an interval from a [`DataType::Timestamp`].
+pub fn subtract_interval(
+    timestamp: &PrimitiveArray<i64>,
+    interval: &PrimitiveArray<months_days_ns>,
+) -> Result<PrimitiveArray<i64>> {
+    let interval = unary(interval, |x| -x, interval.data_type().clone());
+    add_interval(timestamp, &interval)
+}
+
+/// Adds two arrays of [`DataType::Interval`] of
+/// [`IntervalUnit::MonthDayNano`](crate::datatypes::IntervalUnit::MonthDayNano), component-wise.
+/// Components that overflow wrap around.
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn add_intervals(
+    lhs: &PrimitiveArray<months_days_ns>,
+    rhs: &PrimitiveArray<months_days_ns>,
+) -> PrimitiveArray<months_days_ns> {
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        months_days_ns::new(
+            a.months().wrapping_add(b.months()),
+            a.days().wrapping_add(b.days()),
+            a.ns().wrapping_add(b.ns()),
+        )
+    })
+}
+
+/// Subtracts two arrays of [`DataType::Interval`] of
+/// [`IntervalUnit::MonthDayNano`](crate::datatypes::IntervalUnit::MonthDayNano), component-wise.
+/// Components that overflow wrap around.
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn subtract_intervals(
+    lhs: &PrimitiveArray<months_days_ns>,
+    rhs: &PrimitiveArray<months_days_ns>,
+) -> PrimitiveArray<months_days_ns> {
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        months_days_ns::new(
+            a.months().wrapping_sub(b.months()),
+            a.days().wrapping_sub(b.days()),
+            a.ns().wrapping_sub(b.ns()),
+        )
+    })
+}
+
+/// The number of nanoseconds of a [`TimeUnit`].
+fn unit_nanoseconds(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    }
+}
+
+/// The finest of two [`TimeUnit`]s, in which values of both can be represented exactly.
+fn finest_unit(lhs: TimeUnit, rhs: TimeUnit) -> TimeUnit {
+    if unit_nanoseconds(lhs) <= unit_nanoseconds(rhs) {
+        lhs
+    } else {
+        rhs
+    }
+}
+
+/// The [`TimeUnit`] of a value of a temporal [`DataType`] and the number of those units
+/// in one of its values (e.g. a [`DataType::Date32`] counts seconds in units of a day).
+fn temporal_unit(data_type: &DataType) -> Result<(TimeUnit, i64)> {
+    match data_type.to_logical_type() {
+        DataType::Timestamp(unit, _)
+        | DataType::Time32(unit)
+        | DataType::Time64(unit)
+        | DataType::Duration(unit) => Ok((*unit, 1)),
+        DataType::Date32 => Ok((TimeUnit::Second, temporal_conversions::SECONDS_IN_DAY)),
+        DataType::Date64 => Ok((TimeUnit::Millisecond, 1)),
+        other => Err(ArrowError::InvalidArgumentError(format!(
+            "{:?} is not a temporal type",
+            other
+        ))),
+    }
+}
+
+/// Returns the unit of the result of an operation between the temporal arrays of type
+/// `lhs` and `rhs`, together with the factors that convert each of them to it.
+fn normalize(lhs: &DataType, rhs: &DataType) -> Result<(TimeUnit, i64, i64)> {
+    let (lhs_unit, lhs_factor) = temporal_unit(lhs)?;
+    let (rhs_unit, rhs_factor) = temporal_unit(rhs)?;
+    let unit = finest_unit(lhs_unit, rhs_unit);
+    let nanoseconds = unit_nanoseconds(unit);
+    Ok((
+        unit,
+        lhs_factor * unit_nanoseconds(lhs_unit) / nanoseconds,
+        rhs_factor * unit_nanoseconds(rhs_unit) / nanoseconds,
+    ))
+}
+
+fn durations(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+    op: impl Fn(i64, i64) -> i64,
+) -> Result<PrimitiveArray<i64>> {
+    match (lhs.data_type(), rhs.data_type()) {
+        (DataType::Duration(_), DataType::Duration(_)) => {
+            let (unit, lhs_factor, rhs_factor) = normalize(lhs.data_type(), rhs.data_type())?;
+            Ok(binary_checked(
+                lhs,
+                rhs,
+                DataType::Duration(unit),
+                |a, b| Some(op(a.checked_mul(lhs_factor)?, b.checked_mul(rhs_factor)?)),
+            ))
+        }
+        _ => Err(ArrowError::InvalidArgumentError(
+            "Incorrect data type for the arguments".to_string(),
+        )),
+    }
+}
+
+/// Adds two arrays of [`DataType::Duration`], returning a [`DataType::Duration`] in the
+/// finest of their [`TimeUnit`]s. Values that overflow when converted to it are null.
+/// # Example
+/// ```
+/// use arrow2::compute::arithmetics::time::add_durations;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let seconds = PrimitiveArray::from([Some(1i64), None]).to(DataType::Duration(TimeUnit::Second));
+/// let milliseconds =
+///     PrimitiveArray::from([Some(10i64), Some(1)]).to(DataType::Duration(TimeUnit::Millisecond));
+///
+/// let result = add_durations(&seconds, &milliseconds).unwrap();
+/// let expected =
+///     PrimitiveArray::from([Some(1_010i64), None]).to(DataType::Duration(TimeUnit::Millisecond));
+/// assert_eq!(result, expected);
+/// ```
+/// # Error
+/// Errors iff any of the arrays is not a [`DataType::Duration`].
+pub fn add_durations(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    durations(lhs, rhs, |a, b| a + b)
+}
+
+/// Subtracts two arrays of [`DataType::Duration`], returning a [`DataType::Duration`] in the
+/// finest of their [`TimeUnit`]s. Values that overflow when converted to it are null.
+/// # Error
+/// Errors iff any of the arrays is not a [`DataType::Duration`].
+pub fn subtract_durations(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    durations(lhs, rhs, |a, b| a - b)
+}
+
+/// Multiplies an array of [`DataType::Duration`] by an array of [`DataType::Int64`] factors,
+/// returning a [`DataType::Duration`] in the same [`TimeUnit`]. Values that overflow are null.
+/// # Example
+/// ```
+/// use arrow2::compute::arithmetics::time::mul_duration;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let duration = PrimitiveArray::from([Some(10i64), None]).to(DataType::Duration(TimeUnit::Second));
+/// let factor = PrimitiveArray::from([Some(-2i64), Some(3)]);
+///
+/// let result = mul_duration(&duration, &factor).unwrap();
+/// let expected = PrimitiveArray::from([Some(-20i64), None]).to(DataType::Duration(TimeUnit::Second));
+/// assert_eq!(result, expected);
+/// ```
+/// # Error
+/// Errors iff `lhs` is not a [`DataType::Duration`] or `rhs` is not a [`DataType::Int64`].
+pub fn mul_duration(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    match (lhs.data_type(), rhs.data_type()) {
+        (DataType::Duration(_), DataType::Int64) => {
+            Ok(binary_checked(lhs, rhs, lhs.data_type().clone(), |a, b| {
+                a.checked_mul(b)
+            }))
+        }
+        _ => Err(ArrowError::InvalidArgumentError(
+            "Incorrect data type for the arguments".to_string(),
+        )),
+    }
+}
+
+/// Multiplies an array of [`DataType::Duration`] by a [`DataType::Int64`] factor,
+/// returning a [`DataType::Duration`] in the same [`TimeUnit`]. Values that overflow are null.
+/// # Error
+/// Errors iff `lhs` is not a [`DataType::Duration`] or `rhs` is not a [`DataType::Int64`].
+pub fn mul_duration_scalar(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveScalar<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    match (lhs.data_type(), rhs.data_type()) {
+        (DataType::Duration(_), DataType::Int64) => {
+            let data_type = lhs.data_type().clone();
+            Ok(match rhs.value() {
+                Some(factor) => unary_checked(lhs, |a| a.checked_mul(factor), data_type),
+                None => PrimitiveArray::<i64>::new_null(data_type, lhs.len()),
+            })
+        }
+        _ => Err(ArrowError::InvalidArgumentError(
+            "Incorrect data type for the arguments".to_string(),
+        )),
+    }
+}
+
+/// Calculates the difference between two arrays of the same temporal type (Timestamp, Time
+/// or Date), returning a [`DataType::Duration`] in the finest of their [`TimeUnit`]s.
+/// Values that overflow when converted to it are null.
+///
+/// Timestamps are subtracted regardless of their timezones, since their values are
+/// relative to the UNIX epoch in UTC. Dates are subtracted in seconds
+/// ([`DataType::Date32`]) or milliseconds ([`DataType::Date64`]).
+/// # Example
+/// ```
+/// use arrow2::compute::arithmetics::time::subtract_temporals;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let lhs = PrimitiveArray::from([Some(2i64), None])
+///     .to(DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string())));
+/// let rhs = PrimitiveArray::from([Some(1_000i64), Some(1)])
+///     .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+///
+/// let result = subtract_temporals(&lhs, &rhs).unwrap();
+/// let expected =
+///     PrimitiveArray::from([Some(1_000i64), None]).to(DataType::Duration(TimeUnit::Millisecond));
+/// assert_eq!(result, expected);
+/// ```
+/// # Error
+/// Errors iff the arrays are not both Timestamps, Dates of the same type or Times.
+pub fn subtract_temporals<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<i64>>
+where
+    T: NativeType + AsPrimitive<i64>,
+{
+    use DataType::*;
+    match (
+        lhs.data_type().to_logical_type(),
+        rhs.data_type().to_logical_type(),
+    ) {
+        (Timestamp(_, _), Timestamp(_, _))
+        | (Date32, Date32)
+        | (Date64, Date64)
+        | (Time32(_), Time32(_))
+        | (Time64(_), Time64(_)) => {
+            let (unit, lhs_factor, rhs_factor) = normalize(lhs.data_type(), rhs.data_type())?;
+            let lhs = unary_checked(lhs, |a| a.as_().checked_mul(lhs_factor), DataType::Int64);
+            let rhs = unary_checked(rhs, |b| b.as_().checked_mul(rhs_factor), DataType::Int64);
+            Ok(binary(&lhs, &rhs, DataType::Duration(unit), |a, b| a - b))
+        }
+        _ => Err(ArrowError::InvalidArgumentError(
+            "Incorrect data type for the arguments".to_string(),
+        )),
+    }
+}
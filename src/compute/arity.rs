@@ -3,9 +3,10 @@
 use super::utils::{check_same_len, combine_validities};
 use crate::{
     array::PrimitiveArray,
+    bitmap::utils::{bytes_for, extend_aligned, BitChunks},
     bitmap::{Bitmap, MutableBitmap},
     datatypes::DataType,
-    error::Result,
+    error::{ArrowError, Result},
     types::NativeType,
 };
 
@@ -269,13 +270,155 @@ where
         .into();
 
     let bitmap: Bitmap = mut_bitmap.into();
-    let validity = combine_validities(lhs.validity(), rhs.validity());
 
     // The validity has to be checked against the bitmap created during the
     // creation of the values with the iterator. If an error was found during
     // the iteration, then the validity is changed to None to mark the value
     // as Null
-    let validity = combine_validities(validity.as_ref(), Some(&bitmap));
+    let validity = prep_validity(&[lhs.validity(), rhs.validity(), Some(&bitmap)]);
 
     PrimitiveArray::<T>::new(data_type, values, validity)
 }
+
+/// Returns the results of `op` on the values whose slot is set in `validity`, and
+/// `O::default()` on all others, without applying `op` to them.
+fn apply_valid<A, O, F>(values: impl Iterator<Item = A>, validity: Option<&Bitmap>, op: F) -> Vec<O>
+where
+    O: NativeType,
+    F: Fn(A) -> O,
+{
+    match validity {
+        None => values.map(op).collect(),
+        Some(validity) if validity.null_count() == validity.len() => {
+            vec![O::default(); validity.len()]
+        }
+        Some(validity) => values
+            .zip(validity.iter())
+            .map(|(value, is_valid)| if is_valid { op(value) } else { O::default() })
+            .collect(),
+    }
+}
+
+/// Version of [`unary`] that intersects the validity of `array` with a precomputed
+/// `validity` (e.g. the rows selected by a condition) and only applies `op` to the slots
+/// valid in both; all other slots are null.
+///
+/// # Implementation
+/// The bitmaps are only intersected when both have nulls (see [`prep_validity`]). `op` is
+/// applied to every value when no slot is null and to none when every slot is null.
+/// # Panics
+/// Panics iff `validity.len() != array.len()`.
+pub fn unary_with_validity<I, F, O>(
+    array: &PrimitiveArray<I>,
+    op: F,
+    data_type: DataType,
+    validity: &Bitmap,
+) -> PrimitiveArray<O>
+where
+    I: NativeType,
+    O: NativeType,
+    F: Fn(I) -> O,
+{
+    assert_eq!(
+        validity.len(),
+        array.len(),
+        "The validity must have the same length as the array"
+    );
+    let validity = prep_validity(&[array.validity(), Some(validity)]);
+
+    let values = apply_valid(array.values().iter().copied(), validity.as_ref(), op);
+
+    PrimitiveArray::<O>::new(data_type, values.into(), validity)
+}
+
+/// Version of [`binary`] that intersects the validities of `lhs` and `rhs` with a
+/// precomputed `validity` (e.g. the rows selected by a condition) and only applies `op` to
+/// the slots valid in all of them; all other slots are null.
+///
+/// # Implementation
+/// See [`unary_with_validity`].
+/// # Errors
+/// This function errors iff the arrays and `validity` have different lengths.
+pub fn binary_with_validity<T, D, F>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<D>,
+    data_type: DataType,
+    op: F,
+    validity: &Bitmap,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    D: NativeType,
+    F: Fn(T, D) -> T,
+{
+    check_same_len(lhs, rhs)?;
+    if validity.len() != lhs.len() {
+        return Err(ArrowError::LengthMismatch {
+            expected: lhs.len(),
+            found: validity.len(),
+        });
+    }
+    let validity = prep_validity(&[lhs.validity(), rhs.validity(), Some(validity)]);
+
+    let values = lhs
+        .values()
+        .iter()
+        .copied()
+        .zip(rhs.values().iter().copied());
+    let values = apply_valid(values, validity.as_ref(), |(l, r)| op(l, r));
+
+    Ok(PrimitiveArray::<T>::new(data_type, values.into(), validity))
+}
+
+/// Returns the intersection of `validities`, i.e. a slot is valid iff it is valid in all of
+/// them. Returns `None` when none of them has unset bits.
+///
+/// This is the validity of the result of kernels over any number of inputs that propagate nulls.
+/// # Implementation
+/// Bitmaps without unset bits are skipped and a bitmap without set bits is returned as is, so
+/// that nothing is allocated when at most one bitmap has nulls or when one has no valid slots.
+/// Otherwise, the bits of the first bitmap are copied once and the others are intersected with
+/// them in place, 64 bits at a time, regardless of the number and offsets of the bitmaps.
+/// # Panics
+/// Panics iff the bitmaps with unset bits have different lengths.
+pub fn prep_validity(validities: &[Option<&Bitmap>]) -> Option<Bitmap> {
+    let validities = validities
+        .iter()
+        .filter_map(|x| x.filter(|x| x.null_count() > 0))
+        .collect::<Vec<_>>();
+    let (first, others) = validities.split_first()?;
+    let length = first.len();
+    others
+        .iter()
+        .for_each(|x| assert_eq!(x.len(), length, "validities must have the same length"));
+
+    if others.is_empty() {
+        return Some((*first).clone());
+    }
+    if let Some(all_null) = validities.iter().find(|x| x.null_count() == length) {
+        return Some((*all_null).clone());
+    }
+
+    let (slice, offset, _) = first.as_slice();
+    let mut buffer = Vec::with_capacity(bytes_for(length));
+    extend_aligned(&mut buffer, slice, offset, length);
+
+    for validity in others {
+        let (slice, offset, _) = validity.as_slice();
+        let chunks = BitChunks::<u64>::new(slice, offset, length);
+        let remainder = chunks.remainder().to_ne_bytes();
+
+        let mut words = buffer.chunks_exact_mut(8);
+        (&mut words).zip(chunks).for_each(|(word, chunk)| {
+            let value = u64::from_ne_bytes(word.try_into().unwrap()) & chunk;
+            word.copy_from_slice(&value.to_ne_bytes());
+        });
+        words
+            .into_remainder()
+            .iter_mut()
+            .zip(remainder.iter())
+            .for_each(|(byte, remainder)| *byte &= remainder);
+    }
+
+    Some(Bitmap::from_u8_vec(buffer, length))
+}
@@ -1,8 +1,11 @@
-//! Contains bitwise operators: [`or`], [`and`], [`xor`] and [`not`].
+//! Contains bitwise operators: [`or`], [`and`], [`xor`], [`not`], [`shift_left`] and
+//! [`shift_right`].
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
+use num_traits::{CheckedShl, CheckedShr, ToPrimitive};
+
 use crate::array::PrimitiveArray;
-use crate::compute::arity::{binary, unary};
+use crate::compute::arity::{binary, binary_checked, unary, unary_checked};
 use crate::types::NativeType;
 
 /// Performs `OR` operation on two [`PrimitiveArray`]s.
@@ -73,3 +76,59 @@ where
 {
     unary(lhs, |a| a & *rhs, lhs.data_type().clone())
 }
+
+/// Shifts each value of `lhs` to the left by the corresponding value of `rhs`.
+/// Values whose shift is negative or not smaller than the number of bits of `T` are null.
+/// # Panic
+/// This function panics when the arrays have different lengths.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::bitwise::shift_left;
+///
+/// let a = Int32Array::from(&[Some(1), Some(3), None, Some(1)]);
+/// let b = Int32Array::from(&[Some(4), Some(1), Some(1), Some(32)]);
+/// let result = shift_left(&a, &b);
+/// assert_eq!(result, Int32Array::from(&[Some(16), Some(6), None, None]));
+/// ```
+pub fn shift_left<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedShl + ToPrimitive,
+{
+    binary_checked(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        b.to_u32().and_then(|b| a.checked_shl(b))
+    })
+}
+
+/// Shifts each value of `lhs` to the right by the corresponding value of `rhs`.
+/// The shift of signed integers is arithmetic, i.e. it preserves their sign.
+/// Values whose shift is negative or not smaller than the number of bits of `T` are null.
+/// # Panic
+/// This function panics when the arrays have different lengths.
+pub fn shift_right<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedShr + ToPrimitive,
+{
+    binary_checked(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        b.to_u32().and_then(|b| a.checked_shr(b))
+    })
+}
+
+/// Shifts each value of a [`PrimitiveArray`] to the left by `rhs`.
+/// All values are null if `rhs` is not smaller than the number of bits of `T`.
+pub fn shift_left_scalar<T>(lhs: &PrimitiveArray<T>, rhs: u32) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedShl,
+{
+    unary_checked(lhs, |a| a.checked_shl(rhs), lhs.data_type().clone())
+}
+
+/// Shifts each value of a [`PrimitiveArray`] to the right by `rhs`.
+/// The shift of signed integers is arithmetic, i.e. it preserves their sign.
+/// All values are null if `rhs` is not smaller than the number of bits of `T`.
+pub fn shift_right_scalar<T>(lhs: &PrimitiveArray<T>, rhs: u32) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedShr,
+{
+    unary_checked(lhs, |a| a.checked_shr(rhs), lhs.data_type().clone())
+}
@@ -4,24 +4,33 @@ mod binary_to;
 mod boolean_to;
 mod decimal_to;
 mod dictionary_to;
+mod overflow;
 mod primitive_to;
+mod report;
 mod utf8_to;
 
 pub use binary_to::*;
 pub use boolean_to::*;
 pub use decimal_to::*;
 pub use dictionary_to::*;
+pub use overflow::*;
 pub use primitive_to::*;
+pub use report::*;
 pub use utf8_to::*;
 
 use crate::{
     array::*,
     datatypes::*,
     error::{ArrowError, Result},
+    temporal_conversions::MILLISECONDS_IN_DAY,
 };
 
 /// options defining how Cast kernels behave
+///
+/// New options may be added in minor releases, so [`CastOptions`] is built from its
+/// [`Default`] and the `with_*` methods, e.g. `CastOptions::default().with_wrapped(true)`.
 #[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
 pub struct CastOptions {
     /// default to false
     /// whether an overflowing cast should be converted to `None` (default), or be wrapped (i.e. `256i16 as u8 = 0` vectorized).
@@ -30,13 +39,54 @@ pub struct CastOptions {
     /// default to false
     /// whether to cast to an integer at the best-effort
     pub partial: bool,
+    /// how temporal casts that change the unit of their values (e.g. `Timestamp(ns)` to
+    /// `Timestamp(s)`, `Time32(ms)` to `Time64(us)` or `Date64` to `Date32`) handle values
+    /// that cannot be represented exactly. Defaults to [`TemporalCastPolicy::NullOnOverflow`].
+    pub temporal: TemporalCastPolicy,
+    /// default to false
+    /// whether temporal casts to a coarser unit floor values, so that values before the epoch
+    /// are cast to the start of their period (e.g. `-1ms` to `-1s`), instead of truncating
+    /// them towards zero (e.g. `-1ms` to `0s`).
+    pub floor_temporal: bool,
+}
+
+/// Policy of temporal casts that change the unit of their values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TemporalCastPolicy {
+    /// Values that overflow the target type are null (default)
+    #[default]
+    NullOnOverflow,
+    /// Errors with [`ArrowError::Overflow`] on values that overflow the target type
+    ErrorOnOverflow,
+    /// Errors on values that overflow the target type or that would lose precision
+    /// (e.g. a timestamp with a fractional number of seconds cast to seconds, or a
+    /// `Date64` that is not at midnight)
+    Exact,
 }
 
 impl CastOptions {
-    fn with_wrapped(&self, v: bool) -> Self {
-        let mut option = *self;
-        option.wrapped = v;
-        option
+    /// Sets [`CastOptions::wrapped`]
+    pub fn with_wrapped(mut self, wrapped: bool) -> Self {
+        self.wrapped = wrapped;
+        self
+    }
+
+    /// Sets [`CastOptions::partial`]
+    pub fn with_partial(mut self, partial: bool) -> Self {
+        self.partial = partial;
+        self
+    }
+
+    /// Sets [`CastOptions::temporal`]
+    pub fn with_temporal(mut self, temporal: TemporalCastPolicy) -> Self {
+        self.temporal = temporal;
+        self
+    }
+
+    /// Sets [`CastOptions::floor_temporal`]
+    pub fn with_floor_temporal(mut self, floor_temporal: bool) -> Self {
+        self.floor_temporal = floor_temporal;
+        self
     }
 }
 
@@ -68,6 +118,13 @@ macro_rules! primitive_dyn {
     }};
 }
 
+macro_rules! primitive_try_dyn {
+    ($from:expr, $expr:expr, $($arg:expr),+) => {{
+        let from = $from.as_any().downcast_ref().unwrap();
+        Ok(Box::new($expr(from, $($arg),+)?))
+    }};
+}
+
 /// Return true if a value of type `from_type` can be cast into a
 /// value of `to_type`. Note that such as cast may be lossy.
 ///
@@ -119,14 +176,18 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Utf8, Date64) => true,
         (Utf8, Timestamp(TimeUnit::Nanosecond, _)) => true,
         (Utf8, LargeUtf8) => true,
+        (Utf8, Duration(_)) => true,
         (Utf8, _) => is_numeric(to_type),
         (LargeUtf8, Date32) => true,
         (LargeUtf8, Date64) => true,
         (LargeUtf8, Timestamp(TimeUnit::Nanosecond, _)) => true,
         (LargeUtf8, Utf8) => true,
+        (LargeUtf8, Duration(_)) => true,
         (LargeUtf8, _) => is_numeric(to_type),
         (Timestamp(_, _), Utf8) => true,
         (Timestamp(_, _), LargeUtf8) => true,
+        (Duration(_), Utf8) => true,
+        (Duration(_), LargeUtf8) => true,
         (_, Utf8) => is_numeric(from_type) || from_type == &Binary,
         (_, LargeUtf8) => is_numeric(from_type) || from_type == &Binary,
 
@@ -275,6 +336,8 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Time64(_), Int64) => true,
         (Date32, Date64) => true,
         (Date64, Date32) => true,
+        (Date32, Timestamp(_, _)) => true,
+        (Date64, Timestamp(_, _)) => true,
         (Time32(TimeUnit::Second), Time32(TimeUnit::Millisecond)) => true,
         (Time32(TimeUnit::Millisecond), Time32(TimeUnit::Second)) => true,
         (Time32(_), Time64(_)) => true,
@@ -362,12 +425,17 @@ fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
+/// * Temporal unit changes: values are truncated (or floored, see
+///   [`CastOptions::floor_temporal`]) when going to a higher interval and overflows are
+///   handled according to [`CastOptions::temporal`]
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Duration to/from Utf8: ISO 8601 durations (e.g. `"PT1H30M"`); strings that can't be
+///   parsed return null
 /// Unsupported Casts
 /// * To or from `StructArray`
 /// * List to primitive
 /// * Utf8 to boolean
-/// * Interval and duration
+/// * Interval, and Duration other than to/from Int64 and Utf8
 pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Result<Box<dyn Array>> {
     use DataType::*;
     let from_type = array.data_type();
@@ -488,6 +556,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
                 utf8_to_timestamp_ns_dyn::<i32>(array, tz.clone())
             }
+            Duration(time_unit) => utf8_to_duration_dyn::<i32>(array, *time_unit),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -512,6 +581,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
                 utf8_to_timestamp_ns_dyn::<i64>(array, tz.clone())
             }
+            Duration(time_unit) => utf8_to_duration_dyn::<i64>(array, *time_unit),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -548,6 +618,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 let from = array.as_any().downcast_ref().unwrap();
                 Ok(Box::new(naive_timestamp_to_utf8::<i32>(from, *from_unit)))
             }
+            Duration(time_unit) => {
+                let from = array.as_any().downcast_ref().unwrap();
+                Ok(Box::new(duration_to_utf8::<i32>(from, *time_unit)))
+            }
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -584,6 +658,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 let from = array.as_any().downcast_ref().unwrap();
                 Ok(Box::new(naive_timestamp_to_utf8::<i64>(from, *from_unit)))
             }
+            Duration(time_unit) => {
+                let from = array.as_any().downcast_ref().unwrap();
+                Ok(Box::new(duration_to_utf8::<i64>(from, *time_unit)))
+            }
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -813,32 +891,97 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Date64, Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Time64(_), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Date32, Date64) => primitive_dyn!(array, date32_to_date64),
-        (Date64, Date32) => primitive_dyn!(array, date64_to_date32),
-        (Time32(TimeUnit::Second), Time32(TimeUnit::Millisecond)) => {
-            primitive_dyn!(array, time32s_to_time32ms)
-        }
-        (Time32(TimeUnit::Millisecond), Time32(TimeUnit::Second)) => {
-            primitive_dyn!(array, time32ms_to_time32s)
+        (Date64, Date32) => {
+            primitive_try_dyn!(
+                array,
+                date64_to_date32_with_policy,
+                options.temporal,
+                options.floor_temporal
+            )
         }
-        (Time32(from_unit), Time64(to_unit)) => {
-            primitive_dyn!(array, time32_to_time64, *from_unit, *to_unit)
+        (Date32, Timestamp(to_unit, tz)) => primitive_try_dyn!(
+            array,
+            date_to_timestamp::<i32>,
+            1,
+            *to_unit,
+            tz,
+            options.temporal,
+            options.floor_temporal
+        ),
+        (Date64, Timestamp(to_unit, tz)) => primitive_try_dyn!(
+            array,
+            date_to_timestamp::<i64>,
+            MILLISECONDS_IN_DAY,
+            *to_unit,
+            tz,
+            options.temporal,
+            options.floor_temporal
+        ),
+        (Time32(from_unit), Time32(_)) => {
+            primitive_try_dyn!(
+                array,
+                time_to_time::<i32, i32>,
+                *from_unit,
+                to_type.clone(),
+                options.temporal,
+                options.floor_temporal
+            )
         }
-        (Time64(TimeUnit::Microsecond), Time64(TimeUnit::Nanosecond)) => {
-            primitive_dyn!(array, time64us_to_time64ns)
+        (Time32(from_unit), Time64(_)) => {
+            primitive_try_dyn!(
+                array,
+                time_to_time::<i32, i64>,
+                *from_unit,
+                to_type.clone(),
+                options.temporal,
+                options.floor_temporal
+            )
         }
-        (Time64(TimeUnit::Nanosecond), Time64(TimeUnit::Microsecond)) => {
-            primitive_dyn!(array, time64ns_to_time64us)
+        (Time64(from_unit), Time64(_)) => {
+            primitive_try_dyn!(
+                array,
+                time_to_time::<i64, i64>,
+                *from_unit,
+                to_type.clone(),
+                options.temporal,
+                options.floor_temporal
+            )
         }
-        (Time64(from_unit), Time32(to_unit)) => {
-            primitive_dyn!(array, time64_to_time32, *from_unit, *to_unit)
+        (Time64(from_unit), Time32(_)) => {
+            primitive_try_dyn!(
+                array,
+                time_to_time::<i64, i32>,
+                *from_unit,
+                to_type.clone(),
+                options.temporal,
+                options.floor_temporal
+            )
         }
         (Timestamp(_, _), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Int64, Timestamp(_, _)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
-        (Timestamp(from_unit, _), Timestamp(to_unit, tz)) => {
-            primitive_dyn!(array, timestamp_to_timestamp, *from_unit, *to_unit, tz)
-        }
-        (Timestamp(from_unit, _), Date32) => primitive_dyn!(array, timestamp_to_date32, *from_unit),
-        (Timestamp(from_unit, _), Date64) => primitive_dyn!(array, timestamp_to_date64, *from_unit),
+        (Timestamp(from_unit, _), Timestamp(to_unit, tz)) => primitive_try_dyn!(
+            array,
+            timestamp_to_timestamp_with_policy,
+            *from_unit,
+            *to_unit,
+            tz,
+            options.temporal,
+            options.floor_temporal
+        ),
+        (Timestamp(from_unit, _), Date32) => primitive_try_dyn!(
+            array,
+            timestamp_to_date32_with_policy,
+            *from_unit,
+            options.temporal,
+            options.floor_temporal
+        ),
+        (Timestamp(from_unit, _), Date64) => primitive_try_dyn!(
+            array,
+            timestamp_to_date64_with_policy,
+            *from_unit,
+            options.temporal,
+            options.floor_temporal
+        ),
 
         (Int64, Duration(_)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Duration(_), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
@@ -857,6 +1000,38 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
     }
 }
 
+/// Lazily casts each array of `chunks` to `to_type`, as the returned iterator is consumed.
+///
+/// Unlike casting the concatenation of `chunks`, at most one (cast) chunk is in memory at a
+/// time, which allows converting streams of arrays (e.g. the columns of an IPC stream)
+/// between types without materializing them. See [`cast`] for the supported casts.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::cast::{cast_iter, CastOptions};
+/// use arrow2::datatypes::DataType;
+///
+/// let chunks: Vec<Box<dyn Array>> = vec![
+///     Box::new(Int32Array::from_slice([1, 2])),
+///     Box::new(Int32Array::from_slice([3])),
+/// ];
+/// let mut iter = cast_iter(chunks, DataType::Int64, CastOptions::default());
+/// assert_eq!(iter.next().unwrap().unwrap().data_type(), &DataType::Int64);
+/// ```
+pub fn cast_iter<I, A>(
+    chunks: I,
+    to_type: DataType,
+    options: CastOptions,
+) -> impl Iterator<Item = Result<Box<dyn Array>>>
+where
+    I: IntoIterator<Item = A>,
+    A: AsRef<dyn Array>,
+{
+    chunks
+        .into_iter()
+        .map(move |array| cast(array.as_ref(), &to_type, options))
+}
+
 /// Attempts to encode an array into an `ArrayDictionary` with index
 /// type K and value (dictionary) type value_type
 ///
@@ -0,0 +1,101 @@
+//! Casts between numeric types with an explicit handling of values that overflow the target type.
+use crate::{
+    array::{Array, PrimitiveArray},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+use super::{
+    cast_with_report, is_numeric, primitive_to_primitive_saturating, CastOptions, CastReport,
+};
+
+/// Casts `array` of a numeric type to another numeric type, clamping values that overflow
+/// `to_type` to its minimum or maximum value (e.g. `300i64` is cast to `127i8`).
+/// `NaN`s are cast to null.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int64Array, Int8Array};
+/// use arrow2::compute::cast::saturating_cast;
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Int64Array::from([Some(300), Some(-300), None, Some(1)]);
+/// let casted = saturating_cast(&array, &DataType::Int8).unwrap();
+/// assert_eq!(casted.as_ref(), &Int8Array::from([Some(127), Some(-128), None, Some(1)]) as &dyn Array);
+/// ```
+/// # Errors
+/// Errors iff `array` or `to_type` are not numeric.
+pub fn saturating_cast(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>> {
+    check_numeric("saturating_cast", array.data_type(), to_type)?;
+    use DataType::*;
+    match array.data_type() {
+        Int8 => saturating_cast_from::<i8>(array, to_type),
+        Int16 => saturating_cast_from::<i16>(array, to_type),
+        Int32 => saturating_cast_from::<i32>(array, to_type),
+        Int64 => saturating_cast_from::<i64>(array, to_type),
+        UInt8 => saturating_cast_from::<u8>(array, to_type),
+        UInt16 => saturating_cast_from::<u16>(array, to_type),
+        UInt32 => saturating_cast_from::<u32>(array, to_type),
+        UInt64 => saturating_cast_from::<u64>(array, to_type),
+        Float32 => saturating_cast_from::<f32>(array, to_type),
+        Float64 => saturating_cast_from::<f64>(array, to_type),
+        _ => unreachable!(),
+    }
+}
+
+fn saturating_cast_from<I>(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>>
+where
+    I: NativeType + num_traits::NumCast + num_traits::Zero + PartialOrd,
+{
+    let from = array.as_any().downcast_ref::<PrimitiveArray<I>>().unwrap();
+    use DataType::*;
+    Ok(match to_type {
+        Int8 => Box::new(primitive_to_primitive_saturating::<I, i8>(from, to_type)),
+        Int16 => Box::new(primitive_to_primitive_saturating::<I, i16>(from, to_type)),
+        Int32 => Box::new(primitive_to_primitive_saturating::<I, i32>(from, to_type)),
+        Int64 => Box::new(primitive_to_primitive_saturating::<I, i64>(from, to_type)),
+        UInt8 => Box::new(primitive_to_primitive_saturating::<I, u8>(from, to_type)),
+        UInt16 => Box::new(primitive_to_primitive_saturating::<I, u16>(from, to_type)),
+        UInt32 => Box::new(primitive_to_primitive_saturating::<I, u32>(from, to_type)),
+        UInt64 => Box::new(primitive_to_primitive_saturating::<I, u64>(from, to_type)),
+        Float32 => Box::new(primitive_to_primitive_saturating::<I, f32>(from, to_type)),
+        Float64 => Box::new(primitive_to_primitive_saturating::<I, f64>(from, to_type)),
+        _ => unreachable!(),
+    })
+}
+
+/// Casts `array` of a numeric type to another numeric type, casting values that overflow
+/// `to_type` to null, and returns a [`CastReport`] with the slots of those values.
+/// Use it to narrow columns (e.g. `Int64` to `Int32`) and know which values did not fit.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int64Array, Int32Array};
+/// use arrow2::compute::cast::checked_cast_with_report;
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Int64Array::from([Some(1), Some(i64::MAX), None]);
+/// let (casted, report) = checked_cast_with_report(&array, &DataType::Int32).unwrap();
+/// assert_eq!(casted.as_ref(), &Int32Array::from([Some(1), None, None]) as &dyn Array);
+/// assert_eq!(report.failure_count(), 1);
+/// assert!(report.failures().unwrap().get_bit(1));
+/// ```
+/// # Errors
+/// Errors iff `array` or `to_type` are not numeric.
+pub fn checked_cast_with_report(
+    array: &dyn Array,
+    to_type: &DataType,
+) -> Result<(Box<dyn Array>, CastReport)> {
+    check_numeric("checked_cast_with_report", array.data_type(), to_type)?;
+    cast_with_report(array, to_type, CastOptions::default())
+}
+
+fn check_numeric(name: &str, from_type: &DataType, to_type: &DataType) -> Result<()> {
+    if is_numeric(from_type) && is_numeric(to_type) {
+        Ok(())
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "`{}` only supports numeric types, but got a cast from {:?} to {:?}",
+            name, from_type, to_type
+        )))
+    }
+}
@@ -3,18 +3,18 @@ use std::hash::Hash;
 use num_traits::{AsPrimitive, Float, ToPrimitive};
 
 use crate::datatypes::IntervalUnit;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::types::{days_ms, months_days_ns};
 use crate::{
     array::*,
     bitmap::Bitmap,
-    compute::arity::unary,
+    compute::arity::{unary, unary_checked},
     datatypes::{DataType, TimeUnit},
     temporal_conversions::*,
     types::NativeType,
 };
 
-use super::CastOptions;
+use super::{CastOptions, TemporalCastPolicy};
 
 /// Returns a [`BinaryArray`] where every element is the binary representation of the number.
 pub fn primitive_to_binary<T: NativeType + lexical_core::ToLexical, O: Offset>(
@@ -157,6 +157,33 @@ where
     PrimitiveArray::<O>::from_trusted_len_iter(iter).to(to_type.clone())
 }
 
+/// Cast [`PrimitiveArray`] to a [`PrimitiveArray`] of another physical type via numeric conversion,
+/// clamping values that overflow the target type to its minimum or maximum value.
+/// `NaN`s are cast to null.
+pub fn primitive_to_primitive_saturating<I, O>(
+    from: &PrimitiveArray<I>,
+    to_type: &DataType,
+) -> PrimitiveArray<O>
+where
+    I: NativeType + num_traits::NumCast + num_traits::Zero + PartialOrd,
+    O: NativeType + num_traits::NumCast + num_traits::Bounded,
+{
+    let iter = from.iter().map(|v| {
+        v.and_then(|x| {
+            num_traits::cast::cast::<I, O>(*x).or_else(|| {
+                if *x < I::zero() {
+                    Some(O::min_value())
+                } else if *x > I::zero() {
+                    Some(O::max_value())
+                } else {
+                    None
+                }
+            })
+        })
+    });
+    PrimitiveArray::<O>::from_trusted_len_iter(iter).to(to_type.clone())
+}
+
 /// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
 pub fn integer_to_decimal<T: NativeType + AsPrimitive<i128>>(
     from: &PrimitiveArray<T>,
@@ -319,16 +346,135 @@ const fn time_unit_multiple(unit: TimeUnit) -> i64 {
     }
 }
 
+/// The number of ticks of `unit` in a day
+const fn ticks_per_day(unit: TimeUnit) -> i64 {
+    time_unit_multiple(unit) * SECONDS_IN_DAY
+}
+
+/// Returns `x`, with `from_size` ticks per day, with `to_size` ticks per day, or `None` if it
+/// overflows. Values converted to a coarser unit are floored when `floor`, else truncated.
+#[inline]
+fn scale(x: i64, from_size: i64, to_size: i64, floor: bool) -> Option<i64> {
+    if from_size >= to_size {
+        let factor = from_size / to_size;
+        Some(if floor {
+            x.div_euclid(factor)
+        } else {
+            x / factor
+        })
+    } else {
+        x.checked_mul(to_size / from_size)
+    }
+}
+
+/// Converts temporal values with `from_size` ticks per day to values with `to_size`
+/// ticks per day, according to `policy`.
+/// Values converted to a coarser unit are truncated towards zero or, when `floor`, floored, so
+/// that negative values (before the epoch) are converted to the start of their period.
+fn rescale<I, O>(
+    from: &PrimitiveArray<I>,
+    from_size: i64,
+    to_size: i64,
+    policy: TemporalCastPolicy,
+    floor: bool,
+    to_type: DataType,
+) -> Result<PrimitiveArray<O>>
+where
+    I: NativeType + Into<i64>,
+    O: NativeType + TryFrom<i64>,
+{
+    if policy == TemporalCastPolicy::NullOnOverflow {
+        return Ok(unary_checked(
+            from,
+            |x| scale(x.into(), from_size, to_size, floor).and_then(|x| O::try_from(x).ok()),
+            to_type,
+        ));
+    }
+
+    let convert = |x: i64| -> Result<Option<O>> {
+        if policy == TemporalCastPolicy::Exact
+            && from_size > to_size
+            && x.rem_euclid(from_size / to_size) != 0
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The value {} of type {:?} cannot be cast to {:?} without losing precision",
+                x,
+                from.data_type(),
+                to_type
+            )));
+        }
+        match scale(x, from_size, to_size, floor).and_then(|x| O::try_from(x).ok()) {
+            Some(x) => Ok(Some(x)),
+            None => Err(ArrowError::Overflow),
+        }
+    };
+
+    let values = from
+        .iter()
+        .map(|x| match x {
+            Some(x) => convert((*x).into()),
+            None => Ok(None),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PrimitiveArray::<O>::from(values).to(to_type))
+}
+
 /// Conversion of dates
 pub fn date32_to_date64(from: &PrimitiveArray<i32>) -> PrimitiveArray<i64> {
     unary(from, |x| x as i64 * MILLISECONDS_IN_DAY, DataType::Date64)
 }
 
-/// Conversion of dates
+/// Conversion of dates. Values that are not at midnight are truncated towards zero.
 pub fn date64_to_date32(from: &PrimitiveArray<i64>) -> PrimitiveArray<i32> {
     unary(from, |x| (x / MILLISECONDS_IN_DAY) as i32, DataType::Date32)
 }
 
+/// Normalizes a [`DataType::Date64`] array so that all its values are at midnight,
+/// flooring values to the start of their day.
+pub fn date64_normalize(from: &PrimitiveArray<i64>) -> PrimitiveArray<i64> {
+    unary(
+        from,
+        |x| x - x.rem_euclid(MILLISECONDS_IN_DAY),
+        DataType::Date64,
+    )
+}
+
+/// Conversion of dates according to `policy`
+pub(super) fn date64_to_date32_with_policy(
+    from: &PrimitiveArray<i64>,
+    policy: TemporalCastPolicy,
+    floor: bool,
+) -> Result<PrimitiveArray<i32>> {
+    rescale(
+        from,
+        MILLISECONDS_IN_DAY,
+        1,
+        policy,
+        floor,
+        DataType::Date32,
+    )
+}
+
+/// Conversion of dates to timestamps, at midnight of each day
+pub(super) fn date_to_timestamp<I: NativeType + Into<i64>>(
+    from: &PrimitiveArray<I>,
+    from_size: i64,
+    to_unit: TimeUnit,
+    tz: &Option<String>,
+    policy: TemporalCastPolicy,
+    floor: bool,
+) -> Result<PrimitiveArray<i64>> {
+    let days = rescale::<I, i64>(from, from_size, 1, policy, floor, DataType::Int64)?;
+    rescale(
+        &days,
+        1,
+        ticks_per_day(to_unit),
+        policy,
+        floor,
+        DataType::Timestamp(to_unit, tz.clone()),
+    )
+}
+
 /// Conversion of times
 pub fn time32s_to_time32ms(from: &PrimitiveArray<i32>) -> PrimitiveArray<i32> {
     unary(from, |x| x * 1000, DataType::Time32(TimeUnit::Millisecond))
@@ -349,27 +495,97 @@ pub fn time64ns_to_time64us(from: &PrimitiveArray<i64>) -> PrimitiveArray<i64> {
     unary(from, |x| x / 1000, DataType::Time64(TimeUnit::Microsecond))
 }
 
-/// Conversion of timestamp
-pub fn timestamp_to_date64(from: &PrimitiveArray<i64>, from_unit: TimeUnit) -> PrimitiveArray<i64> {
-    let from_size = time_unit_multiple(from_unit);
-    let to_size = MILLISECONDS;
-    let to_type = DataType::Date64;
+/// Conversion of times between any two units according to `policy`
+pub(super) fn time_to_time<I, O>(
+    from: &PrimitiveArray<I>,
+    from_unit: TimeUnit,
+    to_type: DataType,
+    policy: TemporalCastPolicy,
+    floor: bool,
+) -> Result<PrimitiveArray<O>>
+where
+    I: NativeType + Into<i64>,
+    O: NativeType + TryFrom<i64>,
+{
+    let to_unit = match &to_type {
+        DataType::Time32(unit) | DataType::Time64(unit) => *unit,
+        _ => unreachable!(),
+    };
+    rescale(
+        from,
+        time_unit_multiple(from_unit),
+        time_unit_multiple(to_unit),
+        policy,
+        floor,
+        to_type,
+    )
+}
 
-    // Scale time_array by (to_size / from_size) using a
-    // single integer operation, but need to avoid integer
-    // math rounding down to zero
+/// Conversion of timestamp. The time of the day is dropped, i.e. values are truncated towards
+/// zero to midnight, and values that overflow are null.
+pub fn timestamp_to_date64(from: &PrimitiveArray<i64>, from_unit: TimeUnit) -> PrimitiveArray<i64> {
+    let from_size = ticks_per_day(from_unit);
+    unary_checked(
+        from,
+        |x| (x / from_size).checked_mul(MILLISECONDS_IN_DAY),
+        DataType::Date64,
+    )
+}
 
-    match to_size.cmp(&from_size) {
-        std::cmp::Ordering::Less => unary(from, |x| (x / (from_size / to_size)), to_type),
-        std::cmp::Ordering::Equal => primitive_to_same_primitive(from, &to_type),
-        std::cmp::Ordering::Greater => unary(from, |x| (x * (to_size / from_size)), to_type),
+/// Conversion of timestamp to dates according to `policy`
+pub(super) fn timestamp_to_date64_with_policy(
+    from: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+    policy: TemporalCastPolicy,
+    floor: bool,
+) -> Result<PrimitiveArray<i64>> {
+    if policy == TemporalCastPolicy::NullOnOverflow && !floor {
+        return Ok(timestamp_to_date64(from, from_unit));
     }
+    let days = rescale::<i64, i64>(
+        from,
+        ticks_per_day(from_unit),
+        1,
+        policy,
+        floor,
+        DataType::Int64,
+    )?;
+    rescale(
+        &days,
+        1,
+        MILLISECONDS_IN_DAY,
+        policy,
+        floor,
+        DataType::Date64,
+    )
 }
 
-/// Conversion of timestamp
+/// Conversion of timestamp. The time of the day is dropped, i.e. values are truncated towards
+/// zero to their day, and values that overflow are null.
 pub fn timestamp_to_date32(from: &PrimitiveArray<i64>, from_unit: TimeUnit) -> PrimitiveArray<i32> {
-    let from_size = time_unit_multiple(from_unit) * SECONDS_IN_DAY;
-    unary(from, |x| (x / from_size) as i32, DataType::Date32)
+    let from_size = ticks_per_day(from_unit);
+    unary_checked(
+        from,
+        |x| i32::try_from(x / from_size).ok(),
+        DataType::Date32,
+    )
+}
+
+/// Conversion of timestamp to dates according to `policy`
+pub(super) fn timestamp_to_date32_with_policy(
+    from: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+    policy: TemporalCastPolicy,
+    floor: bool,
+) -> Result<PrimitiveArray<i32>> {
+    rescale(
+        from,
+        ticks_per_day(from_unit),
+        1,
+        policy,
+        floor,
+        DataType::Date32,
+    )
 }
 
 /// Conversion of time
@@ -400,7 +616,8 @@ pub fn time64_to_time32(
     )
 }
 
-/// Conversion of timestamp
+/// Conversion of timestamp. Values converted to a coarser unit are truncated towards zero
+/// (e.g. `-1ms` is cast to `0s`) and values that overflow when converted to a finer unit are null.
 pub fn timestamp_to_timestamp(
     from: &PrimitiveArray<i64>,
     from_unit: TimeUnit,
@@ -412,12 +629,36 @@ pub fn timestamp_to_timestamp(
     let to_type = DataType::Timestamp(to_unit, tz.clone());
     // we either divide or multiply, depending on size of each unit
     if from_size >= to_size {
-        unary(from, |x| (x / (from_size / to_size)), to_type)
+        let factor = from_size / to_size;
+        unary(from, |x| x / factor, to_type)
     } else {
-        unary(from, |x| (x * (to_size / from_size)), to_type)
+        let factor = to_size / from_size;
+        unary_checked(from, |x| x.checked_mul(factor), to_type)
     }
 }
 
+/// Conversion of timestamp according to `policy`
+pub(super) fn timestamp_to_timestamp_with_policy(
+    from: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+    to_unit: TimeUnit,
+    tz: &Option<String>,
+    policy: TemporalCastPolicy,
+    floor: bool,
+) -> Result<PrimitiveArray<i64>> {
+    if policy == TemporalCastPolicy::NullOnOverflow && !floor {
+        return Ok(timestamp_to_timestamp(from, from_unit, to_unit, tz));
+    }
+    rescale(
+        from,
+        time_unit_multiple(from_unit),
+        time_unit_multiple(to_unit),
+        policy,
+        floor,
+        DataType::Timestamp(to_unit, tz.clone()),
+    )
+}
+
 fn timestamp_to_utf8_impl<O: Offset, T: chrono::TimeZone>(
     from: &PrimitiveArray<i64>,
     time_unit: TimeUnit,
@@ -554,6 +795,18 @@ pub fn naive_timestamp_to_utf8<O: Offset>(
     }
 }
 
+/// Returns a [`Utf8Array`] where every element is the ISO 8601 representation of the
+/// duration, e.g. `"PT1H30M"` (see [`crate::temporal_conversions::duration_to_iso8601`]).
+pub fn duration_to_utf8<O: Offset>(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+) -> Utf8Array<O> {
+    let iter = from
+        .iter()
+        .map(|x| x.map(|x| duration_to_iso8601(*x, time_unit)));
+    Utf8Array::from_trusted_len_iter(iter)
+}
+
 #[inline]
 fn days_ms_to_months_days_ns_scalar(from: days_ms) -> months_days_ns {
     months_days_ns::new(0, from.days(), from.milliseconds() as i64 * 1000)
@@ -0,0 +1,103 @@
+use crate::{
+    array::{Array, DictionaryArray, DictionaryKey},
+    bitmap::Bitmap,
+    datatypes::DataType,
+    error::Result,
+};
+
+use super::{cast, CastOptions};
+
+/// The slots of a cast whose (non-null) values could not be cast and became null,
+/// returned by [`cast_with_report`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CastReport {
+    failures: Option<Bitmap>,
+}
+
+impl CastReport {
+    /// Returns a [`Bitmap`] whose set bits are the slots whose value failed to be cast,
+    /// or `None` if all values were cast.
+    pub fn failures(&self) -> Option<&Bitmap> {
+        self.failures.as_ref()
+    }
+
+    /// Returns the number of values that failed to be cast.
+    pub fn failure_count(&self) -> usize {
+        self.failures
+            .as_ref()
+            .map(|x| x.len() - x.null_count())
+            .unwrap_or(0)
+    }
+
+    /// Returns whether all values were cast.
+    pub fn is_empty(&self) -> bool {
+        self.failures.is_none()
+    }
+}
+
+/// Returns the validity of the values of `array`, taking the values of dictionaries into account.
+fn logical_validity(array: &dyn Array) -> Option<Bitmap> {
+    match array.data_type().to_logical_type() {
+        DataType::Null => Some(Bitmap::new_zeroed(array.len())),
+        DataType::Dictionary(key_type, _, _) => {
+            match_integer_type!(key_type, |$T| {
+                dictionary_validity::<$T>(array.as_any().downcast_ref().unwrap())
+            })
+        }
+        _ => array.validity().cloned(),
+    }
+}
+
+fn dictionary_validity<K: DictionaryKey>(array: &DictionaryArray<K>) -> Option<Bitmap> {
+    let values = array.values();
+    if values.null_count() == 0 {
+        return array.keys().validity().cloned();
+    }
+    Some(
+        array
+            .keys()
+            .iter()
+            .map(|key| matches!(key, Some(key) if values.is_valid(key.to_usize().unwrap())))
+            .collect(),
+    )
+}
+
+/// Same as [`cast`], but also returns a [`CastReport`] with the slots whose (non-null) value
+/// could not be cast, e.g. unparseable strings or values that overflow the target type, and
+/// thus became null. Use it to quarantine invalid values instead of failing on them.
+///
+/// When `options.wrapped` is set, overflowing values wrap around and are thus not failures.
+/// Casts to [`DataType::Null`] do not report failures.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::compute::cast::{cast_with_report, CastOptions};
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Utf8Array::<i32>::from([Some("1"), Some("a"), None, Some("3")]);
+/// let (casted, report) = cast_with_report(&array, &DataType::Int32, CastOptions::default()).unwrap();
+/// assert_eq!(casted.as_ref(), &Int32Array::from([Some(1), None, None, Some(3)]) as &dyn Array);
+/// assert_eq!(report.failure_count(), 1);
+/// assert!(report.failures().unwrap().get_bit(1));
+/// ```
+/// # Errors
+/// Errors iff the cast is not supported.
+pub fn cast_with_report(
+    array: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<(Box<dyn Array>, CastReport)> {
+    let casted = cast(array, to_type, options)?;
+    if to_type == &DataType::Null {
+        return Ok((casted, CastReport::default()));
+    }
+
+    let failures = match (logical_validity(array), logical_validity(casted.as_ref())) {
+        (_, None) => None,
+        (None, Some(casted)) => Some(!&casted),
+        (Some(validity), Some(casted)) => Some(&validity & &(!&casted)),
+    };
+    let failures = failures.filter(|x| x.null_count() != x.len());
+
+    Ok((casted, CastReport { failures }))
+}
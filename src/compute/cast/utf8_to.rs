@@ -4,10 +4,10 @@ use chrono::Datelike;
 
 use crate::{
     array::*,
-    datatypes::DataType,
+    datatypes::{DataType, TimeUnit},
     error::{ArrowError, Result},
     temporal_conversions::{
-        utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
+        iso8601_to_duration, utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
         utf8_to_timestamp_ns as utf8_to_timestamp_ns_, EPOCH_DAYS_FROM_CE,
     },
     types::NativeType,
@@ -144,6 +144,28 @@ pub fn utf8_to_timestamp_ns<O: Offset>(
     utf8_to_timestamp_ns_(from, RFC3339, timezone)
 }
 
+pub(super) fn utf8_to_duration_dyn<O: Offset>(
+    from: &dyn Array,
+    time_unit: TimeUnit,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(utf8_to_duration::<O>(from, time_unit)))
+}
+
+/// Casts a [`Utf8Array`] of ISO 8601 durations (e.g. `"PT1H30M"`) to a [`PrimitiveArray`] of
+/// [`DataType::Duration`], making any unparsable value a Null.
+/// See [`crate::temporal_conversions::iso8601_to_duration`] for the supported durations.
+pub fn utf8_to_duration<O: Offset>(
+    from: &Utf8Array<O>,
+    time_unit: TimeUnit,
+) -> PrimitiveArray<i64> {
+    let iter = from
+        .iter()
+        .map(|x| x.and_then(|x| iso8601_to_duration(x, time_unit)));
+
+    PrimitiveArray::<i64>::from_trusted_len_iter(iter).to(DataType::Duration(time_unit))
+}
+
 /// Conversion of utf8
 pub fn utf8_to_large_utf8(from: &Utf8Array<i32>) -> Utf8Array<i64> {
     let data_type = Utf8Array::<i64>::default_data_type();
@@ -0,0 +1,178 @@
+//! Contains the operator [`coalesce`].
+use crate::array::{growable, Array};
+use crate::compute::cast::{cast, CastOptions};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// Returns the bit width and signedness of integer types
+fn integer_width(data_type: &DataType) -> Option<(usize, bool)> {
+    use DataType::*;
+    Some(match data_type {
+        Int8 => (8, true),
+        Int16 => (16, true),
+        Int32 => (32, true),
+        Int64 => (64, true),
+        UInt8 => (8, false),
+        UInt16 => (16, false),
+        UInt32 => (32, false),
+        UInt64 => (64, false),
+        _ => return None,
+    })
+}
+
+fn integer_type(width: usize, signed: bool) -> DataType {
+    use DataType::*;
+    match (width, signed) {
+        (8, true) => Int8,
+        (16, true) => Int16,
+        (32, true) => Int32,
+        (8, false) => UInt8,
+        (16, false) => UInt16,
+        (32, false) => UInt32,
+        (64, true) => Int64,
+        (64, false) => UInt64,
+        _ => unreachable!(),
+    }
+}
+
+/// Returns the [`DataType`] that all `data_types` can be cast to without loss of
+/// information (when it exists), following SQL's type promotion rules:
+/// * [`DataType::Null`] is promoted to any other type
+/// * integers are promoted to the smallest integer that can hold all of them, or to
+///   `Decimal(20, 0)` when none can (`UInt64` mixed with signed integers)
+/// * integers and floats are promoted to `Float64` (`Float32` when all floats are `Float32`
+///   and all integers have at most 16 bits)
+/// * `Utf8` and `LargeUtf8` are promoted to `LargeUtf8` and `Binary` and `LargeBinary` to
+///   `LargeBinary`
+///
+/// Returns `None` when the types can't be unified.
+pub fn coalesce_data_type(data_types: &[&DataType]) -> Option<DataType> {
+    use DataType::*;
+    let data_types = data_types
+        .iter()
+        .filter(|x| ***x != Null)
+        .collect::<Vec<_>>();
+    let first = match data_types.first() {
+        Some(first) => **first,
+        None => return Some(Null),
+    };
+    if data_types.iter().all(|x| *x == &first) {
+        return Some(first.clone());
+    }
+
+    if data_types.iter().all(|x| matches!(x, Utf8 | LargeUtf8)) {
+        return Some(LargeUtf8);
+    }
+    if data_types.iter().all(|x| matches!(x, Binary | LargeBinary)) {
+        return Some(LargeBinary);
+    }
+
+    let mut signed_width = 0;
+    let mut unsigned_width = 0;
+    let mut float_width = 0;
+    for data_type in data_types {
+        match (data_type, integer_width(data_type)) {
+            (_, Some((width, true))) => signed_width = signed_width.max(width),
+            (_, Some((width, false))) => unsigned_width = unsigned_width.max(width),
+            (Float32, _) => float_width = float_width.max(32),
+            (Float64, _) => float_width = float_width.max(64),
+            _ => return None,
+        }
+    }
+    let integer_width = if signed_width == 0 {
+        unsigned_width
+    } else {
+        // an unsigned integer needs a signed integer twice as wide
+        signed_width.max(2 * unsigned_width)
+    };
+
+    Some(match float_width {
+        // `Decimal(20, 0)` holds both `u64::MAX` and `i64::MIN`
+        0 if integer_width > 64 => Decimal(20, 0),
+        0 => integer_type(integer_width, signed_width > 0),
+        32 if integer_width <= 16 => Float32,
+        _ => Float64,
+    })
+}
+
+/// Returns an array whose slot `i` is the first non-null slot `i` of `arrays`, or null if
+/// all of them are null. This has the same semantics as SQL's `COALESCE`.
+///
+/// The arrays are cast to a common type, as described by [`coalesce_data_type`].
+/// # Errors
+/// This function errors iff
+/// * `arrays` is empty
+/// * the arrays do not have the same length
+/// * the arrays' types can't be unified (see [`coalesce_data_type`])
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::compute::coalesce::coalesce;
+/// use arrow2::array::{Int32Array, Int64Array};
+///
+/// # fn main() -> Result<()> {
+/// let a = Int32Array::from(&[Some(1), None, None]);
+/// let b = Int64Array::from(&[Some(4), Some(5), None]);
+/// let result = coalesce(&[&a, &b])?;
+///
+/// let expected = Int64Array::from(&[Some(1), Some(5), None]);
+///
+/// assert_eq!(expected, result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    let length = arrays
+        .first()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError("coalesce requires at least one array".to_string())
+        })?
+        .len();
+    if let Some(array) = arrays.iter().find(|array| array.len() != length) {
+        return Err(ArrowError::LengthMismatch {
+            expected: length,
+            found: array.len(),
+        });
+    }
+
+    let data_types = arrays.iter().map(|x| x.data_type()).collect::<Vec<_>>();
+    let data_type = coalesce_data_type(&data_types).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!("coalesce can't unify the types {:?}", data_types))
+    })?;
+
+    let arrays = arrays
+        .iter()
+        .map(|array| cast(*array, &data_type, CastOptions::default()))
+        .collect::<Result<Vec<_>>>()?;
+    let arrays = arrays.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+
+    // the index of the array to take each slot from (`None` when all are null), in runs
+    let mut growable = growable::make_growable(&arrays, true, length);
+    let mut run: Option<(Option<usize>, usize, usize)> = None;
+    for row in 0..length {
+        let index = arrays.iter().position(|array| array.is_valid(row));
+        run = match run {
+            Some((current, start, len)) if current == index => Some((current, start, len + 1)),
+            previous => {
+                if let Some(previous) = previous {
+                    extend(growable.as_mut(), previous);
+                }
+                Some((index, row, 1))
+            }
+        };
+    }
+    if let Some(run) = run {
+        extend(growable.as_mut(), run);
+    }
+    Ok(growable.as_box())
+}
+
+fn extend(
+    growable: &mut dyn growable::Growable,
+    (index, start, len): (Option<usize>, usize, usize),
+) {
+    match index {
+        Some(index) => growable.extend(index, start, len),
+        None => growable.extend_validity(len),
+    }
+}
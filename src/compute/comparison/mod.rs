@@ -57,7 +57,7 @@ mod simd;
 pub use simd::{Simd8, Simd8Lanes, Simd8PartialEq, Simd8PartialOrd};
 
 use super::take::take_boolean;
-use crate::bitmap::Bitmap;
+use crate::bitmap::{ternary, Bitmap};
 use crate::compute;
 pub(crate) use primitive::{
     compare_values_op as primitive_compare_values_op,
@@ -169,7 +169,8 @@ pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
     compare!(lhs, rhs, eq, match_eq)
 }
 
-/// `==` between two [`Array`]s and includes validities in comparison.
+/// `==` between two [`Array`]s and includes validities in comparison: two nulls are equal and
+/// a null is different from any value, i.e. SQL's `IS NOT DISTINCT FROM`. The result has no nulls.
 /// Use [`can_eq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
@@ -196,7 +197,8 @@ pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
     compare!(lhs, rhs, neq, match_eq)
 }
 
-/// `!=` between two [`Array`]s and includes validities in comparison.
+/// `!=` between two [`Array`]s and includes validities in comparison: two nulls are equal and
+/// a null is different from any value, i.e. SQL's `IS DISTINCT FROM`. The result has no nulls.
 /// Use [`can_neq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
@@ -344,13 +346,17 @@ pub fn eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
     compare_scalar!(lhs, rhs, eq_scalar, match_eq)
 }
 
-/// `==` between an [`Array`] and a [`Scalar`] and includes validities in comparison.
+/// `==` between an [`Array`] and a [`Scalar`] and includes validities in comparison: two nulls
+/// are equal and a null is different from any value. The result has no nulls.
 /// Use [`can_eq_scalar`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
 pub fn eq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    if !rhs.is_valid() {
+        return compute::boolean::is_null(lhs);
+    }
     compare_scalar!(lhs, rhs, eq_scalar_and_validity, match_eq)
 }
 
@@ -369,13 +375,17 @@ pub fn neq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
     compare_scalar!(lhs, rhs, neq_scalar, match_eq)
 }
 
-/// `!=` between an [`Array`] and a [`Scalar`] and includes validities in comparison.
+/// `!=` between an [`Array`] and a [`Scalar`] and includes validities in comparison: two nulls
+/// are equal and a null is different from any value. The result has no nulls.
 /// Use [`can_neq_scalar`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
 pub fn neq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    if !rhs.is_valid() {
+        return compute::boolean::is_not_null(lhs);
+    }
     compare_scalar!(lhs, rhs, neq_scalar_and_validity, match_eq)
 }
 
@@ -520,10 +530,15 @@ fn finish_eq_validities(
         )
         .unwrap(),
         (Some(lhs), Some(rhs)) => {
-            let lhs = BooleanArray::new(DataType::Boolean, lhs, None);
-            let rhs = BooleanArray::new(DataType::Boolean, rhs, None);
-            let eq_validities = compute::comparison::boolean::eq(&lhs, &rhs);
-            compute::boolean::and(&output_without_validities, &eq_validities).unwrap()
+            // equal iff both are valid and equal or both are null, irrespectively of the
+            // values behind the nulls
+            let values = ternary(
+                output_without_validities.values(),
+                &lhs,
+                &rhs,
+                |out, lhs, rhs| (out & lhs & rhs) | !(lhs | rhs),
+            );
+            BooleanArray::new(DataType::Boolean, values, None)
         }
     }
 }
@@ -545,10 +560,15 @@ fn finish_neq_validities(
             compute::boolean::or(&output_without_validities, &rhs_negated).unwrap()
         }
         (Some(lhs), Some(rhs)) => {
-            let lhs = BooleanArray::new(DataType::Boolean, lhs, None);
-            let rhs = BooleanArray::new(DataType::Boolean, rhs, None);
-            let neq_validities = compute::comparison::boolean::neq(&lhs, &rhs);
-            compute::boolean::or(&output_without_validities, &neq_validities).unwrap()
+            // different iff both are valid and different or only one of them is null,
+            // irrespectively of the values behind the nulls
+            let values = ternary(
+                output_without_validities.values(),
+                &lhs,
+                &rhs,
+                |out, lhs, rhs| (out & lhs & rhs) | (lhs ^ rhs),
+            );
+            BooleanArray::new(DataType::Boolean, values, None)
         }
     }
 }
@@ -13,7 +13,10 @@
 //! assert_eq!(arr.len(), 3);
 //! ```
 
+use std::collections::VecDeque;
+
 use crate::array::{growable::make_growable, Array};
+use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 
 /// Concatenate multiple [Array] of the same type into a single [`Array`].
@@ -24,13 +27,14 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
         ));
     }
 
-    if arrays
+    if let Some(array) = arrays
         .iter()
-        .any(|array| array.data_type() != arrays[0].data_type())
+        .find(|array| array.data_type() != arrays[0].data_type())
     {
-        return Err(ArrowError::InvalidArgumentError(
-            "It is not possible to concatenate arrays of different data types.".to_string(),
-        ));
+        return Err(ArrowError::DataTypeMismatch {
+            expected: Box::new(arrays[0].data_type().clone()),
+            found: Box::new(array.data_type().clone()),
+        });
     }
 
     let lengths = arrays.iter().map(|array| array.len()).collect::<Vec<_>>();
@@ -44,3 +48,147 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
 
     Ok(mutable.as_box())
 }
+
+/// Lazily concatenates the arrays of `chunks` into arrays of `chunk_size` slots (the last
+/// one may be shorter), as the returned iterator is consumed.
+///
+/// At most `chunk_size` slots of `chunks` are buffered at a time, so this can be used to
+/// re-chunk streams of arrays of the same type without materializing them. Chunks that
+/// are contained in a single array of `chunks` are sliced instead of copied.
+/// # Errors
+/// This function errors if `chunk_size` is zero. The iterator errors when an array of
+/// `chunks` has a different [`DataType`] than the first one.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::concatenate::concatenate_iter;
+///
+/// let chunks: Vec<Box<dyn Array>> = vec![
+///     Box::new(Int32Array::from_slice([1, 2, 3])),
+///     Box::new(Int32Array::from_slice([4, 5])),
+/// ];
+/// let lengths = concatenate_iter(chunks, 2)
+///     .unwrap()
+///     .map(|array| array.unwrap().len())
+///     .collect::<Vec<_>>();
+/// assert_eq!(lengths, vec![2, 2, 1]);
+/// ```
+pub fn concatenate_iter<I, A>(
+    chunks: I,
+    chunk_size: usize,
+) -> Result<ConcatenateIter<I::IntoIter, A>>
+where
+    I: IntoIterator<Item = A>,
+    A: AsRef<dyn Array>,
+{
+    if chunk_size == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "concatenate_iter requires a chunk size larger than zero".to_string(),
+        ));
+    }
+    Ok(ConcatenateIter {
+        iter: chunks.into_iter(),
+        chunk_size,
+        pending: VecDeque::new(),
+        offset: 0,
+        pending_len: 0,
+        data_type: None,
+    })
+}
+
+/// [`Iterator`] of arrays returned by [`concatenate_iter`].
+pub struct ConcatenateIter<I: Iterator<Item = A>, A: AsRef<dyn Array>> {
+    iter: I,
+    chunk_size: usize,
+    // arrays whose slots were not yet returned; the slots of the first one start at `offset`
+    pending: VecDeque<A>,
+    offset: usize,
+    // number of slots in `pending` that were not yet returned
+    pending_len: usize,
+    data_type: Option<DataType>,
+}
+
+impl<I: Iterator<Item = A>, A: AsRef<dyn Array>> ConcatenateIter<I, A> {
+    /// Buffers arrays from `iter` until there are `chunk_size` pending slots.
+    fn fill(&mut self) -> Result<()> {
+        while self.pending_len < self.chunk_size {
+            let array = match self.iter.next() {
+                Some(array) => array,
+                None => return Ok(()),
+            };
+            let data_type = array.as_ref().data_type();
+            match &self.data_type {
+                Some(expected) if expected != data_type => {
+                    return Err(ArrowError::DataTypeMismatch {
+                        expected: Box::new(expected.clone()),
+                        found: Box::new(data_type.clone()),
+                    })
+                }
+                Some(_) => {}
+                None => self.data_type = Some(data_type.clone()),
+            }
+            let length = array.as_ref().len();
+            if length > 0 {
+                self.pending_len += length;
+                self.pending.push_back(array);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<I: Iterator<Item = A>, A: AsRef<dyn Array>> Iterator for ConcatenateIter<I, A> {
+    type Item = Result<Box<dyn Array>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Err(e) = self.fill() {
+            return Some(Err(e));
+        }
+        if self.pending_len == 0 {
+            return None;
+        }
+        let length = self.pending_len.min(self.chunk_size);
+
+        // the (index, start, length) of the slices of `pending` that form the next chunk
+        let mut slices = vec![];
+        let mut remaining = length;
+        let mut start = self.offset;
+        for (index, array) in self.pending.iter().enumerate() {
+            if remaining == 0 {
+                break;
+            }
+            let len = (array.as_ref().len() - start).min(remaining);
+            slices.push((index, start, len));
+            remaining -= len;
+            start = 0;
+        }
+
+        let chunk = if let [(_, start, len)] = slices.as_slice() {
+            self.pending[0].as_ref().slice(*start, *len)
+        } else {
+            let arrays = self
+                .pending
+                .iter()
+                .take(slices.len())
+                .map(|array| array.as_ref())
+                .collect::<Vec<_>>();
+            let mut growable = make_growable(&arrays, false, length);
+            for (index, start, len) in slices.iter() {
+                growable.extend(*index, *start, *len)
+            }
+            growable.as_box()
+        };
+
+        // drop the arrays whose slots were all returned
+        let (last, start, len) = slices[slices.len() - 1];
+        if start + len == self.pending[last].as_ref().len() {
+            self.pending.drain(..=last);
+            self.offset = 0;
+        } else {
+            self.pending.drain(..last);
+            self.offset = start + len;
+        }
+        self.pending_len -= length;
+        Some(Ok(chunk))
+    }
+}
@@ -18,9 +18,10 @@ where
     O: Offset,
 {
     if list.len() != values.len() {
-        return Err(ArrowError::InvalidArgumentError(
-            "Contains requires arrays of the same length".to_string(),
-        ));
+        return Err(ArrowError::LengthMismatch {
+            expected: list.len(),
+            found: values.len(),
+        });
     }
     if list.values().data_type() != values.data_type() {
         return Err(ArrowError::InvalidArgumentError(
@@ -52,9 +53,10 @@ where
     OO: Offset,
 {
     if list.len() != values.len() {
-        return Err(ArrowError::InvalidArgumentError(
-            "Contains requires arrays of the same length".to_string(),
-        ));
+        return Err(ArrowError::LengthMismatch {
+            expected: list.len(),
+            found: values.len(),
+        });
     }
     if list.values().data_type() != values.data_type() {
         return Err(ArrowError::InvalidArgumentError(
@@ -86,9 +88,10 @@ where
     OO: Offset,
 {
     if list.len() != values.len() {
-        return Err(ArrowError::InvalidArgumentError(
-            "Contains requires arrays of the same length".to_string(),
-        ));
+        return Err(ArrowError::LengthMismatch {
+            expected: list.len(),
+            found: values.len(),
+        });
     }
     if list.values().data_type() != values.data_type() {
         return Err(ArrowError::InvalidArgumentError(
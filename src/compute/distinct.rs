@@ -0,0 +1,146 @@
+//! Defines [`distinct`], to find the distinct rows of a [`Chunk`], e.g. to deduplicate it.
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::{
+    array::{
+        ord::{build_compare, DynComparator},
+        Array, DictionaryArray, PrimitiveArray,
+    },
+    bitmap::{Bitmap, MutableBitmap},
+    chunk::Chunk,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::group::dictionary_group_ids;
+use super::hash::{hash, hash_primitive};
+
+/// A key column, prepared to hash and compare its slots
+struct Key {
+    hashes: PrimitiveArray<u64>,
+    compare: DynComparator,
+    validity: Option<Bitmap>,
+}
+
+impl Key {
+    fn try_new(array: &dyn Array) -> Result<Self> {
+        if let DataType::Dictionary(key_type, ..) = array.data_type() {
+            // dictionaries are keyed by the group ids of their values, so that equal values
+            // under different keys are equal
+            let ids = match_integer_type!(key_type, |$T| {
+                let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                dictionary_group_ids(array)?.0
+            });
+            return Ok(Self {
+                hashes: hash_primitive(&ids),
+                compare: build_compare(&ids, &ids)?,
+                validity: None,
+            });
+        }
+        Ok(Self {
+            hashes: hash(array)?,
+            compare: build_compare(array, array)?,
+            validity: array.validity().cloned(),
+        })
+    }
+
+    #[inline]
+    fn is_valid(&self, i: usize) -> bool {
+        self.validity
+            .as_ref()
+            .map(|validity| validity.get_bit(i))
+            .unwrap_or(true)
+    }
+
+    #[inline]
+    fn hash(&self, i: usize) -> u64 {
+        if self.is_valid(i) {
+            self.hashes.values()[i]
+        } else {
+            NULL_HASH
+        }
+    }
+
+    #[inline]
+    fn eq(&self, i: usize, j: usize) -> bool {
+        match (self.is_valid(i), self.is_valid(j)) {
+            (true, true) => (self.compare)(i, j) == Ordering::Equal,
+            (false, false) => true,
+            _ => false,
+        }
+    }
+}
+
+const NULL_HASH: u64 = 0x5bd1_e995_2f9b_7a3c;
+
+#[inline]
+fn combine_hashes(lhs: u64, rhs: u64) -> u64 {
+    lhs ^ rhs
+        .wrapping_add(0x9e37_79b9_7f4a_7c15)
+        .wrapping_add(lhs << 6)
+        .wrapping_add(lhs >> 2)
+}
+
+/// Returns a [`Bitmap`] whose set bits are the first occurrence of every distinct combination
+/// of the values of the columns `keys` of `chunk`, i.e. the rows to keep to deduplicate it
+/// on `keys` (e.g. via [`filter_chunk`](super::filter::filter_chunk)).
+///
+/// Null values are equal to each other, and dictionary-encoded columns are compared by
+/// their values. Use all column indices as `keys` to find the distinct rows of `chunk`.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::compute::distinct::distinct;
+///
+/// let chunk = Chunk::new(vec![
+///     Arc::new(Int32Array::from([Some(1), Some(1), None, None])) as Arc<dyn Array>,
+///     Arc::new(Utf8Array::<i32>::from_slice(["a", "a", "b", "c"])),
+/// ]);
+/// let first = distinct(&chunk, &[0, 1]).unwrap();
+/// assert_eq!(first, [true, false, true, true].into());
+/// let first = distinct(&chunk, &[0]).unwrap();
+/// assert_eq!(first, [true, false, true, false].into());
+/// ```
+/// # Implementation
+/// The rows are hashed and rows with equal hashes are compared value by value.
+/// # Errors
+/// Errors iff any of `keys` is out of bounds or the data type of a key column can't be
+/// hashed (see [`can_hash`](super::hash::can_hash)).
+pub fn distinct<A: AsRef<dyn Array>>(chunk: &Chunk<A>, keys: &[usize]) -> Result<Bitmap> {
+    let columns = chunk.arrays();
+    let keys = keys
+        .iter()
+        .map(|&index| {
+            let array = columns.get(index).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "The key {} is out of bounds of a chunk with {} columns",
+                    index,
+                    columns.len()
+                ))
+            })?;
+            Key::try_new(array.as_ref())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let len = chunk.len();
+    let mut first = MutableBitmap::from_len_zeroed(len);
+    // the first row of each distinct key combination, by their hash
+    let mut distinct = HashMap::<u64, Vec<usize>>::new();
+    for i in 0..len {
+        let hash = keys
+            .iter()
+            .fold(0, |acc, key| combine_hashes(acc, key.hash(i)));
+        let candidates = distinct.entry(hash).or_default();
+        let is_duplicate = candidates
+            .iter()
+            .any(|&j| keys.iter().all(|key| key.eq(i, j)));
+        if !is_duplicate {
+            candidates.push(i);
+            first.set(i, true);
+        }
+    }
+    Ok(first.into())
+}
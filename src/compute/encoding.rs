@@ -0,0 +1,236 @@
+//! Defines kernels to encode [`BinaryArray`]s into [`Utf8Array`]s (and decode them back) as
+//! hexadecimal or base64 strings, e.g. to represent binary ids in JSON or CSV.
+use crate::{
+    array::{BinaryArray, Offset, Utf8Array},
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+/// Applies `op` to every (non-null) value of an array of `offsets` and `values`, returning the
+/// offsets and values of the outputs written by `op`. Null values are empty in the output.
+fn apply<O, F>(
+    offsets: &[O],
+    values: &[u8],
+    validity: Option<&Bitmap>,
+    capacity: usize,
+    mut op: F,
+) -> Result<(Buffer<O>, Buffer<u8>)>
+where
+    O: Offset,
+    F: FnMut(&[u8], &mut Vec<u8>) -> Result<()>,
+{
+    let mut new_offsets = Vec::<O>::with_capacity(offsets.len());
+    let mut new_values = Vec::<u8>::with_capacity(capacity);
+    new_offsets.push(O::default());
+
+    for (i, window) in offsets.windows(2).enumerate() {
+        if validity.map(|x| x.get_bit(i)).unwrap_or(true) {
+            let start = window[0].to_usize();
+            let end = window[1].to_usize();
+            op(&values[start..end], &mut new_values)?;
+        }
+        let offset = O::from_usize(new_values.len()).ok_or(ArrowError::Overflow)?;
+        new_offsets.push(offset);
+    }
+    Ok((new_offsets.into(), new_values.into()))
+}
+
+/// Returns the length of `offsets`' values
+fn values_len<O: Offset>(offsets: &[O]) -> usize {
+    offsets[offsets.len() - 1].to_usize() - offsets[0].to_usize()
+}
+
+fn utf8_data_type<O: Offset>() -> DataType {
+    if O::is_large() {
+        DataType::LargeUtf8
+    } else {
+        DataType::Utf8
+    }
+}
+
+fn binary_data_type<O: Offset>() -> DataType {
+    if O::is_large() {
+        DataType::LargeBinary
+    } else {
+        DataType::Binary
+    }
+}
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+#[inline]
+fn hex_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Encodes every value of `array` as a lowercase hexadecimal string.
+/// # Example
+/// ```
+/// use arrow2::array::{BinaryArray, Utf8Array};
+/// use arrow2::compute::encoding::hex_encode;
+///
+/// let array = BinaryArray::<i32>::from([Some(b"\x01\xab".as_ref()), None]);
+/// let result = hex_encode(&array).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("01ab"), None]));
+/// ```
+/// # Error
+/// Errors iff the encoded values do not fit in an array with offsets `O`.
+pub fn hex_encode<O: Offset>(array: &BinaryArray<O>) -> Result<Utf8Array<O>> {
+    let capacity = values_len(array.offsets()) * 2;
+    let (offsets, values) = apply(
+        array.offsets(),
+        array.values(),
+        array.validity(),
+        capacity,
+        |value, values| {
+            values.extend(
+                value
+                    .iter()
+                    .flat_map(|x| [HEX[(x >> 4) as usize], HEX[(x & 0xf) as usize]]),
+            );
+            Ok(())
+        },
+    )?;
+    // Safety: offsets are monotonically increasing and the values are ASCII
+    Ok(unsafe {
+        Utf8Array::new_unchecked(
+            utf8_data_type::<O>(),
+            offsets,
+            values,
+            array.validity().cloned(),
+        )
+    })
+}
+
+/// Decodes every value of `array` from a (case-insensitive) hexadecimal string.
+/// # Example
+/// ```
+/// use arrow2::array::{BinaryArray, Utf8Array};
+/// use arrow2::compute::encoding::hex_decode;
+///
+/// let array = Utf8Array::<i32>::from([Some("01AB"), None]);
+/// let result = hex_decode(&array).unwrap();
+/// assert_eq!(result, BinaryArray::<i32>::from([Some(b"\x01\xab".as_ref()), None]));
+/// ```
+/// # Error
+/// Errors iff a (non-null) value is not a valid hexadecimal string, i.e. it has an odd
+/// length or contains characters other than `0-9`, `a-f` and `A-F`.
+pub fn hex_decode<O: Offset>(array: &Utf8Array<O>) -> Result<BinaryArray<O>> {
+    let capacity = values_len(array.offsets()) / 2;
+    let (offsets, values) = apply(
+        array.offsets(),
+        array.values(),
+        array.validity(),
+        capacity,
+        |value, values| {
+            let error = || {
+                ArrowError::InvalidArgumentError(format!(
+                    "The value {:?} is not a valid hexadecimal string",
+                    String::from_utf8_lossy(value)
+                ))
+            };
+            if value.len() % 2 == 1 {
+                return Err(error());
+            }
+            for pair in value.chunks_exact(2) {
+                let high = hex_value(pair[0]).ok_or_else(error)?;
+                let low = hex_value(pair[1]).ok_or_else(error)?;
+                values.push((high << 4) | low);
+            }
+            Ok(())
+        },
+    )?;
+    Ok(BinaryArray::new(
+        binary_data_type::<O>(),
+        offsets,
+        values,
+        array.validity().cloned(),
+    ))
+}
+
+/// Encodes every value of `array` as a base64 string, using the standard alphabet
+/// with padding.
+/// # Example
+/// ```
+/// use arrow2::array::{BinaryArray, Utf8Array};
+/// use arrow2::compute::encoding::base64_encode;
+///
+/// let array = BinaryArray::<i32>::from([Some(b"arrow".as_ref()), None]);
+/// let result = base64_encode(&array).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("YXJyb3c="), None]));
+/// ```
+/// # Error
+/// Errors iff the encoded values do not fit in an array with offsets `O`.
+pub fn base64_encode<O: Offset>(array: &BinaryArray<O>) -> Result<Utf8Array<O>> {
+    // 4 bytes for every (started) block of 3 bytes
+    let encoded_len = |len: usize| {
+        let blocks = len / 3;
+        4 * if blocks * 3 < len { blocks + 1 } else { blocks }
+    };
+    let capacity = encoded_len(values_len(array.offsets()));
+    let (offsets, values) = apply(
+        array.offsets(),
+        array.values(),
+        array.validity(),
+        capacity,
+        |value, values| {
+            let start = values.len();
+            values.resize(start + encoded_len(value.len()), 0);
+            base64::encode_config_slice(value, base64::STANDARD, &mut values[start..]);
+            Ok(())
+        },
+    )?;
+    // Safety: offsets are monotonically increasing and the values are ASCII
+    Ok(unsafe {
+        Utf8Array::new_unchecked(
+            utf8_data_type::<O>(),
+            offsets,
+            values,
+            array.validity().cloned(),
+        )
+    })
+}
+
+/// Decodes every value of `array` from a base64 string of the standard alphabet with padding.
+/// # Example
+/// ```
+/// use arrow2::array::{BinaryArray, Utf8Array};
+/// use arrow2::compute::encoding::base64_decode;
+///
+/// let array = Utf8Array::<i32>::from([Some("YXJyb3c="), None]);
+/// let result = base64_decode(&array).unwrap();
+/// assert_eq!(result, BinaryArray::<i32>::from([Some(b"arrow".as_ref()), None]));
+/// ```
+/// # Error
+/// Errors iff a (non-null) value is not a valid base64 string.
+pub fn base64_decode<O: Offset>(array: &Utf8Array<O>) -> Result<BinaryArray<O>> {
+    let capacity = values_len(array.offsets()) / 4 * 3;
+    let (offsets, values) = apply(
+        array.offsets(),
+        array.values(),
+        array.validity(),
+        capacity,
+        |value, values| {
+            base64::decode_config_buf(value, base64::STANDARD, values).map_err(|error| {
+                ArrowError::InvalidArgumentError(format!(
+                    "The value {:?} is not a valid base64 string: {}",
+                    String::from_utf8_lossy(value),
+                    error
+                ))
+            })
+        },
+    )?;
+    Ok(BinaryArray::new(
+        binary_data_type::<O>(),
+        offsets,
+        values,
+        array.validity().cloned(),
+    ))
+}
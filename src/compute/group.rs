@@ -0,0 +1,128 @@
+//! Defines kernels to assign group ids to the slots of arrays, e.g. to group-by or join on them.
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::{
+    array::{ord::build_compare, Array, DictionaryArray, DictionaryKey, PrimitiveArray},
+    datatypes::{DataType, PhysicalType, PrimitiveType},
+    error::{ArrowError, Result},
+    types::{days_ms, months_days_ns, NativeType},
+};
+
+use super::hash::hash;
+
+type DynEqual<'a> = Box<dyn Fn(usize, usize) -> bool + 'a>;
+
+fn primitive_equal<T: NativeType>(values: &dyn Array) -> DynEqual<'_> {
+    let values = values
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .values();
+    Box::new(move |i, j| values[i] == values[j])
+}
+
+/// Returns a function that tells whether the values of two slots of `values` are equal,
+/// regardless of their validity.
+fn build_equal(values: &dyn Array) -> Result<DynEqual<'_>> {
+    Ok(match values.data_type().to_physical_type() {
+        // intervals have no order
+        PhysicalType::Primitive(PrimitiveType::DaysMs) => primitive_equal::<days_ms>(values),
+        PhysicalType::Primitive(PrimitiveType::MonthDayNano) => {
+            primitive_equal::<months_days_ns>(values)
+        }
+        _ => {
+            let compare = build_compare(values, values)?;
+            Box::new(move |i, j| compare(i, j) == Ordering::Equal)
+        }
+    })
+}
+
+/// Returns, for every value of `values`, the index of the first value equal to it, or `None`
+/// if the value is null.
+fn first_equal_values(values: &dyn Array) -> Result<Vec<Option<usize>>> {
+    let hashes = hash(values)?;
+    // only the values of valid slots are compared, since null slots are not hashed
+    let equal = build_equal(values)?;
+
+    // indices of the distinct values, by their hash
+    let mut distinct = HashMap::<u64, Vec<usize>>::new();
+    Ok(hashes
+        .iter()
+        .enumerate()
+        .map(|(i, hash)| {
+            let hash = hash?;
+            let candidates = distinct.entry(*hash).or_default();
+            let first = candidates.iter().copied().find(|j| equal(i, *j));
+            Some(first.unwrap_or_else(|| {
+                candidates.push(i);
+                i
+            }))
+        })
+        .collect())
+}
+
+/// Returns the group id of every slot of `array` and the number of groups. Slots with equal
+/// values have the same id, ids are assigned in order of first appearance, and null slots
+/// (null keys or keys of null values) form a single group.
+///
+/// The dictionary values are hashed and deduplicated once, after which every key is mapped
+/// to its group id by a lookup. This is much faster than hashing every slot for dictionaries
+/// with few values, e.g. low-cardinality strings.
+/// # Example
+/// ```
+/// use arrow2::array::{MutableDictionaryArray, MutableUtf8Array, TryExtend, UInt32Array};
+/// use arrow2::compute::group::dictionary_group_ids;
+///
+/// let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+/// array.try_extend([Some("b"), Some("a"), None, Some("b")]).unwrap();
+/// let array = array.into();
+///
+/// let (ids, num_groups) = dictionary_group_ids(&array).unwrap();
+/// assert_eq!(ids, UInt32Array::from_slice([0, 1, 2, 0]));
+/// assert_eq!(num_groups, 3);
+/// ```
+/// # Errors
+/// Errors iff the dictionary values can't be hashed (see [`can_hash`](super::hash::can_hash))
+/// or there are more than `u32::MAX` of them.
+pub fn dictionary_group_ids<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+) -> Result<(PrimitiveArray<u32>, usize)> {
+    let values = array.values().as_ref();
+    if values.len() >= u32::MAX as usize {
+        return Err(ArrowError::Overflow);
+    }
+
+    // every value is mapped to a slot of `ids`, where equal values share the slot of the
+    // first of them and null values share the last slot
+    let null_slot = values.len();
+    let slots = first_equal_values(values)?
+        .into_iter()
+        .map(|first| first.unwrap_or(null_slot))
+        .collect::<Vec<_>>();
+
+    const UNASSIGNED: u32 = u32::MAX;
+    let mut ids = vec![UNASSIGNED; values.len() + 1];
+    let mut num_groups = 0;
+
+    let keys = array.keys();
+    let group_ids = keys
+        .iter()
+        .map(|key| {
+            let slot = key
+                .map(|key| slots[key.to_usize().unwrap()])
+                .unwrap_or(null_slot);
+            let id = &mut ids[slot];
+            if *id == UNASSIGNED {
+                *id = num_groups;
+                num_groups += 1;
+            }
+            *id
+        })
+        .collect::<Vec<_>>();
+
+    Ok((
+        PrimitiveArray::new(DataType::UInt32, group_ids.into(), None),
+        num_groups as usize,
+    ))
+}
@@ -29,19 +29,19 @@ pub fn if_then_else(
     rhs: &dyn Array,
 ) -> Result<Box<dyn Array>> {
     if lhs.data_type() != rhs.data_type() {
-        return Err(ArrowError::InvalidArgumentError(format!(
-            "If then else requires the arguments to have the same datatypes ({:?} != {:?})",
-            lhs.data_type(),
-            rhs.data_type()
-        )));
+        return Err(ArrowError::DataTypeMismatch {
+            expected: Box::new(lhs.data_type().clone()),
+            found: Box::new(rhs.data_type().clone()),
+        });
     }
-    if (lhs.len() != rhs.len()) | (lhs.len() != predicate.len()) {
-        return Err(ArrowError::InvalidArgumentError(format!(
-            "If then else requires all arguments to have the same length (predicate = {}, lhs = {}, rhs = {})",
-            predicate.len(),
-            lhs.len(),
-            rhs.len()
-        )));
+    if let Some(len) = [rhs.len(), predicate.len()]
+        .into_iter()
+        .find(|len| *len != lhs.len())
+    {
+        return Err(ArrowError::LengthMismatch {
+            expected: lhs.len(),
+            found: len,
+        });
     }
 
     let result = if predicate.null_count() > 0 {
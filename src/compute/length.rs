@@ -44,7 +44,27 @@ where
     PrimitiveArray::<O>::new(data_type, values.into(), array.validity().cloned())
 }
 
+fn utf8_length_chars<O: Offset + NativeType>(array: &Utf8Array<O>) -> PrimitiveArray<O> {
+    if array.is_ascii() {
+        // every char is a single byte
+        return unary_offsets_string::<O, _>(array, |x| x);
+    }
+    let values = array
+        .values_iter()
+        .map(|x| O::from_usize(x.chars().count()).unwrap())
+        .collect::<Vec<_>>();
+
+    let data_type = if O::is_large() {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    };
+
+    PrimitiveArray::<O>::new(data_type, values.into(), array.validity().cloned())
+}
+
 /// Returns an array of integers with the number of bytes on each string of the array.
+/// See [`length_chars`] for the number of characters.
 pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
     match array.data_type() {
         DataType::Utf8 => {
@@ -62,6 +82,27 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
     }
 }
 
+/// Returns an array of integers with the number of characters on each string of the array.
+/// Arrays whose values are all ASCII are measured by bytes, which is equivalent and faster.
+/// # Error
+/// Errors iff the array is not a \[Large\]Utf8 array.
+pub fn length_chars(array: &dyn Array) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            Ok(Box::new(utf8_length_chars(array)))
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            Ok(Box::new(utf8_length_chars(array)))
+        }
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "length_chars not supported for {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
 /// Checks if an array of type `datatype` can perform length operation
 ///
 /// # Examples
@@ -12,19 +12,174 @@ use crate::{
     error::{ArrowError, Result},
 };
 
-#[inline]
-fn is_like_pattern(c: char) -> bool {
-    c == '%' || c == '_'
+/// Options of the `LIKE` kernels of [`Utf8Array`]s, such as [`like_utf8_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LikeOptions {
+    /// Whether values are matched case-insensitively, as in `ILIKE`
+    pub case_insensitive: bool,
+    /// A character that, in a pattern, escapes `%`, `_` or itself to match it literally
+    pub escape: Option<char>,
+}
+
+const ILIKE: LikeOptions = LikeOptions {
+    case_insensitive: true,
+    escape: None,
+};
+
+enum Token {
+    Literal(String),
+    /// `_`
+    One,
+    /// `%`
+    Any,
+}
+
+/// Splits `pattern` into literals and wildcards. Consecutive `%` are merged into one.
+fn tokenize(pattern: &str, escape: Option<char>) -> Result<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut literal = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        let wildcard = if Some(c) == escape {
+            let escaped = chars.next().ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "The LIKE pattern \"{}\" can't end with its escape character",
+                    pattern
+                ))
+            })?;
+            literal.push(escaped);
+            continue;
+        } else if c == '%' {
+            Token::Any
+        } else if c == '_' {
+            Token::One
+        } else {
+            literal.push(c);
+            continue;
+        };
+        if !literal.is_empty() {
+            tokens.push(Token::Literal(std::mem::take(&mut literal)));
+        }
+        if !(matches!(wildcard, Token::Any) && matches!(tokens.last(), Some(Token::Any))) {
+            tokens.push(wildcard);
+        }
+    }
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+    Ok(tokens)
+}
+
+/// A compiled `LIKE` pattern of values of type `T`. Patterns without `_` and with `%` only at
+/// their ends are matched with comparisons of `T`; all others with a regex `R`.
+enum Matcher<T, R> {
+    All,
+    Equals(T),
+    StartsWith(T),
+    EndsWith(T),
+    Contains(T),
+    Regex(R),
+}
+
+type Utf8Matcher = Matcher<String, Regex>;
+type BinaryMatcher = Matcher<Vec<u8>, BytesRegex>;
+
+impl<T, R> Matcher<T, R> {
+    fn try_new<L, N>(pattern: &str, options: &LikeOptions, literal: L, new_regex: N) -> Result<Self>
+    where
+        L: Fn(String) -> T,
+        N: Fn(&str) -> std::result::Result<R, regex::Error>,
+    {
+        let tokens = tokenize(pattern, options.escape)?;
+
+        if let [Token::Any] = tokens.as_slice() {
+            return Ok(Self::All);
+        }
+        if !options.case_insensitive {
+            match tokens.as_slice() {
+                [] => return Ok(Self::Equals(literal(String::new()))),
+                [Token::Literal(x)] => return Ok(Self::Equals(literal(x.clone()))),
+                [Token::Literal(x), Token::Any] => return Ok(Self::StartsWith(literal(x.clone()))),
+                [Token::Any, Token::Literal(x)] => return Ok(Self::EndsWith(literal(x.clone()))),
+                [Token::Any, Token::Literal(x), Token::Any] => {
+                    return Ok(Self::Contains(literal(x.clone())))
+                }
+                _ => {}
+            }
+        }
+
+        // `(?s)` so that wildcards also match new lines
+        let mut regex = if options.case_insensitive {
+            "(?is)^".to_string()
+        } else {
+            "(?s)^".to_string()
+        };
+        for token in tokens {
+            match token {
+                Token::Literal(x) => regex.push_str(&regex::escape(&x)),
+                Token::One => regex.push('.'),
+                Token::Any => regex.push_str(".*"),
+            }
+        }
+        regex.push('$');
+        let regex = new_regex(&regex).map_err(|e| {
+            ArrowError::InvalidArgumentError(format!(
+                "Unable to build regex from LIKE pattern: {}",
+                e
+            ))
+        })?;
+        Ok(Self::Regex(regex))
+    }
+}
+
+impl Utf8Matcher {
+    fn try_new_utf8(pattern: &str, options: &LikeOptions) -> Result<Self> {
+        Self::try_new(pattern, options, |x| x, Regex::new)
+    }
+
+    #[inline]
+    fn is_match(&self, value: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Equals(x) => value == x,
+            Self::StartsWith(x) => value.starts_with(x.as_str()),
+            Self::EndsWith(x) => value.ends_with(x.as_str()),
+            Self::Contains(x) => value.contains(x.as_str()),
+            Self::Regex(x) => x.is_match(value),
+        }
+    }
 }
 
-fn replace_pattern(pattern: &str) -> String {
-    pattern.replace('%', ".*").replace('_', ".")
+impl BinaryMatcher {
+    fn try_new_binary(pattern: &[u8], options: &LikeOptions) -> Result<Self> {
+        let pattern = simdutf8::basic::from_utf8(pattern).map_err(|e| {
+            ArrowError::InvalidArgumentError(format!(
+                "Unable to convert the LIKE pattern to string: {}",
+                e
+            ))
+        })?;
+        Self::try_new(pattern, options, String::into_bytes, BytesRegex::new)
+    }
+
+    #[inline]
+    fn is_match(&self, value: &[u8]) -> bool {
+        match self {
+            Self::All => true,
+            Self::Equals(x) => value == x.as_slice(),
+            Self::StartsWith(x) => value.starts_with(x),
+            Self::EndsWith(x) => value.ends_with(x),
+            // literals are not empty
+            Self::Contains(x) => value.windows(x.len()).any(|window| window == x.as_slice()),
+            Self::Regex(x) => x.is_match(value),
+        }
+    }
 }
 
 #[inline]
 fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &Utf8Array<O>,
+    options: &LikeOptions,
     op: F,
 ) -> Result<BooleanArray> {
     if lhs.len() != rhs.len() {
@@ -41,20 +196,13 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
         Bitmap::try_from_trusted_len_iter(lhs.iter().zip(rhs.iter()).map(|(lhs, rhs)| {
             match (lhs, rhs) {
                 (Some(lhs), Some(pattern)) => {
-                    let pattern = if let Some(pattern) = map.get(pattern) {
-                        pattern
+                    let matcher = if let Some(matcher) = map.get(pattern) {
+                        matcher
                     } else {
-                        let re_pattern = replace_pattern(pattern);
-                        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-                            ArrowError::InvalidArgumentError(format!(
-                                "Unable to build regex from LIKE pattern: {}",
-                                e
-                            ))
-                        })?;
-                        map.insert(pattern, re);
+                        map.insert(pattern, Utf8Matcher::try_new_utf8(pattern, options)?);
                         map.get(pattern).unwrap()
                     };
-                    Result::Ok(op(pattern.is_match(lhs)))
+                    Result::Ok(op(matcher.is_match(lhs)))
                 }
                 _ => Ok(false),
             }
@@ -86,7 +234,7 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
 /// assert_eq!(result, BooleanArray::from_slice(&[true, false, true, false, true]));
 /// ```
 pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| x)
+    a_like_utf8(lhs, rhs, &LikeOptions::default(), |x| x)
 }
 
 /// Returns `lhs NOT LIKE rhs` operation on two [`Utf8Array`].
@@ -96,36 +244,72 @@ pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<Bo
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 pub fn nlike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| !x)
+    a_like_utf8(lhs, rhs, &LikeOptions::default(), |x| !x)
+}
+
+/// Returns `lhs ILIKE rhs` operation on two [`Utf8Array`], i.e. a case-insensitive
+/// [`like_utf8`].
+/// # Error
+/// Errors iff:
+/// * the arrays have a different length
+/// * any of the patterns is not valid
+pub fn ilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    like_utf8_with(lhs, rhs, &ILIKE)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation on two [`Utf8Array`].
+pub fn nilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    nlike_utf8_with(lhs, rhs, &ILIKE)
+}
+
+/// Returns `lhs LIKE rhs` operation on two [`Utf8Array`] with [`LikeOptions`], i.e.
+/// optionally case-insensitive and with an escape character.
+/// # Error
+/// Errors iff:
+/// * the arrays have a different length
+/// * any of the patterns is not valid or ends with the escape character
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::{like_utf8_with, LikeOptions};
+///
+/// let strings = Utf8Array::<i32>::from_slice(&["10%", "10", "100%", "A_b"]);
+/// let patterns = Utf8Array::<i32>::from_slice(&["%!%", "%!%", "1_0!%", "a!_B"]);
+///
+/// let options = LikeOptions {
+///     case_insensitive: true,
+///     escape: Some('!'),
+/// };
+/// let result = like_utf8_with(&strings, &patterns, &options).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false, true, true]));
+/// ```
+pub fn like_utf8_with<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+    options: &LikeOptions,
+) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, options, |x| x)
+}
+
+/// Returns `lhs NOT LIKE rhs` operation on two [`Utf8Array`] with [`LikeOptions`].
+pub fn nlike_utf8_with<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+    options: &LikeOptions,
+) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, options, |x| !x)
 }
 
 fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &str,
+    options: &LikeOptions,
     op: F,
 ) -> Result<BooleanArray> {
     let validity = lhs.validity();
 
-    let values = if !rhs.contains(is_like_pattern) {
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x == rhs)))
-    } else if rhs.ends_with('%') && !rhs[..rhs.len() - 1].contains(is_like_pattern) {
-        // fast path, can use starts_with
-        let starts_with = &rhs[..rhs.len() - 1];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.starts_with(starts_with))))
-    } else if rhs.starts_with('%') && !rhs[1..].contains(is_like_pattern) {
-        // fast path, can use ends_with
-        let ends_with = &rhs[1..];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
-    } else {
-        let re_pattern = replace_pattern(rhs);
-        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-            ArrowError::InvalidArgumentError(format!(
-                "Unable to build regex from LIKE pattern: {}",
-                e
-            ))
-        })?;
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
-    };
+    let matcher = Utf8Matcher::try_new_utf8(rhs, options)?;
+    let values = Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(matcher.is_match(x))));
     Ok(BooleanArray::new(
         DataType::Boolean,
         values,
@@ -155,7 +339,7 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
 /// assert_eq!(result, BooleanArray::from_slice(&[true, true, true, false]));
 /// ```
 pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| x)
+    a_like_utf8_scalar(lhs, rhs, &LikeOptions::default(), |x| x)
 }
 
 /// Returns `lhs NOT LIKE rhs` operation.
@@ -165,7 +349,64 @@ pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<Bool
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 pub fn nlike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| !x)
+    a_like_utf8_scalar(lhs, rhs, &LikeOptions::default(), |x| !x)
+}
+
+/// Returns `lhs ILIKE rhs` operation, i.e. a case-insensitive [`like_utf8_scalar`].
+/// # Error
+/// Errors iff the pattern is not valid
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::ilike_utf8_scalar;
+///
+/// let array = Utf8Array::<i32>::from_slice(&["Arrow", "arrow", "ARROW", "BA"]);
+///
+/// let result = ilike_utf8_scalar(&array, "arr%").unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, true, true, false]));
+/// ```
+pub fn ilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    like_utf8_scalar_with(lhs, rhs, &ILIKE)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation.
+pub fn nilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    nlike_utf8_scalar_with(lhs, rhs, &ILIKE)
+}
+
+/// Returns `lhs LIKE rhs` operation with [`LikeOptions`], i.e. optionally case-insensitive and
+/// with an escape character.
+/// # Error
+/// Errors iff the pattern is not valid or ends with the escape character
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::{like_utf8_scalar_with, LikeOptions};
+///
+/// let array = Utf8Array::<i32>::from_slice(&["50%", "50", "5%0"]);
+///
+/// let options = LikeOptions {
+///     escape: Some('\\'),
+///     ..Default::default()
+/// };
+/// let result = like_utf8_scalar_with(&array, r"%\%", &options).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false, false]));
+/// ```
+pub fn like_utf8_scalar_with<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &str,
+    options: &LikeOptions,
+) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, options, |x| x)
+}
+
+/// Returns `lhs NOT LIKE rhs` operation with [`LikeOptions`].
+pub fn nlike_utf8_scalar_with<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &str,
+    options: &LikeOptions,
+) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, options, |x| !x)
 }
 
 #[inline]
@@ -188,21 +429,15 @@ fn a_like_binary<O: Offset, F: Fn(bool) -> bool>(
         Bitmap::try_from_trusted_len_iter(lhs.iter().zip(rhs.iter()).map(|(lhs, rhs)| {
             match (lhs, rhs) {
                 (Some(lhs), Some(pattern)) => {
-                    let pattern = if let Some(pattern) = map.get(pattern) {
-                        pattern
+                    let matcher = if let Some(matcher) = map.get(pattern) {
+                        matcher
                     } else {
-                        let re_pattern = simdutf8::basic::from_utf8(pattern).unwrap();
-                        let re_pattern = replace_pattern(re_pattern);
-                        let re = BytesRegex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-                            ArrowError::InvalidArgumentError(format!(
-                                "Unable to build regex from LIKE pattern: {}",
-                                e
-                            ))
-                        })?;
-                        map.insert(pattern, re);
+                        let matcher =
+                            BinaryMatcher::try_new_binary(pattern, &LikeOptions::default())?;
+                        map.insert(pattern, matcher);
                         map.get(pattern).unwrap()
                     };
-                    Result::Ok(op(pattern.is_match(lhs)))
+                    Result::Ok(op(matcher.is_match(lhs)))
                 }
                 _ => Ok(false),
             }
@@ -254,33 +489,9 @@ fn a_like_binary_scalar<O: Offset, F: Fn(bool) -> bool>(
     op: F,
 ) -> Result<BooleanArray> {
     let validity = lhs.validity();
-    let pattern = simdutf8::basic::from_utf8(rhs).map_err(|e| {
-        ArrowError::InvalidArgumentError(format!(
-            "Unable to convert the LIKE pattern to string: {}",
-            e
-        ))
-    })?;
-
-    let values = if !pattern.contains(is_like_pattern) {
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x == rhs)))
-    } else if pattern.ends_with('%') && !pattern[..pattern.len() - 1].contains(is_like_pattern) {
-        // fast path, can use starts_with
-        let starts_with = &rhs[..rhs.len() - 1];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.starts_with(starts_with))))
-    } else if pattern.starts_with('%') && !pattern[1..].contains(is_like_pattern) {
-        // fast path, can use ends_with
-        let ends_with = &rhs[1..];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
-    } else {
-        let re_pattern = replace_pattern(pattern);
-        let re = BytesRegex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-            ArrowError::InvalidArgumentError(format!(
-                "Unable to build regex from LIKE pattern: {}",
-                e
-            ))
-        })?;
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
-    };
+
+    let matcher = BinaryMatcher::try_new_binary(rhs, &LikeOptions::default())?;
+    let values = Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(matcher.is_match(x))));
     Ok(BooleanArray::new(
         DataType::Boolean,
         values,
@@ -0,0 +1,120 @@
+//! Contains operators for [`MapArray`]s: [`keys`], [`values`] and [`map_get`].
+use crate::{
+    array::{Array, ListArray, MapArray, PrimitiveArray, StructArray},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    scalar::Scalar,
+};
+
+use super::comparison::{can_eq_scalar, eq_scalar};
+use super::take::take;
+
+fn entries(array: &MapArray) -> &StructArray {
+    array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .expect("the field of a MapArray is a StructArray")
+}
+
+fn project(array: &MapArray, index: usize) -> ListArray<i32> {
+    let entries = entries(array);
+    ListArray::new(
+        DataType::List(Box::new(entries.fields()[index].clone())),
+        array.offsets().clone(),
+        entries.values()[index].clone(),
+        array.validity().cloned(),
+    )
+}
+
+/// Returns the keys of each entry of `array` as a [`ListArray`], whose validity is the
+/// validity of `array`.
+/// # Implementation
+/// This operation is `O(1)`, as it shares the offsets and the keys of `array`.
+pub fn keys(array: &MapArray) -> ListArray<i32> {
+    project(array, 0)
+}
+
+/// Returns the values of each entry of `array` as a [`ListArray`], whose validity is the
+/// validity of `array`.
+/// # Implementation
+/// This operation is `O(1)`, as it shares the offsets and the values of `array`.
+pub fn values(array: &MapArray) -> ListArray<i32> {
+    project(array, 1)
+}
+
+/// Returns, for each row of `array`, the value of its entry whose key equals `key`.
+/// The result is null for rows that are null or have no such entry, and uses the first
+/// entry when a row has many.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, MapArray, StructArray, Utf8Array};
+/// use arrow2::compute::map::map_get;
+/// use arrow2::datatypes::{DataType, Field};
+/// use arrow2::scalar::Utf8Scalar;
+///
+/// let fields = vec![
+///     Field::new("key", DataType::Utf8, false),
+///     Field::new("value", DataType::Int32, true),
+/// ];
+/// let entries = StructArray::from_data(
+///     DataType::Struct(fields.clone()),
+///     vec![
+///         Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "a"])),
+///         Arc::new(Int32Array::from_slice([1, 2, 3])),
+///     ],
+///     None,
+/// );
+/// let field = Field::new("entries", DataType::Struct(fields), false);
+/// let data_type = DataType::Map(Box::new(field), false);
+/// // [{a: 1, b: 2}, {a: 3}, {}]
+/// let array = MapArray::from_data(data_type, vec![0, 2, 3, 3].into(), Arc::new(entries), None);
+///
+/// let result = map_get(&array, &Utf8Scalar::<i32>::new(Some("a"))).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(1), Some(3), None]) as &dyn Array);
+/// ```
+/// # Errors
+/// This function errors iff `key`'s logical type differs from the logical type of the keys
+/// of `array` or the keys do not support [`eq_scalar`].
+pub fn map_get(array: &MapArray, key: &dyn Scalar) -> Result<Box<dyn Array>> {
+    let entries = entries(array);
+    let keys = entries.values()[0].as_ref();
+    if keys.data_type().to_logical_type() != key.data_type().to_logical_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "map_get requires a key of the map's key type {:?}, but it is of type {:?}",
+            keys.data_type(),
+            key.data_type()
+        )));
+    }
+    if !can_eq_scalar(keys.data_type()) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "map_get for keys of type {:?}",
+            keys.data_type()
+        )));
+    }
+
+    let matches = eq_scalar(keys, key);
+    // a null key never matches
+    let matches = match matches.validity() {
+        Some(validity) => matches.values() & validity,
+        None => matches.values().clone(),
+    };
+
+    let validity = array.validity();
+    let indices = array
+        .offsets()
+        .windows(2)
+        .enumerate()
+        .map(|(row, offsets)| {
+            if matches!(validity, Some(validity) if !validity.get_bit(row)) {
+                return None;
+            }
+            (offsets[0] as usize..offsets[1] as usize)
+                .find(|index| matches.get_bit(*index))
+                .map(|index| index as i32)
+        })
+        .collect::<PrimitiveArray<i32>>();
+
+    take(entries.values()[1].as_ref(), &indices)
+}
@@ -0,0 +1,94 @@
+//! Contains elementwise mathematical functions of arrays of floats, such as [`exp`], [`ln`],
+//! [`sqrt`], [`sin`] and [`pow`].
+//!
+//! Nulls are propagated: the result is null iff any of the operands is null. Values outside of
+//! the domain of a function (e.g. `ln(-1.0)`) are `NaN`, like in [`f64`].
+use num_traits::Float;
+
+use crate::array::PrimitiveArray;
+use crate::compute::arity::{binary, unary};
+use crate::types::NativeType;
+
+macro_rules! unary_float {
+    ($(#[$doc:meta] $name:ident),* $(,)?) => {
+        $(
+            #[$doc]
+            pub fn $name<T: NativeType + Float>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+                unary(array, |x| x.$name(), array.data_type().clone())
+            }
+        )*
+    };
+}
+
+unary_float!(
+    /// Returns `e^x` of each value of a [`PrimitiveArray`].
+    exp,
+    /// Returns `2^x` of each value of a [`PrimitiveArray`].
+    exp2,
+    /// Returns the natural logarithm of each value of a [`PrimitiveArray`].
+    ln,
+    /// Returns the base 2 logarithm of each value of a [`PrimitiveArray`].
+    log2,
+    /// Returns the base 10 logarithm of each value of a [`PrimitiveArray`].
+    log10,
+    /// Returns the square root of each value of a [`PrimitiveArray`].
+    sqrt,
+    /// Returns the cubic root of each value of a [`PrimitiveArray`].
+    cbrt,
+    /// Returns the sine of each value (in radians) of a [`PrimitiveArray`].
+    sin,
+    /// Returns the cosine of each value (in radians) of a [`PrimitiveArray`].
+    cos,
+    /// Returns the tangent of each value (in radians) of a [`PrimitiveArray`].
+    tan,
+    /// Returns the arcsine (in radians) of each value of a [`PrimitiveArray`].
+    asin,
+    /// Returns the arccosine (in radians) of each value of a [`PrimitiveArray`].
+    acos,
+    /// Returns the arctangent (in radians) of each value of a [`PrimitiveArray`].
+    atan,
+);
+
+/// Returns the logarithm of each value of a [`PrimitiveArray`] in base `base`.
+pub fn log<T: NativeType + Float>(array: &PrimitiveArray<T>, base: T) -> PrimitiveArray<T> {
+    unary(array, |x| x.log(base), array.data_type().clone())
+}
+
+/// Raises each value of `lhs` to the power of the corresponding value of `rhs`.
+/// # Panic
+/// This function panics iff the arrays have a different length.
+/// # Example
+/// ```
+/// use arrow2::array::Float64Array;
+/// use arrow2::compute::math::pow;
+///
+/// let base = Float64Array::from(&[Some(2.0), Some(9.0), None]);
+/// let exponent = Float64Array::from(&[Some(3.0), Some(0.5), Some(1.0)]);
+/// let result = pow(&base, &exponent);
+/// assert_eq!(result, Float64Array::from(&[Some(8.0), Some(3.0), None]));
+/// ```
+pub fn pow<T: NativeType + Float>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> PrimitiveArray<T> {
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| a.powf(b))
+}
+
+/// Raises each value of a [`PrimitiveArray`] to the power of `exponent`.
+pub fn pow_scalar<T: NativeType + Float>(
+    array: &PrimitiveArray<T>,
+    exponent: T,
+) -> PrimitiveArray<T> {
+    unary(array, |x| x.powf(exponent), array.data_type().clone())
+}
+
+/// Returns the four quadrant arctangent (in radians) of each value of `lhs` (`y`) and the
+/// corresponding value of `rhs` (`x`).
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn atan2<T: NativeType + Float>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> PrimitiveArray<T> {
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| a.atan2(b))
+}
@@ -69,6 +69,9 @@ use crate::array::{
 pub use crate::compute::sort::SortOptions;
 use crate::error::Result;
 
+mod streams;
+pub use streams::{merge_streams, MergeStreams, SortKey};
+
 /// A slice denoting `(array_index, start, len)` representing a slice from one of N arrays.
 /// This is used to keep track of contiguous blocks of slots.
 /// An array of MergeSlice, `[MergeSlice]`, represents inter-leaved array slices.
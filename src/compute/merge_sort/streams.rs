@@ -0,0 +1,309 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use crate::array::{ord::DynComparator, Array};
+use crate::chunk::Chunk;
+use crate::compute::sort::build_compare;
+use crate::error::{ArrowError, Result};
+
+use super::{take_arrays, MergeSlice, SortOptions};
+
+/// A column of the chunks to merge by, and the order of its values.
+#[derive(Debug, Clone, Copy)]
+pub struct SortKey {
+    /// The index of the column in the chunks
+    pub column: usize,
+    /// The order of the column's values
+    pub options: SortOptions,
+}
+
+/// Returns an [`Iterator`] of [`Chunk`]s with `chunk_size` rows (the last may have less),
+/// resulting from merging `streams` of [`Chunk`]s sorted by `sort_keys`.
+///
+/// The chunks are lazily read from the streams: besides the chunks that the rows of the
+/// [`Chunk`] being returned come from, only the current [`Chunk`] of each stream is held in
+/// memory, which makes this suitable for external sorts (merging sorted runs spilled to disk,
+/// e.g. via IPC) and compactions. Rows that compare equal are taken from the stream with the
+/// lowest index first.
+///
+/// The streams are kept in a binary heap ordered by their current row, so that each row is
+/// merged in `O(log K)` comparisons for `K` streams. The comparators between the current chunks
+/// of every two streams are built once, when a stream moves to its next chunk.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::compute::merge_sort::{merge_streams, SortKey, SortOptions};
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let chunk = |x: &[i32]| Ok(Chunk::new(vec![Arc::new(Int32Array::from_slice(x)) as Arc<dyn Array>]));
+/// let a = vec![chunk(&[1, 4]), chunk(&[5])];
+/// let b = vec![chunk(&[2, 3, 6])];
+///
+/// let key = SortKey { column: 0, options: SortOptions { descending: false, nulls_first: true } };
+/// let merged = merge_streams(vec![a.into_iter(), b.into_iter()], vec![key], 4)
+///     .collect::<arrow2::error::Result<Vec<_>>>()?;
+///
+/// assert_eq!(merged[0].arrays()[0].as_ref(), &Int32Array::from_slice([1, 2, 3, 4]) as &dyn Array);
+/// assert_eq!(merged[1].arrays()[0].as_ref(), &Int32Array::from_slice([5, 6]) as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Iterating errors iff a stream errors or the column of a sort key does not exist or can't be
+/// compared across chunks.
+/// # Panics
+/// Panics iff `chunk_size == 0`. Iterating panics iff the streams' chunks do not have the
+/// same columns.
+pub fn merge_streams<I>(
+    streams: Vec<I>,
+    sort_keys: Vec<SortKey>,
+    chunk_size: usize,
+) -> MergeStreams<I>
+where
+    I: Iterator<Item = Result<Chunk<Arc<dyn Array>>>>,
+{
+    assert!(chunk_size > 0, "chunk_size must be larger than zero");
+    let heads = streams.iter().map(|_| None).collect();
+    let comparators = streams
+        .iter()
+        .map(|_| streams.iter().map(|_| vec![]).collect())
+        .collect();
+    MergeStreams {
+        streams,
+        heads,
+        heap: vec![],
+        started: false,
+        next_id: 0,
+        sort_keys,
+        chunk_size,
+        comparators,
+    }
+}
+
+/// The current chunk of a stream
+struct Head {
+    // unique identifier of the chunk across all streams
+    id: usize,
+    chunk: Chunk<Arc<dyn Array>>,
+    // the current row of the chunk
+    row: usize,
+}
+
+/// Comparators between the current chunks of every two streams `i < j`, one per sort key, at
+/// `[i][j]`.
+type Comparators = Vec<Vec<Vec<DynComparator>>>;
+
+/// Returns whether the current row of stream `lhs` is merged before the one of stream `rhs`.
+/// Rows that compare equal are merged by the index of their stream.
+fn is_before(comparators: &Comparators, heads: &[Option<Head>], lhs: usize, rhs: usize) -> bool {
+    let (i, j) = (lhs.min(rhs), lhs.max(rhs));
+    let row = |index: usize| heads[index].as_ref().unwrap().row;
+    let ordering = comparators[i][j]
+        .iter()
+        .map(|comparator| (comparator)(row(i), row(j)))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal);
+    let ordering = if lhs < rhs {
+        ordering
+    } else {
+        ordering.reverse()
+    };
+    ordering.then(lhs.cmp(&rhs)) == Ordering::Less
+}
+
+/// Moves the stream at `index` of the (min-)heap up until its parent is before it.
+fn sift_up(heap: &mut [usize], mut index: usize, is_before: impl Fn(usize, usize) -> bool) {
+    while index > 0 {
+        let parent = (index - 1) / 2;
+        if !is_before(heap[index], heap[parent]) {
+            break;
+        }
+        heap.swap(index, parent);
+        index = parent;
+    }
+}
+
+/// Moves the stream at `index` of the (min-)heap down until it is before its children.
+fn sift_down(heap: &mut [usize], mut index: usize, is_before: impl Fn(usize, usize) -> bool) {
+    loop {
+        let left = 2 * index + 1;
+        let right = left + 1;
+        let mut first = index;
+        if left < heap.len() && is_before(heap[left], heap[first]) {
+            first = left;
+        }
+        if right < heap.len() && is_before(heap[right], heap[first]) {
+            first = right;
+        }
+        if first == index {
+            break;
+        }
+        heap.swap(index, first);
+        index = first;
+    }
+}
+
+/// [`Iterator`] returned by [`merge_streams`].
+pub struct MergeStreams<I>
+where
+    I: Iterator<Item = Result<Chunk<Arc<dyn Array>>>>,
+{
+    streams: Vec<I>,
+    heads: Vec<Option<Head>>,
+    // the indices of the streams with a current chunk, as a min-heap of their current rows
+    heap: Vec<usize>,
+    started: bool,
+    next_id: usize,
+    sort_keys: Vec<SortKey>,
+    chunk_size: usize,
+    comparators: Comparators,
+}
+
+impl<I> MergeStreams<I>
+where
+    I: Iterator<Item = Result<Chunk<Arc<dyn Array>>>>,
+{
+    /// Returns the comparators between the rows of `lhs` and the rows of `rhs`
+    fn build_comparators(
+        &self,
+        lhs: &Chunk<Arc<dyn Array>>,
+        rhs: &Chunk<Arc<dyn Array>>,
+    ) -> Result<Vec<DynComparator>> {
+        self.sort_keys
+            .iter()
+            .map(|key| {
+                let column = |chunk: &Chunk<Arc<dyn Array>>| {
+                    chunk.arrays().get(key.column).cloned().ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!(
+                            "The sort key's column {} does not exist in the chunk",
+                            key.column
+                        ))
+                    })
+                };
+                build_compare(column(lhs)?.as_ref(), column(rhs)?.as_ref(), key.options)
+            })
+            .collect()
+    }
+
+    /// Replaces the current chunk of stream `index` by its next non-empty chunk, if any, and
+    /// builds the comparators between it and the current chunks of the other streams.
+    /// Returns whether the stream has a current chunk.
+    fn advance(&mut self, index: usize) -> Result<bool> {
+        self.heads[index] = None;
+        self.comparators[index].iter_mut().for_each(|x| x.clear());
+        self.comparators
+            .iter_mut()
+            .for_each(|comparators| comparators[index].clear());
+
+        let chunk = loop {
+            match self.streams[index].next().transpose()? {
+                Some(chunk) if chunk.is_empty() => continue,
+                Some(chunk) => break chunk,
+                None => return Ok(false),
+            }
+        };
+        for (other, head) in self.heads.iter().enumerate() {
+            if let Some(head) = head {
+                let (i, j) = (index.min(other), index.max(other));
+                self.comparators[i][j] = if index < other {
+                    self.build_comparators(&chunk, &head.chunk)?
+                } else {
+                    self.build_comparators(&head.chunk, &chunk)?
+                };
+            }
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.heads[index] = Some(Head { id, chunk, row: 0 });
+        Ok(true)
+    }
+
+    fn next_chunk(&mut self) -> Result<Option<Chunk<Arc<dyn Array>>>> {
+        if !self.started {
+            self.started = true;
+            for index in 0..self.streams.len() {
+                if self.advance(index)? {
+                    self.heap.push(index);
+                    let Self {
+                        heap,
+                        comparators,
+                        heads,
+                        ..
+                    } = self;
+                    let last = heap.len() - 1;
+                    sift_up(heap, last, |lhs, rhs| {
+                        is_before(comparators, heads, lhs, rhs)
+                    });
+                }
+            }
+        }
+
+        // the chunks used by this output and the slices of them, in order
+        let mut chunks: Vec<(usize, Chunk<Arc<dyn Array>>)> = vec![];
+        let mut slices: Vec<MergeSlice> = vec![];
+        let mut rows = 0;
+        while rows < self.chunk_size {
+            let stream = match self.heap.first() {
+                Some(stream) => *stream,
+                None => break,
+            };
+            let head = self.heads[stream].as_mut().unwrap();
+            let position = match chunks.iter().position(|(id, _)| *id == head.id) {
+                Some(position) => position,
+                None => {
+                    chunks.push((head.id, head.chunk.clone()));
+                    chunks.len() - 1
+                }
+            };
+            match slices.last_mut() {
+                Some((last, start, len)) if *last == position && *start + *len == head.row => {
+                    *len += 1
+                }
+                _ => slices.push((position, head.row, 1)),
+            }
+            rows += 1;
+            head.row += 1;
+            if head.row == head.chunk.len() && !self.advance(stream)? {
+                // the stream is exhausted: replace it by the last of the heap
+                self.heap.swap_remove(0);
+            }
+            let Self {
+                heap,
+                comparators,
+                heads,
+                ..
+            } = self;
+            sift_down(heap, 0, |lhs, rhs| is_before(comparators, heads, lhs, rhs));
+        }
+
+        if rows == 0 {
+            return Ok(None);
+        }
+
+        let num_columns = chunks[0].1.arrays().len();
+        let arrays = (0..num_columns)
+            .map(|column| {
+                let arrays = chunks
+                    .iter()
+                    .map(|(_, chunk)| chunk.arrays()[column].as_ref())
+                    .collect::<Vec<_>>();
+                Arc::from(take_arrays(&arrays, slices.iter().copied(), None))
+            })
+            .collect();
+        Chunk::try_new(arrays).map(Some)
+    }
+}
+
+impl<I> Iterator for MergeStreams<I>
+where
+    I: Iterator<Item = Result<Chunk<Arc<dyn Array>>>>,
+{
+    type Item = Result<Chunk<Arc<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk().transpose()
+    }
+}
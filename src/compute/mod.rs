@@ -30,6 +30,9 @@ pub mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_cast")))]
 pub mod cast;
+#[cfg(feature = "compute_coalesce")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_coalesce")))]
+pub mod coalesce;
 #[cfg(feature = "compute_comparison")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_comparison")))]
 pub mod comparison;
@@ -39,9 +42,18 @@ pub mod concatenate;
 #[cfg(feature = "compute_contains")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_contains")))]
 pub mod contains;
+#[cfg(feature = "compute_distinct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_distinct")))]
+pub mod distinct;
+#[cfg(feature = "compute_encoding")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_encoding")))]
+pub mod encoding;
 #[cfg(feature = "compute_filter")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_filter")))]
 pub mod filter;
+#[cfg(feature = "compute_group")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_group")))]
+pub mod group;
 #[cfg(feature = "compute_hash")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_hash")))]
 pub mod hash;
@@ -57,18 +69,30 @@ pub mod like;
 #[cfg(feature = "compute_limit")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_limit")))]
 pub mod limit;
+#[cfg(feature = "compute_map")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_map")))]
+pub mod map;
+#[cfg(feature = "compute_math")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_math")))]
+pub mod math;
 #[cfg(feature = "compute_merge_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_merge_sort")))]
 pub mod merge_sort;
 #[cfg(feature = "compute_nullif")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_nullif")))]
 pub mod nullif;
+#[cfg(feature = "compute_parse")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_parse")))]
+pub mod parse;
 #[cfg(feature = "compute_partition")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_partition")))]
 pub mod partition;
 #[cfg(feature = "compute_regex_match")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_regex_match")))]
 pub mod regex_match;
+#[cfg(feature = "compute_selection")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_selection")))]
+pub mod selection;
 #[cfg(feature = "compute_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_sort")))]
 pub mod sort;
@@ -1,8 +1,8 @@
 //! Contains the operator [`nullif`].
-use crate::array::PrimitiveArray;
+use crate::array::{BooleanArray, PrimitiveArray};
 use crate::bitmap::Bitmap;
 use crate::compute::comparison::{
-    primitive_compare_values_op, primitive_compare_values_op_scalar, Simd8, Simd8PartialEq,
+    self, primitive_compare_values_op, primitive_compare_values_op_scalar, Simd8, Simd8PartialEq,
 };
 use crate::datatypes::DataType;
 use crate::scalar::PrimitiveScalar;
@@ -78,6 +78,15 @@ where
     PrimitiveArray::<T>::new(lhs.data_type().clone(), lhs.values().clone(), validity)
 }
 
+/// Returns `validity` unset wherever `equal` is `true` (null slots of `equal` are ignored).
+fn nullif_validity(validity: Option<&Bitmap>, equal: &BooleanArray) -> Option<Bitmap> {
+    let equal = match equal.validity() {
+        Some(equal_validity) => equal.values() & equal_validity,
+        None => equal.values().clone(),
+    };
+    combine_validities(validity, Some(&!&equal))
+}
+
 /// Returns an [`Array`] with the same type as `lhs` and whose validity
 /// is null iff either `lhs == rhs` or `lhs` is null.
 ///
@@ -89,7 +98,7 @@ where
 /// * The physical type is not supported for this operation (use [`can_nullif`] to check)
 /// # Example
 /// ```rust
-/// # use arrow2::array::Int32Array;
+/// # use arrow2::array::{Int32Array, Utf8Array};
 /// # use arrow2::datatypes::DataType;
 /// # use arrow2::compute::nullif::nullif;
 /// # fn main() {
@@ -100,6 +109,14 @@ where
 /// let expected = Int32Array::from(&[None, None, Some(1), None, Some(1)]);
 ///
 /// assert_eq!(expected, result.as_ref());
+///
+/// let lhs = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None]);
+/// let rhs = Utf8Array::<i32>::from(&[Some("a"), Some("c"), Some("c")]);
+/// let result = nullif(&lhs, &rhs);
+///
+/// let expected = Utf8Array::<i32>::from(&[None, Some("b"), None]);
+///
+/// assert_eq!(expected, result.as_ref());
 /// # }
 /// ```
 pub fn nullif(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
@@ -114,7 +131,10 @@ pub fn nullif(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
                 rhs.as_any().downcast_ref().unwrap(),
             ))
         }),
-        other => unimplemented!("Nullif is not implemented for physical type {:?}", other),
+        _ => {
+            let equal = comparison::eq(lhs, rhs);
+            lhs.with_validity(nullif_validity(lhs.validity(), &equal))
+        }
     }
 }
 
@@ -122,7 +142,7 @@ pub fn nullif(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// is null iff either `lhs == rhs` or `lhs` is null.
 /// # Panics
 /// iff
-/// * Scalar is null
+/// * `rhs` is a null scalar of a primitive type
 /// * lhs and rhs do not have the same type
 /// * The physical type is not supported for this operation (use [`can_nullif`] to check)
 /// # Example
@@ -154,7 +174,10 @@ pub fn nullif_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
                 scalar,
             ))
         }),
-        other => unimplemented!("Nullif is not implemented for physical type {:?}", other),
+        _ => {
+            let equal = comparison::eq_scalar(lhs, rhs);
+            lhs.with_validity(nullif_validity(lhs.validity(), &equal))
+        }
     }
 }
 
@@ -164,5 +187,5 @@ pub fn can_nullif(lhs: &DataType, rhs: &DataType) -> bool {
         return false;
     };
     use crate::datatypes::PhysicalType;
-    matches!(lhs.to_physical_type(), PhysicalType::Primitive(_))
+    matches!(lhs.to_physical_type(), PhysicalType::Primitive(_)) || comparison::can_eq(lhs)
 }
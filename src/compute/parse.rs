@@ -0,0 +1,275 @@
+//! Defines kernels to parse numbers from [`Utf8Array`]s, with options to handle
+//! locale-specific formats such as `1.234,5`.
+//!
+//! Contrary to [`cast`](crate::compute::cast), these kernels allow declaring how numbers
+//! are formatted (thousands and decimal separators, whitespace) and whether values that
+//! can't be parsed become null or error.
+
+use num_traits::{Float, PrimInt};
+
+use crate::{
+    array::*,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    types::{
+        decimal::{self, RoundingMode},
+        NativeType,
+    },
+};
+
+/// What to do with (non-null) values that can't be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnError {
+    /// The value becomes null
+    Null,
+    /// The kernel errors
+    Error,
+}
+
+/// Options declaring how numbers are formatted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// The character separating groups of thousands (e.g. `Some(',')` for `1,000`), which
+    /// is ignored. Defaults to `None`.
+    pub thousands_separator: Option<char>,
+    /// The character separating the integer from the fractional part. Defaults to `.`.
+    pub decimal_separator: char,
+    /// Whether leading and trailing whitespace is ignored. Defaults to `false`.
+    pub trim: bool,
+    /// What to do with values that can't be parsed. Defaults to [`OnError::Null`].
+    pub on_error: OnError,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            thousands_separator: None,
+            decimal_separator: '.',
+            trim: false,
+            on_error: OnError::Null,
+        }
+    }
+}
+
+/// Returns `value` without whitespace (if `trim`) nor thousands separators and with
+/// `.` as decimal separator, or `None` if it contains a `.` that is not a separator.
+fn normalize<'a>(
+    value: &'a str,
+    options: &ParseOptions,
+    buffer: &'a mut Vec<u8>,
+) -> Option<&'a [u8]> {
+    let value = if options.trim { value.trim() } else { value };
+    if options.thousands_separator.is_none() && options.decimal_separator == '.' {
+        return Some(value.as_bytes());
+    }
+
+    buffer.clear();
+    for c in value.chars() {
+        if Some(c) == options.thousands_separator {
+            continue;
+        } else if c == options.decimal_separator {
+            buffer.push(b'.');
+        } else if c == '.' {
+            return None;
+        } else {
+            let mut bytes = [0; 4];
+            buffer.extend_from_slice(c.encode_utf8(&mut bytes).as_bytes());
+        }
+    }
+    Some(buffer)
+}
+
+fn parse_values<T, O, F>(
+    array: &Utf8Array<O>,
+    data_type: DataType,
+    options: ParseOptions,
+    parse: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    O: Offset,
+    F: Fn(&[u8]) -> Option<T>,
+{
+    if options.thousands_separator == Some(options.decimal_separator) {
+        return Err(ArrowError::InvalidArgumentError(
+            "The thousands separator must be different from the decimal separator".to_string(),
+        ));
+    }
+
+    let mut buffer = vec![];
+    let mut values = MutablePrimitiveArray::<T>::with_capacity(array.len());
+    for value in array.iter() {
+        let value = match value {
+            Some(value) => value,
+            None => {
+                values.push(None);
+                continue;
+            }
+        };
+        match normalize(value, &options, &mut buffer).and_then(&parse) {
+            Some(parsed) => values.push(Some(parsed)),
+            None if options.on_error == OnError::Null => values.push(None),
+            None => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Could not parse {:?} as {:?}",
+                    value, data_type
+                )))
+            }
+        }
+    }
+    Ok(values.to(data_type).into())
+}
+
+/// Parses the values of a [`Utf8Array`] into integers according to `options`.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::parse::{parse_int, ParseOptions};
+///
+/// let array = Utf8Array::<i32>::from([Some(" 1,000"), Some("-2"), Some("a"), None]);
+/// let options = ParseOptions { thousands_separator: Some(','), trim: true, ..Default::default() };
+/// let result = parse_int::<i32, _>(&array, options).unwrap();
+/// assert_eq!(result, Int32Array::from([Some(1000), Some(-2), None, None]));
+/// ```
+/// # Error
+/// Errors iff the thousands separator equals the decimal separator or a value can't be
+/// parsed and `options.on_error` is [`OnError::Error`].
+pub fn parse_int<T, O>(array: &Utf8Array<O>, options: ParseOptions) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PrimInt + lexical_core::FromLexical,
+    O: Offset,
+{
+    parse_values(array, T::PRIMITIVE.into(), options, |x| {
+        lexical_core::parse(x).ok()
+    })
+}
+
+/// Parses the values of a [`Utf8Array`] into floats according to `options`.
+/// # Example
+/// ```
+/// use arrow2::array::{Float64Array, Utf8Array};
+/// use arrow2::compute::parse::{parse_float, ParseOptions};
+///
+/// let array = Utf8Array::<i32>::from([Some("1.234,5"), Some("-0,5"), None]);
+/// let options = ParseOptions {
+///     thousands_separator: Some('.'),
+///     decimal_separator: ',',
+///     ..Default::default()
+/// };
+/// let result = parse_float::<f64, _>(&array, options).unwrap();
+/// assert_eq!(result, Float64Array::from([Some(1234.5), Some(-0.5), None]));
+/// ```
+/// # Error
+/// Errors iff the thousands separator equals the decimal separator or a value can't be
+/// parsed and `options.on_error` is [`OnError::Error`].
+pub fn parse_float<T, O>(array: &Utf8Array<O>, options: ParseOptions) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + Float + lexical_core::FromLexical,
+    O: Offset,
+{
+    parse_values(array, T::PRIMITIVE.into(), options, |x| {
+        lexical_core::parse(x).ok()
+    })
+}
+
+/// Parses the values of a [`Utf8Array`] into decimals of [`DataType::Decimal`] with
+/// `precision` and `scale`, according to `options`.
+///
+/// Values with more significant digits than `precision`, or more (non-zero) fractional
+/// digits than `scale`, can't be parsed.
+/// # Example
+/// ```
+/// use arrow2::array::{Int128Array, Utf8Array};
+/// use arrow2::compute::parse::{parse_decimal, ParseOptions};
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Utf8Array::<i32>::from([Some("1,000.5"), Some("-0.25"), Some("0.125")]);
+/// let options = ParseOptions { thousands_separator: Some(','), ..Default::default() };
+/// let result = parse_decimal(&array, 6, 2, options).unwrap();
+/// let expected = Int128Array::from([Some(100050), Some(-25), None]).to(DataType::Decimal(6, 2));
+/// assert_eq!(result, expected);
+/// ```
+/// # Error
+/// Errors iff `scale > precision`, the thousands separator equals the decimal separator or a
+/// value can't be parsed and `options.on_error` is [`OnError::Error`].
+pub fn parse_decimal<O: Offset>(
+    array: &Utf8Array<O>,
+    precision: usize,
+    scale: usize,
+    options: ParseOptions,
+) -> Result<PrimitiveArray<i128>> {
+    if scale > precision {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The scale ({}) of a decimal must not be larger than its precision ({})",
+            scale, precision
+        )));
+    }
+    parse_values(array, DataType::Decimal(precision, scale), options, |x| {
+        decimal::parse_decimal(x, precision, scale, RoundingMode::Reject)
+    })
+}
+
+fn parse_utf8<O: Offset>(
+    array: &Utf8Array<O>,
+    to_type: &DataType,
+    options: ParseOptions,
+) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    Ok(match to_type {
+        Int8 => Box::new(parse_int::<i8, O>(array, options)?),
+        Int16 => Box::new(parse_int::<i16, O>(array, options)?),
+        Int32 => Box::new(parse_int::<i32, O>(array, options)?),
+        Int64 => Box::new(parse_int::<i64, O>(array, options)?),
+        UInt8 => Box::new(parse_int::<u8, O>(array, options)?),
+        UInt16 => Box::new(parse_int::<u16, O>(array, options)?),
+        UInt32 => Box::new(parse_int::<u32, O>(array, options)?),
+        UInt64 => Box::new(parse_int::<u64, O>(array, options)?),
+        Float32 => Box::new(parse_float::<f32, O>(array, options)?),
+        Float64 => Box::new(parse_float::<f64, O>(array, options)?),
+        Decimal(precision, scale) => Box::new(parse_decimal(array, *precision, *scale, options)?),
+        _ => unreachable!(),
+    })
+}
+
+/// Returns whether [`parse`] supports parsing arrays of `from_type` into `to_type`.
+pub fn can_parse(from_type: &DataType, to_type: &DataType) -> bool {
+    use DataType::*;
+    matches!(from_type, Utf8 | LargeUtf8)
+        && matches!(
+            to_type,
+            Int8 | Int16
+                | Int32
+                | Int64
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Float32
+                | Float64
+                | Decimal(_, _)
+        )
+}
+
+/// Parses the values of a [`Utf8Array`] into an array of `to_type` according to `options`.
+/// # Error
+/// Errors iff the parsing is not supported (see [`can_parse`]), or the kernel of
+/// `to_type` ([`parse_int`], [`parse_float`] or [`parse_decimal`]) errors.
+pub fn parse(
+    array: &dyn Array,
+    to_type: &DataType,
+    options: ParseOptions,
+) -> Result<Box<dyn Array>> {
+    if !can_parse(array.data_type(), to_type) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Parsing {:?} into {:?} is not supported",
+            array.data_type(),
+            to_type
+        )));
+    }
+    match array.data_type() {
+        DataType::Utf8 => {
+            parse_utf8::<i32>(array.as_any().downcast_ref().unwrap(), to_type, options)
+        }
+        _ => parse_utf8::<i64>(array.as_any().downcast_ref().unwrap(), to_type, options),
+    }
+}
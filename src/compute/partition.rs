@@ -63,7 +63,7 @@ impl LexicographicalPartitionIterator {
 
         let comparators = columns
             .iter()
-            .map(|x| build_compare(x.values, x.options.unwrap_or_default()))
+            .map(|x| build_compare(x.values, x.values, x.options.unwrap_or_default()))
             .collect::<Result<Vec<_>>>()?;
 
         let comparator = Box::new(move |a_idx: usize, b_idx: usize| -> Ordering {
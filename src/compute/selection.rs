@@ -0,0 +1,325 @@
+//! Kernels that only compute a subset of rows of their inputs, declared by a [`SelectionVector`].
+//!
+//! When evaluating multiple predicates (e.g. `a > 1 AND b < a + c`), each predicate only
+//! needs to be evaluated on the rows that passed the previous ones. Instead of materializing
+//! a filtered copy of every input, the kernels in this module take a [`SelectionVector`]
+//! and only compute the selected rows; all other rows are null in the result. Unlike the
+//! kernels of [`crate::compute::arity`], neither the values nor the validities of the rows
+//! that are not selected are read.
+//!
+//! # Example
+//! ```
+//! use arrow2::array::{BooleanArray, PrimitiveArray};
+//! use arrow2::compute::selection::{add, lt, gt_scalar, SelectionVector};
+//!
+//! let a = PrimitiveArray::from_slice([1, 5, 2, 7]);
+//! let b = PrimitiveArray::from_slice([9, 1, 4, 9]);
+//! let c = PrimitiveArray::from_slice([0, 1, 3, 1]);
+//!
+//! // a > 1
+//! let selection = SelectionVector::all(a.len());
+//! let selection = selection.refine(&gt_scalar(&a, 1, &selection));
+//! assert_eq!(selection.indices(), &[1, 2, 3]);
+//!
+//! // ... AND b < a + c, where `a + c` is only computed on the rows [1, 2, 3]
+//! let sum = add(&a, &c, &selection);
+//! assert_eq!(sum, PrimitiveArray::from([None, Some(6), Some(5), Some(8)]));
+//! let selection = selection.refine(&lt(&b, &sum, &selection));
+//! assert_eq!(selection.indices(), &[1, 2]);
+//! ```
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{
+    array::{Array, BooleanArray, PrimitiveArray},
+    bitmap::{Bitmap, MutableBitmap},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+/// A sorted list of unique row indices that a kernel should compute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionVector {
+    indices: Vec<u32>,
+}
+
+impl SelectionVector {
+    /// Returns a new [`SelectionVector`].
+    /// # Error
+    /// Errors iff `indices` is not strictly increasing.
+    pub fn try_new(indices: Vec<u32>) -> Result<Self> {
+        if indices.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(ArrowError::InvalidArgumentError(
+                "The indices of a SelectionVector must be strictly increasing".to_string(),
+            ));
+        }
+        Ok(Self { indices })
+    }
+
+    /// Returns a [`SelectionVector`] selecting all rows `0..length`.
+    /// # Panics
+    /// Panics iff `length` does not fit in a `u32`.
+    pub fn all(length: usize) -> Self {
+        let length = u32::try_from(length).expect("The length must fit in a u32");
+        Self {
+            indices: (0..length).collect(),
+        }
+    }
+
+    /// Returns a [`SelectionVector`] selecting the rows of `mask` that are valid and `true`.
+    pub fn from_mask(mask: &BooleanArray) -> Self {
+        Self {
+            indices: mask.true_indices().collect(),
+        }
+    }
+
+    /// Returns the selected indices
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    /// Returns the number of selected rows
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns whether no row is selected
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns a new [`SelectionVector`] with the selected rows of `self` whose value on `mask`
+    /// is valid and `true`. Rows of `mask` that are not selected by `self` are not read, so
+    /// `mask` is usually the result of a kernel of this module computed on `self`.
+    /// # Panics
+    /// Panics iff a selected index is out of bounds of `mask`.
+    pub fn refine(&self, mask: &BooleanArray) -> Self {
+        let is_valid = |i: usize| match mask.validity() {
+            Some(validity) => validity.get_bit(i),
+            None => true,
+        };
+        let indices = self
+            .indices
+            .iter()
+            .copied()
+            .filter(|&i| {
+                let i = i as usize;
+                mask.values().get_bit(i) && is_valid(i)
+            })
+            .collect();
+        Self { indices }
+    }
+
+    /// Returns a [`Bitmap`] of `length` bits whose selected bits are set.
+    /// # Panics
+    /// Panics iff a selected index is out of bounds.
+    pub fn to_bitmap(&self, length: usize) -> Bitmap {
+        let mut bitmap = MutableBitmap::from_len_zeroed(length);
+        self.indices
+            .iter()
+            .for_each(|&i| bitmap.set(i as usize, true));
+        bitmap.into()
+    }
+
+    fn check_bounds(&self, length: usize) {
+        if let Some(&last) = self.indices.last() {
+            assert!(
+                (last as usize) < length,
+                "The selection index {} is out of bounds of an array of length {}",
+                last,
+                length
+            );
+        }
+    }
+}
+
+/// Applies `op` to the selected and valid rows of `array`.
+/// All other rows are null and their values are `O::default()`.
+/// # Panics
+/// Panics iff a selected index is out of bounds.
+pub fn unary<I, O, F>(
+    array: &PrimitiveArray<I>,
+    selection: &SelectionVector,
+    op: F,
+    data_type: DataType,
+) -> PrimitiveArray<O>
+where
+    I: NativeType,
+    O: NativeType,
+    F: Fn(I) -> O,
+{
+    let len = array.len();
+    selection.check_bounds(len);
+
+    let mut values = vec![O::default(); len];
+    let mut validity = MutableBitmap::from_len_zeroed(len);
+    for &i in selection.indices() {
+        let i = i as usize;
+        if array.is_valid(i) {
+            values[i] = op(array.values()[i]);
+            validity.set(i, true);
+        }
+    }
+    PrimitiveArray::new(data_type, values.into(), validity.into())
+}
+
+/// Applies `op` to the selected rows of `lhs` and `rhs` that are valid on both.
+/// All other rows are null and their values are `O::default()`.
+/// # Panics
+/// Panics iff the arrays have different lengths or a selected index is out of bounds.
+pub fn binary<T, D, O, F>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<D>,
+    selection: &SelectionVector,
+    data_type: DataType,
+    op: F,
+) -> PrimitiveArray<O>
+where
+    T: NativeType,
+    D: NativeType,
+    O: NativeType,
+    F: Fn(T, D) -> O,
+{
+    assert_eq!(lhs.len(), rhs.len(), "The arrays must have the same length");
+    let len = lhs.len();
+    selection.check_bounds(len);
+
+    // validities are only read on the selected rows
+    let is_valid = |i: usize| lhs.is_valid(i) && rhs.is_valid(i);
+
+    let mut values = vec![O::default(); len];
+    let mut validity = MutableBitmap::from_len_zeroed(len);
+    for &i in selection.indices() {
+        let i = i as usize;
+        if is_valid(i) {
+            values[i] = op(lhs.values()[i], rhs.values()[i]);
+            validity.set(i, true);
+        }
+    }
+    PrimitiveArray::new(data_type, values.into(), validity.into())
+}
+
+/// Applies the comparison `op` to the selected rows of `lhs` and `rhs` that are valid on both.
+/// All other rows are null.
+/// # Panics
+/// Panics iff the arrays have different lengths or a selected index is out of bounds.
+pub fn compare<T, F>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+    selection: &SelectionVector,
+    op: F,
+) -> BooleanArray
+where
+    T: NativeType,
+    F: Fn(T, T) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len(), "The arrays must have the same length");
+    let len = lhs.len();
+    selection.check_bounds(len);
+
+    // validities are only read on the selected rows
+    let is_valid = |i: usize| lhs.is_valid(i) && rhs.is_valid(i);
+
+    let mut values = MutableBitmap::from_len_zeroed(len);
+    let mut validity = MutableBitmap::from_len_zeroed(len);
+    for &i in selection.indices() {
+        let i = i as usize;
+        if is_valid(i) {
+            values.set(i, op(lhs.values()[i], rhs.values()[i]));
+            validity.set(i, true);
+        }
+    }
+    BooleanArray::new(DataType::Boolean, values.into(), validity.into())
+}
+
+/// Applies the comparison `op` between the selected and valid rows of `lhs` and `rhs`.
+/// All other rows are null.
+/// # Panics
+/// Panics iff a selected index is out of bounds.
+pub fn compare_scalar<T, F>(
+    lhs: &PrimitiveArray<T>,
+    rhs: T,
+    selection: &SelectionVector,
+    op: F,
+) -> BooleanArray
+where
+    T: NativeType,
+    F: Fn(T, T) -> bool,
+{
+    let len = lhs.len();
+    selection.check_bounds(len);
+
+    let mut values = MutableBitmap::from_len_zeroed(len);
+    let mut validity = MutableBitmap::from_len_zeroed(len);
+    for &i in selection.indices() {
+        let i = i as usize;
+        if lhs.is_valid(i) {
+            values.set(i, op(lhs.values()[i], rhs));
+            validity.set(i, true);
+        }
+    }
+    BooleanArray::new(DataType::Boolean, values.into(), validity.into())
+}
+
+macro_rules! arithmetic {
+    ($name:ident, $trait:ident, $op:tt, $doc:literal) => {
+        #[doc = concat!($doc, " the selected rows of two [`PrimitiveArray`]s; all other rows are null.")]
+        /// # Panics
+        /// Panics iff the arrays have different lengths, a selected index is out of bounds
+        /// or the operation panics on a selected and valid row (e.g. it overflows).
+        pub fn $name<T>(
+            lhs: &PrimitiveArray<T>,
+            rhs: &PrimitiveArray<T>,
+            selection: &SelectionVector,
+        ) -> PrimitiveArray<T>
+        where
+            T: NativeType + $trait<Output = T>,
+        {
+            binary(lhs, rhs, selection, lhs.data_type().clone(), |a, b| a $op b)
+        }
+    };
+}
+
+arithmetic!(add, Add, +, "Adds");
+arithmetic!(sub, Sub, -, "Subtracts");
+arithmetic!(mul, Mul, *, "Multiplies");
+arithmetic!(div, Div, /, "Divides");
+
+macro_rules! comparison {
+    ($name:ident, $scalar_name:ident, $op:tt, $doc:literal) => {
+        #[doc = concat!("Returns whether the selected rows of `lhs` are ", $doc, " the ones of `rhs`; all other rows are null.")]
+        /// # Panics
+        /// Panics iff the arrays have different lengths or a selected index is out of bounds.
+        pub fn $name<T>(
+            lhs: &PrimitiveArray<T>,
+            rhs: &PrimitiveArray<T>,
+            selection: &SelectionVector,
+        ) -> BooleanArray
+        where
+            T: NativeType + PartialOrd,
+        {
+            compare(lhs, rhs, selection, |a, b| a $op b)
+        }
+
+        #[doc = concat!("Returns whether the selected rows of `lhs` are ", $doc, " `rhs`; all other rows are null.")]
+        /// # Panics
+        /// Panics iff a selected index is out of bounds.
+        pub fn $scalar_name<T>(
+            lhs: &PrimitiveArray<T>,
+            rhs: T,
+            selection: &SelectionVector,
+        ) -> BooleanArray
+        where
+            T: NativeType + PartialOrd,
+        {
+            compare_scalar(lhs, rhs, selection, |a, b| a $op b)
+        }
+    };
+}
+
+comparison!(eq, eq_scalar, ==, "equal to");
+comparison!(neq, neq_scalar, !=, "not equal to");
+comparison!(lt, lt_scalar, <, "less than");
+comparison!(lt_eq, lt_eq_scalar, <=, "less than or equal to");
+comparison!(gt, gt_scalar, >, "greater than");
+comparison!(gt_eq, gt_eq_scalar, >=, "greater than or equal to");
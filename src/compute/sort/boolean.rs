@@ -6,6 +6,9 @@ use crate::{
 use super::SortOptions;
 
 /// Returns the indices that would sort a [`BooleanArray`].
+///
+/// Since a boolean only takes two values, the valid indices are sorted by a stable partition
+/// instead of a comparison sort, which stops as soon as `limit` indices are collected.
 pub fn sort_boolean<I: Index>(
     values: &BooleanArray,
     value_indices: Vec<I>,
@@ -14,39 +17,34 @@ pub fn sort_boolean<I: Index>(
     limit: Option<usize>,
 ) -> PrimitiveArray<I> {
     let descending = options.descending;
+    let limit = limit.unwrap_or(values.len()).min(values.len());
 
-    // create tuples that are used for sorting
-    let mut valids = value_indices
-        .into_iter()
-        .map(|index| (index, values.value(index.to_usize())))
-        .collect::<Vec<(I, bool)>>();
+    let (first, second) = if descending {
+        (true, false)
+    } else {
+        (false, true)
+    };
+    let valids = value_indices
+        .iter()
+        .filter(|index| values.value(index.to_usize()) == first)
+        .chain(
+            value_indices
+                .iter()
+                .filter(|index| values.value(index.to_usize()) == second),
+        )
+        .copied();
 
     let mut nulls = null_indices;
-
-    if !descending {
-        valids.sort_by(|a, b| a.1.cmp(&b.1));
-    } else {
-        valids.sort_by(|a, b| b.1.cmp(&a.1));
+    if descending {
         // reverse to keep a stable ordering
         nulls.reverse();
     }
 
-    let mut values = Vec::<I>::with_capacity(values.len());
-
-    if options.nulls_first {
-        values.extend_from_slice(nulls.as_slice());
-        values.extend(valids.iter().map(|x| x.0));
+    let values: Vec<I> = if options.nulls_first {
+        nulls.into_iter().chain(valids).take(limit).collect()
     } else {
-        // nulls last
-        values.extend(valids.iter().map(|x| x.0));
-        values.extend_from_slice(nulls.as_slice());
-    }
-
-    // un-efficient; there are much more performant ways of sorting nulls above, anyways.
-    if let Some(limit) = limit {
-        values.truncate(limit);
-        values.shrink_to_fit();
-    }
+        valids.chain(nulls).take(limit).collect()
+    };
 
     let data_type = I::PRIMITIVE.into();
     PrimitiveArray::<I>::new(data_type, values.into(), None)
@@ -78,48 +78,61 @@ fn build_is_valid(array: &dyn Array) -> IsValid {
     }
 }
 
-pub(crate) fn build_compare(array: &dyn Array, sort_option: SortOptions) -> Result<DynComparator> {
-    let is_valid = build_is_valid(array);
-    let comparator = ord::build_compare(array, array)?;
-
-    Ok(match (sort_option.descending, sort_option.nulls_first) {
-        (true, true) => Box::new(move |i: usize, j: usize| match (is_valid(i), is_valid(j)) {
-            (true, true) => match (comparator)(i, j) {
-                Ordering::Equal => Ordering::Equal,
-                other => other.reverse(),
-            },
-            (false, true) => Ordering::Less,
-            (true, false) => Ordering::Greater,
-            (false, false) => Ordering::Equal,
-        }),
-        (false, true) => Box::new(move |i: usize, j: usize| match (is_valid(i), is_valid(j)) {
-            (true, true) => match (comparator)(i, j) {
-                Ordering::Equal => Ordering::Equal,
-                other => other,
-            },
-            (false, true) => Ordering::Less,
-            (true, false) => Ordering::Greater,
-            (false, false) => Ordering::Equal,
-        }),
-        (false, false) => Box::new(move |i: usize, j: usize| match (is_valid(i), is_valid(j)) {
-            (true, true) => match (comparator)(i, j) {
-                Ordering::Equal => Ordering::Equal,
-                other => other,
-            },
-            (false, true) => Ordering::Greater,
-            (true, false) => Ordering::Less,
-            (false, false) => Ordering::Equal,
-        }),
-        (true, false) => Box::new(move |i: usize, j: usize| match (is_valid(i), is_valid(j)) {
-            (true, true) => match (comparator)(i, j) {
-                Ordering::Equal => Ordering::Equal,
-                other => other.reverse(),
-            },
-            (false, true) => Ordering::Greater,
-            (true, false) => Ordering::Less,
+/// Returns a comparator of the slots of `left` with the slots of `right` following `options`,
+/// i.e. the order used by [`sort`](super::sort) and [`lexsort`].
+///
+/// This can be used to implement custom sorts and merges (e.g. k-way merges) of arrays.
+/// # Example
+/// ```
+/// use std::cmp::Ordering;
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::sort::{build_compare, SortOptions};
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let left = Int32Array::from(&[Some(1), None]);
+/// let right = Int32Array::from(&[Some(2)]);
+/// let options = SortOptions { descending: true, nulls_first: false };
+///
+/// let cmp = build_compare(&left, &right, options)?;
+/// assert_eq!((cmp)(0, 0), Ordering::Greater);
+/// assert_eq!((cmp)(1, 0), Ordering::Greater);
+/// # Ok(())
+/// # }
+/// ```
+/// # Error
+/// Errors iff the arrays have different data types or their type has no natural order.
+pub fn build_compare(
+    left: &dyn Array,
+    right: &dyn Array,
+    options: SortOptions,
+) -> Result<DynComparator> {
+    let is_left_valid = build_is_valid(left);
+    let is_right_valid = build_is_valid(right);
+    let comparator = ord::build_compare(left, right)?;
+
+    // the ordering of a null relative to a value
+    let null_ordering = if options.nulls_first {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    };
+    let descending = options.descending;
+
+    Ok(Box::new(move |i: usize, j: usize| {
+        match (is_left_valid(i), is_right_valid(j)) {
+            (true, true) => {
+                let ordering = (comparator)(i, j);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+            (false, true) => null_ordering,
+            (true, false) => null_ordering.reverse(),
             (false, false) => Ordering::Equal,
-        }),
-    })
+        }
+    }))
 }
 
 /// Sorts a list of [`SortColumn`] into a non-nullable [`PrimitiveArray`]
@@ -150,7 +163,11 @@ pub fn lexsort_to_indices<I: Index>(
     let comparators = columns
         .iter()
         .map(|column| -> Result<DynComparator> {
-            build_compare(column.values, column.options.unwrap_or_default())
+            build_compare(
+                column.values,
+                column.values,
+                column.options.unwrap_or_default(),
+            )
         })
         .collect::<Result<Vec<DynComparator>>>()?;
 
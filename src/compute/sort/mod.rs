@@ -17,8 +17,7 @@ mod lex_sort;
 mod primitive;
 mod utf8;
 
-pub(crate) use lex_sort::build_compare;
-pub use lex_sort::{lexsort, lexsort_to_indices, SortColumn};
+pub use lex_sort::{build_compare, lexsort, lexsort_to_indices, SortColumn};
 
 macro_rules! dyn_sort {
     ($ty:ty, $array:expr, $cmp:expr, $options:expr, $limit:expr) => {{
@@ -36,6 +35,9 @@ macro_rules! dyn_sort {
 ///
 /// Performs an unstable sort on values and indices. Nulls are ordered according to the `nulls_first` flag in `options`.
 /// Floats are sorted using IEEE 754 totalOrder
+///
+/// When `limit` is `Some(k)`, only the first `k` elements of the sorted array are returned
+/// (see [`sort_to_indices`]).
 /// # Errors
 /// Errors if the [`DataType`] is not supported.
 pub fn sort(
@@ -94,6 +96,11 @@ macro_rules! dyn_sort_indices {
 }
 
 /// Sort elements from `values` into a non-nullable [`PrimitiveArray`] of indices that sort `values`.
+///
+/// When `limit` is `Some(k)`, only the indices of the first `k` elements of the sorted array are
+/// returned. For primitive, binary, utf8 and dictionary arrays these are found by a partial
+/// selection followed by a sort of the `k` selected elements, which avoids sorting the whole
+/// array for top-k queries.
 pub fn sort_to_indices<I: Index>(
     values: &dyn Array,
     options: &SortOptions,
@@ -202,14 +209,9 @@ pub fn sort_to_indices<I: Index>(
                 ))),
             }
         }
-        DataType::Dictionary(key_type, value_type, _) => match value_type.as_ref() {
-            DataType::Utf8 => Ok(sort_dict::<I, i32>(values, key_type, options, limit)),
-            DataType::LargeUtf8 => Ok(sort_dict::<I, i64>(values, key_type, options, limit)),
-            t => Err(ArrowError::NotYetImplemented(format!(
-                "Sort not supported for dictionary type with keys {:?}",
-                t
-            ))),
-        },
+        DataType::Dictionary(key_type, _, _) => match_integer_type!(key_type, |$T| {
+            sort_dict::<I, $T>(values.as_any().downcast_ref().unwrap(), options, limit)
+        }),
         t => Err(ArrowError::NotYetImplemented(format!(
             "Sort not supported for data type {:?}",
             t
@@ -217,19 +219,48 @@ pub fn sort_to_indices<I: Index>(
     }
 }
 
-fn sort_dict<I: Index, O: Offset>(
-    values: &dyn Array,
-    key_type: &IntegerType,
+/// Returns the indices that sort a [`DictionaryArray`] by its values.
+///
+/// The dictionary's values are sorted once to compute the rank of each value, after which the
+/// keys are sorted by the rank of the value they point to. Slots whose key or value is null are
+/// considered null.
+fn sort_dict<I: Index, K: DictionaryKey>(
+    array: &DictionaryArray<K>,
     options: &SortOptions,
     limit: Option<usize>,
-) -> PrimitiveArray<I> {
-    match_integer_type!(key_type, |$T| {
-        utf8::indices_sorted_unstable_by_dictionary::<I, $T, O>(
-            values.as_any().downcast_ref().unwrap(),
-            options,
-            limit,
-        )
-    })
+) -> Result<PrimitiveArray<I>> {
+    let values = array.values().as_ref();
+    let ascending = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let sorted = sort_to_indices::<u64>(values, &ascending, None)?;
+
+    let mut ranks = vec![0u64; values.len()];
+    sorted
+        .values()
+        .iter()
+        .enumerate()
+        .for_each(|(rank, index)| ranks[*index as usize] = rank as u64);
+
+    let ranks = array
+        .keys()
+        .iter()
+        .map(|key| {
+            key.and_then(|key| {
+                // Note: there is no check that the keys are within bounds of the dictionary.
+                let index = key.to_usize().unwrap();
+                values.is_valid(index).then(|| ranks[index])
+            })
+        })
+        .collect::<PrimitiveArray<u64>>();
+
+    Ok(primitive::indices_sorted_unstable_by::<I, u64, _>(
+        &ranks,
+        ord::total_cmp,
+        options,
+        limit,
+    ))
 }
 
 /// Checks if an array of type `datatype` can be sorted
@@ -282,9 +313,7 @@ pub fn can_sort(data_type: &DataType) -> bool {
                     | DataType::UInt64
             )
         }
-        DataType::Dictionary(_, value_type, _) => {
-            matches!(*value_type.as_ref(), DataType::Utf8 | DataType::LargeUtf8)
-        }
+        DataType::Dictionary(_, value_type, _) => can_sort(value_type.as_ref()),
         _ => false,
     }
 }
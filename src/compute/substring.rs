@@ -23,111 +23,196 @@ use crate::{
     error::{ArrowError, Result},
 };
 
-fn utf8_substring<O: Offset>(array: &Utf8Array<O>, start: O, length: &Option<O>) -> Utf8Array<O> {
-    let length = length.map(|v| v.to_usize());
+/// Returns the character range of `value` starting at character `start` with at most
+/// `length` characters. A negative `start` counts from the end of `value`.
+fn substring_chars_value(value: &str, start: i64, length: Option<u64>) -> &str {
+    let start = if start >= 0 {
+        start as usize
+    } else {
+        let chars = value.chars().count();
+        chars.saturating_sub(start.unsigned_abs() as usize)
+    };
 
-    let iter = array.values_iter().map(|str_val| {
-        // compute where we should start slicing this entry.
-        let start = if start >= O::zero() {
-            start.to_usize()
-        } else {
-            let start = (O::zero() - start).to_usize();
-            str_val
-                .char_indices()
-                .rev()
-                .nth(start)
-                .map(|(idx, _)| idx + 1)
-                .unwrap_or(0)
-        };
+    // the byte index of each char, followed by the end of the value
+    let mut indices = value
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain(std::iter::once(value.len()));
 
-        let mut iter_chars = str_val.char_indices();
-        if let Some((start_idx, _)) = iter_chars.nth(start) {
-            // length of the str
-            let len_end = str_val.len() - start_idx;
-
-            // length to slice
-            let length = length.unwrap_or(len_end);
+    let start = match indices.nth(start) {
+        Some(start) => start,
+        None => return "",
+    };
+    let end = match length {
+        None => value.len(),
+        Some(0) => start,
+        Some(length) => indices.nth(length as usize - 1).unwrap_or(value.len()),
+    };
+    &value[start..end]
+}
 
-            if length == 0 {
-                return "";
-            }
-            // compute
-            let end_idx = iter_chars
-                .nth(length.saturating_sub(1))
-                .map(|(idx, _)| idx)
-                .unwrap_or(str_val.len());
+fn utf8_substring_chars<O: Offset>(
+    array: &Utf8Array<O>,
+    start: i64,
+    length: Option<u64>,
+) -> Utf8Array<O> {
+    if array.is_ascii() {
+        // every char is a single byte
+        let (offsets, values) =
+            substring_bytes_values(array.offsets(), array.values(), start, length);
+        return Utf8Array::<O>::new(
+            array.data_type().clone(),
+            offsets.into(),
+            values.into(),
+            array.validity().cloned(),
+        );
+    }
 
-            &str_val[start_idx..end_idx]
-        } else {
-            ""
-        }
-    });
+    let iter = array
+        .values_iter()
+        .map(|value| substring_chars_value(value, start, length));
 
     let new = Utf8Array::<O>::from_trusted_len_values_iter(iter);
     new.with_validity(array.validity().cloned())
 }
 
-fn binary_substring<O: Offset>(
-    array: &BinaryArray<O>,
-    start: O,
-    length: &Option<O>,
-) -> BinaryArray<O> {
-    let validity = array.validity();
-    let offsets = array.offsets();
-    let values = array.values();
-
-    let mut new_offsets = Vec::<O>::with_capacity(array.len() + 1);
+/// Slices each value declared by `offsets` over `values` by bytes, returning the new
+/// offsets and values.
+fn substring_bytes_values<O: Offset>(
+    offsets: &[O],
+    values: &[u8],
+    start: i64,
+    length: Option<u64>,
+) -> (Vec<O>, Vec<u8>) {
+    let mut new_offsets = Vec::<O>::with_capacity(offsets.len());
     let mut new_values = Vec::<u8>::new(); // we have no way to estimate how much this will be.
 
     let mut length_so_far = O::zero();
     new_offsets.push(length_so_far);
 
     offsets.windows(2).for_each(|windows| {
-        let length_i: O = windows[1] - windows[0];
+        let offset = windows[0].to_usize();
+        let length_i = windows[1].to_usize() - offset;
 
         // compute where we should start slicing this entry
-        let start = windows[0]
-            + if start >= O::zero() {
-                start
-            } else {
-                length_i + start
-            };
-        let start = start.max(windows[0]).min(windows[1]);
-
-        let length: O = length
-            .unwrap_or(length_i)
-            // .max(0) is not needed as it is guaranteed
-            .min(windows[1] - start); // so we do not go beyond this entry
-        length_so_far += length;
-        new_offsets.push(length_so_far);
-
-        // we need usize for ranges
-        let start = start.to_usize();
-        let length = length.to_usize();
+        let start = if start >= 0 {
+            (start as u64).min(length_i as u64) as usize
+        } else {
+            length_i - start.unsigned_abs().min(length_i as u64) as usize
+        };
+        // so we do not go beyond this entry
+        let length = length.map_or(length_i - start, |length| {
+            length.min((length_i - start) as u64) as usize
+        });
 
+        let start = offset + start;
         new_values.extend_from_slice(&values[start..start + length]);
+        length_so_far += O::from_usize(length).unwrap();
+        new_offsets.push(length_so_far);
     });
+    (new_offsets, new_values)
+}
+
+fn binary_substring<O: Offset>(
+    array: &BinaryArray<O>,
+    start: i64,
+    length: Option<u64>,
+) -> BinaryArray<O> {
+    let (offsets, values) = substring_bytes_values(array.offsets(), array.values(), start, length);
 
     BinaryArray::<O>::new(
         array.data_type().clone(),
-        new_offsets.into(),
-        new_values.into(),
-        validity.cloned(),
+        offsets.into(),
+        values.into(),
+        array.validity().cloned(),
     )
 }
 
-/// Returns an ArrayRef with a substring starting from `start` and with optional length `length` of each of the elements in `array`.
-/// `start` can be negative, in which case the start counts from the end of the string.
-/// this function errors when the passed array is not a \[Large\]String array.
+fn utf8_substring_bytes<O: Offset>(
+    array: &Utf8Array<O>,
+    start: i64,
+    length: Option<u64>,
+) -> Result<Utf8Array<O>> {
+    let (offsets, values) = substring_bytes_values(array.offsets(), array.values(), start, length);
+
+    Utf8Array::<O>::try_new(
+        array.data_type().clone(),
+        offsets.into(),
+        values.into(),
+        array.validity().cloned(),
+    )
+    .map_err(|_| {
+        ArrowError::InvalidArgumentError(
+            "substring_bytes can only slice utf8 values at char boundaries".to_string(),
+        )
+    })
+}
+
+/// Returns an [`Array`] with a substring starting from `start` and with optional length
+/// `length` of each of the elements in `array`.
+/// `start` can be negative, in which case the start counts from the end of each element.
+///
+/// Elements of \[Large\]Utf8 arrays are sliced by characters (see [`substring_chars`]) and
+/// elements of \[Large\]Binary arrays are sliced by bytes (see [`substring_bytes`]).
+/// # Error
+/// Errors iff the array is not a \[Large\]Utf8 or \[Large\]Binary array.
 pub fn substring(array: &dyn Array, start: i64, length: &Option<u64>) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::Utf8 | DataType::LargeUtf8 => substring_chars(array, start, length),
+        _ => substring_bytes(array, start, length),
+    }
+}
+
+/// Returns an [`Array`] with a substring starting from the character `start` and with at most
+/// `length` characters of each of the elements in `array`.
+/// `start` can be negative, in which case the start counts from the end of each element.
+///
+/// Arrays whose values are all ASCII are sliced by bytes, which is equivalent and faster.
+/// # Error
+/// Errors iff the array is not a \[Large\]Utf8 array.
+pub fn substring_chars(
+    array: &dyn Array,
+    start: i64,
+    length: &Option<u64>,
+) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::Utf8 => Ok(Box::new(utf8_substring_chars(
+            array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap(),
+            start,
+            *length,
+        ))),
+        DataType::LargeUtf8 => Ok(Box::new(utf8_substring_chars(
+            array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap(),
+            start,
+            *length,
+        ))),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "substring_chars does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Returns an [`Array`] with a substring starting from the byte `start` and with at most
+/// `length` bytes of each of the elements in `array`.
+/// `start` can be negative, in which case the start counts from the end of each element.
+/// # Error
+/// Errors iff:
+/// * the array is not a \[Large\]Utf8 or \[Large\]Binary array
+/// * the array is a \[Large\]Utf8 array and a substring does not start or end at a char boundary
+pub fn substring_bytes(
+    array: &dyn Array,
+    start: i64,
+    length: &Option<u64>,
+) -> Result<Box<dyn Array>> {
     match array.data_type() {
         DataType::Binary => Ok(Box::new(binary_substring(
             array
                 .as_any()
                 .downcast_ref::<BinaryArray<i32>>()
                 .expect("A binary is expected"),
-            start as i32,
-            &length.map(|e| e as i32),
+            start,
+            *length,
         ))),
         DataType::LargeBinary => Ok(Box::new(binary_substring(
             array
@@ -135,24 +220,24 @@ pub fn substring(array: &dyn Array, start: i64, length: &Option<u64>) -> Result<
                 .downcast_ref::<BinaryArray<i64>>()
                 .expect("A large binary is expected"),
             start,
-            &length.map(|e| e as i64),
+            *length,
         ))),
-        DataType::LargeUtf8 => Ok(Box::new(utf8_substring(
+        DataType::Utf8 => Ok(Box::new(utf8_substring_bytes(
             array
                 .as_any()
-                .downcast_ref::<Utf8Array<i64>>()
-                .expect("A large string is expected"),
+                .downcast_ref::<Utf8Array<i32>>()
+                .expect("A string is expected"),
             start,
-            &length.map(|e| e as i64),
-        ))),
-        DataType::Utf8 => Ok(Box::new(utf8_substring(
+            *length,
+        )?)),
+        DataType::LargeUtf8 => Ok(Box::new(utf8_substring_bytes(
             array
                 .as_any()
-                .downcast_ref::<Utf8Array<i32>>()
-                .expect("A string is expected"),
-            start as i32,
-            &length.map(|e| e as i32),
-        ))),
+                .downcast_ref::<Utf8Array<i64>>()
+                .expect("A large string is expected"),
+            start,
+            *length,
+        )?)),
         _ => Err(ArrowError::InvalidArgumentError(format!(
             "substring does not support type {:?}",
             array.data_type()
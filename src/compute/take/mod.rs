@@ -18,9 +18,9 @@
 //! Defines take kernel for [`Array`]
 
 use crate::{
-    array::{new_empty_array, Array, NullArray, PrimitiveArray},
+    array::{growable::make_growable, new_empty_array, Array, NullArray, PrimitiveArray},
     datatypes::DataType,
-    error::Result,
+    error::{ArrowError, Result},
     types::Index,
 };
 
@@ -94,6 +94,46 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
     }
 }
 
+/// Returns a new [`Array`] with the slices of `values` at `ranges`, in order, where each range
+/// is a `(start, length)` pair.
+///
+/// This is equivalent to [`take`] with the indices of every range, but copies each slice at
+/// once into an array of exact capacity. It is therefore much faster when the selection is
+/// made of contiguous runs of rows, e.g. the row ranges selected by pruning with a page index.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::take::take_ranges;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3), Some(4), Some(5)]);
+/// let result = take_ranges(&array, &[(3, 2), (0, 2)]).unwrap();
+/// let expected = Int32Array::from(&[Some(4), Some(5), Some(1), None]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+/// # Errors
+/// Errors iff a range is out of bounds of `values`.
+pub fn take_ranges(values: &dyn Array, ranges: &[(usize, usize)]) -> Result<Box<dyn Array>> {
+    let mut capacity = 0usize;
+    for &(start, length) in ranges {
+        let end = start.checked_add(length).ok_or(ArrowError::Overflow)?;
+        if end > values.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The range ({}, {}) is out of bounds of an array of length {}",
+                start,
+                length,
+                values.len()
+            )));
+        }
+        capacity += length;
+    }
+
+    let mut growable = make_growable(&[values], false, capacity);
+    for &(start, length) in ranges {
+        growable.extend(0, start, length);
+    }
+    Ok(growable.as_box())
+}
+
 /// Checks if an array of type `datatype` can perform take operation
 ///
 /// # Examples
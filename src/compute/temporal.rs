@@ -17,6 +17,9 @@
 
 //! Defines temporal kernels for time and date related functions.
 
+use std::fmt::{Display, Write};
+
+use chrono::format::{Item, StrftimeItems};
 use chrono::{Datelike, Timelike};
 
 use crate::array::*;
@@ -33,6 +36,14 @@ trait U32Weekday: Datelike {
     fn u32_weekday(&self) -> u32 {
         self.weekday().number_from_monday()
     }
+
+    fn u32_days_from_monday(&self) -> u32 {
+        self.weekday().num_days_from_monday()
+    }
+
+    fn u32_days_from_sunday(&self) -> u32 {
+        self.weekday().num_days_from_sunday()
+    }
 }
 
 impl U32Weekday for chrono::NaiveDateTime {}
@@ -109,6 +120,25 @@ pub fn iso_week(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
     date_like!(u32_iso_week, array, DataType::UInt32)
 }
 
+/// The day that starts the week, see [`day_of_week`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeekStart {
+    /// Monday is 0, Tuesday is 1, ..., Sunday is 6 (as in ISO 8601)
+    Monday,
+    /// Sunday is 0, Monday is 1, ..., Saturday is 6
+    Sunday,
+}
+
+/// Extracts the day of the week of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 0 (`week_start`) to 6.
+/// Use [`can_day_of_week`] to check if this operation is supported for the target [`DataType`]
+pub fn day_of_week(array: &dyn Array, week_start: WeekStart) -> Result<PrimitiveArray<u32>> {
+    match week_start {
+        WeekStart::Monday => date_like!(u32_days_from_monday, array, DataType::UInt32),
+        WeekStart::Sunday => date_like!(u32_days_from_sunday, array, DataType::UInt32),
+    }
+}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Timelike` methods on Arrays
 macro_rules! time_like {
@@ -348,6 +378,11 @@ pub fn can_weekday(data_type: &DataType) -> bool {
     can_date(data_type)
 }
 
+/// Checks if an array of type `data_type` can perform day of week operation
+pub fn can_day_of_week(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
 /// Checks if an array of type `data_type` can perform ISO week operation
 pub fn can_iso_week(data_type: &DataType) -> bool {
     can_date(data_type)
@@ -401,3 +436,264 @@ fn can_time(data_type: &DataType) -> bool {
             | DataType::Timestamp(_, _)
     )
 }
+
+// Macro to avoid repetition in functions that format the slots of date-like arrays:
+// `$op` is applied to either `chrono::NaiveDateTime` or `chrono::DateTime`.
+macro_rules! format_like {
+    ($array:ident, |$x:ident| $op:expr) => {
+        match $array.data_type().to_logical_type() {
+            DataType::Date32 => {
+                let array = $array
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i32>>()
+                    .unwrap();
+                format_values(array, |x| {
+                    let $x = date32_to_datetime(x);
+                    $op
+                })
+            }
+            DataType::Date64 => {
+                let array = $array
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i64>>()
+                    .unwrap();
+                format_values(array, |x| {
+                    let $x = date64_to_datetime(x);
+                    $op
+                })
+            }
+            DataType::Timestamp(time_unit, None) => {
+                let array = $array
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i64>>()
+                    .unwrap();
+                format_values(array, |x| {
+                    let $x = timestamp_to_naive_datetime(x, *time_unit);
+                    $op
+                })
+            }
+            DataType::Timestamp(time_unit, Some(timezone_str)) => {
+                let array = $array
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i64>>()
+                    .unwrap();
+                if let Ok(timezone) = parse_offset(timezone_str) {
+                    format_values(array, |x| {
+                        let $x = timestamp_to_datetime(x, *time_unit, &timezone);
+                        $op
+                    })
+                } else {
+                    format_tz(array, *time_unit, timezone_str, |$x| $op)
+                }
+            }
+            dt => Err(ArrowError::NotYetImplemented(format!(
+                "Formatting does not support type {:?}",
+                dt
+            ))),
+        }
+    };
+}
+
+/// Writes each valid slot of `array`, mapped by `op`, to a [`Utf8Array`].
+fn format_values<T, D, F>(array: &PrimitiveArray<T>, op: F) -> Result<Utf8Array<i32>>
+where
+    T: NativeType,
+    D: Display,
+    F: Fn(T) -> D,
+{
+    let mut values = String::new();
+    let mut offsets = Vec::with_capacity(array.len() + 1);
+    offsets.push(0i32);
+    for (index, x) in array.values().iter().enumerate() {
+        if array.is_valid(index) {
+            write!(values, "{}", op(*x)).map_err(|_| {
+                ArrowError::InvalidArgumentError(
+                    "the format is not supported by the array's type \
+                    (e.g. a timezone of an array without timezone)"
+                        .to_string(),
+                )
+            })?;
+        }
+        offsets.push(i32::try_from(values.len()).map_err(|_| ArrowError::Overflow)?);
+    }
+    Utf8Array::try_new(
+        DataType::Utf8,
+        offsets.into(),
+        values.into_bytes().into(),
+        array.validity().cloned(),
+    )
+}
+
+#[cfg(feature = "chrono-tz")]
+fn format_tz<D, F>(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    op: F,
+) -> Result<Utf8Array<i32>>
+where
+    D: Display,
+    F: Fn(chrono::DateTime<chrono_tz::Tz>) -> D,
+{
+    let timezone = parse_offset_tz(timezone_str)?;
+    format_values(array, |x| {
+        op(timestamp_to_datetime(x, time_unit, &timezone))
+    })
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn format_tz<D, F>(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+    _: F,
+) -> Result<Utf8Array<i32>>
+where
+    D: Display,
+    F: Fn(chrono::DateTime<chrono::FixedOffset>) -> D,
+{
+    Err(ArrowError::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+fn strftime_items(format: &str) -> Result<Vec<Item<'_>>> {
+    let items = StrftimeItems::new(format).collect::<Vec<_>>();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "\"{}\" is not a valid strftime format",
+            format
+        )));
+    }
+    Ok(items)
+}
+
+/// Formats each slot of a temporal array to a [`Utf8Array`] according to `format`,
+/// a [`strftime`](chrono::format::strftime) format string.
+///
+/// Timestamps with a timezone are formatted in their timezone.
+/// # Errors
+/// This function errors if `format` is not valid, or when it requires information that
+/// the array does not have (e.g. `%z` on timestamps without timezone).
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::temporal::strftime;
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Int32Array::from(&[Some(0), None]).to(DataType::Date32);
+/// let result = strftime(&array, "%Y/%m/%d (%a)").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("1970/01/01 (Thu)"), None]));
+/// ```
+pub fn strftime(array: &dyn Array, format: &str) -> Result<Utf8Array<i32>> {
+    let items = strftime_items(format)?;
+    format_like!(array, |x| x.format_with_items(items.iter()))
+}
+
+/// Extracts the (English) month names of a temporal array as [`Utf8Array`], e.g. `"January"`.
+/// Use [`can_month`] to check if this operation is supported for the target [`DataType`].
+pub fn month_name(array: &dyn Array) -> Result<Utf8Array<i32>> {
+    strftime(array, "%B")
+}
+
+/// Extracts the (English) day names of a temporal array as [`Utf8Array`], e.g. `"Monday"`.
+/// Use [`can_weekday`] to check if this operation is supported for the target [`DataType`].
+pub fn day_name(array: &dyn Array) -> Result<Utf8Array<i32>> {
+    strftime(array, "%A")
+}
+
+// Formats chrono's date times with a locale, with or without timezone.
+#[cfg(feature = "compute_temporal_locale")]
+trait FormatLocalized {
+    fn format_localized<'a, I, B>(
+        &self,
+        items: I,
+        locale: chrono::Locale,
+    ) -> chrono::format::DelayedFormat<I>
+    where
+        I: Iterator<Item = B> + Clone,
+        B: std::borrow::Borrow<Item<'a>>;
+}
+
+#[cfg(feature = "compute_temporal_locale")]
+impl FormatLocalized for chrono::NaiveDateTime {
+    fn format_localized<'a, I, B>(
+        &self,
+        items: I,
+        locale: chrono::Locale,
+    ) -> chrono::format::DelayedFormat<I>
+    where
+        I: Iterator<Item = B> + Clone,
+        B: std::borrow::Borrow<Item<'a>>,
+    {
+        chrono::format::DelayedFormat::new_with_locale(
+            Some(self.date()),
+            Some(self.time()),
+            items,
+            locale,
+        )
+    }
+}
+
+#[cfg(feature = "compute_temporal_locale")]
+impl<T: chrono::TimeZone> FormatLocalized for chrono::DateTime<T>
+where
+    T::Offset: Display,
+{
+    fn format_localized<'a, I, B>(
+        &self,
+        items: I,
+        locale: chrono::Locale,
+    ) -> chrono::format::DelayedFormat<I>
+    where
+        I: Iterator<Item = B> + Clone,
+        B: std::borrow::Borrow<Item<'a>>,
+    {
+        self.format_localized_with_items(items, locale)
+    }
+}
+
+/// Like [`strftime`], but formats names (e.g. of months and days) and representations
+/// (e.g. `%x`) according to `locale`, a POSIX locale name such as `"fr_FR"`.
+/// # Errors
+/// This function errors on the same conditions as [`strftime`], and if `locale` is unknown.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::temporal::strftime_localized;
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Int32Array::from_slice([0]).to(DataType::Date32);
+/// let result = strftime_localized(&array, "%A %-d %B %Y", "fr_FR").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from_slice(["jeudi 1 janvier 1970"]));
+/// ```
+#[cfg(feature = "compute_temporal_locale")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_temporal_locale")))]
+pub fn strftime_localized(array: &dyn Array, format: &str, locale: &str) -> Result<Utf8Array<i32>> {
+    let locale = chrono::Locale::try_from(locale).map_err(|_| {
+        ArrowError::InvalidArgumentError(format!("\"{}\" is not a known locale", locale))
+    })?;
+    let items = strftime_items(format)?;
+    format_like!(array, |x| FormatLocalized::format_localized(
+        &x,
+        items.iter(),
+        locale
+    ))
+}
+
+/// Extracts the month names of a temporal array in `locale` as [`Utf8Array`].
+/// See [`strftime_localized`] for the supported locales.
+#[cfg(feature = "compute_temporal_locale")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_temporal_locale")))]
+pub fn month_name_localized(array: &dyn Array, locale: &str) -> Result<Utf8Array<i32>> {
+    strftime_localized(array, "%B", locale)
+}
+
+/// Extracts the day names of a temporal array in `locale` as [`Utf8Array`].
+/// See [`strftime_localized`] for the supported locales.
+#[cfg(feature = "compute_temporal_locale")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_temporal_locale")))]
+pub fn day_name_localized(array: &dyn Array, locale: &str) -> Result<Utf8Array<i32>> {
+    strftime_localized(array, "%A", locale)
+}
@@ -1,25 +1,24 @@
+use super::arity::prep_validity;
 use crate::{
     array::Array,
     bitmap::Bitmap,
     error::{ArrowError, Result},
 };
 
+/// Returns the intersection of two validities. See [`prep_validity`].
+#[inline]
 pub fn combine_validities(lhs: Option<&Bitmap>, rhs: Option<&Bitmap>) -> Option<Bitmap> {
-    match (lhs, rhs) {
-        (Some(lhs), None) => Some(lhs.clone()),
-        (None, Some(rhs)) => Some(rhs.clone()),
-        (None, None) => None,
-        (Some(lhs), Some(rhs)) => Some(lhs & rhs),
-    }
+    prep_validity(&[lhs, rhs])
 }
 
 // Errors iff the two arrays have a different length.
 #[inline]
 pub fn check_same_len(lhs: &dyn Array, rhs: &dyn Array) -> Result<()> {
     if lhs.len() != rhs.len() {
-        return Err(ArrowError::InvalidArgumentError(
-            "Arrays must have the same length".to_string(),
-        ));
+        return Err(ArrowError::LengthMismatch {
+            expected: lhs.len(),
+            found: rhs.len(),
+        });
     }
     Ok(())
 }
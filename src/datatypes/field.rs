@@ -1,4 +1,4 @@
-use super::{DataType, Metadata};
+use super::{DataType, IntegerType, Metadata};
 
 #[cfg(feature = "serde_types")]
 use serde_derive::{Deserialize, Serialize};
@@ -46,6 +46,67 @@ impl Field {
         }
     }
 
+    /// Creates a new [`Field`] of [`DataType::List`] whose items are described by `item`.
+    /// # Example
+    /// ```
+    /// use arrow2::datatypes::{DataType, Field};
+    ///
+    /// let field = Field::list_of("a", Field::new("item", DataType::Int32, true), false);
+    /// assert_eq!(
+    ///     field.data_type(),
+    ///     &DataType::List(Box::new(Field::new("item", DataType::Int32, true)))
+    /// );
+    /// ```
+    pub fn list_of<T: Into<String>>(name: T, item: Field, is_nullable: bool) -> Self {
+        Self::new(name, DataType::List(Box::new(item)), is_nullable)
+    }
+
+    /// Creates a new [`Field`] of [`DataType::Struct`] with `fields`.
+    pub fn struct_of<T: Into<String>>(name: T, fields: Vec<Field>, is_nullable: bool) -> Self {
+        Self::new(name, DataType::Struct(fields), is_nullable)
+    }
+
+    /// Creates a new [`Field`] of [`DataType::Map`] from keys of [`DataType`] `key` to `value`s.
+    ///
+    /// The entries and keys are named "entries" and "key" and are not nullable, as required
+    /// by the specification (see [`DataType::Map`]). The keys are declared as unsorted.
+    pub fn map_of<T: Into<String>>(
+        name: T,
+        key: DataType,
+        value: Field,
+        is_nullable: bool,
+    ) -> Self {
+        let entries = DataType::Struct(vec![Field::new("key", key, false), value]);
+        let entries = Field::new("entries", entries, false);
+        Self::new(name, DataType::Map(Box::new(entries), false), is_nullable)
+    }
+
+    /// Creates a new [`Field`] of [`DataType::Dictionary`] with keys of [`IntegerType`] `key`
+    /// and values of [`DataType`] `values`. The dictionary is declared as unsorted.
+    pub fn dictionary_of<T: Into<String>>(
+        name: T,
+        key: IntegerType,
+        values: DataType,
+        is_nullable: bool,
+    ) -> Self {
+        Self::new(
+            name,
+            DataType::Dictionary(key, Box::new(values), false),
+            is_nullable,
+        )
+    }
+
+    /// Returns this [`Field`] with the metadata entry `key` set to `value`.
+    #[inline]
+    pub fn with_metadata_entry<K: Into<String>, V: Into<String>>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
     /// Returns the [`Field`]'s [`DataType`].
     #[inline]
     pub fn data_type(&self) -> &DataType {
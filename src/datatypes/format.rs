@@ -0,0 +1,565 @@
+//! A textual representation of [`DataType`], used by its [`std::fmt::Display`] and
+//! [`std::str::FromStr`] implementations.
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+
+use crate::error::{ArrowError, Result};
+
+use super::{DataType, Field, IntegerType, IntervalUnit, TimeUnit, UnionMode};
+
+/// The name of the children of nested types whose name is omitted (e.g. `List<Int32>`).
+const DEFAULT_CHILD_NAME: &str = "item";
+
+/// The maximum nesting of the data types that [`FromStr`] parses, so that deeply nested
+/// inputs error instead of overflowing the stack.
+const MAX_DEPTH: usize = 128;
+
+/// Whether `c` can be part of an unquoted word, such as a field name or a timezone.
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '/' | '+' | '-' | '.')
+}
+
+/// Writes `value` unquoted if it is a word, and quoted otherwise.
+fn write_string(f: &mut Formatter<'_>, value: &str) -> std::fmt::Result {
+    if !value.is_empty() && value.chars().all(is_word_char) {
+        return f.write_str(value);
+    }
+    f.write_char('"')?;
+    for c in value.chars() {
+        if matches!(c, '"' | '\\') {
+            f.write_char('\\')?;
+        }
+        f.write_char(c)?;
+    }
+    f.write_char('"')
+}
+
+fn time_unit_to_str(unit: TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Second => "s",
+        TimeUnit::Millisecond => "ms",
+        TimeUnit::Microsecond => "us",
+        TimeUnit::Nanosecond => "ns",
+    }
+}
+
+/// Writes `field` as `name: type`, followed by `not null` if it is not nullable.
+/// The name is omitted when `omit_default_name` and the name is [`DEFAULT_CHILD_NAME`].
+fn write_field(f: &mut Formatter<'_>, field: &Field, omit_default_name: bool) -> std::fmt::Result {
+    if !(omit_default_name && field.name == DEFAULT_CHILD_NAME) {
+        write_string(f, &field.name)?;
+        f.write_str(": ")?;
+    }
+    write!(f, "{}", field.data_type)?;
+    if !field.is_nullable {
+        f.write_str(" not null")?;
+    }
+    Ok(())
+}
+
+fn write_fields(f: &mut Formatter<'_>, fields: &[Field]) -> std::fmt::Result {
+    f.write_char('<')?;
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write_field(f, field, false)?;
+    }
+    f.write_char('>')
+}
+
+/// Formats a [`DataType`] in a textual grammar that its [`FromStr`] implementation parses back.
+///
+/// Parameters of a type are written in parenthesis and its children in angle brackets, e.g.
+/// `Timestamp(ms, UTC)`, `Decimal(10, 2)`, `FixedSizeList(3)<Float32>`,
+/// `List<Struct<a: Int32, b: Utf8 not null>>`, `Dictionary(Int32, ordered)<Utf8>` or
+/// `Union(Dense, [1, 5])<a: Int8, b: Utf8>`.
+/// Children are nullable unless followed by `not null`, and children of lists and maps
+/// without a name are named `item`. Strings that are not words (e.g. a field named `a b`)
+/// are double quoted.
+///
+/// The metadata of fields is not represented.
+impl Display for DataType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use DataType::*;
+        match self {
+            Timestamp(unit, tz) => {
+                write!(f, "Timestamp({}", time_unit_to_str(*unit))?;
+                if let Some(tz) = tz {
+                    f.write_str(", ")?;
+                    write_string(f, tz)?;
+                }
+                f.write_char(')')
+            }
+            Time32(unit) => write!(f, "Time32({})", time_unit_to_str(*unit)),
+            Time64(unit) => write!(f, "Time64({})", time_unit_to_str(*unit)),
+            Duration(unit) => write!(f, "Duration({})", time_unit_to_str(*unit)),
+            Interval(unit) => write!(f, "Interval({:?})", unit),
+            FixedSizeBinary(size) => write!(f, "FixedSizeBinary({})", size),
+            Decimal(precision, scale) => write!(f, "Decimal({}, {})", precision, scale),
+            List(field) | LargeList(field) | FixedSizeList(field, _) | Map(field, _) => {
+                match self {
+                    List(_) => f.write_str("List")?,
+                    LargeList(_) => f.write_str("LargeList")?,
+                    FixedSizeList(_, size) => write!(f, "FixedSizeList({})", size)?,
+                    Map(_, true) => f.write_str("Map(sorted)")?,
+                    _ => f.write_str("Map")?,
+                };
+                f.write_char('<')?;
+                write_field(f, field, true)?;
+                f.write_char('>')
+            }
+            Struct(fields) => {
+                f.write_str("Struct")?;
+                write_fields(f, fields)
+            }
+            Union(fields, ids, mode) => {
+                write!(f, "Union({:?}", mode)?;
+                if let Some(ids) = ids {
+                    f.write_str(", [")?;
+                    for (i, id) in ids.iter().enumerate() {
+                        if i > 0 {
+                            f.write_str(", ")?;
+                        }
+                        write!(f, "{}", id)?;
+                    }
+                    f.write_char(']')?;
+                }
+                f.write_char(')')?;
+                write_fields(f, fields)
+            }
+            Dictionary(key_type, values, is_ordered) => {
+                write!(f, "Dictionary({:?}", key_type)?;
+                if *is_ordered {
+                    f.write_str(", ordered")?;
+                }
+                write!(f, ")<{}>", values)
+            }
+            Extension(name, inner, metadata) => {
+                f.write_str("Extension(")?;
+                write_string(f, name)?;
+                if let Some(metadata) = metadata {
+                    f.write_str(", ")?;
+                    write_string(f, metadata)?;
+                }
+                write!(f, ")<{}>", inner)
+            }
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// One of `(),<>:[]`
+    Punct(char),
+    Word(String),
+    Quoted(String),
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Punct(c) => write!(f, "`{}`", c),
+            Token::Word(word) => write!(f, "`{}`", word),
+            Token::Quoted(string) => write!(f, "{:?}", string),
+        }
+    }
+}
+
+fn tokenize(value: &str) -> Result<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '(' | ')' | ',' | '<' | '>' | ':' | '[' | ']' => tokens.push(Token::Punct(c)),
+            '"' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c) => string.push(c),
+                            None => return Err(parse_error("an unterminated string")),
+                        },
+                        Some(c) => string.push(c),
+                        None => return Err(parse_error("an unterminated string")),
+                    }
+                }
+                tokens.push(Token::Quoted(string))
+            }
+            c if is_word_char(c) => {
+                let mut word = c.to_string();
+                while let Some(c) = chars.next_if(|c| is_word_char(*c)) {
+                    word.push(c)
+                }
+                tokens.push(Token::Word(word))
+            }
+            c => return Err(parse_error(format!("an unexpected character `{}`", c))),
+        }
+    }
+    Ok(tokens)
+}
+
+fn parse_error<T: Display>(found: T) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!("Invalid data type: found {}", found))
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    // the number of data types being parsed
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn unexpected(token: Option<Token>, expected: &str) -> ArrowError {
+        match token {
+            Some(token) => parse_error(format!("{} where {} was expected", token, expected)),
+            None => parse_error(format!(
+                "the end of the input where {} was expected",
+                expected
+            )),
+        }
+    }
+
+    /// Consumes the next token iff it is the punctuation `c`.
+    fn accept(&mut self, c: char) -> bool {
+        if self.peek() == Some(&Token::Punct(c)) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes the next token iff it is the (case-insensitive) keyword `keyword`.
+    fn accept_keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword)) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        match self.next() {
+            Some(Token::Punct(p)) if p == c => Ok(()),
+            token => Err(Self::unexpected(token, &format!("`{}`", c))),
+        }
+    }
+
+    fn expect_word(&mut self, expected: &str) -> Result<String> {
+        match self.next() {
+            Some(Token::Word(word)) => Ok(word),
+            token => Err(Self::unexpected(token, expected)),
+        }
+    }
+
+    fn expect_string(&mut self, expected: &str) -> Result<String> {
+        match self.next() {
+            Some(Token::Word(string)) | Some(Token::Quoted(string)) => Ok(string),
+            token => Err(Self::unexpected(token, expected)),
+        }
+    }
+
+    fn expect_number<T: FromStr>(&mut self, expected: &str) -> Result<T> {
+        let word = self.expect_word(expected)?;
+        word.parse()
+            .map_err(|_| Self::unexpected(Some(Token::Word(word)), expected))
+    }
+
+    fn expect_time_unit(&mut self) -> Result<TimeUnit> {
+        let word = self.expect_word("a time unit")?;
+        Ok(match word.as_str() {
+            "s" => TimeUnit::Second,
+            "ms" => TimeUnit::Millisecond,
+            "us" => TimeUnit::Microsecond,
+            "ns" => TimeUnit::Nanosecond,
+            _ => return Err(Self::unexpected(Some(Token::Word(word)), "a time unit")),
+        })
+    }
+
+    /// Parses `(unit)`
+    fn parse_time_unit_parameter(&mut self) -> Result<TimeUnit> {
+        self.expect('(')?;
+        let unit = self.expect_time_unit()?;
+        self.expect(')')?;
+        Ok(unit)
+    }
+
+    /// Parses `(number)`
+    fn parse_size_parameter(&mut self) -> Result<usize> {
+        self.expect('(')?;
+        let size = self.expect_number("a size")?;
+        self.expect(')')?;
+        Ok(size)
+    }
+
+    /// Parses `[name:] type [not null]`, where the name defaults to `default_name` when given.
+    fn parse_field(&mut self, default_name: Option<&str>) -> Result<Field> {
+        let is_named = match self.peek() {
+            Some(Token::Quoted(_)) => true,
+            Some(Token::Word(_)) => self.tokens.get(self.position + 1) == Some(&Token::Punct(':')),
+            _ => false,
+        };
+        let name = if is_named {
+            let name = self.expect_string("a field name")?;
+            self.expect(':')?;
+            name
+        } else if let Some(default_name) = default_name {
+            default_name.to_string()
+        } else {
+            return Err(Self::unexpected(self.next(), "a field name"));
+        };
+        let data_type = self.parse_type()?;
+        let is_nullable = if self.accept_keyword("not") {
+            if !self.accept_keyword("null") {
+                return Err(Self::unexpected(self.next(), "`null`"));
+            }
+            false
+        } else {
+            true
+        };
+        Ok(Field::new(name, data_type, is_nullable))
+    }
+
+    /// Parses `<field>`
+    fn parse_child(&mut self) -> Result<Field> {
+        self.expect('<')?;
+        let field = self.parse_field(Some(DEFAULT_CHILD_NAME))?;
+        self.expect('>')?;
+        Ok(field)
+    }
+
+    /// Parses `<field, ...>`
+    fn parse_fields(&mut self) -> Result<Vec<Field>> {
+        self.expect('<')?;
+        let mut fields = vec![];
+        if self.accept('>') {
+            return Ok(fields);
+        }
+        loop {
+            fields.push(self.parse_field(None)?);
+            if self.accept('>') {
+                return Ok(fields);
+            }
+            self.expect(',')?;
+        }
+    }
+
+    /// Parses `<type>`
+    fn parse_child_type(&mut self) -> Result<DataType> {
+        self.expect('<')?;
+        let data_type = self.parse_type()?;
+        self.expect('>')?;
+        Ok(data_type)
+    }
+
+    fn parse_type(&mut self) -> Result<DataType> {
+        if self.depth == MAX_DEPTH {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid data type: it is nested more than {} levels deep",
+                MAX_DEPTH
+            )));
+        }
+        self.depth += 1;
+        let data_type = self.parse_type_inner();
+        self.depth -= 1;
+        data_type
+    }
+
+    fn parse_type_inner(&mut self) -> Result<DataType> {
+        let name = self.expect_word("a data type")?;
+        Ok(match name.as_str() {
+            "Null" => DataType::Null,
+            "Boolean" => DataType::Boolean,
+            "Int8" => DataType::Int8,
+            "Int16" => DataType::Int16,
+            "Int32" => DataType::Int32,
+            "Int64" => DataType::Int64,
+            "UInt8" => DataType::UInt8,
+            "UInt16" => DataType::UInt16,
+            "UInt32" => DataType::UInt32,
+            "UInt64" => DataType::UInt64,
+            "Float16" => DataType::Float16,
+            "Float32" => DataType::Float32,
+            "Float64" => DataType::Float64,
+            "Date32" => DataType::Date32,
+            "Date64" => DataType::Date64,
+            "Binary" => DataType::Binary,
+            "LargeBinary" => DataType::LargeBinary,
+            "Utf8" => DataType::Utf8,
+            "LargeUtf8" => DataType::LargeUtf8,
+            "Timestamp" => {
+                self.expect('(')?;
+                let unit = self.expect_time_unit()?;
+                let tz = if self.accept(',') {
+                    Some(self.expect_string("a timezone")?)
+                } else {
+                    None
+                };
+                self.expect(')')?;
+                DataType::Timestamp(unit, tz)
+            }
+            "Time32" => DataType::Time32(self.parse_time_unit_parameter()?),
+            "Time64" => DataType::Time64(self.parse_time_unit_parameter()?),
+            "Duration" => DataType::Duration(self.parse_time_unit_parameter()?),
+            "Interval" => {
+                self.expect('(')?;
+                let word = self.expect_word("an interval unit")?;
+                let unit = match word.as_str() {
+                    "YearMonth" => IntervalUnit::YearMonth,
+                    "DayTime" => IntervalUnit::DayTime,
+                    "MonthDayNano" => IntervalUnit::MonthDayNano,
+                    _ => {
+                        return Err(Self::unexpected(
+                            Some(Token::Word(word)),
+                            "an interval unit",
+                        ))
+                    }
+                };
+                self.expect(')')?;
+                DataType::Interval(unit)
+            }
+            "FixedSizeBinary" => DataType::FixedSizeBinary(self.parse_size_parameter()?),
+            "Decimal" => {
+                self.expect('(')?;
+                let precision = self.expect_number("a precision")?;
+                self.expect(',')?;
+                let scale = self.expect_number("a scale")?;
+                self.expect(')')?;
+                DataType::Decimal(precision, scale)
+            }
+            "List" => DataType::List(Box::new(self.parse_child()?)),
+            "LargeList" => DataType::LargeList(Box::new(self.parse_child()?)),
+            "FixedSizeList" => {
+                let size = self.parse_size_parameter()?;
+                DataType::FixedSizeList(Box::new(self.parse_child()?), size)
+            }
+            "Map" => {
+                let is_sorted = if self.accept('(') {
+                    if !self.accept_keyword("sorted") {
+                        return Err(Self::unexpected(self.next(), "`sorted`"));
+                    }
+                    self.expect(')')?;
+                    true
+                } else {
+                    false
+                };
+                DataType::Map(Box::new(self.parse_child()?), is_sorted)
+            }
+            "Struct" => DataType::Struct(self.parse_fields()?),
+            "Union" => {
+                self.expect('(')?;
+                let word = self.expect_word("a union mode")?;
+                let mode = match word.as_str() {
+                    "Sparse" => UnionMode::Sparse,
+                    "Dense" => UnionMode::Dense,
+                    _ => return Err(Self::unexpected(Some(Token::Word(word)), "a union mode")),
+                };
+                let ids = if self.accept(',') {
+                    self.expect('[')?;
+                    let mut ids = vec![];
+                    if !self.accept(']') {
+                        loop {
+                            ids.push(self.expect_number("a type id")?);
+                            if self.accept(']') {
+                                break;
+                            }
+                            self.expect(',')?;
+                        }
+                    }
+                    Some(ids)
+                } else {
+                    None
+                };
+                self.expect(')')?;
+                DataType::Union(self.parse_fields()?, ids, mode)
+            }
+            "Dictionary" => {
+                self.expect('(')?;
+                let word = self.expect_word("an integer type")?;
+                let key_type = match word.as_str() {
+                    "Int8" => IntegerType::Int8,
+                    "Int16" => IntegerType::Int16,
+                    "Int32" => IntegerType::Int32,
+                    "Int64" => IntegerType::Int64,
+                    "UInt8" => IntegerType::UInt8,
+                    "UInt16" => IntegerType::UInt16,
+                    "UInt32" => IntegerType::UInt32,
+                    "UInt64" => IntegerType::UInt64,
+                    _ => return Err(Self::unexpected(Some(Token::Word(word)), "an integer type")),
+                };
+                let is_ordered = if self.accept(',') {
+                    if !self.accept_keyword("ordered") {
+                        return Err(Self::unexpected(self.next(), "`ordered`"));
+                    }
+                    true
+                } else {
+                    false
+                };
+                self.expect(')')?;
+                DataType::Dictionary(key_type, Box::new(self.parse_child_type()?), is_ordered)
+            }
+            "Extension" => {
+                self.expect('(')?;
+                let name = self.expect_string("an extension name")?;
+                let metadata = if self.accept(',') {
+                    Some(self.expect_string("the extension metadata")?)
+                } else {
+                    None
+                };
+                self.expect(')')?;
+                DataType::Extension(name, Box::new(self.parse_child_type()?), metadata)
+            }
+            _ => return Err(Self::unexpected(Some(Token::Word(name)), "a data type")),
+        })
+    }
+}
+
+/// Parses a [`DataType`] from the textual grammar of its [`Display`] implementation,
+/// e.g. `Timestamp(ms, UTC)` or `List<Struct<a: Int32, b: Utf8 not null>>`.
+/// # Example
+/// ```
+/// use arrow2::datatypes::{DataType, Field, TimeUnit};
+///
+/// let data_type: DataType = "List<Struct<a: Int32, b: Timestamp(ms, UTC) not null>>".parse().unwrap();
+/// let fields = vec![
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("b", DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".to_string())), false),
+/// ];
+/// let item = Field::new("item", DataType::Struct(fields), true);
+/// assert_eq!(data_type, DataType::List(Box::new(item)));
+/// assert_eq!(data_type.to_string().parse::<DataType>().unwrap(), data_type);
+/// ```
+/// # Error
+/// Errors with [`ArrowError::InvalidArgumentError`] iff `value` is not a valid data type.
+impl FromStr for DataType {
+    type Err = ArrowError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(value)?,
+            position: 0,
+            depth: 0,
+        };
+        let data_type = parser.parse_type()?;
+        match parser.next() {
+            None => Ok(data_type),
+            token => Err(Parser::unexpected(token, "the end of the input")),
+        }
+    }
+}
@@ -2,12 +2,13 @@
 //! Contains all metadata, such as [`PhysicalType`], [`DataType`], [`Field`] and [`Schema`].
 
 mod field;
+mod format;
 mod physical_type;
 mod schema;
 
 pub use field::Field;
 pub use physical_type::*;
-pub use schema::Schema;
+pub use schema::{Schema, SchemaBuilder};
 
 use std::collections::BTreeMap;
 use std::sync::Arc;
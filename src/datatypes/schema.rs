@@ -1,4 +1,5 @@
 use super::{Field, Metadata};
+use crate::error::{ArrowError, Result};
 
 #[cfg(feature = "serde_types")]
 use serde_derive::{Deserialize, Serialize};
@@ -18,6 +19,12 @@ pub struct Schema {
 }
 
 impl Schema {
+    /// Returns a new [`SchemaBuilder`].
+    #[inline]
+    pub fn builder() -> SchemaBuilder {
+        SchemaBuilder::default()
+    }
+
     /// Attaches a [`Metadata`] to [`Schema`]
     #[inline]
     pub fn with_metadata(self, metadata: Metadata) -> Self {
@@ -26,6 +33,69 @@ impl Schema {
             metadata,
         }
     }
+
+    /// Returns the position of the first field named `name`.
+    /// # Error
+    /// Errors with [`ArrowError::FieldNotFound`] iff no field is named `name`.
+    pub fn index_of(&self, name: &str) -> Result<usize> {
+        self.fields
+            .iter()
+            .position(|field| field.name == name)
+            .ok_or_else(|| ArrowError::FieldNotFound(name.to_string()))
+    }
+}
+
+/// A builder of [`Schema`], declaring its fields and metadata one at a time.
+/// # Example
+/// ```
+/// use arrow2::datatypes::{DataType, Field, IntegerType, Schema};
+///
+/// let schema = Schema::builder()
+///     .field(Field::new("id", DataType::Int64, false))
+///     .field(Field::dictionary_of("city", IntegerType::Int32, DataType::Utf8, true))
+///     .field(Field::list_of("tags", Field::new("item", DataType::Utf8, true), true))
+///     .metadata("source", "sensors")
+///     .build();
+///
+/// assert_eq!(schema.fields.len(), 3);
+/// assert_eq!(schema.metadata["source"], "sensors");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SchemaBuilder {
+    fields: Vec<Field>,
+    metadata: Metadata,
+}
+
+impl SchemaBuilder {
+    /// Appends `field` to the fields of the schema.
+    #[inline]
+    pub fn field(mut self, field: Field) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Appends `fields` to the fields of the schema.
+    #[inline]
+    pub fn fields<I: IntoIterator<Item = Field>>(mut self, fields: I) -> Self {
+        self.fields.extend(fields);
+        self
+    }
+
+    /// Sets the metadata entry `key` of the schema to `value`.
+    #[inline]
+    pub fn metadata<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the [`Schema`].
+    #[inline]
+    pub fn build(self) -> Schema {
+        Schema {
+            fields: self.fields,
+            metadata: self.metadata,
+        }
+    }
 }
 
 impl From<Vec<Field>> for Schema {
@@ -3,6 +3,8 @@ use std::fmt::{Debug, Display, Formatter};
 
 use std::error::Error;
 
+use crate::datatypes::DataType;
+
 /// Enum with all errors in this crate.
 #[derive(Debug)]
 #[non_exhaustive]
@@ -22,6 +24,48 @@ pub enum ArrowError {
     Overflow,
     /// Whenever incoming data from the C data interface, IPC or Flight does not fulfil the Arrow specification.
     OutOfSpec(String),
+    /// When an argument has a different [`DataType`] than the one required by an operation.
+    DataTypeMismatch {
+        /// The required data type
+        expected: Box<DataType>,
+        /// The data type of the argument
+        found: Box<DataType>,
+    },
+    /// When an argument has a different length than the one required by an operation.
+    LengthMismatch {
+        /// The required length
+        expected: usize,
+        /// The length of the argument
+        found: usize,
+    },
+    /// When a field is not found by its name.
+    FieldNotFound(String),
+}
+
+/// The kind of an [`ArrowError`], to match on errors without their details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// [`ArrowError::NotYetImplemented`]
+    NotYetImplemented,
+    /// [`ArrowError::External`]
+    External,
+    /// [`ArrowError::Io`]
+    Io,
+    /// [`ArrowError::InvalidArgumentError`]
+    InvalidArgument,
+    /// [`ArrowError::ExternalFormat`]
+    ExternalFormat,
+    /// [`ArrowError::Overflow`]
+    Overflow,
+    /// [`ArrowError::OutOfSpec`]
+    OutOfSpec,
+    /// [`ArrowError::DataTypeMismatch`]
+    DataTypeMismatch,
+    /// [`ArrowError::LengthMismatch`]
+    LengthMismatch,
+    /// [`ArrowError::FieldNotFound`]
+    FieldNotFound,
 }
 
 impl ArrowError {
@@ -34,6 +78,73 @@ impl ArrowError {
         Self::OutOfSpec(msg.into())
     }
 
+    /// Returns the [`ErrorKind`] of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ArrowError::NotYetImplemented(_) => ErrorKind::NotYetImplemented,
+            ArrowError::External(_, _) => ErrorKind::External,
+            ArrowError::Io(_) => ErrorKind::Io,
+            ArrowError::InvalidArgumentError(_) => ErrorKind::InvalidArgument,
+            ArrowError::ExternalFormat(_) => ErrorKind::ExternalFormat,
+            ArrowError::Overflow => ErrorKind::Overflow,
+            ArrowError::OutOfSpec(_) => ErrorKind::OutOfSpec,
+            ArrowError::DataTypeMismatch { .. } => ErrorKind::DataTypeMismatch,
+            ArrowError::LengthMismatch { .. } => ErrorKind::LengthMismatch,
+            ArrowError::FieldNotFound(_) => ErrorKind::FieldNotFound,
+        }
+    }
+
+    /// Returns the error of values that do not fit in the `i32` offsets of `data_type`,
+    /// suggesting its counterpart with `i64` offsets.
+    pub(crate) fn offset_overflow(data_type: &DataType) -> Self {
+        let large = match data_type.to_logical_type() {
+            DataType::Binary => DataType::LargeBinary,
+            DataType::Utf8 => DataType::LargeUtf8,
+            DataType::List(field) => DataType::LargeList(field.clone()),
+            other => other.clone(),
+        };
+        Self::InvalidArgumentError(format!(
+            "The values of {:?} exceed its maximum offset ({}); use {:?} instead",
+            data_type,
+            i32::MAX,
+            large
+        ))
+    }
+
+    /// Returns this error with `context` (e.g. where it happened) added to its message,
+    /// keeping its variant. Variants without a message are returned unchanged.
+    #[allow(dead_code)]
+    pub(crate) fn with_context(self, context: impl Display) -> Self {
+        match self {
+            Self::NotYetImplemented(message) => {
+                Self::NotYetImplemented(format!("{}: {}", context, message))
+            }
+            Self::External(message, source) => {
+                let message = if message.is_empty() {
+                    context.to_string()
+                } else {
+                    format!("{}: {}", context, message.trim_start())
+                };
+                Self::External(message, source)
+            }
+            Self::Io(error) => Self::Io(std::io::Error::new(
+                error.kind(),
+                ContextError {
+                    context: context.to_string(),
+                    source: error,
+                },
+            )),
+            Self::InvalidArgumentError(message) => {
+                Self::InvalidArgumentError(format!("{}: {}", context, message))
+            }
+            Self::ExternalFormat(message) => {
+                Self::ExternalFormat(format!("{}: {}", context, message))
+            }
+            Self::OutOfSpec(message) => Self::OutOfSpec(format!("{}: {}", context, message)),
+            error => error,
+        }
+    }
+
     #[allow(dead_code)]
     pub(crate) fn nyi<A: Into<String>>(msg: A) -> Self {
         Self::NotYetImplemented(msg.into())
@@ -65,7 +176,13 @@ impl Display for ArrowError {
                 write!(f, "Not yet implemented: {}", &source)
             }
             ArrowError::External(message, source) => {
-                write!(f, "External error{}: {}", message, &source)
+                // messages may start with a space, e.g. " at line 1"
+                let message = message.trim_start();
+                if message.is_empty() {
+                    write!(f, "External error: {}", &source)
+                } else {
+                    write!(f, "External error {}: {}", message, &source)
+                }
             }
             ArrowError::Io(desc) => write!(f, "Io error: {}", desc),
             ArrowError::InvalidArgumentError(desc) => {
@@ -80,11 +197,86 @@ impl Display for ArrowError {
             ArrowError::OutOfSpec(message) => {
                 write!(f, "{}", message)
             }
+            ArrowError::DataTypeMismatch { expected, found } => {
+                write!(
+                    f,
+                    "Data type mismatch: expected {:?}, found {:?}",
+                    expected, found
+                )
+            }
+            ArrowError::LengthMismatch { expected, found } => {
+                write!(f, "Length mismatch: expected {}, found {}", expected, found)
+            }
+            ArrowError::FieldNotFound(name) => {
+                write!(f, "Field \"{}\" not found", name)
+            }
         }
     }
 }
 
-impl Error for ArrowError {}
+impl Error for ArrowError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ArrowError::External(_, source) => Some(source.as_ref()),
+            ArrowError::Io(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// An [`std::io::Error`] with context (e.g. where it happened), which remains its source.
+#[derive(Debug)]
+struct ContextError {
+    context: String,
+    source: std::io::Error,
+}
+
+impl Display for ContextError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.context, self.source)
+    }
+}
+
+impl Error for ContextError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
 
 /// Typedef for a [`std::result::Result`] of an [`ArrowError`].
 pub type Result<T> = std::result::Result<T, ArrowError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn io_context_keeps_source() {
+        let error = ArrowError::from(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "eof",
+        ))
+        .with_context("row group 1");
+        assert_eq!(error.to_string(), "Io error: row group 1: eof");
+
+        let source = error.source().unwrap().source().unwrap();
+        assert_eq!(source.to_string(), "eof");
+    }
+
+    #[test]
+    fn external_context() {
+        let source = || Box::new(std::fmt::Error);
+
+        let error = ArrowError::External("".to_string(), source()).with_context("row group 1");
+        assert_eq!(
+            error.to_string(),
+            "External error row group 1: an error occurred when formatting an argument"
+        );
+
+        let error = ArrowError::External(" at line 2".to_string(), source()).with_context("a");
+        assert_eq!(
+            error.to_string(),
+            "External error a: at line 2: an error occurred when formatting an argument"
+        );
+    }
+}
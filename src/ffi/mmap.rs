@@ -0,0 +1,97 @@
+//! Functionality to create [`ArrowArray`]s whose buffers are owned by a region of memory,
+//! such as a memory-mapped file.
+use std::sync::Arc;
+
+use crate::array::Array;
+
+use super::bridge::align_to_c_data_interface;
+use super::ArrowArray;
+
+#[allow(dead_code)]
+struct PrivateData<T> {
+    // the owner of the region of memory that the buffers point to
+    data: Arc<T>,
+    buffers_ptr: Box<[*const std::os::raw::c_void]>,
+    children_ptr: Box<[*mut ArrowArray]>,
+    dictionary_ptr: Option<*mut ArrowArray>,
+}
+
+// callback used to drop [ArrowArray] created by [`create_array`]
+unsafe extern "C" fn release<T>(array: *mut ArrowArray) {
+    if array.is_null() {
+        return;
+    }
+    let array = &mut *array;
+
+    // take ownership of `private_data`, therefore dropping it (and its reference to the data)
+    let private = Box::from_raw(array.private_data as *mut PrivateData<T>);
+    for child in private.children_ptr.iter() {
+        let _ = Box::from_raw(*child);
+    }
+
+    if let Some(ptr) = private.dictionary_ptr {
+        let _ = Box::from_raw(ptr);
+    }
+
+    array.release = None;
+}
+
+/// Creates an [`ArrowArray`] whose buffers point to regions of `data`. The [`ArrowArray`]
+/// holds a reference to `data`, which is only released when the array is released.
+/// # Safety
+/// The pointers in `buffers` must be null or point to regions of `data`, and together with
+/// `children` and `dictionary` must be valid according to the C data interface for the
+/// [`DataType`](crate::datatypes::DataType) the array is imported with.
+pub(crate) unsafe fn create_array<
+    T: AsRef<[u8]>,
+    I: Iterator<Item = Option<*const u8>>,
+    II: Iterator<Item = ArrowArray>,
+>(
+    data: Arc<T>,
+    num_rows: usize,
+    null_count: usize,
+    buffers: I,
+    children: II,
+    dictionary: Option<ArrowArray>,
+) -> ArrowArray {
+    let buffers_ptr = buffers
+        .map(|maybe_buffer| match maybe_buffer {
+            Some(b) => b as *const std::os::raw::c_void,
+            None => std::ptr::null(),
+        })
+        .collect::<Box<[_]>>();
+    let n_buffers = buffers_ptr.len() as i64;
+
+    let children_ptr = children
+        .map(|child| Box::into_raw(Box::new(child)))
+        .collect::<Box<_>>();
+    let n_children = children_ptr.len() as i64;
+
+    let dictionary_ptr = dictionary.map(|array| Box::into_raw(Box::new(array)));
+
+    let mut private_data = Box::new(PrivateData::<T> {
+        data,
+        buffers_ptr,
+        children_ptr,
+        dictionary_ptr,
+    });
+
+    ArrowArray {
+        length: num_rows as i64,
+        null_count: null_count as i64,
+        offset: 0,
+        n_buffers,
+        n_children,
+        buffers: private_data.buffers_ptr.as_mut_ptr(),
+        children: private_data.children_ptr.as_mut_ptr(),
+        dictionary: private_data.dictionary_ptr.unwrap_or(std::ptr::null_mut()),
+        release: Some(release::<T>),
+        private_data: Box::into_raw(private_data) as *mut ::std::os::raw::c_void,
+    }
+}
+
+/// Exports `array` (e.g. the values of a dictionary) to an [`ArrowArray`] that can be used
+/// as a child or dictionary of an array created by [`create_array`].
+pub(crate) fn export_array(array: Arc<dyn Array>) -> ArrowArray {
+    ArrowArray::new(align_to_c_data_interface(array))
+}
@@ -3,6 +3,8 @@
 mod array;
 mod bridge;
 mod generated;
+#[cfg(feature = "io_ipc")]
+pub(crate) mod mmap;
 mod schema;
 mod stream;
 
@@ -11,14 +13,15 @@ pub(crate) use array::{ArrowArrayRef, InternalArrowArray};
 
 use std::sync::Arc;
 
-use crate::array::Array;
-use crate::datatypes::{DataType, Field};
-use crate::error::Result;
+use crate::array::{Array, StructArray};
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, Schema};
+use crate::error::{ArrowError, Result};
 
 use self::schema::to_field;
 
 pub use generated::{ArrowArray, ArrowArrayStream, ArrowSchema};
-pub use stream::{export_iterator, ArrowArrayStreamReader};
+pub use stream::{export_iterator, export_iterator_send, ArrowArrayStreamReader};
 
 /// Exports an [`Arc<dyn Array>`] to the C data interface.
 /// # Safety
@@ -44,6 +47,34 @@ pub unsafe fn import_field_from_c(field: &ArrowSchema) -> Result<Field> {
     to_field(field)
 }
 
+/// Exports a [`Schema`] to the C data interface, as a (non-nullable) struct whose children
+/// are the schema's fields and whose metadata is the schema's metadata.
+/// # Safety
+/// The pointer `ptr` must be allocated and valid
+pub unsafe fn export_schema_to_c(schema: &Schema, ptr: *mut ArrowSchema) {
+    let field = Field::new("", DataType::Struct(schema.fields.clone()), false)
+        .with_metadata(schema.metadata.clone());
+    export_field_to_c(&field, ptr)
+}
+
+/// Imports a [`Schema`] from the C data interface, exported as a struct whose children
+/// are the schema's fields (e.g. via [`export_schema_to_c`] or pyarrow's `Schema._export_to_c`).
+/// # Error
+/// Errors iff the [`ArrowSchema`] is not a struct.
+/// # Safety
+/// This function is intrinsically `unsafe` and relies on a [`ArrowSchema`]
+/// valid according to the [C data interface](https://arrow.apache.org/docs/format/CDataInterface.html) (FFI).
+pub unsafe fn import_schema_from_c(schema: &ArrowSchema) -> Result<Schema> {
+    let field = to_field(schema)?;
+    match field.data_type {
+        DataType::Struct(fields) => Ok(Schema::from(fields).with_metadata(field.metadata)),
+        other => Err(ArrowError::OutOfSpec(format!(
+            "A schema must be exported as a struct, but it was exported as {:?}",
+            other
+        ))),
+    }
+}
+
 /// Imports an [`Array`] from the C data interface.
 /// # Safety
 /// This function is intrinsically `unsafe` and relies on a [`ArrowArray`]
@@ -54,3 +85,79 @@ pub unsafe fn import_array_from_c(
 ) -> Result<Box<dyn Array>> {
     try_from(Arc::new(InternalArrowArray::new(array, data_type)))
 }
+
+/// A [`Chunk`] exported to the C data interface, as a (non-nullable) struct array whose
+/// children are the chunk's columns.
+///
+/// This struct owns the exported [`ArrowArray`] and [`ArrowSchema`]: the pointers returned
+/// by [`ExportedChunk::array_ptr`] and [`ExportedChunk::schema_ptr`] are valid for as long as
+/// it is alive. Consumers (e.g. pyarrow's `RecordBatch._import_from_c`) move the structs out
+/// of these pointers and become responsible for calling their `release` callbacks; structs
+/// that were not moved are released when this guard is dropped.
+#[derive(Debug)]
+pub struct ExportedChunk {
+    array: Box<ArrowArray>,
+    schema: Box<ArrowSchema>,
+}
+
+impl ExportedChunk {
+    /// Returns a pointer to the exported [`ArrowArray`].
+    pub fn array_ptr(&mut self) -> *mut ArrowArray {
+        self.array.as_mut() as *mut ArrowArray
+    }
+
+    /// Returns a pointer to the exported [`ArrowSchema`].
+    pub fn schema_ptr(&mut self) -> *mut ArrowSchema {
+        self.schema.as_mut() as *mut ArrowSchema
+    }
+
+    /// Returns the exported [`ArrowArray`] and [`ArrowSchema`], transferring their ownership
+    /// to the caller.
+    pub fn into_inner(self) -> (Box<ArrowArray>, Box<ArrowSchema>) {
+        (self.array, self.schema)
+    }
+}
+
+/// Exports a [`Chunk`] and its [`Schema`] to the C data interface, as a (non-nullable) struct
+/// array whose children are the chunk's columns (like pyarrow's `RecordBatch._export_to_c`).
+/// # Error
+/// Errors iff the chunk has no columns or its columns' [`DataType`]s differ from the fields of `schema`.
+pub fn export_chunk_to_c(chunk: Chunk<Arc<dyn Array>>, schema: &Schema) -> Result<ExportedChunk> {
+    let data_type = DataType::Struct(schema.fields.clone());
+    let array = StructArray::try_new(data_type, chunk.into_arrays(), None)?;
+    let field =
+        Field::new("", array.data_type().clone(), false).with_metadata(schema.metadata.clone());
+
+    let array = bridge::align_to_c_data_interface(Arc::new(array));
+    Ok(ExportedChunk {
+        array: Box::new(ArrowArray::new(array)),
+        schema: Box::new(ArrowSchema::new(&field)),
+    })
+}
+
+/// Imports a [`Chunk`] and its [`Schema`] from the C data interface, exported as a struct
+/// array whose children are the chunk's columns (e.g. via [`export_chunk_to_c`] or pyarrow's
+/// `RecordBatch._export_to_c`).
+/// # Error
+/// Errors iff the [`ArrowSchema`] is not a struct or the struct array has null entries.
+/// # Safety
+/// This function is intrinsically `unsafe` and relies on a [`ArrowArray`] and [`ArrowSchema`]
+/// valid according to the [C data interface](https://arrow.apache.org/docs/format/CDataInterface.html) (FFI).
+pub unsafe fn import_chunk_from_c(
+    array: Box<ArrowArray>,
+    schema: &ArrowSchema,
+) -> Result<(Schema, Chunk<Arc<dyn Array>>)> {
+    let schema = import_schema_from_c(schema)?;
+    let array = import_array_from_c(array, DataType::Struct(schema.fields.clone()))?;
+    let array = array
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .expect("a struct data type is imported as a StructArray");
+    if array.null_count() > 0 {
+        return Err(ArrowError::OutOfSpec(
+            "A chunk must be exported as a struct array without nulls".to_string(),
+        ));
+    }
+    let chunk = Chunk::try_new(array.values().to_vec())?;
+    Ok((schema, chunk))
+}
@@ -46,7 +46,8 @@ impl ArrowSchema {
         let mut flags = field.is_nullable as i64 * 2;
 
         // allocate (and hold) the children
-        let children_vec = match field.data_type() {
+        // extension types are exported as their storage type plus metadata
+        let children_vec = match field.data_type().to_logical_type() {
             DataType::List(field) => {
                 vec![Box::new(ArrowSchema::new(field.as_ref()))]
             }
@@ -77,7 +78,9 @@ impl ArrowSchema {
             .collect::<Box<_>>();
         let n_children = children_ptr.len() as i64;
 
-        let dictionary = if let DataType::Dictionary(_, values, is_ordered) = field.data_type() {
+        let dictionary = if let DataType::Dictionary(_, values, is_ordered) =
+            field.data_type().to_logical_type()
+        {
             flags += *is_ordered as i64;
             // we do not store field info in the dict values, so can't recover it all :(
             let field = Field::new("", values.as_ref().clone(), true);
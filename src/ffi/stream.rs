@@ -1,7 +1,11 @@
 use std::ffi::{CStr, CString};
 use std::sync::Arc;
 
-use crate::{array::Array, datatypes::Field, error::ArrowError};
+use crate::{
+    array::Array,
+    datatypes::Field,
+    error::{ArrowError, ErrorKind},
+};
 
 use super::{export_array_to_c, export_field_to_c, import_array_from_c, import_field_from_c};
 use super::{ArrowArray, ArrowArrayStream, ArrowSchema};
@@ -28,24 +32,23 @@ impl ArrowArrayStream {
     }
 }
 
-unsafe fn handle_error(iter: &mut ArrowArrayStream) -> ArrowError {
+unsafe fn handle_error(iter: &mut ArrowArrayStream, status: i32) -> ArrowError {
     let error = unsafe { (iter.get_last_error.unwrap())(&mut *iter) };
 
-    if error.is_null() {
-        return ArrowError::External(
-            "C stream".to_string(),
-            Box::new(ArrowError::ExternalFormat(
-                "an unspecified error".to_string(),
-            )),
-        );
-    }
+    let message = if error.is_null() {
+        "an unspecified error".to_string()
+    } else {
+        unsafe { CStr::from_ptr(error) }
+            .to_string_lossy()
+            .into_owned()
+    };
 
-    let error = unsafe { CStr::from_ptr(error) };
     ArrowError::External(
         "C stream".to_string(),
-        Box::new(ArrowError::ExternalFormat(
-            error.to_str().unwrap().to_string(),
-        )),
+        Box::new(ArrowError::ExternalFormat(format!(
+            "{} (error code {})",
+            message, status
+        ))),
     )
 }
 
@@ -89,7 +92,7 @@ impl ArrowArrayStreamReader {
         };
 
         if status != 0 {
-            return Err(unsafe { handle_error(&mut iter) });
+            return Err(unsafe { handle_error(&mut iter, status) });
         }
 
         let field = unsafe { import_field_from_c(&field)? };
@@ -115,7 +118,7 @@ impl ArrowArrayStreamReader {
         let status = unsafe { (self.iter.get_next.unwrap())(&mut *self.iter, &mut *array) };
 
         if status != 0 {
-            return Some(Err(unsafe { handle_error(&mut self.iter) }));
+            return Some(Err(unsafe { handle_error(&mut self.iter, status) }));
         }
 
         // last paragraph of https://arrow.apache.org/docs/format/CStreamInterface.html#c.ArrowArrayStream.get_next
@@ -128,17 +131,48 @@ impl ArrowArrayStreamReader {
     }
 }
 
+// errno-compatible error codes, as recommended by the C stream interface. Unlike most errno
+// values, these are the same on all major platforms.
+const EIO: i32 = 5;
+const EINVAL: i32 = 22;
+
+/// Returns the errno-compatible code of `error`
+fn error_code(error: &ArrowError) -> i32 {
+    match error.kind() {
+        ErrorKind::Io | ErrorKind::External => EIO,
+        _ => EINVAL,
+    }
+}
+
 struct PrivateData {
     iter: Box<dyn Iterator<Item = Result<Arc<dyn Array>, ArrowError>>>,
     field: Field,
     error: Option<CString>,
 }
 
-unsafe extern "C" fn get_next(iter: *mut ArrowArrayStream, array: *mut ArrowArray) -> i32 {
-    if iter.is_null() {
-        return 2001;
+impl PrivateData {
+    /// Stores `error` to be returned by `get_last_error` and returns its code
+    fn set_error(&mut self, error: ArrowError) -> i32 {
+        let code = error_code(&error);
+        let message = format!("{} (error code {})", error, code).replace('\0', " ");
+        self.error = Some(CString::new(message).unwrap());
+        code
+    }
+}
+
+/// Returns the private data of `iter`, or `None` if it is null or was released
+unsafe fn private_data<'a>(iter: *mut ArrowArrayStream) -> Option<&'a mut PrivateData> {
+    if iter.is_null() || (*iter).private_data.is_null() {
+        return None;
     }
-    let mut private = &mut *((*iter).private_data as *mut PrivateData);
+    Some(&mut *((*iter).private_data as *mut PrivateData))
+}
+
+unsafe extern "C" fn get_next(iter: *mut ArrowArrayStream, array: *mut ArrowArray) -> i32 {
+    let private = match private_data(iter) {
+        Some(private) => private,
+        None => return EINVAL,
+    };
 
     match private.iter.next() {
         Some(Ok(item)) => {
@@ -146,18 +180,17 @@ unsafe extern "C" fn get_next(iter: *mut ArrowArrayStream, array: *mut ArrowArra
             let item_dt = item.data_type();
             let expected_dt = private.field.data_type();
             if item_dt != expected_dt {
-                private.error = Some(CString::new(format!("The iterator produced an item of data type {item_dt:?} but the producer expects data type {expected_dt:?}").as_bytes().to_vec()).unwrap());
-                return 2001; // custom application specific error (since this is never a result of this interface)
+                return private.set_error(ArrowError::DataTypeMismatch {
+                    expected: Box::new(expected_dt.clone()),
+                    found: Box::new(item_dt.clone()),
+                });
             }
 
             export_array_to_c(item, array);
             private.error = None;
             0
         }
-        Some(Err(err)) => {
-            private.error = Some(CString::new(err.to_string().as_bytes().to_vec()).unwrap());
-            2001 // custom application specific error (since this is never a result of this interface)
-        }
+        Some(Err(err)) => private.set_error(err),
         None => {
             let a = ArrowArray::empty();
             std::ptr::write_unaligned(array, a);
@@ -168,20 +201,20 @@ unsafe extern "C" fn get_next(iter: *mut ArrowArrayStream, array: *mut ArrowArra
 }
 
 unsafe extern "C" fn get_schema(iter: *mut ArrowArrayStream, schema: *mut ArrowSchema) -> i32 {
-    if iter.is_null() {
-        return 2001;
-    }
-    let private = &mut *((*iter).private_data as *mut PrivateData);
+    let private = match private_data(iter) {
+        Some(private) => private,
+        None => return EINVAL,
+    };
 
     export_field_to_c(&private.field, schema);
     0
 }
 
 unsafe extern "C" fn get_last_error(iter: *mut ArrowArrayStream) -> *const ::std::os::raw::c_char {
-    if iter.is_null() {
-        return std::ptr::null();
-    }
-    let private = &mut *((*iter).private_data as *mut PrivateData);
+    let private = match private_data(iter) {
+        Some(private) => private,
+        None => return std::ptr::null(),
+    };
 
     private
         .error
@@ -191,21 +224,53 @@ unsafe extern "C" fn get_last_error(iter: *mut ArrowArrayStream) -> *const ::std
 }
 
 unsafe extern "C" fn release(iter: *mut ArrowArrayStream) {
-    if iter.is_null() {
+    if iter.is_null() || (*iter).private_data.is_null() {
         return;
     }
     let _ = Box::from_raw((*iter).private_data as *mut PrivateData);
+    (*iter).private_data = std::ptr::null_mut();
     (*iter).release = None;
     // private drops automatically
 }
 
 /// Exports an iterator to the [C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html)
+///
+/// Errors of `iter` and items whose data type differs from `field`'s are returned by
+/// `get_next` as errno-compatible codes (`EIO` for [`ArrowError::Io`] and
+/// [`ArrowError::External`], `EINVAL` otherwise) and their messages, which include the code,
+/// by `get_last_error`.
+///
+/// The stream must be consumed from the thread that exported it; use [`export_iterator_send`]
+/// to export a stream that can be consumed from any thread.
 /// # Safety
 /// The pointer `consumer` must be allocated
 pub unsafe fn export_iterator(
     iter: Box<dyn Iterator<Item = Result<Arc<dyn Array>, ArrowError>>>,
     field: Field,
     consumer: *mut ArrowArrayStream,
+) {
+    export(iter, field, consumer)
+}
+
+/// Exports a [`Send`] iterator to the [C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html),
+/// like [`export_iterator`].
+///
+/// The stream's callbacks may be called from any thread, as long as they are not called
+/// concurrently, as consumers such as pyarrow and duckdb do.
+/// # Safety
+/// The pointer `consumer` must be allocated
+pub unsafe fn export_iterator_send(
+    iter: Box<dyn Iterator<Item = Result<Arc<dyn Array>, ArrowError>> + Send>,
+    field: Field,
+    consumer: *mut ArrowArrayStream,
+) {
+    export(iter, field, consumer)
+}
+
+unsafe fn export(
+    iter: Box<dyn Iterator<Item = Result<Arc<dyn Array>, ArrowError>>>,
+    field: Field,
+    consumer: *mut ArrowArrayStream,
 ) {
     let private_data = Box::new(PrivateData {
         iter,
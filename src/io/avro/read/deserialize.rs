@@ -21,6 +21,7 @@ fn make_mutable(
     capacity: usize,
 ) -> Result<Box<dyn MutableArray>> {
     Ok(match data_type.to_physical_type() {
+        PhysicalType::Null => Box::new(MutableNullArray::new()) as Box<dyn MutableArray>,
         PhysicalType::Boolean => {
             Box::new(MutableBooleanArray::with_capacity(capacity)) as Box<dyn MutableArray>
         }
@@ -64,6 +65,25 @@ fn make_mutable(
                 Box::new(DynMutableStructArray::new(values, data_type.clone()))
                     as Box<dyn MutableArray>
             }
+            DataType::Union(fields, _, _) => {
+                let schemas = match avro_schema {
+                    Some(AvroSchema::Union(schemas)) => Some(schemas),
+                    _ => None,
+                };
+                let values = fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| {
+                        let schema = schemas.and_then(|x| x.get(i));
+                        make_mutable(field.data_type(), schema, 0)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Box::new(DynMutableUnionArray::new(
+                    values,
+                    data_type.clone(),
+                    capacity,
+                )) as Box<dyn MutableArray>
+            }
             other => {
                 return Err(ArrowError::NotYetImplemented(format!(
                     "Deserializing type {:#?} is still not implemented",
@@ -82,6 +102,18 @@ fn is_union_null_first(avro_field: &AvroSchema) -> bool {
     }
 }
 
+/// Reads the index of the variant of a union with `num_variants` variants
+fn union_variant(block: &mut &[u8], num_variants: usize) -> Result<usize> {
+    let variant = util::zigzag_i64(block)?;
+    if variant < 0 || variant as usize >= num_variants {
+        return Err(ArrowError::ExternalFormat(format!(
+            "Avro union variant {} is out of bounds for a union of {} variants",
+            variant, num_variants
+        )));
+    }
+    Ok(variant as usize)
+}
+
 fn deserialize_item<'a>(
     array: &mut dyn MutableArray,
     is_nullable: bool,
@@ -134,8 +166,8 @@ fn deserialize_value<'a>(
                 for _ in 0..len {
                     block = deserialize_item(values, is_nullable, avro_inner, block)?;
                 }
-                array.try_push_valid()?;
             }
+            array.try_push_valid()?;
         }
         DataType::Struct(inner_fields) => {
             let fields = match avro_field {
@@ -162,7 +194,22 @@ fn deserialize_value<'a>(
                 block = deserialize_item(values, *is_nullable, &field.schema, block)?;
             }
         }
+        DataType::Union(fields, _, _) => {
+            let schemas = match avro_field {
+                AvroSchema::Union(schemas) => schemas,
+                _ => unreachable!(),
+            };
+            let variant = union_variant(&mut block, fields.len())?;
+
+            let array = array
+                .as_mut_any()
+                .downcast_mut::<DynMutableUnionArray>()
+                .unwrap();
+            let values = array.try_push_field(variant)?;
+            block = deserialize_value(values, &schemas[variant], block)?;
+        }
         _ => match data_type.to_physical_type() {
+            PhysicalType::Null => array.push_null(),
             PhysicalType::Boolean => {
                 let is_valid = block[0] == 1;
                 block = &block[1..];
@@ -278,7 +325,9 @@ fn deserialize_value<'a>(
                     .as_mut_any()
                     .downcast_mut::<MutableUtf8Array<i32>>()
                     .unwrap();
-                array.push(Some(data))
+                array
+                    .try_push(Some(data))
+                    .map_err(|_| ArrowError::offset_overflow(array.data_type()))?;
             }
             PhysicalType::Binary => {
                 let len: usize = util::zigzag_i64(&mut block)?.try_into().map_err(|_| {
@@ -293,7 +342,9 @@ fn deserialize_value<'a>(
                     .as_mut_any()
                     .downcast_mut::<MutableBinaryArray<i32>>()
                     .unwrap();
-                array.push(Some(data));
+                array
+                    .try_push(Some(data))
+                    .map_err(|_| ArrowError::offset_overflow(array.data_type()))?;
             }
             PhysicalType::FixedSizeBinary => {
                 let array = array
@@ -367,7 +418,16 @@ fn skip_item<'a>(field: &Field, avro_field: &AvroSchema, mut block: &'a [u8]) ->
                 block = skip_item(field, &avro_field.schema, block)?;
             }
         }
+        DataType::Union(fields, _, _) => {
+            let schemas = match avro_field {
+                AvroSchema::Union(schemas) => schemas,
+                _ => unreachable!(),
+            };
+            let variant = union_variant(&mut block, fields.len())?;
+            block = skip_item(&fields[variant], &schemas[variant], block)?;
+        }
         _ => match field.data_type.to_physical_type() {
+            PhysicalType::Null => {}
             PhysicalType::Boolean => {
                 let _ = block[0] == 1;
                 block = &block[1..];
@@ -438,18 +498,14 @@ fn skip_item<'a>(field: &Field, avro_field: &AvroSchema, mut block: &'a [u8]) ->
     Ok(block)
 }
 
-/// Deserializes a [`Block`] into [`Chunk`], projected
-pub fn deserialize(
-    block: &Block,
+/// Returns one [`MutableArray`] per field, with capacity for `rows` rows if projected.
+fn make_mutables(
     fields: &[Field],
     avro_schemas: &[AvroSchema],
     projection: &[bool],
-) -> Result<Chunk<Arc<dyn Array>>> {
-    let rows = block.number_of_rows;
-    let mut block = block.data.as_ref();
-
-    // create mutables, one per field
-    let mut arrays: Vec<Box<dyn MutableArray>> = fields
+    rows: usize,
+) -> Result<Vec<Box<dyn MutableArray>>> {
+    fields
         .iter()
         .zip(avro_schemas.iter())
         .zip(projection.iter())
@@ -461,24 +517,37 @@ pub fn deserialize(
                 make_mutable(&DataType::Int32, None, 0)
             }
         })
-        .collect::<Result<_>>()?;
+        .collect()
+}
 
-    // this is _the_ expensive transpose (rows -> columns)
-    for _ in 0..rows {
-        let iter = arrays
-            .iter_mut()
-            .zip(fields.iter())
-            .zip(avro_schemas.iter())
-            .zip(projection.iter());
+/// Deserializes a single row from `block` into `arrays`, returning the remaining of `block`.
+fn deserialize_row<'a>(
+    arrays: &mut [Box<dyn MutableArray>],
+    fields: &[Field],
+    avro_schemas: &[AvroSchema],
+    projection: &[bool],
+    mut block: &'a [u8],
+) -> Result<&'a [u8]> {
+    let iter = arrays
+        .iter_mut()
+        .zip(fields.iter())
+        .zip(avro_schemas.iter())
+        .zip(projection.iter());
 
-        for (((array, field), avro_field), projection) in iter {
-            block = if *projection {
-                deserialize_item(array.as_mut(), field.is_nullable, avro_field, block)
-            } else {
-                skip_item(field, avro_field, block)
-            }?
-        }
+    for (((array, field), avro_field), projection) in iter {
+        block = if *projection {
+            deserialize_item(array.as_mut(), field.is_nullable, avro_field, block)
+        } else {
+            skip_item(field, avro_field, block)
+        }?
     }
+    Ok(block)
+}
+
+fn finish(
+    mut arrays: Vec<Box<dyn MutableArray>>,
+    projection: &[bool],
+) -> Result<Chunk<Arc<dyn Array>>> {
     Chunk::try_new(
         arrays
             .iter_mut()
@@ -488,3 +557,55 @@ pub fn deserialize(
             .collect(),
     )
 }
+
+/// Deserializes a [`Block`] into [`Chunk`], projected
+pub fn deserialize(
+    block: &Block,
+    fields: &[Field],
+    avro_schemas: &[AvroSchema],
+    projection: &[bool],
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let rows = block.number_of_rows;
+    let mut block = block.data.as_ref();
+
+    // create mutables, one per field
+    let mut arrays = make_mutables(fields, avro_schemas, projection, rows)?;
+
+    // this is _the_ expensive transpose (rows -> columns)
+    for _ in 0..rows {
+        block = deserialize_row(&mut arrays, fields, avro_schemas, projection, block)?;
+    }
+    finish(arrays, projection)
+}
+
+/// Deserializes standalone Avro-encoded records (i.e. not in an Avro file), such as the
+/// messages of a stream, into a [`Chunk`], projected.
+///
+/// Each record must be the binary encoding of a single record of the schema `avro_schemas`
+/// (see [`parse_schema`](super::parse_schema)), without any header
+/// (see [`split_confluent_header`](super::split_confluent_header)).
+/// # Errors
+/// Errors iff a record is not a valid encoding of the schema.
+pub fn deserialize_records<'a, I>(
+    records: I,
+    fields: &[Field],
+    avro_schemas: &[AvroSchema],
+    projection: &[bool],
+) -> Result<Chunk<Arc<dyn Array>>>
+where
+    I: IntoIterator<Item = &'a [u8]>,
+{
+    let records = records.into_iter();
+    let mut arrays = make_mutables(fields, avro_schemas, projection, records.size_hint().0)?;
+
+    for record in records {
+        let remaining = deserialize_row(&mut arrays, fields, avro_schemas, projection, record)?;
+        if !remaining.is_empty() {
+            return Err(ArrowError::ExternalFormat(format!(
+                "The Avro record has {} bytes beyond its schema",
+                remaining.len()
+            )));
+        }
+    }
+    finish(arrays, projection)
+}
@@ -10,7 +10,7 @@ mod decompress;
 pub use block::BlockStreamIterator;
 pub use decompress::{decompress_block, Decompressor};
 mod deserialize;
-pub use deserialize::deserialize;
+pub use deserialize::{deserialize, deserialize_records};
 mod header;
 mod nested;
 mod schema;
@@ -22,7 +22,7 @@ pub(super) use schema::infer_schema;
 use crate::array::Array;
 use crate::chunk::Chunk;
 use crate::datatypes::{Field, Schema};
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 
 use super::Compression;
 
@@ -32,15 +32,52 @@ pub fn read_metadata<R: std::io::Read>(
     reader: &mut R,
 ) -> Result<(Vec<AvroSchema>, Schema, Option<Compression>, [u8; 16])> {
     let (avro_schema, codec, marker) = util::read_schema(reader)?;
+    let (avro_schema, schema) = split_record_schema(avro_schema)?;
+
+    Ok((avro_schema, schema, codec, marker))
+}
+
+/// Returns the Avro schemas of the fields of the record `avro_schema` and the corresponding [`Schema`].
+fn split_record_schema(avro_schema: AvroSchema) -> Result<(Vec<AvroSchema>, Schema)> {
     let schema = infer_schema(&avro_schema)?;
 
     let avro_schema = if let AvroSchema::Record(Record { fields, .. }) = avro_schema {
         fields.into_iter().map(|x| x.schema).collect()
     } else {
-        panic!()
+        // `infer_schema` errors on non-record schemas
+        unreachable!()
     };
+    Ok((avro_schema, schema))
+}
 
-    Ok((avro_schema, schema, codec, marker))
+/// Parses the JSON declaration of an Avro record schema (e.g. obtained from a schema registry)
+/// into the Avro schemas of its fields and the corresponding [`Schema`], as used by
+/// [`deserialize_records`].
+/// # Errors
+/// Errors iff `json` is not a valid Avro schema of a record, or one of its fields can't be
+/// represented in Arrow.
+pub fn parse_schema(json: &[u8]) -> Result<(Vec<AvroSchema>, Schema)> {
+    let avro_schema =
+        serde_json::from_slice(json).map_err(|e| ArrowError::ExternalFormat(e.to_string()))?;
+    split_record_schema(avro_schema)
+}
+
+/// Splits a message in Confluent's wire format into its schema id and its Avro-encoded
+/// record, which can be deserialized via [`deserialize_records`].
+///
+/// The wire format prefixes the record with a header of 5 bytes: a zero magic byte followed
+/// by the id of the record's schema in the schema registry, as a big-endian `u32`.
+/// # Errors
+/// Errors iff `message` is shorter than the header or its magic byte is not zero.
+pub fn split_confluent_header(message: &[u8]) -> Result<(u32, &[u8])> {
+    match message {
+        [0, id0, id1, id2, id3, record @ ..] => {
+            Ok((u32::from_be_bytes([*id0, *id1, *id2, *id3]), record))
+        }
+        _ => Err(ArrowError::ExternalFormat(
+            "The message does not start with the header of Confluent's wire format".to_string(),
+        )),
+    }
 }
 
 /// Single threaded, blocking reader of Avro; [`Iterator`] of [`Chunk`].
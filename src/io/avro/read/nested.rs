@@ -285,3 +285,144 @@ impl MutableArray for DynMutableStructArray {
         todo!();
     }
 }
+
+/// Auxiliary struct
+#[derive(Debug)]
+pub struct DynMutableUnionArray {
+    data_type: DataType,
+    types: Vec<i8>,
+    offsets: Vec<i32>,
+    fields: Vec<Box<dyn MutableArray>>,
+}
+
+impl DynMutableUnionArray {
+    pub fn new(fields: Vec<Box<dyn MutableArray>>, data_type: DataType, capacity: usize) -> Self {
+        Self {
+            data_type,
+            types: Vec::with_capacity(capacity),
+            offsets: Vec::with_capacity(capacity),
+            fields,
+        }
+    }
+
+    /// Pushes a new slot of field `field` and returns its values, to which the slot's value
+    /// must be pushed.
+    /// # Errors
+    /// Errors iff `field` does not fit in an `i8` or the field's length in an `i32`.
+    pub fn try_push_field(&mut self, field: usize) -> Result<&mut dyn MutableArray> {
+        let values = self.fields[field].as_mut();
+        let type_ = i8::try_from(field).map_err(|_| ArrowError::Overflow)?;
+        let offset = i32::try_from(values.len()).map_err(|_| ArrowError::Overflow)?;
+        self.types.push(type_);
+        self.offsets.push(offset);
+        Ok(values)
+    }
+}
+
+impl MutableArray for DynMutableUnionArray {
+    fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        let fields = self.fields.iter_mut().map(|x| x.as_arc()).collect();
+
+        Box::new(UnionArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.types).into(),
+            fields,
+            Some(std::mem::take(&mut self.offsets).into()),
+        ))
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.as_box().into()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Pushes a null to the first field of type [`DataType::Null`], or, if none exists, to
+    /// the first field.
+    /// # Panics
+    /// Panics iff the field does not fit in an `i8` or its length in an `i32`.
+    fn push_null(&mut self) {
+        let field = self
+            .fields
+            .iter()
+            .position(|x| x.data_type() == &DataType::Null)
+            .unwrap_or(0);
+        self.try_push_field(field).unwrap().push_null()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.types.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        self.fields.iter_mut().for_each(|x| x.shrink_to_fit());
+    }
+}
+
+/// Auxiliary struct
+#[derive(Debug)]
+pub struct MutableNullArray {
+    length: usize,
+}
+
+impl MutableNullArray {
+    pub fn new() -> Self {
+        Self { length: 0 }
+    }
+}
+
+impl MutableArray for MutableNullArray {
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(NullArray::new_null(
+            DataType::Null,
+            std::mem::take(&mut self.length),
+        ))
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.as_box().into()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &DataType::Null
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.length += 1
+    }
+
+    fn shrink_to_fit(&mut self) {}
+}
@@ -1,4 +1,4 @@
-use avro_schema::{Enum, Fixed, Record, Schema as AvroSchema};
+use avro_schema::{Enum, Field as AvroField, Fixed, Record, Schema as AvroSchema};
 
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
@@ -19,21 +19,62 @@ fn external_props(schema: &AvroSchema) -> Metadata {
     props
 }
 
+fn aliases_to_json(aliases: &[String]) -> Result<String> {
+    serde_json::to_string(aliases).map_err(|e| ArrowError::ExternalFormat(e.to_string()))
+}
+
+/// Returns the metadata of the [`Field`] of `field`: its `doc` (defaulting to the `doc` of its
+/// type), `aliases` and `default` annotations, in the keys `avro::doc`, `avro::aliases` (a JSON array)
+/// and `avro::default` (the JSON of the default value).
+fn field_props(field: &AvroField) -> Result<Metadata> {
+    let mut props = external_props(&field.schema);
+    if let Some(doc) = &field.doc {
+        props.insert("avro::doc".to_string(), doc.clone());
+    }
+    if !field.aliases.is_empty() {
+        props.insert(
+            "avro::aliases".to_string(),
+            aliases_to_json(&field.aliases)?,
+        );
+    }
+    if let Some(default) = &field.default {
+        // `avro_schema` parses the default value `null` to `AvroSchema::Null`
+        let default = match default {
+            AvroSchema::Null => "null".to_string(),
+            other => serde_json::to_string(other)
+                .map_err(|e| ArrowError::ExternalFormat(e.to_string()))?,
+        };
+        props.insert("avro::default".to_string(), default);
+    }
+    Ok(props)
+}
+
 /// Infers an [`Schema`] from the root [`AvroSchema`].
-/// This
+///
+/// The `doc`, `aliases` and `default` annotations of the fields of the record are
+/// declared in the metadata of the corresponding [`Field`]s, in the keys `avro::doc`,
+/// `avro::aliases` (a JSON array of the aliases) and `avro::default` (the JSON of the default value).
+/// The `doc` and `aliases` of the record are declared in the metadata of the [`Schema`].
 pub fn infer_schema(schema: &AvroSchema) -> Result<Schema> {
-    if let AvroSchema::Record(Record { fields, .. }) = schema {
-        Ok(fields
+    if let AvroSchema::Record(Record {
+        fields,
+        doc,
+        aliases,
+        ..
+    }) = schema
+    {
+        let mut metadata = Metadata::new();
+        if let Some(doc) = doc {
+            metadata.insert("avro::doc".to_string(), doc.clone());
+        }
+        if !aliases.is_empty() {
+            metadata.insert("avro::aliases".to_string(), aliases_to_json(aliases)?);
+        }
+        let fields = fields
             .iter()
-            .map(|field| {
-                schema_to_field(
-                    &field.schema,
-                    Some(&field.name),
-                    external_props(&field.schema),
-                )
-            })
-            .collect::<Result<Vec<_>>>()?
-            .into())
+            .map(|field| schema_to_field(&field.schema, Some(&field.name), field_props(field)?))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Schema::from(fields).with_metadata(metadata))
     } else {
         Err(ArrowError::OutOfSpec(
             "The root AvroSchema must be of type Record".to_string(),
@@ -115,13 +156,7 @@ fn schema_to_field(schema: &AvroSchema, name: Option<&str>, props: Metadata) ->
         AvroSchema::Record(Record { fields, .. }) => {
             let fields = fields
                 .iter()
-                .map(|field| {
-                    let mut props = Metadata::new();
-                    if let Some(doc) = &field.doc {
-                        props.insert("avro::doc".to_string(), doc.clone());
-                    }
-                    schema_to_field(&field.schema, Some(&field.name), props)
-                })
+                .map(|field| schema_to_field(&field.schema, Some(&field.name), field_props(field)?))
                 .collect::<Result<_>>()?;
             DataType::Struct(fields)
         }
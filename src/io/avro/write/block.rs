@@ -7,13 +7,23 @@ use super::{util::zigzag_encode, SYNC_NUMBER};
 
 /// Writes a [`CompressedBlock`] to `writer`
 pub fn write_block<W: Write>(writer: &mut W, compressed_block: &CompressedBlock) -> Result<()> {
+    write_block_with_marker(writer, compressed_block, &SYNC_NUMBER)
+}
+
+/// Writes a [`CompressedBlock`] to `writer` followed by the sync `marker`. The marker must be
+/// the one declared in the file's metadata, e.g. when appending to an existing file.
+pub fn write_block_with_marker<W: Write>(
+    writer: &mut W,
+    compressed_block: &CompressedBlock,
+    marker: &[u8; 16],
+) -> Result<()> {
     // write size and rows
     zigzag_encode(compressed_block.number_of_rows as i64, writer)?;
     zigzag_encode(compressed_block.data.len() as i64, writer)?;
 
     writer.write_all(&compressed_block.data)?;
 
-    writer.write_all(&SYNC_NUMBER)?;
+    writer.write_all(marker)?;
 
     Ok(())
 }
@@ -0,0 +1,176 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use avro_schema::Field as AvroField;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Schema;
+use crate::error::{ArrowError, Result};
+
+use super::super::read::read_metadata;
+use super::{
+    compress, new_serializer, to_avro_schema, write_block_with_marker, write_schema_metadata,
+    Block, CompressedBlock, Compression, SYNC_NUMBER,
+};
+
+/// The size of the blocks written by a [`FileWriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockSize {
+    /// Blocks with (at most) this number of rows
+    Rows(usize),
+    /// Blocks whose uncompressed size is (approximately) this number of bytes.
+    /// A block is written as soon as it reaches this size.
+    Bytes(usize),
+}
+
+/// Writes [`Chunk`]s to an Avro object container file, grouping rows into blocks.
+///
+/// Without a [`BlockSize`], each call to [`FileWriter::write`] writes a single block.
+/// Rows pending to be written are only written on [`FileWriter::flush`] and
+/// [`FileWriter::into_inner`]; dropping the writer discards them.
+pub struct FileWriter<W: Write> {
+    writer: W,
+    avro_fields: Vec<AvroField>,
+    compression: Option<Compression>,
+    marker: [u8; 16],
+    block_size: Option<BlockSize>,
+    block: Block,
+    compressed: CompressedBlock,
+}
+
+impl<W: Write> FileWriter<W> {
+    /// Creates a new [`FileWriter`], writing the file's metadata to `writer`.
+    /// # Error
+    /// Errors iff the `schema` can't be converted to Avro or writing fails.
+    pub fn try_new(
+        mut writer: W,
+        schema: &Schema,
+        compression: Option<Compression>,
+    ) -> Result<Self> {
+        let avro_fields = to_avro_schema(schema)?;
+        write_schema_metadata(&mut writer, schema, compression)?;
+        Ok(Self::new(writer, avro_fields, compression, SYNC_NUMBER))
+    }
+
+    fn new(
+        writer: W,
+        avro_fields: Vec<AvroField>,
+        compression: Option<Compression>,
+        marker: [u8; 16],
+    ) -> Self {
+        Self {
+            writer,
+            avro_fields,
+            compression,
+            marker,
+            block_size: None,
+            block: Block::default(),
+            compressed: CompressedBlock::default(),
+        }
+    }
+
+    /// Sets the size of the blocks written by this writer
+    pub fn set_block_size(&mut self, block_size: BlockSize) {
+        self.block_size = Some(block_size);
+    }
+
+    /// Returns the sync marker written after each block of this file
+    pub fn marker(&self) -> [u8; 16] {
+        self.marker
+    }
+
+    /// Returns the [`Compression`] of the blocks of this file
+    pub fn compression(&self) -> Option<Compression> {
+        self.compression
+    }
+
+    /// Writes `chunk` to the file, writing a block whenever the block size is reached.
+    /// # Error
+    /// Errors iff the number of arrays in `chunk` differs from the number of fields
+    /// or writing fails.
+    /// # Panic
+    /// Panics iff the arrays' data types are not those of the schema of this writer.
+    pub fn write<A: AsRef<dyn Array>>(&mut self, chunk: &Chunk<A>) -> Result<()> {
+        if chunk.arrays().len() != self.avro_fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The chunk has {} arrays but the file has {} fields",
+                chunk.arrays().len(),
+                self.avro_fields.len()
+            )));
+        }
+        let mut serializers = chunk
+            .arrays()
+            .iter()
+            .zip(self.avro_fields.iter())
+            .map(|(array, field)| new_serializer(array.as_ref(), &field.schema))
+            .collect::<Vec<_>>();
+
+        for _ in 0..chunk.len() {
+            for serializer in &mut serializers {
+                self.block.data.extend(serializer.next().unwrap());
+            }
+            self.block.number_of_rows += 1;
+
+            let is_full = match self.block_size {
+                Some(BlockSize::Rows(rows)) => self.block.number_of_rows >= rows,
+                Some(BlockSize::Bytes(bytes)) => self.block.data.len() >= bytes,
+                None => false,
+            };
+            if is_full {
+                self.flush()?;
+            }
+        }
+        if self.block_size.is_none() {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Writes the pending rows, if any, as a block.
+    pub fn flush(&mut self) -> Result<()> {
+        if self.block.number_of_rows == 0 {
+            return Ok(());
+        }
+        compress(&mut self.block, &mut self.compressed, self.compression)?;
+        write_block_with_marker(&mut self.writer, &self.compressed, &self.marker)?;
+        self.block.data.clear();
+        self.block.number_of_rows = 0;
+        Ok(())
+    }
+
+    /// Writes the pending rows and returns the inner writer.
+    pub fn into_inner(mut self) -> Result<W> {
+        self.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Read + Write + Seek> FileWriter<W> {
+    /// Creates a new [`FileWriter`] that appends blocks to the existing Avro file in `writer`,
+    /// using the file's compression and sync marker.
+    /// # Error
+    /// Errors iff the file's metadata is invalid or its schema differs from `schema`.
+    pub fn try_append(mut writer: W, schema: &Schema) -> Result<Self> {
+        writer.seek(SeekFrom::Start(0))?;
+        let (avro_schemas, file_schema, compression, marker) = read_metadata(&mut writer)?;
+
+        let avro_fields = to_avro_schema(schema)?;
+        let is_equal = avro_fields.len() == avro_schemas.len()
+            && avro_fields
+                .iter()
+                .zip(avro_schemas.iter())
+                .zip(file_schema.fields.iter())
+                .all(|((field, avro_schema), file_field)| {
+                    field.name == file_field.name && &field.schema == avro_schema
+                });
+        if !is_equal {
+            return Err(ArrowError::InvalidArgumentError(
+                "Avro files can only be appended with the schema they were written with"
+                    .to_string(),
+            ));
+        }
+
+        writer.seek(SeekFrom::End(0))?;
+        Ok(Self::new(writer, avro_fields, compression, marker))
+    }
+}
@@ -1,19 +1,26 @@
 use std::collections::HashMap;
 
 use avro_schema::Schema;
-use serde_json;
+use serde_json::{self, Value};
 
+use crate::datatypes::Field;
 use crate::error::{ArrowError, Result};
 
-use super::Compression;
+use super::{schema::declare_defaults, Compression};
 
-/// Serializes an [`Schema`] and optional [`Compression`] into an avro header.
+/// Serializes an [`Schema`] and optional [`Compression`] into an avro header, declaring the
+/// default values of the record's `fields`, if any.
 pub(crate) fn serialize_header(
     schema: &Schema,
+    fields: &[Field],
     compression: Option<Compression>,
 ) -> Result<HashMap<String, Vec<u8>>> {
-    let schema =
-        serde_json::to_string(schema).map_err(|e| ArrowError::ExternalFormat(e.to_string()))?;
+    let mut schema =
+        serde_json::to_value(schema).map_err(|e| ArrowError::ExternalFormat(e.to_string()))?;
+    if let Some(Value::Array(values)) = schema.get_mut("fields") {
+        declare_defaults(values, fields)?;
+    }
+    let schema = schema.to_string();
 
     let mut header = HashMap::<String, Vec<u8>>::default();
 
@@ -1,6 +1,7 @@
 //! APIs to write to Avro format.
 use avro_schema::{Field as AvroField, Record, Schema as AvroSchema};
 
+use crate::datatypes::{Field, Schema};
 use crate::error::Result;
 
 pub use super::Compression;
@@ -8,18 +9,21 @@ pub use super::Compression;
 mod header;
 pub(super) use header::serialize_header;
 mod schema;
-pub use schema::to_avro_schema;
+pub use schema::{to_avro_record, to_avro_schema};
 mod serialize;
 pub use serialize::{can_serialize, new_serializer, BoxSerializer};
 mod block;
 pub use block::*;
 mod compress;
+mod file;
+pub use file::{BlockSize, FileWriter};
 pub(super) mod util;
 pub use compress::compress;
 
 pub use super::{Block, CompressedBlock};
 
-pub(super) const SYNC_NUMBER: [u8; 16] = [1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4];
+/// The sync marker written after each block by [`write_metadata`] and [`write_block`].
+pub const SYNC_NUMBER: [u8; 16] = [1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4];
 // * Four bytes, ASCII 'O', 'b', 'j', followed by 1.
 pub(super) const AVRO_MAGIC: [u8; 4] = [b'O', b'b', b'j', 1u8];
 
@@ -28,13 +32,35 @@ pub fn write_metadata<W: std::io::Write>(
     writer: &mut W,
     fields: Vec<AvroField>,
     compression: Option<Compression>,
+) -> Result<()> {
+    let schema = AvroSchema::Record(Record::new("", fields));
+    write_avro_metadata(writer, &schema, &[], compression)
+}
+
+/// Writes Avro's metadata to `writer`, declaring the record of [`to_avro_record`] as the
+/// schema of the file and the `default` annotations of the fields from the key
+/// `avro::default` (the JSON of the default value) of their metadata.
+/// # Error
+/// Errors iff the `schema` can't be converted to Avro or writing fails.
+pub fn write_schema_metadata<W: std::io::Write>(
+    writer: &mut W,
+    schema: &Schema,
+    compression: Option<Compression>,
+) -> Result<()> {
+    let record = AvroSchema::Record(to_avro_record(schema)?);
+    write_avro_metadata(writer, &record, &schema.fields, compression)
+}
+
+fn write_avro_metadata<W: std::io::Write>(
+    writer: &mut W,
+    schema: &AvroSchema,
+    fields: &[Field],
+    compression: Option<Compression>,
 ) -> Result<()> {
     writer.write_all(&AVRO_MAGIC)?;
 
     // * file metadata, including the schema.
-    let schema = AvroSchema::Record(Record::new("", fields));
-
-    write_schema(writer, &schema, compression)?;
+    write_schema(writer, schema, fields, compression)?;
 
     // The 16-byte, randomly-generated sync marker for this file.
     writer.write_all(&SYNC_NUMBER)?;
@@ -66,9 +92,10 @@ pub fn serialize<'a>(serializers: &mut [BoxSerializer<'a>], block: &mut Block) {
 pub(super) fn write_schema<W: std::io::Write>(
     writer: &mut W,
     schema: &AvroSchema,
+    fields: &[Field],
     compression: Option<Compression>,
 ) -> Result<()> {
-    let header = serialize_header(schema, compression)?;
+    let header = serialize_header(schema, fields, compression)?;
 
     util::zigzag_encode(header.len() as i64, writer)?;
     for (name, item) in header {
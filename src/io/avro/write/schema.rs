@@ -2,22 +2,117 @@ use avro_schema::{
     BytesLogical, Field as AvroField, Fixed, FixedLogical, IntLogical, LongLogical, Record,
     Schema as AvroSchema,
 };
+use serde_json::Value;
 
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 
 /// Converts a [`Schema`] to a vector of [`AvroField`] with it.
+///
+/// The `doc` and `aliases` annotations of the fields are declared from the keys `avro::doc`
+/// and `avro::aliases` (a JSON array of the aliases) of the metadata of the [`Field`]s, as
+/// declared when reading Avro files. [`AvroField`] can't represent default values, so the key
+/// `avro::default` (the JSON of the default value) is only declared by
+/// [`write_schema_metadata`](super::write_schema_metadata).
 pub fn to_avro_schema(schema: &Schema) -> Result<Vec<AvroField>> {
     schema.fields.iter().map(field_to_field).collect()
 }
 
+/// Converts a [`Schema`] to the [`Record`] of the root of an Avro file, whose fields are
+/// declared by [`to_avro_schema`] and whose `doc` and `aliases` are declared from the keys
+/// `avro::doc` and `avro::aliases` of the metadata of the [`Schema`].
+pub fn to_avro_record(schema: &Schema) -> Result<Record> {
+    let mut record = Record::new("", to_avro_schema(schema)?);
+    record.doc = schema.metadata.get("avro::doc").cloned();
+    record.aliases = aliases(&schema.metadata)?;
+    Ok(record)
+}
+
+fn aliases(metadata: &Metadata) -> Result<Vec<String>> {
+    metadata
+        .get("avro::aliases")
+        .map(|aliases| {
+            serde_json::from_str(aliases).map_err(|e| {
+                ArrowError::InvalidArgumentError(format!(
+                    "The metadata \"avro::aliases\" must be a JSON array of strings: {}",
+                    e
+                ))
+            })
+        })
+        .transpose()
+        .map(|x| x.unwrap_or_default())
+}
+
+fn default(metadata: &Metadata) -> Result<Option<Value>> {
+    metadata
+        .get("avro::default")
+        .map(|default| {
+            serde_json::from_str(default).map_err(|e| {
+                ArrowError::InvalidArgumentError(format!(
+                    "The metadata \"avro::default\" must be a JSON value: {}",
+                    e
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Declares the `default` annotations of `fields` (and of their children) in `values`, the
+/// JSON of the corresponding Avro fields.
+pub(super) fn declare_defaults(values: &mut [Value], fields: &[Field]) -> Result<()> {
+    for (value, field) in values.iter_mut().zip(fields) {
+        let value = match value.as_object_mut() {
+            Some(value) => value,
+            None => continue,
+        };
+        if let Some(default) = default(&field.metadata)? {
+            value.insert("default".to_string(), default);
+        }
+        if let Some(type_) = value.get_mut("type") {
+            declare_type_defaults(type_, field.data_type())?;
+        }
+    }
+    Ok(())
+}
+
+fn declare_type_defaults(value: &mut Value, data_type: &DataType) -> Result<()> {
+    match (value, data_type.to_logical_type()) {
+        (Value::Array(variants), DataType::Union(fields, _, _)) => variants
+            .iter_mut()
+            .zip(fields)
+            .try_for_each(|(variant, field)| declare_type_defaults(variant, field.data_type())),
+        // a nullable type, `["null", type]`
+        (Value::Array(variants), data_type) => match variants.last_mut() {
+            Some(variant) => declare_type_defaults(variant, data_type),
+            None => Ok(()),
+        },
+        (Value::Object(map), DataType::Struct(fields)) => match map.get_mut("fields") {
+            Some(Value::Array(values)) => declare_defaults(values, fields),
+            _ => Ok(()),
+        },
+        (
+            Value::Object(map),
+            DataType::List(inner) | DataType::LargeList(inner) | DataType::FixedSizeList(inner, _),
+        ) => match map.get_mut("items") {
+            Some(items) => declare_type_defaults(items, inner.data_type()),
+            None => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
 fn field_to_field(field: &Field) -> Result<AvroField> {
     let schema = type_to_schema(field.data_type(), field.is_nullable)?;
-    Ok(AvroField::new(&field.name, schema))
+    let mut avro_field = AvroField::new(&field.name, schema);
+    avro_field.doc = field.metadata.get("avro::doc").cloned();
+    avro_field.aliases = aliases(&field.metadata)?;
+    Ok(avro_field)
 }
 
 fn type_to_schema(data_type: &DataType, is_nullable: bool) -> Result<AvroSchema> {
-    Ok(if is_nullable {
+    // unions do not have a validity; nulls are a variant of them
+    let is_union = matches!(data_type.to_logical_type(), DataType::Union(..));
+    Ok(if is_nullable && !is_union {
         AvroSchema::Union(vec![AvroSchema::Null, _type_to_schema(data_type)?])
     } else {
         _type_to_schema(data_type)?
@@ -36,9 +131,12 @@ fn _type_to_schema(data_type: &DataType) -> Result<AvroSchema> {
         DataType::LargeBinary => AvroSchema::Bytes(None),
         DataType::Utf8 => AvroSchema::String(None),
         DataType::LargeUtf8 => AvroSchema::String(None),
-        DataType::LargeList(inner) | DataType::List(inner) => AvroSchema::Array(Box::new(
-            type_to_schema(&inner.data_type, inner.is_nullable)?,
-        )),
+        DataType::LargeList(inner) | DataType::List(inner) | DataType::FixedSizeList(inner, _) => {
+            AvroSchema::Array(Box::new(type_to_schema(
+                &inner.data_type,
+                inner.is_nullable,
+            )?))
+        }
         DataType::Struct(fields) => AvroSchema::Record(Record::new(
             "",
             fields
@@ -46,6 +144,22 @@ fn _type_to_schema(data_type: &DataType) -> Result<AvroSchema> {
                 .map(field_to_field)
                 .collect::<Result<Vec<_>>>()?,
         )),
+        DataType::Union(fields, _, _) => AvroSchema::Union(
+            fields
+                .iter()
+                .map(|field| {
+                    // Avro unions cannot contain unions, so their variants cannot be nullable
+                    if field.is_nullable && field.data_type() != &DataType::Null {
+                        Err(ArrowError::NotYetImplemented(format!(
+                            "write union with nullable field {:?} to avro",
+                            field.name
+                        )))
+                    } else {
+                        _type_to_schema(field.data_type())
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?,
+        ),
         DataType::Date32 => AvroSchema::Int(Some(IntLogical::Date)),
         DataType::Time32(TimeUnit::Millisecond) => AvroSchema::Int(Some(IntLogical::Time)),
         DataType::Time64(TimeUnit::Microsecond) => AvroSchema::Long(Some(LongLogical::Time)),
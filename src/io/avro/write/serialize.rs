@@ -62,58 +62,61 @@ fn binary_optional<O: Offset>(array: &BinaryArray<O>) -> BoxSerializer {
     ))
 }
 
-fn list_required<'a, O: Offset>(array: &'a ListArray<O>, schema: &AvroSchema) -> BoxSerializer<'a> {
-    let mut inner = new_serializer(array.values().as_ref(), schema);
-    let lengths = array
-        .offsets()
-        .windows(2)
-        .map(|w| (w[1] - w[0]).to_usize() as i64);
-
+/// Serializes lists whose items are the next `length` items of `inner`, for every `(length, is_valid)`
+/// of `lengths`. The items of null lists are skipped.
+fn list<'a, I>(lengths: I, mut inner: BoxSerializer<'a>, is_nullable: bool) -> BoxSerializer<'a>
+where
+    I: Iterator<Item = (usize, bool)> + Send + Sync + 'a,
+{
     Box::new(BufStreamingIterator::new(
         lengths,
-        move |length, buf| {
-            util::zigzag_encode(length, buf).unwrap();
-            let mut rows = 0;
-            while let Some(item) = inner.next() {
-                buf.extend_from_slice(item);
-                rows += 1;
-                if rows == length {
-                    util::zigzag_encode(0, buf).unwrap();
-                    break;
+        move |(length, is_valid), buf| {
+            if is_nullable {
+                util::zigzag_encode(is_valid as i64, buf).unwrap();
+            }
+            if !is_valid {
+                (0..length).for_each(|_| inner.advance());
+                return;
+            }
+            // an array is a sequence of blocks terminated by an empty block
+            if length > 0 {
+                util::zigzag_encode(length as i64, buf).unwrap();
+                for _ in 0..length {
+                    buf.extend_from_slice(inner.next().unwrap());
                 }
             }
+            util::zigzag_encode(0, buf).unwrap();
         },
         vec![],
     ))
 }
 
-fn list_optional<'a, O: Offset>(array: &'a ListArray<O>, schema: &AvroSchema) -> BoxSerializer<'a> {
+fn list_serializer<'a, O: Offset>(
+    array: &'a ListArray<O>,
+    schema: &AvroSchema,
+    is_nullable: bool,
+) -> BoxSerializer<'a> {
     let mut inner = new_serializer(array.values().as_ref(), schema);
-    let lengths = array
-        .offsets()
-        .windows(2)
-        .map(|w| (w[1] - w[0]).to_usize() as i64);
-    let lengths = zip_validity(lengths, array.validity().as_ref().map(|x| x.iter()));
+    // the values of sliced arrays start at the first offset
+    (0..array.offsets()[0].to_usize()).for_each(|_| inner.advance());
 
-    Box::new(BufStreamingIterator::new(
-        lengths,
-        move |length, buf| {
-            util::zigzag_encode(length.is_some() as i64, buf).unwrap();
-            if let Some(length) = length {
-                util::zigzag_encode(length, buf).unwrap();
-                let mut rows = 0;
-                while let Some(item) = inner.next() {
-                    buf.extend_from_slice(item);
-                    rows += 1;
-                    if rows == length {
-                        util::zigzag_encode(0, buf).unwrap();
-                        break;
-                    }
-                }
-            }
-        },
-        vec![],
-    ))
+    let lengths = array.offsets().windows(2).map(|w| (w[1] - w[0]).to_usize());
+    let lengths = lengths
+        .enumerate()
+        .map(move |(i, length)| (length, array.is_valid(i)));
+    list(lengths, inner, is_nullable)
+}
+
+fn fixed_size_list_serializer<'a>(
+    array: &'a FixedSizeListArray,
+    schema: &AvroSchema,
+    is_nullable: bool,
+) -> BoxSerializer<'a> {
+    let inner = new_serializer(array.values().as_ref(), schema);
+    let size = FixedSizeListArray::get_child_and_size(array.data_type()).1;
+
+    let lengths = (0..array.len()).map(move |i| (size, array.is_valid(i)));
+    list(lengths, inner, is_nullable)
 }
 
 fn struct_required<'a>(array: &'a StructArray, schema: &Record) -> BoxSerializer<'a> {
@@ -166,6 +169,37 @@ fn struct_optional<'a>(array: &'a StructArray, schema: &Record) -> BoxSerializer
     ))
 }
 
+fn union<'a>(array: &'a UnionArray, schemas: &[AvroSchema]) -> BoxSerializer<'a> {
+    // the slots of dense unions are not necessarily increasing; serialize the values of
+    // each field upfront so that any slot is a lookup by field and slot
+    let fields = array
+        .fields()
+        .iter()
+        .zip(schemas.iter())
+        .map(|(x, schema)| {
+            let mut serializer = new_serializer(x.as_ref(), schema);
+            let mut values = vec![];
+            let mut offsets = vec![0];
+            while let Some(item) = serializer.next() {
+                values.extend_from_slice(item);
+                offsets.push(values.len());
+            }
+            (values, offsets)
+        })
+        .collect::<Vec<_>>();
+
+    Box::new(BufStreamingIterator::new(
+        0..array.len(),
+        move |index, buf| {
+            let (field, slot) = array.index(index);
+            let (values, offsets) = &fields[field];
+            util::zigzag_encode(field as i64, buf).unwrap();
+            buf.extend_from_slice(&values[offsets[slot]..offsets[slot + 1]]);
+        },
+        vec![],
+    ))
+}
+
 /// Creates a [`StreamingIterator`] trait object that presents items from `array`
 /// encoded according to `schema`.
 /// # Panic
@@ -404,26 +438,34 @@ pub fn new_serializer<'a>(array: &'a dyn Array, schema: &AvroSchema) -> BoxSeria
         }
 
         (PhysicalType::List, AvroSchema::Array(schema)) => {
-            list_required::<i32>(array.as_any().downcast_ref().unwrap(), schema.as_ref())
+            list_serializer::<i32>(array.as_any().downcast_ref().unwrap(), schema, false)
         }
         (PhysicalType::LargeList, AvroSchema::Array(schema)) => {
-            list_required::<i64>(array.as_any().downcast_ref().unwrap(), schema.as_ref())
+            list_serializer::<i64>(array.as_any().downcast_ref().unwrap(), schema, false)
         }
-        (PhysicalType::List, AvroSchema::Union(inner)) => {
-            let schema = if let AvroSchema::Array(schema) = &inner[1] {
-                schema.as_ref()
-            } else {
-                unreachable!("The schema declaration does not match the deserialization")
-            };
-            list_optional::<i32>(array.as_any().downcast_ref().unwrap(), schema)
+        (PhysicalType::FixedSizeList, AvroSchema::Array(schema)) => {
+            fixed_size_list_serializer(array.as_any().downcast_ref().unwrap(), schema, false)
         }
-        (PhysicalType::LargeList, AvroSchema::Union(inner)) => {
+        (
+            PhysicalType::List | PhysicalType::LargeList | PhysicalType::FixedSizeList,
+            AvroSchema::Union(inner),
+        ) => {
             let schema = if let AvroSchema::Array(schema) = &inner[1] {
                 schema.as_ref()
             } else {
                 unreachable!("The schema declaration does not match the deserialization")
             };
-            list_optional::<i64>(array.as_any().downcast_ref().unwrap(), schema)
+            match array.data_type().to_physical_type() {
+                PhysicalType::List => {
+                    list_serializer::<i32>(array.as_any().downcast_ref().unwrap(), schema, true)
+                }
+                PhysicalType::LargeList => {
+                    list_serializer::<i64>(array.as_any().downcast_ref().unwrap(), schema, true)
+                }
+                _ => {
+                    fixed_size_list_serializer(array.as_any().downcast_ref().unwrap(), schema, true)
+                }
+            }
         }
         (PhysicalType::Struct, AvroSchema::Record(inner)) => {
             struct_required(array.as_any().downcast_ref().unwrap(), inner)
@@ -436,6 +478,12 @@ pub fn new_serializer<'a>(array: &'a dyn Array, schema: &AvroSchema) -> BoxSeria
             };
             struct_optional(array.as_any().downcast_ref().unwrap(), inner)
         }
+        (PhysicalType::Union, AvroSchema::Union(inner)) => {
+            union(array.as_any().downcast_ref().unwrap(), inner)
+        }
+        (PhysicalType::Null, AvroSchema::Null) => {
+            Box::new(BufStreamingIterator::new(0..array.len(), |_, _| {}, vec![]))
+        }
         (a, b) => todo!("{:?} -> {:?} not supported", a, b),
     }
 }
@@ -446,6 +494,7 @@ pub fn can_serialize(data_type: &DataType) -> bool {
     match data_type.to_logical_type() {
         List(inner) => return can_serialize(&inner.data_type),
         LargeList(inner) => return can_serialize(&inner.data_type),
+        FixedSizeList(inner, _) => return can_serialize(&inner.data_type),
         _ => {}
     };
 
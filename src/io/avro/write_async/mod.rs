@@ -5,10 +5,11 @@ pub use block::write_block;
 use avro_schema::{Field as AvroField, Record, Schema as AvroSchema};
 use futures::{AsyncWrite, AsyncWriteExt};
 
+use crate::datatypes::{Field, Schema};
 use crate::error::Result;
 
 use super::{
-    write::{write_schema, AVRO_MAGIC, SYNC_NUMBER},
+    write::{to_avro_record, write_schema, AVRO_MAGIC, SYNC_NUMBER},
     Compression,
 };
 
@@ -18,16 +19,43 @@ pub async fn write_metadata<W>(
     fields: Vec<AvroField>,
     compression: Option<Compression>,
 ) -> Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let schema = AvroSchema::Record(Record::new("", fields));
+    write_avro_metadata(writer, &schema, &[], compression).await
+}
+
+/// Writes Avro's metadata to `writer`, declaring the record of `schema` as
+/// [`write_schema_metadata`](super::write::write_schema_metadata) does.
+/// # Error
+/// Errors iff the `schema` can't be converted to Avro or writing fails.
+pub async fn write_schema_metadata<W>(
+    writer: &mut W,
+    schema: &Schema,
+    compression: Option<Compression>,
+) -> Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let record = AvroSchema::Record(to_avro_record(schema)?);
+    write_avro_metadata(writer, &record, &schema.fields, compression).await
+}
+
+async fn write_avro_metadata<W>(
+    writer: &mut W,
+    schema: &AvroSchema,
+    fields: &[Field],
+    compression: Option<Compression>,
+) -> Result<()>
 where
     W: AsyncWrite + Unpin,
 {
     writer.write_all(&AVRO_MAGIC).await?;
 
     // * file metadata, including the schema.
-    let schema = AvroSchema::Record(Record::new("", fields));
-
     let mut scratch = vec![];
-    write_schema(&mut scratch, &schema, compression)?;
+    write_schema(&mut scratch, schema, fields, compression)?;
 
     writer.write_all(&scratch).await?;
 
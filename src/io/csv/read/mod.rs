@@ -7,7 +7,10 @@ pub use csv::{ByteRecord, Reader, ReaderBuilder};
 
 mod infer_schema;
 
-pub use super::utils::infer;
-pub use deserialize::{deserialize_batch, deserialize_column};
+pub use super::read_utils::DeserializeOptions;
+pub use super::utils::{infer, infer_with_booleans, BooleanTokens};
+pub use deserialize::{
+    deserialize_batch, deserialize_column, deserialize_column_strict, deserialize_column_with,
+};
 pub use infer_schema::infer_schema;
 pub use reader::*;
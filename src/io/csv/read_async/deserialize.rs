@@ -11,7 +11,7 @@ use crate::{
 
 use super::super::read_utils::{
     deserialize_batch as deserialize_batch_gen, deserialize_column as deserialize_column_gen,
-    ByteRecordGeneric,
+    ByteRecordGeneric, DeserializeOptions,
 };
 
 impl ByteRecordGeneric for ByteRecord {
@@ -22,13 +22,49 @@ impl ByteRecordGeneric for ByteRecord {
 }
 
 /// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`.
+/// Values that can't be deserialized into `datatype` are null.
 pub fn deserialize_column(
     rows: &[ByteRecord],
     column: usize,
     datatype: DataType,
     line_number: usize,
 ) -> Result<Arc<dyn Array>> {
-    deserialize_column_gen(rows, column, datatype, line_number)
+    deserialize_column_gen(rows, column, datatype, line_number, &Default::default())
+}
+
+/// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`.
+/// Contrarily to [`deserialize_column`], values that can't be deserialized into `datatype` are
+/// an error. Empty values are null.
+/// # Errors
+/// Errors iff a value can't be deserialized or `datatype` is not supported. The error reports
+/// the line of the value, where `line_number` is the line of the first row.
+pub fn deserialize_column_strict(
+    rows: &[ByteRecord],
+    column: usize,
+    datatype: DataType,
+    line_number: usize,
+) -> Result<Arc<dyn Array>> {
+    let options = DeserializeOptions {
+        strict: true,
+        ..Default::default()
+    };
+    deserialize_column_gen(rows, column, datatype, line_number, &options)
+}
+
+/// Deserializes `column` of `rows` into an [`Array`] according to [`DeserializeOptions`].
+/// # Errors
+/// Errors iff `datatype` is not supported, a value can't be deserialized and
+/// [`DeserializeOptions::strict`] is set, or the values of a [`DataType::Utf8`] or
+/// [`DataType::Binary`] column exceed its maximum offset and
+/// [`DeserializeOptions::promote_large`] is not set.
+pub fn deserialize_column_with(
+    rows: &[ByteRecord],
+    column: usize,
+    datatype: DataType,
+    line_number: usize,
+    options: &DeserializeOptions,
+) -> Result<Arc<dyn Array>> {
+    deserialize_column_gen(rows, column, datatype, line_number, options)
 }
 
 /// Deserializes rows [`ByteRecord`] into [`Chunk`].
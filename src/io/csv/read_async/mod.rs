@@ -7,8 +7,11 @@ mod deserialize;
 mod infer_schema;
 mod reader;
 
-pub use super::utils::infer;
-pub use deserialize::{deserialize_batch, deserialize_column};
+pub use super::read_utils::DeserializeOptions;
+pub use super::utils::{infer, infer_with_booleans, BooleanTokens};
+pub use deserialize::{
+    deserialize_batch, deserialize_column, deserialize_column_strict, deserialize_column_with,
+};
 pub use infer_schema::infer_schema;
 pub use reader::*;
 
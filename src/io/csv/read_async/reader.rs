@@ -1,8 +1,15 @@
+use std::sync::Arc;
+
 use futures::AsyncRead;
 
-use super::{AsyncReader, ByteRecord};
+use super::{deserialize_batch, deserialize_column, AsyncReader, ByteRecord};
 
-use crate::error::{ArrowError, Result};
+use crate::{
+    array::Array,
+    chunk::Chunk,
+    datatypes::Field,
+    error::{ArrowError, Result},
+};
 
 /// Asynchronosly read `len` rows from `reader` into `row`, skiping the first `skip`.
 /// This operation has minimal CPU work and is thus the fastest way to read through a CSV
@@ -36,3 +43,81 @@ where
     }
     Ok(row_number)
 }
+
+/// An asynchronous reader of [`Chunk`]s of up to `chunk_size` rows from a CSV.
+///
+/// The [`ByteRecord`]s are allocated once and reused for every chunk, and only the
+/// columns in `projection` are deserialized.
+pub struct ChunkReader<R: AsyncRead + Unpin + Send + Sync> {
+    reader: AsyncReader<R>,
+    fields: Vec<Field>,
+    projection: Option<Vec<usize>>,
+    rows: Vec<ByteRecord>,
+    line_number: usize,
+}
+
+impl<R: AsyncRead + Unpin + Send + Sync> ChunkReader<R> {
+    /// Returns a new [`ChunkReader`] deserializing the rows of `reader` according to `fields`
+    /// (e.g. inferred via [`infer_schema`](super::infer_schema)).
+    /// # Errors
+    /// This function errors iff `chunk_size` is zero or `projection` has
+    /// indices out of bounds of `fields`.
+    pub fn try_new(
+        reader: AsyncReader<R>,
+        fields: Vec<Field>,
+        projection: Option<Vec<usize>>,
+        chunk_size: usize,
+    ) -> Result<Self> {
+        if chunk_size == 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "The chunk size must be larger than zero".to_string(),
+            ));
+        }
+        if let Some(projection) = &projection {
+            if let Some(index) = projection.iter().find(|index| **index >= fields.len()) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The projection index {} is out of bounds of the {} fields",
+                    index,
+                    fields.len()
+                )));
+            }
+        }
+        Ok(Self {
+            reader,
+            fields,
+            projection,
+            rows: vec![ByteRecord::default(); chunk_size],
+            line_number: 0,
+        })
+    }
+
+    /// Returns the fields of the chunks returned by this reader, after projection.
+    pub fn fields(&self) -> Vec<&Field> {
+        match &self.projection {
+            Some(projection) => projection.iter().map(|i| &self.fields[*i]).collect(),
+            None => self.fields.iter().collect(),
+        }
+    }
+
+    /// Reads and deserializes the next chunk, returning `None` when the reader is exhausted.
+    pub async fn next_chunk(&mut self) -> Result<Option<Chunk<Arc<dyn Array>>>> {
+        let rows_read = read_rows(&mut self.reader, 0, &mut self.rows).await?;
+        if rows_read == 0 {
+            return Ok(None);
+        }
+        let chunk = deserialize_batch(
+            &self.rows[..rows_read],
+            &self.fields,
+            self.projection.as_deref(),
+            self.line_number,
+            deserialize_column,
+        )?;
+        self.line_number += rows_read;
+        Ok(Some(chunk))
+    }
+
+    /// Returns the underlying [`AsyncReader`].
+    pub fn into_inner(self) -> AsyncReader<R> {
+        self.reader
+    }
+}
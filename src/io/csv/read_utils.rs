@@ -15,108 +15,125 @@ use crate::{
     datatypes::*,
     error::{ArrowError, Result},
     temporal_conversions,
-    types::NativeType,
+    trusted_len::TrustedLen,
+    types::{
+        decimal::{self, RoundingMode},
+        NativeType,
+    },
 };
 
-use super::utils::RFC3339;
+use super::utils::{BooleanTokens, RFC3339};
 
 #[inline]
 fn to_utf8(bytes: &[u8]) -> Option<&str> {
     simdutf8::basic::from_utf8(bytes).ok()
 }
 
+/// Options of how CSV values are deserialized into arrays.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeserializeOptions {
+    /// Whether values that can't be deserialized into the column's [`DataType`] are an error
+    /// instead of null.
+    pub strict: bool,
+    /// Whether [`DataType::Utf8`] and [`DataType::Binary`] columns whose values exceed the
+    /// maximum `i32` offset are deserialized into [`DataType::LargeUtf8`] and
+    /// [`DataType::LargeBinary`] instead of erroring.
+    pub promote_large: bool,
+    /// The tokens deserialized into [`DataType::Boolean`] columns.
+    pub boolean_tokens: BooleanTokens,
+}
+
+/// Returns the error of a non-empty value of row `line_number` that can't be deserialized.
+fn deserialize_error(bytes: &[u8], datatype: &DataType, line_number: usize) -> ArrowError {
+    ArrowError::ExternalFormat(format!(
+        "Could not deserialize \"{}\" in line {} as {:?}",
+        String::from_utf8_lossy(bytes),
+        line_number,
+        datatype
+    ))
+}
+
+/// Deserializes `column` of `rows` with `op`. Empty and missing values are null; values that
+/// `op` fails to deserialize are null or, when `strict`, an error.
+#[inline]
+fn deserialize_values<'a, T, B: ByteRecordGeneric, F>(
+    rows: &'a [B],
+    column: usize,
+    datatype: &'a DataType,
+    line_number: usize,
+    strict: bool,
+    op: F,
+) -> impl TrustedLen<Item = Result<Option<T>>> + 'a
+where
+    F: Fn(&'a [u8]) -> Option<T> + 'a,
+{
+    rows.iter()
+        .enumerate()
+        .map(move |(index, row)| match row.get(column) {
+            Some(bytes) if !bytes.is_empty() => match op(bytes) {
+                Some(value) => Ok(Some(value)),
+                None if strict => Err(deserialize_error(bytes, datatype, line_number + index)),
+                None => Ok(None),
+            },
+            _ => Ok(None),
+        })
+}
+
 #[inline]
 fn deserialize_primitive<T, B: ByteRecordGeneric, F>(
     rows: &[B],
     column: usize,
     datatype: DataType,
+    line_number: usize,
+    strict: bool,
     op: F,
-) -> Arc<dyn Array>
+) -> Result<Arc<dyn Array>>
 where
-    T: NativeType + lexical_core::FromLexical,
+    T: NativeType,
     F: Fn(&[u8]) -> Option<T>,
 {
-    let iter = rows.iter().map(|row| match row.get(column) {
-        Some(bytes) => {
-            if bytes.is_empty() {
-                return None;
-            }
-            op(bytes)
-        }
-        None => None,
-    });
-    Arc::new(PrimitiveArray::<T>::from_trusted_len_iter(iter).to(datatype))
+    let iter = deserialize_values(rows, column, &datatype, line_number, strict, &op);
+    let array = PrimitiveArray::<T>::try_from_trusted_len_iter(iter)?;
+    Ok(Arc::new(array.to(datatype)))
 }
 
 #[inline]
-fn significant_bytes(bytes: &[u8]) -> usize {
-    bytes.iter().map(|byte| (*byte != b'0') as usize).sum()
-}
-
-/// Deserializes bytes to a single i128 representing a decimal
-/// The decimal precision and scale are not checked.
-#[inline]
-fn deserialize_decimal(bytes: &[u8], precision: usize, scale: usize) -> Option<i128> {
-    let mut a = bytes.split(|x| *x == b'.');
-    let lhs = a.next();
-    let rhs = a.next();
-    match (lhs, rhs) {
-        (Some(lhs), Some(rhs)) => lexical_core::parse::<i128>(lhs).ok().and_then(|x| {
-            lexical_core::parse::<i128>(rhs)
-                .ok()
-                .map(|y| (x, lhs, y, rhs))
-                .and_then(|(lhs, lhs_b, rhs, rhs_b)| {
-                    let lhs_s = significant_bytes(lhs_b);
-                    let rhs_s = significant_bytes(rhs_b);
-                    if lhs_s + rhs_s > precision || rhs_s > scale {
-                        None
-                    } else {
-                        Some((lhs, rhs, rhs_s))
-                    }
-                })
-                .map(|(lhs, rhs, rhs_s)| lhs * 10i128.pow(rhs_s as u32) + rhs)
-        }),
-        (None, Some(rhs)) => {
-            if rhs.len() != precision || rhs.len() != scale {
-                return None;
-            }
-            lexical_core::parse::<i128>(rhs).ok()
-        }
-        (Some(lhs), None) => {
-            if lhs.len() != precision || scale != 0 {
-                return None;
-            }
-            lexical_core::parse::<i128>(lhs).ok()
-        }
-        (None, None) => None,
-    }
-}
-
-#[inline]
-fn deserialize_boolean<B, F>(rows: &[B], column: usize, op: F) -> Arc<dyn Array>
+fn deserialize_boolean<B, F>(
+    rows: &[B],
+    column: usize,
+    line_number: usize,
+    strict: bool,
+    op: F,
+) -> Result<Arc<dyn Array>>
 where
     B: ByteRecordGeneric,
     F: Fn(&[u8]) -> Option<bool>,
 {
-    let iter = rows.iter().map(|row| match row.get(column) {
-        Some(bytes) => {
-            if bytes.is_empty() {
-                return None;
-            }
-            op(bytes)
-        }
-        None => None,
-    });
-    Arc::new(BooleanArray::from_trusted_len_iter(iter))
+    let iter = deserialize_values(rows, column, &DataType::Boolean, line_number, strict, &op);
+    Ok(Arc::new(BooleanArray::try_from_trusted_len_iter(iter)?))
 }
 
 #[inline]
-fn deserialize_utf8<O: Offset, B: ByteRecordGeneric>(rows: &[B], column: usize) -> Arc<dyn Array> {
-    let iter = rows.iter().map(|row| match row.get(column) {
-        Some(bytes) => to_utf8(bytes),
-        None => None,
-    });
-    Arc::new(Utf8Array::<O>::from_trusted_len_iter(iter))
+fn deserialize_utf8<O: Offset, B: ByteRecordGeneric>(
+    rows: &[B],
+    column: usize,
+    line_number: usize,
+    strict: bool,
+) -> Result<Arc<dyn Array>> {
+    let datatype = Utf8Array::<O>::default_data_type();
+    // empty strings are valid
+    let iter = rows
+        .iter()
+        .enumerate()
+        .map(|(index, row)| match row.get(column) {
+            Some(bytes) => match to_utf8(bytes) {
+                Some(value) => Ok(Some(value)),
+                None if strict => Err(deserialize_error(bytes, &datatype, line_number + index)),
+                None => Ok(None),
+            },
+            None => Ok(None),
+        });
+    Ok(Arc::new(Utf8Array::<O>::try_from_trusted_len_iter(iter)?))
 }
 
 #[inline]
@@ -144,93 +161,101 @@ fn deserialize_datetime<T: chrono::TimeZone>(string: &str, tz: &T) -> Option<i64
     }
 }
 
+/// Returns whether the values of `column` of `rows` exceed the maximum `i32` offset.
+fn exceeds_i32_offsets<B: ByteRecordGeneric>(rows: &[B], column: usize) -> bool {
+    let length: usize = rows
+        .iter()
+        .filter_map(|row| row.get(column))
+        .map(|bytes| bytes.len())
+        .sum();
+    length > i32::MAX as usize
+}
+
 /// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`.
 #[inline]
 pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
     rows: &[B],
     column: usize,
     datatype: DataType,
-    _line_number: usize,
+    line_number: usize,
+    options: &DeserializeOptions,
 ) -> Result<Arc<dyn Array>> {
     use DataType::*;
-    Ok(match datatype {
-        Boolean => deserialize_boolean(rows, column, |bytes| {
-            if bytes.eq_ignore_ascii_case(b"false") {
-                Some(false)
-            } else if bytes.eq_ignore_ascii_case(b"true") {
-                Some(true)
-            } else {
-                None
-            }
+    let strict = options.strict;
+    match datatype {
+        Boolean => deserialize_boolean(rows, column, line_number, strict, |bytes| {
+            options.boolean_tokens.parse(bytes)
         }),
-        Int8 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Int8 => deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
             lexical_core::parse::<i8>(bytes).ok()
         }),
-        Int16 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Int16 => deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
             lexical_core::parse::<i16>(bytes).ok()
         }),
-        Int32 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Int32 => deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
             lexical_core::parse::<i32>(bytes).ok()
         }),
-        Int64 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Int64 => deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
             lexical_core::parse::<i64>(bytes).ok()
         }),
-        UInt8 => deserialize_primitive(rows, column, datatype, |bytes| {
+        UInt8 => deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
             lexical_core::parse::<u8>(bytes).ok()
         }),
-        UInt16 => deserialize_primitive(rows, column, datatype, |bytes| {
+        UInt16 => deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
             lexical_core::parse::<u16>(bytes).ok()
         }),
-        UInt32 => deserialize_primitive(rows, column, datatype, |bytes| {
+        UInt32 => deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
             lexical_core::parse::<u32>(bytes).ok()
         }),
-        UInt64 => deserialize_primitive(rows, column, datatype, |bytes| {
+        UInt64 => deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
             lexical_core::parse::<u64>(bytes).ok()
         }),
-        Float32 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Float32 => deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
             lexical_core::parse::<f32>(bytes).ok()
         }),
-        Float64 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Float64 => deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
             lexical_core::parse::<f64>(bytes).ok()
         }),
-        Date32 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Date32 => deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
             to_utf8(bytes)
                 .and_then(|x| x.parse::<chrono::NaiveDate>().ok())
                 .map(|x| x.num_days_from_ce() - temporal_conversions::EPOCH_DAYS_FROM_CE)
         }),
-        Date64 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Date64 => deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
             to_utf8(bytes)
                 .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
                 .map(|x| x.timestamp_millis())
         }),
         Timestamp(TimeUnit::Nanosecond, None) => {
-            deserialize_primitive(rows, column, datatype, |bytes| {
+            deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
                 to_utf8(bytes)
                     .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
                     .map(|x| x.timestamp_nanos())
             })
         }
         Timestamp(TimeUnit::Microsecond, None) => {
-            deserialize_primitive(rows, column, datatype, |bytes| {
+            deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
                 to_utf8(bytes)
                     .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
                     .map(|x| x.timestamp_nanos() / 1000)
             })
         }
-        Timestamp(time_unit, None) => deserialize_primitive(rows, column, datatype, |bytes| {
-            to_utf8(bytes)
-                .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
-                .map(|x| x.timestamp_nanos())
-                .map(|x| match time_unit {
-                    TimeUnit::Second => x / 1_000_000_000,
-                    TimeUnit::Millisecond => x / 1_000_000,
-                    TimeUnit::Microsecond => x / 1_000,
-                    TimeUnit::Nanosecond => x,
-                })
-        }),
+        Timestamp(time_unit, None) => {
+            deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
+                to_utf8(bytes)
+                    .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
+                    .map(|x| x.timestamp_nanos())
+                    .map(|x| match time_unit {
+                        TimeUnit::Second => x / 1_000_000_000,
+                        TimeUnit::Millisecond => x / 1_000_000,
+                        TimeUnit::Microsecond => x / 1_000,
+                        TimeUnit::Nanosecond => x,
+                    })
+            })
+        }
         Timestamp(time_unit, Some(ref tz)) => {
             let tz = temporal_conversions::parse_offset(tz)?;
-            deserialize_primitive(rows, column, datatype, |bytes| {
+            deserialize_primitive(rows, column, datatype, line_number, strict, |bytes| {
                 to_utf8(bytes)
                     .and_then(|x| deserialize_datetime(x, &tz))
                     .map(|x| match time_unit {
@@ -241,20 +266,32 @@ pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
                     })
             })
         }
-        Decimal(precision, scale) => deserialize_primitive(rows, column, datatype, |x| {
-            deserialize_decimal(x, precision, scale)
-        }),
-        Utf8 => deserialize_utf8::<i32, _>(rows, column),
-        LargeUtf8 => deserialize_utf8::<i64, _>(rows, column),
-        Binary => deserialize_binary::<i32, _>(rows, column),
-        LargeBinary => deserialize_binary::<i64, _>(rows, column),
-        other => {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "Deserializing type \"{:?}\" is not implemented",
-                other
-            )))
+        Decimal(_, scale) => {
+            deserialize_primitive(rows, column, datatype, line_number, strict, |x| {
+                // as in previous versions, the precision is not checked: 38 is the
+                // maximum precision of an `i128`
+                decimal::parse_decimal(x, 38, scale, RoundingMode::Reject)
+            })
+        }
+        Utf8 | Binary if exceeds_i32_offsets(rows, column) => {
+            if !options.promote_large {
+                return Err(ArrowError::offset_overflow(&datatype));
+            }
+            if datatype == Utf8 {
+                deserialize_utf8::<i64, _>(rows, column, line_number, strict)
+            } else {
+                Ok(deserialize_binary::<i64, _>(rows, column))
+            }
         }
-    })
+        Utf8 => deserialize_utf8::<i32, _>(rows, column, line_number, strict),
+        LargeUtf8 => deserialize_utf8::<i64, _>(rows, column, line_number, strict),
+        Binary => Ok(deserialize_binary::<i32, _>(rows, column)),
+        LargeBinary => Ok(deserialize_binary::<i64, _>(rows, column)),
+        other => Err(ArrowError::NotYetImplemented(format!(
+            "Deserializing type \"{:?}\" is not implemented",
+            other
+        ))),
+    }
 }
 
 /// Deserializes rows [`ByteRecord`] into [`Chunk`].
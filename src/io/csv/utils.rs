@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 
 use crate::datatypes::{DataType, Field, TimeUnit};
+use crate::error::{ArrowError, Result};
 
 pub(super) const RFC3339: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
 
@@ -8,6 +9,84 @@ fn is_boolean(bytes: &[u8]) -> bool {
     bytes.eq_ignore_ascii_case(b"true") | bytes.eq_ignore_ascii_case(b"false")
 }
 
+/// Tokens that are inferred and deserialized as [`DataType::Boolean`], e.g. "yes" and "no".
+/// Tokens are compared case-insensitively. The default tokens are "true" and "false".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BooleanTokens {
+    truthy: Vec<String>,
+    falsy: Vec<String>,
+}
+
+impl Default for BooleanTokens {
+    fn default() -> Self {
+        Self {
+            truthy: vec!["true".to_string()],
+            falsy: vec!["false".to_string()],
+        }
+    }
+}
+
+impl BooleanTokens {
+    /// Creates new [`BooleanTokens`] whose `truthy` tokens are deserialized as `true` and
+    /// `falsy` tokens as `false`.
+    /// # Errors
+    /// Errors iff a token is empty or is both truthy and falsy.
+    pub fn try_new<T, F, S>(truthy: T, falsy: F) -> Result<Self>
+    where
+        T: IntoIterator<Item = S>,
+        F: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let truthy = truthy
+            .into_iter()
+            .map(|x| x.into())
+            .collect::<Vec<String>>();
+        let falsy = falsy.into_iter().map(|x| x.into()).collect::<Vec<String>>();
+        if truthy.iter().chain(falsy.iter()).any(|x| x.is_empty()) {
+            return Err(ArrowError::InvalidArgumentError(
+                "Boolean tokens can't be empty, since empty values are null".to_string(),
+            ));
+        }
+        if let Some(token) = truthy
+            .iter()
+            .find(|x| falsy.iter().any(|y| x.eq_ignore_ascii_case(y)))
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The boolean token \"{}\" can't be both truthy and falsy",
+                token
+            )));
+        }
+        Ok(Self { truthy, falsy })
+    }
+
+    /// The tokens deserialized as `true`
+    pub fn truthy(&self) -> &[String] {
+        &self.truthy
+    }
+
+    /// The tokens deserialized as `false`
+    pub fn falsy(&self) -> &[String] {
+        &self.falsy
+    }
+
+    /// Returns the boolean of `bytes`, or `None` if it is not one of the tokens.
+    #[inline]
+    pub fn parse(&self, bytes: &[u8]) -> Option<bool> {
+        let matches = |tokens: &[String]| {
+            tokens
+                .iter()
+                .any(|x| bytes.eq_ignore_ascii_case(x.as_bytes()))
+        };
+        if matches(&self.truthy) {
+            Some(true)
+        } else if matches(&self.falsy) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
 fn is_float(bytes: &[u8]) -> bool {
     lexical_core::parse::<f64>(bytes).is_ok()
 }
@@ -56,7 +135,36 @@ fn is_datetime(string: &str) -> Option<String> {
 pub fn infer(bytes: &[u8]) -> DataType {
     if is_boolean(bytes) {
         DataType::Boolean
-    } else if is_integer(bytes) {
+    } else {
+        infer_non_boolean(bytes)
+    }
+}
+
+/// Infers [`DataType`] from `bytes` like [`infer`], but with `tokens` instead of "true" and
+/// "false" mapped to [`DataType::Boolean`].
+///
+/// Tokens take precedence over the other types: with numeric tokens such as "1" and "0",
+/// a column whose values are all tokens is inferred as [`DataType::Boolean`], while a column
+/// that mixes them with other integers is inferred as [`DataType::Utf8`].
+/// # Example
+/// ```
+/// use arrow2::datatypes::DataType;
+/// use arrow2::io::csv::read::{infer_with_booleans, BooleanTokens};
+///
+/// let tokens = BooleanTokens::try_new(["yes", "y"], ["no", "n"]).unwrap();
+/// assert_eq!(infer_with_booleans(b"Yes", &tokens), DataType::Boolean);
+/// assert_eq!(infer_with_booleans(b"true", &tokens), DataType::Utf8);
+/// ```
+pub fn infer_with_booleans(bytes: &[u8], tokens: &BooleanTokens) -> DataType {
+    if tokens.parse(bytes).is_some() {
+        DataType::Boolean
+    } else {
+        infer_non_boolean(bytes)
+    }
+}
+
+fn infer_non_boolean(bytes: &[u8]) -> DataType {
+    if is_integer(bytes) {
         DataType::Int64
     } else if is_float(bytes) {
         DataType::Float64
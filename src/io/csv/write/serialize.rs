@@ -1,19 +1,17 @@
 use lexical_core::ToLexical;
 
-use crate::datatypes::IntegerType;
 use crate::temporal_conversions;
-use crate::types::NativeType;
+use crate::types::{decimal, NativeType};
 use crate::util::lexical_to_bytes_mut;
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array},
+    array::{Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, PrimitiveArray, Utf8Array},
     datatypes::{DataType, TimeUnit},
-    error::Result,
+    error::{ArrowError, Result},
 };
 
 use super::super::super::iterator::{BufStreamingIterator, StreamingIterator};
 use crate::array::{DictionaryArray, DictionaryKey, Offset};
 use csv_core::WriteResult;
-use std::any::Any;
 
 /// Options to serialize logical types to CSV
 /// The default is to format times and dates as `chrono` crate formats them.
@@ -265,8 +263,12 @@ fn new_utf8_serializer<'a, O: Offset>(
 /// Supported types:
 /// * boolean
 /// * numeric types (i.e. floats, int, uint)
+/// * decimals
 /// * times and dates
 /// * naive timestamps (timestamps without timezone information)
+/// * binary and utf8
+/// * fixed-size binary, as lowercase hexadecimal digits
+/// * dictionaries of any supported type, as their values
 /// # Error
 /// This function errors if any of the logical types in `batch` is not supported.
 pub fn new_serializer<'a>(
@@ -408,6 +410,22 @@ pub fn new_serializer<'a>(
         DataType::Float64 => {
             dyn_primitive!(f64, array)
         }
+        DataType::Decimal(_, scale) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap();
+            let scale = *scale;
+            Box::new(BufStreamingIterator::new(
+                array.iter(),
+                move |x, buf| {
+                    if let Some(x) = x {
+                        decimal::write_decimal(*x, scale, buf)
+                    }
+                },
+                vec![],
+            ))
+        }
         DataType::Utf8 => {
             let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
             new_utf8_serializer(array, options)
@@ -440,49 +458,62 @@ pub fn new_serializer<'a>(
                 vec![],
             ))
         }
-        DataType::Dictionary(keys_dt, values_dt, _) => match &**values_dt {
-            DataType::LargeUtf8 => match *keys_dt {
-                IntegerType::UInt32 => serialize_utf8_dict::<u32, i64>(array.as_any()),
-                IntegerType::UInt64 => serialize_utf8_dict::<u64, i64>(array.as_any()),
-                _ => todo!(),
-            },
-            DataType::Utf8 => match *keys_dt {
-                IntegerType::UInt32 => serialize_utf8_dict::<u32, i32>(array.as_any()),
-                IntegerType::UInt64 => serialize_utf8_dict::<u64, i32>(array.as_any()),
-                _ => todo!(),
-            },
-            _ => {
-                panic!("only dictionary with string values are supported by csv writer")
-            }
-        },
-        dt => panic!("data type: {:?} not supported by csv writer", dt),
+        DataType::FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            Box::new(BufStreamingIterator::new(
+                array.iter(),
+                |x, buf| {
+                    if let Some(x) = x {
+                        write_hex(x, buf);
+                    }
+                },
+                vec![],
+            ))
+        }
+        DataType::Dictionary(key_type, _, _) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            dictionary_serializer(array, options)?
+        }),
+        dt => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "data type: {:?} not supported by csv writer",
+                dt
+            )))
+        }
     })
 }
 
-/// Helper for serializing a dictonary array. The generic parameters are:
-/// - `K` for the type of the keys of the dictionary
-/// - `O` for the type of the offsets in the Utf8Array: {i32, i64}
-fn serialize_utf8_dict<'a, K: DictionaryKey, O: Offset>(
-    array: &'a dyn Any,
-) -> Box<dyn StreamingIterator<Item = [u8]> + 'a> {
-    let array = array.downcast_ref::<DictionaryArray<K>>().unwrap();
-    let keys = array.keys();
-    let values = array
-        .values()
-        .as_any()
-        .downcast_ref::<Utf8Array<O>>()
-        .unwrap();
-    Box::new(BufStreamingIterator::new(
-        keys.iter(),
+/// Writes `bytes` to `buf` as lowercase hexadecimal digits.
+fn write_hex(bytes: &[u8], buf: &mut Vec<u8>) {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    buf.reserve(bytes.len() * 2);
+    for byte in bytes {
+        buf.push(DIGITS[(byte >> 4) as usize]);
+        buf.push(DIGITS[(byte & 0xf) as usize]);
+    }
+}
+
+/// Returns a [`StreamingIterator`] over the serialized values of a [`DictionaryArray`].
+/// Each value of the dictionary is serialized once and copied for each key pointing to it.
+fn dictionary_serializer<'a, K: DictionaryKey>(
+    array: &'a DictionaryArray<K>,
+    options: &'a SerializeOptions,
+) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a>> {
+    let mut serializer = new_serializer(array.values().as_ref(), options)?;
+    let mut values = Vec::with_capacity(array.values().len());
+    while let Some(value) = serializer.next() {
+        values.push(value.to_vec());
+    }
+    Ok(Box::new(BufStreamingIterator::new(
+        array.keys().iter(),
         move |x, buf| {
             if let Some(x) = x {
-                let i = x.to_usize().unwrap();
-                if !values.is_null(i) {
-                    let val = values.value(i);
-                    buf.extend_from_slice(val.as_bytes());
-                }
+                buf.extend_from_slice(&values[x.to_usize().unwrap()]);
             }
         },
         vec![],
-    ))
+    )))
 }
@@ -0,0 +1,6 @@
+//! APIs to read the Feather v1 (legacy) format.
+//!
+//! Feather v1 predates the Arrow IPC file format and is still found in datasets
+//! written by old versions of R's `feather` package and of Python's `feather-format`.
+//! Feather v2 files are Arrow IPC files and are read with [`crate::io::ipc::read`].
+pub mod read;
@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use crate::array::{
+    Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array,
+};
+use crate::bitmap::{utils::bytes_for, Bitmap};
+use crate::buffer::Buffer;
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{ArrowError, Result};
+use crate::types::{NativeType, Offset};
+
+use super::ArrayMetadata;
+
+/// The alignment of the buffers of a Feather v1 array
+const ALIGNMENT: usize = 8;
+
+fn out_of_spec(what: &str) -> ArrowError {
+    ArrowError::OutOfSpec(format!(
+        "The {} of a Feather array is larger than its buffers",
+        what
+    ))
+}
+
+/// Splits `length` bytes (plus their padding, if present) from the front of `data`.
+fn take<'a>(data: &mut &'a [u8], length: usize, what: &str) -> Result<&'a [u8]> {
+    if data.len() < length {
+        return Err(out_of_spec(what));
+    }
+    let padded = (length + (ALIGNMENT - length % ALIGNMENT) % ALIGNMENT).min(data.len());
+    let (buffer, remaining) = data.split_at(padded);
+    *data = remaining;
+    Ok(&buffer[..length])
+}
+
+fn read_buffer<T: NativeType>(data: &[u8], length: usize, what: &str) -> Result<Buffer<T>> {
+    let bytes = length
+        .checked_mul(std::mem::size_of::<T>())
+        .filter(|bytes| *bytes <= data.len())
+        .ok_or_else(|| out_of_spec(what))?;
+    let mut buffer = vec![T::default(); length];
+    bytemuck::cast_slice_mut(&mut buffer).copy_from_slice(&data[..bytes]);
+    Ok(buffer.into())
+}
+
+fn read_bitmap(data: &[u8], length: usize, what: &str) -> Result<Bitmap> {
+    let bytes = bytes_for(length);
+    if data.len() < bytes {
+        return Err(out_of_spec(what));
+    }
+    Ok(Bitmap::from_u8_vec(data[..bytes].to_vec(), length))
+}
+
+/// Splits the validity (if any) from the front of `data`
+fn read_validity(data: &mut &[u8], array: &ArrayMetadata) -> Result<Option<Bitmap>> {
+    if array.null_count == 0 {
+        return Ok(None);
+    }
+    let validity = take(data, bytes_for(array.length), "validity")?;
+    read_bitmap(validity, array.length, "validity").map(Some)
+}
+
+fn read_primitive<T: NativeType>(
+    mut data: &[u8],
+    array: &ArrayMetadata,
+    data_type: DataType,
+) -> Result<PrimitiveArray<T>> {
+    let validity = read_validity(&mut data, array)?;
+    let values = read_buffer(data, array.length, "values")?;
+    PrimitiveArray::try_new(data_type, values, validity)
+}
+
+fn read_boolean(mut data: &[u8], array: &ArrayMetadata) -> Result<BooleanArray> {
+    let validity = read_validity(&mut data, array)?;
+    let values = read_bitmap(data, array.length, "values")?;
+    BooleanArray::try_new(DataType::Boolean, values, validity)
+}
+
+fn read_offsets<O: Offset>(data: &mut &[u8], array: &ArrayMetadata) -> Result<Buffer<O>> {
+    let length = array
+        .length
+        .checked_add(1)
+        .ok_or_else(|| out_of_spec("offsets"))?;
+    let bytes = length
+        .checked_mul(std::mem::size_of::<O>())
+        .ok_or_else(|| out_of_spec("offsets"))?;
+    let offsets = take(data, bytes, "offsets")?;
+    read_buffer(offsets, length, "offsets")
+}
+
+fn read_utf8<O: Offset>(
+    mut data: &[u8],
+    array: &ArrayMetadata,
+    data_type: DataType,
+) -> Result<Utf8Array<O>> {
+    let validity = read_validity(&mut data, array)?;
+    let offsets = read_offsets(&mut data, array)?;
+    Utf8Array::try_new(data_type, offsets, data.to_vec().into(), validity)
+}
+
+fn read_binary<O: Offset>(
+    mut data: &[u8],
+    array: &ArrayMetadata,
+    data_type: DataType,
+) -> Result<BinaryArray<O>> {
+    let validity = read_validity(&mut data, array)?;
+    let offsets = read_offsets(&mut data, array)?;
+    BinaryArray::try_new(data_type, offsets, data.to_vec().into(), validity)
+}
+
+fn read_dictionary<K: DictionaryKey>(
+    data: &[u8],
+    array: &ArrayMetadata,
+    levels: Option<(&[u8], &ArrayMetadata)>,
+    values_data_type: DataType,
+) -> Result<DictionaryArray<K>> {
+    let (levels, levels_array) = levels.ok_or_else(|| {
+        ArrowError::OutOfSpec("A Feather categorical column must have levels".to_string())
+    })?;
+    let keys = read_primitive::<K>(data, array, K::PRIMITIVE.into())?;
+    let values = deserialize(levels, levels_array, None, values_data_type)?;
+
+    if keys
+        .iter()
+        .flatten()
+        .any(|key| !matches!(key.to_usize(), Some(key) if key < values.len()))
+    {
+        return Err(ArrowError::OutOfSpec(
+            "The indices of a Feather categorical column must be smaller than its number of levels"
+                .to_string(),
+        ));
+    }
+    Ok(DictionaryArray::from_data(keys, values))
+}
+
+/// Deserializes the buffers `data` of the Feather array `array` into an [`Array`] of
+/// `data_type`. `levels` are the buffers and metadata of the dictionary values of
+/// categorical columns.
+pub fn deserialize(
+    data: &[u8],
+    array: &ArrayMetadata,
+    levels: Option<(&[u8], &ArrayMetadata)>,
+    data_type: DataType,
+) -> Result<Arc<dyn Array>> {
+    if cfg!(target_endian = "big") {
+        return Err(ArrowError::NotYetImplemented(
+            "Reading Feather v1 files on big endian platforms".to_string(),
+        ));
+    }
+    if array.encoding != 0 {
+        return Err(ArrowError::NotYetImplemented(
+            "Reading dictionary-encoded Feather v1 arrays".to_string(),
+        ));
+    }
+
+    use PhysicalType::*;
+    Ok(match data_type.to_physical_type() {
+        Boolean => Arc::new(read_boolean(data, array)?),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            Arc::new(read_primitive::<$T>(data, array, data_type)?)
+        }),
+        Utf8 => Arc::new(read_utf8::<i32>(data, array, data_type)?),
+        LargeUtf8 => Arc::new(read_utf8::<i64>(data, array, data_type)?),
+        Binary => Arc::new(read_binary::<i32>(data, array, data_type)?),
+        LargeBinary => Arc::new(read_binary::<i64>(data, array, data_type)?),
+        Dictionary(key_type) => {
+            let values_data_type = if let DataType::Dictionary(_, values, _) = &data_type {
+                values.as_ref().clone()
+            } else {
+                unreachable!()
+            };
+            match_integer_type!(key_type, |$T| {
+                Arc::new(read_dictionary::<$T>(data, array, levels, values_data_type)?)
+            })
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Reading Feather v1 arrays of physical type {:?}",
+                other
+            )))
+        }
+    })
+}
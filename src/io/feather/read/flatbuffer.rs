@@ -0,0 +1,116 @@
+//! A minimal, bounds-checked reader of flatbuffer tables, sufficient to read the
+//! (small and fixed) Feather v1 metadata schema without generated code.
+use crate::error::{ArrowError, Result};
+
+fn out_of_spec() -> ArrowError {
+    ArrowError::OutOfSpec("The Feather metadata contains an out-of-bounds offset".to_string())
+}
+
+fn read_bytes<const N: usize>(buf: &[u8], position: usize) -> Result<[u8; N]> {
+    buf.get(position..position.checked_add(N).ok_or_else(out_of_spec)?)
+        .ok_or_else(out_of_spec)
+        .map(|x| x.try_into().unwrap())
+}
+
+fn read_u32(buf: &[u8], position: usize) -> Result<usize> {
+    read_bytes::<4>(buf, position).map(|x| u32::from_le_bytes(x) as usize)
+}
+
+/// Returns the position pointed to by the unsigned offset stored at `position`
+fn indirect(buf: &[u8], position: usize) -> Result<usize> {
+    position
+        .checked_add(read_u32(buf, position)?)
+        .ok_or_else(out_of_spec)
+}
+
+/// A flatbuffer table
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Table<'a> {
+    buf: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Table<'a> {
+    /// Returns the root table of `buf`
+    pub fn root(buf: &'a [u8]) -> Result<Self> {
+        Ok(Self {
+            buf,
+            position: indirect(buf, 0)?,
+        })
+    }
+
+    /// Returns the position of the field `index` of this table, if present
+    fn field(&self, index: usize) -> Result<Option<usize>> {
+        let offset = i32::from_le_bytes(read_bytes::<4>(self.buf, self.position)?) as i64;
+        let vtable = usize::try_from(self.position as i64 - offset).map_err(|_| out_of_spec())?;
+        let vtable_length = u16::from_le_bytes(read_bytes::<2>(self.buf, vtable)?) as usize;
+        let entry = 4 + 2 * index;
+        if entry + 2 > vtable_length {
+            return Ok(None);
+        }
+        let field = u16::from_le_bytes(read_bytes::<2>(self.buf, vtable + entry)?) as usize;
+        Ok(if field == 0 {
+            None
+        } else {
+            Some(self.position + field)
+        })
+    }
+
+    pub fn i64(&self, index: usize, default: i64) -> Result<i64> {
+        self.field(index)?
+            .map(|position| read_bytes::<8>(self.buf, position).map(i64::from_le_bytes))
+            .unwrap_or(Ok(default))
+    }
+
+    pub fn u8(&self, index: usize, default: u8) -> Result<u8> {
+        self.field(index)?
+            .map(|position| read_bytes::<1>(self.buf, position).map(|x| x[0]))
+            .unwrap_or(Ok(default))
+    }
+
+    pub fn str(&self, index: usize) -> Result<Option<&'a str>> {
+        self.field(index)?
+            .map(|position| {
+                let start = indirect(self.buf, position)?;
+                let length = read_u32(self.buf, start)?;
+                let bytes = self
+                    .buf
+                    .get(start + 4..start + 4 + length)
+                    .ok_or_else(out_of_spec)?;
+                std::str::from_utf8(bytes).map_err(|_| {
+                    ArrowError::OutOfSpec(
+                        "The Feather metadata contains an invalid utf8 string".to_string(),
+                    )
+                })
+            })
+            .transpose()
+    }
+
+    pub fn table(&self, index: usize) -> Result<Option<Table<'a>>> {
+        self.field(index)?
+            .map(|position| {
+                Ok(Table {
+                    buf: self.buf,
+                    position: indirect(self.buf, position)?,
+                })
+            })
+            .transpose()
+    }
+
+    pub fn tables(&self, index: usize) -> Result<Vec<Table<'a>>> {
+        let position = if let Some(position) = self.field(index)? {
+            indirect(self.buf, position)?
+        } else {
+            return Ok(vec![]);
+        };
+        let length = read_u32(self.buf, position)?;
+        (0..length)
+            .map(|i| {
+                Ok(Table {
+                    buf: self.buf,
+                    position: indirect(self.buf, position + 4 + 4 * i)?,
+                })
+            })
+            .collect()
+    }
+}
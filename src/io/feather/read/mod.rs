@@ -0,0 +1,298 @@
+//! APIs to read Feather v1 files.
+//!
+//! A Feather v1 file is composed by the magic `FEA1`, the columns' buffers, a
+//! flatbuffer-encoded metadata, its length (`u32`) and the magic `FEA1`.
+use std::collections::HashSet;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, IntegerType, Schema, TimeUnit};
+use crate::error::{ArrowError, Result};
+
+mod deserialize;
+mod flatbuffer;
+
+use flatbuffer::Table;
+
+const MAGIC: [u8; 4] = *b"FEA1";
+const IPC_MAGIC: [u8; 6] = *b"ARROW1";
+
+/// The metadata of an array of a Feather v1 file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ArrayMetadata {
+    /// The feather type of the array
+    pub type_: u8,
+    /// Whether the array is dictionary-encoded
+    pub encoding: u8,
+    /// The position of the array's buffers in the file
+    pub offset: u64,
+    pub length: usize,
+    pub null_count: usize,
+    /// The total length of the array's buffers
+    pub total_bytes: usize,
+}
+
+/// The metadata of a column of a Feather v1 file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ColumnMetadata {
+    pub values: ArrayMetadata,
+    /// The values of the dictionary, for categorical columns
+    pub levels: Option<ArrayMetadata>,
+}
+
+/// The metadata of a Feather v1 file, read via [`read_metadata`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileMetadata {
+    /// The schema of the file
+    pub schema: Schema,
+    /// The number of rows of the file
+    pub num_rows: usize,
+    /// The (optional) description of the file
+    pub description: Option<String>,
+    pub(super) columns: Vec<ColumnMetadata>,
+}
+
+fn to_usize(value: i64) -> Result<usize> {
+    value.try_into().map_err(|_| {
+        ArrowError::OutOfSpec("The Feather metadata contains a negative length".to_string())
+    })
+}
+
+fn deserialize_array(table: &Table) -> Result<ArrayMetadata> {
+    Ok(ArrayMetadata {
+        type_: table.u8(0, 0)?,
+        encoding: table.u8(1, 0)?,
+        offset: to_usize(table.i64(2, 0)?)? as u64,
+        length: to_usize(table.i64(3, 0)?)?,
+        null_count: to_usize(table.i64(4, 0)?)?,
+        total_bytes: to_usize(table.i64(5, 0)?)?,
+    })
+}
+
+fn deserialize_time_unit(unit: u8) -> Result<TimeUnit> {
+    Ok(match unit {
+        0 => TimeUnit::Second,
+        1 => TimeUnit::Millisecond,
+        2 => TimeUnit::Microsecond,
+        3 => TimeUnit::Nanosecond,
+        other => {
+            return Err(ArrowError::OutOfSpec(format!(
+                "The Feather metadata contains an unknown time unit ({})",
+                other
+            )))
+        }
+    })
+}
+
+/// Returns the [`DataType`] of a non-categorical feather type
+fn deserialize_type(type_: u8) -> Result<DataType> {
+    Ok(match type_ {
+        0 => DataType::Boolean,
+        1 => DataType::Int8,
+        2 => DataType::Int16,
+        3 => DataType::Int32,
+        4 => DataType::Int64,
+        5 => DataType::UInt8,
+        6 => DataType::UInt16,
+        7 => DataType::UInt32,
+        8 => DataType::UInt64,
+        9 => DataType::Float32,
+        10 => DataType::Float64,
+        11 => DataType::Utf8,
+        12 => DataType::Binary,
+        17 => DataType::LargeUtf8,
+        18 => DataType::LargeBinary,
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Reading Feather v1 arrays of type {}",
+                other
+            )))
+        }
+    })
+}
+
+fn deserialize_key_type(data_type: &DataType) -> Result<IntegerType> {
+    Ok(match data_type {
+        DataType::Int8 => IntegerType::Int8,
+        DataType::Int16 => IntegerType::Int16,
+        DataType::Int32 => IntegerType::Int32,
+        DataType::Int64 => IntegerType::Int64,
+        DataType::UInt8 => IntegerType::UInt8,
+        DataType::UInt16 => IntegerType::UInt16,
+        DataType::UInt32 => IntegerType::UInt32,
+        DataType::UInt64 => IntegerType::UInt64,
+        other => {
+            return Err(ArrowError::OutOfSpec(format!(
+                "The indices of a Feather categorical column must be integers, but are {:?}",
+                other
+            )))
+        }
+    })
+}
+
+fn deserialize_column(table: &Table) -> Result<(Field, ColumnMetadata)> {
+    let name = table.str(0)?.unwrap_or_default();
+    let values = table.table(1)?.ok_or_else(|| {
+        ArrowError::OutOfSpec(format!("The Feather column \"{}\" has no values", name))
+    })?;
+    let values = deserialize_array(&values)?;
+    let metadata = table.table(3)?;
+
+    let mut levels = None;
+    let data_type = match (table.u8(2, 0)?, metadata) {
+        // categorical
+        (1, Some(metadata)) => {
+            let key_type = deserialize_key_type(&deserialize_type(values.type_)?)?;
+            let levels_table = metadata.table(0)?.ok_or_else(|| {
+                ArrowError::OutOfSpec(format!(
+                    "The Feather categorical column \"{}\" has no levels",
+                    name
+                ))
+            })?;
+            let array = deserialize_array(&levels_table)?;
+            let data_type = deserialize_type(array.type_)?;
+            levels = Some(array);
+            DataType::Dictionary(key_type, Box::new(data_type), false)
+        }
+        // timestamp
+        (2, Some(metadata)) => DataType::Timestamp(
+            deserialize_time_unit(metadata.u8(0, 0)?)?,
+            metadata.str(1)?.map(|x| x.to_string()),
+        ),
+        // date
+        (3, _) => DataType::Date32,
+        // time
+        (4, Some(metadata)) => match deserialize_time_unit(metadata.u8(0, 0)?)? {
+            unit @ (TimeUnit::Second | TimeUnit::Millisecond) => DataType::Time32(unit),
+            unit => DataType::Time64(unit),
+        },
+        _ => deserialize_type(values.type_)?,
+    };
+    Ok((
+        Field::new(name, data_type, true),
+        ColumnMetadata { values, levels },
+    ))
+}
+
+fn deserialize_metadata(data: &[u8]) -> Result<FileMetadata> {
+    let table = Table::root(data)?;
+    let description = table.str(0)?.map(|x| x.to_string());
+    let num_rows = to_usize(table.i64(1, 0)?)?;
+
+    let (fields, columns): (Vec<_>, _) = table
+        .tables(2)?
+        .iter()
+        .map(deserialize_column)
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    Ok(FileMetadata {
+        schema: Schema::from(fields),
+        num_rows,
+        description,
+        columns,
+    })
+}
+
+/// Reads the metadata of a Feather v1 file.
+/// # Errors
+/// This function errors if the file is not a Feather v1 file. In particular, Feather v2
+/// files are Arrow IPC files and must be read via [`crate::io::ipc::read::read_file_metadata`].
+pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata> {
+    let mut start = [0; 6];
+    reader.seek(SeekFrom::Start(0))?;
+    reader.read_exact(&mut start)?;
+    if start == IPC_MAGIC {
+        return Err(ArrowError::ExternalFormat(
+            "This file is a Feather v2 file, i.e. an Arrow IPC file; \
+            read it with `io::ipc::read::read_file_metadata` and `io::ipc::read::FileReader`"
+                .to_string(),
+        ));
+    }
+    if start[..4] != MAGIC {
+        return Err(ArrowError::OutOfSpec(
+            "Feather v1 files must start with the magic \"FEA1\"".to_string(),
+        ));
+    }
+
+    let end = reader.seek(SeekFrom::End(-8))?;
+    let mut footer = [0; 8];
+    reader.read_exact(&mut footer)?;
+    if footer[4..] != MAGIC {
+        return Err(ArrowError::OutOfSpec(
+            "Feather v1 files must end with the magic \"FEA1\"".to_string(),
+        ));
+    }
+    let length = u32::from_le_bytes(footer[..4].try_into().unwrap()) as u64;
+    let start = end.checked_sub(length).filter(|x| *x >= 4).ok_or_else(|| {
+        ArrowError::OutOfSpec("The Feather metadata length is larger than the file".to_string())
+    })?;
+
+    let mut data = vec![0; length as usize];
+    reader.seek(SeekFrom::Start(start))?;
+    reader.read_exact(&mut data)?;
+    deserialize_metadata(&data)
+}
+
+fn read_buffer<R: Read + Seek>(reader: &mut R, array: &ArrayMetadata) -> Result<Vec<u8>> {
+    let file_length = reader.seek(SeekFrom::End(0))?;
+    let end = array.offset.checked_add(array.total_bytes as u64);
+    if !matches!(end, Some(end) if end <= file_length) {
+        return Err(ArrowError::OutOfSpec(
+            "A Feather array is located beyond the end of the file".to_string(),
+        ));
+    }
+    let mut data = vec![0; array.total_bytes];
+    reader.seek(SeekFrom::Start(array.offset))?;
+    reader.read_exact(&mut data)?;
+    Ok(data)
+}
+
+/// Reads the columns of a Feather v1 file into a [`Chunk`].
+///
+/// `projection` selects the indices of the columns to read; all columns are read when
+/// `None`. The columns are returned in the order of the file, regardless of the order
+/// of `projection`.
+/// # Errors
+/// This function errors if the file's buffers are out of spec or if it contains an
+/// unsupported type.
+pub fn read<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetadata,
+    projection: Option<&[usize]>,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let projection = projection.map(|projection| projection.iter().collect::<HashSet<_>>());
+    let columns = metadata
+        .schema
+        .fields
+        .iter()
+        .zip(metadata.columns.iter())
+        .enumerate()
+        .filter(|(index, _)| {
+            projection
+                .as_ref()
+                .map(|projection| projection.contains(index))
+                .unwrap_or(true)
+        })
+        .map(|(_, (field, column))| {
+            let values = read_buffer(reader, &column.values)?;
+            let levels = column
+                .levels
+                .as_ref()
+                .map(|levels| read_buffer(reader, levels).map(|data| (data, levels)))
+                .transpose()?;
+            deserialize::deserialize(
+                &values,
+                &column.values,
+                levels
+                    .as_ref()
+                    .map(|(data, levels)| (data.as_ref(), *levels)),
+                field.data_type().clone(),
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(columns)
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use arrow_format::flight::data::FlightData;
+use arrow_format::ipc;
+use arrow_format::ipc::planus::ReadAsRoot;
+
+use crate::{
+    array::Array,
+    chunk::Chunk,
+    datatypes::Schema,
+    error::{ArrowError, Result},
+    io::ipc::read::{self, Dictionaries},
+    io::ipc::IpcSchema,
+};
+
+use super::deserialize_message;
+
+/// A stateful decoder of a stream of [`FlightData`] (e.g. the responses of a `DoGet`),
+/// with the same semantics as the IPC stream reader:
+/// * the schema is the first message, unless it is passed to [`FlightDecoder::with_schema`]
+/// * dictionary batches are stored and used by all subsequent record batches; a dictionary
+///   batch with the id of a previous one replaces it, or is appended to it when it is a delta
+/// * record batches are decoded into [`Chunk`]s
+#[derive(Debug, Default)]
+pub struct FlightDecoder {
+    schema: Option<(Schema, IpcSchema)>,
+    dictionaries: Dictionaries,
+}
+
+impl FlightDecoder {
+    /// Creates a new [`FlightDecoder`] whose first message must be the schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`FlightDecoder`] of a stream with a known schema (e.g. from
+    /// [`deserialize_schemas`](super::deserialize_schemas)), whose messages are only
+    /// dictionaries and record batches.
+    pub fn with_schema(schema: Schema, ipc_schema: IpcSchema) -> Self {
+        Self {
+            schema: Some((schema, ipc_schema)),
+            dictionaries: Default::default(),
+        }
+    }
+
+    /// Returns the [`Schema`] of the stream, if it was already decoded.
+    pub fn schema(&self) -> Option<&Schema> {
+        self.schema.as_ref().map(|(schema, _)| schema)
+    }
+
+    /// Returns the [`IpcSchema`] of the stream, if it was already decoded.
+    pub fn ipc_schema(&self) -> Option<&IpcSchema> {
+        self.schema.as_ref().map(|(_, ipc_schema)| ipc_schema)
+    }
+
+    /// Returns the dictionaries decoded so far.
+    pub fn dictionaries(&self) -> &Dictionaries {
+        &self.dictionaries
+    }
+
+    /// Returns a mutable reference to the dictionaries, e.g. to limit their memory via
+    /// [`Dictionaries::set_memory_limit`].
+    pub fn dictionaries_mut(&mut self) -> &mut Dictionaries {
+        &mut self.dictionaries
+    }
+
+    /// Decodes a message of the stream. Returns the [`Chunk`] of record batches and `None` for
+    /// the schema and dictionary batches, whose state is kept by the decoder.
+    /// # Errors
+    /// Errors iff the message is invalid, is not a schema, dictionary or record batch, is a
+    /// schema after the start of the stream, or is a dictionary or record batch before the
+    /// schema.
+    pub fn decode(&mut self, data: &FlightData) -> Result<Option<Chunk<Arc<dyn Array>>>> {
+        let message = ipc::MessageRef::read_as_root(&data.data_header).map_err(|err| {
+            ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
+        })?;
+        let header = message
+            .header()?
+            .ok_or_else(|| ArrowError::oos("Unable to convert flight data header to a message"))?;
+
+        if let ipc::MessageHeaderRef::Schema(_) = header {
+            if self.schema.is_some() {
+                return Err(ArrowError::oos(
+                    "A flight stream can only contain a schema in its first message",
+                ));
+            }
+            self.schema = Some(read::deserialize_schema(&data.data_header)?);
+            return Ok(None);
+        }
+
+        let (schema, ipc_schema) = self.schema.as_ref().ok_or_else(|| {
+            ArrowError::oos("The first message of a flight stream must be its schema")
+        })?;
+        deserialize_message(data, &schema.fields, ipc_schema, &mut self.dictionaries)
+    }
+}
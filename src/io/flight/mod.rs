@@ -6,7 +6,7 @@ use arrow_format::ipc;
 use arrow_format::ipc::planus::ReadAsRoot;
 
 use crate::{
-    array::Array,
+    array::{new_empty_array, Array},
     chunk::Chunk,
     datatypes::*,
     error::{ArrowError, Result},
@@ -18,6 +18,9 @@ use crate::{
 use super::ipc::write::default_ipc_fields;
 use super::ipc::{IpcField, IpcSchema};
 
+mod decoder;
+pub use decoder::FlightDecoder;
+
 /// Serializes [`Chunk`] to a vector of [`FlightData`] representing the serialized dictionaries
 /// and a [`FlightData`] representing the batch.
 pub fn serialize_batch(
@@ -37,6 +40,92 @@ pub fn serialize_batch(
     (flight_dictionaries, flight_batch)
 }
 
+/// Serializes [`Chunk`] to a vector of [`FlightData`] representing the serialized dictionaries
+/// and a vector of [`FlightData`] representing the batch, each of at most `max_message_size`
+/// bytes (e.g. gRPC's maximum message size).
+///
+/// The batch is split into multiple [`FlightData`] on row boundaries by slicing its arrays
+/// (without copying them). Use [`deserialize_batches`] to reassemble them into a single [`Chunk`].
+/// # Errors
+/// Errors iff a dictionary or a single row does not fit in `max_message_size` bytes.
+pub fn serialize_batch_with_limit(
+    columns: &Chunk<Arc<dyn Array>>,
+    fields: &[IpcField],
+    options: &WriteOptions,
+    max_message_size: usize,
+) -> Result<(Vec<FlightData>, Vec<FlightData>)> {
+    let mut dictionary_tracker = DictionaryTracker::new(false);
+    let mut dictionaries = vec![];
+    let mut batches = vec![];
+    serialize_limited(
+        columns,
+        fields,
+        options,
+        max_message_size,
+        &mut dictionary_tracker,
+        &mut dictionaries,
+        &mut batches,
+    )?;
+    Ok((dictionaries, batches))
+}
+
+fn message_size(data: &EncodedData) -> usize {
+    data.ipc_message.len() + data.arrow_data.len()
+}
+
+fn serialize_limited(
+    columns: &Chunk<Arc<dyn Array>>,
+    fields: &[IpcField],
+    options: &WriteOptions,
+    max_message_size: usize,
+    dictionary_tracker: &mut DictionaryTracker,
+    dictionaries: &mut Vec<FlightData>,
+    batches: &mut Vec<FlightData>,
+) -> Result<()> {
+    // dictionaries are only emitted the first time they are seen by the tracker
+    let (encoded_dictionaries, encoded_batch) =
+        encode_chunk(columns, fields, dictionary_tracker, options)?;
+    for dictionary in encoded_dictionaries {
+        let size = message_size(&dictionary);
+        if size > max_message_size {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A dictionary requires {} bytes, which exceeds the maximum message size of {} bytes",
+                size, max_message_size
+            )));
+        }
+        dictionaries.push(dictionary.into());
+    }
+
+    let size = message_size(&encoded_batch);
+    let len = columns.len();
+    if size <= max_message_size {
+        batches.push(encoded_batch.into());
+        return Ok(());
+    }
+    if len <= 1 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "A single row requires {} bytes, which exceeds the maximum message size of {} bytes",
+            size, max_message_size
+        )));
+    }
+
+    // estimate the number of rows that fit in a message; slices that still
+    // exceed the limit are further split
+    let rows = (len * max_message_size / size).clamp(1, len / 2);
+    columns.split(rows).into_iter().try_for_each(|slice| {
+        let slice = Chunk::new(slice.into_arrays().into_iter().map(Arc::from).collect());
+        serialize_limited(
+            &slice,
+            fields,
+            options,
+            max_message_size,
+            dictionary_tracker,
+            dictionaries,
+            batches,
+        )
+    })
+}
+
 impl From<EncodedData> for FlightData {
     fn from(data: EncodedData) -> Self {
         FlightData {
@@ -106,6 +195,9 @@ pub fn deserialize_schemas(bytes: &[u8]) -> Result<(Schema, IpcSchema)> {
 }
 
 /// Deserializes [`FlightData`] to [`Chunk`].
+///
+/// The dictionaries of the batch must already be in `dictionaries`; use [`FlightDecoder`] to
+/// decode a stream of messages with dictionary batches.
 pub fn deserialize_batch(
     data: &FlightData,
     fields: &[Field],
@@ -140,3 +232,80 @@ pub fn deserialize_batch(
         )),
     }
 }
+
+/// Deserializes a [`FlightData`] representing either a dictionary or a batch. Dictionaries are
+/// inserted into `dictionaries` and return `None`; batches return their [`Chunk`].
+pub fn deserialize_message(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut read::Dictionaries,
+) -> Result<Option<Chunk<Arc<dyn Array>>>> {
+    let message =
+        arrow_format::ipc::MessageRef::read_as_root(&data.data_header).map_err(|err| {
+            ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
+        })?;
+
+    let mut reader = std::io::Cursor::new(&data.data_body);
+
+    match message
+        .header()?
+        .ok_or_else(|| ArrowError::oos("Unable to convert flight data header to a message"))?
+    {
+        ipc::MessageHeaderRef::RecordBatch(batch) => read::read_record_batch(
+            batch,
+            fields,
+            ipc_schema,
+            None,
+            dictionaries,
+            message.version()?,
+            &mut reader,
+            0,
+        )
+        .map(Some),
+        ipc::MessageHeaderRef::DictionaryBatch(batch) => {
+            read::read_dictionary(batch, fields, ipc_schema, dictionaries, &mut reader, 0)?;
+            Ok(None)
+        }
+        _ => Err(ArrowError::nyi(
+            "flight currently only supports reading RecordBatch and DictionaryBatch messages",
+        )),
+    }
+}
+
+/// Deserializes [`FlightData`] representing dictionaries and batches (e.g. returned by
+/// [`serialize_batch_with_limit`]) into a single [`Chunk`] with the rows of all batches.
+/// # Implementation
+/// The batches' data is copied iff there is more than one batch.
+pub fn deserialize_batches<'a, I: IntoIterator<Item = &'a FlightData>>(
+    messages: I,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut read::Dictionaries,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let mut chunks = vec![];
+    for data in messages {
+        if let Some(chunk) = deserialize_message(data, fields, ipc_schema, dictionaries)? {
+            if !chunk.is_empty() {
+                chunks.push(chunk);
+            }
+        }
+    }
+    let arrays = match chunks.len() {
+        0 => fields
+            .iter()
+            .map(|field| new_empty_array(field.data_type().clone()).into())
+            .collect(),
+        1 => chunks.pop().unwrap().into_arrays(),
+        _ => (0..fields.len())
+            .map(|column| {
+                let arrays = chunks
+                    .iter()
+                    .map(|chunk| chunk.arrays()[column].as_ref())
+                    .collect::<Vec<_>>();
+                Arc::from(read::concatenate(&arrays))
+            })
+            .collect(),
+    };
+    Ok(Chunk::new(arrays))
+}
@@ -74,5 +74,5 @@ pub fn skip_fixed_size_list(
 
     let (field, _) = FixedSizeListArray::get_child_and_size(data_type);
 
-    skip(field_nodes, field.data_type(), buffers)
+    skip(field_nodes, field, buffers)
 }
@@ -89,7 +89,7 @@ pub fn skip_list<O: Offset>(
         .pop_front()
         .ok_or_else(|| ArrowError::oos("IPC: missing offsets buffer."))?;
 
-    let data_type = ListArray::<O>::get_child_type(data_type);
+    let field = ListArray::<O>::get_child_field(data_type);
 
-    skip(field_nodes, data_type, buffers)
+    skip(field_nodes, field, buffers)
 }
@@ -85,7 +85,7 @@ pub fn skip_map(
         .pop_front()
         .ok_or_else(|| ArrowError::oos("IPC: missing offsets buffer."))?;
 
-    let data_type = MapArray::get_field(data_type).data_type();
+    let field = MapArray::get_field(data_type);
 
-    skip(field_nodes, data_type, buffers)
+    skip(field_nodes, field, buffers)
 }
@@ -6,7 +6,7 @@ use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 
 use super::super::super::IpcField;
-use super::super::deserialize::{read, skip};
+use super::super::deserialize::{read, read_projected, skip};
 use super::super::read_basic::*;
 use super::super::Dictionaries;
 use super::super::{Compression, IpcBuffer, Node, Version};
@@ -64,6 +64,64 @@ pub fn read_struct<R: Read + Seek>(
     StructArray::try_new(data_type, values, validity)
 }
 
+/// Reads a struct of type `data_type`, keeping only the children in `projected`, a struct
+/// whose children are a subset of the ones of `data_type`, in the same order.
+#[allow(clippy::too_many_arguments)]
+pub fn read_struct_projected<R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    data_type: &DataType,
+    projected: DataType,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+    version: Version,
+) -> Result<StructArray> {
+    let field_node = field_nodes.pop_front().ok_or_else(|| {
+        ArrowError::oos(format!(
+            "IPC: unable to fetch the field for {:?}. The file or stream is corrupted.",
+            data_type
+        ))
+    })?;
+
+    let validity = read_validity(
+        buffers,
+        field_node,
+        reader,
+        block_offset,
+        is_little_endian,
+        compression,
+    )?;
+
+    let fields = StructArray::get_fields(data_type);
+    let mut projected_fields = StructArray::get_fields(&projected).iter().peekable();
+
+    let mut values = Vec::with_capacity(projected_fields.len());
+    for (field, ipc_field) in fields.iter().zip(ipc_field.fields.iter()) {
+        match projected_fields.next_if(|projected| projected.name == field.name) {
+            Some(projected) => values.push(read_projected(
+                field_nodes,
+                field,
+                projected,
+                ipc_field,
+                buffers,
+                reader,
+                dictionaries,
+                block_offset,
+                is_little_endian,
+                compression,
+                version,
+            )?),
+            None => skip(field_nodes, field, buffers)?,
+        }
+    }
+
+    StructArray::try_new(projected, values, validity)
+}
+
 pub fn skip_struct(
     field_nodes: &mut VecDeque<Node>,
     data_type: &DataType,
@@ -83,5 +141,5 @@ pub fn skip_struct(
 
     fields
         .iter()
-        .try_for_each(|field| skip(field_nodes, field.data_type(), buffers))
+        .try_for_each(|field| skip(field_nodes, field, buffers))
 }
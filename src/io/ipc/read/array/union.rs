@@ -114,5 +114,5 @@ pub fn skip_union(
 
     fields
         .iter()
-        .try_for_each(|field| skip(field_nodes, field.data_type(), buffers))
+        .try_for_each(|field| skip(field_nodes, field, buffers))
 }
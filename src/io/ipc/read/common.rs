@@ -4,13 +4,13 @@ use std::sync::Arc;
 
 use arrow_format;
 
-use crate::array::*;
+use crate::array::{growable::make_growable, *};
 use crate::chunk::Chunk;
-use crate::datatypes::{DataType, Field};
+use crate::datatypes::{DataType, Field, Schema};
 use crate::error::{ArrowError, Result};
 use crate::io::ipc::{IpcField, IpcSchema};
 
-use super::deserialize::{read, skip};
+use super::deserialize::{read, read_projected, skip};
 use super::Dictionaries;
 
 type ArrayRef = Arc<dyn Array>;
@@ -86,6 +86,32 @@ pub fn read_record_batch<R: Read + Seek>(
     version: arrow_format::ipc::MetadataVersion,
     reader: &mut R,
     block_offset: u64,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    read_projected_record_batch(
+        batch,
+        fields,
+        ipc_schema,
+        projection.map(|projection| (projection, None)),
+        dictionaries,
+        version,
+        reader,
+        block_offset,
+    )
+}
+
+/// Same as [`read_record_batch`], but where the projection optionally declares the projected
+/// fields, whose (nested) structs may only have a subset of the children of the file's
+/// (see [`project_by_names`]).
+#[allow(clippy::too_many_arguments)]
+pub(super) fn read_projected_record_batch<R: Read + Seek>(
+    batch: arrow_format::ipc::RecordBatchRef,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    projection: Option<(&[usize], Option<&[Field]>)>,
+    dictionaries: &Dictionaries,
+    version: arrow_format::ipc::MetadataVersion,
+    reader: &mut R,
+    block_offset: u64,
 ) -> Result<Chunk<Arc<dyn Array>>> {
     assert_eq!(fields.len(), ipc_schema.fields.len());
     let buffers = batch
@@ -98,26 +124,34 @@ pub fn read_record_batch<R: Read + Seek>(
         .ok_or_else(|| ArrowError::oos("IPC RecordBatch must contain field nodes"))?;
     let mut field_nodes = field_nodes.iter().collect::<VecDeque<_>>();
 
-    let columns = if let Some(projection) = projection {
+    let columns = if let Some((projection, projected_fields)) = projection {
+        let mut projected_fields = projected_fields.map(|fields| fields.iter());
         let projection =
             ProjectionIter::new(projection, fields.iter().zip(ipc_schema.fields.iter()));
 
         projection
             .map(|maybe_field| match maybe_field {
-                ProjectionResult::Selected((field, ipc_field)) => Ok(Some(read(
-                    &mut field_nodes,
-                    field,
-                    ipc_field,
-                    &mut buffers,
-                    reader,
-                    dictionaries,
-                    block_offset,
-                    ipc_schema.is_little_endian,
-                    batch.compression()?,
-                    version,
-                )?)),
+                ProjectionResult::Selected((field, ipc_field)) => {
+                    let projected = projected_fields
+                        .as_mut()
+                        .and_then(|fields| fields.next())
+                        .unwrap_or(field);
+                    Ok(Some(read_projected(
+                        &mut field_nodes,
+                        field,
+                        projected,
+                        ipc_field,
+                        &mut buffers,
+                        reader,
+                        dictionaries,
+                        block_offset,
+                        ipc_schema.is_little_endian,
+                        batch.compression()?,
+                        version,
+                    )?))
+                }
                 ProjectionResult::NotSelected((field, _)) => {
-                    skip(&mut field_nodes, &field.data_type, &mut buffers)?;
+                    skip(&mut field_nodes, field, &mut buffers)?;
                     Ok(None)
                 }
             })
@@ -146,6 +180,97 @@ pub fn read_record_batch<R: Read + Seek>(
     Chunk::try_new(columns)
 }
 
+/// Resolves the column names `names` against `schema`, returning the indices of the
+/// selected columns and the projected [`Schema`], for use in [`FileReader::try_new_with_names`](super::FileReader::try_new_with_names).
+///
+/// A name of the form `"a.b"` selects the child `b` of the struct column `a`, at any depth.
+/// The projected schema then declares `a` as a struct with only the selected children, and
+/// the remaining children are skipped when reading. A field whose name contains dots
+/// takes precedence over a nested path.
+///
+/// The columns (and children) are returned in the order of `schema`, not of `names`, and
+/// selecting a field also selects all of its children.
+/// # Errors
+/// This function errors iff `names` is empty, a name does not resolve to a field or a
+/// nested path goes through a field that is not a [`DataType::Struct`].
+pub fn project_by_names(schema: &Schema, names: &[&str]) -> Result<(Vec<usize>, Schema)> {
+    if names.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "A projection must select at least one column".to_string(),
+        ));
+    }
+    let mut selected = vec![None; schema.fields.len()];
+    for name in names {
+        select(&schema.fields, &mut selected, name)?;
+    }
+
+    let (projection, fields) = selected
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, field)| field.map(|field| (index, field)))
+        .unzip();
+    let schema = Schema {
+        fields,
+        metadata: schema.metadata.clone(),
+    };
+    Ok((projection, schema))
+}
+
+/// Selects the field that `path` refers to in `fields`, merging it with the fields
+/// in `selected`, the (projected) fields selected so far.
+fn select(fields: &[Field], selected: &mut [Option<Field>], path: &str) -> Result<()> {
+    if let Some(index) = fields.iter().position(|field| field.name == path) {
+        selected[index] = Some(fields[index].clone());
+        return Ok(());
+    }
+
+    let not_found =
+        || ArrowError::InvalidArgumentError(format!("The schema has no field named \"{}\"", path));
+    let (name, path) = path.split_once('.').ok_or_else(not_found)?;
+    let index = fields
+        .iter()
+        .position(|field| field.name == name)
+        .ok_or_else(not_found)?;
+    let field = &fields[index];
+    let children = match &field.data_type {
+        DataType::Struct(children) => children,
+        other => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The field \"{}\" is of type {:?} and cannot be projected into \"{}\"",
+                name, other, path
+            )))
+        }
+    };
+
+    let mut selected_children = match &selected[index] {
+        // the whole field is already selected
+        Some(selected) if selected == field => return Ok(()),
+        Some(selected) => {
+            let mut projected = match &selected.data_type {
+                DataType::Struct(projected) => projected.iter().peekable(),
+                _ => unreachable!(),
+            };
+            children
+                .iter()
+                .map(|child| {
+                    projected
+                        .next_if(|projected| projected.name == child.name)
+                        .cloned()
+                })
+                .collect()
+        }
+        None => vec![None; children.len()],
+    };
+    select(children, &mut selected_children, path)?;
+
+    let children = selected_children.into_iter().flatten().collect();
+    selected[index] = Some(Field {
+        data_type: DataType::Struct(children),
+        ..field.clone()
+    });
+    Ok(())
+}
+
 fn find_first_dict_field_d<'a>(
     id: i64,
     data_type: &'a DataType,
@@ -182,7 +307,7 @@ fn find_first_dict_field<'a>(
     find_first_dict_field_d(id, &field.data_type, ipc_field)
 }
 
-fn first_dict_field<'a>(
+pub(crate) fn first_dict_field<'a>(
     id: i64,
     fields: &'a [Field],
     ipc_fields: &'a [IpcField],
@@ -209,15 +334,29 @@ pub fn read_dictionary<R: Read + Seek>(
     reader: &mut R,
     block_offset: u64,
 ) -> Result<()> {
-    if batch.is_delta()? {
-        return Err(ArrowError::NotYetImplemented(
-            "delta dictionary batches not supported".to_string(),
-        ));
+    let id = batch.id()?;
+    let is_delta = batch.is_delta()?;
+    if is_delta && dictionaries.get(&id).is_none() {
+        return Err(ArrowError::OutOfSpec(format!(
+            "delta dictionary batch for dictionary id {} must be preceded by a dictionary batch with the same id",
+            id
+        )));
     }
 
-    let id = batch.id()?;
     let (first_field, first_ipc_field) = first_dict_field(id, fields, &ipc_schema.fields)?;
 
+    // avoid decoding dictionaries whose body would exceed the limit
+    if let (Some(_), Some(data)) = (dictionaries.memory_limit(), batch.data()?) {
+        let body_length = data
+            .buffers()?
+            .map(|buffers| buffers.iter().map(|buffer| buffer.length()).sum::<i64>())
+            .unwrap_or(0);
+        let body_length: usize = body_length
+            .try_into()
+            .map_err(|_| ArrowError::oos("The dictionary's buffers must have a positive length"))?;
+        dictionaries.check_body(id, body_length, is_delta)?;
+    }
+
     // As the dictionary batch does not contain the type of the
     // values array, we need to retrieve this from the schema.
     // Get an array representing this dictionary's values.
@@ -250,9 +389,25 @@ pub fn read_dictionary<R: Read + Seek>(
         ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
     })?;
 
-    dictionaries.insert(id, dictionary_values);
+    // a delta appends its values to the existing dictionary's values
+    let dictionary_values = if is_delta {
+        let existing = dictionaries.get(&id).unwrap();
+        concatenate(&[existing.as_ref(), dictionary_values.as_ref()]).into()
+    } else {
+        dictionary_values
+    };
 
-    Ok(())
+    dictionaries.insert(id, dictionary_values)
+}
+
+/// Returns the concatenation of `arrays`, which must have the same [`DataType`].
+pub(crate) fn concatenate(arrays: &[&dyn Array]) -> Box<dyn Array> {
+    let capacity = arrays.iter().map(|array| array.len()).sum();
+    let mut growable = make_growable(arrays, false, capacity);
+    for (index, array) in arrays.iter().enumerate() {
+        growable.extend(index, 0, array.len())
+    }
+    growable.as_box()
 }
 
 #[cfg(test)]
@@ -0,0 +1,385 @@
+//! Conversion of layouts of newer versions of the Arrow specification that arrow2 does not
+//! support to equivalent (dense) layouts that it supports.
+use std::collections::VecDeque;
+use std::convert::TryInto;
+use std::io::{Read, Seek};
+use std::sync::Arc;
+
+use crate::array::{growable::make_growable, Array, ListArray, Offset, PrimitiveArray};
+use crate::datatypes::{DataType, Field, IntegerType};
+use crate::error::{ArrowError, Result};
+
+use super::super::IpcField;
+use super::deserialize::{read, skip};
+use super::read_basic::{read_buffer, read_validity};
+use super::Dictionaries;
+use super::{Compression, IpcBuffer, Node, Version};
+
+/// The key of the [`Field`]'s metadata recording the layout that the field was converted from
+pub(super) const LAYOUT_KEY: &str = "ARROW2:ipc:converted_layout";
+
+/// The key of the [`crate::datatypes::Schema`]'s metadata listing the fields that were
+/// converted from unsupported layouts.
+pub const CONVERTED_FIELDS_KEY: &str = "ARROW2:ipc:converted_fields";
+
+/// How to read fields whose layout is not supported by arrow2, e.g. layouts introduced in
+/// newer versions of the Arrow specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnsupportedLayouts {
+    /// Error when a field has an unsupported layout.
+    Error,
+    /// Convert fields with an unsupported layout to an equivalent layout when possible, and
+    /// error otherwise. Specifically:
+    /// * `RunEndEncoded` is converted to (the dense layout of) its values
+    /// * `ListView` and `LargeListView` are converted to `List` and `LargeList`
+    ///
+    /// The converted fields are listed in the schema's metadata under
+    /// [`CONVERTED_FIELDS_KEY`].
+    Convert,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for UnsupportedLayouts {
+    fn default() -> Self {
+        Self::Error
+    }
+}
+
+/// A layout that arrow2 does not support and that is converted while reading
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Layout {
+    RunEndEncoded(IntegerType),
+    ListView,
+    LargeListView,
+}
+
+impl Layout {
+    /// Returns the name of the layout corresponding to the tag of the type of an IPC field
+    pub fn name(tag: u8) -> &'static str {
+        match tag {
+            22 => "RunEndEncoded",
+            23 => "BinaryView",
+            24 => "Utf8View",
+            25 => "ListView",
+            26 => "LargeListView",
+            _ => "unknown",
+        }
+    }
+
+    pub fn to_metadata(self) -> String {
+        match self {
+            Self::RunEndEncoded(run_ends) => format!("RunEndEncoded<{:?}>", run_ends),
+            Self::ListView => "ListView".to_string(),
+            Self::LargeListView => "LargeListView".to_string(),
+        }
+    }
+
+    /// Returns the [`Layout`] that `field` was converted from, if any
+    pub fn try_from_field(field: &Field) -> Result<Option<Self>> {
+        let layout = match field.metadata.get(LAYOUT_KEY) {
+            Some(layout) => layout.as_str(),
+            None => return Ok(None),
+        };
+        Ok(Some(match layout {
+            "RunEndEncoded<Int16>" => Self::RunEndEncoded(IntegerType::Int16),
+            "RunEndEncoded<Int32>" => Self::RunEndEncoded(IntegerType::Int32),
+            "RunEndEncoded<Int64>" => Self::RunEndEncoded(IntegerType::Int64),
+            "ListView" => Self::ListView,
+            "LargeListView" => Self::LargeListView,
+            other => {
+                return Err(ArrowError::oos(format!(
+                    "IPC: unknown converted layout \"{}\"",
+                    other
+                )))
+            }
+        }))
+    }
+}
+
+fn pop_node<'a>(field_nodes: &mut VecDeque<Node<'a>>, layout: Layout) -> Result<Node<'a>> {
+    field_nodes.pop_front().ok_or_else(|| {
+        ArrowError::oos(format!(
+            "IPC: unable to fetch the field for {:?}. The file or stream is corrupted.",
+            layout
+        ))
+    })
+}
+
+fn to_run_ends<T: crate::types::NativeType + TryInto<usize>>(
+    array: &dyn Array,
+) -> Result<Vec<usize>> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    array
+        .values()
+        .iter()
+        .map(|x| {
+            (*x).try_into()
+                .map_err(|_| ArrowError::oos("IPC: run ends must be positive"))
+        })
+        .collect()
+}
+
+/// Expands `values` so that each of its slots `i` is repeated up to `run_ends[i]`
+fn expand_runs(values: &dyn Array, run_ends: &[usize], length: usize) -> Result<Arc<dyn Array>> {
+    if run_ends.len() > values.len() {
+        return Err(ArrowError::oos(
+            "IPC: a RunEndEncoded array must have as many values as run ends",
+        ));
+    }
+    let mut growable = make_growable(&[values], true, length);
+    let mut start = 0;
+    for (index, end) in run_ends.iter().enumerate() {
+        let end = (*end).min(length);
+        if end < start {
+            return Err(ArrowError::oos(
+                "IPC: the run ends of a RunEndEncoded array must be increasing",
+            ));
+        }
+        (start..end).for_each(|_| growable.extend(0, index, 1));
+        start = end;
+    }
+    if start != length {
+        return Err(ArrowError::oos(
+            "IPC: the run ends of a RunEndEncoded array must cover its length",
+        ));
+    }
+    Ok(growable.as_arc())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn read_run_end_encoded<R: Read + Seek>(
+    run_ends_type: IntegerType,
+    field_nodes: &mut VecDeque<Node>,
+    field: &Field,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+    version: Version,
+) -> Result<Arc<dyn Array>> {
+    let node = pop_node(field_nodes, Layout::RunEndEncoded(run_ends_type))?;
+    let length = node.length() as usize;
+
+    let run_ends_field = Field::new("run_ends", run_ends_type.into(), false);
+    let run_ends = read(
+        field_nodes,
+        &run_ends_field,
+        &ipc_field.fields[0],
+        buffers,
+        reader,
+        dictionaries,
+        block_offset,
+        is_little_endian,
+        compression,
+        version,
+    )?;
+    let run_ends = match run_ends_type {
+        IntegerType::Int16 => to_run_ends::<i16>(run_ends.as_ref())?,
+        IntegerType::Int32 => to_run_ends::<i32>(run_ends.as_ref())?,
+        _ => to_run_ends::<i64>(run_ends.as_ref())?,
+    };
+
+    let values_field = Field::new("values", field.data_type().clone(), true);
+    let values = read(
+        field_nodes,
+        &values_field,
+        &ipc_field.fields[1],
+        buffers,
+        reader,
+        dictionaries,
+        block_offset,
+        is_little_endian,
+        compression,
+        version,
+    )?;
+
+    expand_runs(values.as_ref(), &run_ends, length)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn read_list_view<O: Offset, R: Read + Seek>(
+    layout: Layout,
+    field_nodes: &mut VecDeque<Node>,
+    field: &Field,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+    version: Version,
+) -> Result<Arc<dyn Array>>
+where
+    Vec<u8>: TryInto<O::Bytes>,
+{
+    let node = pop_node(field_nodes, layout)?;
+    let length = node.length() as usize;
+
+    let validity = read_validity(
+        buffers,
+        node,
+        reader,
+        block_offset,
+        is_little_endian,
+        compression,
+    )?;
+    let offsets = read_buffer::<O, _>(
+        buffers,
+        length,
+        reader,
+        block_offset,
+        is_little_endian,
+        compression,
+    )?;
+    let sizes = read_buffer::<O, _>(
+        buffers,
+        length,
+        reader,
+        block_offset,
+        is_little_endian,
+        compression,
+    )?;
+
+    let child = ListArray::<O>::get_child_field(field.data_type());
+    let values = read(
+        field_nodes,
+        child,
+        &ipc_field.fields[0],
+        buffers,
+        reader,
+        dictionaries,
+        block_offset,
+        is_little_endian,
+        compression,
+        version,
+    )?;
+
+    // the views may overlap and be in any order: copy them to contiguous values
+    let mut growable = make_growable(&[values.as_ref()], false, values.len());
+    let mut dense_offsets = Vec::with_capacity(length + 1);
+    dense_offsets.push(O::default());
+    let mut total = 0;
+    for (index, (offset, size)) in offsets.iter().zip(sizes.iter()).enumerate() {
+        let is_valid = validity
+            .as_ref()
+            .map(|validity| validity.get_bit(index))
+            .unwrap_or(true);
+        if is_valid {
+            let (offset, size) = (offset.to_usize(), size.to_usize());
+            if offset.saturating_add(size) > values.len() {
+                return Err(ArrowError::oos(
+                    "IPC: the views of a ListView array must be within its values",
+                ));
+            }
+            growable.extend(0, offset, size);
+            total += size;
+        }
+        dense_offsets.push(O::from_usize(total).ok_or(ArrowError::Overflow)?);
+    }
+
+    ListArray::<O>::try_new(
+        field.data_type().clone(),
+        dense_offsets.into(),
+        growable.as_arc(),
+        validity,
+    )
+    .map(|x| Arc::new(x) as Arc<dyn Array>)
+}
+
+/// Reads a field converted from `layout` into its converted (dense) layout
+#[allow(clippy::too_many_arguments)]
+pub(super) fn read_converted<R: Read + Seek>(
+    layout: Layout,
+    field_nodes: &mut VecDeque<Node>,
+    field: &Field,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<Compression>,
+    version: Version,
+) -> Result<Arc<dyn Array>> {
+    match layout {
+        Layout::RunEndEncoded(run_ends_type) => read_run_end_encoded(
+            run_ends_type,
+            field_nodes,
+            field,
+            ipc_field,
+            buffers,
+            reader,
+            dictionaries,
+            block_offset,
+            is_little_endian,
+            compression,
+            version,
+        ),
+        Layout::ListView => read_list_view::<i32, _>(
+            layout,
+            field_nodes,
+            field,
+            ipc_field,
+            buffers,
+            reader,
+            dictionaries,
+            block_offset,
+            is_little_endian,
+            compression,
+            version,
+        ),
+        Layout::LargeListView => read_list_view::<i64, _>(
+            layout,
+            field_nodes,
+            field,
+            ipc_field,
+            buffers,
+            reader,
+            dictionaries,
+            block_offset,
+            is_little_endian,
+            compression,
+            version,
+        ),
+    }
+}
+
+/// Skips a field converted from `layout`
+pub(super) fn skip_converted(
+    layout: Layout,
+    field_nodes: &mut VecDeque<Node>,
+    field: &Field,
+    buffers: &mut VecDeque<IpcBuffer>,
+) -> Result<()> {
+    let _ = pop_node(field_nodes, layout)?;
+    match layout {
+        Layout::RunEndEncoded(run_ends_type) => {
+            skip(
+                field_nodes,
+                &Field::new("run_ends", run_ends_type.into(), false),
+                buffers,
+            )?;
+            skip(
+                field_nodes,
+                &Field::new("values", field.data_type().clone(), true),
+                buffers,
+            )
+        }
+        Layout::ListView | Layout::LargeListView => {
+            for _ in 0..3 {
+                let _ = buffers
+                    .pop_front()
+                    .ok_or_else(|| ArrowError::oos("IPC: missing ListView buffer."))?;
+            }
+            let child = match field.data_type() {
+                DataType::List(child) | DataType::LargeList(child) => child.as_ref(),
+                _ => unreachable!(),
+            };
+            skip(field_nodes, child, buffers)
+        }
+    }
+}
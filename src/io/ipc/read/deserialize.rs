@@ -8,10 +8,11 @@ use arrow_format::ipc::BodyCompressionRef;
 use arrow_format::ipc::MetadataVersion;
 
 use crate::array::*;
-use crate::datatypes::{DataType, Field, PhysicalType};
+use crate::datatypes::{Field, PhysicalType};
 use crate::error::Result;
 use crate::io::ipc::IpcField;
 
+use super::compat::{read_converted, skip_converted, Layout};
 use super::{array::*, Dictionaries};
 use super::{IpcBuffer, Node};
 
@@ -28,6 +29,22 @@ pub fn read<R: Read + Seek>(
     compression: Option<BodyCompressionRef>,
     version: MetadataVersion,
 ) -> Result<Arc<dyn Array>> {
+    if let Some(layout) = Layout::try_from_field(field)? {
+        return read_converted(
+            layout,
+            field_nodes,
+            field,
+            ipc_field,
+            buffers,
+            reader,
+            dictionaries,
+            block_offset,
+            is_little_endian,
+            compression,
+            version,
+        );
+    }
+
     use PhysicalType::*;
     let data_type = field.data_type.clone();
 
@@ -211,12 +228,63 @@ pub fn read<R: Read + Seek>(
     }
 }
 
+/// Reads the array of `field`, keeping only the children of (nested) structs that are in
+/// `projected`, a field whose struct children are a subset of the ones of `field`.
+#[allow(clippy::too_many_arguments)]
+pub fn read_projected<R: Read + Seek>(
+    field_nodes: &mut VecDeque<Node>,
+    field: &Field,
+    projected: &Field,
+    ipc_field: &IpcField,
+    buffers: &mut VecDeque<IpcBuffer>,
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    block_offset: u64,
+    is_little_endian: bool,
+    compression: Option<BodyCompressionRef>,
+    version: MetadataVersion,
+) -> Result<Arc<dyn Array>> {
+    if field.data_type == projected.data_type {
+        return read(
+            field_nodes,
+            field,
+            ipc_field,
+            buffers,
+            reader,
+            dictionaries,
+            block_offset,
+            is_little_endian,
+            compression,
+            version,
+        );
+    }
+    read_struct_projected(
+        field_nodes,
+        &field.data_type,
+        projected.data_type.clone(),
+        ipc_field,
+        buffers,
+        reader,
+        dictionaries,
+        block_offset,
+        is_little_endian,
+        compression,
+        version,
+    )
+    .map(|x| Arc::new(x) as Arc<dyn Array>)
+}
+
 pub fn skip(
     field_nodes: &mut VecDeque<Node>,
-    data_type: &DataType,
+    field: &Field,
     buffers: &mut VecDeque<IpcBuffer>,
 ) -> Result<()> {
+    if let Some(layout) = Layout::try_from_field(field)? {
+        return skip_converted(layout, field_nodes, field, buffers);
+    }
+
     use PhysicalType::*;
+    let data_type = field.data_type();
     match data_type.to_physical_type() {
         Null => skip_null(field_nodes),
         Boolean => skip_boolean(field_nodes, buffers),
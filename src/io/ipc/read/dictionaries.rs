@@ -0,0 +1,267 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::datatypes::PhysicalType;
+use crate::error::{ArrowError, Result};
+use crate::io::memory::estimated_bytes_size;
+use crate::types::NativeType;
+
+/// The dictionaries read from Arrow's IPC format, by dictionary id.
+///
+/// [`Dictionaries`] dereferences to a [`HashMap`] of the dictionaries by id, and tracks
+/// the memory they use (as estimated from the size of their buffers).
+/// Use [`Dictionaries::set_memory_limit`] to error instead of reading dictionaries beyond a
+/// given size, e.g. when reading untrusted streams, and [`Dictionaries::set_deduplicate`] to
+/// share the values of dictionaries that are equal across ids.
+///
+/// # Migration
+/// [`Dictionaries`] used to be an alias of `HashMap<i64, Arc<dyn Array>>`. Reading it is
+/// unchanged through [`Deref`], while it is built from and converted into a [`HashMap`] via
+/// [`From`]. Since it tracks the memory of its dictionaries it can't be mutated through
+/// the [`HashMap`]; use [`Dictionaries::insert`], which errors when the limit is exceeded.
+#[derive(Debug, Clone, Default)]
+pub struct Dictionaries {
+    dictionaries: HashMap<i64, Arc<dyn Array>>,
+    // number of ids and bytes of each distinct values, by its address
+    allocations: HashMap<usize, (usize, usize)>,
+    // ids of the dictionaries by the hash of their values, when deduplicating
+    hashes: HashMap<u64, Vec<i64>>,
+    memory_usage: usize,
+    memory_limit: Option<usize>,
+    deduplicate: bool,
+}
+
+impl Deref for Dictionaries {
+    type Target = HashMap<i64, Arc<dyn Array>>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.dictionaries
+    }
+}
+
+impl From<HashMap<i64, Arc<dyn Array>>> for Dictionaries {
+    fn from(dictionaries: HashMap<i64, Arc<dyn Array>>) -> Self {
+        let mut new = Self::new();
+        dictionaries.into_iter().for_each(|(id, values)| {
+            // there is no limit, so this is infallible
+            new.insert(id, values).unwrap()
+        });
+        new
+    }
+}
+
+impl From<Dictionaries> for HashMap<i64, Arc<dyn Array>> {
+    fn from(dictionaries: Dictionaries) -> Self {
+        dictionaries.dictionaries
+    }
+}
+
+/// Returns the address of the values of `array`, which identifies shared values.
+fn address(array: &Arc<dyn Array>) -> usize {
+    Arc::as_ptr(array) as *const () as usize
+}
+
+/// Hashes the values of the valid slots of `array`, for the types whose values are hashed.
+fn hash_values<H: Hasher>(array: &dyn Array, hasher: &mut H) {
+    use PhysicalType::*;
+    // the values of null slots are undefined and must not be hashed
+    match array.data_type().to_physical_type() {
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            array.iter().for_each(|x| x.hash(hasher))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$T>>()
+                .unwrap();
+            array
+                .iter()
+                .for_each(|x| x.map(|x| x.to_le_bytes()).hash(hasher))
+        }),
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            array.iter().for_each(|x| x.hash(hasher))
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            array.iter().for_each(|x| x.hash(hasher))
+        }
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            array.iter().for_each(|x| x.hash(hasher))
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            array.iter().for_each(|x| x.hash(hasher))
+        }
+        // other types are only hashed by their data type, length and null count
+        _ => {}
+    }
+}
+
+/// Returns a hash of `array` that is equal for equal arrays.
+fn hash_array(array: &dyn Array) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    array.data_type().hash(&mut hasher);
+    array.len().hash(&mut hasher);
+    array.null_count().hash(&mut hasher);
+    hash_values(array, &mut hasher);
+    hasher.finish()
+}
+
+impl Dictionaries {
+    /// Creates a new, empty, [`Dictionaries`] without a memory limit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of bytes that the dictionaries may use.
+    /// Inserting a dictionary that exceeds it errors.
+    pub fn set_memory_limit(&mut self, memory_limit: Option<usize>) {
+        self.memory_limit = memory_limit;
+    }
+
+    /// Returns the maximum number of bytes that the dictionaries may use, if any.
+    pub fn memory_limit(&self) -> Option<usize> {
+        self.memory_limit
+    }
+
+    /// Sets whether dictionaries equal to an existing dictionary share its values,
+    /// in which case their memory is only accounted for once.
+    pub fn set_deduplicate(&mut self, deduplicate: bool) {
+        self.deduplicate = deduplicate;
+        self.hashes = if deduplicate {
+            let mut hashes = HashMap::<u64, Vec<i64>>::new();
+            self.dictionaries.iter().for_each(|(id, values)| {
+                hashes
+                    .entry(hash_array(values.as_ref()))
+                    .or_default()
+                    .push(*id)
+            });
+            hashes
+        } else {
+            HashMap::new()
+        };
+    }
+
+    /// Returns the number of bytes used by the dictionaries, counting shared values once.
+    #[inline]
+    pub fn memory_usage(&self) -> usize {
+        self.memory_usage
+    }
+
+    /// Returns the number of bytes that replacing the dictionary with id `id` frees.
+    fn freed(&self, id: i64) -> usize {
+        self.dictionaries
+            .get(&id)
+            .and_then(|values| self.allocations.get(&address(values)))
+            .map(|(count, bytes)| if *count == 1 { *bytes } else { 0 })
+            .unwrap_or(0)
+    }
+
+    /// Checks that a dictionary with id `id` whose body has `bytes` bytes can be read,
+    /// before decoding it.
+    /// # Error
+    /// Errors iff the memory used by the dictionaries would exceed the memory limit.
+    pub(super) fn check_body(&self, id: i64, bytes: usize, is_delta: bool) -> Result<()> {
+        if self.deduplicate && !is_delta {
+            // the decoded values may be shared with an existing dictionary
+            self.check_memory(id, bytes, None)
+        } else {
+            // a delta is appended to the existing dictionary instead of replacing it
+            let freed = if is_delta { 0 } else { self.freed(id) };
+            self.check_memory(id, bytes, Some(freed))
+        }
+    }
+
+    /// Checks that `bytes` more bytes can be used by the dictionaries once `freed` bytes
+    /// are freed, or that `bytes` alone can be used when `freed` is `None`.
+    fn check_memory(&self, id: i64, bytes: usize, freed: Option<usize>) -> Result<()> {
+        if let Some(limit) = self.memory_limit {
+            let usage = freed
+                .map_or(0, |freed| self.memory_usage - freed)
+                .saturating_add(bytes);
+            if usage > limit {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Reading dictionary {} requires {} bytes, which exceeds the limit of {} bytes",
+                    id, usage, limit
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the values of a dictionary equal to `values` with an id other than `id`, if any.
+    fn find_equal(&self, id: i64, hash: u64, values: &dyn Array) -> Option<Arc<dyn Array>> {
+        self.hashes
+            .get(&hash)?
+            .iter()
+            .filter(|other_id| **other_id != id)
+            .map(|other_id| &self.dictionaries[other_id])
+            .find(|other| other.as_ref() == values)
+            .cloned()
+    }
+
+    /// Removes `id` from the bookkeeping of its current values, if any.
+    fn release(&mut self, id: i64) {
+        let values = match self.dictionaries.get(&id) {
+            Some(values) => values,
+            None => return,
+        };
+        let address = address(values);
+        let (count, bytes) = self.allocations.get_mut(&address).unwrap();
+        *count -= 1;
+        if *count == 0 {
+            self.memory_usage -= *bytes;
+            self.allocations.remove(&address);
+        }
+        if self.deduplicate {
+            let hash = hash_array(values.as_ref());
+            let ids = self.hashes.get_mut(&hash).unwrap();
+            ids.retain(|other_id| *other_id != id);
+            if ids.is_empty() {
+                self.hashes.remove(&hash);
+            }
+        }
+    }
+
+    /// Inserts (or replaces) the dictionary with id `id`.
+    /// # Error
+    /// Errors iff the memory used by the dictionaries would exceed the memory limit,
+    /// in which case the dictionaries are left unchanged.
+    pub fn insert(&mut self, id: i64, values: Arc<dyn Array>) -> Result<()> {
+        let hash = self.deduplicate.then(|| hash_array(values.as_ref()));
+        let values = hash
+            .and_then(|hash| self.find_equal(id, hash, values.as_ref()))
+            .unwrap_or(values);
+
+        let address = address(&values);
+        if self.dictionaries.get(&id).map(self::address) == Some(address) {
+            // the dictionary already has these values
+            return Ok(());
+        }
+        let bytes = match self.allocations.get(&address) {
+            Some(_) => 0,
+            None => estimated_bytes_size(values.as_ref()),
+        };
+        self.check_memory(id, bytes, Some(self.freed(id)))?;
+
+        self.release(id);
+        let (count, allocated) = self.allocations.entry(address).or_insert((0, bytes));
+        *count += 1;
+        if *count == 1 {
+            self.memory_usage += *allocated;
+        }
+        if let Some(hash) = hash {
+            self.hashes.entry(hash).or_default().push(id);
+        }
+        self.dictionaries.insert(id, values);
+        Ok(())
+    }
+}
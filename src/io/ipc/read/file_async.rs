@@ -2,10 +2,7 @@
 use std::io::SeekFrom;
 use std::sync::Arc;
 
-use arrow_format::ipc::{
-    planus::{ReadAsRoot, Vector},
-    BlockRef, FooterRef, MessageHeaderRef, MessageRef,
-};
+use arrow_format::ipc::{planus::ReadAsRoot, Block, MessageHeaderRef, MessageRef};
 use futures::{
     stream::BoxStream, AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, Stream, StreamExt,
 };
@@ -17,10 +14,10 @@ use crate::error::{ArrowError, Result};
 use crate::io::ipc::{IpcSchema, ARROW_MAGIC, CONTINUATION_MARKER};
 
 use super::common::{read_dictionary, read_record_batch};
-use super::reader::get_serialized_batch;
-use super::schema::fb_to_schema;
+use super::reader::{deserialize_footer, get_serialized_batch};
 use super::Dictionaries;
 use super::FileMetadata;
+use super::UnsupportedLayouts;
 
 /// Async reader for Arrow IPC files
 pub struct FileStream<'a> {
@@ -143,39 +140,23 @@ where
     let mut footer = vec![0; footer_size as usize];
     reader.seek(SeekFrom::End(-10 - footer_size as i64)).await?;
     reader.read_exact(&mut footer).await?;
-    let footer = FooterRef::read_as_root(&footer[..])
-        .map_err(|err| ArrowError::OutOfSpec(format!("unable to get root as footer: {:?}", err)))?;
-
-    let blocks = footer.record_batches()?.ok_or_else(|| {
-        ArrowError::OutOfSpec("unable to get record batches from footer".to_string())
-    })?;
-    let schema = footer
-        .schema()?
-        .ok_or_else(|| ArrowError::OutOfSpec("unable to get schema from footer".to_string()))?;
-    let (schema, ipc_schema) = fb_to_schema(schema)?;
-    let dictionary_blocks = footer.dictionaries()?;
-    let dictionaries = if let Some(blocks) = dictionary_blocks {
-        read_dictionaries(reader, &schema.fields[..], &ipc_schema, blocks).await?
-    } else {
-        Default::default()
-    };
+    let mut metadata = deserialize_footer(&footer, UnsupportedLayouts::Error)?;
 
-    Ok(FileMetadata {
-        schema,
-        ipc_schema,
-        blocks: blocks
-            .iter()
-            .map(|block| Ok(block.try_into()?))
-            .collect::<Result<Vec<_>>>()?,
-        dictionaries,
-    })
+    metadata.dictionaries = read_dictionaries(
+        reader,
+        &metadata.schema.fields[..],
+        &metadata.ipc_schema,
+        &metadata.dictionary_blocks,
+    )
+    .await?;
+    Ok(metadata)
 }
 
 async fn read_dictionaries<R>(
     mut reader: R,
     fields: &[Field],
     ipc_schema: &IpcSchema,
-    blocks: Vector<'_, BlockRef<'_>>,
+    blocks: &[Block],
 ) -> Result<Dictionaries>
 where
     R: AsyncRead + AsyncSeek + Unpin,
@@ -185,7 +166,7 @@ where
     let mut buffer = vec![];
 
     for block in blocks {
-        let offset = block.offset() as u64;
+        let offset = block.offset as u64;
         read_dictionary_message(&mut reader, offset, &mut data).await?;
 
         let message = MessageRef::read_as_root(&data).map_err(|err| {
@@ -197,7 +178,7 @@ where
         match header {
             MessageHeaderRef::DictionaryBatch(batch) => {
                 buffer.clear();
-                buffer.resize(block.body_length() as usize, 0);
+                buffer.resize(block.body_length as usize, 0);
                 reader.read_exact(&mut buffer).await?;
                 let mut cursor = std::io::Cursor::new(&mut buffer);
                 read_dictionary(batch, fields, ipc_schema, &mut dictionaries, &mut cursor, 0)?;
@@ -0,0 +1,7 @@
+//! Zero-copy reading of Arrow's IPC file format from memory, e.g. from a memory-mapped file.
+//!
+//! Unlike the [`FileReader`](super::FileReader), which copies every buffer out of the file,
+//! the arrays returned here point directly to the region of memory that contains the file.
+//! Each array holds a reference to that region (an [`Arc`](std::sync::Arc)) and thus keeps it alive
+//! (e.g. mapped) for as long as any of its buffers is in use.
+pub use crate::mmap::{mmap_dictionaries_unchecked, mmap_unchecked};
@@ -4,14 +4,12 @@
 //! which provides arbitrary access to any of its messages, and the
 //! [`StreamReader`](stream::StreamReader), which only supports reading
 //! data in the order it was written in.
-use std::collections::HashMap;
-use std::sync::Arc;
-
-use crate::array::Array;
-
 mod array;
 mod common;
+mod compat;
 mod deserialize;
+mod dictionaries;
+pub mod mmap;
 mod read_basic;
 mod reader;
 mod schema;
@@ -24,13 +22,22 @@ pub mod stream_async;
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_read_async")))]
 pub mod file_async;
 
-pub use common::{read_dictionary, read_record_batch};
-pub use reader::{read_file_metadata, FileMetadata, FileReader};
+pub(crate) use common::{concatenate, first_dict_field};
+pub use common::{project_by_names, read_dictionary, read_record_batch};
+pub(crate) use compat::Layout;
+pub use compat::{UnsupportedLayouts, CONVERTED_FIELDS_KEY};
+pub use dictionaries::Dictionaries;
+pub(crate) use reader::get_serialized_batch;
+pub use reader::{
+    read_batch, read_batch_projected, read_file_dictionaries, read_file_metadata,
+    read_file_metadata_only, read_file_metadata_with_dictionaries, read_file_metadata_with_layouts,
+    FileMetadata, FileReader,
+};
 pub use schema::deserialize_schema;
-pub use stream::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
-
-/// how dictionaries are tracked in this crate
-pub type Dictionaries = HashMap<i64, Arc<dyn Array>>;
+pub use stream::{
+    read_stream_metadata, read_stream_metadata_with_layouts, try_read_stream_metadata,
+    StreamMetadata, StreamMetadataState, StreamReader, StreamState,
+};
 
 pub(crate) type Node<'a> = arrow_format::ipc::FieldNodeRef<'a>;
 pub(crate) type IpcBuffer<'a> = arrow_format::ipc::BufferRef<'a>;
@@ -4,15 +4,15 @@ use std::sync::Arc;
 
 use crate::array::Array;
 use crate::chunk::Chunk;
-use crate::datatypes::{Field, Schema};
+use crate::datatypes::{Field, Metadata, Schema};
 use crate::error::{ArrowError, Result};
 use crate::io::ipc::IpcSchema;
 
 use super::super::{ARROW_MAGIC, CONTINUATION_MARKER};
 use super::common::*;
 use super::schema::fb_to_schema;
-use super::Dictionaries;
-use arrow_format::ipc::planus::{ReadAsRoot, Vector};
+use super::{Dictionaries, UnsupportedLayouts};
+use arrow_format::ipc::planus::ReadAsRoot;
 
 /// Metadata of an Arrow IPC file, written in the footer of the file.
 #[derive(Debug, Clone)]
@@ -23,15 +23,39 @@ pub struct FileMetadata {
     /// The files' [`IpcSchema`]
     pub ipc_schema: IpcSchema,
 
+    /// The custom metadata of the file's footer (not to be confused with the schema's metadata)
+    pub custom_metadata: Metadata,
+
     /// The blocks in the file
     ///
     /// A block indicates the regions in the file to read to get data
-    pub(super) blocks: Vec<arrow_format::ipc::Block>,
+    pub(crate) blocks: Vec<arrow_format::ipc::Block>,
+
+    /// The blocks of the dictionaries in the file
+    pub(crate) dictionary_blocks: Vec<arrow_format::ipc::Block>,
 
     /// Dictionaries associated to each dict_id
     pub(super) dictionaries: Dictionaries,
 }
 
+impl FileMetadata {
+    /// Returns the dictionaries read from the file
+    pub fn dictionaries(&self) -> &Dictionaries {
+        &self.dictionaries
+    }
+
+    /// Returns the blocks of the file's record batches, in order. Each block declares the
+    /// offset and the sizes of the metadata and of the body of a record batch.
+    pub fn blocks(&self) -> &[arrow_format::ipc::Block] {
+        &self.blocks
+    }
+
+    /// Returns the blocks of the file's dictionary batches, in order.
+    pub fn dictionary_blocks(&self) -> &[arrow_format::ipc::Block] {
+        &self.dictionary_blocks
+    }
+}
+
 /// Arrow File reader
 pub struct FileReader<R: Read + Seek> {
     reader: R,
@@ -66,14 +90,14 @@ fn read_dictionaries<R: Read + Seek>(
     reader: &mut R,
     fields: &[Field],
     ipc_schema: &IpcSchema,
-    blocks: Vector<arrow_format::ipc::BlockRef>,
+    blocks: &[arrow_format::ipc::Block],
+    mut dictionaries: Dictionaries,
 ) -> Result<Dictionaries> {
-    let mut dictionaries = Default::default();
     let mut data = vec![];
 
     for block in blocks {
-        let offset = block.offset() as u64;
-        let length = block.meta_data_length() as u64;
+        let offset = block.offset as u64;
+        let length = block.meta_data_length as u64;
         read_dictionary_message(reader, offset, &mut data)?;
 
         let message = arrow_format::ipc::MessageRef::read_as_root(&data).map_err(|err| {
@@ -107,8 +131,8 @@ fn read_dictionaries<R: Read + Seek>(
     Ok(dictionaries)
 }
 
-/// Read the IPC file's metadata
-pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata> {
+/// Reads the footer of an Arrow IPC file
+fn read_footer<R: Read + Seek>(reader: &mut R) -> Result<Vec<u8>> {
     // check if header and footer contain correct magic bytes
     let mut magic_buffer: [u8; 6] = [0; 6];
     reader.read_exact(&mut magic_buffer)?;
@@ -134,8 +158,15 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata
     let mut footer_data = vec![0; footer_len as usize];
     reader.seek(SeekFrom::End(-10 - footer_len as i64))?;
     reader.read_exact(&mut footer_data)?;
+    Ok(footer_data)
+}
 
-    let footer = arrow_format::ipc::FooterRef::read_as_root(&footer_data)
+/// Deserializes the footer of an Arrow IPC file into a [`FileMetadata`] without dictionaries
+pub(super) fn deserialize_footer(
+    footer_data: &[u8],
+    layouts: UnsupportedLayouts,
+) -> Result<FileMetadata> {
+    let footer = arrow_format::ipc::FooterRef::read_as_root(footer_data)
         .map_err(|err| ArrowError::OutOfSpec(format!("Unable to get root as footer: {:?}", err)))?;
 
     let blocks = footer.record_batches()?.ok_or_else(|| {
@@ -145,28 +176,97 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata
     let ipc_schema = footer
         .schema()?
         .ok_or_else(|| ArrowError::OutOfSpec("Unable to get the schema from footer".to_string()))?;
-    let (schema, ipc_schema) = fb_to_schema(ipc_schema)?;
-
-    let dictionary_blocks = footer.dictionaries()?;
-
-    let dictionaries = if let Some(blocks) = dictionary_blocks {
-        read_dictionaries(reader, &schema.fields, &ipc_schema, blocks)?
-    } else {
-        Default::default()
-    };
+    let (schema, ipc_schema) = fb_to_schema(ipc_schema, layouts)?;
+
+    let mut custom_metadata = Metadata::default();
+    if let Some(key_values) = footer.custom_metadata()? {
+        for kv in key_values {
+            let kv = kv?;
+            if let (Some(key), Some(value)) = (kv.key()?, kv.value()?) {
+                custom_metadata.insert(key.to_string(), value.to_string());
+            }
+        }
+    }
 
     Ok(FileMetadata {
         schema,
         ipc_schema,
+        custom_metadata,
         blocks: blocks
             .iter()
             .map(|block| Ok(block.try_into()?))
             .collect::<Result<Vec<_>>>()?,
-        dictionaries,
+        dictionary_blocks: footer
+            .dictionaries()?
+            .map(|blocks| {
+                blocks
+                    .iter()
+                    .map(|block| Ok(block.try_into()?))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .transpose()?
+            .unwrap_or_default(),
+        dictionaries: Default::default(),
     })
 }
 
-pub(super) fn get_serialized_batch<'a>(
+/// Read the IPC file's metadata
+pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata> {
+    read_file_metadata_with_dictionaries(reader, Default::default())
+}
+
+/// Read the IPC file's metadata, reading the file's dictionaries into `dictionaries`.
+/// Use it to limit the memory used by (or to deduplicate) the file's dictionaries.
+pub fn read_file_metadata_with_dictionaries<R: Read + Seek>(
+    reader: &mut R,
+    dictionaries: Dictionaries,
+) -> Result<FileMetadata> {
+    let mut metadata = read_file_metadata_only(reader)?;
+    metadata.dictionaries = dictionaries;
+    read_file_dictionaries(reader, &mut metadata)?;
+    Ok(metadata)
+}
+
+/// Read the IPC file's metadata from its footer only: its schema, custom metadata and
+/// block index, without reading its dictionaries.
+///
+/// This performs a constant number of reads independently of the file's content, which
+/// makes it suitable to inspect files (e.g. their schema or number of batches).
+/// Use [`read_file_dictionaries`] before reading batches of a file with dictionary-encoded fields.
+pub fn read_file_metadata_only<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata> {
+    let footer_data = read_footer(reader)?;
+    deserialize_footer(&footer_data, UnsupportedLayouts::Error)
+}
+
+/// Read the IPC file's metadata, reading fields with layouts unsupported by arrow2
+/// (e.g. from newer producers) according to `layouts`.
+pub fn read_file_metadata_with_layouts<R: Read + Seek>(
+    reader: &mut R,
+    layouts: UnsupportedLayouts,
+) -> Result<FileMetadata> {
+    let footer_data = read_footer(reader)?;
+    let mut metadata = deserialize_footer(&footer_data, layouts)?;
+    read_file_dictionaries(reader, &mut metadata)?;
+    Ok(metadata)
+}
+
+/// Reads the dictionaries of the IPC file into `metadata`, e.g. after
+/// [`read_file_metadata_only`].
+pub fn read_file_dictionaries<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &mut FileMetadata,
+) -> Result<()> {
+    metadata.dictionaries = read_dictionaries(
+        reader,
+        &metadata.schema.fields,
+        &metadata.ipc_schema,
+        &metadata.dictionary_blocks,
+        std::mem::take(&mut metadata.dictionaries),
+    )?;
+    Ok(())
+}
+
+pub(crate) fn get_serialized_batch<'a>(
     message: &'a arrow_format::ipc::MessageRef,
 ) -> Result<arrow_format::ipc::RecordBatchRef<'a>> {
     let header = message.header()?.ok_or_else(|| {
@@ -191,6 +291,24 @@ pub fn read_batch<R: Read + Seek>(
     projection: Option<&[usize]>,
     block: usize,
     block_data: &mut Vec<u8>,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    read_batch_projected(
+        reader,
+        metadata,
+        projection.map(|projection| (projection, None)),
+        block,
+        block_data,
+    )
+}
+
+/// Same as [`read_batch`], but the `projection` optionally declares the projected fields,
+/// whose structs may only have a subset of the children of the file's (see [`project_by_names`]).
+pub fn read_batch_projected<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetadata,
+    projection: Option<(&[usize], Option<&[Field]>)>,
+    block: usize,
+    block_data: &mut Vec<u8>,
 ) -> Result<Chunk<Arc<dyn Array>>> {
     let block = metadata.blocks[block];
 
@@ -213,7 +331,7 @@ pub fn read_batch<R: Read + Seek>(
 
     let batch = get_serialized_batch(&message)?;
 
-    read_record_batch(
+    read_projected_record_batch(
         batch,
         &metadata.schema.fields,
         &metadata.ipc_schema,
@@ -258,6 +376,23 @@ impl<R: Read + Seek> FileReader<R> {
         }
     }
 
+    /// Creates a new [`FileReader`] that only reads the columns named `names`, which may
+    /// also select children of struct columns via paths like `"a.b"`. The columns are read
+    /// in the order of the file's schema; see [`project_by_names`] for details.
+    /// # Errors
+    /// This function errors iff `names` is empty or a name does not resolve to a field of the
+    /// file's schema.
+    pub fn try_new_with_names(reader: R, metadata: FileMetadata, names: &[&str]) -> Result<Self> {
+        let projection = project_by_names(&metadata.schema, names)?;
+        Ok(Self {
+            reader,
+            metadata,
+            projection: Some(projection),
+            current_block: 0,
+            buffer: vec![],
+        })
+    }
+
     /// Return the schema of the file
     pub fn schema(&self) -> &Schema {
         self.projection
@@ -271,6 +406,35 @@ impl<R: Read + Seek> FileReader<R> {
         &self.metadata
     }
 
+    /// Returns the blocks of the file's record batches, in order.
+    pub fn blocks(&self) -> &[arrow_format::ipc::Block] {
+        self.metadata.blocks()
+    }
+
+    /// Reads the record batch at block `index` (with the projection of this reader), without
+    /// reading the batches before it. This does not affect the batches returned by iterating
+    /// over this reader.
+    /// # Errors
+    /// This function errors iff `index` is out of bounds or the batch cannot be read.
+    pub fn read_at(&mut self, index: usize) -> Result<Chunk<Arc<dyn Array>>> {
+        if index >= self.metadata.blocks.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The block index {} is out of bounds for a file with {} blocks",
+                index,
+                self.metadata.blocks.len()
+            )));
+        }
+        read_batch_projected(
+            &mut self.reader,
+            &self.metadata,
+            self.projection
+                .as_ref()
+                .map(|(projection, schema)| (projection.as_ref(), Some(schema.fields.as_ref()))),
+            index,
+            &mut self.buffer,
+        )
+    }
+
     /// Consumes this FileReader, returning the underlying reader
     pub fn into_inner(self) -> R {
         self.reader
@@ -285,13 +449,7 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
         if self.current_block < self.metadata.blocks.len() {
             let block = self.current_block;
             self.current_block += 1;
-            Some(read_batch(
-                &mut self.reader,
-                &self.metadata,
-                self.projection.as_ref().map(|x| x.0.as_ref()),
-                block,
-                &mut self.buffer,
-            ))
+            Some(self.read_at(block))
         } else {
             None
         }
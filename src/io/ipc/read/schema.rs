@@ -10,6 +10,7 @@ use crate::{
 
 use super::{
     super::{IpcField, IpcSchema},
+    compat::{Layout, UnsupportedLayouts, CONVERTED_FIELDS_KEY, LAYOUT_KEY},
     StreamMetadata,
 };
 
@@ -25,12 +26,20 @@ fn try_unzip_vec<A, B, I: Iterator<Item = Result<(A, B)>>>(iter: I) -> Result<(V
     Ok((a, b))
 }
 
-fn deserialize_field(ipc_field: arrow_format::ipc::FieldRef) -> Result<(Field, IpcField)> {
-    let metadata = read_metadata(&ipc_field)?;
+fn deserialize_field(
+    ipc_field: arrow_format::ipc::FieldRef,
+    layouts: UnsupportedLayouts,
+) -> Result<(Field, IpcField)> {
+    let mut metadata = read_metadata(&ipc_field)?;
 
-    let extension = get_extension(&metadata);
-
-    let (data_type, ipc_field_) = get_data_type(ipc_field, extension, true)?;
+    let (data_type, ipc_field_) = if let Some(tag) = unknown_type_tag(&ipc_field)? {
+        let (data_type, ipc_field_, layout) = deserialize_unsupported(ipc_field, tag, layouts)?;
+        metadata.insert(LAYOUT_KEY.to_string(), layout.to_metadata());
+        (data_type, ipc_field_)
+    } else {
+        let extension = get_extension(&metadata);
+        get_data_type(ipc_field, extension, true, layouts)?
+    };
 
     let field = Field {
         name: ipc_field
@@ -45,6 +54,123 @@ fn deserialize_field(ipc_field: arrow_format::ipc::FieldRef) -> Result<(Field, I
     Ok((field, ipc_field_))
 }
 
+/// Returns the tag of the type of `field` if it is a type unknown to this version of the
+/// specification, e.g. a type introduced in a newer version.
+fn unknown_type_tag(field: &arrow_format::ipc::FieldRef) -> Result<Option<u8>> {
+    match field.type_() {
+        Ok(_) => Ok(None),
+        Err(error) => match error.error_kind {
+            arrow_format::ipc::planus::errors::ErrorKind::UnknownUnionTag { tag } => Ok(Some(tag)),
+            _ => Err(error.into()),
+        },
+    }
+}
+
+fn has_dictionary(ipc_field: &IpcField) -> bool {
+    ipc_field.dictionary_id.is_some() || ipc_field.fields.iter().any(has_dictionary)
+}
+
+/// Deserializes a field whose type has the (unknown) tag `tag` into the [`DataType`] that
+/// it is converted to.
+fn deserialize_unsupported(
+    field: arrow_format::ipc::FieldRef,
+    tag: u8,
+    layouts: UnsupportedLayouts,
+) -> Result<(DataType, IpcField, Layout)> {
+    let name = Layout::name(tag);
+    if layouts == UnsupportedLayouts::Error {
+        return Err(ArrowError::nyi(format!(
+            "Reading IPC fields of type {} (type tag {}). \
+            Use `UnsupportedLayouts::Convert` to read them into an equivalent layout",
+            name, tag
+        )));
+    }
+    let children = field
+        .children()?
+        .ok_or_else(|| ArrowError::oos(format!("IPC: {} must contain children", name)))?;
+    let child = |index: usize| -> Result<(Field, IpcField)> {
+        let child = children.get(index).ok_or_else(|| {
+            ArrowError::oos(format!("IPC: {} must contain {} children", name, index + 1))
+        })??;
+        deserialize_field(child, layouts)
+    };
+
+    match tag {
+        22 => {
+            let (run_ends, run_ends_ipc) = child(0)?;
+            let run_ends_type = match run_ends.data_type() {
+                DataType::Int16 => IntegerType::Int16,
+                DataType::Int32 => IntegerType::Int32,
+                DataType::Int64 => IntegerType::Int64,
+                other => {
+                    return Err(ArrowError::oos(format!(
+                    "IPC: the run ends of RunEndEncoded must be Int16, Int32 or Int64, not {:?}",
+                    other
+                )))
+                }
+            };
+            let (values, values_ipc) = child(1)?;
+            if values.metadata.contains_key(LAYOUT_KEY) || has_dictionary(&values_ipc) {
+                return Err(ArrowError::nyi(
+                    "Converting RunEndEncoded fields whose values are dictionary-encoded or \
+                    have an unsupported layout",
+                ));
+            }
+            Ok((
+                values.data_type,
+                IpcField {
+                    fields: vec![run_ends_ipc, values_ipc],
+                    dictionary_id: None,
+                },
+                Layout::RunEndEncoded(run_ends_type),
+            ))
+        }
+        25 | 26 => {
+            let (child, child_ipc) = child(0)?;
+            let (data_type, layout) = if tag == 25 {
+                (DataType::List(Box::new(child)), Layout::ListView)
+            } else {
+                (DataType::LargeList(Box::new(child)), Layout::LargeListView)
+            };
+            Ok((
+                data_type,
+                IpcField {
+                    fields: vec![child_ipc],
+                    dictionary_id: None,
+                },
+                layout,
+            ))
+        }
+        _ => Err(ArrowError::nyi(format!(
+            "Reading (or converting) IPC fields of type {} (type tag {})",
+            name, tag
+        ))),
+    }
+}
+
+/// Appends the (dot-separated) names of the fields converted from unsupported layouts
+fn converted_fields(fields: &[Field], prefix: &str, names: &mut Vec<String>) {
+    for field in fields {
+        let name = format!("{}{}", prefix, field.name);
+        if let Some(layout) = field.metadata.get(LAYOUT_KEY) {
+            names.push(format!("{}: {}", name, layout));
+        }
+        let prefix = format!("{}.", name);
+        match field.data_type().to_logical_type() {
+            DataType::List(child)
+            | DataType::LargeList(child)
+            | DataType::FixedSizeList(child, _)
+            | DataType::Map(child, _) => {
+                converted_fields(std::slice::from_ref(child.as_ref()), &prefix, names)
+            }
+            DataType::Struct(children) | DataType::Union(children, _, _) => {
+                converted_fields(children, &prefix, names)
+            }
+            _ => {}
+        }
+    }
+}
+
 fn read_metadata(field: &arrow_format::ipc::FieldRef) -> Result<Metadata> {
     Ok(if let Some(list) = field.custom_metadata()? {
         let mut metadata_map = Metadata::new();
@@ -93,6 +219,7 @@ fn get_data_type(
     field: arrow_format::ipc::FieldRef,
     extension: Extension,
     may_be_dictionary: bool,
+    layouts: UnsupportedLayouts,
 ) -> Result<(DataType, IpcField)> {
     if let Some(dictionary) = field.dictionary()? {
         if may_be_dictionary {
@@ -100,7 +227,7 @@ fn get_data_type(
                 .index_type()?
                 .ok_or_else(|| ArrowError::oos("indexType is mandatory in Dictionary."))?;
             let index_type = deserialize_integer(int)?;
-            let (inner, mut ipc_field) = get_data_type(field, extension, false)?;
+            let (inner, mut ipc_field) = get_data_type(field, extension, false, layouts)?;
             ipc_field.dictionary_id = Some(dictionary.id()?);
             return Ok((
                 DataType::Dictionary(index_type, Box::new(inner), dictionary.is_ordered()?),
@@ -111,7 +238,7 @@ fn get_data_type(
 
     if let Some(extension) = extension {
         let (name, metadata) = extension;
-        let (data_type, fields) = get_data_type(field, None, false)?;
+        let (data_type, fields) = get_data_type(field, None, false, layouts)?;
         return Ok((
             DataType::Extension(name, Box::new(data_type), metadata),
             fields,
@@ -208,7 +335,7 @@ fn get_data_type(
             let inner = children
                 .get(0)
                 .ok_or_else(|| ArrowError::oos("IPC: List must contain one child"))??;
-            let (field, ipc_field) = deserialize_field(inner)?;
+            let (field, ipc_field) = deserialize_field(inner, layouts)?;
 
             (
                 DataType::List(Box::new(field)),
@@ -225,7 +352,7 @@ fn get_data_type(
             let inner = children
                 .get(0)
                 .ok_or_else(|| ArrowError::oos("IPC: List must contain one child"))??;
-            let (field, ipc_field) = deserialize_field(inner)?;
+            let (field, ipc_field) = deserialize_field(inner, layouts)?;
 
             (
                 DataType::LargeList(Box::new(field)),
@@ -242,7 +369,7 @@ fn get_data_type(
             let inner = children
                 .get(0)
                 .ok_or_else(|| ArrowError::oos("IPC: FixedSizeList must contain one child"))??;
-            let (field, ipc_field) = deserialize_field(inner)?;
+            let (field, ipc_field) = deserialize_field(inner, layouts)?;
 
             let size = list.list_size()? as usize;
 
@@ -264,7 +391,7 @@ fn get_data_type(
                 ));
             }
             let (fields, ipc_fields) = try_unzip_vec(fields.iter().map(|field| {
-                let (field, fields) = deserialize_field(field?)?;
+                let (field, fields) = deserialize_field(field?, layouts)?;
                 Ok((field, fields))
             }))?;
             let ipc_field = IpcField {
@@ -287,7 +414,7 @@ fn get_data_type(
             }
 
             let (fields, ipc_fields) = try_unzip_vec(fields.iter().map(|field| {
-                let (field, fields) = deserialize_field(field?)?;
+                let (field, fields) = deserialize_field(field?, layouts)?;
                 Ok((field, fields))
             }))?;
             let ipc_field = IpcField {
@@ -305,7 +432,7 @@ fn get_data_type(
             let inner = children
                 .get(0)
                 .ok_or_else(|| ArrowError::oos("IPC: Map must contain one child"))??;
-            let (field, ipc_field) = deserialize_field(inner)?;
+            let (field, ipc_field) = deserialize_field(inner, layouts)?;
 
             let data_type = DataType::Map(Box::new(field), is_sorted);
             (
@@ -333,16 +460,19 @@ pub fn deserialize_schema(bytes: &[u8]) -> Result<(Schema, IpcSchema)> {
         )),
     }?;
 
-    fb_to_schema(schema)
+    fb_to_schema(schema, UnsupportedLayouts::Error)
 }
 
 /// Deserialize the raw Schema table from IPC format to Schema data type
-pub(super) fn fb_to_schema(schema: arrow_format::ipc::SchemaRef) -> Result<(Schema, IpcSchema)> {
+pub(super) fn fb_to_schema(
+    schema: arrow_format::ipc::SchemaRef,
+    layouts: UnsupportedLayouts,
+) -> Result<(Schema, IpcSchema)> {
     let fields = schema
         .fields()?
         .ok_or_else(|| ArrowError::oos("IPC: Schema must contain fields"))?;
     let (fields, ipc_fields) = try_unzip_vec(fields.iter().map(|field| {
-        let (field, fields) = deserialize_field(field?)?;
+        let (field, fields) = deserialize_field(field?, layouts)?;
         Ok((field, fields))
     }))?;
 
@@ -365,6 +495,12 @@ pub(super) fn fb_to_schema(schema: arrow_format::ipc::SchemaRef) -> Result<(Sche
         }
     }
 
+    let mut converted = vec![];
+    converted_fields(&fields, "", &mut converted);
+    if !converted.is_empty() {
+        metadata.insert(CONVERTED_FIELDS_KEY.to_string(), converted.join(", "));
+    }
+
     Ok((
         Schema { fields, metadata },
         IpcSchema {
@@ -374,7 +510,10 @@ pub(super) fn fb_to_schema(schema: arrow_format::ipc::SchemaRef) -> Result<(Sche
     ))
 }
 
-pub(super) fn deserialize_stream_metadata(meta: &[u8]) -> Result<StreamMetadata> {
+pub(super) fn deserialize_stream_metadata(
+    meta: &[u8],
+    layouts: UnsupportedLayouts,
+) -> Result<StreamMetadata> {
     let message = arrow_format::ipc::MessageRef::read_as_root(meta).map_err(|err| {
         ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
     })?;
@@ -390,7 +529,7 @@ pub(super) fn deserialize_stream_metadata(meta: &[u8]) -> Result<StreamMetadata>
             "The first IPC message of the stream must be a schema",
         ));
     };
-    let (schema, ipc_schema) = fb_to_schema(schema)?;
+    let (schema, ipc_schema) = fb_to_schema(schema, layouts)?;
 
     Ok(StreamMetadata {
         schema,
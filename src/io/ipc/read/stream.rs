@@ -6,14 +6,14 @@ use arrow_format::ipc::planus::ReadAsRoot;
 
 use crate::array::Array;
 use crate::chunk::Chunk;
-use crate::datatypes::Schema;
+use crate::datatypes::{Metadata, Schema};
 use crate::error::{ArrowError, Result};
 use crate::io::ipc::IpcSchema;
 
 use super::super::CONTINUATION_MARKER;
 use super::common::*;
 use super::schema::deserialize_stream_metadata;
-use super::Dictionaries;
+use super::{Dictionaries, UnsupportedLayouts};
 
 /// Metadata of an Arrow IPC stream, written at the start of the stream
 #[derive(Debug, Clone)]
@@ -30,6 +30,15 @@ pub struct StreamMetadata {
 
 /// Reads the metadata of the stream
 pub fn read_stream_metadata<R: Read>(reader: &mut R) -> Result<StreamMetadata> {
+    read_stream_metadata_with_layouts(reader, UnsupportedLayouts::Error)
+}
+
+/// Reads the metadata of the stream, reading fields with layouts unsupported by arrow2
+/// (e.g. from newer producers) according to `layouts`.
+pub fn read_stream_metadata_with_layouts<R: Read>(
+    reader: &mut R,
+    layouts: UnsupportedLayouts,
+) -> Result<StreamMetadata> {
     // determine metadata length
     let mut meta_size: [u8; 4] = [0; 4];
     reader.read_exact(&mut meta_size)?;
@@ -45,7 +54,53 @@ pub fn read_stream_metadata<R: Read>(reader: &mut R) -> Result<StreamMetadata> {
     let mut meta_buffer = vec![0; meta_len as usize];
     reader.read_exact(&mut meta_buffer)?;
 
-    deserialize_stream_metadata(&meta_buffer)
+    deserialize_stream_metadata(&meta_buffer, layouts)
+}
+
+/// The result of [`try_read_stream_metadata`].
+#[derive(Debug, Clone)]
+pub enum StreamMetadataState {
+    /// The buffer does not contain the whole metadata: at least this number of
+    /// additional bytes is needed.
+    NeedMoreData(usize),
+    /// The metadata of the stream and the number of bytes of the buffer that it spans,
+    /// after which the stream's messages start.
+    Ready(StreamMetadata, usize),
+}
+
+/// Attempts to read the metadata of a stream from `data`, the (possibly incomplete)
+/// start of the stream, without blocking.
+///
+/// This is useful to read streams from buffers managed by the user (e.g. filled from a
+/// non-blocking socket): when `data` does not contain the whole metadata, it returns
+/// [`StreamMetadataState::NeedMoreData`] with the number of missing bytes, and it should be
+/// called again once they are available.
+/// # Error
+/// Errors iff the metadata in `data` is invalid.
+pub fn try_read_stream_metadata(data: &[u8]) -> Result<StreamMetadataState> {
+    if data.len() < 4 {
+        return Ok(StreamMetadataState::NeedMoreData(4 - data.len()));
+    }
+    // If a continuation marker is encountered, the size is in the next four bytes.
+    let offset = if data[..4] == CONTINUATION_MARKER {
+        if data.len() < 8 {
+            return Ok(StreamMetadataState::NeedMoreData(8 - data.len()));
+        }
+        8
+    } else {
+        4
+    };
+    let meta_len = i32::from_le_bytes(data[offset - 4..offset].try_into().unwrap());
+    let meta_len: usize = meta_len
+        .try_into()
+        .map_err(|_| ArrowError::OutOfSpec("The metadata length must be positive".to_string()))?;
+
+    let end = offset + meta_len;
+    if data.len() < end {
+        return Ok(StreamMetadataState::NeedMoreData(end - data.len()));
+    }
+    let metadata = deserialize_stream_metadata(&data[offset..end], UnsupportedLayouts::Error)?;
+    Ok(StreamMetadataState::Ready(metadata, end))
 }
 
 /// Encodes the stream's status after each read.
@@ -87,6 +142,7 @@ fn read_next<R: Read>(
     dictionaries: &mut Dictionaries,
     message_buffer: &mut Vec<u8>,
     data_buffer: &mut Vec<u8>,
+    custom_metadata: &mut Metadata,
 ) -> Result<Option<StreamState>> {
     // determine metadata length
     let mut meta_length: [u8; 4] = [0; 4];
@@ -133,6 +189,16 @@ fn read_next<R: Read>(
     match header {
         arrow_format::ipc::MessageHeaderRef::Schema(_) => Err(ArrowError::oos("A stream ")),
         arrow_format::ipc::MessageHeaderRef::RecordBatch(batch) => {
+            custom_metadata.clear();
+            if let Some(key_values) = message.custom_metadata()? {
+                for kv in key_values {
+                    let kv = kv?;
+                    if let (Some(key), Some(value)) = (kv.key()?, kv.value()?) {
+                        custom_metadata.insert(key.to_string(), value.to_string());
+                    }
+                }
+            }
+
             // read the block that makes up the record batch into a buffer
             data_buffer.clear();
             data_buffer.resize(message.body_length()? as usize, 0);
@@ -169,7 +235,14 @@ fn read_next<R: Read>(
             )?;
 
             // read the next message until we encounter a RecordBatch message
-            read_next(reader, metadata, dictionaries, message_buffer, data_buffer)
+            read_next(
+                reader,
+                metadata,
+                dictionaries,
+                message_buffer,
+                data_buffer,
+                custom_metadata,
+            )
         }
         t => Err(ArrowError::OutOfSpec(format!(
             "Reading types other than record batches not yet supported, unable to read {:?} ",
@@ -191,6 +264,7 @@ pub struct StreamReader<R: Read> {
     finished: bool,
     data_buffer: Vec<u8>,
     message_buffer: Vec<u8>,
+    custom_metadata: Metadata,
 }
 
 impl<R: Read> StreamReader<R> {
@@ -200,13 +274,24 @@ impl<R: Read> StreamReader<R> {
     /// encounter a schema.
     /// To check if the reader is done, use `is_finished(self)`
     pub fn new(reader: R, metadata: StreamMetadata) -> Self {
+        Self::with_dictionaries(reader, metadata, Default::default())
+    }
+
+    /// Creates a new stream reader that reads the stream's dictionaries into `dictionaries`.
+    /// Use it to limit the memory used by (or to deduplicate) the stream's dictionaries.
+    pub fn with_dictionaries(
+        reader: R,
+        metadata: StreamMetadata,
+        dictionaries: Dictionaries,
+    ) -> Self {
         Self {
             reader,
             metadata,
-            dictionaries: Default::default(),
+            dictionaries,
             finished: false,
             data_buffer: vec![],
             message_buffer: vec![],
+            custom_metadata: Metadata::default(),
         }
     }
 
@@ -215,6 +300,11 @@ impl<R: Read> StreamReader<R> {
         &self.metadata
     }
 
+    /// Returns the custom metadata of the message of the last record batch read from the stream
+    pub fn custom_metadata(&self) -> &Metadata {
+        &self.custom_metadata
+    }
+
     /// Check if the stream is finished
     pub fn is_finished(&self) -> bool {
         self.finished
@@ -230,6 +320,7 @@ impl<R: Read> StreamReader<R> {
             &mut self.dictionaries,
             &mut self.message_buffer,
             &mut self.data_buffer,
+            &mut self.custom_metadata,
         )?;
         if batch.is_none() {
             self.finished = true;
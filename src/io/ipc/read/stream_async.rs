@@ -16,6 +16,7 @@ use super::common::{read_dictionary, read_record_batch};
 use super::schema::deserialize_stream_metadata;
 use super::Dictionaries;
 use super::StreamMetadata;
+use super::UnsupportedLayouts;
 
 /// A (private) state of stream messages
 struct ReadState<R> {
@@ -55,7 +56,7 @@ pub async fn read_stream_metadata_async<R: AsyncRead + Unpin + Send>(
     let mut meta_buffer = vec![0; meta_len as usize];
     reader.read_exact(&mut meta_buffer).await?;
 
-    deserialize_stream_metadata(&meta_buffer)
+    deserialize_stream_metadata(&meta_buffer, UnsupportedLayouts::Error)
 }
 
 /// Reads the next item, yielding `None` if the stream has been closed,
@@ -161,10 +162,20 @@ pub struct AsyncStreamReader<'a, R: AsyncRead + Unpin + Send + 'a> {
 impl<'a, R: AsyncRead + Unpin + Send + 'a> AsyncStreamReader<'a, R> {
     /// Creates a new [`AsyncStreamReader`]
     pub fn new(reader: R, metadata: StreamMetadata) -> Self {
+        Self::with_dictionaries(reader, metadata, Default::default())
+    }
+
+    /// Creates a new [`AsyncStreamReader`] that reads the stream's dictionaries into
+    /// `dictionaries`. Use it to limit the memory used by (or to deduplicate) them.
+    pub fn with_dictionaries(
+        reader: R,
+        metadata: StreamMetadata,
+        dictionaries: Dictionaries,
+    ) -> Self {
         let state = ReadState {
             reader,
             metadata: metadata.clone(),
-            dictionaries: Default::default(),
+            dictionaries,
             data_buffer: Default::default(),
             message_buffer: Default::default(),
         };
@@ -0,0 +1,152 @@
+//! Helpers to exchange [`Chunk`]s over a bidirectional byte stream such as a
+//! [`std::net::TcpStream`] or a `std::os::unix::net::UnixStream`.
+//!
+//! Data is framed according to Arrow's IPC streaming format, so either end can be
+//! any Arrow implementation reading or writing a stream.
+//!
+//! * [`StreamSender`] writes the schema on creation and each [`Chunk`] on [`StreamSender::send`].
+//!   [`StreamSender::keep_alive`] writes a batch without rows whose message's custom metadata
+//!   contains the key [`KEEP_ALIVE`], which readers can use to detect that the sender is still
+//!   alive. [`StreamSender::finish`] writes the end-of-stream marker.
+//! * [`StreamReceiver`] is an [`Iterator`] of [`Chunk`]s that skips keep-alive batches (chunks
+//!   without rows sent via [`StreamSender::send`] are returned) and ends when it reads the
+//!   end-of-stream marker or when the sender closes the connection.
+//!
+//! # Example
+//! ```
+//! use std::net::{TcpListener, TcpStream};
+//! use std::sync::Arc;
+//! use arrow2::array::{Array, Int32Array};
+//! use arrow2::chunk::Chunk;
+//! use arrow2::datatypes::{DataType, Field, Schema};
+//! use arrow2::io::ipc::transport::{StreamReceiver, StreamSender};
+//! use arrow2::io::ipc::write::WriteOptions;
+//!
+//! # fn main() -> arrow2::error::Result<()> {
+//! let listener = TcpListener::bind("127.0.0.1:0")?;
+//! let address = listener.local_addr()?;
+//!
+//! let sender = std::thread::spawn(move || -> arrow2::error::Result<()> {
+//!     let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+//!     let stream = TcpStream::connect(address)?;
+//!     let mut sender = StreamSender::try_new(stream, &schema, WriteOptions { compression: None })?;
+//!     let array = Int32Array::from_slice([1, 2, 3]);
+//!     sender.send(&Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]))?;
+//!     sender.finish()?;
+//!     Ok(())
+//! });
+//!
+//! let (stream, _) = listener.accept()?;
+//! let receiver = StreamReceiver::try_new(stream)?;
+//! let chunks = receiver.collect::<arrow2::error::Result<Vec<_>>>()?;
+//! assert_eq!(chunks.len(), 1);
+//! sender.join().unwrap()?;
+//! # Ok(())
+//! # }
+//! ```
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+use crate::array::{new_empty_array, Array};
+use crate::chunk::Chunk;
+use crate::datatypes::{Metadata, Schema};
+use crate::error::Result;
+
+use super::read::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
+use super::write::{StreamWriter, WriteOptions};
+
+/// The key of the custom metadata of the messages written by [`StreamSender::keep_alive`].
+pub const KEEP_ALIVE: &str = "ARROW2:keep_alive";
+
+/// Writes [`Chunk`]s to a byte stream using Arrow's IPC streaming format.
+/// See the [module's documentation](self) for details.
+pub struct StreamSender<W: Write> {
+    writer: StreamWriter<W>,
+    schema: Schema,
+}
+
+impl<W: Write> StreamSender<W> {
+    /// Creates a new [`StreamSender`], writing the `schema` to `writer`.
+    pub fn try_new(writer: W, schema: &Schema, options: WriteOptions) -> Result<Self> {
+        let mut writer = StreamWriter::new(writer, options);
+        writer.start(schema, None)?;
+        Ok(Self {
+            writer,
+            schema: schema.clone(),
+        })
+    }
+
+    /// Returns the [`Schema`] of this stream
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Writes `chunk` to the stream.
+    /// # Error
+    /// Errors iff the stream was finished or writing to it fails.
+    pub fn send(&mut self, chunk: &Chunk<Arc<dyn Array>>) -> Result<()> {
+        self.writer.write(chunk, None)
+    }
+
+    /// Writes a batch without rows marked with [`KEEP_ALIVE`] to the stream, signaling that
+    /// the sender is alive. [`StreamReceiver`] skips these batches.
+    pub fn keep_alive(&mut self) -> Result<()> {
+        let arrays = self
+            .schema
+            .fields
+            .iter()
+            .map(|field| Arc::from(new_empty_array(field.data_type().clone())))
+            .collect();
+        let mut custom_metadata = Metadata::new();
+        custom_metadata.insert(KEEP_ALIVE.to_string(), String::new());
+        self.writer
+            .write_with_metadata(&Chunk::new(arrays), None, &custom_metadata)
+    }
+
+    /// Writes the end-of-stream marker and returns the inner writer.
+    pub fn finish(mut self) -> Result<W> {
+        self.writer.finish()?;
+        Ok(self.writer.into_inner())
+    }
+}
+
+/// An [`Iterator`] of [`Chunk`]s read from a byte stream in Arrow's IPC streaming format.
+/// See the [module's documentation](self) for details.
+pub struct StreamReceiver<R: Read> {
+    reader: StreamReader<R>,
+}
+
+impl<R: Read> StreamReceiver<R> {
+    /// Creates a new [`StreamReceiver`], blocking until the schema is read from `reader`.
+    pub fn try_new(mut reader: R) -> Result<Self> {
+        let metadata = read_stream_metadata(&mut reader)?;
+        Ok(Self {
+            reader: StreamReader::new(reader, metadata),
+        })
+    }
+
+    /// Returns the [`StreamMetadata`] of this stream
+    pub fn metadata(&self) -> &StreamMetadata {
+        self.reader.metadata()
+    }
+}
+
+impl<R: Read> Iterator for StreamReceiver<R> {
+    type Item = Result<Chunk<Arc<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.reader.next()? {
+                Ok(StreamState::Some(_))
+                    if self.reader.custom_metadata().contains_key(KEEP_ALIVE) =>
+                {
+                    continue
+                }
+                Ok(StreamState::Some(chunk)) => return Some(Ok(chunk)),
+                // reads from a connection only reach EOF once it is closed by the sender
+                Ok(StreamState::Waiting) => return None,
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
@@ -9,8 +9,10 @@ use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::io::ipc::endianess::is_native_little_endian;
 use crate::io::ipc::read::Dictionaries;
+use crate::types::NativeType;
 
 use super::super::IpcField;
+use super::schema::serialize_custom_metadata;
 use super::{write, write_dictionary};
 
 /// Compression codec
@@ -30,6 +32,15 @@ pub struct WriteOptions {
     pub compression: Option<Compression>,
 }
 
+/// Returns the [`IpcField`] of the child of a field with a single child (e.g. a list)
+fn single_child(field: &IpcField) -> Result<&IpcField> {
+    field.fields.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(
+            "The IpcField of a nested type must have the IpcField of its child".to_string(),
+        )
+    })
+}
+
 fn encode_dictionary(
     field: &IpcField,
     array: &Arc<dyn Array>,
@@ -53,15 +64,30 @@ fn encode_dictionary(
                 encoded_dictionaries
             )?;
 
-            let emit = dictionary_tracker.insert(dict_id, array)?;
-
-            if emit {
-                encoded_dictionaries.push(dictionary_batch_to_bytes(
-                    dict_id,
-                    array.as_ref(),
-                    options,
-                    is_native_little_endian(),
-                ));
+            match dictionary_tracker.update(dict_id, array)? {
+                DictionaryUpdate::Unchanged => {}
+                DictionaryUpdate::Full => {
+                    encoded_dictionaries.push(dictionary_batch_to_bytes(
+                        dict_id,
+                        array.as_ref(),
+                        false,
+                        options,
+                        is_native_little_endian(),
+                    ));
+                }
+                DictionaryUpdate::Delta(offset) => {
+                    // only the values appended since the last written dictionary are written
+                    let values = values.slice(offset, values.len() - offset);
+                    let keys = PrimitiveArray::<$T>::new_empty($T::PRIMITIVE.into());
+                    let delta = DictionaryArray::<$T>::from_data(keys, values.into());
+                    encoded_dictionaries.push(dictionary_batch_to_bytes(
+                        dict_id,
+                        &delta,
+                        true,
+                        options,
+                        is_native_little_endian(),
+                    ));
+                }
             };
             Ok(())
         }),
@@ -92,7 +118,7 @@ fn encode_dictionary(
                 .downcast_ref::<ListArray<i32>>()
                 .unwrap()
                 .values();
-            let field = &field.fields[0]; // todo: error instead
+            let field = single_child(field)?;
             encode_dictionary(
                 field,
                 values,
@@ -107,7 +133,7 @@ fn encode_dictionary(
                 .downcast_ref::<ListArray<i64>>()
                 .unwrap()
                 .values();
-            let field = &field.fields[0]; // todo: error instead
+            let field = single_child(field)?;
             encode_dictionary(
                 field,
                 values,
@@ -122,7 +148,7 @@ fn encode_dictionary(
                 .downcast_ref::<FixedSizeListArray>()
                 .unwrap()
                 .values();
-            let field = &field.fields[0]; // todo: error instead
+            let field = single_child(field)?;
             encode_dictionary(
                 field,
                 values,
@@ -137,7 +163,7 @@ fn encode_dictionary(
                 .downcast_ref::<UnionArray>()
                 .unwrap()
                 .fields();
-            let fields = &field.fields[..]; // todo: error instead
+            let fields = field.fields.as_slice();
             if values.len() != fields.len() {
                 return Err(ArrowError::InvalidArgumentError(
                     "The number of fields in a union must equal the number of children in IpcField"
@@ -159,7 +185,7 @@ fn encode_dictionary(
         }
         Map => {
             let values = array.as_any().downcast_ref::<MapArray>().unwrap().field();
-            let field = &field.fields[0]; // todo: error instead
+            let field = single_child(field)?;
             encode_dictionary(
                 field,
                 values,
@@ -176,6 +202,23 @@ pub fn encode_chunk(
     fields: &[IpcField],
     dictionary_tracker: &mut DictionaryTracker,
     options: &WriteOptions,
+) -> Result<(Vec<EncodedData>, EncodedData)> {
+    encode_chunk_with_metadata(
+        columns,
+        fields,
+        dictionary_tracker,
+        options,
+        &Metadata::default(),
+    )
+}
+
+/// Same as [`encode_chunk`], but also writes `custom_metadata` to the record batch's message.
+pub fn encode_chunk_with_metadata(
+    columns: &Chunk<Arc<dyn Array>>,
+    fields: &[IpcField],
+    dictionary_tracker: &mut DictionaryTracker,
+    options: &WriteOptions,
+    custom_metadata: &Metadata,
 ) -> Result<(Vec<EncodedData>, EncodedData)> {
     let mut encoded_dictionaries = vec![];
 
@@ -189,7 +232,7 @@ pub fn encode_chunk(
         )?;
     }
 
-    let encoded_message = columns_to_bytes(columns, options);
+    let encoded_message = columns_to_bytes(columns, options, custom_metadata);
 
     Ok((encoded_dictionaries, encoded_message))
 }
@@ -213,7 +256,11 @@ fn serialize_compression(
 
 /// Write [`Chunk`] into two sets of bytes, one for the header (ipc::Schema::Message) and the
 /// other for the batch's data
-fn columns_to_bytes(columns: &Chunk<Arc<dyn Array>>, options: &WriteOptions) -> EncodedData {
+fn columns_to_bytes(
+    columns: &Chunk<Arc<dyn Array>>,
+    options: &WriteOptions,
+    custom_metadata: &Metadata,
+) -> EncodedData {
     let mut nodes: Vec<arrow_format::ipc::FieldNode> = vec![];
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
@@ -243,7 +290,7 @@ fn columns_to_bytes(columns: &Chunk<Arc<dyn Array>>, options: &WriteOptions) ->
             },
         ))),
         body_length: arrow_data.len() as i64,
-        custom_metadata: None,
+        custom_metadata: serialize_custom_metadata(custom_metadata),
     };
 
     let mut builder = Builder::new();
@@ -260,6 +307,7 @@ fn columns_to_bytes(columns: &Chunk<Arc<dyn Array>>, options: &WriteOptions) ->
 fn dictionary_batch_to_bytes(
     dict_id: i64,
     array: &dyn Array,
+    is_delta: bool,
     options: &WriteOptions,
     is_little_endian: bool,
 ) -> EncodedData {
@@ -291,7 +339,7 @@ fn dictionary_batch_to_bytes(
                     buffers: Some(buffers),
                     compression,
                 })),
-                is_delta: false,
+                is_delta,
             },
         ))),
         body_length: arrow_data.len() as i64,
@@ -307,12 +355,42 @@ fn dictionary_batch_to_bytes(
     }
 }
 
+/// Returns the values of the dictionary `array`.
+fn dictionary_values(array: &Arc<dyn Array>) -> &Arc<dyn Array> {
+    match array.data_type() {
+        DataType::Dictionary(key_type, _, _) => {
+            match_integer_type!(key_type, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap();
+                array.values()
+            })
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// What needs to be written for a dictionary passed to [`DictionaryTracker::update`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictionaryUpdate {
+    /// The dictionary was already written with the same values
+    Unchanged,
+    /// All the dictionary's values must be written
+    Full,
+    /// Only the dictionary's values from the given offset must be written, as a delta of the
+    /// dictionary previously written with the same id
+    Delta(usize),
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
 /// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
-/// isn't allowed in the `FileWriter`.
+/// isn't allowed in the `FileWriter`, and can optionally emit updates that only append values to
+/// an existing dictionary as deltas.
 pub struct DictionaryTracker {
     written: Dictionaries,
     error_on_replacement: bool,
+    deltas: bool,
 }
 
 impl DictionaryTracker {
@@ -320,9 +398,25 @@ impl DictionaryTracker {
         Self {
             written: Dictionaries::new(),
             error_on_replacement,
+            deltas: false,
         }
     }
 
+    /// Creates a new [`DictionaryTracker`] that considers `written` as already written.
+    pub fn with_dictionaries(written: Dictionaries, error_on_replacement: bool) -> Self {
+        Self {
+            written,
+            error_on_replacement,
+            deltas: false,
+        }
+    }
+
+    /// Sets whether dictionaries whose values start with the values of the dictionary previously
+    /// written with the same id are emitted as deltas.
+    pub fn set_deltas(&mut self, deltas: bool) {
+        self.deltas = deltas;
+    }
+
     /// Keep track of the dictionary with the given ID and values. Behavior:
     ///
     /// * If this ID has been written already and has the same data, return `Ok(false)` to indicate
@@ -333,18 +427,7 @@ impl DictionaryTracker {
     ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
     ///   inserted.
     pub fn insert(&mut self, dict_id: i64, array: &Arc<dyn Array>) -> Result<bool> {
-        let values = match array.data_type() {
-            DataType::Dictionary(key_type, _, _) => {
-                match_integer_type!(key_type, |$T| {
-                    let array = array
-                        .as_any()
-                        .downcast_ref::<DictionaryArray<$T>>()
-                        .unwrap();
-                    array.values()
-                })
-            }
-            _ => unreachable!(),
-        };
+        let values = dictionary_values(array);
 
         // If a dictionary with this id was already emitted, check if it was the same.
         if let Some(last) = self.written.get(&dict_id) {
@@ -352,18 +435,40 @@ impl DictionaryTracker {
                 // Same dictionary values => no need to emit it again
                 return Ok(false);
             } else if self.error_on_replacement {
-                return Err(ArrowError::InvalidArgumentError(
-                    "Dictionary replacement detected when writing IPC file format. \
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Dictionary replacement detected when writing IPC file format: the values \
+                     of the dictionary with id {} differ from the values previously written. \
                      Arrow IPC files only support a single dictionary for a given field \
-                     across all batches."
-                        .to_string(),
-                ));
+                     across all batches.",
+                    dict_id
+                )));
             }
         };
 
-        self.written.insert(dict_id, values.clone());
+        self.written.insert(dict_id, values.clone())?;
         Ok(true)
     }
+
+    /// Same as [`DictionaryTracker::insert`], but returns what needs to be written. If deltas
+    /// are enabled and this ID has been written already with values that are a prefix of the new
+    /// values, returns [`DictionaryUpdate::Delta`] with the number of values already written.
+    pub fn update(&mut self, dict_id: i64, array: &Arc<dyn Array>) -> Result<DictionaryUpdate> {
+        if self.deltas {
+            let values = dictionary_values(array);
+            if let Some(last) = self.written.get(&dict_id) {
+                let written = last.len();
+                if written < values.len() && last.as_ref() == values.slice(0, written).as_ref() {
+                    self.written.insert(dict_id, values.clone())?;
+                    return Ok(DictionaryUpdate::Delta(written));
+                }
+            }
+        }
+        Ok(if self.insert(dict_id, array)? {
+            DictionaryUpdate::Full
+        } else {
+            DictionaryUpdate::Unchanged
+        })
+    }
 }
 
 /// Stores the encoded data, which is an ipc::Schema::Message, and optional Arrow data
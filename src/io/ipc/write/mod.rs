@@ -1,4 +1,16 @@
 //! APIs to write to Arrow's IPC format.
+//!
+//! # Determinism
+//! The bytes written by [`FileWriter`] and [`StreamWriter`] only depend on the schema, the
+//! [`IpcField`]s, the [`WriteOptions`] and the written chunks, so that writing the same data
+//! twice results in byte-identical output (e.g. for content-addressed storage or snapshot tests):
+//! * when no [`IpcField`]s are declared, dictionary ids are assigned by [`default_ipc_fields`] in
+//!   the order of the schema's fields;
+//! * padding bytes and the bits of validity bitmaps beyond the length of their arrays are unset,
+//!   also for sliced arrays;
+//! * no timestamps or other environment-dependent values are written.
+//!
+//! The values of null slots are written as they are in the arrays.
 pub(crate) mod common;
 mod schema;
 mod serialize;
@@ -43,12 +55,13 @@ fn default_ipc_field(data_type: &DataType, current_id: &mut i64) -> IpcField {
                 .collect(),
             dictionary_id: None,
         },
-        // dictionary => current_id
+        // dictionary => current_id, followed by the ids of the dictionaries in its values.
+        // Like in IPC's schema, the children are the children of its values.
         Dictionary(_, data_type, _) => {
             let dictionary_id = Some(*current_id);
             *current_id += 1;
             IpcField {
-                fields: vec![default_ipc_field(data_type, current_id)],
+                fields: default_ipc_field(data_type, current_id).fields,
                 dictionary_id,
             }
         }
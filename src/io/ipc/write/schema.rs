@@ -36,27 +36,32 @@ pub fn serialize_schema(schema: &Schema, ipc_fields: &[IpcField]) -> arrow_forma
         .map(|(field, ipc_field)| serialize_field(field, ipc_field))
         .collect::<Vec<_>>();
 
-    let mut custom_metadata = vec![];
-    for (key, value) in &schema.metadata {
-        custom_metadata.push(arrow_format::ipc::KeyValue {
-            key: Some(key.clone()),
-            value: Some(value.clone()),
-        });
-    }
-    let custom_metadata = if custom_metadata.is_empty() {
-        None
-    } else {
-        Some(custom_metadata)
-    };
-
     arrow_format::ipc::Schema {
         endianness,
         fields: Some(fields),
-        custom_metadata,
+        custom_metadata: serialize_custom_metadata(&schema.metadata),
         features: None, // todo add this one
     }
 }
 
+/// Serializes [`Metadata`] into IPC key-values, or `None` when it is empty
+pub(crate) fn serialize_custom_metadata(
+    metadata: &Metadata,
+) -> Option<Vec<arrow_format::ipc::KeyValue>> {
+    if metadata.is_empty() {
+        return None;
+    }
+    Some(
+        metadata
+            .iter()
+            .map(|(key, value)| arrow_format::ipc::KeyValue {
+                key: Some(key.clone()),
+                value: Some(value.clone()),
+            })
+            .collect(),
+    )
+}
+
 fn write_metadata(metadata: &Metadata, kv_vec: &mut Vec<arrow_format::ipc::KeyValue>) {
     for (k, v) in metadata {
         if k != "ARROW:extension:name" && k != "ARROW:extension:metadata" {
@@ -2,7 +2,7 @@ use arrow_format::ipc;
 
 use crate::{
     array::*,
-    bitmap::Bitmap,
+    bitmap::{utils::extend_aligned, Bitmap},
     datatypes::{DataType, PhysicalType},
     trusted_len::TrustedLen,
     types::NativeType,
@@ -674,13 +674,21 @@ fn write_bitmap(
         Some(bitmap) => {
             assert_eq!(bitmap.len(), length);
             let (slice, slice_offset, _) = bitmap.as_slice();
-            if slice_offset != 0 {
-                // case where we can't slice the bitmap as the offsets are not multiple of 8
-                let bytes = Bitmap::from_trusted_len_iter(bitmap.iter());
-                let (slice, _, _) = bytes.as_slice();
+            if slice_offset == 0 && length & 7 == 0 {
                 write_bytes(slice, buffers, arrow_data, offset, compression)
+            } else if compression.is_some() {
+                // the offset is not a multiple of 8 or the last byte has bits beyond `length`:
+                // realign the bits and unset the trailing ones before compressing them, so that
+                // the output does not depend on bits outside of the bitmap
+                let mut bytes = vec![];
+                extend_aligned(&mut bytes, slice, slice_offset, length);
+                write_bytes(&bytes, buffers, arrow_data, offset, compression)
             } else {
-                write_bytes(slice, buffers, arrow_data, offset, compression)
+                // the offset is not a multiple of 8 or the last byte has bits beyond `length`:
+                // realign the bits and unset the trailing ones directly into `arrow_data`
+                let start = arrow_data.len();
+                extend_aligned(arrow_data, slice, slice_offset, length);
+                buffers.push(finish_buffer(arrow_data, start, offset));
             }
         }
         None => {
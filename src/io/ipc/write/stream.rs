@@ -7,7 +7,7 @@ use std::io::Write;
 use std::sync::Arc;
 
 use super::super::IpcField;
-use super::common::{encode_chunk, DictionaryTracker, EncodedData, WriteOptions};
+use super::common::{encode_chunk_with_metadata, DictionaryTracker, EncodedData, WriteOptions};
 use super::common_sync::{write_continuation, write_message};
 use super::{default_ipc_fields, schema_to_bytes};
 
@@ -47,6 +47,14 @@ impl<W: Write> StreamWriter<W> {
         }
     }
 
+    /// Sets whether dictionaries are written as deltas (dictionary batches with `isDelta` set)
+    /// when their values only append values to the dictionary previously written with the
+    /// same id, in which case only the appended values are written. Defaults to `false`, in which
+    /// case the whole dictionary is written again.
+    pub fn set_dictionary_deltas(&mut self, deltas: bool) {
+        self.dictionary_tracker.set_deltas(deltas);
+    }
+
     /// Starts the stream by writing a Schema message to it.
     /// Use `ipc_fields` to declare dictionary ids in the schema, for dictionary-reuse
     pub fn start(&mut self, schema: &Schema, ipc_fields: Option<Vec<IpcField>>) -> Result<()> {
@@ -69,6 +77,17 @@ impl<W: Write> StreamWriter<W> {
         &mut self,
         columns: &Chunk<Arc<dyn Array>>,
         ipc_fields: Option<&[IpcField]>,
+    ) -> Result<()> {
+        self.write_with_metadata(columns, ipc_fields, &Metadata::default())
+    }
+
+    /// Writes [`Chunk`] to the stream with `custom_metadata` in its record batch's message.
+    /// Readers expose it via [`StreamReader::custom_metadata`](crate::io::ipc::read::StreamReader::custom_metadata).
+    pub fn write_with_metadata(
+        &mut self,
+        columns: &Chunk<Arc<dyn Array>>,
+        ipc_fields: Option<&[IpcField]>,
+        custom_metadata: &Metadata,
     ) -> Result<()> {
         if self.finished {
             return Err(ArrowError::Io(std::io::Error::new(
@@ -81,11 +100,12 @@ impl<W: Write> StreamWriter<W> {
         #[allow(clippy::or_fun_call)]
         let fields = ipc_fields.unwrap_or(self.ipc_fields.as_ref().unwrap());
 
-        let (encoded_dictionaries, encoded_message) = encode_chunk(
+        let (encoded_dictionaries, encoded_message) = encode_chunk_with_metadata(
             columns,
             fields,
             &mut self.dictionary_tracker,
             &self.write_options,
+            custom_metadata,
         )?;
 
         for encoded_dictionary in encoded_dictionaries {
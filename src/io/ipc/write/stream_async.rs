@@ -2,7 +2,7 @@
 
 use std::{pin::Pin, task::Poll};
 
-use futures::{future::BoxFuture, AsyncWrite, FutureExt, Sink};
+use futures::{future::BoxFuture, AsyncWrite, AsyncWriteExt, FutureExt, Sink};
 
 use super::super::IpcField;
 pub use super::common::WriteOptions;
@@ -16,6 +16,14 @@ use crate::error::{ArrowError, Result};
 /// A sink that writes array [`chunks`](Chunk) as an IPC stream.
 ///
 /// The stream header is automatically written before writing the first chunk.
+/// It accepts [`Record`]s, which [`Chunk`]s (optionally with their [`IpcField`]s) convert
+/// into, so that it can be plugged into stream pipelines (e.g. via
+/// [`futures::StreamExt::forward`]).
+///
+/// At most one chunk is written at a time: [`Sink::poll_ready`] is only ready once the
+/// previous chunk was written, which applies backpressure to the producer.
+/// [`Sink::poll_flush`] flushes the underlying writer and [`Sink::poll_close`] writes the
+/// end of the stream and closes it.
 ///
 /// # Examples
 ///
@@ -54,6 +62,8 @@ pub struct StreamSink<'a, W: AsyncWrite + Unpin + Send + 'a> {
     options: WriteOptions,
     dictionary_tracker: DictionaryTracker,
     fields: Vec<IpcField>,
+    // whether the end of the stream was written
+    finished: bool,
 }
 
 impl<'a, W> StreamSink<'a, W>
@@ -75,9 +85,16 @@ where
             fields,
             dictionary_tracker: DictionaryTracker::new(false),
             options: write_options,
+            finished: false,
         }
     }
 
+    /// Returns the underlying writer, or `None` if a chunk is still being written to it
+    /// or writing to it failed.
+    pub fn into_inner(self) -> Option<W> {
+        self.writer
+    }
+
     fn start(
         mut writer: W,
         schema: &Schema,
@@ -95,6 +112,11 @@ where
     }
 
     fn write(&mut self, record: Record<'_>) -> Result<()> {
+        if self.finished {
+            return Err(ArrowError::InvalidArgumentError(
+                "Cannot write to a closed StreamSink".to_string(),
+            ));
+        }
         let fields = record.fields().unwrap_or(&self.fields[..]);
         let (dictionaries, message) = encode_chunk(
             record.columns(),
@@ -157,27 +179,34 @@ where
     }
 
     fn poll_flush(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Result<()>> {
-        self.get_mut().poll_complete(cx)
+        let this = self.get_mut();
+        futures::ready!(this.poll_complete(cx))?;
+        if let Some(writer) = &mut this.writer {
+            Pin::new(writer).poll_flush(cx).map_err(ArrowError::from)
+        } else {
+            Poll::Ready(Ok(()))
+        }
     }
 
     fn poll_close(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Result<()>> {
         let this = self.get_mut();
-        match this.poll_complete(cx) {
-            Poll::Ready(Ok(())) => {
-                if let Some(mut writer) = this.writer.take() {
-                    this.task = Some(
-                        async move {
-                            write_continuation(&mut writer, 0).await?;
-                            Ok(None)
-                        }
-                        .boxed(),
-                    );
-                    this.poll_complete(cx)
-                } else {
-                    Poll::Ready(Ok(()))
+        futures::ready!(this.poll_complete(cx))?;
+        if this.finished {
+            return Poll::Ready(Ok(()));
+        }
+        if let Some(mut writer) = this.writer.take() {
+            this.finished = true;
+            this.task = Some(
+                async move {
+                    write_continuation(&mut writer, 0).await?;
+                    writer.close().await?;
+                    Ok(Some(writer))
                 }
-            }
-            res => res,
+                .boxed(),
+            );
+            this.poll_complete(cx)
+        } else {
+            Poll::Ready(Ok(()))
         }
     }
 }
@@ -1,10 +1,14 @@
-use std::{io::Write, sync::Arc};
+use std::{
+    io::{Read, Seek, SeekFrom, Write},
+    sync::Arc,
+};
 
 use arrow_format::ipc::planus::Builder;
 
 use super::{
+    super::read::read_file_metadata,
     super::IpcField,
-    super::ARROW_MAGIC,
+    super::{ARROW_MAGIC, CONTINUATION_MARKER},
     common::{encode_chunk, DictionaryTracker, EncodedData, WriteOptions},
     common_sync::{write_continuation, write_message},
     default_ipc_fields, schema, schema_to_bytes,
@@ -34,6 +38,8 @@ pub struct FileWriter<W: Write> {
     finished: bool,
     /// Keeps track of dictionaries that have been written
     dictionary_tracker: DictionaryTracker,
+    /// The custom metadata written to the footer
+    custom_metadata: Metadata,
 }
 
 impl<W: Write> FileWriter<W> {
@@ -71,6 +77,7 @@ impl<W: Write> FileWriter<W> {
             record_blocks: vec![],
             finished: false,
             dictionary_tracker: DictionaryTracker::new(true),
+            custom_metadata: Metadata::default(),
         })
     }
 
@@ -79,7 +86,18 @@ impl<W: Write> FileWriter<W> {
         self.writer
     }
 
+    /// Sets the custom metadata written to the footer of the file by [`FileWriter::finish`].
+    /// Unlike the schema's metadata, it can be set after the chunks are written
+    /// (e.g. to record statistics about them).
+    pub fn set_custom_metadata(&mut self, custom_metadata: Metadata) {
+        self.custom_metadata = custom_metadata;
+    }
+
     /// Writes [`Chunk`] to the file
+    /// # Errors
+    /// Errors iff a dictionary of `columns` has different values than the dictionary with the
+    /// same id written by a previous chunk, since the IPC file format does not support dictionary
+    /// replacement. In this case nothing is written and the writer can still be used.
     pub fn write(
         &mut self,
         columns: &Chunk<Arc<dyn Array>>,
@@ -142,7 +160,7 @@ impl<W: Write> FileWriter<W> {
             schema: Some(Box::new(schema)),
             dictionaries: Some(std::mem::take(&mut self.dictionary_blocks)),
             record_batches: Some(std::mem::take(&mut self.record_blocks)),
-            custom_metadata: None,
+            custom_metadata: schema::serialize_custom_metadata(&self.custom_metadata),
         };
         let mut builder = Builder::new();
         let footer_data = builder.finish(&root, None);
@@ -156,3 +174,58 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 }
+
+impl<W: Read + Seek + Write> FileWriter<W> {
+    /// Opens an existing Arrow file so that new [`Chunk`]s can be appended to it.
+    ///
+    /// The schema, record blocks and dictionaries are read from the file's footer.
+    /// New chunks are written over the end-of-stream marker and the footer, which are
+    /// rewritten by [`FileWriter::finish`]; the file is invalid until then.
+    /// # Errors
+    /// Errors iff `writer` is not a valid Arrow file.
+    pub fn try_append(mut writer: W, options: WriteOptions) -> Result<Self> {
+        let metadata = read_file_metadata(&mut writer)?;
+
+        // the footer is followed by its length (4 bytes) and the magic (6 bytes)
+        let mut footer_size = [0u8; 4];
+        let file_len = writer.seek(SeekFrom::End(-10))? + 10;
+        writer.read_exact(&mut footer_size)?;
+        let footer_start = (file_len - 10)
+            .checked_sub(i32::from_le_bytes(footer_size) as u64)
+            .ok_or_else(|| ArrowError::OutOfSpec("The footer length is invalid".to_string()))?;
+
+        // the footer is preceded by the end-of-stream marker, either
+        // the continuation marker followed by 0 or (legacy) a single 0
+        let mut eos = [0u8; 8];
+        let eos_start = footer_start.checked_sub(8).ok_or_else(|| {
+            ArrowError::OutOfSpec("The file does not contain an end-of-stream marker".to_string())
+        })?;
+        writer.seek(SeekFrom::Start(eos_start))?;
+        writer.read_exact(&mut eos)?;
+        let end = if eos[..4] == CONTINUATION_MARKER && eos[4..] == [0, 0, 0, 0] {
+            eos_start
+        } else if eos[4..] == [0, 0, 0, 0] {
+            footer_start - 4
+        } else {
+            return Err(ArrowError::OutOfSpec(
+                "The file does not contain an end-of-stream marker".to_string(),
+            ));
+        };
+        writer.seek(SeekFrom::Start(end))?;
+
+        let dictionary_tracker =
+            DictionaryTracker::with_dictionaries(metadata.dictionaries().clone(), true);
+        Ok(Self {
+            writer,
+            options,
+            schema: metadata.schema,
+            ipc_fields: metadata.ipc_schema.fields,
+            block_offsets: end as usize,
+            dictionary_blocks: metadata.dictionary_blocks,
+            record_blocks: metadata.blocks,
+            finished: false,
+            dictionary_tracker,
+            custom_metadata: metadata.custom_metadata,
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
 use std::hash::Hasher;
 use std::{collections::hash_map::DefaultHasher, sync::Arc};
 
@@ -12,7 +12,10 @@ use crate::{
     bitmap::MutableBitmap,
     datatypes::{DataType, IntervalUnit},
     error::ArrowError,
-    types::NativeType,
+    types::{
+        decimal::{self, RoundingMode},
+        NativeType,
+    },
 };
 
 /// A function that converts a &Value into an optional tuple of a byte slice and a Value.
@@ -21,24 +24,6 @@ type Extract = Box<dyn Fn(&Value) -> Option<(u64, &Value)>>;
 
 fn build_extract(data_type: &DataType) -> Extract {
     match data_type {
-        DataType::Utf8 | DataType::LargeUtf8 => Box::new(|value| match &value {
-            Value::String(v) => {
-                let mut hasher = DefaultHasher::new();
-                hasher.write(v.as_bytes());
-                Some((hasher.finish(), value))
-            }
-            Value::Number(v) => v.as_f64().map(|x| {
-                let mut hasher = DefaultHasher::new();
-                hasher.write(&x.to_le_bytes());
-                (hasher.finish(), value)
-            }),
-            Value::Bool(v) => {
-                let mut hasher = DefaultHasher::new();
-                hasher.write(&[*v as u8]);
-                Some((hasher.finish(), value))
-            }
-            _ => None,
-        }),
         DataType::Int32 | DataType::Int64 | DataType::Int16 | DataType::Int8 => {
             Box::new(move |value| match &value {
                 Value::Number(v) => v.as_f64().map(|x| {
@@ -90,55 +75,93 @@ fn deserialize_float<T: NativeType + NumCast, A: Borrow<Value>>(
     PrimitiveArray::from_trusted_len_iter(iter).to(data_type)
 }
 
-fn deserialize_binary<O: Offset, A: Borrow<Value>>(rows: &[A]) -> BinaryArray<O> {
+fn deserialize_decimal<A: Borrow<Value>>(
+    rows: &[A],
+    data_type: DataType,
+    precision: usize,
+    scale: usize,
+) -> PrimitiveArray<i128> {
+    let parse =
+        |x: &str| decimal::parse_decimal(x.as_bytes(), precision, scale, RoundingMode::Reject);
+    let iter = rows.iter().map(|row| match row.borrow() {
+        // numbers are represented as `f64` and thus may have lost precision
+        Value::Number(number) => parse(&number.to_string()),
+        Value::String(string) => parse(string),
+        _ => None,
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(data_type)
+}
+
+fn deserialize_binary<O: Offset, A: Borrow<Value>>(
+    rows: &[A],
+) -> Result<BinaryArray<O>, ArrowError> {
     let iter = rows.iter().map(|row| match row.borrow() {
         Value::String(v) => Some(v.as_bytes()),
         _ => None,
     });
-    BinaryArray::from_trusted_len_iter(iter)
+    let mut array = MutableBinaryArray::<O>::with_capacity(rows.len());
+    array
+        .try_extend(iter)
+        .map_err(|_| ArrowError::offset_overflow(array.data_type()))?;
+    Ok(array.into())
 }
 
-fn deserialize_utf8<O: Offset, A: Borrow<Value>>(rows: &[A]) -> Utf8Array<O> {
+fn deserialize_utf8<O: Offset, A: Borrow<Value>>(rows: &[A]) -> Result<Utf8Array<O>, ArrowError> {
     let iter = rows.iter().map(|row| match row.borrow() {
-        Value::String(v) => Some(v.clone()),
-        Value::Number(v) => Some(v.to_string()),
-        Value::Bool(v) => Some(v.to_string()),
+        Value::String(v) => Some(Cow::Borrowed(v.as_str())),
+        Value::Number(v) => Some(Cow::Owned(v.to_string())),
+        Value::Bool(v) => Some(Cow::Owned(v.to_string())),
         _ => None,
     });
-    Utf8Array::<O>::from_trusted_len_iter(iter)
+    let mut array = MutableUtf8Array::<O>::with_capacity(rows.len());
+    array
+        .try_extend(iter)
+        .map_err(|_| ArrowError::offset_overflow(array.data_type()))?;
+    Ok(array.into())
 }
 
-fn deserialize_list<O: Offset, A: Borrow<Value>>(rows: &[A], data_type: DataType) -> ListArray<O> {
+fn deserialize_list<O: Offset, A: Borrow<Value>>(
+    rows: &[A],
+    data_type: DataType,
+) -> Result<ListArray<O>, ArrowError> {
     let child = ListArray::<O>::get_child_type(&data_type);
 
     let mut validity = MutableBitmap::with_capacity(rows.len());
     let mut offsets = Vec::<O>::with_capacity(rows.len() + 1);
     let mut inner = vec![];
     offsets.push(O::zero());
-    rows.iter().fold(O::zero(), |mut length, row| {
+    rows.iter().try_fold(O::zero(), |mut length, row| {
         match row.borrow() {
             Value::Array(value) => {
                 inner.extend(value.iter());
                 validity.push(true);
-                // todo make this an Err
-                length += O::from_usize(value.len()).expect("List offset is too large :/");
+                length = O::from_usize(value.len())
+                    .and_then(|len| length.checked_add(&len))
+                    .ok_or_else(|| ArrowError::offset_overflow(&data_type))?;
                 offsets.push(length);
-                length
             }
             _ => {
                 validity.push(false);
                 offsets.push(length);
-                length
             }
-        }
-    });
+        };
+        Ok::<_, ArrowError>(length)
+    })?;
 
-    let values = _deserialize(&inner, child.clone());
+    let values = _deserialize(&inner, child.clone())?;
 
-    ListArray::<O>::new(data_type, offsets.into(), values, validity.into())
+    Ok(ListArray::<O>::new(
+        data_type,
+        offsets.into(),
+        values,
+        validity.into(),
+    ))
 }
 
-fn deserialize_struct<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> StructArray {
+fn deserialize_struct<A: Borrow<Value>>(
+    rows: &[A],
+    data_type: DataType,
+) -> Result<StructArray, ArrowError> {
     let fields = StructArray::get_fields(&data_type);
 
     let mut values = fields
@@ -164,16 +187,37 @@ fn deserialize_struct<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Stru
     let values = values
         .into_iter()
         .map(|(_, (data_type, values))| _deserialize(&values, data_type.clone()))
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    Ok(StructArray::new(data_type, values, None))
+}
+
+/// Deserializes `rows` into a dictionary of strings, interning each string as it is read.
+fn deserialize_utf8_dictionary<K: DictionaryKey, O: Offset, A: Borrow<Value>>(
+    rows: &[A],
+) -> Result<DictionaryArray<K>, ArrowError> {
+    let iter = rows.iter().map(|row| match row.borrow() {
+        Value::String(v) => Some(Cow::Borrowed(v.as_str())),
+        Value::Number(v) => Some(Cow::Owned(v.to_string())),
+        Value::Bool(v) => Some(Cow::Owned(v.to_string())),
+        _ => None,
+    });
 
-    StructArray::new(data_type, values, None)
+    let mut array = MutableDictionaryArray::<K, MutableUtf8Array<O>>::new();
+    array.try_extend(iter)?;
+    Ok(array.into())
 }
 
 fn deserialize_dictionary<K: DictionaryKey, A: Borrow<Value>>(
     rows: &[A],
     data_type: DataType,
-) -> DictionaryArray<K> {
+) -> Result<DictionaryArray<K>, ArrowError> {
     let child = DictionaryArray::<K>::get_child(&data_type);
+    match child {
+        DataType::Utf8 => return deserialize_utf8_dictionary::<K, i32, _>(rows),
+        DataType::LargeUtf8 => return deserialize_utf8_dictionary::<K, i64, _>(rows),
+        _ => {}
+    }
 
     let mut map = HashedMap::<u64, K>::default();
 
@@ -198,12 +242,15 @@ fn deserialize_dictionary<K: DictionaryKey, A: Borrow<Value>>(
         })
         .collect::<PrimitiveArray<K>>();
 
-    let values = _deserialize(&inner, child.clone());
-    DictionaryArray::<K>::from_data(keys, values)
+    let values = _deserialize(&inner, child.clone())?;
+    Ok(DictionaryArray::<K>::from_data(keys, values))
 }
 
-pub(crate) fn _deserialize<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Arc<dyn Array> {
-    match &data_type {
+pub(crate) fn _deserialize<A: Borrow<Value>>(
+    rows: &[A],
+    data_type: DataType,
+) -> Result<Arc<dyn Array>, ArrowError> {
+    Ok(match &data_type {
         DataType::Null => Arc::new(NullArray::new(data_type, rows.len())),
         DataType::Boolean => Arc::new(deserialize_boolean(rows)),
         DataType::Int8 => Arc::new(deserialize_int::<i8, _>(rows, data_type)),
@@ -229,25 +276,30 @@ pub(crate) fn _deserialize<A: Borrow<Value>>(rows: &[A], data_type: DataType) ->
         DataType::Float16 => unreachable!(),
         DataType::Float32 => Arc::new(deserialize_float::<f32, _>(rows, data_type)),
         DataType::Float64 => Arc::new(deserialize_float::<f64, _>(rows, data_type)),
-        DataType::Utf8 => Arc::new(deserialize_utf8::<i32, _>(rows)),
-        DataType::LargeUtf8 => Arc::new(deserialize_utf8::<i64, _>(rows)),
-        DataType::List(_) => Arc::new(deserialize_list::<i32, _>(rows, data_type)),
-        DataType::LargeList(_) => Arc::new(deserialize_list::<i64, _>(rows, data_type)),
-        DataType::Binary => Arc::new(deserialize_binary::<i32, _>(rows)),
-        DataType::LargeBinary => Arc::new(deserialize_binary::<i64, _>(rows)),
-        DataType::Struct(_) => Arc::new(deserialize_struct(rows, data_type)),
+        DataType::Decimal(precision, scale) => Arc::new(deserialize_decimal(
+            rows,
+            data_type.clone(),
+            *precision,
+            *scale,
+        )),
+        DataType::Utf8 => Arc::new(deserialize_utf8::<i32, _>(rows)?),
+        DataType::LargeUtf8 => Arc::new(deserialize_utf8::<i64, _>(rows)?),
+        DataType::List(_) => Arc::new(deserialize_list::<i32, _>(rows, data_type)?),
+        DataType::LargeList(_) => Arc::new(deserialize_list::<i64, _>(rows, data_type)?),
+        DataType::Binary => Arc::new(deserialize_binary::<i32, _>(rows)?),
+        DataType::LargeBinary => Arc::new(deserialize_binary::<i64, _>(rows)?),
+        DataType::Struct(_) => Arc::new(deserialize_struct(rows, data_type)?),
         DataType::Dictionary(key_type, _, _) => {
             match_integer_type!(key_type, |$T| {
-                Arc::new(deserialize_dictionary::<$T, _>(rows, data_type))
+                Arc::new(deserialize_dictionary::<$T, _>(rows, data_type)?)
             })
         }
         _ => todo!(),
         /*
         DataType::FixedSizeBinary(_) => Box::new(FixedSizeBinaryArray::new_empty(data_type)),
         DataType::FixedSizeList(_, _) => Box::new(FixedSizeListArray::new_empty(data_type)),
-        DataType::Decimal(_, _) => Box::new(PrimitiveArray::<i128>::new_empty(data_type)),
         */
-    }
+    })
 }
 
 /// Deserializes a `json` [`Value`] into an [`Array`] of [`DataType`]
@@ -256,11 +308,13 @@ pub(crate) fn _deserialize<A: Borrow<Value>>(rows: &[A], data_type: DataType) ->
 /// This function errors iff either:
 /// * `json` is not a [`Value::Array`]
 /// * `data_type` is neither [`DataType::List`] nor [`DataType::LargeList`]
+/// * the values exceed the maximum offset of their data type (e.g. more than 2GB of
+///   [`DataType::Utf8`] values)
 pub fn deserialize(json: &Value, data_type: DataType) -> Result<Arc<dyn Array>, ArrowError> {
     match json {
         Value::Array(rows) => match data_type {
             DataType::List(inner) | DataType::LargeList(inner) => {
-                Ok(_deserialize(rows, inner.data_type))
+                _deserialize(rows, inner.data_type)
             }
             _ => Err(ArrowError::nyi("read an Array from a non-Array data type")),
         },
@@ -5,7 +5,7 @@ use indexmap::set::IndexSet as HashSet;
 use serde_json::Value;
 
 use crate::datatypes::*;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 
 const ITEM_NAME: &str = "item";
 
@@ -154,6 +154,90 @@ pub(crate) fn coerce_data_type<A: Borrow<DataType>>(datatypes: &[A]) -> DataType
     };
 }
 
+fn dictionary_encode(data_type: &DataType, key_type: IntegerType) -> Option<DataType> {
+    match data_type {
+        DataType::Utf8 | DataType::LargeUtf8 => Some(DataType::Dictionary(
+            key_type,
+            Box::new(data_type.clone()),
+            false,
+        )),
+        DataType::List(inner) => dictionary_encode(inner.data_type(), key_type).map(|data_type| {
+            let mut inner = inner.as_ref().clone();
+            inner.data_type = data_type;
+            DataType::List(Box::new(inner))
+        }),
+        DataType::LargeList(inner) => {
+            dictionary_encode(inner.data_type(), key_type).map(|data_type| {
+                let mut inner = inner.as_ref().clone();
+                inner.data_type = data_type;
+                DataType::LargeList(Box::new(inner))
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Marks the fields `names` of a [`DataType::Struct`] (e.g. the one returned by [`infer`]) as
+/// low-cardinality, so that they are deserialized into [`DictionaryArray`](crate::array::DictionaryArray)s
+/// with keys of `key_type` whose strings are interned while deserializing.
+///
+/// Fields of strings become [`DataType::Dictionary`] of strings; lists of strings become lists
+/// of dictionaries.
+/// # Example
+/// ```
+/// use arrow2::datatypes::{DataType, Field, IntegerType};
+/// use arrow2::io::json::read::with_dictionary_fields;
+///
+/// let data_type = DataType::Struct(vec![
+///     Field::new("id", DataType::Int64, true),
+///     Field::new("country", DataType::Utf8, true),
+/// ]);
+/// let data_type = with_dictionary_fields(data_type, &["country"], IntegerType::UInt8).unwrap();
+///
+/// let expected = DataType::Struct(vec![
+///     Field::new("id", DataType::Int64, true),
+///     Field::new(
+///         "country",
+///         DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Utf8), false),
+///         true,
+///     ),
+/// ]);
+/// assert_eq!(data_type, expected);
+/// ```
+/// # Errors
+/// Errors iff `data_type` is not a [`DataType::Struct`], or a field in `names` does not exist
+/// or is neither of strings nor of lists of strings.
+pub fn with_dictionary_fields(
+    data_type: DataType,
+    names: &[&str],
+    key_type: IntegerType,
+) -> Result<DataType> {
+    let mut fields = match data_type {
+        DataType::Struct(fields) => fields,
+        other => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Only fields of a Struct can be dictionary-encoded, not of {:?}",
+                other
+            )))
+        }
+    };
+    for name in names {
+        let field = fields
+            .iter_mut()
+            .find(|field| field.name == *name)
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!("The field \"{}\" does not exist", name))
+            })?;
+        field.data_type = dictionary_encode(&field.data_type, key_type).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The field \"{}\" of type {:?} can't be dictionary-encoded",
+                name, field.data_type
+            ))
+        })?;
+    }
+    Ok(DataType::Struct(fields))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
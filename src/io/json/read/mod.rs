@@ -5,4 +5,4 @@ mod infer_schema;
 pub(crate) use deserialize::_deserialize;
 pub use deserialize::deserialize;
 pub(crate) use infer_schema::coerce_data_type;
-pub use infer_schema::infer;
+pub use infer_schema::{infer, with_dictionary_fields};
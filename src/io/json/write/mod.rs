@@ -3,10 +3,37 @@ mod serialize;
 
 pub use fallible_streaming_iterator::*;
 pub(crate) use serialize::new_serializer;
-use serialize::serialize;
+pub(crate) use serialize::serialize;
+
+use std::io::Write;
 
 use crate::{array::Array, error::ArrowError};
 
+use serialize::{new_line, new_serializer_ordered, write_pretty};
+
+/// The order in which the fields of structs are written to JSON objects
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum FieldOrder {
+    /// The order in which the fields are declared in the [`DataType::Struct`](crate::datatypes::DataType::Struct)
+    #[default]
+    Declared,
+    /// Sorted by name
+    Sorted,
+    /// The fields with the given names first, in that order, followed by the remaining
+    /// fields in their declared order. Names without a field are ignored.
+    Custom(Vec<String>),
+}
+
+/// Options to write JSON
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WriteOptions {
+    /// Whether to write every item and field in its own line, indented by two spaces per
+    /// level of nesting
+    pub pretty: bool,
+    /// The order of the fields of structs, at every level of nesting
+    pub field_order: FieldOrder,
+}
+
 /// [`FallibleStreamingIterator`] that serializes an [`Array`] to bytes of valid JSON
 /// # Implementation
 /// Advancing this iterator CPU-bounded
@@ -76,3 +103,82 @@ where
     writer.write_all(&[b']'])?;
     Ok(())
 }
+
+/// Writes a JSON array whose items are the items of [`Array`]s, one item at a time.
+///
+/// Unlike [`Serializer`], which serializes whole arrays into a buffer, only one item is held in
+/// memory at a time, so arbitrarily large arrays can be written. Arrays of
+/// [`DataType::Struct`](crate::datatypes::DataType::Struct) are written as an array of records.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::io::json::write::{StreamWriter, WriteOptions};
+///
+/// let mut writer = StreamWriter::new(vec![], WriteOptions::default());
+/// writer.start().unwrap();
+/// writer.write(&Int32Array::from([Some(1), None])).unwrap();
+/// writer.write(&Int32Array::from_slice([3])).unwrap();
+/// writer.finish().unwrap();
+/// assert_eq!(writer.into_inner(), b"[1,null,3]");
+/// ```
+#[derive(Debug)]
+pub struct StreamWriter<W: Write> {
+    writer: W,
+    options: WriteOptions,
+    is_first_item: bool,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> StreamWriter<W> {
+    /// Creates a new [`StreamWriter`].
+    pub fn new(writer: W, options: WriteOptions) -> Self {
+        Self {
+            writer,
+            options,
+            is_first_item: true,
+            buffer: vec![],
+        }
+    }
+
+    /// Writes the start of the JSON array. Must be called before [`StreamWriter::write`].
+    pub fn start(&mut self) -> Result<(), ArrowError> {
+        self.writer.write_all(b"[")?;
+        Ok(())
+    }
+
+    /// Writes the items of `array` to the JSON array.
+    pub fn write(&mut self, array: &dyn Array) -> Result<(), ArrowError> {
+        let mut serializer = new_serializer_ordered(array, &self.options.field_order);
+        for _ in 0..array.len() {
+            // `unwrap` is infalible because the serializer has `array.len()` items
+            let item = serializer.next().unwrap();
+            self.buffer.clear();
+            if !self.is_first_item {
+                self.buffer.push(b',');
+            }
+            self.is_first_item = false;
+            if self.options.pretty {
+                new_line(&mut self.buffer, 1);
+                write_pretty(item, 1, &mut self.buffer);
+            } else {
+                self.buffer.extend_from_slice(item);
+            }
+            self.writer.write_all(&self.buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the end of the JSON array.
+    pub fn finish(&mut self) -> Result<(), ArrowError> {
+        if self.options.pretty && !self.is_first_item {
+            self.writer.write_all(b"\n")?;
+        }
+        self.writer.write_all(b"]")?;
+        Ok(())
+    }
+
+    /// Consumes this [`StreamWriter`], returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
@@ -11,7 +11,21 @@ use crate::temporal_conversions::{
     timestamp_s_to_datetime, timestamp_us_to_datetime,
 };
 use crate::util::lexical_to_bytes_mut;
-use crate::{array::*, datatypes::DataType, types::NativeType};
+use crate::{
+    array::*,
+    datatypes::{DataType, Field},
+    types::{decimal, NativeType},
+};
+
+use super::FieldOrder;
+
+fn null_serializer(len: usize) -> Box<dyn StreamingIterator<Item = [u8]> + Send + Sync> {
+    Box::new(BufStreamingIterator::new(
+        0..len,
+        |_, buf| buf.extend_from_slice(b"null"),
+        vec![],
+    ))
+}
 
 fn boolean_serializer<'a>(
     array: &'a BooleanArray,
@@ -43,6 +57,23 @@ fn primitive_serializer<'a, T: NativeType + ToLexical>(
     ))
 }
 
+fn decimal_serializer<'a>(
+    array: &'a PrimitiveArray<i128>,
+    scale: usize,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    Box::new(BufStreamingIterator::new(
+        array.iter(),
+        move |x, buf| {
+            if let Some(x) = x {
+                decimal::write_decimal(*x, scale, buf)
+            } else {
+                buf.extend(b"null")
+            }
+        },
+        vec![],
+    ))
+}
+
 fn utf8_serializer<'a, O: Offset>(
     array: &'a Utf8Array<O>,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
@@ -59,8 +90,31 @@ fn utf8_serializer<'a, O: Offset>(
     ))
 }
 
+/// Returns the indices of `fields` in the order they are written.
+fn ordered_fields(fields: &[Field], order: &FieldOrder) -> Vec<usize> {
+    match order {
+        FieldOrder::Declared => (0..fields.len()).collect(),
+        FieldOrder::Sorted => {
+            let mut indices = (0..fields.len()).collect::<Vec<_>>();
+            indices.sort_by_key(|i| fields[*i].name.as_str());
+            indices
+        }
+        FieldOrder::Custom(names) => {
+            let mut is_ordered = vec![false; fields.len()];
+            let mut indices = names
+                .iter()
+                .filter_map(|name| fields.iter().position(|field| &field.name == name))
+                .filter(|i| !std::mem::replace(&mut is_ordered[*i], true))
+                .collect::<Vec<_>>();
+            indices.extend((0..fields.len()).filter(|i| !is_ordered[*i]));
+            indices
+        }
+    }
+}
+
 fn struct_serializer<'a>(
     array: &'a StructArray,
+    order: &'a FieldOrder,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     // {"a": [1, 2, 3], "b": [a, b, c], "c": {"a": [1, 2, 3]}}
     // [
@@ -72,25 +126,24 @@ fn struct_serializer<'a>(
     let mut serializers = array
         .values()
         .iter()
-        .map(|x| x.as_ref())
-        .map(new_serializer)
+        .map(|x| new_serializer_ordered(x.as_ref(), order))
         .collect::<Vec<_>>();
-    let names = array.fields().iter().map(|f| f.name.as_str());
+    let fields = array.fields();
+    let ordered = ordered_fields(fields, order);
 
     Box::new(BufStreamingIterator::new(
         zip_validity(0..array.len(), array.validity().map(|x| x.iter())),
         move |maybe, buf| {
             if maybe.is_some() {
-                let names = names.clone();
-                let mut record: Vec<(&str, &[u8])> = Default::default();
-                serializers
+                // `unwrap` is infalible because `array.len()` equals `len` on `Chunk`
+                let items = serializers
                     .iter_mut()
-                    .zip(names)
-                    // `unwrap` is infalible because `array.len()` equals `len` on `Chunk`
-                    .for_each(|(iter, name)| {
-                        let item = iter.next().unwrap();
-                        record.push((name, item));
-                    });
+                    .map(|iter| iter.next().unwrap())
+                    .collect::<Vec<_>>();
+                let record = ordered
+                    .iter()
+                    .map(|i| (fields[*i].name.as_str(), items[*i]))
+                    .collect::<Vec<_>>();
                 serialize_item(buf, &record, true);
             } else {
                 serializers.iter_mut().for_each(|iter| {
@@ -103,25 +156,25 @@ fn struct_serializer<'a>(
     ))
 }
 
-fn list_serializer<'a, O: Offset>(
-    array: &'a ListArray<O>,
-) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+/// Serializes lists whose items are the next `length` items of `serializer`, for every
+/// `(length, is_valid)` of `lengths`. The items of null lists are skipped.
+fn list<'a, I>(
+    lengths: I,
+    mut serializer: Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
+where
+    I: Iterator<Item = (usize, bool)> + Send + Sync + 'a,
+{
     // [[1, 2], [3]]
     // [
     //  [1, 2],
     //  [3]
     // ]
     //
-    let mut serializer = new_serializer(array.values().as_ref());
-
     Box::new(BufStreamingIterator::new(
-        zip_validity(
-            array.offsets().windows(2),
-            array.validity().map(|x| x.iter()),
-        ),
-        move |offset, buf| {
-            if let Some(offset) = offset {
-                let length = (offset[1] - offset[0]).to_usize();
+        lengths,
+        move |(length, is_valid), buf| {
+            if is_valid {
                 buf.push(b'[');
                 let mut is_first_row = true;
                 for _ in 0..length {
@@ -133,6 +186,7 @@ fn list_serializer<'a, O: Offset>(
                 }
                 buf.push(b']');
             } else {
+                (0..length).for_each(|_| serializer.advance());
                 buf.extend(b"null");
             }
         },
@@ -140,6 +194,34 @@ fn list_serializer<'a, O: Offset>(
     ))
 }
 
+fn list_serializer<'a, O: Offset>(
+    array: &'a ListArray<O>,
+    order: &'a FieldOrder,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    let mut serializer = new_serializer_ordered(array.values().as_ref(), order);
+    // the values of sliced arrays start at the first offset
+    (0..array.offsets()[0].to_usize()).for_each(|_| serializer.advance());
+
+    let lengths = array
+        .offsets()
+        .windows(2)
+        .map(|w| (w[1] - w[0]).to_usize())
+        .enumerate()
+        .map(move |(i, length)| (length, array.is_valid(i)));
+    list(lengths, serializer)
+}
+
+fn fixed_size_list_serializer<'a>(
+    array: &'a FixedSizeListArray,
+    order: &'a FieldOrder,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    let serializer = new_serializer_ordered(array.values().as_ref(), order);
+    let size = FixedSizeListArray::get_child_and_size(array.data_type()).1;
+
+    let lengths = (0..array.len()).map(move |i| (size, array.is_valid(i)));
+    list(lengths, serializer)
+}
+
 fn date_serializer<'a, T, F>(
     array: &'a PrimitiveArray<T>,
     convert: F,
@@ -183,10 +265,20 @@ where
     ))
 }
 
-pub(crate) fn new_serializer<'a>(
+pub(crate) fn new_serializer(
+    array: &dyn Array,
+) -> Box<dyn StreamingIterator<Item = [u8]> + '_ + Send + Sync> {
+    static DECLARED: FieldOrder = FieldOrder::Declared;
+    new_serializer_ordered(array, &DECLARED)
+}
+
+/// Returns a serializer of the items of `array`, writing the fields of structs in `order`.
+pub(crate) fn new_serializer_ordered<'a>(
     array: &'a dyn Array,
+    order: &'a FieldOrder,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     match array.data_type().to_logical_type() {
+        DataType::Null => null_serializer(array.len()),
         DataType::Boolean => boolean_serializer(array.as_any().downcast_ref().unwrap()),
         DataType::Int8 => primitive_serializer::<i8>(array.as_any().downcast_ref().unwrap()),
         DataType::Int16 => primitive_serializer::<i16>(array.as_any().downcast_ref().unwrap()),
@@ -198,11 +290,19 @@ pub(crate) fn new_serializer<'a>(
         DataType::UInt64 => primitive_serializer::<u64>(array.as_any().downcast_ref().unwrap()),
         DataType::Float32 => primitive_serializer::<f32>(array.as_any().downcast_ref().unwrap()),
         DataType::Float64 => primitive_serializer::<f64>(array.as_any().downcast_ref().unwrap()),
+        DataType::Decimal(_, scale) => {
+            decimal_serializer(array.as_any().downcast_ref().unwrap(), *scale)
+        }
         DataType::Utf8 => utf8_serializer::<i32>(array.as_any().downcast_ref().unwrap()),
         DataType::LargeUtf8 => utf8_serializer::<i64>(array.as_any().downcast_ref().unwrap()),
-        DataType::Struct(_) => struct_serializer(array.as_any().downcast_ref().unwrap()),
-        DataType::List(_) => list_serializer::<i32>(array.as_any().downcast_ref().unwrap()),
-        DataType::LargeList(_) => list_serializer::<i64>(array.as_any().downcast_ref().unwrap()),
+        DataType::Struct(_) => struct_serializer(array.as_any().downcast_ref().unwrap(), order),
+        DataType::List(_) => list_serializer::<i32>(array.as_any().downcast_ref().unwrap(), order),
+        DataType::LargeList(_) => {
+            list_serializer::<i64>(array.as_any().downcast_ref().unwrap(), order)
+        }
+        DataType::FixedSizeList(_, _) => {
+            fixed_size_list_serializer(array.as_any().downcast_ref().unwrap(), order)
+        }
         DataType::Date32 => date_serializer(array.as_any().downcast_ref().unwrap(), date32_to_date),
         DataType::Date64 => date_serializer(array.as_any().downcast_ref().unwrap(), date64_to_date),
         DataType::Timestamp(tu, tz) => {
@@ -253,3 +353,59 @@ pub(crate) fn serialize(array: &dyn Array, buffer: &mut Vec<u8>) {
         buffer.extend_from_slice(serializer.next().unwrap());
     });
 }
+
+/// Writes a new line to `buffer` indented by `level`.
+pub(crate) fn new_line(buffer: &mut Vec<u8>, level: usize) {
+    buffer.push(b'\n');
+    let len = buffer.len() + 2 * level;
+    buffer.resize(len, b' ');
+}
+
+/// Writes the compact JSON `json` to `buffer` indented by two spaces per level of nesting,
+/// starting at `level`. Empty objects and arrays are kept in a single line.
+pub(crate) fn write_pretty(json: &[u8], level: usize, buffer: &mut Vec<u8>) {
+    let mut level = level;
+    let mut in_string = false;
+    let mut is_escaped = false;
+    let mut iter = json.iter().copied().peekable();
+    while let Some(byte) = iter.next() {
+        if in_string {
+            buffer.push(byte);
+            if is_escaped {
+                is_escaped = false;
+            } else if byte == b'\\' {
+                is_escaped = true;
+            } else if byte == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match byte {
+            b'"' => {
+                in_string = true;
+                buffer.push(byte);
+            }
+            b'{' | b'[' => {
+                buffer.push(byte);
+                match iter.peek() {
+                    Some(b'}') | Some(b']') => buffer.push(iter.next().unwrap()),
+                    _ => {
+                        level += 1;
+                        new_line(buffer, level);
+                    }
+                }
+            }
+            b'}' | b']' => {
+                level -= 1;
+                new_line(buffer, level);
+                buffer.push(byte);
+            }
+            b',' => {
+                buffer.push(byte);
+                new_line(buffer, level);
+            }
+            b':' => buffer.extend_from_slice(b": "),
+            _ => buffer.push(byte),
+        }
+    }
+}
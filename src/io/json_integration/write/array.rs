@@ -1,13 +1,258 @@
+use std::io::Write;
 use std::sync::Arc;
 
+use serde_json::Value;
+
 use crate::{
-    array::{Array, PrimitiveArray},
+    array::*,
     chunk::Chunk,
-    datatypes::DataType,
+    datatypes::{PhysicalType, PrimitiveType},
+    error::Result,
+    types::{days_ms, months_days_ns, NativeType},
 };
 
 use super::super::{ArrowJsonBatch, ArrowJsonColumn};
 
+/// Serializes a float, writing non-finite values (which JSON numbers can't represent)
+/// as strings.
+fn float_to_value(value: f64) -> Value {
+    if value.is_finite() {
+        value.into()
+    } else {
+        Value::String(value.to_string())
+    }
+}
+
+fn days_ms_to_value(value: days_ms) -> Value {
+    serde_json::json!({"days": value.days(), "milliseconds": value.milliseconds()})
+}
+
+fn months_days_ns_to_value(value: months_days_ns) -> Value {
+    serde_json::json!({
+        "months": value.months(),
+        "days": value.days(),
+        "nanoseconds": value.ns(),
+    })
+}
+
+fn primitive_data<T: NativeType, F: Fn(T) -> Value>(array: &dyn Array, f: F) -> Vec<Value> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    array.values().iter().map(|x| f(*x)).collect()
+}
+
+fn primitive_to_data(array: &dyn Array, primitive: PrimitiveType) -> Vec<Value> {
+    use PrimitiveType::*;
+    // 64-bit integers are written as strings since JSON numbers are not guaranteed
+    // to represent them exactly.
+    match primitive {
+        Int8 => primitive_data::<i8, _>(array, Value::from),
+        Int16 => primitive_data::<i16, _>(array, Value::from),
+        Int32 => primitive_data::<i32, _>(array, Value::from),
+        Int64 => primitive_data::<i64, _>(array, |x| Value::String(x.to_string())),
+        Int128 => primitive_data::<i128, _>(array, |x| Value::String(x.to_string())),
+        UInt8 => primitive_data::<u8, _>(array, Value::from),
+        UInt16 => primitive_data::<u16, _>(array, Value::from),
+        UInt32 => primitive_data::<u32, _>(array, Value::from),
+        UInt64 => primitive_data::<u64, _>(array, |x| Value::String(x.to_string())),
+        Float32 => primitive_data::<f32, _>(array, |x| float_to_value(x as f64)),
+        Float64 => primitive_data::<f64, _>(array, float_to_value),
+        DaysMs => primitive_data::<days_ms, _>(array, days_ms_to_value),
+        MonthDayNano => primitive_data::<months_days_ns, _>(array, months_days_ns_to_value),
+    }
+}
+
+/// Returns the offsets re-based to start at zero (as the JSON integration format expects)
+/// and the range of the values that they cover.
+fn offsets_to_value<O: Offset>(offsets: &[O]) -> (Vec<Value>, usize, usize) {
+    let start = offsets[0].to_usize();
+    let end = offsets[offsets.len() - 1].to_usize();
+    let offsets = offsets
+        .iter()
+        .map(|x| {
+            let x = x.to_usize() - start;
+            if O::is_large() {
+                Value::String(x.to_string())
+            } else {
+                Value::from(x)
+            }
+        })
+        .collect();
+    (offsets, start, end)
+}
+
+fn validity(array: &dyn Array) -> Option<Vec<u8>> {
+    Some((0..array.len()).map(|i| array.is_valid(i) as u8).collect())
+}
+
+fn column(name: String, array: &dyn Array) -> ArrowJsonColumn {
+    ArrowJsonColumn {
+        name,
+        count: array.len(),
+        validity: validity(array),
+        data: None,
+        offset: None,
+        type_id: None,
+        children: None,
+    }
+}
+
+fn serialize_binary<O: Offset>(array: &dyn Array, name: String) -> ArrowJsonColumn {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    let (offset, _, _) = offsets_to_value(array.offsets());
+    ArrowJsonColumn {
+        data: Some(
+            array
+                .values_iter()
+                .map(|x| Value::String(hex::encode(x)))
+                .collect(),
+        ),
+        offset: Some(offset),
+        ..column(name, array)
+    }
+}
+
+fn serialize_utf8<O: Offset>(array: &dyn Array, name: String) -> ArrowJsonColumn {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let (offset, _, _) = offsets_to_value(array.offsets());
+    ArrowJsonColumn {
+        data: Some(array.values_iter().map(Value::from).collect()),
+        offset: Some(offset),
+        ..column(name, array)
+    }
+}
+
+fn serialize_list<O: Offset>(array: &dyn Array, name: String) -> ArrowJsonColumn {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let child = ListArray::<O>::get_child_field(array.data_type());
+    let (offset, start, end) = offsets_to_value(array.offsets());
+    let values = array.values().slice(start, end - start);
+    ArrowJsonColumn {
+        offset: Some(offset),
+        children: Some(vec![serialize_array(values.as_ref(), child.name.clone())]),
+        ..column(name, array)
+    }
+}
+
+fn serialize_dictionary<K: DictionaryKey>(array: &dyn Array, name: String) -> ArrowJsonColumn {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    ArrowJsonColumn {
+        data: Some(primitive_to_data(array.keys(), K::PRIMITIVE)),
+        children: Some(vec![serialize_array(
+            array.values().as_ref(),
+            "dictionary".to_string(),
+        )]),
+        ..column(name, array)
+    }
+}
+
+/// Serializes an [`Array`] to an [`ArrowJsonColumn`] named `name`, slot by slot, as
+/// described by the Arrow JSON integration format.
+///
+/// Values that JSON numbers can't represent exactly (64-bit integers, decimals, non-finite
+/// floats) are written as strings. Null slots are written with the (arbitrary) value of
+/// the slot in the array.
+/// # Implementation
+/// The JSON integration format stores the values of dictionary-encoded arrays in separate
+/// dictionary batches; this function instead writes them as the only child (named
+/// `"dictionary"`) of the column, so that the column is self-contained.
+pub fn serialize_array(array: &dyn Array, name: String) -> ArrowJsonColumn {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Null => ArrowJsonColumn {
+            validity: None,
+            ..column(name, array)
+        },
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            ArrowJsonColumn {
+                data: Some(array.values().iter().map(Value::from).collect()),
+                ..column(name, array)
+            }
+        }
+        Primitive(primitive) => ArrowJsonColumn {
+            data: Some(primitive_to_data(array, primitive)),
+            ..column(name, array)
+        },
+        Binary => serialize_binary::<i32>(array, name),
+        LargeBinary => serialize_binary::<i64>(array, name),
+        Utf8 => serialize_utf8::<i32>(array, name),
+        LargeUtf8 => serialize_utf8::<i64>(array, name),
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            ArrowJsonColumn {
+                data: Some(
+                    array
+                        .values()
+                        .chunks(array.size())
+                        .map(|x| Value::String(hex::encode(x)))
+                        .collect(),
+                ),
+                ..column(name, array)
+            }
+        }
+        List => serialize_list::<i32>(array, name),
+        LargeList => serialize_list::<i64>(array, name),
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let (child, _) = FixedSizeListArray::get_child_and_size(array.data_type());
+            ArrowJsonColumn {
+                children: Some(vec![serialize_array(
+                    array.values().as_ref(),
+                    child.name.clone(),
+                )]),
+                ..column(name, array)
+            }
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let children = array
+                .fields()
+                .iter()
+                .zip(array.values())
+                .map(|(field, values)| serialize_array(values.as_ref(), field.name.clone()))
+                .collect();
+            ArrowJsonColumn {
+                children: Some(children),
+                ..column(name, array)
+            }
+        }
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            let children = UnionArray::get_fields(array.data_type())
+                .iter()
+                .zip(array.fields())
+                .map(|(field, values)| serialize_array(values.as_ref(), field.name.clone()))
+                .collect();
+            ArrowJsonColumn {
+                validity: None,
+                type_id: Some(array.types().iter().map(|x| Value::from(*x)).collect()),
+                offset: array
+                    .offsets()
+                    .map(|offsets| offsets.iter().map(|x| Value::from(*x)).collect()),
+                children: Some(children),
+                ..column(name, array)
+            }
+        }
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let child = MapArray::get_field(array.data_type());
+            let (offset, start, end) = offsets_to_value(array.offsets());
+            let field = array.field().slice(start, end - start);
+            ArrowJsonColumn {
+                offset: Some(offset),
+                children: Some(vec![serialize_array(field.as_ref(), child.name.clone())]),
+                ..column(name, array)
+            }
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            serialize_dictionary::<$T>(array, name)
+        }),
+    }
+}
+
 /// Serializes a [`Chunk`] to [`ArrowJsonBatch`].
 pub fn serialize_chunk<A: ToString>(
     columns: &Chunk<Arc<dyn Array>>,
@@ -19,36 +264,24 @@ pub fn serialize_chunk<A: ToString>(
         .arrays()
         .iter()
         .zip(names.iter())
-        .map(|(array, name)| match array.data_type() {
-            DataType::Int8 => {
-                let array = array.as_any().downcast_ref::<PrimitiveArray<i8>>().unwrap();
-
-                let (validity, data) = array
-                    .iter()
-                    .map(|x| (x.is_some() as u8, x.copied().unwrap_or_default().into()))
-                    .unzip();
-
-                ArrowJsonColumn {
-                    name: name.to_string(),
-                    count: array.len(),
-                    validity: Some(validity),
-                    data: Some(data),
-                    offset: None,
-                    type_id: None,
-                    children: None,
-                }
-            }
-            _ => ArrowJsonColumn {
-                name: name.to_string(),
-                count: array.len(),
-                validity: None,
-                data: None,
-                offset: None,
-                type_id: None,
-                children: None,
-            },
-        })
+        .map(|(array, name)| serialize_array(array.as_ref(), name.to_string()))
         .collect();
 
     ArrowJsonBatch { count, columns }
 }
+
+/// Writes a [`Chunk`] to `writer` as a single line of JSON in the Arrow JSON integration
+/// format (see [`serialize_chunk`]).
+///
+/// Writing one chunk per line produces line-delimited JSON, which is useful to inspect
+/// the buffers, offsets and validity of IPC payloads when debugging incompatibilities
+/// between implementations.
+pub fn write_chunk<W: Write, A: ToString>(
+    writer: &mut W,
+    columns: &Chunk<Arc<dyn Array>>,
+    names: &[A],
+) -> Result<()> {
+    serde_json::to_writer(&mut *writer, &serialize_chunk(columns, names))?;
+    writer.write_all(b"\n")?;
+    Ok(())
+}
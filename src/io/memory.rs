@@ -0,0 +1,120 @@
+use crate::array::*;
+use crate::bitmap::Bitmap;
+use crate::chunk::Chunk;
+use crate::datatypes::PhysicalType;
+
+fn validity_size(validity: Option<&Bitmap>) -> usize {
+    validity.map(|b| b.as_slice().0.len()).unwrap_or(0)
+}
+
+fn binary_size<O: Offset>(values: &[u8], offsets: &[O], validity: Option<&Bitmap>) -> usize {
+    values.len() + std::mem::size_of_val(offsets) + validity_size(validity)
+}
+
+/// Returns the size in bytes of the (visible) buffers of `array`, including nested arrays.
+///
+/// Buffers shared within the array (e.g. by multiple fields of a [`StructArray`]) are counted
+/// once per use, which is an upper bound of their size in memory but the right measure of the
+/// amount of data to encode.
+pub(crate) fn estimated_bytes_size(array: &dyn Array) -> usize {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Null => 0,
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            array.values().as_slice().0.len() + validity_size(array.validity())
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$T>>()
+                .unwrap();
+            std::mem::size_of_val(array.values().as_slice()) + validity_size(array.validity())
+        }),
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            binary_size(array.values(), array.offsets(), array.validity())
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            binary_size(array.values(), array.offsets(), array.validity())
+        }
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            binary_size(array.values(), array.offsets(), array.validity())
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            binary_size(array.values(), array.offsets(), array.validity())
+        }
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            array.values().len() + validity_size(array.validity())
+        }
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            estimated_bytes_size(array.values().as_ref())
+                + std::mem::size_of_val(array.offsets().as_slice())
+                + validity_size(array.validity())
+        }
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            estimated_bytes_size(array.values().as_ref())
+                + std::mem::size_of_val(array.offsets().as_slice())
+                + validity_size(array.validity())
+        }
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            estimated_bytes_size(array.values().as_ref()) + validity_size(array.validity())
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            array
+                .values()
+                .iter()
+                .map(|x| estimated_bytes_size(x.as_ref()))
+                .sum::<usize>()
+                + validity_size(array.validity())
+        }
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            let offsets = array
+                .offsets()
+                .map(|x| std::mem::size_of_val(x.as_slice()))
+                .unwrap_or_default();
+            array
+                .fields()
+                .iter()
+                .map(|x| estimated_bytes_size(x.as_ref()))
+                .sum::<usize>()
+                + array.types().len()
+                + offsets
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            estimated_bytes_size(array.keys()) + estimated_bytes_size(array.values().as_ref())
+        }),
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            estimated_bytes_size(array.field().as_ref())
+                + std::mem::size_of_val(array.offsets().as_slice())
+                + validity_size(array.validity())
+        }
+    }
+}
+
+/// Returns the size in bytes of the (visible) buffers of the arrays of `chunk`.
+/// See [`estimated_bytes_size`].
+pub(crate) fn estimated_chunk_bytes_size<A: AsRef<dyn Array>>(chunk: &Chunk<A>) -> usize {
+    chunk
+        .arrays()
+        .iter()
+        .map(|x| estimated_bytes_size(x.as_ref()))
+        .sum()
+}
@@ -27,10 +27,18 @@ pub mod json;
 #[cfg_attr(docsrs, doc(cfg(feature = "io_json")))]
 pub mod ndjson;
 
+#[cfg(feature = "io_serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_serde")))]
+pub mod serde;
+
 #[cfg(feature = "io_ipc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc")))]
 pub mod ipc;
 
+#[cfg(feature = "io_feather")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_feather")))]
+pub mod feather;
+
 #[cfg(feature = "io_flight")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_flight")))]
 pub mod flight;
@@ -53,3 +61,6 @@ pub mod print;
 
 #[cfg(any(feature = "io_csv_write", feature = "io_avro", feature = "io_json"))]
 mod iterator;
+
+#[cfg(feature = "io_ipc")]
+mod memory;
@@ -1,26 +1,245 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use serde_json::Value;
 
-use crate::array::Array;
-use crate::datatypes::DataType;
+use crate::array::{Array, MapArray, StructArray, Utf8Array};
+use crate::bitmap::MutableBitmap;
+use crate::datatypes::{DataType, Field};
 use crate::error::ArrowError;
 
 use super::super::super::json::read::_deserialize;
 
+/// Declares how records whose fields do not match the fields of a [`DataType::Struct`]
+/// (e.g. records of an evolving event stream) are deserialized by [`deserialize_with_policy`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum RecordPolicy {
+    /// Errors on records that are not objects, that contain a field that is not part of the
+    /// data type or that miss a field that is not nullable.
+    Error,
+    /// Missing fields are null and fields that are not part of the data type are ignored.
+    /// This is the behavior of [`deserialize`].
+    #[default]
+    NullFill,
+    /// Like [`RecordPolicy::NullFill`], but fields that are not part of the data type are
+    /// collected into the field with this name, whose data type must be [`extras_data_type`]
+    /// (see [`extras_field`]). Each extra field is an entry whose key is the field's name and
+    /// whose value is the field's JSON.
+    CollectExtras(String),
+}
+
+/// Returns the [`DataType`] of the field that collects the extra fields of records under
+/// [`RecordPolicy::CollectExtras`]: a map of field names to their JSON.
+pub fn extras_data_type() -> DataType {
+    let entries = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Utf8, true),
+    ]);
+    DataType::Map(Box::new(Field::new("entries", entries, false)), false)
+}
+
+/// Returns a nullable [`Field`] named `name` that collects the extra fields of records under
+/// [`RecordPolicy::CollectExtras`].
+pub fn extras_field(name: &str) -> Field {
+    Field::new(name, extras_data_type(), true)
+}
+
+fn parse(rows: &[String]) -> Result<Vec<Value>, ArrowError> {
+    rows.iter()
+        .map(|row| serde_json::from_str(row.as_ref()).map_err(ArrowError::from))
+        .collect()
+}
+
 /// Deserializes rows into an [`Array`] of [`DataType`].
 /// # Implementation
 /// This function is CPU-bounded.
 /// This function is guaranteed to return an array of length equal to `rows.len()`.
 /// # Errors
-/// This function errors iff any of the rows is not a valid JSON (i.e. the format is not valid NDJSON).
+/// This function errors iff any of the rows is not a valid JSON (i.e. the format is not valid NDJSON)
+/// or the values exceed the maximum offset of their data type (e.g. more than 2GB of
+/// [`DataType::Utf8`] values).
 pub fn deserialize(rows: &[String], data_type: DataType) -> Result<Arc<dyn Array>, ArrowError> {
     // deserialize strings to `Value`s
-    let rows = rows
-        .iter()
-        .map(|row| serde_json::from_str(row.as_ref()).map_err(ArrowError::from))
-        .collect::<Result<Vec<Value>, ArrowError>>()?;
+    let rows = parse(rows)?;
 
     // deserialize &[Value] to Array
-    Ok(_deserialize(&rows, data_type))
+    _deserialize(&rows, data_type)
+}
+
+/// Deserializes rows into an [`Array`] of [`DataType::Struct`], declaring via `policy` how
+/// records whose fields do not match `data_type`'s fields are deserialized.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, StructArray};
+/// use arrow2::datatypes::{DataType, Field};
+/// use arrow2::io::ndjson::read::{deserialize_with_policy, extras_field, RecordPolicy};
+///
+/// let rows = vec![
+///     r#"{"a": 1}"#.to_string(),
+///     r#"{"a": 2, "b": "new"}"#.to_string(),
+/// ];
+/// let data_type = DataType::Struct(vec![
+///     Field::new("a", DataType::Int64, true),
+///     extras_field("_extras"),
+/// ]);
+///
+/// let policy = RecordPolicy::CollectExtras("_extras".to_string());
+/// let array = deserialize_with_policy(&rows, data_type.clone(), &policy).unwrap();
+/// let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+/// assert_eq!(array.values()[1].len(), 2);
+///
+/// assert!(deserialize_with_policy(&rows, data_type, &RecordPolicy::Error).is_err());
+/// ```
+/// # Implementation
+/// This function is CPU-bounded.
+/// This function is guaranteed to return an array of length equal to `rows.len()`.
+/// # Errors
+/// This function errors iff:
+/// * any of the rows is not a valid JSON (i.e. the format is not valid NDJSON)
+/// * `data_type` is not a [`DataType::Struct`]
+/// * a record does not match `data_type` under [`RecordPolicy::Error`]
+/// * the field of [`RecordPolicy::CollectExtras`] is not part of `data_type` or its data type
+///   is not [`extras_data_type`]
+pub fn deserialize_with_policy(
+    rows: &[String],
+    data_type: DataType,
+    policy: &RecordPolicy,
+) -> Result<Arc<dyn Array>, ArrowError> {
+    let fields = match &data_type {
+        DataType::Struct(fields) => fields,
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Records can only be deserialized with a policy to a Struct, not to {:?}",
+                data_type
+            )))
+        }
+    };
+
+    let rows = parse(rows)?;
+
+    match policy {
+        RecordPolicy::Error => {
+            rows.iter()
+                .enumerate()
+                .try_for_each(|(row, value)| check_record(value, fields, row))?;
+            _deserialize(&rows, data_type)
+        }
+        RecordPolicy::NullFill => _deserialize(&rows, data_type),
+        RecordPolicy::CollectExtras(name) => collect_extras(&rows, data_type, name),
+    }
+}
+
+fn check_record(value: &Value, fields: &[Field], row: usize) -> Result<(), ArrowError> {
+    let record = match value {
+        Value::Object(record) => record,
+        _ => {
+            return Err(ArrowError::ExternalFormat(format!(
+                "The record at row {} is not an object",
+                row
+            )))
+        }
+    };
+    if let Some(key) = record
+        .keys()
+        .find(|key| !fields.iter().any(|field| &field.name == *key))
+    {
+        return Err(ArrowError::ExternalFormat(format!(
+            "The record at row {} contains the field \"{}\", which is not part of the schema",
+            row, key
+        )));
+    }
+    if let Some(field) = fields
+        .iter()
+        .find(|field| !field.is_nullable && !record.contains_key(&field.name))
+    {
+        return Err(ArrowError::ExternalFormat(format!(
+            "The record at row {} misses the non-nullable field \"{}\"",
+            row, field.name
+        )));
+    }
+    Ok(())
+}
+
+fn collect_extras(
+    rows: &[Value],
+    data_type: DataType,
+    name: &str,
+) -> Result<Arc<dyn Array>, ArrowError> {
+    let fields = StructArray::get_fields(&data_type);
+    let index = fields
+        .iter()
+        .position(|field| field.name == name)
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The field \"{}\" that collects extra fields is not part of the schema",
+                name
+            ))
+        })?;
+    if fields[index].data_type() != &extras_data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The field \"{}\" that collects extra fields must be of data type {:?}",
+            name,
+            extras_data_type()
+        )));
+    }
+
+    // all other fields are deserialized as usual
+    let mut others = fields.to_vec();
+    others.remove(index);
+    let others = _deserialize(rows, DataType::Struct(others))?;
+    let mut values = others
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap()
+        .values()
+        .to_vec();
+
+    let names = fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect::<HashSet<_>>();
+
+    let mut keys = vec![];
+    let mut entries = vec![];
+    let mut offsets = Vec::with_capacity(rows.len() + 1);
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+    offsets.push(0i32);
+    for row in rows {
+        match row {
+            Value::Object(record) => {
+                record
+                    .iter()
+                    .filter(|(key, _)| !names.contains(key.as_str()))
+                    .for_each(|(key, value)| {
+                        keys.push(key.as_str());
+                        entries.push(value.to_string());
+                    });
+                validity.push(true);
+            }
+            _ => validity.push(false),
+        }
+        let length = i32::try_from(entries.len()).map_err(|_| ArrowError::Overflow)?;
+        offsets.push(length);
+    }
+
+    let entries_type = MapArray::get_field(&fields[index].data_type)
+        .data_type()
+        .clone();
+    let entries = StructArray::new(
+        entries_type,
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(keys)),
+            Arc::new(Utf8Array::<i32>::from_slice(entries)),
+        ],
+        None,
+    );
+    let extras = MapArray::new(
+        extras_data_type(),
+        offsets.into(),
+        Arc::new(entries),
+        validity.into(),
+    );
+    values.insert(index, Arc::new(extras));
+
+    Ok(Arc::new(StructArray::new(data_type, values, None)))
 }
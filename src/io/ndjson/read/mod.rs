@@ -4,5 +4,9 @@ pub use fallible_streaming_iterator::FallibleStreamingIterator;
 
 mod deserialize;
 mod file;
-pub use deserialize::deserialize;
+pub use deserialize::{
+    deserialize, deserialize_with_policy, extras_data_type, extras_field, RecordPolicy,
+};
 pub use file::{infer, FileReader};
+
+pub use super::super::json::read::with_dictionary_fields;
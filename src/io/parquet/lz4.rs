@@ -0,0 +1,96 @@
+//! LZ4 codecs compatible with the formats used by parquet writers for the LZ4 codec:
+//! the LZ4 frame format (written by parquet2), the Hadoop LZ4 format (written by
+//! Hadoop-based writers) and the raw LZ4 block format.
+use crate::error::Result;
+
+/// The maximum number of (uncompressed) bytes of each block of the Hadoop LZ4 format,
+/// Hadoop's default buffer size.
+#[cfg(feature = "io_parquet_compression")]
+const HADOOP_BLOCK_SIZE: usize = 256 * 1024;
+
+/// The magic number (little endian) that starts an LZ4 frame.
+#[cfg(feature = "io_parquet_compression")]
+const LZ4_FRAME_MAGIC: [u8; 4] = [0x04, 0x22, 0x4D, 0x18];
+
+/// Decompresses `input` into `output`, detecting whether `input` is in the LZ4 frame format,
+/// the Hadoop LZ4 format or the raw LZ4 block format.
+/// # Error
+/// Errors iff `input` is not valid in any of the formats or does not decompress to exactly
+/// `output.len()` bytes.
+#[cfg(feature = "io_parquet_compression")]
+pub(crate) fn decompress_lz4(input: &[u8], output: &mut [u8]) -> Result<()> {
+    use std::io::Read;
+
+    use crate::error::ArrowError;
+
+    if input.starts_with(&LZ4_FRAME_MAGIC) {
+        let mut decoder = lz4::Decoder::new(input)?;
+        return decoder.read_exact(output).map_err(|e| e.into());
+    }
+    if decompress_hadoop(input, output).is_some() {
+        return Ok(());
+    }
+
+    let size = i32::try_from(output.len()).map_err(|_| ArrowError::Overflow)?;
+    let written = lz4::block::decompress_to_buffer(input, Some(size), output)?;
+    if written != output.len() {
+        return Err(ArrowError::OutOfSpec(format!(
+            "An LZ4 page decompressed to {} bytes, but its header declares {} bytes",
+            written,
+            output.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Decompresses `input` in the Hadoop LZ4 format into `output`: a sequence of blocks, each
+/// prefixed by its decompressed and compressed lengths as big-endian `u32`.
+/// Returns `None` iff `input` is not in this format or does not decompress to `output.len()` bytes.
+#[cfg(feature = "io_parquet_compression")]
+fn decompress_hadoop(mut input: &[u8], mut output: &mut [u8]) -> Option<()> {
+    while input.len() >= 8 {
+        let decompressed = u32::from_be_bytes(input[..4].try_into().unwrap()) as usize;
+        let compressed = u32::from_be_bytes(input[4..8].try_into().unwrap()) as usize;
+        input = &input[8..];
+        if compressed > input.len() || decompressed > output.len() {
+            return None;
+        }
+        let (block, rest) = std::mem::take(&mut output).split_at_mut(decompressed);
+        let size = i32::try_from(decompressed).ok()?;
+        let written = lz4::block::decompress_to_buffer(&input[..compressed], Some(size), block);
+        if written.ok()? != decompressed {
+            return None;
+        }
+        input = &input[compressed..];
+        output = rest;
+    }
+    if input.is_empty() && output.is_empty() {
+        Some(())
+    } else {
+        None
+    }
+}
+
+/// Compresses `input` in the Hadoop LZ4 format, appending it to `output`.
+#[cfg(feature = "io_parquet_compression")]
+pub(crate) fn compress_hadoop_lz4(input: &[u8], output: &mut Vec<u8>) -> Result<()> {
+    for block in input.chunks(HADOOP_BLOCK_SIZE) {
+        let compressed = lz4::block::compress(block, None, false)?;
+        output.extend_from_slice(&(block.len() as u32).to_be_bytes());
+        output.extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+        output.extend_from_slice(&compressed);
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "io_parquet_compression"))]
+pub(crate) fn decompress_lz4(_input: &[u8], _output: &mut [u8]) -> Result<()> {
+    use crate::error::ArrowError;
+    Err(ArrowError::OutOfSpec("The crate was compiled without parquet compression. Use `io_parquet_compression` to read compressed parquet.".to_string()))
+}
+
+#[cfg(not(feature = "io_parquet_compression"))]
+pub(crate) fn compress_hadoop_lz4(_input: &[u8], _output: &mut Vec<u8>) -> Result<()> {
+    use crate::error::ArrowError;
+    Err(ArrowError::OutOfSpec("The crate was compiled without parquet compression. Use `io_parquet_compression` to write compressed parquet.".to_string()))
+}
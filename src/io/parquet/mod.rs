@@ -1,6 +1,7 @@
 //! APIs to read from and write to Parquet format.
 use crate::error::ArrowError;
 
+mod lz4;
 pub mod read;
 pub mod write;
 
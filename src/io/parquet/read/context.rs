@@ -0,0 +1,116 @@
+//! Adds the location of errors (column, row group, page and encoding) to errors raised
+//! while deserializing parquet pages.
+use std::sync::{Arc, Mutex};
+
+use parquet2::encoding::Encoding;
+use parquet2::error::ParquetError;
+
+use super::{ArrayIter, DataPage, DataPages, FallibleStreamingIterator};
+
+#[derive(Debug, Default)]
+struct PageState {
+    // number of pages read so far
+    pages: usize,
+    // index of the last page that was read or failed to be read
+    page: Option<usize>,
+    // the encoding of the last page read, if it was read successfully
+    encoding: Option<Encoding>,
+}
+
+/// The location of a column chunk being deserialized, shared between a [`PageTracker`]
+/// and the errors raised while deserializing its pages.
+#[derive(Debug, Clone)]
+pub(super) struct ColumnContext {
+    path: String,
+    state: Arc<Mutex<PageState>>,
+}
+
+impl ColumnContext {
+    pub fn new(path_in_schema: &[String]) -> Self {
+        Self {
+            path: path_in_schema.join("."),
+            state: Default::default(),
+        }
+    }
+
+    fn describe(&self) -> String {
+        let state = self.state.lock().unwrap();
+        let mut description = format!("column \"{}\"", self.path);
+        if let Some(page) = state.page {
+            description.push_str(&format!(", page {}", page));
+        }
+        if let Some(encoding) = state.encoding {
+            description.push_str(&format!(", encoding {:?}", encoding));
+        }
+        description
+    }
+}
+
+/// A [`DataPages`] that records which page is being read in a [`ColumnContext`].
+pub(super) struct PageTracker<I: DataPages> {
+    iter: I,
+    context: ColumnContext,
+}
+
+impl<I: DataPages> PageTracker<I> {
+    pub fn new(iter: I, context: ColumnContext) -> Self {
+        Self { iter, context }
+    }
+}
+
+impl<I: DataPages> FallibleStreamingIterator for PageTracker<I> {
+    type Item = DataPage;
+    type Error = ParquetError;
+
+    fn advance(&mut self) -> Result<(), ParquetError> {
+        let result = self.iter.advance();
+        let mut state = self.context.state.lock().unwrap();
+        match (&result, self.iter.get()) {
+            (Ok(()), Some(page)) => {
+                state.page = Some(state.pages);
+                state.pages += 1;
+                state.encoding = Some(page.encoding());
+            }
+            (Err(_), _) => {
+                state.page = Some(state.pages);
+                state.encoding = None;
+            }
+            _ => {}
+        }
+        result
+    }
+
+    fn get(&self) -> Option<&DataPage> {
+        self.iter.get()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Adds the location of the page being deserialized in `columns` to the errors of `iter`,
+/// keeping their variant.
+pub(super) fn with_columns_context(
+    iter: ArrayIter<'_>,
+    columns: Vec<ColumnContext>,
+) -> ArrayIter<'_> {
+    Box::new(iter.map(move |result| {
+        result.map_err(|error| {
+            let context = columns
+                .iter()
+                .map(|column| column.describe())
+                .collect::<Vec<_>>()
+                .join("; ");
+            error.with_context(context)
+        })
+    }))
+}
+
+/// Adds the index of the row group being deserialized to the errors of `iter`, keeping
+/// their variant.
+pub(super) fn with_row_group_context(iter: ArrayIter<'_>, row_group: usize) -> ArrayIter<'_> {
+    Box::new(iter.map(move |result| {
+        result.map_err(|error| error.with_context(format_args!("row group {}", row_group)))
+    }))
+}
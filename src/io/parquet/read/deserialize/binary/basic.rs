@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 use std::default::Default;
+use std::sync::atomic::AtomicUsize;
 
 use parquet2::{
     encoding::{hybrid_rle, Encoding},
@@ -8,10 +9,13 @@ use parquet2::{
 };
 
 use crate::{
-    array::{Array, BinaryArray, Offset, Utf8Array},
+    array::{
+        specification::{try_check_offsets, try_check_offsets_and_utf8},
+        Array, BinaryArray, MutableArray, MutableBinaryArray, MutableUtf8Array, Offset, Utf8Array,
+    },
     bitmap::{Bitmap, MutableBitmap},
     buffer::Buffer,
-    datatypes::DataType,
+    datatypes::{DataType, PhysicalType},
     error::Result,
 };
 
@@ -19,7 +23,10 @@ use super::super::utils::{
     extend_from_decoder, next, BinaryIter, DecodedState, MaybeNext, OptionalPageValidity,
 };
 use super::super::DataPages;
-use super::{super::utils, utils::Binary};
+use super::{
+    super::utils,
+    utils::{check_dictionary, check_plain, Binary, BinaryLimits, Offsets, Utf8Validation},
+};
 
 /*
 fn read_delta_optional<O: Offset>(
@@ -145,6 +152,51 @@ impl<O: Offset> TraitBinaryArray<O> for Utf8Array<O> {
     }
 }
 
+pub trait TraitMutableBinaryArray<O: Offset>: MutableArray + Default + 'static {
+    fn into_data(self) -> (DataType, Vec<O>, Vec<u8>, Option<MutableBitmap>);
+
+    fn try_new(
+        data_type: DataType,
+        offsets: Vec<O>,
+        values: Vec<u8>,
+        validity: Option<MutableBitmap>,
+    ) -> Result<Self>
+    where
+        Self: Sized;
+}
+
+impl<O: Offset> TraitMutableBinaryArray<O> for MutableBinaryArray<O> {
+    fn into_data(self) -> (DataType, Vec<O>, Vec<u8>, Option<MutableBitmap>) {
+        self.into_data()
+    }
+
+    fn try_new(
+        data_type: DataType,
+        offsets: Vec<O>,
+        values: Vec<u8>,
+        validity: Option<MutableBitmap>,
+    ) -> Result<Self> {
+        try_check_offsets(&offsets, values.len())?;
+        Ok(Self::from_data(data_type, offsets, values, validity))
+    }
+}
+
+impl<O: Offset> TraitMutableBinaryArray<O> for MutableUtf8Array<O> {
+    fn into_data(self) -> (DataType, Vec<O>, Vec<u8>, Option<MutableBitmap>) {
+        self.into_data()
+    }
+
+    fn try_new(
+        data_type: DataType,
+        offsets: Vec<O>,
+        values: Vec<u8>,
+        validity: Option<MutableBitmap>,
+    ) -> Result<Self> {
+        try_check_offsets_and_utf8(&offsets, &values)?;
+        Ok(Self::from_data(data_type, offsets, values, validity))
+    }
+}
+
 impl<'a, O: Offset> DecodedState<'a> for (Binary<O>, MutableBitmap) {
     fn len(&self) -> usize {
         self.0.len()
@@ -153,9 +205,22 @@ impl<'a, O: Offset> DecodedState<'a> for (Binary<O>, MutableBitmap) {
 
 #[derive(Debug, Default)]
 struct BinaryDecoder<O: Offset> {
+    limits: BinaryLimits,
+    /// the number of bytes decoded so far, checked against `limits`
+    total_bytes: AtomicUsize,
     phantom_o: std::marker::PhantomData<O>,
 }
 
+impl<O: Offset> BinaryDecoder<O> {
+    fn new(limits: BinaryLimits) -> Self {
+        Self {
+            limits,
+            total_bytes: AtomicUsize::new(0),
+            phantom_o: Default::default(),
+        }
+    }
+}
+
 impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
     type State = State<'a>;
     type DecodedState = (Binary<O>, MutableBitmap);
@@ -166,13 +231,14 @@ impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
 
         match (page.encoding(), page.dictionary_page(), is_optional) {
             (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), false) => {
-                Ok(State::RequiredDictionary(ValuesDictionary::new(
-                    page,
-                    dict.as_any().downcast_ref().unwrap(),
-                )))
+                let dict = dict.as_any().downcast_ref().unwrap();
+                check_dictionary(page, dict, &self.limits, &self.total_bytes)?;
+
+                Ok(State::RequiredDictionary(ValuesDictionary::new(page, dict)))
             }
             (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), true) => {
                 let dict = dict.as_any().downcast_ref().unwrap();
+                check_dictionary(page, dict, &self.limits, &self.total_bytes)?;
 
                 Ok(State::OptionalDictionary(
                     OptionalPageValidity::new(page),
@@ -181,12 +247,16 @@ impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
             }
             (Encoding::Plain, _, true) => {
                 let (_, _, values) = utils::split_buffer(page);
+                check_plain(values, &self.limits, &self.total_bytes)?;
 
                 let values = BinaryIter::new(values);
 
                 Ok(State::Optional(OptionalPageValidity::new(page), values))
             }
-            (Encoding::Plain, _, false) => Ok(State::Required(Required::new(page))),
+            (Encoding::Plain, _, false) => {
+                check_plain(page.buffer(), &self.limits, &self.total_bytes)?;
+                Ok(State::Required(Required::new(page)))
+            }
             _ => Err(utils::not_implemented(
                 &page.encoding(),
                 is_optional,
@@ -220,6 +290,7 @@ impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
                 page_values,
             ),
             State::Required(page) => {
+                let additional = additional.min(page.remaining);
                 page.remaining -= additional;
                 for x in page.values.by_ref().take(additional) {
                     values.push(x)
@@ -263,9 +334,19 @@ impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
 
 pub(super) fn finish<O: Offset, A: TraitBinaryArray<O>>(
     data_type: &DataType,
-    values: Binary<O>,
-    validity: MutableBitmap,
+    mut values: Binary<O>,
+    mut validity: MutableBitmap,
+    validation: Utf8Validation,
 ) -> Result<A> {
+    values.check_offsets(data_type)?;
+    if matches!(
+        data_type.to_physical_type(),
+        PhysicalType::Utf8 | PhysicalType::LargeUtf8
+    ) {
+        values.validate_utf8(&mut validity, validation);
+        // replaced values may be longer than the original ones
+        values.check_offsets(data_type)?;
+    }
     A::try_new(
         data_type.clone(),
         values.offsets.0.into(),
@@ -274,21 +355,64 @@ pub(super) fn finish<O: Offset, A: TraitBinaryArray<O>>(
     )
 }
 
+/// Replaces the values of `array` by the deserialized `pages`, reusing its allocations.
+/// # Errors
+/// Errors iff the pages can't be deserialized, in which case `array` is left empty.
+pub fn read_into<O, A, I>(mut pages: I, array: &mut A) -> Result<()>
+where
+    O: Offset,
+    A: TraitMutableBinaryArray<O>,
+    I: DataPages,
+{
+    let (data_type, mut offsets, mut values, validity) = std::mem::take(array).into_data();
+    offsets.clear();
+    offsets.push(O::default());
+    values.clear();
+    let mut validity = validity.unwrap_or_default();
+    validity.clear();
+
+    let binary = Binary {
+        offsets: Offsets(offsets),
+        values,
+        last_offset: O::default(),
+    };
+    let mut decoded = (binary, validity);
+    let result = utils::extend_from_pages(&mut pages, &mut decoded, &BinaryDecoder::<O>::default());
+
+    let (mut binary, mut validity) = decoded;
+    if result.is_err() {
+        // the values decoded so far may be invalid: keep only their allocations
+        binary.offsets.0.truncate(1);
+        binary.values.clear();
+        validity.clear();
+    }
+    binary.check_offsets(&data_type)?;
+    let validity = if validity.is_empty() {
+        None
+    } else {
+        Some(validity)
+    };
+    *array = A::try_new(data_type, binary.offsets.0, binary.values, validity)?;
+    result
+}
+
 pub struct Iter<O: Offset, A: TraitBinaryArray<O>, I: DataPages> {
     iter: I,
     data_type: DataType,
     items: VecDeque<(Binary<O>, MutableBitmap)>,
     chunk_size: usize,
+    decoder: BinaryDecoder<O>,
     phantom_a: std::marker::PhantomData<A>,
 }
 
 impl<O: Offset, A: TraitBinaryArray<O>, I: DataPages> Iter<O, A, I> {
-    pub fn new(iter: I, data_type: DataType, chunk_size: usize) -> Self {
+    pub fn new(iter: I, data_type: DataType, chunk_size: usize, limits: BinaryLimits) -> Self {
         Self {
             iter,
             data_type,
             items: VecDeque::new(),
             chunk_size,
+            decoder: BinaryDecoder::new(limits),
             phantom_a: Default::default(),
         }
     }
@@ -302,12 +426,15 @@ impl<O: Offset, A: TraitBinaryArray<O>, I: DataPages> Iterator for Iter<O, A, I>
             &mut self.iter,
             &mut self.items,
             self.chunk_size,
-            &BinaryDecoder::<O>::default(),
+            &self.decoder,
         );
         match maybe_state {
-            MaybeNext::Some(Ok((values, validity))) => {
-                Some(finish(&self.data_type, values, validity))
-            }
+            MaybeNext::Some(Ok((values, validity))) => Some(finish(
+                &self.data_type,
+                values,
+                validity,
+                self.decoder.limits.utf8_validation,
+            )),
             MaybeNext::Some(Err(e)) => Some(Err(e)),
             MaybeNext::None => None,
             MaybeNext::More => self.next(),
@@ -10,8 +10,10 @@ use crate::{
 };
 
 use super::super::dictionary::*;
+use super::super::nested_utils::{InitNested, NestedState};
 use super::super::utils::MaybeNext;
 use super::super::DataPages;
+use super::utils::{check_dictionary_values, Binary, BinaryLimits, Offsets};
 
 /// An iterator adapter over [`DataPages`] assumed to be encoded as parquet's dictionary-encoded binary representation
 #[derive(Debug)]
@@ -26,6 +28,7 @@ where
     values: Dict,
     items: VecDeque<(Vec<K>, MutableBitmap)>,
     chunk_size: usize,
+    limits: BinaryLimits,
     phantom: std::marker::PhantomData<O>,
 }
 
@@ -35,7 +38,7 @@ where
     O: Offset,
     I: DataPages,
 {
-    pub fn new(iter: I, data_type: DataType, chunk_size: usize) -> Self {
+    pub fn new(iter: I, data_type: DataType, chunk_size: usize, limits: BinaryLimits) -> Self {
         let data_type = match data_type {
             DataType::Dictionary(_, values, _) => values.as_ref().clone(),
             _ => unreachable!(),
@@ -46,13 +49,19 @@ where
             values: Dict::Empty,
             items: VecDeque::new(),
             chunk_size,
+            limits,
             phantom: std::marker::PhantomData,
         }
     }
 }
 
-fn read_dict<O: Offset>(data_type: DataType, dict: &dyn DictPage) -> Arc<dyn Array> {
+fn read_dict<O: Offset>(
+    data_type: DataType,
+    dict: &dyn DictPage,
+    limits: BinaryLimits,
+) -> Result<Arc<dyn Array>> {
     let dict = dict.as_any().downcast_ref::<BinaryPageDict>().unwrap();
+    check_dictionary_values(dict, &limits)?;
     let offsets = dict
         .offsets()
         .iter()
@@ -60,13 +69,22 @@ fn read_dict<O: Offset>(data_type: DataType, dict: &dyn DictPage) -> Arc<dyn Arr
         .collect::<Vec<_>>();
     let values = dict.values().to_vec();
 
-    match data_type.to_physical_type() {
-        PhysicalType::Utf8 | PhysicalType::LargeUtf8 => Arc::new(Utf8Array::<O>::from_data(
-            data_type,
-            offsets.into(),
-            values.into(),
-            None,
-        )) as _,
+    Ok(match data_type.to_physical_type() {
+        PhysicalType::Utf8 | PhysicalType::LargeUtf8 => {
+            let mut values = Binary {
+                last_offset: offsets.last().copied().unwrap_or_default(),
+                offsets: Offsets(offsets),
+                values,
+            };
+            let mut validity = MutableBitmap::new();
+            values.validate_utf8(&mut validity, limits.utf8_validation);
+            Arc::new(Utf8Array::<O>::try_new(
+                data_type,
+                values.offsets.0.into(),
+                values.values.into(),
+                validity.into(),
+            )?) as _
+        }
         PhysicalType::Binary | PhysicalType::LargeBinary => Arc::new(BinaryArray::<O>::from_data(
             data_type,
             offsets.into(),
@@ -74,7 +92,7 @@ fn read_dict<O: Offset>(data_type: DataType, dict: &dyn DictPage) -> Arc<dyn Arr
             None,
         )) as _,
         _ => unreachable!(),
-    }
+    })
 }
 
 impl<K, O, I> Iterator for DictIter<K, O, I>
@@ -91,7 +109,85 @@ where
             &mut self.items,
             &mut self.values,
             self.chunk_size,
-            |dict| read_dict::<O>(self.data_type.clone(), dict),
+            |dict| read_dict::<O>(self.data_type.clone(), dict, self.limits),
+        );
+        match maybe_state {
+            MaybeNext::Some(Ok(dict)) => Some(Ok(dict)),
+            MaybeNext::Some(Err(e)) => Some(Err(e)),
+            MaybeNext::None => None,
+            MaybeNext::More => self.next(),
+        }
+    }
+}
+
+/// An iterator adapter over [`DataPages`] of a nested column whose leaves are assumed to be
+/// encoded as parquet's dictionary-encoded binary representation
+#[derive(Debug)]
+pub struct NestedDictIter<K, O, I>
+where
+    I: DataPages,
+    O: Offset,
+    K: DictionaryKey,
+{
+    iter: I,
+    init: InitNested,
+    data_type: DataType,
+    values: Dict,
+    items: VecDeque<(Vec<K>, MutableBitmap)>,
+    nested: VecDeque<NestedState>,
+    chunk_size: usize,
+    limits: BinaryLimits,
+    phantom: std::marker::PhantomData<O>,
+}
+
+impl<K, O, I> NestedDictIter<K, O, I>
+where
+    K: DictionaryKey,
+    O: Offset,
+    I: DataPages,
+{
+    pub fn new(
+        iter: I,
+        init: InitNested,
+        data_type: DataType,
+        chunk_size: usize,
+        limits: BinaryLimits,
+    ) -> Self {
+        let data_type = match data_type {
+            DataType::Dictionary(_, values, _) => values.as_ref().clone(),
+            _ => unreachable!(),
+        };
+        Self {
+            iter,
+            init,
+            data_type,
+            values: Dict::Empty,
+            items: VecDeque::new(),
+            nested: VecDeque::new(),
+            chunk_size,
+            limits,
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<K, O, I> Iterator for NestedDictIter<K, O, I>
+where
+    I: DataPages,
+    O: Offset,
+    K: DictionaryKey,
+{
+    type Item = Result<(NestedState, DictionaryArray<K>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let maybe_state = nested_next_dict(
+            &mut self.iter,
+            &mut self.items,
+            &mut self.nested,
+            &self.init,
+            &mut self.values,
+            self.chunk_size,
+            |dict| read_dict::<O>(self.data_type.clone(), dict, self.limits),
         );
         match maybe_state {
             MaybeNext::Some(Ok(dict)) => Some(Ok(dict)),
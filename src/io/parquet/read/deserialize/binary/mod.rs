@@ -17,8 +17,10 @@ use super::{
     DataPages,
 };
 
+pub(super) use basic::read_into;
 pub use basic::Iter;
-pub use dictionary::DictIter;
+pub use dictionary::{DictIter, NestedDictIter};
+pub use utils::{BinaryLimits, Utf8Validation};
 
 /// Converts [`DataPages`] to an [`Iterator`] of [`Array`]
 pub fn iter_to_arrays_nested<'a, O, A, I>(
@@ -26,6 +28,7 @@ pub fn iter_to_arrays_nested<'a, O, A, I>(
     init: InitNested,
     data_type: DataType,
     chunk_size: usize,
+    limits: BinaryLimits,
 ) -> NestedArrayIter<'a>
 where
     I: 'a + DataPages,
@@ -33,7 +36,7 @@ where
     O: Offset,
 {
     Box::new(
-        ArrayIterator::<O, A, I>::new(iter, init, data_type, chunk_size).map(|x| {
+        ArrayIterator::<O, A, I>::new(iter, init, data_type, chunk_size, limits).map(|x| {
             x.map(|(mut nested, array)| {
                 let _ = nested.nested.pop().unwrap(); // the primitive
                 let values = Arc::new(array) as Arc<dyn Array>;
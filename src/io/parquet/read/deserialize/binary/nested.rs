@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use std::sync::atomic::AtomicUsize;
 
 use parquet2::{encoding::Encoding, page::DataPage, schema::Repetition};
 
@@ -10,7 +11,7 @@ use crate::{
 use super::super::nested_utils::*;
 use super::super::utils::MaybeNext;
 use super::basic::ValuesDictionary;
-use super::utils::Binary;
+use super::utils::{check_dictionary, check_plain, Binary, BinaryLimits};
 use super::{
     super::utils,
     basic::{finish, Required, TraitBinaryArray},
@@ -38,9 +39,22 @@ impl<'a> utils::PageState<'a> for State<'a> {
 
 #[derive(Debug, Default)]
 struct BinaryDecoder<O: Offset> {
+    limits: BinaryLimits,
+    /// the number of bytes decoded so far, checked against `limits`
+    total_bytes: AtomicUsize,
     phantom_o: std::marker::PhantomData<O>,
 }
 
+impl<O: Offset> BinaryDecoder<O> {
+    fn new(limits: BinaryLimits) -> Self {
+        Self {
+            limits,
+            total_bytes: AtomicUsize::new(0),
+            phantom_o: Default::default(),
+        }
+    }
+}
+
 impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
     type State = State<'a>;
     type DecodedState = (Binary<O>, MutableBitmap);
@@ -52,10 +66,12 @@ impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
         match (page.encoding(), page.dictionary_page(), is_optional) {
             (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), false) => {
                 let dict = dict.as_any().downcast_ref().unwrap();
+                check_dictionary(page, dict, &self.limits, &self.total_bytes)?;
                 Ok(State::RequiredDictionary(ValuesDictionary::new(page, dict)))
             }
             (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), true) => {
                 let dict = dict.as_any().downcast_ref().unwrap();
+                check_dictionary(page, dict, &self.limits, &self.total_bytes)?;
                 Ok(State::OptionalDictionary(
                     Optional::new(page),
                     ValuesDictionary::new(page, dict),
@@ -63,12 +79,16 @@ impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
             }
             (Encoding::Plain, None, true) => {
                 let (_, _, values) = utils::split_buffer(page);
+                check_plain(values, &self.limits, &self.total_bytes)?;
 
                 let values = utils::BinaryIter::new(values);
 
                 Ok(State::Optional(Optional::new(page), values))
             }
-            (Encoding::Plain, None, false) => Ok(State::Required(Required::new(page))),
+            (Encoding::Plain, None, false) => {
+                check_plain(page.buffer(), &self.limits, &self.total_bytes)?;
+                Ok(State::Required(Required::new(page)))
+            }
             _ => Err(utils::not_implemented(
                 &page.encoding(),
                 is_optional,
@@ -106,6 +126,7 @@ impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
                 )
             }
             State::Required(page) => {
+                let additional = additional.min(page.remaining);
                 page.remaining -= additional;
                 for x in page.values.by_ref().take(additional) {
                     values.push(x)
@@ -156,11 +177,18 @@ pub struct ArrayIterator<O: Offset, A: TraitBinaryArray<O>, I: DataPages> {
     items: VecDeque<(Binary<O>, MutableBitmap)>,
     nested: VecDeque<NestedState>,
     chunk_size: usize,
+    decoder: BinaryDecoder<O>,
     phantom_a: std::marker::PhantomData<A>,
 }
 
 impl<O: Offset, A: TraitBinaryArray<O>, I: DataPages> ArrayIterator<O, A, I> {
-    pub fn new(iter: I, init: InitNested, data_type: DataType, chunk_size: usize) -> Self {
+    pub fn new(
+        iter: I,
+        init: InitNested,
+        data_type: DataType,
+        chunk_size: usize,
+        limits: BinaryLimits,
+    ) -> Self {
         Self {
             iter,
             data_type,
@@ -168,6 +196,7 @@ impl<O: Offset, A: TraitBinaryArray<O>, I: DataPages> ArrayIterator<O, A, I> {
             items: VecDeque::new(),
             nested: VecDeque::new(),
             chunk_size,
+            decoder: BinaryDecoder::new(limits),
             phantom_a: Default::default(),
         }
     }
@@ -183,11 +212,15 @@ impl<O: Offset, A: TraitBinaryArray<O>, I: DataPages> Iterator for ArrayIterator
             &mut self.nested,
             &self.init,
             self.chunk_size,
-            &BinaryDecoder::<O>::default(),
+            &self.decoder,
         );
         match maybe_state {
             MaybeNext::Some(Ok((nested, decoded))) => {
-                Some(finish(&self.data_type, decoded.0, decoded.1).map(|array| (nested, array)))
+                let validation = self.decoder.limits.utf8_validation;
+                Some(
+                    finish(&self.data_type, decoded.0, decoded.1, validation)
+                        .map(|array| (nested, array)),
+                )
             }
             MaybeNext::Some(Err(e)) => Some(Err(e)),
             MaybeNext::None => None,
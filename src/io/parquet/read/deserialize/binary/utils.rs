@@ -1,6 +1,149 @@
-use crate::array::Offset;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-use super::super::utils::Pushable;
+use parquet2::page::{BinaryPageDict, DataPage};
+
+use crate::{
+    array::{specification::try_check_offsets_and_utf8, Offset},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::super::utils::{self, Pushable};
+
+/// How values of utf8 columns that are not valid utf8 are deserialized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Utf8Validation {
+    /// Invalid values are an error (the default)
+    #[default]
+    Validate,
+    /// Invalid values are skipped, i.e. deserialized as nulls
+    Skip,
+    /// Invalid sequences of bytes are replaced by `U+FFFD`, as in [`String::from_utf8_lossy`]
+    Lossy,
+}
+
+/// Limits applied when deserializing binary and utf8 columns.
+///
+/// These limits bound how many bytes a column chunk can decode to, so that corrupted or
+/// malicious files result in an error instead of an unbounded allocation. When any of them
+/// is set, each length prefix of a page is also validated against the page before it is
+/// decoded. By default, no limit is applied and utf8 columns with invalid utf8 are an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinaryLimits {
+    /// The maximum length, in bytes, of a single value
+    pub max_value_length: usize,
+    /// The maximum number of bytes decoded from a single column chunk
+    pub max_total_bytes: usize,
+    /// How values of utf8 columns that are not valid utf8 are deserialized
+    pub utf8_validation: Utf8Validation,
+}
+
+impl Default for BinaryLimits {
+    fn default() -> Self {
+        Self {
+            max_value_length: usize::MAX,
+            max_total_bytes: usize::MAX,
+            utf8_validation: Utf8Validation::default(),
+        }
+    }
+}
+
+impl BinaryLimits {
+    fn is_unlimited(&self) -> bool {
+        self.max_value_length == usize::MAX && self.max_total_bytes == usize::MAX
+    }
+
+    /// Accounts a value of `length` bytes to `total`, erroring if any limit is exceeded.
+    fn check(&self, length: usize, total: &mut usize) -> Result<()> {
+        if length > self.max_value_length {
+            return Err(ArrowError::OutOfSpec(format!(
+                "A binary value of {} bytes exceeds the maximum value length of {} bytes",
+                length, self.max_value_length
+            )));
+        }
+        *total = total.saturating_add(length);
+        if *total > self.max_total_bytes {
+            return Err(ArrowError::OutOfSpec(format!(
+                "The column chunk decodes to more than the maximum of {} bytes",
+                self.max_total_bytes
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Validates the length prefixes of plain-encoded `values` against both the buffer
+/// and `limits`, accounting the decoded bytes to `total`. This is a no-op when `limits`
+/// is unlimited.
+pub(super) fn check_plain(values: &[u8], limits: &BinaryLimits, total: &AtomicUsize) -> Result<()> {
+    if limits.is_unlimited() {
+        return Ok(());
+    }
+    let mut remaining = values;
+    let mut decoded = total.load(Ordering::Relaxed);
+    while !remaining.is_empty() {
+        if remaining.len() < 4 {
+            return Err(ArrowError::OutOfSpec(
+                "A binary page ends with a truncated length prefix".to_string(),
+            ));
+        }
+        let length = u32::from_le_bytes(remaining[..4].try_into().unwrap()) as usize;
+        remaining = &remaining[4..];
+        if length > remaining.len() {
+            return Err(ArrowError::OutOfSpec(format!(
+                "A binary value declares a length of {} bytes but its page only has {} bytes left",
+                length,
+                remaining.len()
+            )));
+        }
+        limits.check(length, &mut decoded)?;
+        remaining = &remaining[length..];
+    }
+    total.store(decoded, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Validates the dictionary indices of `page` against `dict` and `limits`, accounting
+/// the decoded bytes to `total`. This is a no-op when `limits` is unlimited.
+pub(super) fn check_dictionary(
+    page: &DataPage,
+    dict: &BinaryPageDict,
+    limits: &BinaryLimits,
+    total: &AtomicUsize,
+) -> Result<()> {
+    if limits.is_unlimited() {
+        return Ok(());
+    }
+    let offsets = dict.offsets();
+    let (_, _, indices_buffer) = utils::split_buffer(page);
+    let mut decoded = total.load(Ordering::Relaxed);
+    for index in utils::dict_indices_decoder(indices_buffer, page.num_values()) {
+        let index = index as usize;
+        if index + 1 >= offsets.len() {
+            return Err(ArrowError::OutOfSpec(format!(
+                "A dictionary index of {} is out of bounds for a dictionary of {} values",
+                index,
+                offsets.len().saturating_sub(1)
+            )));
+        }
+        limits.check((offsets[index + 1] - offsets[index]) as usize, &mut decoded)?;
+    }
+    total.store(decoded, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Validates the values of `dict`, deserialized as the values of a dictionary array,
+/// against `limits`. This is a no-op when `limits` is unlimited.
+pub(super) fn check_dictionary_values(dict: &BinaryPageDict, limits: &BinaryLimits) -> Result<()> {
+    if limits.is_unlimited() {
+        return Ok(());
+    }
+    let mut decoded = 0;
+    dict.offsets()
+        .windows(2)
+        .try_for_each(|x| limits.check((x[1] - x[0]) as usize, &mut decoded))
+}
 
 /// [`Pushable`] for variable length binary data.
 #[derive(Debug)]
@@ -50,10 +193,51 @@ impl<O: Offset> Binary<O> {
     #[inline]
     pub fn push(&mut self, v: &[u8]) {
         self.values.extend(v);
-        self.last_offset += O::from_usize(v.len()).unwrap();
+        // values whose offsets overflow `O` are an error when the array is finished
+        self.last_offset = O::from_usize(self.values.len()).unwrap_or(self.last_offset);
         self.offsets.push(self.last_offset)
     }
 
+    /// Errors iff the values do not fit in the offsets of `data_type`
+    #[inline]
+    pub fn check_offsets(&self, data_type: &DataType) -> Result<()> {
+        if O::from_usize(self.values.len()).is_none() {
+            return Err(ArrowError::offset_overflow(data_type));
+        }
+        Ok(())
+    }
+
+    /// Applies `validation` to the values, which are assumed to be utf8. Invalid values are
+    /// nulls in `validity` (which is empty when all values are valid) or replaced.
+    /// Under [`Utf8Validation::Validate`], the values are left to be validated (and error)
+    /// when the array is created.
+    pub fn validate_utf8(&mut self, validity: &mut MutableBitmap, validation: Utf8Validation) {
+        if validation == Utf8Validation::Validate
+            || try_check_offsets_and_utf8(&self.offsets.0, &self.values).is_ok()
+        {
+            return;
+        }
+
+        let length = self.len();
+        if validation == Utf8Validation::Skip && validity.is_empty() {
+            validity.extend_constant(length, true);
+        }
+        let mut validated = Self::with_capacity(length);
+        validated.values.reserve(self.values.len());
+        for (index, window) in self.offsets.0.windows(2).enumerate() {
+            let value = &self.values[window[0].to_usize()..window[1].to_usize()];
+            if simdutf8::basic::from_utf8(value).is_ok() {
+                validated.push(value);
+            } else if validation == Utf8Validation::Skip {
+                validated.push(&[]);
+                validity.set(index, false);
+            } else {
+                validated.push(String::from_utf8_lossy(value).as_bytes());
+            }
+        }
+        *self = validated;
+    }
+
     #[inline]
     pub fn extend_constant(&mut self, additional: usize) {
         self.offsets.extend_constant(additional, self.last_offset);
@@ -3,7 +3,7 @@ use std::collections::VecDeque;
 use parquet2::{encoding::Encoding, page::DataPage, schema::Repetition};
 
 use crate::{
-    array::BooleanArray,
+    array::{BooleanArray, MutableBooleanArray},
     bitmap::{utils::BitmapIter, MutableBitmap},
     datatypes::DataType,
     error::Result,
@@ -139,6 +139,26 @@ fn finish(data_type: &DataType, values: MutableBitmap, validity: MutableBitmap)
     BooleanArray::new(data_type.clone(), values.into(), validity.into())
 }
 
+/// Replaces the values of `array` by the deserialized `pages`, reusing its allocations.
+pub fn read_into<I: DataPages>(mut pages: I, array: &mut MutableBooleanArray) -> Result<()> {
+    let (data_type, mut values, validity) = std::mem::take(array).into_data();
+    values.clear();
+    let mut validity = validity.unwrap_or_default();
+    validity.clear();
+
+    let mut decoded = (values, validity);
+    let result = utils::extend_from_pages(&mut pages, &mut decoded, &BooleanDecoder::default());
+
+    let (values, validity) = decoded;
+    let validity = if validity.is_empty() {
+        None
+    } else {
+        Some(validity)
+    };
+    *array = MutableBooleanArray::from_data(data_type, values, validity);
+    result
+}
+
 /// An iterator adapter over [`DataPages`] assumed to be encoded as boolean arrays
 #[derive(Debug)]
 pub struct Iter<I: DataPages> {
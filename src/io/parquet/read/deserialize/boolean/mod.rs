@@ -11,6 +11,7 @@ use super::{
     DataPages,
 };
 
+pub(super) use self::basic::read_into;
 pub use self::basic::Iter;
 
 /// Converts [`DataPages`] to an [`Iterator`] of [`Array`]
@@ -13,6 +13,9 @@ use crate::{
 };
 
 use super::{
+    nested_utils::{
+        self, extend_offsets1, read_optional_values, InitNested, NestedPage, NestedState,
+    },
     utils::{self, extend_from_decoder, DecodedState, Decoder, MaybeNext, OptionalPageValidity},
     DataPages,
 };
@@ -203,7 +206,7 @@ pub(super) fn next_dict<
     'a,
     K: DictionaryKey,
     I: DataPages,
-    F: Fn(&dyn DictPage) -> Arc<dyn Array>,
+    F: Fn(&dyn DictPage) -> Result<Arc<dyn Array>>,
 >(
     iter: &'a mut I,
     items: &mut VecDeque<(Vec<K>, MutableBitmap)>,
@@ -226,9 +229,10 @@ pub(super) fn next_dict<
                         "dictionary arrays from non-dict-encoded pages",
                     )));
                 }
-                (Dict::Empty, Some(dict_page)) => {
-                    *dict = Dict::Complete(read_dict(dict_page.as_ref()))
-                }
+                (Dict::Empty, Some(dict_page)) => match read_dict(dict_page.as_ref()) {
+                    Ok(values) => *dict = Dict::Complete(values),
+                    Err(e) => return MaybeNext::Some(Err(e)),
+                },
                 (Dict::Complete(_), _) => {}
             };
 
@@ -265,3 +269,200 @@ pub(super) fn next_dict<
         }
     }
 }
+
+// The state of a `DataPage` of dictionary-encoded keys of a nested column
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum NestedDictState<'a, K>
+where
+    K: DictionaryKey,
+{
+    Optional(
+        nested_utils::Optional<'a>,
+        std::iter::Map<HybridRleDecoder<'a>, Box<dyn Fn(u32) -> K + 'a>>,
+    ),
+    Required(Required<'a, K>),
+}
+
+impl<'a, K> utils::PageState<'a> for NestedDictState<'a, K>
+where
+    K: DictionaryKey,
+{
+    fn len(&self) -> usize {
+        match self {
+            NestedDictState::Optional(validity, _) => validity.len(),
+            NestedDictState::Required(required) => required.values.size_hint().0,
+        }
+    }
+}
+
+/// A [`Decoder`] of the keys of a nested column, whose validity is derived from the
+/// definition levels of the page.
+#[derive(Debug)]
+pub struct NestedDictDecoder<K>
+where
+    K: DictionaryKey,
+{
+    phantom_k: std::marker::PhantomData<K>,
+}
+
+impl<K> Default for NestedDictDecoder<K>
+where
+    K: DictionaryKey,
+{
+    #[inline]
+    fn default() -> Self {
+        Self {
+            phantom_k: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, K> utils::Decoder<'a> for NestedDictDecoder<K>
+where
+    K: DictionaryKey,
+{
+    type State = NestedDictState<'a, K>;
+    type DecodedState = (Vec<K>, MutableBitmap);
+
+    fn build_state(&self, page: &'a DataPage) -> Result<Self::State> {
+        let is_optional =
+            page.descriptor().type_().get_basic_info().repetition() == &Repetition::Optional;
+
+        match (page.encoding(), is_optional) {
+            (Encoding::PlainDictionary | Encoding::RleDictionary, false) => {
+                Ok(NestedDictState::Required(Required::new(page)))
+            }
+            (Encoding::PlainDictionary | Encoding::RleDictionary, true) => {
+                let (_, _, indices_buffer) = utils::split_buffer(page);
+                Ok(NestedDictState::Optional(
+                    nested_utils::Optional::new(page),
+                    values_iter1(indices_buffer, page.num_values()),
+                ))
+            }
+            _ => Err(utils::not_implemented(
+                &page.encoding(),
+                is_optional,
+                false,
+                "any",
+                "Primitive",
+            )),
+        }
+    }
+
+    fn with_capacity(&self, capacity: usize) -> Self::DecodedState {
+        (
+            Vec::<K>::with_capacity(capacity),
+            MutableBitmap::with_capacity(capacity),
+        )
+    }
+
+    fn extend_from_state(
+        &self,
+        state: &mut Self::State,
+        decoded: &mut Self::DecodedState,
+        remaining: usize,
+    ) {
+        let (values, validity) = decoded;
+        match state {
+            NestedDictState::Optional(page_validity, page_values) => {
+                let max_def = page_validity.max_def();
+                read_optional_values(
+                    page_validity.definition_levels.by_ref(),
+                    max_def,
+                    page_values.by_ref(),
+                    values,
+                    validity,
+                    remaining,
+                )
+            }
+            NestedDictState::Required(page) => {
+                values.extend(page.values.by_ref().take(remaining));
+            }
+        }
+    }
+}
+
+#[inline]
+fn finish_nested<K: DictionaryKey>(
+    nested: NestedState,
+    (values, validity): (Vec<K>, MutableBitmap),
+    dict: &Dict,
+) -> MaybeNext<Result<(NestedState, DictionaryArray<K>)>> {
+    let keys = finish_key(values, validity);
+    MaybeNext::Some(Ok((
+        nested,
+        DictionaryArray::from_data(keys, dict.unwrap()),
+    )))
+}
+
+/// Like [`next_dict`], but for a nested column whose leaves are dictionary-encoded.
+#[inline]
+pub(super) fn nested_next_dict<
+    K: DictionaryKey,
+    I: DataPages,
+    F: Fn(&dyn DictPage) -> Result<Arc<dyn Array>>,
+>(
+    iter: &mut I,
+    items: &mut VecDeque<(Vec<K>, MutableBitmap)>,
+    nested_items: &mut VecDeque<NestedState>,
+    init: &InitNested,
+    dict: &mut Dict,
+    chunk_size: usize,
+    read_dict: F,
+) -> MaybeNext<Result<(NestedState, DictionaryArray<K>)>> {
+    if items.len() > 1 {
+        let nested = nested_items.pop_front().unwrap();
+        let decoded = items.pop_front().unwrap();
+        return finish_nested(nested, decoded, dict);
+    }
+    match iter.next() {
+        Err(e) => MaybeNext::Some(Err(e.into())),
+        Ok(Some(page)) => {
+            // consume the dictionary page
+            match (&dict, page.dictionary_page()) {
+                (Dict::Empty, None) => {
+                    return MaybeNext::Some(Err(ArrowError::nyi(
+                        "dictionary arrays from non-dict-encoded pages",
+                    )));
+                }
+                (Dict::Empty, Some(dict_page)) => match read_dict(dict_page.as_ref()) {
+                    Ok(values) => *dict = Dict::Complete(values),
+                    Err(e) => return MaybeNext::Some(Err(e)),
+                },
+                (Dict::Complete(_), _) => {}
+            };
+
+            // there is a new page => consume the page from the start
+            let mut nested_page = NestedPage::new(page);
+
+            extend_offsets1(&mut nested_page, init, nested_items, chunk_size);
+
+            let decoder = NestedDictDecoder::<K>::default();
+            let page = match decoder.build_state(page) {
+                Ok(page) => page,
+                Err(e) => return MaybeNext::Some(Err(e)),
+            };
+
+            nested_utils::extend_from_new_page(page, items, nested_items, &decoder);
+
+            if nested_items.front().unwrap().len() < chunk_size {
+                MaybeNext::More
+            } else {
+                let nested = nested_items.pop_front().unwrap();
+                let decoded = items.pop_front().unwrap();
+                finish_nested(nested, decoded, dict)
+            }
+        }
+        Ok(None) => {
+            if let Some(nested) = nested_items.pop_front() {
+                // we have a populated item and no more pages
+                // the only case where an item's length may be smaller than chunk_size
+                let decoded = items.pop_front().unwrap();
+                finish_nested(nested, decoded, dict)
+            } else {
+                MaybeNext::None
+            }
+        }
+    }
+}
@@ -172,6 +172,7 @@ impl<'a> Decoder<'a> for BinaryDecoder {
                 &mut page.values,
             ),
             State::Required(page) => {
+                let remaining = remaining.min(page.remaining);
                 page.remaining -= remaining;
                 for x in page.values.by_ref().take(remaining) {
                     values.push(x)
@@ -201,6 +202,7 @@ impl<'a> Decoder<'a> for BinaryDecoder {
                     &dict_values[index * size..(index + 1) * size]
                 };
 
+                let remaining = remaining.min(page.remaining);
                 page.remaining -= remaining;
                 for x in page.values.by_ref().map(op).take(remaining) {
                     values.push(x)
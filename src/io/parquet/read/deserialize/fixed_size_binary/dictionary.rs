@@ -10,6 +10,7 @@ use crate::{
 };
 
 use super::super::dictionary::*;
+use super::super::nested_utils::{InitNested, NestedState};
 use super::super::utils::MaybeNext;
 use super::super::DataPages;
 
@@ -74,7 +75,72 @@ where
             &mut self.items,
             &mut self.values,
             self.chunk_size,
-            |dict| read_dict(self.data_type.clone(), dict),
+            |dict| Ok(read_dict(self.data_type.clone(), dict)),
+        );
+        match maybe_state {
+            MaybeNext::Some(Ok(dict)) => Some(Ok(dict)),
+            MaybeNext::Some(Err(e)) => Some(Err(e)),
+            MaybeNext::None => None,
+            MaybeNext::More => self.next(),
+        }
+    }
+}
+
+/// An iterator adapter over [`DataPages`] of a nested column whose leaves are assumed to be
+/// encoded as parquet's dictionary-encoded fixed-length binary representation
+#[derive(Debug)]
+pub struct NestedDictIter<K, I>
+where
+    I: DataPages,
+    K: DictionaryKey,
+{
+    iter: I,
+    init: InitNested,
+    data_type: DataType,
+    values: Dict,
+    items: VecDeque<(Vec<K>, MutableBitmap)>,
+    nested: VecDeque<NestedState>,
+    chunk_size: usize,
+}
+
+impl<K, I> NestedDictIter<K, I>
+where
+    K: DictionaryKey,
+    I: DataPages,
+{
+    pub fn new(iter: I, init: InitNested, data_type: DataType, chunk_size: usize) -> Self {
+        let data_type = match data_type {
+            DataType::Dictionary(_, values, _) => values.as_ref().clone(),
+            _ => unreachable!(),
+        };
+        Self {
+            iter,
+            init,
+            data_type,
+            values: Dict::Empty,
+            items: VecDeque::new(),
+            nested: VecDeque::new(),
+            chunk_size,
+        }
+    }
+}
+
+impl<K, I> Iterator for NestedDictIter<K, I>
+where
+    I: DataPages,
+    K: DictionaryKey,
+{
+    type Item = Result<(NestedState, DictionaryArray<K>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let maybe_state = nested_next_dict(
+            &mut self.iter,
+            &mut self.items,
+            &mut self.nested,
+            &self.init,
+            &mut self.values,
+            self.chunk_size,
+            |dict| Ok(read_dict(self.data_type.clone(), dict)),
         );
         match maybe_state {
             MaybeNext::Some(Ok(dict)) => Some(Ok(dict)),
@@ -11,13 +11,15 @@ mod struct_;
 mod utils;
 
 use crate::{
-    array::{Array, BinaryArray, ListArray, Utf8Array},
-    datatypes::{DataType, Field},
+    array::{Array, BinaryArray, DictionaryArray, DictionaryKey, ListArray, Utf8Array},
+    datatypes::{DataType, Field, IntervalUnit},
     error::{ArrowError, Result},
 };
 
 use self::nested_utils::{InitNested, NestedArrayIter, NestedState};
+pub use binary::{BinaryLimits, Utf8Validation};
 use simple::page_iter_to_arrays;
+pub use simple::read_pages_into;
 
 use super::*;
 
@@ -41,7 +43,7 @@ fn create_list(
     nested: &mut NestedState,
     values: Arc<dyn Array>,
 ) -> Result<Arc<dyn Array>> {
-    Ok(match data_type {
+    Ok(match data_type.to_logical_type() {
         DataType::List(_) => {
             let (mut offsets, validity) = nested.nested.pop().unwrap().inner();
             offsets.push(values.len() as i64);
@@ -80,6 +82,7 @@ fn columns_to_iter_recursive<'a, I: 'a>(
     field: Field,
     mut init: Vec<InitNested>,
     chunk_size: usize,
+    limits: BinaryLimits,
 ) -> Result<NestedArrayIter<'a>>
 where
     I: DataPages,
@@ -92,6 +95,7 @@ where
                 types.pop().unwrap(),
                 field.data_type,
                 chunk_size,
+                limits,
             )?
             .map(|x| Ok((NestedState::new(vec![]), x?))),
         ));
@@ -149,6 +153,7 @@ where
                 init.pop().unwrap(),
                 field.data_type().clone(),
                 chunk_size,
+                limits,
             )
         }
         LargeBinary => {
@@ -158,8 +163,18 @@ where
                 init.pop().unwrap(),
                 field.data_type().clone(),
                 chunk_size,
+                limits,
             )
         }
+        Dictionary(key_type, _, _) => {
+            types.pop();
+            let iter = columns.pop().unwrap();
+            let init = init.pop().unwrap();
+            let data_type = field.data_type().clone();
+            match_integer_type!(key_type, |$K| {
+                dict_read_nested::<$K, _>(iter, init, data_type, chunk_size, limits)?
+            })
+        }
         List(inner) => {
             let iter = columns_to_iter_recursive(
                 vec![columns.pop().unwrap()],
@@ -167,6 +182,7 @@ where
                 inner.as_ref().clone(),
                 init,
                 chunk_size,
+                limits,
             )?;
             let iter = iter.map(move |x| {
                 let (mut nested, array) = x?;
@@ -186,16 +202,132 @@ where
                         f.clone(),
                         vec![init.pop().unwrap()],
                         chunk_size,
+                        limits,
                     )
                 })
                 .collect::<Result<Vec<_>>>()?;
             let columns = columns.into_iter().rev().collect();
-            Box::new(struct_::StructIterator::new(columns, fields.clone()))
+            Box::new(struct_::StructIterator::new(columns, field.data_type))
         }
         _ => todo!(),
     })
 }
 
+fn dyn_nested_dict<'a, K, I>(iter: I) -> NestedArrayIter<'a>
+where
+    K: DictionaryKey,
+    I: Iterator<Item = Result<(NestedState, DictionaryArray<K>)>> + Send + Sync + 'a,
+{
+    Box::new(iter.map(|x| {
+        x.map(|(mut nested, array)| {
+            let _ = nested.nested.pop().unwrap(); // the primitive
+            (nested, Arc::new(array) as Arc<dyn Array>)
+        })
+    }))
+}
+
+/// Reads nested columns whose leaves are dictionary-encoded into [`DictionaryArray`]s,
+/// preserving the dictionary.
+fn dict_read_nested<'a, K: DictionaryKey, I: 'a + DataPages>(
+    iter: I,
+    init: InitNested,
+    data_type: DataType,
+    chunk_size: usize,
+    limits: BinaryLimits,
+) -> Result<NestedArrayIter<'a>> {
+    use DataType::*;
+    let values_data_type = if let Dictionary(_, v, _) = data_type.to_logical_type() {
+        v.as_ref()
+    } else {
+        panic!()
+    };
+
+    Ok(match values_data_type.to_logical_type() {
+        UInt8 => dyn_nested_dict(primitive::NestedDictIter::<K, _, _, _, _>::new(
+            iter,
+            init,
+            data_type,
+            chunk_size,
+            |x: i32| x as u8,
+        )),
+        UInt16 => dyn_nested_dict(primitive::NestedDictIter::<K, _, _, _, _>::new(
+            iter,
+            init,
+            data_type,
+            chunk_size,
+            |x: i32| x as u16,
+        )),
+        UInt32 => dyn_nested_dict(primitive::NestedDictIter::<K, _, _, _, _>::new(
+            iter,
+            init,
+            data_type,
+            chunk_size,
+            |x: i32| x as u32,
+        )),
+        Int8 => dyn_nested_dict(primitive::NestedDictIter::<K, _, _, _, _>::new(
+            iter,
+            init,
+            data_type,
+            chunk_size,
+            |x: i32| x as i8,
+        )),
+        Int16 => dyn_nested_dict(primitive::NestedDictIter::<K, _, _, _, _>::new(
+            iter,
+            init,
+            data_type,
+            chunk_size,
+            |x: i32| x as i16,
+        )),
+        Int32 | Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => {
+            dyn_nested_dict(primitive::NestedDictIter::<K, _, _, _, _>::new(
+                iter,
+                init,
+                data_type,
+                chunk_size,
+                |x: i32| x,
+            ))
+        }
+        Int64 | Date64 | Time64(_) | Duration(_) => {
+            dyn_nested_dict(primitive::NestedDictIter::<K, _, _, _, _>::new(
+                iter,
+                init,
+                data_type,
+                chunk_size,
+                |x: i64| x,
+            ))
+        }
+        Float32 => dyn_nested_dict(primitive::NestedDictIter::<K, _, _, _, _>::new(
+            iter,
+            init,
+            data_type,
+            chunk_size,
+            |x: f32| x,
+        )),
+        Float64 => dyn_nested_dict(primitive::NestedDictIter::<K, _, _, _, _>::new(
+            iter,
+            init,
+            data_type,
+            chunk_size,
+            |x: f64| x,
+        )),
+        Utf8 | Binary => dyn_nested_dict(binary::NestedDictIter::<K, i32, _>::new(
+            iter, init, data_type, chunk_size, limits,
+        )),
+        LargeUtf8 | LargeBinary => dyn_nested_dict(binary::NestedDictIter::<K, i64, _>::new(
+            iter, init, data_type, chunk_size, limits,
+        )),
+        FixedSizeBinary(_) => dyn_nested_dict(fixed_size_binary::NestedDictIter::<K, _>::new(
+            iter, init, data_type, chunk_size,
+        )),
+        other => {
+            return Err(ArrowError::nyi(format!(
+                "Reading nested dictionaries of type {:?}",
+                other
+            )))
+        }
+    })
+}
+
 fn field_to_init(field: &Field) -> Vec<InitNested> {
     use crate::datatypes::PhysicalType::*;
     match field.data_type.to_physical_type() {
@@ -244,10 +376,25 @@ pub fn column_iter_to_arrays<'a, I: 'a>(
 ) -> Result<ArrayIter<'a>>
 where
     I: DataPages,
+{
+    column_iter_to_arrays_with_limits(columns, types, field, chunk_size, BinaryLimits::default())
+}
+
+/// Same as [`column_iter_to_arrays`], decoding binary and utf8 columns within `limits`.
+pub fn column_iter_to_arrays_with_limits<'a, I>(
+    columns: Vec<I>,
+    types: Vec<&ParquetType>,
+    field: Field,
+    chunk_size: usize,
+    limits: BinaryLimits,
+) -> Result<ArrayIter<'a>>
+where
+    I: 'a + DataPages,
 {
     let init = field_to_init(&field);
 
     Ok(Box::new(
-        columns_to_iter_recursive(columns, types, field, init, chunk_size)?.map(|x| x.map(|x| x.1)),
+        columns_to_iter_recursive(columns, types, field, init, chunk_size, limits)?
+            .map(|x| x.map(|x| x.1)),
     ))
 }
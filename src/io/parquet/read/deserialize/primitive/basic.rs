@@ -231,6 +231,31 @@ pub(super) fn finish<T: NativeType>(
     MutablePrimitiveArray::from_data(data_type.clone(), values, validity)
 }
 
+/// Replaces the values of `array` by the deserialized `pages`, reusing its allocations.
+pub fn read_into<T, I, P, F>(
+    mut pages: I,
+    array: &mut MutablePrimitiveArray<T>,
+    op: F,
+) -> Result<()>
+where
+    I: DataPages,
+    T: NativeType,
+    P: ParquetNativeType,
+    F: Copy + Fn(P) -> T,
+{
+    let (data_type, mut values, validity) = std::mem::take(array).into_data();
+    values.clear();
+    let mut validity = validity.unwrap_or_default();
+    validity.clear();
+
+    let mut decoded = (values, validity);
+    let result = utils::extend_from_pages(&mut pages, &mut decoded, &PrimitiveDecoder::new(op));
+
+    let (values, validity) = decoded;
+    *array = finish(&data_type, values, validity);
+    result
+}
+
 /// An iterator adapter over [`DataPages`] assumed to be encoded as primitive arrays
 #[derive(Debug)]
 pub struct Iter<T, I, P, F>
@@ -14,6 +14,7 @@ use crate::{
 };
 
 use super::super::dictionary::*;
+use super::super::nested_utils::{InitNested, NestedState};
 use super::super::utils::MaybeNext;
 use super::super::DataPages;
 
@@ -94,7 +95,85 @@ where
             &mut self.items,
             &mut self.values,
             self.chunk_size,
-            |dict| read_dict::<P, T, _>(self.data_type.clone(), self.op, dict),
+            |dict| Ok(read_dict::<P, T, _>(self.data_type.clone(), self.op, dict)),
+        );
+        match maybe_state {
+            MaybeNext::Some(Ok(dict)) => Some(Ok(dict)),
+            MaybeNext::Some(Err(e)) => Some(Err(e)),
+            MaybeNext::None => None,
+            MaybeNext::More => self.next(),
+        }
+    }
+}
+
+/// An iterator adapter over [`DataPages`] of a nested column whose leaves are assumed to be
+/// dictionary-encoded primitive values
+#[derive(Debug)]
+pub struct NestedDictIter<K, T, I, P, F>
+where
+    I: DataPages,
+    T: NativeType,
+    K: DictionaryKey,
+    P: ParquetNativeType,
+    F: Fn(P) -> T,
+{
+    iter: I,
+    init: InitNested,
+    data_type: DataType,
+    values: Dict,
+    items: VecDeque<(Vec<K>, MutableBitmap)>,
+    nested: VecDeque<NestedState>,
+    chunk_size: usize,
+    op: F,
+    phantom: std::marker::PhantomData<P>,
+}
+
+impl<K, T, I, P, F> NestedDictIter<K, T, I, P, F>
+where
+    K: DictionaryKey,
+    I: DataPages,
+    T: NativeType,
+    P: ParquetNativeType,
+    F: Copy + Fn(P) -> T,
+{
+    pub fn new(iter: I, init: InitNested, data_type: DataType, chunk_size: usize, op: F) -> Self {
+        let data_type = match data_type {
+            DataType::Dictionary(_, values, _) => *values,
+            _ => data_type,
+        };
+        Self {
+            iter,
+            init,
+            data_type,
+            values: Dict::Empty,
+            items: VecDeque::new(),
+            nested: VecDeque::new(),
+            chunk_size,
+            op,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<K, T, I, P, F> Iterator for NestedDictIter<K, T, I, P, F>
+where
+    I: DataPages,
+    T: NativeType,
+    K: DictionaryKey,
+    P: ParquetNativeType,
+    F: Copy + Fn(P) -> T,
+{
+    type Item = Result<(NestedState, DictionaryArray<K>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let maybe_state = nested_next_dict(
+            &mut self.iter,
+            &mut self.items,
+            &mut self.nested,
+            &self.init,
+            &mut self.values,
+            self.chunk_size,
+            |dict| Ok(read_dict::<P, T, _>(self.data_type.clone(), self.op, dict)),
         );
         match maybe_state {
             MaybeNext::Some(Ok(dict)) => Some(Ok(dict)),
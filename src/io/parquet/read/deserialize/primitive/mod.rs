@@ -2,7 +2,7 @@ mod basic;
 mod dictionary;
 mod nested;
 
-pub use dictionary::DictIter;
+pub use dictionary::{DictIter, NestedDictIter};
 
 use std::sync::Arc;
 
@@ -10,6 +10,7 @@ use crate::{array::Array, datatypes::DataType};
 
 use super::{nested_utils::*, DataPages};
 
+pub(super) use basic::read_into;
 pub use basic::Iter;
 use nested::ArrayIterator;
 
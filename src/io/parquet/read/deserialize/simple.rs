@@ -1,21 +1,23 @@
 use std::sync::Arc;
 
 use parquet2::{
-    schema::types::{
-        LogicalType, ParquetType, PhysicalType, TimeUnit as ParquetTimeUnit, TimestampType,
-    },
+    schema::types::{ParquetType, PhysicalType, TimeUnit as ParquetTimeUnit},
     types::int96_to_i64_ns,
 };
 
 use crate::{
-    array::{Array, BinaryArray, DictionaryKey, MutablePrimitiveArray, PrimitiveArray, Utf8Array},
+    array::{
+        Array, BinaryArray, DictionaryKey, MutableArray, MutableBinaryArray, MutableBooleanArray,
+        MutablePrimitiveArray, MutableUtf8Array, PrimitiveArray, Utf8Array,
+    },
     datatypes::{DataType, IntervalUnit, TimeUnit},
     error::{ArrowError, Result},
     types::NativeType,
 };
 
+use super::super::schema::timestamp_unit;
 use super::super::{ArrayIter, DataPages};
-use super::binary;
+use super::binary::{self, BinaryLimits};
 use super::boolean;
 use super::fixed_size_binary;
 use super::null;
@@ -63,16 +65,18 @@ pub fn page_iter_to_arrays<'a, I: 'a + DataPages>(
     type_: &ParquetType,
     data_type: DataType,
     chunk_size: usize,
+    limits: BinaryLimits,
 ) -> Result<ArrayIter<'a>> {
     use DataType::*;
 
-    let (physical_type, logical_type) = if let ParquetType::PrimitiveType {
+    let (physical_type, logical_type, converted_type) = if let ParquetType::PrimitiveType {
         physical_type,
         logical_type,
+        converted_type,
         ..
     } = type_
     {
-        (physical_type, logical_type)
+        (physical_type, logical_type, converted_type)
     } else {
         return Err(ArrowError::InvalidArgumentError(
             "page_iter_to_arrays can only be called with a parquet primitive type".into(),
@@ -121,7 +125,7 @@ pub fn page_iter_to_arrays<'a, I: 'a + DataPages>(
             return timestamp(
                 pages,
                 physical_type,
-                logical_type,
+                timestamp_unit(logical_type, converted_type),
                 data_type,
                 chunk_size,
                 time_unit,
@@ -210,21 +214,28 @@ pub fn page_iter_to_arrays<'a, I: 'a + DataPages>(
         ))),
 
         Binary => dyn_iter(binary::Iter::<i32, BinaryArray<i32>, _>::new(
-            pages, data_type, chunk_size,
+            pages, data_type, chunk_size, limits,
         )),
         LargeBinary => dyn_iter(binary::Iter::<i64, BinaryArray<i64>, _>::new(
-            pages, data_type, chunk_size,
+            pages, data_type, chunk_size, limits,
         )),
         Utf8 => dyn_iter(binary::Iter::<i32, Utf8Array<i32>, _>::new(
-            pages, data_type, chunk_size,
+            pages, data_type, chunk_size, limits,
         )),
         LargeUtf8 => dyn_iter(binary::Iter::<i64, Utf8Array<i64>, _>::new(
-            pages, data_type, chunk_size,
+            pages, data_type, chunk_size, limits,
         )),
 
         Dictionary(key_type, _, _) => {
             return match_integer_type!(key_type, |$K| {
-                dict_read::<$K, _>(pages, physical_type, logical_type, data_type, chunk_size)
+                dict_read::<$K, _>(
+                    pages,
+                    physical_type,
+                    timestamp_unit(logical_type, converted_type),
+                    data_type,
+                    chunk_size,
+                    limits,
+                )
             })
         }
 
@@ -237,10 +248,65 @@ pub fn page_iter_to_arrays<'a, I: 'a + DataPages>(
     })
 }
 
+/// Downcasts `array` to `A`, erroring if `array` is not an `A`.
+fn downcast_mut<A: MutableArray + 'static>(array: &mut dyn MutableArray) -> Result<&mut A> {
+    let data_type = array.data_type().clone();
+    array.as_mut_any().downcast_mut::<A>().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "The mutable array of DataType {:?} is not a {}",
+            data_type,
+            std::any::type_name::<A>()
+        ))
+    })
+}
+
+/// Deserializes `pages` into `array`, replacing its values while reusing its allocations.
+///
+/// This is an alternative to [`page_iter_to_arrays`] for long scans with a stable schema:
+/// deserializing the column chunks of every row group into the same `array` avoids
+/// re-allocating its buffers on every chunk.
+/// The pages are decoded according to `array.data_type()`, which must have been
+/// inferred from the parquet type of the column (see [`infer_schema`](super::super::infer_schema)).
+/// # Errors
+/// This function errors iff:
+/// * the pages can't be decoded
+/// * `array` is not the mutable array of its [`DataType`] (e.g. [`MutableUtf8Array<i32>`] for [`DataType::Utf8`])
+/// * its [`DataType`] is not (yet) supported: only primitive, boolean, binary and utf8 types are.
+///
+/// On error, the contents of `array` are unspecified.
+pub fn read_pages_into<I: DataPages>(pages: I, array: &mut dyn MutableArray) -> Result<()> {
+    use DataType::*;
+    match array.data_type().to_logical_type().clone() {
+        Boolean => boolean::read_into(pages, downcast_mut::<MutableBooleanArray>(array)?),
+        UInt8 => primitive::read_into(pages, downcast_mut(array)?, |x: i32| x as u8),
+        UInt16 => primitive::read_into(pages, downcast_mut(array)?, |x: i32| x as u16),
+        UInt32 => primitive::read_into(pages, downcast_mut(array)?, |x: i32| x as u32),
+        Int8 => primitive::read_into(pages, downcast_mut(array)?, |x: i32| x as i8),
+        Int16 => primitive::read_into(pages, downcast_mut(array)?, |x: i32| x as i16),
+        Int32 | Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => {
+            primitive::read_into(pages, downcast_mut(array)?, |x: i32| x)
+        }
+        Int64 | Date64 | Time64(_) | Duration(_) => {
+            primitive::read_into(pages, downcast_mut(array)?, |x: i64| x)
+        }
+        UInt64 => primitive::read_into(pages, downcast_mut(array)?, |x: i64| x as u64),
+        Float32 => primitive::read_into(pages, downcast_mut(array)?, |x: f32| x),
+        Float64 => primitive::read_into(pages, downcast_mut(array)?, |x: f64| x),
+        Binary => binary::read_into(pages, downcast_mut::<MutableBinaryArray<i32>>(array)?),
+        LargeBinary => binary::read_into(pages, downcast_mut::<MutableBinaryArray<i64>>(array)?),
+        Utf8 => binary::read_into(pages, downcast_mut::<MutableUtf8Array<i32>>(array)?),
+        LargeUtf8 => binary::read_into(pages, downcast_mut::<MutableUtf8Array<i64>>(array)?),
+        other => Err(ArrowError::NotYetImplemented(format!(
+            "Reading {:?} from parquet into a mutable array still not implemented",
+            other
+        ))),
+    }
+}
+
 fn timestamp<'a, I: 'a + DataPages>(
     pages: I,
     physical_type: &PhysicalType,
-    logical_type: &Option<LogicalType>,
+    unit: Option<ParquetTimeUnit>,
     data_type: DataType,
     chunk_size: usize,
     time_unit: TimeUnit,
@@ -267,7 +333,7 @@ fn timestamp<'a, I: 'a + DataPages>(
 
     let iter = primitive::Iter::new(pages, data_type, chunk_size, |x: i64| x);
 
-    let unit = if let Some(LogicalType::TIMESTAMP(TimestampType { unit, .. })) = logical_type {
+    let unit = if let Some(unit) = unit {
         unit
     } else {
         return Ok(dyn_iter(iden(iter)));
@@ -295,7 +361,7 @@ fn timestamp<'a, I: 'a + DataPages>(
 fn timestamp_dict<'a, K: DictionaryKey, I: 'a + DataPages>(
     pages: I,
     physical_type: &PhysicalType,
-    logical_type: &Option<LogicalType>,
+    unit: Option<ParquetTimeUnit>,
     data_type: DataType,
     chunk_size: usize,
     time_unit: TimeUnit,
@@ -315,7 +381,7 @@ fn timestamp_dict<'a, K: DictionaryKey, I: 'a + DataPages>(
         }
     };
 
-    let unit = if let Some(LogicalType::TIMESTAMP(TimestampType { unit, .. })) = logical_type {
+    let unit = if let Some(unit) = unit {
         unit
     } else {
         return Ok(dyn_iter(primitive::DictIter::<K, _, _, _, _>::new(
@@ -432,9 +498,10 @@ fn timestamp_dict<'a, K: DictionaryKey, I: 'a + DataPages>(
 fn dict_read<'a, K: DictionaryKey, I: 'a + DataPages>(
     iter: I,
     physical_type: &PhysicalType,
-    logical_type: &Option<LogicalType>,
+    unit: Option<ParquetTimeUnit>,
     data_type: DataType,
     chunk_size: usize,
+    limits: BinaryLimits,
 ) -> Result<ArrayIter<'a>> {
     use DataType::*;
     let values_data_type = if let Dictionary(_, v, _) = &data_type {
@@ -485,7 +552,7 @@ fn dict_read<'a, K: DictionaryKey, I: 'a + DataPages>(
             return timestamp_dict::<K, _>(
                 iter,
                 physical_type,
-                logical_type,
+                unit,
                 data_type,
                 chunk_size,
                 time_unit,
@@ -509,10 +576,10 @@ fn dict_read<'a, K: DictionaryKey, I: 'a + DataPages>(
         )),
 
         Utf8 | Binary => dyn_iter(binary::DictIter::<K, i32, _>::new(
-            iter, data_type, chunk_size,
+            iter, data_type, chunk_size, limits,
         )),
         LargeUtf8 | LargeBinary => dyn_iter(binary::DictIter::<K, i64, _>::new(
-            iter, data_type, chunk_size,
+            iter, data_type, chunk_size, limits,
         )),
         FixedSizeBinary(_) => dyn_iter(fixed_size_binary::DictIter::<K, _>::new(
             iter, data_type, chunk_size,
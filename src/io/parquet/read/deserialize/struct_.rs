@@ -1,20 +1,20 @@
 use std::sync::Arc;
 
 use crate::array::{Array, StructArray};
-use crate::datatypes::{DataType, Field};
+use crate::datatypes::DataType;
 use crate::error::ArrowError;
 
 use super::nested_utils::{NestedArrayIter, NestedState};
 
 pub struct StructIterator<'a> {
     iters: Vec<NestedArrayIter<'a>>,
-    fields: Vec<Field>,
+    data_type: DataType,
 }
 
 impl<'a> StructIterator<'a> {
-    pub fn new(iters: Vec<NestedArrayIter<'a>>, fields: Vec<Field>) -> Self {
-        assert_eq!(iters.len(), fields.len());
-        Self { iters, fields }
+    pub fn new(iters: Vec<NestedArrayIter<'a>>, data_type: DataType) -> Self {
+        assert_eq!(iters.len(), StructArray::get_fields(&data_type).len());
+        Self { iters, data_type }
     }
 }
 
@@ -39,11 +39,7 @@ impl<'a> Iterator for StructIterator<'a> {
         match values {
             Ok(values) => Some(Ok((
                 NestedState::new(vec![]), // todo
-                Arc::new(StructArray::from_data(
-                    DataType::Struct(self.fields.clone()),
-                    values,
-                    None,
-                )),
+                Arc::new(StructArray::from_data(self.data_type.clone(), values, None)),
             ))),
             Err(e) => Some(Err(e)),
         }
@@ -283,6 +283,24 @@ pub(super) fn extend_from_new_page<'a, T: Decoder<'a>>(
     }
 }
 
+/// Extends `decoded` by deserializing all pages of `iter`.
+pub(super) fn extend_from_pages<I, D, S>(
+    iter: &mut I,
+    decoded: &mut S,
+    decoder: &D,
+) -> Result<(), ArrowError>
+where
+    I: DataPages,
+    D: for<'a> Decoder<'a, DecodedState = S>,
+{
+    while let Some(page) = iter.next()? {
+        let mut page = decoder.build_state(page)?;
+        let additional = page.len();
+        decoder.extend_from_state(&mut page, decoded, additional);
+    }
+    Ok(())
+}
+
 #[derive(Debug)]
 pub enum MaybeNext<P> {
     Some(P),
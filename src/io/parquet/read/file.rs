@@ -4,12 +4,13 @@ use std::sync::Arc;
 use crate::array::Array;
 use crate::chunk::Chunk;
 use crate::datatypes::Schema;
-use crate::io::parquet::read::read_columns_many;
+use crate::io::parquet::read::{read_columns_many_with_limits, BinaryLimits};
 use crate::{
     datatypes::Field,
     error::{ArrowError, Result},
 };
 
+use super::context::with_row_group_context;
 use super::{infer_schema, read_metadata, FileMetaData, RowGroupDeserializer, RowGroupMetaData};
 
 type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool>;
@@ -112,6 +113,21 @@ impl<R: Read + Seek> FileReader<R> {
         self.row_groups.set_groups_filter(groups_filter);
     }
 
+    /// Sets the [`BinaryLimits`] that binary and utf8 columns are decoded within.
+    pub fn set_binary_limits(&mut self, limits: BinaryLimits) {
+        self.row_groups.set_binary_limits(limits);
+    }
+
+    /// Returns this reader restricted to the row groups at `indices`, read in the order
+    /// they are declared. See [`RowGroupReader::set_row_groups`] for details.
+    /// # Error
+    /// Errors iff any of the indices is out of bounds of the file's row groups.
+    pub fn with_row_groups(mut self, indices: Vec<usize>) -> Result<Self> {
+        self.row_groups.set_row_groups(indices)?;
+        self.current_row_group = None;
+        Ok(self)
+    }
+
     fn next_row_group(&mut self) -> Result<Option<RowGroupDeserializer>> {
         let result = self.row_groups.next().transpose()?;
 
@@ -177,7 +193,10 @@ pub struct RowGroupReader<R: Read + Seek> {
     schema: Schema,
     groups_filter: Option<GroupFilter>,
     row_groups: Vec<RowGroupMetaData>,
+    // indices of `row_groups` to read, in order
+    indices: Vec<usize>,
     chunk_size: Option<usize>,
+    binary_limits: BinaryLimits,
     remaining_rows: usize,
     current_group: usize,
 }
@@ -196,8 +215,10 @@ impl<R: Read + Seek> RowGroupReader<R> {
             reader,
             schema,
             groups_filter,
+            indices: (0..row_groups.len()).collect(),
             row_groups,
             chunk_size,
+            binary_limits: BinaryLimits::default(),
             remaining_rows: limit.unwrap_or(usize::MAX),
             current_group: 0,
         }
@@ -208,12 +229,36 @@ impl<R: Read + Seek> RowGroupReader<R> {
         self.groups_filter = Some(groups_filter);
     }
 
+    /// Sets the [`BinaryLimits`] that binary and utf8 columns are decoded within.
+    pub fn set_binary_limits(&mut self, limits: BinaryLimits) {
+        self.binary_limits = limits;
+    }
+
+    /// Sets the row groups to read to those at `indices`, in the order they are declared,
+    /// and restarts reading from the first of them.
+    /// Row groups are still subject to the groups filter, which is called with their
+    /// index in the file.
+    /// # Error
+    /// Errors iff any of the indices is out of bounds of the row groups.
+    pub fn set_row_groups(&mut self, indices: Vec<usize>) -> Result<()> {
+        if let Some(index) = indices.iter().find(|&&x| x >= self.row_groups.len()) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Row group index {} is out of bounds for a file with {} row groups",
+                index,
+                self.row_groups.len()
+            )));
+        }
+        self.indices = indices;
+        self.current_group = 0;
+        Ok(())
+    }
+
     #[inline]
     fn _next(&mut self) -> Result<Option<RowGroupDeserializer>> {
         if self.schema.fields.is_empty() {
             return Ok(None);
         }
-        if self.current_group == self.row_groups.len() {
+        if self.current_group == self.indices.len() {
             // reached the last row group
             return Ok(None);
         };
@@ -222,7 +267,7 @@ impl<R: Read + Seek> RowGroupReader<R> {
             return Ok(None);
         }
 
-        let current_row_group = self.current_group;
+        let current_row_group = self.indices[self.current_group];
         let row_group = &self.row_groups[current_row_group];
         if let Some(groups_filter) = self.groups_filter.as_ref() {
             if !(groups_filter)(current_row_group, row_group) {
@@ -232,12 +277,16 @@ impl<R: Read + Seek> RowGroupReader<R> {
         }
         self.current_group += 1;
 
-        let column_chunks = read_columns_many(
+        let column_chunks = read_columns_many_with_limits(
             &mut self.reader,
             row_group,
             self.schema.fields.clone(),
             self.chunk_size,
-        )?;
+            self.binary_limits,
+        )?
+        .into_iter()
+        .map(|iter| with_row_group_context(iter, current_row_group))
+        .collect();
 
         let result = RowGroupDeserializer::new(
             column_chunks,
@@ -259,7 +308,7 @@ impl<R: Read + Seek> Iterator for RowGroupReader<R> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.row_groups.len() - self.current_group;
+        let len = self.indices.len() - self.current_group;
         (len, Some(len))
     }
 }
@@ -0,0 +1,127 @@
+//! A resolved view of parquet's metadata in terms of arrow's [`Schema`].
+use std::convert::TryFrom;
+
+use parquet2::encoding::Encoding;
+
+use crate::datatypes::{Field, Schema};
+use crate::error::Result;
+
+use super::row_group::get_field_columns;
+use super::{infer_schema, ColumnChunkMetaData, FileMetaData};
+
+/// Metadata of a parquet file resolved against its arrow [`Schema`].
+#[derive(Debug, Clone)]
+pub struct ParquetFileInfo {
+    /// The arrow schema of the file
+    pub schema: Schema,
+    /// The total number of rows in the file
+    pub num_rows: usize,
+    /// The number of row groups in the file
+    pub num_row_groups: usize,
+    /// Information about each field of [`ParquetFileInfo::schema`], in the same order
+    pub fields: Vec<FieldInfo>,
+}
+
+/// Metadata of the parquet column chunks associated to an arrow [`Field`].
+#[derive(Debug, Clone)]
+pub struct FieldInfo {
+    /// The arrow field
+    pub field: Field,
+    /// The column chunks of this field, per row group. Nested fields have more than one
+    /// column chunk per row group, one per parquet leaf.
+    pub columns: Vec<Vec<ColumnChunkMetaData>>,
+    /// The sum of the compressed sizes of all column chunks of this field
+    pub compressed_size: u64,
+    /// The sum of the uncompressed sizes of all column chunks of this field
+    pub uncompressed_size: u64,
+    /// The number of nulls of this field across all row groups, or `None` when
+    /// it can't be derived from the statistics (missing statistics or nested fields).
+    pub null_count: Option<u64>,
+    /// The distinct encodings used by the column chunks of this field, in order of appearance
+    pub encodings: Vec<Encoding>,
+}
+
+impl ParquetFileInfo {
+    /// Returns a new [`ParquetFileInfo`] with the schema inferred via [`infer_schema`].
+    /// # Error
+    /// Errors iff the schema can't be inferred or the metadata contains an unknown encoding
+    /// or invalid statistics.
+    pub fn try_new(metadata: &FileMetaData) -> Result<Self> {
+        let schema = infer_schema(metadata)?;
+        Self::try_new_with_schema(metadata, schema)
+    }
+
+    /// Returns a new [`ParquetFileInfo`] whose fields are resolved against `schema`.
+    /// # Error
+    /// Errors iff the metadata contains an unknown encoding or invalid statistics.
+    pub fn try_new_with_schema(metadata: &FileMetaData, schema: Schema) -> Result<Self> {
+        let fields = schema
+            .fields
+            .iter()
+            .map(|field| field_info(metadata, field))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            schema,
+            num_rows: metadata.num_rows as usize,
+            num_row_groups: metadata.row_groups.len(),
+            fields,
+        })
+    }
+
+    /// Returns the [`FieldInfo`] of the field named `name`, if any.
+    pub fn field(&self, name: &str) -> Option<&FieldInfo> {
+        self.fields.iter().find(|x| x.field.name == name)
+    }
+}
+
+fn field_info(metadata: &FileMetaData, field: &Field) -> Result<FieldInfo> {
+    let columns = metadata
+        .row_groups
+        .iter()
+        .map(|row_group| {
+            get_field_columns(row_group.columns(), &field.name)
+                .into_iter()
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut compressed_size = 0;
+    let mut uncompressed_size = 0;
+    let mut encodings = vec![];
+    for column in columns.iter().flatten() {
+        compressed_size += column.compressed_size() as u64;
+        uncompressed_size += column.uncompressed_size() as u64;
+        for encoding in column.column_encoding() {
+            let encoding = Encoding::try_from(*encoding)?;
+            if !encodings.contains(&encoding) {
+                encodings.push(encoding)
+            }
+        }
+    }
+
+    // the null count of a leaf only corresponds to the null count of the field when
+    // the field is not nested
+    let mut null_count = Some(0u64);
+    for row_group in &columns {
+        null_count = match (null_count, row_group.as_slice()) {
+            (Some(count), [column]) if column.descriptor().path_in_schema().len() == 1 => {
+                match column.statistics().transpose()? {
+                    Some(statistics) => statistics.null_count().map(|x| count + x as u64),
+                    None => None,
+                }
+            }
+            _ => None,
+        };
+    }
+
+    Ok(FieldInfo {
+        field: field.clone(),
+        columns,
+        compressed_size,
+        uncompressed_size,
+        null_count,
+        encodings,
+    })
+}
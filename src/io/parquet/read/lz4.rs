@@ -0,0 +1,114 @@
+use std::io::Read;
+
+use parquet2::{
+    compression::Compression,
+    error::ParquetError,
+    metadata::ColumnChunkMetaData,
+    page::{CompressedDataPage, DataPage, DataPageHeader},
+    read::{decompress, PageFilter, PageIterator},
+    FallibleStreamingIterator,
+};
+
+use super::super::lz4::decompress_lz4;
+
+/// A decompressor of data pages like [`BasicDecompressor`](parquet2::read::BasicDecompressor)
+/// that, for column chunks compressed with [`Compression::Lz4`], also decompresses the
+/// data pages written by Hadoop-based writers (in the Hadoop LZ4 format) and by other
+/// writers in the raw LZ4 block format, besides the LZ4 frame format.
+///
+/// Use [`Lz4CompatDecompressor::new`] to create it from a column chunk.
+/// # Implementation
+/// The data pages of LZ4 column chunks are read as uncompressed and decompressed by
+/// this decompressor, which detects their format. Dictionary pages are decompressed by
+/// parquet2, which only supports the LZ4 frame format: column chunks with a dictionary
+/// page are decompressed like by [`BasicDecompressor`](parquet2::read::BasicDecompressor).
+pub struct Lz4CompatDecompressor<I: Iterator<Item = Result<CompressedDataPage, ParquetError>>> {
+    iter: I,
+    is_lz4: bool,
+    buffer: Vec<u8>,
+    current: Option<DataPage>,
+}
+
+impl<R: Read> Lz4CompatDecompressor<PageIterator<R>> {
+    /// Returns a new [`Lz4CompatDecompressor`] over the pages of `column_meta`, read from
+    /// `reader` positioned at the start of the column chunk.
+    pub fn new(
+        reader: R,
+        column_meta: &ColumnChunkMetaData,
+        pages_filter: Option<PageFilter>,
+        buffer: Vec<u8>,
+    ) -> Self {
+        let is_lz4 = cfg!(feature = "io_parquet_compression")
+            && column_meta.compression() == Compression::Lz4
+            && column_meta.dictionary_page_offset().is_none();
+        let compression = if is_lz4 {
+            Compression::Uncompressed
+        } else {
+            column_meta.compression()
+        };
+        let pages = PageIterator::new(
+            reader,
+            column_meta.num_values(),
+            compression,
+            column_meta.descriptor().clone(),
+            pages_filter.unwrap_or_else(|| std::sync::Arc::new(|_, _| true)),
+            buffer,
+        );
+        Self {
+            iter: pages,
+            is_lz4,
+            buffer: vec![],
+            current: None,
+        }
+    }
+}
+
+impl<I> FallibleStreamingIterator for Lz4CompatDecompressor<I>
+where
+    I: Iterator<Item = Result<CompressedDataPage, ParquetError>>,
+{
+    type Item = DataPage;
+    type Error = ParquetError;
+
+    fn advance(&mut self) -> Result<(), ParquetError> {
+        // re-use the buffer of the previous page
+        if let Some(mut page) = self.current.take() {
+            self.buffer = std::mem::take(page.buffer_mut());
+        }
+        let page = match self.iter.next() {
+            Some(page) => page?,
+            None => return Ok(()),
+        };
+        let uncompressed_size = page.uncompressed_size();
+        let mut page = decompress(page, &mut self.buffer)?;
+
+        if self.is_lz4 {
+            let offset = match page.header() {
+                DataPageHeader::V1(_) => Some(0),
+                // the levels of v2 pages are not compressed
+                DataPageHeader::V2(header) => header.is_compressed.unwrap_or(true).then(|| {
+                    (header.definition_levels_byte_length + header.repetition_levels_byte_length)
+                        as usize
+                }),
+            };
+            if let Some(offset) = offset {
+                let compressed = page.buffer();
+                if offset > compressed.len() || offset > uncompressed_size {
+                    return Err(ParquetError::OutOfSpec(
+                        "The levels of a data page are larger than the page".to_string(),
+                    ));
+                }
+                let mut decompressed = vec![0; uncompressed_size];
+                decompressed[..offset].copy_from_slice(&compressed[..offset]);
+                decompress_lz4(&compressed[offset..], &mut decompressed[offset..])?;
+                *page.buffer_mut() = decompressed;
+            }
+        }
+        self.current = Some(page);
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.as_ref()
+    }
+}
@@ -1,9 +1,14 @@
 //! APIs to read from Parquet format.
 #![allow(clippy::type_complexity)]
 
+mod context;
 mod deserialize;
 mod file;
+mod info;
+mod lz4;
+mod remote;
 mod row_group;
+mod rows;
 pub mod schema;
 pub mod statistics;
 
@@ -29,9 +34,20 @@ pub use parquet2::{
     FallibleStreamingIterator,
 };
 
-pub use deserialize::{column_iter_to_arrays, get_page_iterator};
+pub use deserialize::{
+    column_iter_to_arrays, column_iter_to_arrays_with_limits, get_page_iterator, read_pages_into,
+    BinaryLimits, Utf8Validation,
+};
 pub use file::{FileReader, RowGroupReader};
+pub use info::{FieldInfo, ParquetFileInfo};
+pub use lz4::Lz4CompatDecompressor;
+pub use remote::{
+    column_byte_ranges, merge_byte_ranges, read_byte_ranges, read_metadata_cached,
+    read_metadata_ranged, InMemoryMetadataCache, MetadataCache, RangeReader,
+    DEFAULT_FOOTER_PREFETCH,
+};
 pub use row_group::*;
+pub use rows::read_rows;
 pub(crate) use schema::is_type_nullable;
 pub use schema::{infer_schema, FileMetaData};
 
@@ -0,0 +1,195 @@
+//! APIs to read parquet metadata and column chunks from object stores (e.g. S3 or GCS) with a
+//! minimal number of round trips.
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::{Arc, Mutex};
+
+use futures::future::BoxFuture;
+
+use crate::error::{ArrowError, Result};
+
+use super::row_group::get_field_columns;
+use super::{read_metadata, FileMetaData, RowGroupMetaData};
+
+const FOOTER_SIZE: usize = 8;
+const PARQUET_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'1'];
+
+/// The default number of bytes fetched from the end of a file by [`read_metadata_ranged`].
+/// The footer of most files fits in it, so that their metadata is read in a single request.
+pub const DEFAULT_FOOTER_PREFETCH: usize = 64 * 1024;
+
+/// A source of bytes that can be read by ranges, such as an object of an object store.
+pub trait RangeReader: Send + Sync {
+    /// Returns the last `length` bytes of the object, or all of its bytes if it is smaller than
+    /// `length` (e.g. an HTTP request with the header `Range: bytes=-{length}`).
+    fn read_suffix(&self, length: usize) -> BoxFuture<'_, std::io::Result<Vec<u8>>>;
+
+    /// Returns `length` bytes of the object starting at `start`.
+    fn read_range(&self, start: u64, length: usize) -> BoxFuture<'_, std::io::Result<Vec<u8>>>;
+}
+
+/// A cache of parsed [`FileMetaData`], keyed by a user-defined key that identifies a version
+/// of a file (e.g. its path and etag).
+pub trait MetadataCache: Send + Sync {
+    /// Returns the [`FileMetaData`] associated to `key`, if any.
+    fn get(&self, key: &str) -> Option<Arc<FileMetaData>>;
+
+    /// Associates `metadata` to `key`.
+    fn insert(&self, key: String, metadata: Arc<FileMetaData>);
+}
+
+/// An unbounded, in-memory [`MetadataCache`].
+#[derive(Debug, Default)]
+pub struct InMemoryMetadataCache {
+    entries: Mutex<HashMap<String, Arc<FileMetaData>>>,
+}
+
+impl MetadataCache for InMemoryMetadataCache {
+    fn get(&self, key: &str) -> Option<Arc<FileMetaData>> {
+        self.entries.lock().unwrap().get(key).cloned()
+    }
+
+    fn insert(&self, key: String, metadata: Arc<FileMetaData>) {
+        self.entries.lock().unwrap().insert(key, metadata);
+    }
+}
+
+/// Reads the metadata of a parquet file from a [`RangeReader`] using suffix reads only.
+///
+/// The last `prefetch` bytes of the file are fetched in a first request; the footer is
+/// fetched in a second request only when it is larger than `prefetch`.
+/// The file's length is not required.
+/// # Error
+/// Errors iff the reader errors or the file's footer is invalid.
+pub async fn read_metadata_ranged<R: RangeReader + ?Sized>(
+    reader: &R,
+    prefetch: usize,
+) -> Result<FileMetaData> {
+    let suffix = reader.read_suffix(prefetch.max(FOOTER_SIZE)).await?;
+    if suffix.len() < FOOTER_SIZE {
+        return Err(ArrowError::OutOfSpec(
+            "A parquet file must contain at least 8 bytes".to_string(),
+        ));
+    }
+    if suffix[suffix.len() - 4..] != PARQUET_MAGIC {
+        return Err(ArrowError::OutOfSpec(
+            "The file does not end with the parquet magic".to_string(),
+        ));
+    }
+    let metadata_len = i32::from_le_bytes(
+        suffix[suffix.len() - FOOTER_SIZE..suffix.len() - 4]
+            .try_into()
+            .unwrap(),
+    );
+    let footer_len = usize::try_from(metadata_len)
+        .map_err(|_| ArrowError::OutOfSpec("The metadata length is negative".to_string()))?
+        + FOOTER_SIZE;
+
+    let footer = if footer_len <= suffix.len() {
+        suffix[suffix.len() - footer_len..].to_vec()
+    } else {
+        let footer = reader.read_suffix(footer_len).await?;
+        if footer.len() != footer_len {
+            return Err(ArrowError::OutOfSpec(
+                "The metadata length is larger than the file".to_string(),
+            ));
+        }
+        footer
+    };
+
+    // the footer is a valid "file" from the point of view of the metadata reader, since
+    // all offsets in the metadata are absolute
+    read_metadata(&mut Cursor::new(footer))
+}
+
+/// Reads the metadata of a parquet file like [`read_metadata_ranged`], returning it from
+/// `cache` when `key` is present in it and inserting it otherwise.
+/// # Error
+/// Errors iff `key` is not cached and [`read_metadata_ranged`] errors.
+pub async fn read_metadata_cached<R, C>(
+    reader: &R,
+    cache: &C,
+    key: &str,
+    prefetch: usize,
+) -> Result<Arc<FileMetaData>>
+where
+    R: RangeReader + ?Sized,
+    C: MetadataCache + ?Sized,
+{
+    if let Some(metadata) = cache.get(key) {
+        return Ok(metadata);
+    }
+    let metadata = Arc::new(read_metadata_ranged(reader, prefetch).await?);
+    cache.insert(key.to_string(), metadata.clone());
+    Ok(metadata)
+}
+
+/// Returns the byte ranges, as `(start, length)`, of the column chunks of `row_group` that
+/// are part of the fields named `field_names`, in the order of `field_names`.
+/// Use it to prefetch the data required by a projection.
+pub fn column_byte_ranges(row_group: &RowGroupMetaData, field_names: &[&str]) -> Vec<(u64, u64)> {
+    field_names
+        .iter()
+        .flat_map(|name| get_field_columns(row_group.columns(), name))
+        .map(|column| column.byte_range())
+        .collect()
+}
+
+/// Merges byte ranges, as `(start, length)`, whose gap is at most `max_gap` bytes, returning
+/// sorted and non-overlapping ranges.
+/// Use it to reduce the number of requests to an object store at the cost of reading
+/// the (at most `max_gap`) bytes between the ranges.
+pub fn merge_byte_ranges(ranges: &[(u64, u64)], max_gap: u64) -> Vec<(u64, u64)> {
+    let mut ranges = ranges.to_vec();
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+    for (start, length) in ranges {
+        match merged.last_mut() {
+            Some((last_start, last_length)) if start <= *last_start + *last_length + max_gap => {
+                let end = (*last_start + *last_length).max(start + length);
+                *last_length = end - *last_start;
+            }
+            _ => merged.push((start, length)),
+        }
+    }
+    merged
+}
+
+/// Reads byte ranges, as `(start, length)`, from `reader`, returning the bytes of each range
+/// in the order of `ranges`.
+/// Ranges are merged with [`merge_byte_ranges`] so that ranges whose gap is at most `max_gap`
+/// bytes are read in a single request.
+/// # Error
+/// Errors iff the reader errors or returns fewer bytes than requested.
+pub async fn read_byte_ranges<R: RangeReader + ?Sized>(
+    reader: &R,
+    ranges: &[(u64, u64)],
+    max_gap: u64,
+) -> Result<Vec<Vec<u8>>> {
+    let merged = merge_byte_ranges(ranges, max_gap);
+    let mut fetched = Vec::with_capacity(merged.len());
+    for (start, length) in merged.iter() {
+        let length = *length as usize;
+        let bytes = reader.read_range(*start, length).await?;
+        if bytes.len() != length {
+            return Err(ArrowError::OutOfSpec(format!(
+                "Reading {} bytes from {} returned {} bytes",
+                length,
+                start,
+                bytes.len()
+            )));
+        }
+        fetched.push(bytes);
+    }
+
+    Ok(ranges
+        .iter()
+        .map(|(start, length)| {
+            // the merged ranges are sorted and cover every range
+            let index = merged.partition_point(|(merged_start, _)| merged_start <= start) - 1;
+            let offset = (start - merged[index].0) as usize;
+            fetched[index][offset..offset + *length as usize].to_vec()
+        })
+        .collect())
+}
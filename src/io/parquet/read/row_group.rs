@@ -7,18 +7,19 @@ use futures::{
     future::{try_join_all, BoxFuture},
     AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt,
 };
-use parquet2::{
-    metadata::ColumnChunkMetaData,
-    read::{BasicDecompressor, PageIterator},
-};
+use parquet2::metadata::ColumnChunkMetaData;
 
 use crate::{
-    array::Array, chunk::Chunk, datatypes::Field, error::Result,
-    io::parquet::read::column_iter_to_arrays,
+    array::Array,
+    chunk::Chunk,
+    datatypes::Field,
+    error::{ArrowError, Result},
+    io::parquet::read::{column_iter_to_arrays_with_limits, BinaryLimits},
 };
 
-use super::ArrayIter;
+use super::context::{with_columns_context, ColumnContext, PageTracker};
 use super::RowGroupMetaData;
+use super::{ArrayIter, Lz4CompatDecompressor};
 
 /// An [`Iterator`] of [`Chunk`] that (dynamically) adapts a vector of iterators of [`Array`] into
 /// an iterator of [`Chunk`].
@@ -175,28 +176,43 @@ pub fn to_deserializer<'a>(
     field: Field,
     num_rows: usize,
     chunk_size: Option<usize>,
+) -> Result<ArrayIter<'a>> {
+    to_deserializer_with_limits(
+        columns,
+        field,
+        num_rows,
+        chunk_size,
+        BinaryLimits::default(),
+    )
+}
+
+/// Same as [`to_deserializer`], decoding binary and utf8 columns within `limits`.
+pub fn to_deserializer_with_limits<'a>(
+    columns: Vec<(&ColumnChunkMetaData, Vec<u8>)>,
+    field: Field,
+    num_rows: usize,
+    chunk_size: Option<usize>,
+    limits: BinaryLimits,
 ) -> Result<ArrayIter<'a>> {
     let chunk_size = chunk_size.unwrap_or(usize::MAX).min(num_rows);
 
+    let mut contexts = Vec::with_capacity(columns.len());
     let (columns, types): (Vec<_>, Vec<_>) = columns
         .into_iter()
         .map(|(column_meta, chunk)| {
-            let pages = PageIterator::new(
-                std::io::Cursor::new(chunk),
-                column_meta.num_values(),
-                column_meta.compression(),
-                column_meta.descriptor().clone(),
-                Arc::new(|_, _| true),
-                vec![],
-            );
+            let context = ColumnContext::new(column_meta.descriptor().path_in_schema());
+            contexts.push(context.clone());
+            let pages =
+                Lz4CompatDecompressor::new(std::io::Cursor::new(chunk), column_meta, None, vec![]);
             (
-                BasicDecompressor::new(pages, vec![]),
+                PageTracker::new(pages, context),
                 column_meta.descriptor().type_(),
             )
         })
         .unzip();
 
-    column_iter_to_arrays(columns, types, field, chunk_size)
+    let iter = column_iter_to_arrays_with_limits(columns, types, field, chunk_size, limits)?;
+    Ok(with_columns_context(iter, contexts))
 }
 
 /// Returns a vector of iterators of [`Array`] ([`ArrayIter`]) corresponding to the top
@@ -214,6 +230,23 @@ pub fn read_columns_many<'a, R: Read + Seek>(
     row_group: &RowGroupMetaData,
     fields: Vec<Field>,
     chunk_size: Option<usize>,
+) -> Result<Vec<ArrayIter<'a>>> {
+    read_columns_many_with_limits(
+        reader,
+        row_group,
+        fields,
+        chunk_size,
+        BinaryLimits::default(),
+    )
+}
+
+/// Same as [`read_columns_many`], decoding binary and utf8 columns within `limits`.
+pub fn read_columns_many_with_limits<'a, R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+    limits: BinaryLimits,
 ) -> Result<Vec<ArrayIter<'a>>> {
     // reads all the necessary columns for all fields from the row group
     // This operation is IO-bounded `O(C)` where C is the number of columns in the row group
@@ -226,7 +259,13 @@ pub fn read_columns_many<'a, R: Read + Seek>(
         .into_iter()
         .zip(fields.into_iter())
         .map(|(columns, field)| {
-            to_deserializer(columns, field, row_group.num_rows() as usize, chunk_size)
+            to_deserializer_with_limits(
+                columns,
+                field,
+                row_group.num_rows() as usize,
+                chunk_size,
+                limits,
+            )
         })
         .collect()
 }
@@ -251,6 +290,29 @@ pub async fn read_columns_many_async<
     row_group: &RowGroupMetaData,
     fields: Vec<Field>,
     chunk_size: Option<usize>,
+) -> Result<Vec<ArrayIter<'a>>> {
+    read_columns_many_async_with_limits(
+        factory,
+        row_group,
+        fields,
+        chunk_size,
+        BinaryLimits::default(),
+    )
+    .await
+}
+
+/// Same as [`read_columns_many_async`], decoding binary and utf8 columns within `limits`.
+pub async fn read_columns_many_async_with_limits<
+    'a,
+    'b,
+    R: AsyncRead + AsyncSeek + Send + Unpin,
+    F: Fn() -> BoxFuture<'b, std::io::Result<R>> + Clone,
+>(
+    factory: F,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+    limits: BinaryLimits,
 ) -> Result<Vec<ArrayIter<'a>>> {
     let futures = fields
         .iter()
@@ -262,7 +324,89 @@ pub async fn read_columns_many_async<
         .into_iter()
         .zip(fields.into_iter())
         .map(|(columns, field)| {
-            to_deserializer(columns, field, row_group.num_rows() as usize, chunk_size)
+            to_deserializer_with_limits(
+                columns,
+                field,
+                row_group.num_rows() as usize,
+                chunk_size,
+                limits,
+            )
         })
         .collect()
 }
+
+/// Reads the row groups at `indices` and returns a [`RowGroupDeserializer`] per row group,
+/// in the order they are declared. This allows reading arbitrary subsets of row groups
+/// without reading the preceding ones.
+///
+/// # Implementation
+/// This operation is IO-bounded `O(C)` where C is the number of columns in the selected
+/// row groups - it reads all the columns to memory from the selected row groups
+/// associated to the requested fields. It does so asynchronously via `join_all`.
+/// # Error
+/// Errors iff any of the indices is out of bounds of `row_groups` or reading fails.
+pub async fn read_row_groups_async<
+    'b,
+    R: AsyncRead + AsyncSeek + Send + Unpin,
+    F: Fn() -> BoxFuture<'b, std::io::Result<R>> + Clone,
+>(
+    factory: F,
+    row_groups: &[RowGroupMetaData],
+    indices: &[usize],
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+) -> Result<Vec<RowGroupDeserializer>> {
+    read_row_groups_async_with_limits(
+        factory,
+        row_groups,
+        indices,
+        fields,
+        chunk_size,
+        BinaryLimits::default(),
+    )
+    .await
+}
+
+/// Same as [`read_row_groups_async`], decoding binary and utf8 columns within `limits`.
+pub async fn read_row_groups_async_with_limits<
+    'b,
+    R: AsyncRead + AsyncSeek + Send + Unpin,
+    F: Fn() -> BoxFuture<'b, std::io::Result<R>> + Clone,
+>(
+    factory: F,
+    row_groups: &[RowGroupMetaData],
+    indices: &[usize],
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+    limits: BinaryLimits,
+) -> Result<Vec<RowGroupDeserializer>> {
+    let row_groups = indices
+        .iter()
+        .map(|&index| {
+            row_groups.get(index).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Row group index {} is out of bounds for a file with {} row groups",
+                    index,
+                    row_groups.len()
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let futures = row_groups.into_iter().map(|row_group| {
+        let fields = fields.clone();
+        let factory = factory.clone();
+        async move {
+            let column_chunks =
+                read_columns_many_async_with_limits(factory, row_group, fields, chunk_size, limits)
+                    .await?;
+            Result::Ok(RowGroupDeserializer::new(
+                column_chunks,
+                row_group.num_rows() as usize,
+                None,
+            ))
+        }
+    });
+
+    try_join_all(futures).await
+}
@@ -0,0 +1,144 @@
+use std::io::{Read, Seek};
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use parquet2::read::BasicDecompressor;
+
+use crate::{
+    array::{growable::make_growable, new_empty_array, Array},
+    chunk::Chunk,
+    datatypes::Field,
+    error::{ArrowError, Result},
+};
+
+use super::row_group::get_field_columns;
+use super::{
+    column_iter_to_arrays, get_page_iterator, ColumnDescriptor, DataPageHeader, FileMetaData,
+    PageFilter,
+};
+
+/// Returns a [`PageFilter`] that only accepts the data pages of a (non-nested) column chunk
+/// containing rows in `range`, together with the index of the first row of the first
+/// accepted page.
+fn range_filter(range: Range<usize>) -> (PageFilter, Arc<AtomicUsize>) {
+    let seen_rows = AtomicUsize::new(0);
+    let first_row = Arc::new(AtomicUsize::new(usize::MAX));
+    let first = first_row.clone();
+    let filter = Arc::new(move |_: &ColumnDescriptor, header: &DataPageHeader| {
+        let num_rows = header.num_values();
+        let start = seen_rows.fetch_add(num_rows, Ordering::SeqCst);
+        let keep = start < range.end && start + num_rows > range.start;
+        if keep {
+            first.fetch_min(start, Ordering::SeqCst);
+        }
+        keep
+    });
+    (filter, first_row)
+}
+
+/// Reads the rows `rows` (over all row groups) of the top level parquet fields whose names
+/// match `fields`'s names.
+///
+/// Only the row groups and the data pages containing `rows` are decompressed and decoded,
+/// which makes this suitable for point lookups (e.g. rows `1_000_000..1_000_100`) on
+/// sorted files.
+/// # Implementation
+/// Data pages are located from their headers: the pages of a column chunk before the last
+/// page containing `rows` are read, but only the ones containing `rows` are decompressed
+/// and decoded.
+/// # Error
+/// Errors iff:
+/// * `rows` is out of bounds of the file's number of rows
+/// * a field is nested (only non-nested fields are supported)
+/// * the file is invalid
+pub fn read_rows<R: Read + Seek + Send + Sync>(
+    reader: &mut R,
+    metadata: &FileMetaData,
+    fields: &[Field],
+    rows: Range<usize>,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let file_rows = metadata.num_rows as usize;
+    if rows.start > rows.end || rows.end > file_rows {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The rows {:?} are out of bounds of a file with {} rows",
+            rows, file_rows
+        )));
+    }
+
+    let mut columns = fields
+        .iter()
+        .map(|_| vec![])
+        .collect::<Vec<Vec<Box<dyn Array>>>>();
+
+    let mut row_group_start = 0;
+    for row_group in &metadata.row_groups {
+        let num_rows = row_group.num_rows() as usize;
+        let row_group_end = row_group_start + num_rows;
+        let start = rows.start.max(row_group_start);
+        let end = rows.end.min(row_group_end);
+        if start < end {
+            let local = start - row_group_start..end - row_group_start;
+
+            for (field, column) in fields.iter().zip(columns.iter_mut()) {
+                let column_meta = match get_field_columns(row_group.columns(), &field.name)[..] {
+                    [column_meta] if column_meta.descriptor().max_rep_level() == 0 => column_meta,
+                    _ => {
+                        return Err(ArrowError::NotYetImplemented(format!(
+                            "Reading row ranges of the nested field \"{}\"",
+                            field.name
+                        )))
+                    }
+                };
+
+                let (filter, first_row) = range_filter(local.clone());
+                let pages = get_page_iterator(column_meta, &mut *reader, Some(filter), vec![])?;
+                let pages = BasicDecompressor::new(pages, vec![]);
+
+                let arrays = column_iter_to_arrays(
+                    vec![pages],
+                    vec![column_meta.descriptor().type_()],
+                    field.clone(),
+                    num_rows,
+                )?
+                .collect::<Result<Vec<_>>>()?;
+
+                // all accepted pages fit in a single array of `num_rows`
+                let array = arrays.into_iter().next().ok_or_else(|| {
+                    ArrowError::OutOfSpec(format!(
+                        "The column chunk of the field \"{}\" has less rows than its row group",
+                        field.name
+                    ))
+                })?;
+                let offset = local.start - first_row.load(Ordering::SeqCst);
+                if offset + local.len() > array.len() {
+                    return Err(ArrowError::OutOfSpec(format!(
+                        "The column chunk of the field \"{}\" has less rows than its row group",
+                        field.name
+                    )));
+                }
+                column.push(array.slice(offset, local.len()));
+            }
+        }
+        row_group_start = row_group_end;
+    }
+
+    let arrays = fields
+        .iter()
+        .zip(columns)
+        .map(|(field, arrays)| match arrays.len() {
+            0 => Arc::from(new_empty_array(field.data_type().clone())),
+            1 => Arc::from(arrays.into_iter().next().unwrap()),
+            _ => {
+                let refs = arrays.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+                let mut growable = make_growable(&refs, false, rows.len());
+                arrays
+                    .iter()
+                    .enumerate()
+                    .for_each(|(i, x)| growable.extend(i, 0, x.len()));
+                growable.as_arc()
+            }
+        })
+        .collect();
+    Chunk::try_new(arrays)
+}
@@ -126,6 +126,24 @@ fn from_int64(
     }
 }
 
+/// Returns the unit of a parquet timestamp from its logical type or, when it does not have one
+/// (e.g. files written by legacy writers), from its converted type.
+pub(crate) fn timestamp_unit(
+    logical_type: &Option<LogicalType>,
+    converted_type: &Option<PrimitiveConvertedType>,
+) -> Option<ParquetTimeUnit> {
+    match (logical_type, converted_type) {
+        (Some(LogicalType::TIMESTAMP(TimestampType { unit, .. })), _) => Some(unit.clone()),
+        (None, Some(PrimitiveConvertedType::TimestampMillis)) => {
+            Some(ParquetTimeUnit::MILLIS(Default::default()))
+        }
+        (None, Some(PrimitiveConvertedType::TimestampMicros)) => {
+            Some(ParquetTimeUnit::MICROS(Default::default()))
+        }
+        _ => None,
+    }
+}
+
 fn from_byte_array(
     logical_type: &Option<LogicalType>,
     converted_type: &Option<PrimitiveConvertedType>,
@@ -1,6 +1,6 @@
 pub use parquet2::metadata::KeyValue;
 
-use crate::datatypes::{Metadata, Schema};
+use crate::datatypes::{DataType, Field, Metadata, Schema};
 use crate::error::{ArrowError, Result};
 use crate::io::ipc::read::deserialize_schema;
 
@@ -16,17 +16,50 @@ pub fn read_schema_from_metadata(metadata: &mut Metadata) -> Result<Option<Schem
         .transpose()
 }
 
+/// Returns the IPC message of an encoded schema, which is written in one of the following formats:
+/// * `0xFFFFFFFF` (continuation) + length (`u32`) + message, the format since arrow 0.15
+/// * length (`u32`) + message, the legacy format
+/// * message, without a prefix
+///
+/// The message may be followed by up to 7 bytes of padding, which distinguishes a legacy
+/// length from the first bytes of an unprefixed message.
+fn schema_message(bytes: &[u8]) -> Result<&[u8]> {
+    let prefix = |bytes: &[u8]| -> Option<u32> {
+        bytes
+            .get(0..4)
+            .map(|x| u32::from_le_bytes(x.try_into().unwrap()))
+    };
+    let is_legacy = |length: u32| {
+        matches!(
+            (bytes.len() - 4).checked_sub(length as usize),
+            Some(padding) if length > 0 && padding < 8
+        )
+    };
+    let (length, message) = match prefix(bytes) {
+        Some(u32::MAX) => (prefix(&bytes[4..]), bytes.get(8..).unwrap_or_default()),
+        Some(length) if is_legacy(length) => (Some(length), &bytes[4..]),
+        _ => (None, bytes),
+    };
+    match length {
+        Some(length) if length as usize > message.len() => Err(ArrowError::OutOfSpec(format!(
+            "The schema stored in {} declares a length of {} bytes but only has {} bytes",
+            ARROW_SCHEMA_META_KEY,
+            length,
+            message.len()
+        ))),
+        Some(length) => Ok(&message[..length as usize]),
+        None => Ok(message),
+    }
+}
+
 /// Try to convert Arrow schema metadata into a schema
 fn get_arrow_schema_from_metadata(encoded_meta: &str) -> Result<Schema> {
     let decoded = base64::decode(encoded_meta);
     match decoded {
         Ok(bytes) => {
-            let slice = if bytes[0..4] == [255u8; 4] {
-                &bytes[8..]
-            } else {
-                bytes.as_slice()
-            };
-            deserialize_schema(slice).map(|x| x.0)
+            let (mut schema, _) = deserialize_schema(schema_message(&bytes)?)?;
+            schema.fields.iter_mut().for_each(remove_extension_metadata);
+            Ok(schema)
         }
         Err(err) => {
             // The C++ implementation returns an error if the schema can't be parsed.
@@ -38,6 +71,30 @@ fn get_arrow_schema_from_metadata(encoded_meta: &str) -> Result<Schema> {
     }
 }
 
+/// Removes the `ARROW:extension:*` keys from the metadata of extension fields, since
+/// they are already represented by [`DataType::Extension`] (and re-added on write).
+fn remove_extension_metadata(field: &mut Field) {
+    if let DataType::Extension(..) = field.data_type {
+        field.metadata.remove("ARROW:extension:name");
+        field.metadata.remove("ARROW:extension:metadata");
+    }
+    remove_children_extension_metadata(&mut field.data_type)
+}
+
+fn remove_children_extension_metadata(data_type: &mut DataType) {
+    match data_type {
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => remove_extension_metadata(field),
+        DataType::Struct(fields) | DataType::Union(fields, _, _) => {
+            fields.iter_mut().for_each(remove_extension_metadata)
+        }
+        DataType::Extension(_, inner, _) => remove_children_extension_metadata(inner),
+        _ => {}
+    }
+}
+
 pub(super) fn parse_key_value_metadata(key_value_metadata: &Option<Vec<KeyValue>>) -> Metadata {
     key_value_metadata
         .as_ref()
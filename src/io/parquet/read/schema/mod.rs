@@ -14,19 +14,33 @@ pub(crate) use convert::*;
 use self::metadata::parse_key_value_metadata;
 
 /// Infers a [`Schema`] from parquet's [`FileMetaData`]. This first looks for the metadata key
-/// `"ARROW:schema"`; if it does not exist, it converts the parquet types declared in the
-/// file's parquet schema to Arrow's equivalent.
+/// `"ARROW:schema"`, written by arrow implementations (including this one) to preserve arrow's
+/// logical types (e.g. timezones, [`DataType::Duration`](crate::datatypes::DataType::Duration)
+/// and extension types) and uses it when it is compatible with the parquet schema (i.e. has the
+/// same top-level field names); otherwise, it converts the parquet types declared in the file's
+/// parquet schema to Arrow's equivalent.
 /// # Error
 /// This function errors iff the key `"ARROW:schema"` exists but is not correctly encoded,
 /// indicating that that the file's arrow metadata was incorrectly written.
 pub fn infer_schema(file_metadata: &FileMetaData) -> Result<Schema> {
     let mut metadata = parse_key_value_metadata(file_metadata.key_value_metadata());
+    let parquet_fields = file_metadata.schema().fields();
 
-    let schema = read_schema_from_metadata(&mut metadata)?;
-    Ok(schema.unwrap_or_else(|| {
-        let fields = parquet_to_arrow_schema(file_metadata.schema().fields());
-        Schema { fields, metadata }
-    }))
+    let schema = read_schema_from_metadata(&mut metadata)?.filter(|schema| {
+        schema.fields.len() == parquet_fields.len()
+            && schema
+                .fields
+                .iter()
+                .zip(parquet_fields)
+                .all(|(field, type_)| field.name == type_.name())
+    });
+    Ok(match schema {
+        Some(schema) => schema,
+        None => Schema {
+            fields: parquet_to_arrow_schema(parquet_fields),
+            metadata,
+        },
+    })
 }
 
 pub(crate) fn is_type_nullable(type_: &ParquetType) -> bool {
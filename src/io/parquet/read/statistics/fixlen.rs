@@ -1,5 +1,5 @@
 use std::any::Any;
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 
 use super::primitive::PrimitiveStatistics;
 use crate::datatypes::DataType;
@@ -58,6 +58,18 @@ impl From<&ParquetFixedLenStatistics> for FixedLenStatistics {
     }
 }
 
+/// Converts a big-endian two's complement value of `n` bytes to an `i128`, extending its sign.
+/// Returns `None` iff the value does not have `n` bytes or `n` is zero.
+fn convert_i128(value: &[u8], n: usize) -> Option<i128> {
+    if n == 0 || value.len() != n {
+        return None;
+    }
+    let mut bytes = [0u8; 16];
+    bytes[..n].copy_from_slice(value);
+    // the arithmetic shift extends the sign of negative values
+    Some(i128::from_be_bytes(bytes) >> (8 * (16 - n)))
+}
+
 impl TryFrom<(&ParquetFixedLenStatistics, DataType)> for PrimitiveStatistics<i128> {
     type Error = ArrowError;
     fn try_from((stats, data_type): (&ParquetFixedLenStatistics, DataType)) -> Result<Self> {
@@ -71,22 +83,14 @@ impl TryFrom<(&ParquetFixedLenStatistics, DataType)> for PrimitiveStatistics<i12
                 byte_lens
             )))
         } else {
-            let paddings = (0..(16 - byte_lens)).map(|_| 0u8).collect::<Vec<_>>();
-            let max_value = stats.max_value.as_ref().and_then(|value| {
-                [paddings.as_slice(), value]
-                    .concat()
-                    .try_into()
-                    .map(i128::from_be_bytes)
-                    .ok()
-            });
-
-            let min_value = stats.min_value.as_ref().and_then(|value| {
-                [paddings.as_slice(), value]
-                    .concat()
-                    .try_into()
-                    .map(i128::from_be_bytes)
-                    .ok()
-            });
+            let max_value = stats
+                .max_value
+                .as_ref()
+                .and_then(|value| convert_i128(value, byte_lens as usize));
+            let min_value = stats
+                .min_value
+                .as_ref()
+                .and_then(|value| convert_i128(value, byte_lens as usize));
             Ok(Self {
                 data_type,
                 null_count: stats.null_count,
@@ -1,12 +1,11 @@
 use crate::datatypes::TimeUnit;
 use crate::{datatypes::DataType, types::NativeType};
-use parquet2::schema::types::{
-    LogicalType, ParquetType, TimeUnit as ParquetTimeUnit, TimestampType,
-};
+use parquet2::schema::types::{ParquetType, TimeUnit as ParquetTimeUnit};
 use parquet2::statistics::PrimitiveStatistics as ParquetPrimitiveStatistics;
 use parquet2::types::NativeType as ParquetNativeType;
 use std::any::Any;
 
+use super::super::schema::timestamp_unit;
 use super::Statistics;
 use crate::error::Result;
 
@@ -75,13 +74,18 @@ pub(super) fn statistics_from_i32(
 }
 
 fn timestamp(type_: &ParquetType, time_unit: TimeUnit, x: i64) -> i64 {
-    let logical_type = if let ParquetType::PrimitiveType { logical_type, .. } = type_ {
-        logical_type
+    let unit = if let ParquetType::PrimitiveType {
+        logical_type,
+        converted_type,
+        ..
+    } = type_
+    {
+        timestamp_unit(logical_type, converted_type)
     } else {
         unreachable!()
     };
 
-    let unit = if let Some(LogicalType::TIMESTAMP(TimestampType { unit, .. })) = logical_type {
+    let unit = if let Some(unit) = unit {
         unit
     } else {
         return x;
@@ -90,7 +94,7 @@ fn timestamp(type_: &ParquetType, time_unit: TimeUnit, x: i64) -> i64 {
     match (unit, time_unit) {
         (ParquetTimeUnit::MILLIS(_), TimeUnit::Second) => x / 1_000,
         (ParquetTimeUnit::MICROS(_), TimeUnit::Second) => x / 1_000_000,
-        (ParquetTimeUnit::NANOS(_), TimeUnit::Second) => x * 1_000_000_000,
+        (ParquetTimeUnit::NANOS(_), TimeUnit::Second) => x / 1_000_000_000,
 
         (ParquetTimeUnit::MILLIS(_), TimeUnit::Millisecond) => x,
         (ParquetTimeUnit::MICROS(_), TimeUnit::Millisecond) => x / 1_000,
@@ -115,7 +119,7 @@ pub(super) fn statistics_from_i64(
         UInt64 => {
             Box::new(PrimitiveStatistics::<u64>::from((stats, data_type))) as Box<dyn Statistics>
         }
-        Timestamp(time_unit, None) => Box::new(PrimitiveStatistics::<i64> {
+        Timestamp(time_unit, _) => Box::new(PrimitiveStatistics::<i64> {
             data_type,
             null_count: stats.null_count,
             distinct_count: stats.distinct_count,
@@ -38,10 +38,15 @@ where
         None
     };
 
+    let num_values = levels::num_values(nested.offsets());
+    // slots without a value are either null or empty lists
+    let null_count = num_values - (array.len() - array.null_count());
+
     utils::build_plain_page(
         buffer,
-        levels::num_values(nested.offsets()),
-        array.null_count(),
+        num_values,
+        nested.len(),
+        null_count,
         repetition_levels_byte_length,
         definition_levels_byte_length,
         statistics,
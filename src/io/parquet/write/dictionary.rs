@@ -9,13 +9,16 @@ use super::binary::encode_plain as binary_encode_plain;
 use super::fixed_len_bytes::encode_plain as fixed_binary_encode_plain;
 use super::primitive::encode_plain as primitive_encode_plain;
 use super::utf8::encode_plain as utf8_encode_plain;
-use crate::array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray};
+use crate::array::{Array, DictionaryArray, DictionaryKey, Offset, PrimitiveArray};
 use crate::bitmap::Bitmap;
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 use crate::io::parquet::read::is_type_nullable;
+use crate::io::parquet::write::levels::{self, NestedInfo};
 use crate::io::parquet::write::utils;
 
+use super::PageSize;
+
 fn encode_keys<K: DictionaryKey>(
     array: &PrimitiveArray<K>,
     // todo: merge this to not discard values' validity
@@ -94,7 +97,8 @@ fn encode_keys<K: DictionaryKey>(
     utils::build_plain_page(
         buffer,
         array.len(),
-        array.null_count(),
+        array.len(),
+        null_count,
         0,
         definition_levels_byte_length,
         None,
@@ -106,7 +110,7 @@ fn encode_keys<K: DictionaryKey>(
 }
 
 macro_rules! dyn_prim {
-    ($from:ty, $to:ty, $array:expr, $options:expr) => {{
+    ($from:ty, $to:ty, $array:expr) => {{
         let values = $array.values().as_any().downcast_ref().unwrap();
 
         let mut buffer = vec![];
@@ -115,80 +119,93 @@ macro_rules! dyn_prim {
     }};
 }
 
+fn serialize_dict_page<K: DictionaryKey>(array: &DictionaryArray<K>) -> Result<EncodedPage> {
+    let dict_page = match array.values().data_type().to_logical_type() {
+        DataType::Int8 => dyn_prim!(i8, i32, array),
+        DataType::Int16 => dyn_prim!(i16, i32, array),
+        DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
+            dyn_prim!(i32, i32, array)
+        }
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => dyn_prim!(i64, i64, array),
+        DataType::UInt8 => dyn_prim!(u8, i32, array),
+        DataType::UInt16 => dyn_prim!(u16, i32, array),
+        DataType::UInt32 => dyn_prim!(u32, i32, array),
+        DataType::UInt64 => dyn_prim!(i64, i64, array),
+        DataType::Float32 => dyn_prim!(f32, f32, array),
+        DataType::Float64 => dyn_prim!(f64, f64, array),
+        DataType::Utf8 => {
+            let values = array.values().as_any().downcast_ref().unwrap();
+
+            let mut buffer = vec![];
+            utf8_encode_plain::<i32>(values, false, &mut buffer);
+            EncodedDictPage::new(buffer, values.len())
+        }
+        DataType::LargeUtf8 => {
+            let values = array.values().as_any().downcast_ref().unwrap();
+
+            let mut buffer = vec![];
+            utf8_encode_plain::<i64>(values, false, &mut buffer);
+            EncodedDictPage::new(buffer, values.len())
+        }
+        DataType::Binary => {
+            let values = array.values().as_any().downcast_ref().unwrap();
+
+            let mut buffer = vec![];
+            binary_encode_plain::<i32>(values, false, &mut buffer);
+            EncodedDictPage::new(buffer, values.len())
+        }
+        DataType::LargeBinary => {
+            let values = array.values().as_any().downcast_ref().unwrap();
+
+            let mut buffer = vec![];
+            binary_encode_plain::<i64>(values, false, &mut buffer);
+            EncodedDictPage::new(buffer, values.len())
+        }
+        DataType::FixedSizeBinary(_) => {
+            let mut buffer = vec![];
+            let array = array.values().as_any().downcast_ref().unwrap();
+            fixed_binary_encode_plain(array, false, &mut buffer);
+            EncodedDictPage::new(buffer, array.len())
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing dictionary arrays to parquet only support data type {:?}",
+                other
+            )))
+        }
+    };
+    Ok(EncodedPage::Dict(dict_page))
+}
+
 pub fn array_to_pages<K: DictionaryKey>(
     array: &DictionaryArray<K>,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
     encoding: Encoding,
+    page_size: Option<PageSize>,
 ) -> Result<DynIter<'static, Result<EncodedPage>>> {
     match encoding {
         Encoding::PlainDictionary | Encoding::RleDictionary => {
             // write DictPage
-            let dict_page = match array.values().data_type().to_logical_type() {
-                DataType::Int8 => dyn_prim!(i8, i32, array, options),
-                DataType::Int16 => dyn_prim!(i16, i32, array, options),
-                DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
-                    dyn_prim!(i32, i32, array, options)
-                }
-                DataType::Int64
-                | DataType::Date64
-                | DataType::Time64(_)
-                | DataType::Timestamp(_, _)
-                | DataType::Duration(_) => dyn_prim!(i64, i64, array, options),
-                DataType::UInt8 => dyn_prim!(u8, i32, array, options),
-                DataType::UInt16 => dyn_prim!(u16, i32, array, options),
-                DataType::UInt32 => dyn_prim!(u32, i32, array, options),
-                DataType::UInt64 => dyn_prim!(i64, i64, array, options),
-                DataType::Float32 => dyn_prim!(f32, f32, array, options),
-                DataType::Float64 => dyn_prim!(f64, f64, array, options),
-                DataType::Utf8 => {
-                    let values = array.values().as_any().downcast_ref().unwrap();
-
-                    let mut buffer = vec![];
-                    utf8_encode_plain::<i32>(values, false, &mut buffer);
-                    EncodedDictPage::new(buffer, values.len())
-                }
-                DataType::LargeUtf8 => {
-                    let values = array.values().as_any().downcast_ref().unwrap();
-
-                    let mut buffer = vec![];
-                    utf8_encode_plain::<i64>(values, false, &mut buffer);
-                    EncodedDictPage::new(buffer, values.len())
-                }
-                DataType::Binary => {
-                    let values = array.values().as_any().downcast_ref().unwrap();
-
-                    let mut buffer = vec![];
-                    binary_encode_plain::<i32>(values, false, &mut buffer);
-                    EncodedDictPage::new(buffer, values.len())
-                }
-                DataType::LargeBinary => {
-                    let values = array.values().as_any().downcast_ref().unwrap();
-
-                    let mut buffer = vec![];
-                    binary_encode_plain::<i64>(values, false, &mut buffer);
-                    EncodedDictPage::new(buffer, values.len())
-                }
-                DataType::FixedSizeBinary(_) => {
-                    let mut buffer = vec![];
-                    let array = array.values().as_any().downcast_ref().unwrap();
-                    fixed_binary_encode_plain(array, false, &mut buffer);
-                    EncodedDictPage::new(buffer, array.len())
-                }
-                other => {
-                    return Err(ArrowError::NotYetImplemented(format!(
-                        "Writing dictionary arrays to parquet only support data type {:?}",
-                        other
-                    )))
-                }
-            };
-            let dict_page = EncodedPage::Dict(dict_page);
-
-            // write DataPage pointing to DictPage
-            let data_page =
-                encode_keys(array.keys(), array.values().validity(), descriptor, options)?;
-
-            let iter = std::iter::once(Ok(dict_page)).chain(std::iter::once(Ok(data_page)));
+            let dict_page = serialize_dict_page(array)?;
+
+            // write DataPages pointing to DictPage
+            let keys = array.keys();
+            let rows = page_size.map_or(keys.len(), |size| size.rows_per_page(keys));
+            let validity = array.values().validity().cloned();
+            let data_pages = super::page_slices(keys.len(), rows)
+                .map(|(offset, length)| keys.slice(offset, length))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(move |keys| {
+                    encode_keys(&keys, validity.as_ref(), descriptor.clone(), options)
+                });
+
+            let iter = std::iter::once(Ok(dict_page)).chain(data_pages);
             Ok(DynIter::new(Box::new(iter)))
         }
         _ => Err(ArrowError::NotYetImplemented(
@@ -196,3 +213,79 @@ pub fn array_to_pages<K: DictionaryKey>(
         )),
     }
 }
+
+fn encode_nested_keys<K: DictionaryKey, O: Offset>(
+    array: &PrimitiveArray<K>,
+    nested: NestedInfo<O>,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+) -> Result<EncodedPage> {
+    let mut buffer = vec![];
+    levels::write_rep_levels(&mut buffer, &nested, options.version)?;
+    let repetition_levels_byte_length = buffer.len();
+
+    levels::write_def_levels(&mut buffer, &nested, array.validity(), options.version)?;
+    let definition_levels_byte_length = buffer.len() - repetition_levels_byte_length;
+
+    // encode indices
+    let keys = array.iter().flatten().map(|x| x.to_usize().unwrap() as u32);
+    let num_bits = utils::get_bit_width(keys.clone().max().unwrap_or(0) as u64) as u8;
+
+    let keys = utils::ExactSizedIter::new(keys, array.len() - array.null_count());
+
+    // num_bits as a single byte
+    buffer.push(num_bits);
+
+    // followed by the encoded indices.
+    encode_u32(&mut buffer, keys, num_bits)?;
+
+    let num_values = levels::num_values(nested.offsets());
+    // slots without a value are either null or empty lists
+    let null_count = num_values - (array.len() - array.null_count());
+
+    utils::build_plain_page(
+        buffer,
+        num_values,
+        nested.len(),
+        null_count,
+        repetition_levels_byte_length,
+        definition_levels_byte_length,
+        None,
+        descriptor,
+        options,
+        Encoding::RleDictionary,
+    )
+    .map(EncodedPage::Data)
+}
+
+/// Returns the pages of a list whose values are the dictionary-encoded `array`: a single
+/// dictionary page followed by a single data page of keys.
+pub fn nested_array_to_pages<K: DictionaryKey, O: Offset>(
+    offsets: &[O],
+    validity: Option<&Bitmap>,
+    array: &DictionaryArray<K>,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+    encoding: Encoding,
+) -> Result<DynIter<'static, Result<EncodedPage>>> {
+    match encoding {
+        Encoding::PlainDictionary | Encoding::RleDictionary => {
+            let dict_page = serialize_dict_page(array)?;
+
+            // a sliced list only uses the keys between its first and last offset
+            let start = offsets[0].to_usize();
+            let end = offsets[offsets.len() - 1].to_usize();
+            let keys = array.keys().slice(start, end - start);
+
+            let is_optional = is_type_nullable(descriptor.type_());
+            let nested = NestedInfo::new(offsets, validity, is_optional);
+            let data_page = encode_nested_keys(&keys, nested, descriptor, options)?;
+
+            let pages = vec![Ok(dict_page), Ok(data_page)];
+            Ok(DynIter::new(pages.into_iter()))
+        }
+        _ => Err(ArrowError::NotYetImplemented(
+            "Dictionary arrays only support dictionary encoding".to_string(),
+        )),
+    }
+}
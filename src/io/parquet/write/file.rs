@@ -4,22 +4,24 @@ use parquet2::metadata::SchemaDescriptor;
 use parquet2::write::RowGroupIter;
 use parquet2::{metadata::KeyValue, write::WriteOptions};
 
+use crate::array::Array;
+use crate::chunk::Chunk;
 use crate::datatypes::Schema;
 use crate::error::{ArrowError, Result};
 
 use super::{schema::schema_to_metadata_key, to_parquet_schema};
+use super::{Encoding, RowGroupIterator, RowGroupSize};
 
-/// Attaches [`Schema`] to `key_value_metadata`
+/// Attaches [`Schema`] to `key_value_metadata`, replacing an existing schema, if any.
 pub fn add_arrow_schema(
     schema: &Schema,
     key_value_metadata: Option<Vec<KeyValue>>,
 ) -> Option<Vec<KeyValue>> {
-    key_value_metadata
-        .map(|mut x| {
-            x.push(schema_to_metadata_key(schema));
-            x
-        })
-        .or_else(|| Some(vec![schema_to_metadata_key(schema)]))
+    let schema = schema_to_metadata_key(schema);
+    let mut key_value_metadata = key_value_metadata.unwrap_or_default();
+    key_value_metadata.retain(|x| x.key != schema.key);
+    key_value_metadata.push(schema);
+    Some(key_value_metadata)
 }
 
 /// An interface to write a parquet to a [`Write`]
@@ -75,6 +77,29 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.write(row_group, num_rows)?)
     }
 
+    /// Writes `chunks` to the file, split and merged into row groups of `row_group_size`.
+    /// This is a shorthand for writing the row groups of a [`RowGroupIterator`] with
+    /// [`RowGroupIterator::set_row_group_size`].
+    pub fn write_chunks<A, I>(
+        &mut self,
+        chunks: I,
+        encodings: Vec<Encoding>,
+        row_group_size: RowGroupSize,
+    ) -> Result<()>
+    where
+        A: AsRef<dyn Array> + 'static + Send + Sync,
+        I: Iterator<Item = Result<Chunk<A>>>,
+    {
+        let options = *self.options();
+        let mut row_groups = RowGroupIterator::try_new(chunks, &self.schema, options, encodings)?;
+        row_groups.set_row_group_size(row_group_size);
+        for group in row_groups {
+            let (group, len) = group?;
+            self.write(group, len)?;
+        }
+        Ok(())
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(self, key_value_metadata: Option<Vec<KeyValue>>) -> Result<(u64, W)> {
         let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
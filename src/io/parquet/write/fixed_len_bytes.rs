@@ -8,7 +8,8 @@ use parquet2::{
 
 use super::{binary::ord_binary, utils};
 use crate::{
-    array::{Array, FixedSizeBinaryArray},
+    array::{Array, FixedSizeBinaryArray, PrimitiveArray},
+    datatypes::DataType,
     error::Result,
     io::parquet::read::is_type_nullable,
 };
@@ -30,6 +31,47 @@ pub fn array_to_page(
     array: &FixedSizeBinaryArray,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+) -> Result<DataPage> {
+    let statistics = if options.write_statistics {
+        build_statistics(array, descriptor.clone())
+    } else {
+        None
+    };
+    to_page(array, options, descriptor, statistics)
+}
+
+/// Converts a [`DataType::Decimal`](crate::datatypes::DataType::Decimal) array to a page of
+/// big-endian two's complement values of `size` bytes.
+/// Its statistics are ordered by the decimal values (a signed comparison), not by their bytes.
+pub(super) fn decimal_array_to_page(
+    array: &PrimitiveArray<i128>,
+    size: usize,
+    options: WriteOptions,
+    descriptor: ColumnDescriptor,
+) -> Result<DataPage> {
+    let mut values = Vec::<u8>::with_capacity(size * array.len());
+    array.values().iter().for_each(|x| {
+        let bytes = &x.to_be_bytes()[16 - size..];
+        values.extend_from_slice(bytes)
+    });
+    let statistics = if options.write_statistics {
+        build_decimal_statistics(array, size, descriptor.clone())
+    } else {
+        None
+    };
+    let array = FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(size),
+        values.into(),
+        array.validity().cloned(),
+    );
+    to_page(&array, options, descriptor, statistics)
+}
+
+fn to_page(
+    array: &FixedSizeBinaryArray,
+    options: WriteOptions,
+    descriptor: ColumnDescriptor,
+    statistics: Option<ParquetStatistics>,
 ) -> Result<DataPage> {
     let is_optional = is_type_nullable(descriptor.type_());
     let validity = array.validity();
@@ -47,15 +89,10 @@ pub fn array_to_page(
 
     encode_plain(array, is_optional, &mut buffer);
 
-    let statistics = if options.write_statistics {
-        build_statistics(array, descriptor.clone())
-    } else {
-        None
-    };
-
     utils::build_plain_page(
         buffer,
         array.len(),
+        array.len(),
         array.null_count(),
         0,
         definition_levels_byte_length,
@@ -90,3 +127,29 @@ pub(super) fn build_statistics(
         .map(|e| serialize_statistics(&*e))
         .ok()
 }
+
+fn build_decimal_statistics(
+    array: &PrimitiveArray<i128>,
+    size: usize,
+    descriptor: ColumnDescriptor,
+) -> Option<ParquetStatistics> {
+    let pq_statistics = &ParquetStatistics {
+        max: None,
+        min: None,
+        null_count: Some(array.null_count() as i64),
+        distinct_count: None,
+        max_value: array
+            .iter()
+            .flatten()
+            .max()
+            .map(|x| x.to_be_bytes()[16 - size..].to_vec()),
+        min_value: array
+            .iter()
+            .flatten()
+            .min()
+            .map(|x| x.to_be_bytes()[16 - size..].to_vec()),
+    };
+    deserialize_statistics(pq_statistics, descriptor)
+        .map(|e| serialize_statistics(&*e))
+        .ok()
+}
@@ -165,6 +165,11 @@ impl<'a, O: Offset> NestedInfo<'a, O> {
     pub fn offsets(&self) -> &'a [O] {
         self.offsets
     }
+
+    /// The number of rows (lists) of this [`NestedInfo`]
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
 }
 
 fn write_levels_v1<F: Fn(&mut Vec<u8>) -> Result<()>>(
@@ -0,0 +1,53 @@
+use parquet2::{
+    compression::Compression,
+    page::{CompressedDataPage, CompressedPage, DataPageHeader, EncodedPage},
+};
+
+use crate::error::{ArrowError, Result};
+
+use super::super::lz4::compress_hadoop_lz4;
+
+/// The format of the pages written with [`Compression::Lz4`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lz4Format {
+    /// The LZ4 frame format
+    #[default]
+    Frame,
+    /// The Hadoop LZ4 format, a sequence of raw LZ4 blocks prefixed by their lengths,
+    /// expected by Hadoop-based readers of legacy data lakes.
+    /// Dictionary-encoded columns are not supported in this format.
+    Hadoop,
+}
+
+/// Compresses `page` with [`Compression::Lz4`] in the [`Lz4Format::Hadoop`] format.
+pub(super) fn compress(page: EncodedPage) -> Result<CompressedPage> {
+    let page = match page {
+        EncodedPage::Data(page) => page,
+        EncodedPage::Dict(_) => {
+            return Err(ArrowError::NotYetImplemented(
+                "Writing dictionary pages in the Hadoop LZ4 format".to_string(),
+            ))
+        }
+    };
+    let buffer = page.buffer();
+    let mut compressed = vec![];
+    match page.header() {
+        DataPageHeader::V1(_) => compress_hadoop_lz4(buffer, &mut compressed)?,
+        DataPageHeader::V2(header) => {
+            // the levels of v2 pages are not compressed
+            let levels_byte_length = (header.repetition_levels_byte_length
+                + header.definition_levels_byte_length)
+                as usize;
+            compressed.extend_from_slice(&buffer[..levels_byte_length]);
+            compress_hadoop_lz4(&buffer[levels_byte_length..], &mut compressed)?;
+        }
+    }
+    Ok(CompressedPage::Data(CompressedDataPage::new(
+        page.header().clone(),
+        compressed,
+        Compression::Lz4,
+        buffer.len(),
+        page.dictionary_page().cloned(),
+        page.descriptor().clone(),
+    )))
+}
@@ -5,6 +5,7 @@ mod dictionary;
 mod file;
 mod fixed_len_bytes;
 mod levels;
+mod lz4;
 mod primitive;
 mod row_group;
 mod schema;
@@ -16,6 +17,7 @@ use crate::array::*;
 use crate::bitmap::Bitmap;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::io::memory::estimated_bytes_size;
 use crate::io::parquet::read::is_type_nullable;
 use crate::io::parquet::write::levels::NestedInfo;
 use crate::types::days_ms;
@@ -36,7 +38,8 @@ pub use parquet2::{
 };
 
 pub use file::FileWriter;
-pub use row_group::{row_group_iter, RowGroupIterator};
+pub use lz4::Lz4Format;
+pub use row_group::{row_group_iter, RowGroupIterator, RowGroupSize, Spawn};
 pub use schema::to_parquet_type;
 pub use sink::FileSink;
 
@@ -66,7 +69,7 @@ pub fn to_parquet_schema(schema: &Schema) -> Result<SchemaDescriptor> {
 /// what the parquet spec allows.
 pub fn can_encode(data_type: &DataType, encoding: Encoding) -> bool {
     matches!(
-        (encoding, data_type),
+        (encoding, data_type.to_logical_type()),
         (Encoding::Plain, _)
             | (
                 Encoding::DeltaLengthByteArray,
@@ -74,7 +77,58 @@ pub fn can_encode(data_type: &DataType, encoding: Encoding) -> bool {
             )
             | (Encoding::RleDictionary, DataType::Dictionary(_, _, _))
             | (Encoding::PlainDictionary, DataType::Dictionary(_, _, _))
-    )
+    ) || (matches!(
+        encoding,
+        Encoding::RleDictionary | Encoding::PlainDictionary
+    ) && is_list_of_dictionary(data_type))
+}
+
+/// Whether `data_type` is a list whose values are dictionary-encoded
+fn is_list_of_dictionary(data_type: &DataType) -> bool {
+    match data_type.to_logical_type() {
+        DataType::List(inner) | DataType::LargeList(inner) => {
+            matches!(
+                inner.data_type().to_logical_type(),
+                DataType::Dictionary(_, _, _)
+            )
+        }
+        _ => false,
+    }
+}
+
+/// The maximum size of the data pages of a column chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageSize {
+    /// Data pages have at most this number of rows.
+    Rows(usize),
+    /// Data pages have approximately at most this number of (uncompressed) bytes, as
+    /// estimated from the size of the array's buffers in memory. A data page always
+    /// contains at least one row.
+    Bytes(usize),
+}
+
+impl PageSize {
+    /// Returns the number of rows of each data page of `array`
+    fn rows_per_page(&self, array: &dyn Array) -> usize {
+        match *self {
+            PageSize::Rows(rows) => rows.max(1),
+            PageSize::Bytes(bytes) => {
+                let len = array.len();
+                // round up so that the limit is not exceeded
+                let total = estimated_bytes_size(array);
+                let bytes_per_row = total.div_ceil(len.max(1));
+                (bytes / bytes_per_row.max(1)).max(1)
+            }
+        }
+    }
+}
+
+/// Returns the `(offset, length)` of the pages of `len` rows with at most `rows` rows each.
+/// An empty array is written as a single (empty) page.
+fn page_slices(len: usize, rows: usize) -> impl Iterator<Item = (usize, usize)> {
+    (0..len.max(1))
+        .step_by(rows.max(1))
+        .map(move |offset| (offset, rows.min(len - offset)))
 }
 
 /// Returns an iterator of [`EncodedPage`].
@@ -84,7 +138,7 @@ pub fn array_to_pages(
     options: WriteOptions,
     encoding: Encoding,
 ) -> Result<DynIter<'static, Result<EncodedPage>>> {
-    match array.data_type() {
+    match array.data_type().to_logical_type() {
         DataType::Dictionary(key_type, _, _) => {
             match_integer_type!(key_type, |$T| {
                 dictionary::array_to_pages::<$T>(
@@ -92,14 +146,61 @@ pub fn array_to_pages(
                     descriptor,
                     options,
                     encoding,
+                    None,
                 )
             })
         }
+        data_type if is_list_of_dictionary(data_type) => {
+            nested_dictionary_array_to_pages(array, descriptor, options, encoding)
+        }
         _ => array_to_page(array, descriptor, options, encoding)
             .map(|page| DynIter::new(std::iter::once(Ok(page)))),
     }
 }
 
+/// Returns an iterator of [`EncodedPage`] whose data pages are at most `page_size`.
+///
+/// The array is split in slices (without copying), each encoded as a data page of
+/// `options.version`. Dictionary-encoded arrays are written as a single dictionary page
+/// followed by data pages of keys.
+pub fn array_to_sized_pages(
+    array: &dyn Array,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+    encoding: Encoding,
+    page_size: PageSize,
+) -> Result<DynIter<'static, Result<EncodedPage>>> {
+    match array.data_type().to_logical_type() {
+        DataType::Dictionary(key_type, _, _) => {
+            match_integer_type!(key_type, |$T| {
+                dictionary::array_to_pages::<$T>(
+                    array.as_any().downcast_ref().unwrap(),
+                    descriptor,
+                    options,
+                    encoding,
+                    Some(page_size),
+                )
+            })
+        }
+        // the keys of a list are written as a single data page
+        data_type if is_list_of_dictionary(data_type) => {
+            nested_dictionary_array_to_pages(array, descriptor, options, encoding)
+        }
+        _ => {
+            let rows = page_size.rows_per_page(array);
+            if array.len() <= rows {
+                return array_to_pages(array, descriptor, options, encoding);
+            }
+            let slices = page_slices(array.len(), rows)
+                .map(|(offset, length)| array.slice(offset, length))
+                .collect::<Vec<_>>();
+            Ok(DynIter::new(slices.into_iter().map(move |array| {
+                array_to_page(array.as_ref(), descriptor.clone(), options, encoding)
+            })))
+        }
+    }
+}
+
 /// Converts an [`Array`] to a [`CompressedPage`] based on options, descriptor and `encoding`.
 pub fn array_to_page(
     array: &dyn Array,
@@ -275,17 +376,7 @@ pub fn array_to_page(
                 primitive::array_to_page::<i64, i64>(&array, options, descriptor)
             } else {
                 let size = decimal_length_from_precision(precision);
-                let mut values = Vec::<u8>::with_capacity(size * array.len());
-                array.values().iter().for_each(|x| {
-                    let bytes = &x.to_be_bytes()[16 - size..];
-                    values.extend_from_slice(bytes)
-                });
-                let array = FixedSizeBinaryArray::from_data(
-                    DataType::FixedSizeBinary(size),
-                    values.into(),
-                    array.validity().cloned(),
-                );
-                fixed_len_bytes::array_to_page(&array, options, descriptor)
+                fixed_len_bytes::decimal_array_to_page(array, size, options, descriptor)
             }
         }
         DataType::FixedSizeList(_, _) | DataType::List(_) | DataType::LargeList(_) => {
@@ -323,7 +414,13 @@ fn list_array_to_page<O: Offset>(
     let is_optional = is_type_nullable(descriptor.type_());
     let nested = NestedInfo::new(offsets, validity, is_optional);
 
-    match values.data_type() {
+    // a sliced list only uses the values between its first and last offset
+    let start = offsets[0].to_usize();
+    let end = offsets[offsets.len() - 1].to_usize();
+    let values = values.slice(start, end - start);
+    let values = values.as_ref();
+
+    match values.data_type().to_logical_type() {
         Boolean => {
             let values = values.as_any().downcast_ref().unwrap();
             boolean::nested_array_to_page::<O>(values, options, descriptor, nested)
@@ -393,12 +490,51 @@ fn list_array_to_page<O: Offset>(
     }
 }
 
+fn nested_dictionary_array_to_pages(
+    array: &dyn Array,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+    encoding: Encoding,
+) -> Result<DynIter<'static, Result<EncodedPage>>> {
+    let (offsets, validity, values) = match array.data_type().to_logical_type() {
+        DataType::List(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let offsets = array
+                .offsets()
+                .iter()
+                .map(|x| *x as i64)
+                .collect::<Vec<_>>();
+            (offsets, array.validity(), array.values())
+        }
+        DataType::LargeList(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            (array.offsets().to_vec(), array.validity(), array.values())
+        }
+        _ => unreachable!(),
+    };
+    match values.data_type().to_logical_type() {
+        DataType::Dictionary(key_type, _, _) => {
+            match_integer_type!(key_type, |$T| {
+                dictionary::nested_array_to_pages::<$T, i64>(
+                    &offsets,
+                    validity,
+                    values.as_any().downcast_ref().unwrap(),
+                    descriptor,
+                    options,
+                    encoding,
+                )
+            })
+        }
+        _ => unreachable!(),
+    }
+}
+
 fn nested_array_to_page(
     array: &dyn Array,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
 ) -> Result<DataPage> {
-    match array.data_type() {
+    match array.data_type().to_logical_type() {
         DataType::List(_) => {
             let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
             list_array_to_page(
@@ -421,7 +557,7 @@ fn nested_array_to_page(
         }
         DataType::FixedSizeList(_, size) => {
             let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
-            let offsets = (0..array.len())
+            let offsets = (0..=array.len())
                 .map(|x| (*size * x) as i32)
                 .collect::<Vec<_>>();
             list_array_to_page(
@@ -1,18 +1,28 @@
 use parquet2::FallibleStreamingIterator;
-use parquet2::{metadata::ColumnDescriptor, write::Compressor};
+use parquet2::{metadata::ColumnDescriptor, page::CompressedPage, write::compress};
+
+use std::collections::VecDeque;
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::{Arc, Mutex};
 
 use crate::{
     array::Array,
-    chunk::Chunk,
+    chunk::{merge, Chunk},
     datatypes::Schema,
     error::{ArrowError, Result},
 };
 
+use crate::io::memory::estimated_chunk_bytes_size;
+
+use super::lz4::{self, Lz4Format};
 use super::{
-    array_to_pages, to_parquet_schema, DynIter, DynStreamingIterator, Encoding, RowGroupIter,
-    SchemaDescriptor, WriteOptions,
+    array_to_pages, array_to_sized_pages, to_parquet_schema, Compression, DynIter,
+    DynStreamingIterator, Encoding, PageSize, RowGroupIter, SchemaDescriptor, WriteOptions,
 };
 
+/// A function that runs a task to completion, e.g. on a separate thread or on a thread pool.
+pub type Spawn = Arc<dyn Fn(Box<dyn FnOnce() + Send>) + Send + Sync>;
+
 /// Maps a [`Chunk`] and parquet-specific options to an [`RowGroupIter`] used to
 /// write to parquet
 pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
@@ -20,6 +30,134 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
     encodings: Vec<Encoding>,
     columns: Vec<ColumnDescriptor>,
     options: WriteOptions,
+) -> RowGroupIter<'static, ArrowError> {
+    sized_row_group_iter(chunk, encodings, columns, options, None, Lz4Format::Frame)
+}
+
+/// Returns an iterator over the compressed pages of the column chunk of `array`.
+fn compressed_pages(
+    array: &dyn Array,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+    encoding: Encoding,
+    page_size: Option<PageSize>,
+    lz4_format: Lz4Format,
+) -> Result<DynIter<'static, Result<CompressedPage>>> {
+    let pages = match page_size {
+        Some(page_size) => array_to_sized_pages(array, descriptor, options, encoding, page_size),
+        None => array_to_pages(array, descriptor, options, encoding),
+    }?;
+    Ok(DynIter::new(pages.map(move |page| {
+        let page = page?;
+        if options.compression == Compression::Lz4 && lz4_format == Lz4Format::Hadoop {
+            lz4::compress(page)
+        } else {
+            Ok(compress(page, vec![], options.compression)?)
+        }
+    })))
+}
+
+/// A [`FallibleStreamingIterator`] over the pages returned by [`compressed_pages`].
+struct CompressedPages {
+    iter: DynIter<'static, Result<CompressedPage>>,
+    current: Option<CompressedPage>,
+}
+
+impl FallibleStreamingIterator for CompressedPages {
+    type Item = CompressedPage;
+    type Error = ArrowError;
+
+    fn advance(&mut self) -> Result<()> {
+        self.current = self.iter.next().transpose()?;
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.as_ref()
+    }
+}
+
+/// A [`FallibleStreamingIterator`] over the pages of a column chunk encoded by a spawned task.
+struct SpawnedPages {
+    receiver: Mutex<Receiver<Result<Vec<CompressedPage>>>>,
+    pages: Option<std::vec::IntoIter<CompressedPage>>,
+    current: Option<CompressedPage>,
+}
+
+impl FallibleStreamingIterator for SpawnedPages {
+    type Item = CompressedPage;
+    type Error = ArrowError;
+
+    fn advance(&mut self) -> Result<()> {
+        if self.pages.is_none() {
+            // blocks until the task completes
+            let pages = self.receiver.get_mut().unwrap().recv().map_err(|e| {
+                ArrowError::External(
+                    "The task encoding a column chunk did not complete".to_string(),
+                    Box::new(e),
+                )
+            })??;
+            self.pages = Some(pages.into_iter());
+        }
+        self.current = self.pages.as_mut().unwrap().next();
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.as_ref()
+    }
+}
+
+/// Same as [`sized_row_group_iter`], but whose column chunks are encoded by tasks run via
+/// `spawn`, all spawned before this function returns.
+fn spawned_row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
+    chunk: Chunk<A>,
+    encodings: Vec<Encoding>,
+    columns: Vec<ColumnDescriptor>,
+    options: WriteOptions,
+    page_size: Option<PageSize>,
+    lz4_format: Lz4Format,
+    spawn: &Spawn,
+) -> RowGroupIter<'static, ArrowError> {
+    let columns = chunk
+        .into_arrays()
+        .into_iter()
+        .zip(columns)
+        .zip(encodings)
+        .map(|((array, descriptor), encoding)| {
+            let (sender, receiver) = channel();
+            spawn(Box::new(move || {
+                let pages = compressed_pages(
+                    array.as_ref(),
+                    descriptor,
+                    options,
+                    encoding,
+                    page_size,
+                    lz4_format,
+                )
+                .and_then(|pages| pages.collect());
+                // the receiver is dropped when the row group is no longer written
+                let _ = sender.send(pages);
+            }));
+            Ok(DynStreamingIterator::new(SpawnedPages {
+                receiver: Mutex::new(receiver),
+                pages: None,
+                current: None,
+            }))
+        })
+        .collect::<Vec<_>>();
+    DynIter::new(columns.into_iter())
+}
+
+/// Same as [`row_group_iter`], but whose data pages are at most `page_size` and whose LZ4
+/// pages are in `lz4_format`.
+fn sized_row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
+    chunk: Chunk<A>,
+    encodings: Vec<Encoding>,
+    columns: Vec<ColumnDescriptor>,
+    options: WriteOptions,
+    page_size: Option<PageSize>,
+    lz4_format: Lz4Format,
 ) -> RowGroupIter<'static, ArrowError> {
     DynIter::new(
         chunk
@@ -28,25 +166,57 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
             .zip(columns.into_iter())
             .zip(encodings.into_iter())
             .map(move |((array, descriptor), encoding)| {
-                array_to_pages(array.as_ref(), descriptor, options, encoding).map(move |pages| {
-                    let encoded_pages = DynIter::new(pages.map(|x| Ok(x?)));
-                    let compressed_pages =
-                        Compressor::new(encoded_pages, options.compression, vec![])
-                            .map_err(ArrowError::from);
-                    DynStreamingIterator::new(compressed_pages)
+                compressed_pages(
+                    array.as_ref(),
+                    descriptor,
+                    options,
+                    encoding,
+                    page_size,
+                    lz4_format,
+                )
+                .map(|iter| {
+                    DynStreamingIterator::new(CompressedPages {
+                        iter,
+                        current: None,
+                    })
                 })
             }),
     )
 }
 
+/// The target size of the row groups produced by a [`RowGroupIterator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowGroupSize {
+    /// Row groups have at most this number of rows.
+    Rows(usize),
+    /// Row groups have approximately this number of bytes, as estimated from the size of the
+    /// arrays' buffers in memory. A row group always contains at least one row.
+    Bytes(usize),
+}
+
 /// An iterator adapter that converts an iterator over [`Chunk`] into an iterator
 /// of row groups.
 /// Use it to create an iterator consumable by the parquet's API.
+///
+/// By default, every [`Chunk`] is written as a row group. Use
+/// [`RowGroupIterator::set_row_group_size`] to split and merge chunks into row groups of a
+/// target size instead, and [`RowGroupIterator::set_page_size`] to limit the size of
+/// their data pages.
 pub struct RowGroupIterator<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> {
     iter: I,
     options: WriteOptions,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Encoding>,
+    row_group_size: Option<RowGroupSize>,
+    page_size: Option<PageSize>,
+    lz4_format: Lz4Format,
+    spawn: Option<Spawn>,
+    // slices of chunks that do not yet fill a row group
+    pending: Vec<Chunk<Box<dyn Array>>>,
+    pending_rows: usize,
+    pending_bytes: usize,
+    // row groups ready to be returned
+    ready: VecDeque<Chunk<Box<dyn Array>>>,
 }
 
 impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGroupIterator<A, I> {
@@ -66,6 +236,14 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
             options,
             parquet_schema,
             encodings,
+            row_group_size: None,
+            page_size: None,
+            lz4_format: Lz4Format::Frame,
+            spawn: None,
+            pending: vec![],
+            pending_rows: 0,
+            pending_bytes: 0,
+            ready: VecDeque::new(),
         })
     }
 
@@ -73,6 +251,126 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
     pub fn parquet_schema(&self) -> &SchemaDescriptor {
         &self.parquet_schema
     }
+
+    /// Sets the target size of the row groups.
+    /// Chunks larger than the target are split (without copying) and consecutive chunks
+    /// smaller than the target are merged (which copies their data).
+    pub fn set_row_group_size(&mut self, row_group_size: RowGroupSize) {
+        self.row_group_size = Some(row_group_size);
+    }
+
+    /// Sets the maximum size of the data pages of each column chunk.
+    /// Arrays larger than it are split (without copying) into multiple data pages.
+    pub fn set_page_size(&mut self, page_size: PageSize) {
+        self.page_size = Some(page_size);
+    }
+
+    /// Sets the format of the pages when writing with [`Compression::Lz4`] (by default
+    /// [`Lz4Format::Frame`]), e.g. [`Lz4Format::Hadoop`] for Hadoop-based readers.
+    pub fn set_lz4_format(&mut self, lz4_format: Lz4Format) {
+        self.lz4_format = lz4_format;
+    }
+
+    /// Sets the function used to encode and compress the column chunks in parallel.
+    ///
+    /// For every row group, `spawn` is called with one task per column before the row group
+    /// is returned. Each task encodes and compresses its column chunk in memory, and the
+    /// writer receives the pages of each column chunk as its task completes.
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow2::array::{Array, Int32Array};
+    /// # use arrow2::chunk::Chunk;
+    /// # use arrow2::datatypes::{DataType, Field, Schema};
+    /// # use arrow2::io::parquet::write::*;
+    /// # fn main() -> arrow2::error::Result<()> {
+    /// let schema = Schema::from(vec![Field::new("c1", DataType::Int32, false)]);
+    /// let chunk = Chunk::new(vec![Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>]);
+    /// let options = WriteOptions {
+    ///     write_statistics: true,
+    ///     compression: Compression::Snappy,
+    ///     version: Version::V2,
+    /// };
+    /// let mut row_groups =
+    ///     RowGroupIterator::try_new(vec![Ok(chunk)].into_iter(), &schema, options, vec![Encoding::Plain])?;
+    /// row_groups.set_spawn(Arc::new(|task| {
+    ///     std::thread::spawn(task);
+    /// }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_spawn(&mut self, spawn: Spawn) {
+        self.spawn = Some(spawn);
+    }
+
+    fn flush(&mut self) {
+        if !self.pending.is_empty() {
+            let chunk = merge(std::mem::take(&mut self.pending));
+            self.ready.push_back(chunk);
+        }
+        self.pending_rows = 0;
+        self.pending_bytes = 0;
+    }
+
+    /// Splits `chunk` into `pending` and `ready` according to `row_group_size`.
+    fn push(&mut self, chunk: Chunk<A>, row_group_size: RowGroupSize) {
+        let len = chunk.len();
+        let bytes = estimated_chunk_bytes_size(&chunk);
+        // round up so that the target is not exceeded
+        let bytes_per_row = bytes.div_ceil(len.max(1));
+
+        let mut offset = 0;
+        while offset < len {
+            let length = match row_group_size {
+                RowGroupSize::Rows(rows) => rows.saturating_sub(self.pending_rows),
+                RowGroupSize::Bytes(bytes) => {
+                    bytes.saturating_sub(self.pending_bytes) / bytes_per_row.max(1)
+                }
+            };
+            let length = match (length.min(len - offset), self.pending.is_empty()) {
+                // a row group always contains at least one row
+                (0, true) => 1,
+                (0, false) => {
+                    self.flush();
+                    continue;
+                }
+                (length, _) => length,
+            };
+            self.pending.push(chunk.slice(offset, length));
+            self.pending_rows += length;
+            self.pending_bytes += length * bytes_per_row;
+            offset += length;
+
+            let is_full = match row_group_size {
+                RowGroupSize::Rows(rows) => self.pending_rows >= rows,
+                RowGroupSize::Bytes(bytes) => self.pending_bytes + bytes_per_row.max(1) > bytes,
+            };
+            if is_full {
+                self.flush();
+            }
+        }
+    }
+}
+
+fn to_row_group<A: AsRef<dyn Array> + 'static + Send + Sync>(
+    chunk: Chunk<A>,
+    encodings: &[Encoding],
+    parquet_schema: &SchemaDescriptor,
+    options: WriteOptions,
+    page_size: Option<PageSize>,
+    lz4_format: Lz4Format,
+    spawn: Option<&Spawn>,
+) -> (RowGroupIter<'static, ArrowError>, usize) {
+    let len = chunk.len();
+    let encodings = encodings.to_vec();
+    let columns = parquet_schema.columns().to_vec();
+    let iter = match spawn {
+        Some(spawn) => spawned_row_group_iter(
+            chunk, encodings, columns, options, page_size, lz4_format, spawn,
+        ),
+        None => sized_row_group_iter(chunk, encodings, columns, options, page_size, lz4_format),
+    };
+    (iter, len)
 }
 
 impl<A: AsRef<dyn Array> + 'static + Send + Sync, I: Iterator<Item = Result<Chunk<A>>>> Iterator
@@ -83,19 +381,41 @@ impl<A: AsRef<dyn Array> + 'static + Send + Sync, I: Iterator<Item = Result<Chun
     fn next(&mut self) -> Option<Self::Item> {
         let options = self.options;
 
-        self.iter.next().map(|maybe_chunk| {
-            let chunk = maybe_chunk?;
-            let len = chunk.len();
-            let encodings = self.encodings.clone();
-            Ok((
-                row_group_iter(
+        let row_group_size = if let Some(row_group_size) = self.row_group_size {
+            row_group_size
+        } else {
+            return self.iter.next().map(|maybe_chunk| {
+                let chunk = maybe_chunk?;
+                Ok(to_row_group(
                     chunk,
-                    encodings,
-                    self.parquet_schema.columns().to_vec(),
+                    &self.encodings,
+                    &self.parquet_schema,
                     options,
-                ),
-                len,
-            ))
-        })
+                    self.page_size,
+                    self.lz4_format,
+                    self.spawn.as_ref(),
+                ))
+            });
+        };
+
+        loop {
+            if let Some(chunk) = self.ready.pop_front() {
+                return Some(Ok(to_row_group(
+                    chunk,
+                    &self.encodings,
+                    &self.parquet_schema,
+                    options,
+                    self.page_size,
+                    self.lz4_format,
+                    self.spawn.as_ref(),
+                )));
+            }
+            match self.iter.next() {
+                Some(Ok(chunk)) => self.push(chunk, row_group_size),
+                Some(Err(error)) => return Some(Err(error)),
+                None if self.pending.is_empty() => return None,
+                None => self.flush(),
+            }
+        }
     }
 }
@@ -1,8 +1,11 @@
-use crate::bitmap::Bitmap;
+use crate::bitmap::{
+    utils::{bytes_for, extend_aligned},
+    Bitmap,
+};
 
 use parquet2::{
     compression::Compression,
-    encoding::{hybrid_rle::encode_bool, Encoding},
+    encoding::{hybrid_rle::encode_bool, uleb128, Encoding},
     metadata::ColumnDescriptor,
     page::{DataPage, DataPageHeader, DataPageHeaderV1, DataPageHeaderV2},
     statistics::ParquetStatistics,
@@ -13,10 +16,24 @@ use crate::error::Result;
 
 use super::Version;
 
-fn encode_iter_v1<I: Iterator<Item = bool>>(buffer: &mut Vec<u8>, iter: I) -> Result<()> {
+/// Writes the def levels of a bitmap, whose bit-packed encoding (with a bit width of 1) are its
+/// (realigned) bytes.
+fn encode_bitmap(buffer: &mut Vec<u8>, validity: &Bitmap) {
+    let (slice, offset, length) = validity.as_slice();
+
+    // write the number of bit-packed groups of 8 values + indicator (the first bit is set)
+    let header = ((bytes_for(length) as u64) << 1) | 1;
+    let mut container = [0; 10];
+    let used = uleb128::encode(header, &mut container);
+    buffer.extend_from_slice(&container[..used]);
+
+    extend_aligned(buffer, slice, offset, length);
+}
+
+fn encode_v1<F: FnOnce(&mut Vec<u8>) -> Result<()>>(buffer: &mut Vec<u8>, encode: F) -> Result<()> {
     buffer.extend_from_slice(&[0; 4]);
     let start = buffer.len();
-    encode_bool(buffer, iter)?;
+    encode(buffer)?;
     let end = buffer.len();
     let length = end - start;
 
@@ -26,18 +43,14 @@ fn encode_iter_v1<I: Iterator<Item = bool>>(buffer: &mut Vec<u8>, iter: I) -> Re
     Ok(())
 }
 
-fn encode_iter_v2<I: Iterator<Item = bool>>(writer: &mut Vec<u8>, iter: I) -> Result<()> {
-    Ok(encode_bool(writer, iter)?)
-}
-
-fn encode_iter<I: Iterator<Item = bool>>(
+fn encode<F: FnOnce(&mut Vec<u8>) -> Result<()>>(
     writer: &mut Vec<u8>,
-    iter: I,
     version: Version,
+    encode: F,
 ) -> Result<()> {
     match version {
-        Version::V1 => encode_iter_v1(writer, iter),
-        Version::V2 => encode_iter_v2(writer, iter),
+        Version::V1 => encode_v1(writer, encode),
+        Version::V2 => encode(writer),
     }
 }
 
@@ -51,8 +64,13 @@ pub fn write_def_levels(
 ) -> Result<()> {
     // encode def levels
     match (is_optional, validity) {
-        (true, Some(validity)) => encode_iter(writer, validity.iter(), version),
-        (true, None) => encode_iter(writer, std::iter::repeat(true).take(len), version),
+        (true, Some(validity)) => encode(writer, version, |writer| {
+            encode_bitmap(writer, validity);
+            Ok(())
+        }),
+        (true, None) => encode(writer, version, |writer| {
+            Ok(encode_bool(writer, std::iter::repeat_n(true, len))?)
+        }),
         _ => Ok(()), // is required => no def levels
     }
 }
@@ -60,7 +78,8 @@ pub fn write_def_levels(
 #[allow(clippy::too_many_arguments)]
 pub fn build_plain_page(
     buffer: Vec<u8>,
-    len: usize,
+    num_values: usize,
+    num_rows: usize,
     null_count: usize,
     repetition_levels_byte_length: usize,
     definition_levels_byte_length: usize,
@@ -72,7 +91,7 @@ pub fn build_plain_page(
     match options.version {
         Version::V1 => {
             let header = DataPageHeader::V1(DataPageHeaderV1 {
-                num_values: len as i32,
+                num_values: num_values as i32,
                 encoding: encoding.into(),
                 definition_level_encoding: Encoding::Rle.into(),
                 repetition_level_encoding: Encoding::Rle.into(),
@@ -83,10 +102,10 @@ pub fn build_plain_page(
         }
         Version::V2 => {
             let header = DataPageHeader::V2(DataPageHeaderV2 {
-                num_values: len as i32,
+                num_values: num_values as i32,
                 encoding: encoding.into(),
                 num_nulls: null_count as i32,
-                num_rows: len as i32,
+                num_rows: num_rows as i32,
                 definition_levels_byte_length: definition_levels_byte_length as i32,
                 repetition_levels_byte_length: repetition_levels_byte_length as i32,
                 is_compressed: Some(options.compression != Compression::Uncompressed),
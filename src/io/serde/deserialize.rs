@@ -0,0 +1,251 @@
+use ::serde::de::{
+    value::MapAccessDeserializer, DeserializeOwned, DeserializeSeed, Deserializer,
+    IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use ::serde::forward_to_deserialize_any;
+
+use crate::array::{
+    Array, BinaryArray, BooleanArray, ListArray, Offset, PrimitiveArray, StructArray, Utf8Array,
+};
+use crate::chunk::Chunk;
+use crate::datatypes::{Field, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+
+use super::Error;
+
+/// A [`Deserializer`] of the value of an array at a row.
+struct ValueDeserializer<'a> {
+    array: &'a dyn Array,
+    row: usize,
+}
+
+impl<'a> ValueDeserializer<'a> {
+    /// Whether the value is null. The values of [`NullArray`](crate::array::NullArray)s are
+    /// all null even though they have no validity.
+    fn is_null(&self) -> bool {
+        self.array.data_type().to_physical_type() == PhysicalType::Null
+            || self.array.is_null(self.row)
+    }
+
+    fn downcast<A: 'static>(&self) -> &'a A {
+        self.array.as_any().downcast_ref::<A>().unwrap()
+    }
+
+    fn visit_list<'de, O: Offset, V: Visitor<'de>>(
+        &self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, Error> {
+        let array = self.downcast::<ListArray<O>>();
+        let offsets = array.offsets();
+        visitor.visit_seq(ListAccess {
+            values: array.values().as_ref(),
+            index: offsets[self.row].to_usize(),
+            end: offsets[self.row + 1].to_usize(),
+        })
+    }
+}
+
+impl<'de, 'a> Deserializer<'de> for ValueDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> std::result::Result<V::Value, Error> {
+        if self.is_null() {
+            return visitor.visit_unit();
+        }
+        use PhysicalType::*;
+        let row = self.row;
+        match self.array.data_type().to_physical_type() {
+            Boolean => visitor.visit_bool(self.downcast::<BooleanArray>().value(row)),
+            Primitive(PrimitiveType::Int8) => {
+                visitor.visit_i8(self.downcast::<PrimitiveArray<i8>>().value(row))
+            }
+            Primitive(PrimitiveType::Int16) => {
+                visitor.visit_i16(self.downcast::<PrimitiveArray<i16>>().value(row))
+            }
+            Primitive(PrimitiveType::Int32) => {
+                visitor.visit_i32(self.downcast::<PrimitiveArray<i32>>().value(row))
+            }
+            Primitive(PrimitiveType::Int64) => {
+                visitor.visit_i64(self.downcast::<PrimitiveArray<i64>>().value(row))
+            }
+            Primitive(PrimitiveType::UInt8) => {
+                visitor.visit_u8(self.downcast::<PrimitiveArray<u8>>().value(row))
+            }
+            Primitive(PrimitiveType::UInt16) => {
+                visitor.visit_u16(self.downcast::<PrimitiveArray<u16>>().value(row))
+            }
+            Primitive(PrimitiveType::UInt32) => {
+                visitor.visit_u32(self.downcast::<PrimitiveArray<u32>>().value(row))
+            }
+            Primitive(PrimitiveType::UInt64) => {
+                visitor.visit_u64(self.downcast::<PrimitiveArray<u64>>().value(row))
+            }
+            Primitive(PrimitiveType::Float32) => {
+                visitor.visit_f32(self.downcast::<PrimitiveArray<f32>>().value(row))
+            }
+            Primitive(PrimitiveType::Float64) => {
+                visitor.visit_f64(self.downcast::<PrimitiveArray<f64>>().value(row))
+            }
+            Utf8 => visitor.visit_str(self.downcast::<Utf8Array<i32>>().value(row)),
+            LargeUtf8 => visitor.visit_str(self.downcast::<Utf8Array<i64>>().value(row)),
+            Binary => visitor.visit_bytes(self.downcast::<BinaryArray<i32>>().value(row)),
+            LargeBinary => visitor.visit_bytes(self.downcast::<BinaryArray<i64>>().value(row)),
+            List => self.visit_list::<i32, _>(visitor),
+            LargeList => self.visit_list::<i64, _>(visitor),
+            Struct => {
+                let array = self.downcast::<StructArray>();
+                visitor.visit_map(StructAccess {
+                    fields: array.fields(),
+                    arrays: array.values(),
+                    row,
+                    index: 0,
+                })
+            }
+            _ => Err(Error::Arrow(ArrowError::NotYetImplemented(format!(
+                "Deserializing records from arrays of {:?}",
+                self.array.data_type()
+            )))),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> std::result::Result<V::Value, Error> {
+        if self.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> std::result::Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> std::result::Result<V::Value, Error> {
+        // unit variants are serialized as their names
+        let variant = match self.array.data_type().to_physical_type() {
+            _ if self.is_null() => return self.deserialize_any(visitor),
+            PhysicalType::Utf8 => self.downcast::<Utf8Array<i32>>().value(self.row),
+            PhysicalType::LargeUtf8 => self.downcast::<Utf8Array<i64>>().value(self.row),
+            _ => return self.deserialize_any(visitor),
+        };
+        visitor.visit_enum(variant.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// A [`SeqAccess`] of the values of a list.
+struct ListAccess<'a> {
+    values: &'a dyn Array,
+    index: usize,
+    end: usize,
+}
+
+impl<'de, 'a> SeqAccess<'de> for ListAccess<'a> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> std::result::Result<Option<T::Value>, Error> {
+        if self.index == self.end {
+            return Ok(None);
+        }
+        let row = self.index;
+        self.index += 1;
+        seed.deserialize(ValueDeserializer {
+            array: self.values,
+            row,
+        })
+        .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.end - self.index)
+    }
+}
+
+/// A [`MapAccess`] of the values of `arrays` at a row, keyed by the names of `fields`.
+struct StructAccess<'a, A: AsRef<dyn Array>> {
+    fields: &'a [Field],
+    arrays: &'a [A],
+    row: usize,
+    index: usize,
+}
+
+impl<'de, 'a, A: AsRef<dyn Array>> MapAccess<'de> for StructAccess<'a, A> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> std::result::Result<Option<K::Value>, Error> {
+        self.fields
+            .get(self.index)
+            .map(|field| seed.deserialize(field.name.as_str().into_deserializer()))
+            .transpose()
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> std::result::Result<V::Value, Error> {
+        let array = self.arrays[self.index].as_ref();
+        self.index += 1;
+        seed.deserialize(ValueDeserializer {
+            array,
+            row: self.row,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len() - self.index)
+    }
+}
+
+/// Deserializes a [`Chunk`] whose arrays correspond to `fields` into a sequence of records.
+/// Null values are deserialized as `None` (or unit) and structs as maps keyed by the
+/// names of their fields.
+/// # Errors
+/// This function errors iff the chunk and the `fields` have a different number of columns,
+/// a [`DataType`](crate::datatypes::DataType) of the chunk is not supported or the records
+/// can't be deserialized from the chunk.
+pub fn deserialize<T: DeserializeOwned, A: AsRef<dyn Array>>(
+    chunk: &Chunk<A>,
+    fields: &[Field],
+) -> Result<Vec<T>> {
+    if chunk.arrays().len() != fields.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The chunk has {} arrays but {} fields were provided",
+            chunk.arrays().len(),
+            fields.len()
+        )));
+    }
+    (0..chunk.len())
+        .map(|row| {
+            T::deserialize(MapAccessDeserializer::new(StructAccess {
+                fields,
+                arrays: chunk.arrays(),
+                row,
+                index: 0,
+            }))
+            .map_err(|error| error.into())
+        })
+        .collect()
+}
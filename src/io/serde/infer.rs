@@ -0,0 +1,640 @@
+use ::serde::ser::{
+    Impossible, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple,
+    SerializeTupleStruct, Serializer,
+};
+use indexmap::map::IndexMap;
+
+use crate::datatypes::{DataType, Field};
+use crate::error::{ArrowError, Result};
+
+use super::Error;
+
+/// The data type of values, inferred from serde's data model.
+#[derive(Debug, Clone, PartialEq)]
+enum Inferred {
+    /// Only nulls (e.g. `None` or `()`), which does not constrain the data type.
+    Null,
+    Leaf(DataType),
+    List(Box<Inferred>),
+    Struct(IndexMap<String, Inferred>),
+}
+
+/// Returns the bit width and signedness of integer types
+fn integer_width(data_type: &DataType) -> Option<(usize, bool)> {
+    use DataType::*;
+    Some(match data_type {
+        Int8 => (8, true),
+        Int16 => (16, true),
+        Int32 => (32, true),
+        Int64 => (64, true),
+        UInt8 => (8, false),
+        UInt16 => (16, false),
+        UInt32 => (32, false),
+        UInt64 => (64, false),
+        _ => return None,
+    })
+}
+
+/// Returns the smallest integer type that holds all values of the integer types
+/// `lhs` and `rhs`, if any. A signed integer holds an unsigned one twice as narrow.
+fn coerce_integers(
+    (lhs, lhs_signed): (usize, bool),
+    (rhs, rhs_signed): (usize, bool),
+) -> Option<DataType> {
+    use DataType::*;
+    let (width, signed) = match (lhs_signed, rhs_signed) {
+        (true, false) => (lhs.max(2 * rhs), true),
+        (false, true) => (rhs.max(2 * lhs), true),
+        (signed, _) => (lhs.max(rhs), signed),
+    };
+    Some(match (width, signed) {
+        (8, true) => Int8,
+        (16, true) => Int16,
+        (32, true) => Int32,
+        (64, true) => Int64,
+        (8, false) => UInt8,
+        (16, false) => UInt16,
+        (32, false) => UInt32,
+        (64, false) => UInt64,
+        _ => return None,
+    })
+}
+
+fn is_float(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::Float32 | DataType::Float64)
+}
+
+/// Returns the [`DataType`] that values of `lhs` and `rhs` can be represented with:
+/// integers are promoted to the smallest integer that holds both of them, and integers
+/// and floats to [`DataType::Float64`].
+/// # Errors
+/// Errors when no such type exists, e.g. for `UInt64` and a signed integer.
+fn coerce(lhs: DataType, rhs: DataType) -> std::result::Result<DataType, Error> {
+    let is_number = |x: &DataType| integer_width(x).is_some() || is_float(x);
+    let data_type = if lhs == rhs {
+        Some(lhs.clone())
+    } else if let (Some(lhs), Some(rhs)) = (integer_width(&lhs), integer_width(&rhs)) {
+        coerce_integers(lhs, rhs)
+    } else if is_number(&lhs) && is_number(&rhs) {
+        Some(DataType::Float64)
+    } else {
+        None
+    };
+    data_type.ok_or_else(|| {
+        Error::Arrow(ArrowError::InvalidArgumentError(format!(
+            "Values of types {:?} and {:?} can't be inferred to a common data type",
+            lhs, rhs
+        )))
+    })
+}
+
+impl Inferred {
+    /// Returns the [`Inferred`] of a value that is either of `self` or of `other`.
+    fn merge(self, other: Inferred) -> std::result::Result<Inferred, Error> {
+        Ok(match (self, other) {
+            (Inferred::Null, other) | (other, Inferred::Null) => other,
+            (Inferred::Leaf(lhs), Inferred::Leaf(rhs)) => Inferred::Leaf(coerce(lhs, rhs)?),
+            (Inferred::List(lhs), Inferred::List(rhs)) => {
+                Inferred::List(Box::new(lhs.merge(*rhs)?))
+            }
+            (Inferred::Struct(mut lhs), Inferred::Struct(rhs)) => {
+                for (name, rhs) in rhs {
+                    insert(&mut lhs, name, rhs)?;
+                }
+                Inferred::Struct(lhs)
+            }
+            (lhs, rhs) => {
+                return Err(Error::Arrow(ArrowError::InvalidArgumentError(format!(
+                    "Values of types {:?} and {:?} can't be inferred to a common data type",
+                    lhs.data_type(),
+                    rhs.data_type()
+                ))))
+            }
+        })
+    }
+
+    fn data_type(&self) -> DataType {
+        match self {
+            Inferred::Null => DataType::Null,
+            Inferred::Leaf(data_type) => data_type.clone(),
+            Inferred::List(inner) => {
+                DataType::List(Box::new(Field::new("item", inner.data_type(), true)))
+            }
+            Inferred::Struct(fields) => DataType::Struct(
+                fields
+                    .iter()
+                    .map(|(name, inferred)| Field::new(name, inferred.data_type(), true))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+fn insert(
+    fields: &mut IndexMap<String, Inferred>,
+    name: String,
+    inferred: Inferred,
+) -> std::result::Result<(), Error> {
+    let entry = fields.entry(name).or_insert(Inferred::Null);
+    *entry = std::mem::replace(entry, Inferred::Null).merge(inferred)?;
+    Ok(())
+}
+
+fn unsupported(what: &str) -> Error {
+    Error::Arrow(ArrowError::NotYetImplemented(format!(
+        "Converting {} to arrow",
+        what
+    )))
+}
+
+/// A [`Serializer`] that infers the [`Inferred`] of a value.
+struct InferSerializer;
+
+impl Serializer for InferSerializer {
+    type Ok = Inferred;
+    type Error = Error;
+    type SerializeSeq = InferSeq;
+    type SerializeTuple = InferSeq;
+    type SerializeTupleStruct = InferSeq;
+    type SerializeTupleVariant = Impossible<Inferred, Error>;
+    type SerializeMap = InferStruct;
+    type SerializeStruct = InferStruct;
+    type SerializeStructVariant = Impossible<Inferred, Error>;
+
+    fn serialize_bool(self, _: bool) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::Boolean))
+    }
+
+    fn serialize_i8(self, _: i8) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::Int8))
+    }
+
+    fn serialize_i16(self, _: i16) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::Int16))
+    }
+
+    fn serialize_i32(self, _: i32) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::Int32))
+    }
+
+    fn serialize_i64(self, _: i64) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::Int64))
+    }
+
+    fn serialize_u8(self, _: u8) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::UInt8))
+    }
+
+    fn serialize_u16(self, _: u16) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::UInt16))
+    }
+
+    fn serialize_u32(self, _: u32) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::UInt32))
+    }
+
+    fn serialize_u64(self, _: u64) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::UInt64))
+    }
+
+    fn serialize_f32(self, _: f32) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::Float32))
+    }
+
+    fn serialize_f64(self, _: f64) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::Float64))
+    }
+
+    fn serialize_char(self, _: char) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::Utf8))
+    }
+
+    fn serialize_str(self, _: &str) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::Utf8))
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::Binary))
+    }
+
+    fn serialize_none(self) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Null)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(
+        self,
+        value: &T,
+    ) -> std::result::Result<Inferred, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Null)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Leaf(DataType::Utf8))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> std::result::Result<Inferred, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> std::result::Result<Inferred, Error> {
+        Err(unsupported("enum variants with data"))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> std::result::Result<InferSeq, Error> {
+        Ok(InferSeq(Inferred::Null))
+    }
+
+    fn serialize_tuple(self, _: usize) -> std::result::Result<InferSeq, Error> {
+        Ok(InferSeq(Inferred::Null))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<InferSeq, Error> {
+        Ok(InferSeq(Inferred::Null))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self::SerializeTupleVariant, Error> {
+        Err(unsupported("enum variants with data"))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> std::result::Result<InferStruct, Error> {
+        Ok(InferStruct::default())
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<InferStruct, Error> {
+        Ok(InferStruct::default())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self::SerializeStructVariant, Error> {
+        Err(unsupported("enum variants with data"))
+    }
+}
+
+/// Infers the [`Inferred`] of a sequence from the [`Inferred`] of its items.
+struct InferSeq(Inferred);
+
+impl SerializeSeq for InferSeq {
+    type Ok = Inferred;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> std::result::Result<(), Error> {
+        let item = std::mem::replace(&mut self.0, Inferred::Null);
+        self.0 = item.merge(value.serialize(InferSerializer)?)?;
+        Ok(())
+    }
+
+    fn end(self) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::List(Box::new(self.0)))
+    }
+}
+
+impl SerializeTuple for InferSeq {
+    type Ok = Inferred;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> std::result::Result<(), Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> std::result::Result<Inferred, Error> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeTupleStruct for InferSeq {
+    type Ok = Inferred;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> std::result::Result<(), Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> std::result::Result<Inferred, Error> {
+        SerializeSeq::end(self)
+    }
+}
+
+/// Infers the [`Inferred`] of a struct or map from the [`Inferred`] of its values.
+#[derive(Default)]
+struct InferStruct {
+    fields: IndexMap<String, Inferred>,
+    key: Option<String>,
+}
+
+impl SerializeMap for InferStruct {
+    type Ok = Inferred;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> std::result::Result<(), Error> {
+        self.key = Some(key.serialize(KeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> std::result::Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .expect("serialize_key is called before serialize_value");
+        insert(&mut self.fields, key, value.serialize(InferSerializer)?)
+    }
+
+    fn end(self) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Struct(self.fields))
+    }
+}
+
+impl SerializeStruct for InferStruct {
+    type Ok = Inferred;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> std::result::Result<(), Error> {
+        insert(
+            &mut self.fields,
+            key.to_string(),
+            value.serialize(InferSerializer)?,
+        )
+    }
+
+    fn end(self) -> std::result::Result<Inferred, Error> {
+        Ok(Inferred::Struct(self.fields))
+    }
+}
+
+/// A [`Serializer`] of the keys of maps, which must be strings (the names of the fields).
+pub(super) struct KeySerializer;
+
+impl KeySerializer {
+    fn error() -> Error {
+        Error::Arrow(ArrowError::InvalidArgumentError(
+            "Only maps whose keys are strings can be converted to a Chunk".to_string(),
+        ))
+    }
+}
+
+impl Serializer for KeySerializer {
+    type Ok = String;
+    type Error = Error;
+    type SerializeSeq = Impossible<String, Error>;
+    type SerializeTuple = Impossible<String, Error>;
+    type SerializeTupleStruct = Impossible<String, Error>;
+    type SerializeTupleVariant = Impossible<String, Error>;
+    type SerializeMap = Impossible<String, Error>;
+    type SerializeStruct = Impossible<String, Error>;
+    type SerializeStructVariant = Impossible<String, Error>;
+
+    fn serialize_str(self, value: &str) -> std::result::Result<String, Error> {
+        Ok(value.to_string())
+    }
+
+    fn serialize_char(self, value: char) -> std::result::Result<String, Error> {
+        Ok(value.to_string())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> std::result::Result<String, Error> {
+        Ok(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> std::result::Result<String, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_bool(self, _: bool) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_i8(self, _: i8) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_i16(self, _: i16) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_i32(self, _: i32) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_i64(self, _: i64) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_u8(self, _: u8) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_u16(self, _: u16) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_u32(self, _: u32) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_u64(self, _: u64) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_f32(self, _: f32) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_f64(self, _: f64) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_none(self) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_unit(self) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> std::result::Result<String, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> std::result::Result<Self::SerializeSeq, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_tuple(self, _: usize) -> std::result::Result<Self::SerializeTuple, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self::SerializeTupleStruct, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self::SerializeTupleVariant, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> std::result::Result<Self::SerializeMap, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self::SerializeStruct, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self::SerializeStructVariant, Error> {
+        Err(Self::error())
+    }
+}
+
+/// The error of records that are not serialized as maps (e.g. structs).
+pub(super) fn not_a_map() -> ArrowError {
+    ArrowError::InvalidArgumentError(
+        "Only records serialized as maps (e.g. structs) can be converted to a Chunk".to_string(),
+    )
+}
+
+/// Infers the [`Field`]s of a sequence of records.
+/// # Implementation
+/// A field is nullable iff it is null or missing in at least one record.
+/// Fields whose values are all null are inferred as [`DataType::Null`].
+/// The fields of nested structs and the items of lists are always nullable.
+/// # Errors
+/// This function errors iff a record is not serialized as a map, its values are not supported
+/// (e.g. enum variants with data) or the values of a field can't be inferred to a common
+/// data type (e.g. a string and an integer).
+pub fn infer<T: Serialize>(records: &[T]) -> Result<Vec<Field>> {
+    // field name -> (data type, whether it is nullable)
+    let mut fields = IndexMap::<String, (Inferred, bool)>::new();
+    for (i, record) in records.iter().enumerate() {
+        let record = match record.serialize(InferSerializer)? {
+            Inferred::Struct(record) => record,
+            _ => return Err(not_a_map()),
+        };
+        for (name, inferred) in record.iter() {
+            let entry = fields
+                .entry(name.clone())
+                // a field missing from previous records is nullable
+                .or_insert_with(|| (Inferred::Null, i > 0));
+            if *inferred == Inferred::Null {
+                entry.1 = true;
+            }
+            let current = std::mem::replace(&mut entry.0, Inferred::Null);
+            entry.0 = current.merge(inferred.clone())?;
+        }
+        // a field missing from this record is nullable
+        fields
+            .iter_mut()
+            .filter(|(name, _)| !record.contains_key(name.as_str()))
+            .for_each(|(_, (_, is_nullable))| *is_nullable = true);
+    }
+
+    Ok(fields
+        .into_iter()
+        .map(|(name, (inferred, is_nullable))| match inferred {
+            Inferred::Null => Field::new(name, DataType::Null, true),
+            inferred => Field::new(name, inferred.data_type(), is_nullable),
+        })
+        .collect())
+}
@@ -0,0 +1,105 @@
+//! Convert between Rust types implementing [`serde`](::serde)'s traits and [`Chunk`]s.
+//!
+//! Records are converted from and to arrays via serde's data model, without an intermediate
+//! representation. Their [`Field`]s are inferred from the same data model:
+//! * `bool` as [`DataType::Boolean`]
+//! * integers and floats as the [`DataType`] of the same width, e.g. `i32` as
+//!   [`DataType::Int32`] and `f64` as [`DataType::Float64`]
+//! * strings, chars and unit enum variants as [`DataType::Utf8`]
+//! * bytes as [`DataType::Binary`]
+//! * sequences and tuples as [`DataType::List`]
+//! * structs and maps as [`DataType::Struct`]
+//!
+//! # Example
+//! ```
+//! use serde_derive::{Deserialize, Serialize};
+//! use arrow2::io::serde::{deserialize, infer, serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Record {
+//!     id: i64,
+//!     name: Option<String>,
+//! }
+//!
+//! # fn main() -> arrow2::error::Result<()> {
+//! let records = vec![
+//!     Record { id: 1, name: Some("a".to_string()) },
+//!     Record { id: 2, name: None },
+//! ];
+//!
+//! let fields = infer(&records)?;
+//! let chunk = serialize(&records, &fields)?;
+//! assert_eq!(chunk.len(), 2);
+//!
+//! let result: Vec<Record> = deserialize(&chunk, &fields)?;
+//! assert_eq!(result, records);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Chunk`]: crate::chunk::Chunk
+//! [`Field`]: crate::datatypes::Field
+//! [`DataType`]: crate::datatypes::DataType
+//! [`DataType::Boolean`]: crate::datatypes::DataType::Boolean
+//! [`DataType::Int32`]: crate::datatypes::DataType::Int32
+//! [`DataType::Float64`]: crate::datatypes::DataType::Float64
+//! [`DataType::Utf8`]: crate::datatypes::DataType::Utf8
+//! [`DataType::Binary`]: crate::datatypes::DataType::Binary
+//! [`DataType::List`]: crate::datatypes::DataType::List
+//! [`DataType::Struct`]: crate::datatypes::DataType::Struct
+use std::fmt::Display;
+
+use crate::error::ArrowError;
+
+mod deserialize;
+mod infer;
+mod serialize;
+
+pub use deserialize::deserialize;
+pub use infer::infer;
+pub use serialize::serialize;
+
+/// The error of serde's (de)serializers of this module.
+#[derive(Debug)]
+enum Error {
+    Arrow(ArrowError),
+    Serde(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Arrow(error) => Display::fmt(error, f),
+            Error::Serde(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ::serde::ser::Error for Error {
+    fn custom<T: Display>(message: T) -> Self {
+        Error::Serde(message.to_string())
+    }
+}
+
+impl ::serde::de::Error for Error {
+    fn custom<T: Display>(message: T) -> Self {
+        Error::Serde(message.to_string())
+    }
+}
+
+impl From<ArrowError> for Error {
+    fn from(error: ArrowError) -> Self {
+        Error::Arrow(error)
+    }
+}
+
+impl From<Error> for ArrowError {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::Arrow(error) => error,
+            Error::Serde(message) => ArrowError::ExternalFormat(message),
+        }
+    }
+}
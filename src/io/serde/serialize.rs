@@ -0,0 +1,848 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use ::serde::ser::{
+    Impossible, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple,
+    SerializeTupleStruct, Serializer,
+};
+use num_traits::NumCast;
+
+use crate::array::{
+    Array, ListArray, MutableArray, MutableBinaryArray, MutableBooleanArray, MutablePrimitiveArray,
+    MutableUtf8Array, NullArray, StructArray, TryPush,
+};
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::infer::{not_a_map, KeySerializer};
+use super::Error;
+
+/// A [`MutablePrimitiveArray`] that numbers of any width can be pushed to.
+trait NumberBuilder {
+    fn data_type(&self) -> &DataType;
+    fn push_i64(&mut self, value: i64) -> std::result::Result<(), Error>;
+    fn push_u64(&mut self, value: u64) -> std::result::Result<(), Error>;
+    fn push_f64(&mut self, value: f64) -> std::result::Result<(), Error>;
+    fn push_null(&mut self);
+    fn finish(&mut self) -> Arc<dyn Array>;
+}
+
+fn out_of_range<V: std::fmt::Display>(value: V, data_type: &DataType) -> Error {
+    Error::Arrow(ArrowError::InvalidArgumentError(format!(
+        "The value {} is out of the range of {:?}",
+        value, data_type
+    )))
+}
+
+impl<T: NativeType + NumCast> NumberBuilder for MutablePrimitiveArray<T> {
+    fn data_type(&self) -> &DataType {
+        MutableArray::data_type(self)
+    }
+
+    fn push_i64(&mut self, value: i64) -> std::result::Result<(), Error> {
+        let value =
+            T::from(value).ok_or_else(|| out_of_range(value, MutableArray::data_type(self)))?;
+        self.push(Some(value));
+        Ok(())
+    }
+
+    fn push_u64(&mut self, value: u64) -> std::result::Result<(), Error> {
+        let value =
+            T::from(value).ok_or_else(|| out_of_range(value, MutableArray::data_type(self)))?;
+        self.push(Some(value));
+        Ok(())
+    }
+
+    fn push_f64(&mut self, value: f64) -> std::result::Result<(), Error> {
+        // floats are not silently truncated to integers
+        if !matches!(
+            T::PRIMITIVE,
+            PrimitiveType::Float32 | PrimitiveType::Float64
+        ) {
+            return Err(mismatch("a float", MutableArray::data_type(self)));
+        }
+        let value =
+            T::from(value).ok_or_else(|| out_of_range(value, MutableArray::data_type(self)))?;
+        self.push(Some(value));
+        Ok(())
+    }
+
+    fn push_null(&mut self) {
+        self.push(None)
+    }
+
+    fn finish(&mut self) -> Arc<dyn Array> {
+        self.as_arc()
+    }
+}
+
+fn number<T: NativeType + NumCast>(data_type: &DataType) -> Builder {
+    Builder::Number(Box::new(MutablePrimitiveArray::<T>::with_capacity_from(
+        0,
+        data_type.clone(),
+    )))
+}
+
+struct ListBuilder {
+    data_type: DataType,
+    offsets: Vec<i64>,
+    validity: MutableBitmap,
+    values: Box<Builder>,
+}
+
+struct StructBuilder {
+    data_type: DataType,
+    names: HashMap<String, usize>,
+    children: Vec<Builder>,
+    validity: MutableBitmap,
+}
+
+impl StructBuilder {
+    fn try_new(data_type: DataType) -> Result<Self> {
+        let fields = StructArray::get_fields(&data_type);
+        let names = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| (field.name.clone(), i))
+            .collect();
+        let children = fields
+            .iter()
+            .map(|field| Builder::try_new(field.data_type()))
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            data_type,
+            names,
+            children,
+            validity: MutableBitmap::new(),
+        })
+    }
+}
+
+/// A growable array that values serialized via serde's data model are pushed to.
+enum Builder {
+    Null(DataType, usize),
+    Boolean(MutableBooleanArray),
+    Number(Box<dyn NumberBuilder>),
+    Utf8(MutableUtf8Array<i32>),
+    LargeUtf8(MutableUtf8Array<i64>),
+    Binary(MutableBinaryArray<i32>),
+    LargeBinary(MutableBinaryArray<i64>),
+    List(ListBuilder),
+    Struct(StructBuilder),
+}
+
+impl Builder {
+    fn try_new(data_type: &DataType) -> Result<Self> {
+        use PhysicalType::*;
+        Ok(match data_type.to_physical_type() {
+            Null => Builder::Null(data_type.clone(), 0),
+            Boolean => Builder::Boolean(MutableBooleanArray::from_data(
+                data_type.clone(),
+                MutableBitmap::new(),
+                None,
+            )),
+            Primitive(PrimitiveType::Int8) => number::<i8>(data_type),
+            Primitive(PrimitiveType::Int16) => number::<i16>(data_type),
+            Primitive(PrimitiveType::Int32) => number::<i32>(data_type),
+            Primitive(PrimitiveType::Int64) => number::<i64>(data_type),
+            Primitive(PrimitiveType::UInt8) => number::<u8>(data_type),
+            Primitive(PrimitiveType::UInt16) => number::<u16>(data_type),
+            Primitive(PrimitiveType::UInt32) => number::<u32>(data_type),
+            Primitive(PrimitiveType::UInt64) => number::<u64>(data_type),
+            Primitive(PrimitiveType::Float32) => number::<f32>(data_type),
+            Primitive(PrimitiveType::Float64) => number::<f64>(data_type),
+            Utf8 => Builder::Utf8(MutableUtf8Array::from_data(
+                data_type.clone(),
+                vec![0],
+                vec![],
+                None,
+            )),
+            LargeUtf8 => Builder::LargeUtf8(MutableUtf8Array::from_data(
+                data_type.clone(),
+                vec![0],
+                vec![],
+                None,
+            )),
+            Binary => Builder::Binary(MutableBinaryArray::from_data(
+                data_type.clone(),
+                vec![0],
+                vec![],
+                None,
+            )),
+            LargeBinary => Builder::LargeBinary(MutableBinaryArray::from_data(
+                data_type.clone(),
+                vec![0],
+                vec![],
+                None,
+            )),
+            List | LargeList => Builder::List(ListBuilder {
+                data_type: data_type.clone(),
+                offsets: vec![0],
+                validity: MutableBitmap::new(),
+                values: Box::new(Builder::try_new(ListArray::<i32>::get_child_type(
+                    data_type,
+                ))?),
+            }),
+            Struct => Builder::Struct(StructBuilder::try_new(data_type.clone())?),
+            _ => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Serializing records into arrays of {:?}",
+                    data_type
+                )))
+            }
+        })
+    }
+
+    fn data_type(&self) -> &DataType {
+        match self {
+            Builder::Null(data_type, _) => data_type,
+            Builder::Boolean(array) => array.data_type(),
+            Builder::Number(array) => array.data_type(),
+            Builder::Utf8(array) => array.data_type(),
+            Builder::LargeUtf8(array) => array.data_type(),
+            Builder::Binary(array) => array.data_type(),
+            Builder::LargeBinary(array) => array.data_type(),
+            Builder::List(list) => &list.data_type,
+            Builder::Struct(struct_) => &struct_.data_type,
+        }
+    }
+
+    fn push_null(&mut self) {
+        match self {
+            Builder::Null(_, length) => *length += 1,
+            Builder::Boolean(array) => array.push(None),
+            Builder::Number(array) => array.push_null(),
+            Builder::Utf8(array) => array.push::<&str>(None),
+            Builder::LargeUtf8(array) => array.push::<&str>(None),
+            Builder::Binary(array) => array.push::<&[u8]>(None),
+            Builder::LargeBinary(array) => array.push::<&[u8]>(None),
+            Builder::List(list) => {
+                list.offsets.push(*list.offsets.last().unwrap());
+                list.validity.push(false);
+            }
+            Builder::Struct(struct_) => {
+                struct_
+                    .children
+                    .iter_mut()
+                    .for_each(|child| child.push_null());
+                struct_.validity.push(false);
+            }
+        }
+    }
+
+    fn finish(self) -> Result<Arc<dyn Array>> {
+        Ok(match self {
+            Builder::Null(data_type, length) => Arc::new(NullArray::new(data_type, length)),
+            Builder::Boolean(mut array) => array.as_arc(),
+            Builder::Number(mut array) => array.finish(),
+            Builder::Utf8(mut array) => array.as_arc(),
+            Builder::LargeUtf8(mut array) => array.as_arc(),
+            Builder::Binary(mut array) => array.as_arc(),
+            Builder::LargeBinary(mut array) => array.as_arc(),
+            Builder::List(list) => {
+                let values = list.values.finish()?;
+                let validity = to_validity(list.validity);
+                if let DataType::LargeList(_) = list.data_type.to_logical_type() {
+                    Arc::new(ListArray::<i64>::try_new(
+                        list.data_type,
+                        list.offsets.into(),
+                        values,
+                        validity,
+                    )?)
+                } else {
+                    let offsets = list
+                        .offsets
+                        .into_iter()
+                        .map(|offset| i32::try_from(offset).map_err(|_| ArrowError::Overflow))
+                        .collect::<Result<Vec<_>>>()?;
+                    Arc::new(ListArray::<i32>::try_new(
+                        list.data_type,
+                        offsets.into(),
+                        values,
+                        validity,
+                    )?)
+                }
+            }
+            Builder::Struct(struct_) => {
+                let children = struct_
+                    .children
+                    .into_iter()
+                    .map(|child| child.finish())
+                    .collect::<Result<Vec<_>>>()?;
+                Arc::new(StructArray::try_new(
+                    struct_.data_type,
+                    children,
+                    to_validity(struct_.validity),
+                )?)
+            }
+        })
+    }
+}
+
+fn to_validity(validity: MutableBitmap) -> Option<Bitmap> {
+    if validity.null_count() > 0 {
+        Some(validity.into())
+    } else {
+        None
+    }
+}
+
+fn mismatch(what: &str, data_type: &DataType) -> Error {
+    Error::Arrow(ArrowError::InvalidArgumentError(format!(
+        "Values serialized as {} can't be converted to an array of {:?}",
+        what, data_type
+    )))
+}
+
+fn unsupported() -> Error {
+    Error::Arrow(ArrowError::NotYetImplemented(
+        "Converting enum variants with data to arrow".to_string(),
+    ))
+}
+
+/// A [`Serializer`] that pushes a value to a [`Builder`].
+struct ValueSerializer<'a>(&'a mut Builder);
+
+impl<'a> ValueSerializer<'a> {
+    fn push_i64(self, value: i64) -> std::result::Result<(), Error> {
+        match self.0 {
+            Builder::Number(array) => array.push_i64(value),
+            other => Err(mismatch("an integer", other.data_type())),
+        }
+    }
+
+    fn push_u64(self, value: u64) -> std::result::Result<(), Error> {
+        match self.0 {
+            Builder::Number(array) => array.push_u64(value),
+            other => Err(mismatch("an integer", other.data_type())),
+        }
+    }
+
+    fn push_f64(self, value: f64) -> std::result::Result<(), Error> {
+        match self.0 {
+            Builder::Number(array) => array.push_f64(value),
+            other => Err(mismatch("a float", other.data_type())),
+        }
+    }
+}
+
+impl<'a> Serializer for ValueSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = SeqSerializer<'a>;
+    type SerializeTuple = SeqSerializer<'a>;
+    type SerializeTupleStruct = SeqSerializer<'a>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = StructSerializer<'a>;
+    type SerializeStruct = StructSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn serialize_bool(self, value: bool) -> std::result::Result<(), Error> {
+        match self.0 {
+            Builder::Boolean(array) => {
+                array.push(Some(value));
+                Ok(())
+            }
+            other => Err(mismatch("a boolean", other.data_type())),
+        }
+    }
+
+    fn serialize_i8(self, value: i8) -> std::result::Result<(), Error> {
+        self.push_i64(value as i64)
+    }
+
+    fn serialize_i16(self, value: i16) -> std::result::Result<(), Error> {
+        self.push_i64(value as i64)
+    }
+
+    fn serialize_i32(self, value: i32) -> std::result::Result<(), Error> {
+        self.push_i64(value as i64)
+    }
+
+    fn serialize_i64(self, value: i64) -> std::result::Result<(), Error> {
+        self.push_i64(value)
+    }
+
+    fn serialize_u8(self, value: u8) -> std::result::Result<(), Error> {
+        self.push_u64(value as u64)
+    }
+
+    fn serialize_u16(self, value: u16) -> std::result::Result<(), Error> {
+        self.push_u64(value as u64)
+    }
+
+    fn serialize_u32(self, value: u32) -> std::result::Result<(), Error> {
+        self.push_u64(value as u64)
+    }
+
+    fn serialize_u64(self, value: u64) -> std::result::Result<(), Error> {
+        self.push_u64(value)
+    }
+
+    fn serialize_f32(self, value: f32) -> std::result::Result<(), Error> {
+        self.push_f64(value as f64)
+    }
+
+    fn serialize_f64(self, value: f64) -> std::result::Result<(), Error> {
+        self.push_f64(value)
+    }
+
+    fn serialize_char(self, value: char) -> std::result::Result<(), Error> {
+        self.serialize_str(value.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, value: &str) -> std::result::Result<(), Error> {
+        match self.0 {
+            Builder::Utf8(array) => array.try_push(Some(value))?,
+            Builder::LargeUtf8(array) => array.try_push(Some(value))?,
+            other => return Err(mismatch("a string", other.data_type())),
+        };
+        Ok(())
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> std::result::Result<(), Error> {
+        match self.0 {
+            Builder::Binary(array) => array.try_push(Some(value))?,
+            Builder::LargeBinary(array) => array.try_push(Some(value))?,
+            other => return Err(mismatch("bytes", other.data_type())),
+        };
+        Ok(())
+    }
+
+    fn serialize_none(self) -> std::result::Result<(), Error> {
+        self.0.push_null();
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> std::result::Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> std::result::Result<(), Error> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> std::result::Result<(), Error> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> std::result::Result<(), Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> std::result::Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> std::result::Result<(), Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> std::result::Result<SeqSerializer<'a>, Error> {
+        match self.0 {
+            Builder::List(list) => Ok(SeqSerializer { list, length: 0 }),
+            other => Err(mismatch("a sequence", other.data_type())),
+        }
+    }
+
+    fn serialize_tuple(self, length: usize) -> std::result::Result<SeqSerializer<'a>, Error> {
+        self.serialize_seq(Some(length))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        length: usize,
+    ) -> std::result::Result<SeqSerializer<'a>, Error> {
+        self.serialize_seq(Some(length))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self::SerializeTupleVariant, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> std::result::Result<StructSerializer<'a>, Error> {
+        match self.0 {
+            Builder::Struct(struct_) => Ok(StructSerializer::new(struct_)),
+            other => Err(mismatch("a map", other.data_type())),
+        }
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        length: usize,
+    ) -> std::result::Result<StructSerializer<'a>, Error> {
+        self.serialize_map(Some(length))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self::SerializeStructVariant, Error> {
+        Err(unsupported())
+    }
+}
+
+/// Pushes the items of a sequence to the values of a [`ListBuilder`].
+struct SeqSerializer<'a> {
+    list: &'a mut ListBuilder,
+    length: i64,
+}
+
+impl<'a> SerializeSeq for SeqSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> std::result::Result<(), Error> {
+        value.serialize(ValueSerializer(&mut self.list.values))?;
+        self.length += 1;
+        Ok(())
+    }
+
+    fn end(self) -> std::result::Result<(), Error> {
+        let last = *self.list.offsets.last().unwrap();
+        self.list.offsets.push(last + self.length);
+        self.list.validity.push(true);
+        Ok(())
+    }
+}
+
+impl<'a> SerializeTuple for SeqSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> std::result::Result<(), Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> std::result::Result<(), Error> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl<'a> SerializeTupleStruct for SeqSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> std::result::Result<(), Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> std::result::Result<(), Error> {
+        SerializeSeq::end(self)
+    }
+}
+
+/// Pushes the values of a struct or map to the children of a [`StructBuilder`].
+/// Values whose keys are not fields of the struct are ignored and missing values are null.
+struct StructSerializer<'a> {
+    struct_: &'a mut StructBuilder,
+    seen: Vec<bool>,
+    key: Option<String>,
+}
+
+impl<'a> StructSerializer<'a> {
+    fn new(struct_: &'a mut StructBuilder) -> Self {
+        let seen = vec![false; struct_.children.len()];
+        Self {
+            struct_,
+            seen,
+            key: None,
+        }
+    }
+
+    fn push<T: ?Sized + Serialize>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> std::result::Result<(), Error> {
+        let index = match self.struct_.names.get(key) {
+            Some(index) => *index,
+            None => return Ok(()),
+        };
+        if std::mem::replace(&mut self.seen[index], true) {
+            return Err(Error::Arrow(ArrowError::InvalidArgumentError(format!(
+                "The field \"{}\" is serialized more than once",
+                key
+            ))));
+        }
+        value.serialize(ValueSerializer(&mut self.struct_.children[index]))
+    }
+
+    fn finish(self) -> std::result::Result<(), Error> {
+        self.struct_
+            .children
+            .iter_mut()
+            .zip(self.seen)
+            .filter(|(_, seen)| !seen)
+            .for_each(|(child, _)| child.push_null());
+        self.struct_.validity.push(true);
+        Ok(())
+    }
+}
+
+impl<'a> SerializeMap for StructSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> std::result::Result<(), Error> {
+        self.key = Some(key.serialize(KeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> std::result::Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .expect("serialize_key is called before serialize_value");
+        self.push(&key, value)
+    }
+
+    fn end(self) -> std::result::Result<(), Error> {
+        self.finish()
+    }
+}
+
+impl<'a> SerializeStruct for StructSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> std::result::Result<(), Error> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> std::result::Result<(), Error> {
+        self.finish()
+    }
+}
+
+/// Serializes a sequence of records into a [`Chunk`] whose arrays correspond to `fields`.
+/// Values missing from a record are null and values that are not in `fields` are ignored.
+/// # Errors
+/// This function errors iff a record is not serialized as a map, a [`DataType`] in `fields`
+/// is not supported or a value can't be converted to the [`DataType`] of its field
+/// (e.g. a string to [`DataType::Int32`] or `300` to [`DataType::UInt8`]).
+pub fn serialize<T: Serialize>(records: &[T], fields: &[Field]) -> Result<Chunk<Arc<dyn Array>>> {
+    let mut root = StructBuilder::try_new(DataType::Struct(fields.to_vec()))?;
+    for record in records {
+        record.serialize(RecordSerializer(&mut root))?;
+    }
+    let arrays = root
+        .children
+        .into_iter()
+        .map(|child| child.finish())
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
+
+/// A [`Serializer`] of records, which must be serialized as maps.
+struct RecordSerializer<'a>(&'a mut StructBuilder);
+
+impl<'a> RecordSerializer<'a> {
+    fn error() -> Error {
+        Error::Arrow(not_a_map())
+    }
+}
+
+impl<'a> Serializer for RecordSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Impossible<(), Error>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = StructSerializer<'a>;
+    type SerializeStruct = StructSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn serialize_map(self, _: Option<usize>) -> std::result::Result<StructSerializer<'a>, Error> {
+        Ok(StructSerializer::new(self.0))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<StructSerializer<'a>, Error> {
+        Ok(StructSerializer::new(self.0))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> std::result::Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_bool(self, _: bool) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_i8(self, _: i8) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_i16(self, _: i16) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_i32(self, _: i32) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_i64(self, _: i64) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_u8(self, _: u8) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_u16(self, _: u16) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_u32(self, _: u32) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_u64(self, _: u64) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_f32(self, _: f32) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_f64(self, _: f64) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_char(self, _: char) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_str(self, _: &str) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_none(self) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_unit(self) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> std::result::Result<(), Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> std::result::Result<Self::SerializeSeq, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_tuple(self, _: usize) -> std::result::Result<Self::SerializeTuple, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self::SerializeTupleStruct, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self::SerializeTupleVariant, Error> {
+        Err(Self::error())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self::SerializeStructVariant, Error> {
+        Err(Self::error())
+    }
+}
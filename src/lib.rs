@@ -25,6 +25,8 @@ pub mod temporal_conversions;
 pub mod datatypes;
 
 pub mod ffi;
+#[cfg(feature = "io_ipc")]
+mod mmap;
 pub mod util;
 
 // so that documentation gets test
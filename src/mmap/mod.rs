@@ -0,0 +1,606 @@
+//! Implementation of [`crate::io::ipc::read::mmap`], which lives outside of [`crate::io`]
+//! as it requires `unsafe`.
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use arrow_format::ipc::planus::ReadAsRoot;
+use arrow_format::ipc::{Block, MessageRef};
+
+use crate::array::Array;
+use crate::bitmap::utils::bytes_for;
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, PhysicalType};
+use crate::error::{ArrowError, Result};
+use crate::ffi::mmap::{create_array, export_array};
+use crate::ffi::{import_array_from_c, ArrowArray};
+use crate::io::ipc::{IpcField, CONTINUATION_MARKER};
+use crate::types::Offset;
+
+use crate::io::ipc::read::{
+    first_dict_field, get_serialized_batch, Dictionaries, FileMetadata, IpcBuffer, Layout, Node,
+    Version,
+};
+
+// the offsets of an empty array whose offsets buffer was written without any offset
+static EMPTY_OFFSETS: [i64; 1] = [0];
+
+/// The state shared by the arrays of a single record batch
+struct Body<'a, T: AsRef<[u8]>> {
+    data: &'a Arc<T>,
+    // the range of `data` with the batch's body
+    offset: usize,
+    length: usize,
+    dictionaries: &'a Dictionaries,
+    version: Version,
+}
+
+impl<'a, T: AsRef<[u8]>> Body<'a, T> {
+    fn bytes(&self) -> &'a [u8] {
+        let data: &'a T = self.data.as_ref();
+        &data.as_ref()[self.offset..self.offset + self.length]
+    }
+}
+
+fn to_usize(value: i64, what: &str) -> Result<usize> {
+    usize::try_from(value).map_err(|_| {
+        ArrowError::oos(format!(
+            "IPC: the {} ({}) must be non-negative",
+            what, value
+        ))
+    })
+}
+
+/// Returns the message starting at `block` and the offset of its body in `data`.
+fn read_message<'a>(data: &'a [u8], block: &Block) -> Result<(MessageRef<'a>, usize, usize)> {
+    let offset = to_usize(block.offset, "offset of the block")?;
+    let meta_data_length = to_usize(block.meta_data_length as i64, "length of the metadata")?;
+    let body_length = to_usize(block.body_length, "length of the body")?;
+
+    let meta = data
+        .get(offset..offset + meta_data_length)
+        .ok_or_else(|| ArrowError::oos("IPC: the block's metadata is out of the file's bounds"))?;
+    let mut meta = meta;
+    if meta.get(..4) == Some(&CONTINUATION_MARKER[..]) {
+        meta = &meta[4..];
+    }
+    let length = meta
+        .get(..4)
+        .ok_or_else(|| ArrowError::oos("IPC: the block's metadata is too short"))?;
+    let length = i32::from_le_bytes(length.try_into().unwrap());
+    let length = to_usize(length as i64, "length of the message")?;
+    let message = meta
+        .get(4..4 + length)
+        .ok_or_else(|| ArrowError::oos("IPC: the block's message is out of its bounds"))?;
+
+    let message = MessageRef::read_as_root(message)
+        .map_err(|err| ArrowError::oos(format!("Unable parse message: {:?}", err)))?;
+
+    let body_offset = offset + meta_data_length;
+    if body_offset + body_length > data.len() {
+        return Err(ArrowError::oos(
+            "IPC: the block's body is out of the file's bounds",
+        ));
+    }
+    Ok((message, body_offset, body_length))
+}
+
+fn pop_node<'a>(
+    field_nodes: &mut VecDeque<Node<'a>>,
+    data_type: &DataType,
+) -> Result<(usize, usize)> {
+    let node = field_nodes.pop_front().ok_or_else(|| {
+        ArrowError::oos(format!(
+            "IPC: unable to fetch the field for {:?}. The file or stream is corrupted.",
+            data_type
+        ))
+    })?;
+    Ok((
+        to_usize(node.length(), "length of a field")?,
+        to_usize(node.null_count(), "null count of a field")?,
+    ))
+}
+
+/// Returns a pointer to the next buffer, checking that it is within the body, that it has at
+/// least `min_length` bytes and that it is aligned to `align` bytes.
+fn get_buffer<T: AsRef<[u8]>>(
+    body: &Body<T>,
+    buffers: &mut VecDeque<IpcBuffer>,
+    min_length: usize,
+    align: usize,
+) -> Result<(*const u8, usize)> {
+    let buffer = buffers
+        .pop_front()
+        .ok_or_else(|| ArrowError::oos("IPC: unable to fetch a buffer. The file is corrupted."))?;
+    let offset = to_usize(buffer.offset(), "offset of a buffer")?;
+    let length = to_usize(buffer.length(), "length of a buffer")?;
+
+    let values = body
+        .bytes()
+        .get(offset..offset + length)
+        .ok_or_else(|| ArrowError::oos("IPC: a buffer is out of the body's bounds"))?;
+    if length < min_length {
+        return Err(ArrowError::oos(format!(
+            "IPC: a buffer has {} bytes but at least {} bytes are required",
+            length, min_length
+        )));
+    }
+    let ptr = values.as_ptr();
+    if (ptr as usize) & (align - 1) != 0 {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Memory-mapping a buffer that is not aligned to {} bytes",
+            align
+        )));
+    }
+    Ok((ptr, length))
+}
+
+fn get_validity<T: AsRef<[u8]>>(
+    body: &Body<T>,
+    buffers: &mut VecDeque<IpcBuffer>,
+    length: usize,
+    null_count: usize,
+) -> Result<Option<*const u8>> {
+    if null_count > 0 {
+        get_buffer(body, buffers, bytes_for(length), 1).map(|(ptr, _)| Some(ptr))
+    } else {
+        get_buffer(body, buffers, 0, 1).map(|_| None)
+    }
+}
+
+/// Returns a pointer to the offsets and the last offset.
+fn get_offsets<O: Offset, T: AsRef<[u8]>>(
+    body: &Body<T>,
+    buffers: &mut VecDeque<IpcBuffer>,
+    length: usize,
+) -> Result<(*const u8, usize)> {
+    let size = std::mem::size_of::<O>();
+    let (ptr, bytes) = get_buffer(body, buffers, 0, std::mem::align_of::<O>())?;
+    if length == 0 && bytes == 0 {
+        return Ok((EMPTY_OFFSETS.as_ptr() as *const u8, 0));
+    }
+    if bytes < (length + 1) * size {
+        return Err(ArrowError::oos(format!(
+            "IPC: an offsets buffer has {} bytes but {} offsets are required",
+            bytes,
+            length + 1
+        )));
+    }
+    // Safety: the buffer is aligned to `O` and has at least `length + 1` offsets
+    let last = unsafe { *(ptr as *const O).add(length) };
+    if last < O::default() {
+        return Err(ArrowError::oos("IPC: the last offset must be non-negative"));
+    }
+    Ok((ptr, last.to_usize()))
+}
+
+fn mmap_dictionary<T: AsRef<[u8]>>(body: &Body<T>, ipc_field: &IpcField) -> Result<ArrowArray> {
+    let id = ipc_field
+        .dictionary_id
+        .ok_or_else(|| ArrowError::oos("IPC: a dictionary field must have a dictionary id"))?;
+    let values = body.dictionaries.get(&id).ok_or_else(|| {
+        ArrowError::oos(format!("IPC: the dictionary with id {} was not found", id))
+    })?;
+    Ok(export_array(values.clone()))
+}
+
+fn mmap_array<T: AsRef<[u8]>>(
+    body: &Body<T>,
+    field: &Field,
+    ipc_field: &IpcField,
+    field_nodes: &mut VecDeque<Node>,
+    buffers: &mut VecDeque<IpcBuffer>,
+) -> Result<ArrowArray> {
+    if let Some(layout) = Layout::try_from_field(field)? {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Memory-mapping the layout {:?} of field \"{}\"",
+            layout, field.name
+        )));
+    }
+
+    let data_type = &field.data_type;
+    let data = body.data.clone();
+    let (length, null_count) = pop_node(field_nodes, data_type)?;
+
+    use PhysicalType::*;
+    // Safety: every buffer is within the body and has at least the length that the
+    // C data interface requires for `data_type`
+    let array = match data_type.to_physical_type() {
+        Null => unsafe {
+            create_array(
+                data,
+                length,
+                0,
+                std::iter::empty(),
+                std::iter::empty(),
+                None,
+            )
+        },
+        Boolean => {
+            let validity = get_validity(body, buffers, length, null_count)?;
+            let (values, _) = get_buffer(body, buffers, bytes_for(length), 1)?;
+            unsafe {
+                create_array(
+                    data,
+                    length,
+                    null_count,
+                    [validity, Some(values)].into_iter(),
+                    std::iter::empty(),
+                    None,
+                )
+            }
+        }
+        Primitive(primitive) => {
+            let validity = get_validity(body, buffers, length, null_count)?;
+            let (size, align) = with_match_primitive_type!(primitive, |$T| {
+                (std::mem::size_of::<$T>(), std::mem::align_of::<$T>())
+            });
+            let (values, _) = get_buffer(body, buffers, length * size, align)?;
+            unsafe {
+                create_array(
+                    data,
+                    length,
+                    null_count,
+                    [validity, Some(values)].into_iter(),
+                    std::iter::empty(),
+                    None,
+                )
+            }
+        }
+        Utf8 | Binary => mmap_binary::<i32, T>(body, length, null_count, buffers)?,
+        LargeUtf8 | LargeBinary => mmap_binary::<i64, T>(body, length, null_count, buffers)?,
+        FixedSizeBinary => {
+            let size = match data_type.to_logical_type() {
+                DataType::FixedSizeBinary(size) => *size,
+                _ => unreachable!(),
+            };
+            let validity = get_validity(body, buffers, length, null_count)?;
+            let (values, _) = get_buffer(body, buffers, length * size, 1)?;
+            unsafe {
+                create_array(
+                    data,
+                    length,
+                    null_count,
+                    [validity, Some(values)].into_iter(),
+                    std::iter::empty(),
+                    None,
+                )
+            }
+        }
+        List | Map => mmap_list::<i32, T>(
+            body,
+            field,
+            ipc_field,
+            length,
+            null_count,
+            field_nodes,
+            buffers,
+        )?,
+        LargeList => mmap_list::<i64, T>(
+            body,
+            field,
+            ipc_field,
+            length,
+            null_count,
+            field_nodes,
+            buffers,
+        )?,
+        FixedSizeList => {
+            let child = match data_type.to_logical_type() {
+                DataType::FixedSizeList(child, _) => child.as_ref(),
+                _ => unreachable!(),
+            };
+            let validity = get_validity(body, buffers, length, null_count)?;
+            let values = mmap_array(body, child, &ipc_field.fields[0], field_nodes, buffers)?;
+            unsafe {
+                create_array(
+                    data,
+                    length,
+                    null_count,
+                    [validity].into_iter(),
+                    [values].into_iter(),
+                    None,
+                )
+            }
+        }
+        Struct => {
+            let fields = match data_type.to_logical_type() {
+                DataType::Struct(fields) => fields,
+                _ => unreachable!(),
+            };
+            let validity = get_validity(body, buffers, length, null_count)?;
+            let values = fields
+                .iter()
+                .zip(ipc_field.fields.iter())
+                .map(|(field, ipc_field)| mmap_array(body, field, ipc_field, field_nodes, buffers))
+                .collect::<Result<Vec<_>>>()?;
+            unsafe {
+                create_array(
+                    data,
+                    length,
+                    null_count,
+                    [validity].into_iter(),
+                    values.into_iter(),
+                    None,
+                )
+            }
+        }
+        Union => {
+            let (fields, mode) = match data_type.to_logical_type() {
+                DataType::Union(fields, _, mode) => (fields, mode),
+                _ => unreachable!(),
+            };
+            if body.version != Version::V5 {
+                let _ = get_buffer(body, buffers, 0, 1)?;
+            }
+            let (types, _) = get_buffer(body, buffers, length, 1)?;
+            let offsets = if mode.is_sparse() {
+                None
+            } else {
+                Some(get_buffer(body, buffers, length * 4, 4)?.0)
+            };
+            let values = fields
+                .iter()
+                .zip(ipc_field.fields.iter())
+                .map(|(field, ipc_field)| mmap_array(body, field, ipc_field, field_nodes, buffers))
+                .collect::<Result<Vec<_>>>()?;
+            unsafe {
+                create_array(
+                    data,
+                    length,
+                    0,
+                    std::iter::once(Some(types)).chain(offsets.map(Some)),
+                    values.into_iter(),
+                    None,
+                )
+            }
+        }
+        Dictionary(key_type) => {
+            let validity = get_validity(body, buffers, length, null_count)?;
+            let (size, align) = match_integer_type!(key_type, |$T| {
+                (std::mem::size_of::<$T>(), std::mem::align_of::<$T>())
+            });
+            let (keys, _) = get_buffer(body, buffers, length * size, align)?;
+            let dictionary = mmap_dictionary(body, ipc_field)?;
+            unsafe {
+                create_array(
+                    data,
+                    length,
+                    null_count,
+                    [validity, Some(keys)].into_iter(),
+                    std::iter::empty(),
+                    Some(dictionary),
+                )
+            }
+        }
+    };
+    Ok(array)
+}
+
+fn mmap_binary<O: Offset, T: AsRef<[u8]>>(
+    body: &Body<T>,
+    length: usize,
+    null_count: usize,
+    buffers: &mut VecDeque<IpcBuffer>,
+) -> Result<ArrowArray> {
+    let validity = get_validity(body, buffers, length, null_count)?;
+    let (offsets, last) = get_offsets::<O, T>(body, buffers, length)?;
+    let (values, _) = get_buffer(body, buffers, last, 1)?;
+    // Safety: the values have at least as many bytes as the last offset
+    Ok(unsafe {
+        create_array(
+            body.data.clone(),
+            length,
+            null_count,
+            [validity, Some(offsets), Some(values)].into_iter(),
+            std::iter::empty(),
+            None,
+        )
+    })
+}
+
+fn mmap_list<O: Offset, T: AsRef<[u8]>>(
+    body: &Body<T>,
+    field: &Field,
+    ipc_field: &IpcField,
+    length: usize,
+    null_count: usize,
+    field_nodes: &mut VecDeque<Node>,
+    buffers: &mut VecDeque<IpcBuffer>,
+) -> Result<ArrowArray> {
+    let child = match field.data_type.to_logical_type() {
+        DataType::List(child) | DataType::LargeList(child) | DataType::Map(child, _) => {
+            child.as_ref()
+        }
+        _ => unreachable!(),
+    };
+    let validity = get_validity(body, buffers, length, null_count)?;
+    let (offsets, last) = get_offsets::<O, T>(body, buffers, length)?;
+    let values = mmap_array(body, child, &ipc_field.fields[0], field_nodes, buffers)?;
+    if last > values.len() {
+        return Err(ArrowError::oos(format!(
+            "IPC: the last offset ({}) of field \"{}\" exceeds the length of its values ({})",
+            last,
+            field.name,
+            values.len()
+        )));
+    }
+    Ok(unsafe {
+        create_array(
+            body.data.clone(),
+            length,
+            null_count,
+            [validity, Some(offsets)].into_iter(),
+            [values].into_iter(),
+            None,
+        )
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn mmap_record<T: AsRef<[u8]>>(
+    data: &Arc<T>,
+    fields: &[Field],
+    ipc_fields: &[IpcField],
+    dictionaries: &Dictionaries,
+    message: &MessageRef,
+    batch: arrow_format::ipc::RecordBatchRef,
+    offset: usize,
+    length: usize,
+) -> Result<Vec<Arc<dyn Array>>> {
+    if batch.compression()?.is_some() {
+        return Err(ArrowError::NotYetImplemented(
+            "Memory-mapping compressed IPC files".to_string(),
+        ));
+    }
+
+    let mut buffers: VecDeque<IpcBuffer> = batch
+        .buffers()?
+        .ok_or_else(|| ArrowError::oos("IPC RecordBatch must contain buffers"))?
+        .iter()
+        .collect();
+    let mut field_nodes: VecDeque<Node> = batch
+        .nodes()?
+        .ok_or_else(|| ArrowError::oos("IPC RecordBatch must contain field nodes"))?
+        .iter()
+        .collect();
+
+    let body = Body {
+        data,
+        offset,
+        length,
+        dictionaries,
+        version: message.version()?,
+    };
+
+    fields
+        .iter()
+        .zip(ipc_fields.iter())
+        .map(|(field, ipc_field)| {
+            let array = mmap_array(&body, field, ipc_field, &mut field_nodes, &mut buffers)?;
+            // Safety: `array` is valid for `field`'s data type
+            unsafe { import_array_from_c(Box::new(array), field.data_type.clone()) }
+                .map(|array| array.into())
+        })
+        .collect()
+}
+
+fn check_endianness(metadata: &FileMetadata) -> Result<()> {
+    if metadata.ipc_schema.is_little_endian != cfg!(target_endian = "little") {
+        return Err(ArrowError::NotYetImplemented(
+            "Memory-mapping IPC files whose endianness differs from the platform's".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Reads the dictionaries of an Arrow IPC file whose contents are `data`, whose values point
+/// to `data` instead of being copied from it.
+///
+/// `metadata` is usually read via [`read_file_metadata_only`](crate::io::ipc::read::read_file_metadata_only),
+/// since dictionaries read by [`read_file_metadata`](crate::io::ipc::read::read_file_metadata) are copies.
+/// # Errors
+/// This function errors when:
+/// * the file is compressed, delta dictionaries are used, or its endianness differs from
+///   the platform's
+/// * a buffer is not aligned to its type or is out of the bounds of `data`
+/// # Safety
+/// The contents of the buffers are not validated: e.g. utf8 values may be invalid utf8
+/// and offsets may not be monotonically increasing. `data` must therefore be trusted.
+pub unsafe fn mmap_dictionaries_unchecked<T: AsRef<[u8]>>(
+    metadata: &FileMetadata,
+    data: Arc<T>,
+) -> Result<Dictionaries> {
+    check_endianness(metadata)?;
+    let bytes = data.as_ref().as_ref();
+
+    let mut dictionaries = Dictionaries::new();
+    for block in &metadata.dictionary_blocks {
+        let (message, offset, length) = read_message(bytes, block)?;
+        let batch = match message.header()? {
+            Some(arrow_format::ipc::MessageHeaderRef::DictionaryBatch(batch)) => batch,
+            _ => {
+                return Err(ArrowError::oos(
+                    "IPC: a dictionary block must contain a dictionary batch",
+                ))
+            }
+        };
+        let id = batch.id()?;
+        if batch.is_delta()? {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Memory-mapping the delta of the dictionary with id {}",
+                id
+            )));
+        }
+
+        let (field, ipc_field) =
+            first_dict_field(id, &metadata.schema.fields, &metadata.ipc_schema.fields)?;
+        let values_type = match &field.data_type {
+            DataType::Dictionary(_, values_type, _) => values_type.as_ref().clone(),
+            _ => unreachable!(),
+        };
+        let fields = [Field::new("", values_type, false)];
+        let ipc_fields = [ipc_field.clone()];
+
+        let values = batch
+            .data()?
+            .ok_or_else(|| ArrowError::oos("The dictionary batch must have data."))?;
+        let mut values = mmap_record(
+            &data,
+            &fields,
+            &ipc_fields,
+            &dictionaries,
+            &message,
+            values,
+            offset,
+            length,
+        )?;
+        dictionaries.insert(id, values.pop().unwrap())?;
+    }
+    Ok(dictionaries)
+}
+
+/// Reads the record batch `chunk` of an Arrow IPC file whose contents are `data` (e.g. a
+/// memory-mapped file), returning arrays whose buffers point to `data` instead of being
+/// copied from it.
+///
+/// Every array holds a reference to `data`, which is released when the last array is dropped.
+/// The values of dictionary-encoded arrays are taken from `dictionaries`, e.g. read via
+/// [`mmap_dictionaries_unchecked`].
+/// # Errors
+/// This function errors when:
+/// * `chunk` is out of bounds
+/// * the file is compressed or its endianness differs from the platform's
+/// * a buffer is not aligned to its type or is out of the bounds of `data`
+/// * a field uses a layout that this crate converts when reading (e.g. run-end encoding)
+/// # Safety
+/// The contents of the buffers are not validated: e.g. utf8 values may be invalid utf8
+/// and offsets or dictionary keys may be out of bounds. `data` must therefore be trusted.
+pub unsafe fn mmap_unchecked<T: AsRef<[u8]>>(
+    metadata: &FileMetadata,
+    dictionaries: &Dictionaries,
+    data: Arc<T>,
+    chunk: usize,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    check_endianness(metadata)?;
+    let block = metadata.blocks.get(chunk).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "The file has {} record batches but batch {} was requested",
+            metadata.blocks.len(),
+            chunk
+        ))
+    })?;
+    let (message, offset, length) = read_message(data.as_ref().as_ref(), block)?;
+    let batch = get_serialized_batch(&message)?;
+
+    let columns = mmap_record(
+        &data,
+        &metadata.schema.fields,
+        &metadata.ipc_schema.fields,
+        dictionaries,
+        &message,
+        batch,
+        offset,
+        length,
+    )?;
+    Chunk::try_new(columns)
+}
@@ -181,6 +181,158 @@ pub fn timeunit_scale(a: TimeUnit, b: TimeUnit) -> f64 {
     }
 }
 
+/// The number of `time_unit`s in a second, and the number of decimal digits of a fraction
+/// of a second in `time_unit`s.
+fn units_per_second(time_unit: TimeUnit) -> (i64, usize) {
+    match time_unit {
+        TimeUnit::Second => (1, 0),
+        TimeUnit::Millisecond => (MILLISECONDS, 3),
+        TimeUnit::Microsecond => (MICROSECONDS, 6),
+        TimeUnit::Nanosecond => (NANOSECONDS, 9),
+    }
+}
+
+/// Formats a duration of `value` `time_unit`s as an ISO 8601 duration, e.g. `"PT1H2M3.5S"`.
+///
+/// Durations are written in hours, minutes and (fractional) seconds, since days are not
+/// always 24 hours long. Zero is written as `"PT0S"` and negative durations are prefixed
+/// by `-`, e.g. `"-PT1M"`.
+pub fn duration_to_iso8601(value: i64, time_unit: TimeUnit) -> String {
+    let (per_second, digits) = units_per_second(time_unit);
+    let sign = if value < 0 { "-" } else { "" };
+    let value = value.unsigned_abs();
+    let per_second = per_second as u64;
+
+    let fraction = value % per_second;
+    let seconds = value / per_second;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+
+    let mut result = format!("{}PT", sign);
+    if hours > 0 {
+        result.push_str(&format!("{}H", hours));
+    }
+    if minutes > 0 {
+        result.push_str(&format!("{}M", minutes));
+    }
+    if seconds > 0 || fraction > 0 || (hours == 0 && minutes == 0) {
+        result.push_str(&seconds.to_string());
+        if fraction > 0 {
+            let fraction = format!("{:0width$}", fraction, width = digits);
+            result.push('.');
+            result.push_str(fraction.trim_end_matches('0'));
+        }
+        result.push('S');
+    }
+    result
+}
+
+/// Parses the components of the date (`is_time = false`) or time part of an ISO 8601
+/// duration into nanoseconds.
+fn parse_iso8601_components(mut value: &str, is_time: bool) -> Option<i128> {
+    const NANOSECONDS_IN_SECOND: i128 = NANOSECONDS as i128;
+    // the designators in the order they must appear, and their length in nanoseconds
+    let designators: &[(u8, i128)] = if is_time {
+        &[
+            (b'H', 3_600 * NANOSECONDS_IN_SECOND),
+            (b'M', 60 * NANOSECONDS_IN_SECOND),
+            (b'S', NANOSECONDS_IN_SECOND),
+        ]
+    } else {
+        // years and months are not supported since their length varies
+        &[
+            (b'W', 7 * SECONDS_IN_DAY as i128 * NANOSECONDS_IN_SECOND),
+            (b'D', SECONDS_IN_DAY as i128 * NANOSECONDS_IN_SECOND),
+        ]
+    };
+
+    let mut total = 0i128;
+    let mut next_designator = 0;
+    let mut has_fraction = false;
+    while !value.is_empty() {
+        // a fraction is only allowed on the last component
+        if has_fraction {
+            return None;
+        }
+        let end = value.find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')?;
+        let designator = value.as_bytes()[end];
+        let position = designators[next_designator..]
+            .iter()
+            .position(|(d, _)| *d == designator)?;
+        let nanoseconds = designators[next_designator + position].1;
+        next_designator += position + 1;
+
+        // the designator is ASCII
+        let number = &value[..end];
+        value = &value[end + 1..];
+
+        let (integer, fraction) = match number.find(['.', ',']) {
+            Some(index) => {
+                has_fraction = true;
+                (&number[..index], number[index + 1..].trim_end_matches('0'))
+            }
+            None => (number, ""),
+        };
+        if integer.is_empty() || !integer.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        if !fraction.bytes().all(|c| c.is_ascii_digit()) || fraction.len() > 18 {
+            return None;
+        }
+        let integer = integer.parse::<i128>().ok()?;
+        total = total.checked_add(integer.checked_mul(nanoseconds)?)?;
+        if !fraction.is_empty() {
+            let scale = 10i128.pow(fraction.len() as u32);
+            let fraction = fraction.parse::<i128>().ok()?.checked_mul(nanoseconds)?;
+            if fraction % scale != 0 {
+                return None;
+            }
+            total = total.checked_add(fraction / scale)?;
+        }
+    }
+    Some(total)
+}
+
+/// Parses an ISO 8601 duration, e.g. `"P1DT2H"`, `"PT0.5S"` or `"-PT1M"`, into a number of
+/// `time_unit`s.
+///
+/// Weeks, days (of 24 hours), hours, minutes and seconds are supported, and the last of them
+/// may have a fraction. Returns `None` if `value` is not such a duration, if it uses years or
+/// months (whose length varies), if it is not a whole number of `time_unit`s, or if it
+/// overflows.
+pub fn iso8601_to_duration(value: &str, time_unit: TimeUnit) -> Option<i64> {
+    let (is_negative, value) = match value.as_bytes().first() {
+        Some(b'-') => (true, &value[1..]),
+        Some(b'+') => (false, &value[1..]),
+        _ => (false, value),
+    };
+    let value = value.strip_prefix('P')?;
+    let (date, time) = match value.find('T') {
+        Some(index) => (&value[..index], Some(&value[index + 1..])),
+        None => (value, None),
+    };
+    // at least one component is required, also after `T`
+    if (date.is_empty() && time.is_none()) || time.map(|x| x.is_empty()).unwrap_or(false) {
+        return None;
+    }
+
+    let mut nanoseconds = parse_iso8601_components(date, false)?;
+    if let Some(time) = time {
+        // a fraction is only allowed on the last component
+        if date.contains(['.', ',']) {
+            return None;
+        }
+        nanoseconds = nanoseconds.checked_add(parse_iso8601_components(time, true)?)?;
+    }
+
+    let (per_second, _) = units_per_second(time_unit);
+    let unit_nanoseconds = (NANOSECONDS / per_second) as i128;
+    if nanoseconds % unit_nanoseconds != 0 {
+        return None;
+    }
+    let value = nanoseconds / unit_nanoseconds;
+    i64::try_from(if is_negative { -value } else { value }).ok()
+}
+
 /// Parses an offset of the form `"+WX:YZ"` or `"UTC"` into [`FixedOffset`].
 /// # Errors
 /// If the offset is not in any of the allowed forms.
@@ -0,0 +1,148 @@
+//! Parsing and formatting of decimal numbers represented as [`i128`], as used by
+//! [`DataType::Decimal`](crate::datatypes::DataType::Decimal).
+//!
+//! A decimal with scale `s` whose representation is `v` has the value `v / 10^s`,
+//! e.g. `-1.05` is represented as `-105` with scale 2.
+
+/// How to round decimals with more fractional digits than the scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Decimals whose extra fractional digits are not zero can't be parsed
+    #[default]
+    Reject,
+    /// Discards the extra fractional digits (rounds towards zero)
+    Truncate,
+    /// Rounds to the nearest value, and ties away from zero (e.g. `0.5 -> 1`, `-0.5 -> -1`)
+    HalfUp,
+    /// Rounds to the nearest value, and ties to the nearest even value (e.g. `0.5 -> 0`, `1.5 -> 2`)
+    HalfEven,
+}
+
+/// Parses a decimal number such as `-12.30`, `.5` or `1.2e3` into its representation with
+/// `precision` and `scale`.
+///
+/// Returns `None` if `bytes` is not a decimal number, if it has more fractional digits than
+/// `scale` that can't be rounded according to `rounding` or if it has more digits than `precision`.
+/// # Example
+/// ```
+/// use arrow2::types::decimal::{parse_decimal, RoundingMode};
+///
+/// assert_eq!(parse_decimal(b"-1.05", 5, 2, RoundingMode::Reject), Some(-105));
+/// assert_eq!(parse_decimal(b"1.055", 5, 2, RoundingMode::Reject), None);
+/// assert_eq!(parse_decimal(b"1.055", 5, 2, RoundingMode::HalfUp), Some(106));
+/// assert_eq!(parse_decimal(b"1000", 5, 2, RoundingMode::Reject), None);
+/// ```
+pub fn parse_decimal(
+    bytes: &[u8],
+    precision: usize,
+    scale: usize,
+    rounding: RoundingMode,
+) -> Option<i128> {
+    let (is_negative, bytes) = match bytes.first() {
+        Some(b'-') => (true, &bytes[1..]),
+        Some(b'+') => (false, &bytes[1..]),
+        _ => (false, bytes),
+    };
+
+    let mut parts = bytes.splitn(2, |x| *x == b'e' || *x == b'E');
+    let mantissa = parts.next().unwrap_or_default();
+    let exponent = match parts.next() {
+        Some(exponent) => std::str::from_utf8(exponent).ok()?.parse::<i32>().ok()?,
+        None => 0,
+    };
+
+    let mut parts = mantissa.splitn(2, |x| *x == b'.');
+    let integer = parts.next().unwrap_or_default();
+    let fraction = parts.next().unwrap_or_default();
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    if !integer
+        .iter()
+        .chain(fraction.iter())
+        .all(u8::is_ascii_digit)
+    {
+        return None;
+    }
+
+    // the value is `digits * 10^shift / 10^scale`, where `digits` are all digits of the mantissa
+    let shift = (scale as i64) + (exponent as i64) - (fraction.len() as i64);
+    let length = integer.len() + fraction.len();
+    // the number of digits beyond the scale
+    let dropped = if shift < 0 { (-shift) as usize } else { 0 };
+
+    let mut digits = integer.iter().chain(fraction.iter()).map(|x| x - b'0');
+    let mut value = 0i128;
+    for digit in digits.by_ref().take(length.saturating_sub(dropped)) {
+        value = value.checked_mul(10)?.checked_add(digit as i128)?;
+    }
+    if shift > 0 && value != 0 {
+        value = value.checked_mul(10i128.checked_pow(shift.try_into().ok()?)?)?;
+    }
+
+    if dropped > 0 {
+        // the first dropped digit is zero when there are more dropped digits than digits
+        let first = if dropped > length {
+            0
+        } else {
+            digits.next().unwrap_or(0)
+        };
+        let is_rest_zero = digits.all(|x| x == 0);
+        let round_up = match rounding {
+            RoundingMode::Reject if first != 0 || !is_rest_zero => return None,
+            RoundingMode::Reject | RoundingMode::Truncate => false,
+            RoundingMode::HalfUp => first >= 5,
+            RoundingMode::HalfEven => {
+                first > 5 || (first == 5 && (!is_rest_zero || value % 2 == 1))
+            }
+        };
+        if round_up {
+            value = value.checked_add(1)?;
+        }
+    }
+
+    if value >= 10i128.checked_pow(precision as u32)? {
+        return None;
+    }
+    Some(if is_negative { -value } else { value })
+}
+
+/// Writes the decimal represented by `value` with `scale` to `buffer`, e.g. `-1.05`
+/// for `-105` with scale `2`.
+pub fn write_decimal(value: i128, scale: usize, buffer: &mut Vec<u8>) {
+    if value < 0 {
+        buffer.push(b'-');
+    }
+    // `unsigned_abs` so that `i128::MIN` does not overflow
+    let digits = value.unsigned_abs().to_string();
+    let digits = digits.as_bytes();
+    if scale == 0 {
+        buffer.extend_from_slice(digits);
+    } else if digits.len() > scale {
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        buffer.extend_from_slice(integer);
+        buffer.push(b'.');
+        buffer.extend_from_slice(fraction);
+    } else {
+        buffer.extend_from_slice(b"0.");
+        buffer.resize(buffer.len() + scale - digits.len(), b'0');
+        buffer.extend_from_slice(digits);
+    }
+}
+
+/// Returns the decimal represented by `value` with `scale`, e.g. `"-1.05"` for `-105`
+/// with scale `2`.
+/// # Example
+/// ```
+/// use arrow2::types::decimal::format_decimal;
+///
+/// assert_eq!(format_decimal(-105, 2), "-1.05");
+/// assert_eq!(format_decimal(5, 3), "0.005");
+/// assert_eq!(format_decimal(5, 0), "5");
+/// ```
+pub fn format_decimal(value: i128, scale: usize) -> String {
+    let mut buffer = vec![];
+    write_decimal(value, scale, &mut buffer);
+    // Safety: the buffer only contains ASCII
+    unsafe { String::from_utf8_unchecked(buffer) }
+}
@@ -17,11 +17,14 @@
 //! that can be used to iterate over bitmaps in [`BitChunk`]s according to
 //! Arrow's definition of bitmaps.
 //!
-//! Finally, this module contains traits used to compile code based on [`NativeType`] optimized
+//! This module also contains traits used to compile code based on [`NativeType`] optimized
 //! for SIMD, at [`mod@simd`].
+//!
+//! Finally, this module contains utilities to parse and format decimals, at [`mod@decimal`].
 
 mod bit_chunk;
 pub use bit_chunk::{BitChunk, BitChunkIter, BitChunkOnes};
+pub mod decimal;
 mod index;
 pub mod simd;
 pub use index::*;
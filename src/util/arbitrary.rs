@@ -0,0 +1,583 @@
+//! [`proptest`] strategies generating arbitrary, valid, [`DataType`]s and arrays.
+//!
+//! These can be used to property-test code that consumes arrays, e.g. round trips
+//! through IPC, Parquet, Avro or the C data interface.
+//!
+//! # Example
+//! ```
+//! use proptest::strategy::{Strategy, ValueTree};
+//! use proptest::test_runner::TestRunner;
+//! use arrow2::util::arbitrary::{any_array, ArbitraryOptions};
+//!
+//! let mut runner = TestRunner::default();
+//! let array = any_array(ArbitraryOptions::default())
+//!     .new_tree(&mut runner)
+//!     .unwrap()
+//!     .current();
+//! assert_eq!(array.slice(0, array.len()).len(), array.len());
+//! ```
+use std::sync::Arc;
+
+use proptest::prelude::*;
+
+use crate::array::*;
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, IntegerType, IntervalUnit, Schema, TimeUnit, UnionMode};
+use crate::types::{days_ms, months_days_ns, NativeType, Offset};
+
+/// Options of the strategies of this module
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArbitraryOptions {
+    /// The maximum length of the generated arrays
+    pub max_len: usize,
+    /// The probability, in `[0, 1]`, of each slot of a nullable array being null
+    pub null_density: f64,
+    /// The maximum nesting depth of the generated [`DataType`]s
+    pub max_depth: u32,
+}
+
+impl Default for ArbitraryOptions {
+    fn default() -> Self {
+        Self {
+            max_len: 100,
+            null_density: 0.1,
+            max_depth: 3,
+        }
+    }
+}
+
+/// Native types that can be generated
+trait ArbitraryNative: NativeType {
+    fn strategy() -> BoxedStrategy<Self>;
+}
+
+macro_rules! arbitrary_native {
+    ($($type:ty),*) => {
+        $(
+            impl ArbitraryNative for $type {
+                fn strategy() -> BoxedStrategy<Self> {
+                    any::<$type>().boxed()
+                }
+            }
+        )*
+    };
+}
+
+// floats do not generate `NaN`s, so that generated arrays are equal to themselves
+arbitrary_native!(i8, i16, i32, i64, i128, u8, u16, u32, u64, f32, f64);
+
+impl ArbitraryNative for days_ms {
+    fn strategy() -> BoxedStrategy<Self> {
+        (any::<i32>(), any::<i32>())
+            .prop_map(|(days, ms)| days_ms::new(days, ms))
+            .boxed()
+    }
+}
+
+impl ArbitraryNative for months_days_ns {
+    fn strategy() -> BoxedStrategy<Self> {
+        (any::<i32>(), any::<i32>(), any::<i64>())
+            .prop_map(|(months, days, ns)| months_days_ns::new(months, days, ns))
+            .boxed()
+    }
+}
+
+fn time_unit() -> impl Strategy<Value = TimeUnit> {
+    prop_oneof![
+        Just(TimeUnit::Second),
+        Just(TimeUnit::Millisecond),
+        Just(TimeUnit::Microsecond),
+        Just(TimeUnit::Nanosecond),
+    ]
+}
+
+fn integer_type() -> impl Strategy<Value = IntegerType> {
+    prop_oneof![
+        Just(IntegerType::Int8),
+        Just(IntegerType::Int16),
+        Just(IntegerType::Int32),
+        Just(IntegerType::Int64),
+        Just(IntegerType::UInt8),
+        Just(IntegerType::UInt16),
+        Just(IntegerType::UInt32),
+        Just(IntegerType::UInt64),
+    ]
+}
+
+fn leaf_data_type() -> BoxedStrategy<DataType> {
+    let primitive = prop_oneof![
+        Just(DataType::Int8),
+        Just(DataType::Int16),
+        Just(DataType::Int32),
+        Just(DataType::Int64),
+        Just(DataType::UInt8),
+        Just(DataType::UInt16),
+        Just(DataType::UInt32),
+        Just(DataType::UInt64),
+        Just(DataType::Float32),
+        Just(DataType::Float64),
+    ];
+    let temporal = prop_oneof![
+        (time_unit(), prop::option::of(Just("+01:00".to_string())))
+            .prop_map(|(unit, tz)| DataType::Timestamp(unit, tz)),
+        Just(DataType::Date32),
+        Just(DataType::Date64),
+        Just(DataType::Time32(TimeUnit::Second)),
+        Just(DataType::Time32(TimeUnit::Millisecond)),
+        Just(DataType::Time64(TimeUnit::Microsecond)),
+        Just(DataType::Time64(TimeUnit::Nanosecond)),
+        time_unit().prop_map(DataType::Duration),
+        Just(DataType::Interval(IntervalUnit::YearMonth)),
+        Just(DataType::Interval(IntervalUnit::DayTime)),
+        Just(DataType::Interval(IntervalUnit::MonthDayNano)),
+    ];
+    let binary = prop_oneof![
+        Just(DataType::Binary),
+        Just(DataType::LargeBinary),
+        Just(DataType::Utf8),
+        Just(DataType::LargeUtf8),
+        (1..8usize).prop_map(DataType::FixedSizeBinary),
+    ];
+    let dictionary = (
+        integer_type(),
+        prop_oneof![Just(DataType::Utf8), Just(DataType::Int32)],
+    )
+        .prop_map(|(key, values)| DataType::Dictionary(key, Box::new(values), false));
+    let decimal = (1..=38usize)
+        .prop_flat_map(|precision| (Just(precision), 0..=precision))
+        .prop_map(|(precision, scale)| DataType::Decimal(precision, scale));
+
+    prop_oneof![
+        Just(DataType::Null),
+        Just(DataType::Boolean),
+        primitive,
+        temporal,
+        binary,
+        dictionary,
+        decimal,
+        Just(DataType::Extension(
+            "extension".to_string(),
+            Box::new(DataType::Int32),
+            None
+        )),
+    ]
+    .boxed()
+}
+
+fn fields(inner: BoxedStrategy<DataType>) -> impl Strategy<Value = Vec<Field>> {
+    prop::collection::vec((inner, any::<bool>()), 1..4).prop_map(|fields| {
+        fields
+            .into_iter()
+            .enumerate()
+            .map(|(i, (data_type, is_nullable))| {
+                Field::new(format!("f{}", i), data_type, is_nullable)
+            })
+            .collect()
+    })
+}
+
+/// Returns a strategy of arbitrary [`DataType`]s nested up to `max_depth` levels.
+pub fn data_type(max_depth: u32) -> BoxedStrategy<DataType> {
+    leaf_data_type()
+        .prop_recursive(max_depth, 64, 4, |inner| {
+            let item = (inner.clone(), any::<bool>())
+                .prop_map(|(data_type, is_nullable)| {
+                    Box::new(Field::new("item", data_type, is_nullable))
+                })
+                .boxed();
+            let map = inner.clone().prop_map(|data_type| {
+                let entries = DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8, false),
+                    Field::new("value", data_type, true),
+                ]);
+                DataType::Map(Box::new(Field::new("entries", entries, false)), false)
+            });
+            let union = (
+                fields(inner.clone()),
+                prop_oneof![Just(UnionMode::Sparse), Just(UnionMode::Dense)],
+            )
+                .prop_map(|(fields, mode)| DataType::Union(fields, None, mode));
+
+            prop_oneof![
+                item.clone().prop_map(DataType::List),
+                item.clone().prop_map(DataType::LargeList),
+                (item, 1..4usize).prop_map(|(item, size)| DataType::FixedSizeList(item, size)),
+                fields(inner).prop_map(DataType::Struct),
+                map,
+                union,
+            ]
+        })
+        .boxed()
+}
+
+/// Returns a strategy of an arbitrary [`Field`] whose [`DataType`] is nested up to
+/// `max_depth` levels.
+pub fn field(max_depth: u32) -> BoxedStrategy<Field> {
+    (data_type(max_depth), any::<bool>())
+        .prop_map(|(data_type, is_nullable)| Field::new("a", data_type, is_nullable))
+        .boxed()
+}
+
+fn validity(len: usize, null_density: f64) -> BoxedStrategy<Option<Bitmap>> {
+    prop::collection::vec(prop::bool::weighted(1.0 - null_density), len)
+        .prop_map(|validity| {
+            let validity = MutableBitmap::from_iter(validity);
+            (validity.null_count() > 0).then(|| validity.into())
+        })
+        .boxed()
+}
+
+fn primitive<T: NativeType, S: Strategy<Value = T> + 'static>(
+    data_type: DataType,
+    len: usize,
+    null_density: f64,
+    values: S,
+) -> BoxedStrategy<Box<dyn Array>> {
+    (
+        prop::collection::vec(values, len),
+        validity(len, null_density),
+    )
+        .prop_map(move |(values, validity)| {
+            Box::new(PrimitiveArray::<T>::new(
+                data_type.clone(),
+                values.into(),
+                validity,
+            )) as Box<dyn Array>
+        })
+        .boxed()
+}
+
+fn to_offsets<O: Offset>(lengths: impl Iterator<Item = usize>) -> Vec<O> {
+    let mut offsets = vec![O::zero()];
+    let mut offset = 0;
+    offsets.extend(lengths.map(|length| {
+        offset += length;
+        O::from_usize(offset).unwrap()
+    }));
+    offsets
+}
+
+fn binary<O: Offset>(
+    data_type: DataType,
+    len: usize,
+    null_density: f64,
+    values: BoxedStrategy<Vec<u8>>,
+    is_utf8: bool,
+) -> BoxedStrategy<Box<dyn Array>> {
+    (
+        prop::collection::vec(values, len),
+        validity(len, null_density),
+    )
+        .prop_map(move |(values, validity)| {
+            let offsets = to_offsets::<O>(values.iter().map(|x| x.len())).into();
+            let values = values.concat().into();
+            if is_utf8 {
+                Box::new(Utf8Array::<O>::new(
+                    data_type.clone(),
+                    offsets,
+                    values,
+                    validity,
+                )) as Box<dyn Array>
+            } else {
+                Box::new(BinaryArray::<O>::new(
+                    data_type.clone(),
+                    offsets,
+                    values,
+                    validity,
+                ))
+            }
+        })
+        .boxed()
+}
+
+fn list<O: Offset>(
+    data_type: DataType,
+    child: Field,
+    len: usize,
+    null_density: f64,
+) -> BoxedStrategy<Box<dyn Array>> {
+    prop::collection::vec(0..4usize, len)
+        .prop_flat_map(move |lengths| {
+            let offsets = to_offsets::<O>(lengths.iter().copied());
+            let values = array_of(&child, lengths.iter().sum(), null_density);
+            (Just(offsets), values, validity(len, null_density))
+        })
+        .prop_map(move |(offsets, values, validity)| {
+            let values = Arc::from(values);
+            let array: Box<dyn Array> = match data_type.to_logical_type() {
+                DataType::Map(_, _) => Box::new(MapArray::new(
+                    data_type.clone(),
+                    to_i32(offsets).into(),
+                    values,
+                    validity,
+                )),
+                _ => Box::new(ListArray::<O>::new(
+                    data_type.clone(),
+                    offsets.into(),
+                    values,
+                    validity,
+                )),
+            };
+            array
+        })
+        .boxed()
+}
+
+fn to_i32<O: Offset>(offsets: Vec<O>) -> Vec<i32> {
+    offsets.into_iter().map(|x| x.to_usize() as i32).collect()
+}
+
+fn union(
+    data_type: DataType,
+    fields: Vec<Field>,
+    ids: Option<Vec<i32>>,
+    mode: UnionMode,
+    len: usize,
+    null_density: f64,
+) -> BoxedStrategy<Box<dyn Array>> {
+    prop::collection::vec(0..fields.len(), len)
+        .prop_flat_map(move |indices| {
+            let children = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    let len = match mode {
+                        UnionMode::Sparse => indices.len(),
+                        UnionMode::Dense => indices.iter().filter(|x| **x == i).count(),
+                    };
+                    array_of(field, len, null_density)
+                })
+                .collect::<Vec<_>>();
+            (Just(indices), children)
+        })
+        .prop_map(move |(indices, children)| {
+            let types = indices
+                .iter()
+                .map(|&i| ids.as_ref().map(|ids| ids[i]).unwrap_or(i as i32) as i8)
+                .collect::<Vec<_>>();
+            let offsets = match mode {
+                UnionMode::Sparse => None,
+                UnionMode::Dense => {
+                    let mut counts = vec![0i32; children.len()];
+                    Some(
+                        indices
+                            .iter()
+                            .map(|&i| {
+                                counts[i] += 1;
+                                counts[i] - 1
+                            })
+                            .collect::<Vec<_>>()
+                            .into(),
+                    )
+                }
+            };
+            let children = children.into_iter().map(Arc::from).collect();
+            Box::new(UnionArray::new(
+                data_type.clone(),
+                types.into(),
+                children,
+                offsets,
+            )) as Box<dyn Array>
+        })
+        .boxed()
+}
+
+fn dictionary<K: DictionaryKey>(
+    values: DataType,
+    len: usize,
+    null_density: f64,
+) -> BoxedStrategy<Box<dyn Array>> {
+    // all key types can represent up to 127 values
+    (1..=len.clamp(1, 127))
+        .prop_flat_map(move |values_len| {
+            let keys = prop::collection::vec(0..values_len, len);
+            let values = array(values.clone(), values_len, null_density);
+            (keys, values, validity(len, null_density))
+        })
+        .prop_map(|(keys, values, validity)| {
+            let keys = keys
+                .into_iter()
+                .map(|x| K::from_usize(x).unwrap())
+                .collect::<Vec<_>>();
+            let keys = PrimitiveArray::<K>::new(K::PRIMITIVE.into(), keys.into(), validity);
+            Box::new(DictionaryArray::<K>::from_data(keys, Arc::from(values))) as Box<dyn Array>
+        })
+        .boxed()
+}
+
+fn array_of(field: &Field, len: usize, null_density: f64) -> BoxedStrategy<Box<dyn Array>> {
+    let null_density = if field.is_nullable { null_density } else { 0.0 };
+    array(field.data_type().clone(), len, null_density)
+}
+
+/// Returns a strategy of arbitrary, valid, arrays of a given [`DataType`] and length,
+/// where each slot of each nullable array is null with probability `null_density`.
+/// # Implementation
+/// Children of non-nullable [`Field`]s have no nulls. Floats are never `NaN`, so that
+/// generated arrays are equal to themselves, and temporal values are within the ranges
+/// supported by [`chrono`].
+/// # Panic
+/// Panics iff the `data_type` contains a [`DataType::Dictionary`] whose values are nested
+/// or that is the logical type of a [`DataType::Extension`].
+pub fn array(data_type: DataType, len: usize, null_density: f64) -> BoxedStrategy<Box<dyn Array>> {
+    // up to about 1000 years from the epoch
+    const DAYS: i64 = 365_000;
+    const MS: i64 = DAYS * 86_400_000;
+
+    match data_type.to_logical_type().clone() {
+        DataType::Null => Just(())
+            .prop_map(move |_| Box::new(NullArray::new(data_type.clone(), len)) as Box<dyn Array>)
+            .boxed(),
+        DataType::Boolean => (
+            prop::collection::vec(any::<bool>(), len),
+            validity(len, null_density),
+        )
+            .prop_map(move |(values, validity)| {
+                Box::new(BooleanArray::new(
+                    data_type.clone(),
+                    MutableBitmap::from_iter(values).into(),
+                    validity,
+                )) as Box<dyn Array>
+            })
+            .boxed(),
+        DataType::Decimal(precision, _) => {
+            let max = 10i128.pow(precision as u32) - 1;
+            primitive::<i128, _>(data_type, len, null_density, -max..=max)
+        }
+        DataType::Date32 => {
+            let days = DAYS as i32;
+            primitive::<i32, _>(data_type, len, null_density, -days..days)
+        }
+        DataType::Date64 => primitive::<i64, _>(data_type, len, null_density, -MS..MS),
+        DataType::Timestamp(unit, _) => {
+            let max = match unit {
+                TimeUnit::Second => MS / 1_000,
+                TimeUnit::Millisecond => MS,
+                TimeUnit::Microsecond => MS * 1_000,
+                TimeUnit::Nanosecond => i64::MAX,
+            };
+            primitive::<i64, _>(data_type, len, null_density, -max..max)
+        }
+        DataType::Time32(unit) => {
+            let max = match unit {
+                TimeUnit::Second => 86_400,
+                _ => 86_400_000,
+            };
+            primitive::<i32, _>(data_type, len, null_density, 0..max)
+        }
+        DataType::Time64(unit) => {
+            let max: i64 = match unit {
+                TimeUnit::Microsecond => 86_400_000_000,
+                _ => 86_400_000_000_000,
+            };
+            primitive::<i64, _>(data_type, len, null_density, 0..max)
+        }
+        DataType::Utf8 | DataType::LargeUtf8 => {
+            let values = ".{0,10}".prop_map(String::into_bytes).boxed();
+            if let DataType::Utf8 = data_type.to_logical_type() {
+                binary::<i32>(data_type, len, null_density, values, true)
+            } else {
+                binary::<i64>(data_type, len, null_density, values, true)
+            }
+        }
+        DataType::Binary => {
+            let values = prop::collection::vec(any::<u8>(), 0..10).boxed();
+            binary::<i32>(data_type, len, null_density, values, false)
+        }
+        DataType::LargeBinary => {
+            let values = prop::collection::vec(any::<u8>(), 0..10).boxed();
+            binary::<i64>(data_type, len, null_density, values, false)
+        }
+        DataType::FixedSizeBinary(size) => (
+            prop::collection::vec(any::<u8>(), len * size),
+            validity(len, null_density),
+        )
+            .prop_map(move |(values, validity)| {
+                Box::new(FixedSizeBinaryArray::new(
+                    data_type.clone(),
+                    values.into(),
+                    validity,
+                )) as Box<dyn Array>
+            })
+            .boxed(),
+        DataType::List(child) => list::<i32>(data_type, *child, len, null_density),
+        DataType::LargeList(child) => list::<i64>(data_type, *child, len, null_density),
+        DataType::Map(child, _) => list::<i32>(data_type, *child, len, null_density),
+        DataType::FixedSizeList(child, size) => (
+            array_of(&child, len * size, null_density),
+            validity(len, null_density),
+        )
+            .prop_map(move |(values, validity)| {
+                Box::new(FixedSizeListArray::new(
+                    data_type.clone(),
+                    Arc::from(values),
+                    validity,
+                )) as Box<dyn Array>
+            })
+            .boxed(),
+        DataType::Struct(fields) => (
+            fields
+                .iter()
+                .map(|field| array_of(field, len, null_density))
+                .collect::<Vec<_>>(),
+            validity(len, null_density),
+        )
+            .prop_map(move |(values, validity)| {
+                let values = values.into_iter().map(Arc::from).collect();
+                Box::new(StructArray::new(data_type.clone(), values, validity)) as Box<dyn Array>
+            })
+            .boxed(),
+        DataType::Union(fields, ids, mode) => {
+            union(data_type, fields, ids, mode, len, null_density)
+        }
+        DataType::Dictionary(key, values, _) => {
+            assert!(
+                !matches!(data_type, DataType::Extension(..)),
+                "Dictionaries can't be the logical type of extension types"
+            );
+            match_integer_type!(key, |$T| dictionary::<$T>(*values, len, null_density))
+        }
+        other => match other.to_physical_type() {
+            crate::datatypes::PhysicalType::Primitive(primitive_type) => {
+                with_match_primitive_type!(primitive_type, |$T| {
+                    primitive::<$T, _>(data_type, len, null_density, $T::strategy())
+                })
+            }
+            _ => unreachable!(),
+        },
+    }
+}
+
+/// Returns a strategy of arbitrary, valid, arrays of arbitrary [`DataType`]s.
+pub fn any_array(options: ArbitraryOptions) -> BoxedStrategy<Box<dyn Array>> {
+    (data_type(options.max_depth), 0..=options.max_len)
+        .prop_flat_map(move |(data_type, len)| array(data_type, len, options.null_density))
+        .boxed()
+}
+
+/// Returns a strategy of an arbitrary [`Schema`] and a [`Chunk`] whose arrays are valid
+/// arrays of the schema's fields.
+pub fn chunk(options: ArbitraryOptions) -> BoxedStrategy<(Schema, Chunk<Arc<dyn Array>>)> {
+    (
+        prop::collection::vec(field(options.max_depth), 1..4),
+        0..=options.max_len,
+    )
+        .prop_flat_map(move |(fields, len)| {
+            let fields = fields
+                .into_iter()
+                .enumerate()
+                .map(|(i, field)| Field::new(format!("c{}", i), field.data_type, field.is_nullable))
+                .collect::<Vec<_>>();
+            let arrays = fields
+                .iter()
+                .map(|field| array_of(field, len, options.null_density))
+                .collect::<Vec<_>>();
+            (Just(Schema::from(fields)), arrays)
+        })
+        .prop_map(|(schema, arrays)| {
+            let arrays = arrays.into_iter().map(Arc::from).collect();
+            (schema, Chunk::new(arrays))
+        })
+        .boxed()
+}
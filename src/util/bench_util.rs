@@ -94,3 +94,38 @@ pub fn create_string_array<O: Offset>(
         })
         .collect()
 }
+
+/// Creates an random (but fixed-seeded) [`ListArray`] of `i64` of a given length, maximum number
+/// of items per list and null density. The null density applies to both the lists and their items.
+pub fn create_list_array<O: Offset>(
+    length: usize,
+    max_items: usize,
+    null_density: f32,
+    seed: u64,
+) -> ListArray<O> {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let data = (0..length)
+        .map(|_| {
+            if rng.gen::<f32>() < null_density {
+                None
+            } else {
+                let items = rng.gen_range(0..=max_items);
+                let value = (0..items)
+                    .map(|_| {
+                        if rng.gen::<f32>() < null_density {
+                            None
+                        } else {
+                            Some(rng.gen::<i64>())
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                Some(value)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut array = MutableListArray::<O, MutablePrimitiveArray<i64>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
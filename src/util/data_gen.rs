@@ -0,0 +1,547 @@
+//! Random, schema-driven, generation of [`Chunk`]s.
+//!
+//! This is useful to benchmark writers and readers against data of a given [`Schema`] and to
+//! prototype pipelines before real data is available.
+//!
+//! # Example
+//! ```
+//! use arrow2::datatypes::{DataType, Field, Schema};
+//! use arrow2::util::data_gen::{ChunkGenerator, DataGenOptions, LengthDistribution};
+//!
+//! let schema = Schema::from(vec![
+//!     Field::new("id", DataType::Int64, false),
+//!     Field::new("name", DataType::Utf8, true),
+//! ]);
+//! let options = DataGenOptions {
+//!     num_rows: 2500,
+//!     chunk_length: 1000,
+//!     string_length: LengthDistribution::Uniform(5, 10),
+//!     ..Default::default()
+//! };
+//!
+//! let lengths = ChunkGenerator::new(&schema, options)
+//!     .map(|chunk| chunk.map(|chunk| chunk.len()))
+//!     .collect::<arrow2::error::Result<Vec<_>>>()
+//!     .unwrap();
+//! assert_eq!(lengths, vec![1000, 1000, 500]);
+//! ```
+use std::sync::Arc;
+
+use rand::distributions::Alphanumeric;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::array::*;
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, IntegerType, Schema, TimeUnit, UnionMode};
+use crate::error::{ArrowError, Result};
+use crate::types::{days_ms, months_days_ns, NativeType, Offset};
+
+/// The distribution of the lengths of generated variable-sized values, such as strings or lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthDistribution {
+    /// All values have the same length
+    Fixed(usize),
+    /// Lengths are uniformly distributed between the two (inclusive) bounds
+    Uniform(usize, usize),
+}
+
+impl LengthDistribution {
+    fn sample<R: Rng>(&self, rng: &mut R) -> usize {
+        match *self {
+            Self::Fixed(length) => length,
+            Self::Uniform(min, max) => rng.gen_range(min.min(max)..=max.max(min)),
+        }
+    }
+}
+
+/// Options of the data generated by this module
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DataGenOptions {
+    /// The total number of rows generated by a [`ChunkGenerator`]
+    pub num_rows: usize,
+    /// The maximum number of rows of each [`Chunk`] generated by a [`ChunkGenerator`]
+    pub chunk_length: usize,
+    /// The probability, in `[0, 1]`, of each slot of a nullable field being null
+    pub null_density: f64,
+    /// The distribution of the lengths of strings and binary values
+    pub string_length: LengthDistribution,
+    /// The distribution of the number of items of variable-sized lists and maps
+    pub list_length: LengthDistribution,
+    /// The number of distinct values of each dictionary-encoded array. It is capped to the number
+    /// of values representable by the dictionary's key type.
+    pub dictionary_cardinality: usize,
+    /// The seed of the random number generator, so that generated data is reproducible
+    pub seed: u64,
+}
+
+impl Default for DataGenOptions {
+    fn default() -> Self {
+        Self {
+            num_rows: 1024,
+            chunk_length: 1024,
+            null_density: 0.1,
+            string_length: LengthDistribution::Uniform(0, 16),
+            list_length: LengthDistribution::Uniform(0, 4),
+            dictionary_cardinality: 16,
+            seed: 42,
+        }
+    }
+}
+
+/// An [`Iterator`] of random [`Chunk`]s of a [`Schema`], yielding [`DataGenOptions::num_rows`]
+/// rows in chunks of up to [`DataGenOptions::chunk_length`] rows.
+#[derive(Debug, Clone)]
+pub struct ChunkGenerator {
+    fields: Vec<Field>,
+    options: DataGenOptions,
+    rng: StdRng,
+    remaining: usize,
+}
+
+impl ChunkGenerator {
+    /// Returns a new [`ChunkGenerator`] of the fields of `schema`, seeded by
+    /// [`DataGenOptions::seed`].
+    pub fn new(schema: &Schema, options: DataGenOptions) -> Self {
+        Self {
+            fields: schema.fields.clone(),
+            options,
+            rng: StdRng::seed_from_u64(options.seed),
+            remaining: options.num_rows,
+        }
+    }
+}
+
+impl Iterator for ChunkGenerator {
+    type Item = Result<Chunk<Arc<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let length = self.remaining.min(self.options.chunk_length.max(1));
+        self.remaining -= length;
+        Some(chunk(&self.fields, length, &self.options, &mut self.rng))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunk_length = self.options.chunk_length.max(1);
+        let full = self.remaining / chunk_length;
+        let chunks = if full * chunk_length < self.remaining {
+            full + 1
+        } else {
+            full
+        };
+        (chunks, Some(chunks))
+    }
+}
+
+/// Returns a random [`Chunk`] of `length` rows whose arrays are arrays of `fields`.
+/// # Errors
+/// Errors iff any of the fields' [`DataType`]s is not supported (see [`array`]).
+pub fn chunk<R: Rng>(
+    fields: &[Field],
+    length: usize,
+    options: &DataGenOptions,
+    rng: &mut R,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let arrays = fields
+        .iter()
+        .map(|field| array_of(field, length, options, rng).map(Arc::from))
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
+
+fn array_of<R: Rng>(
+    field: &Field,
+    length: usize,
+    options: &DataGenOptions,
+    rng: &mut R,
+) -> Result<Box<dyn Array>> {
+    let null_density = if field.is_nullable {
+        options.null_density
+    } else {
+        0.0
+    };
+    generate(field.data_type(), length, null_density, options, rng)
+}
+
+/// Returns a random array of `data_type` and `length`, where each slot is null with probability
+/// [`DataGenOptions::null_density`].
+/// # Implementation
+/// Children of non-nullable [`Field`]s have no nulls. Floats are never `NaN` and temporal values
+/// are within about 1000 years of the epoch, so that they can be represented by [`chrono`].
+/// # Errors
+/// Errors iff `data_type` is an extension type whose logical type is a [`DataType::Dictionary`].
+pub fn array<R: Rng>(
+    data_type: &DataType,
+    length: usize,
+    options: &DataGenOptions,
+    rng: &mut R,
+) -> Result<Box<dyn Array>> {
+    generate(data_type, length, options.null_density, options, rng)
+}
+
+/// Native types that can be generated
+trait RandomNative: NativeType {
+    fn random<R: Rng>(rng: &mut R) -> Self;
+}
+
+macro_rules! random_native {
+    ($($type:ty),*) => {
+        $(
+            impl RandomNative for $type {
+                fn random<R: Rng>(rng: &mut R) -> Self {
+                    rng.gen()
+                }
+            }
+        )*
+    };
+}
+
+random_native!(i8, i16, i32, i64, i128, u8, u16, u32, u64, f32, f64);
+
+impl RandomNative for days_ms {
+    fn random<R: Rng>(rng: &mut R) -> Self {
+        days_ms::new(rng.gen(), rng.gen())
+    }
+}
+
+impl RandomNative for months_days_ns {
+    fn random<R: Rng>(rng: &mut R) -> Self {
+        months_days_ns::new(rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+fn validity<R: Rng>(length: usize, null_density: f64, rng: &mut R) -> Option<Bitmap> {
+    if null_density <= 0.0 {
+        return None;
+    }
+    let validity = (0..length)
+        .map(|_| !rng.gen_bool(null_density.min(1.0)))
+        .collect::<MutableBitmap>();
+    (validity.null_count() > 0).then(|| validity.into())
+}
+
+fn primitive<T: NativeType, R: Rng, F: FnMut(&mut R) -> T>(
+    data_type: &DataType,
+    length: usize,
+    null_density: f64,
+    rng: &mut R,
+    mut value: F,
+) -> Box<dyn Array> {
+    let values = (0..length).map(|_| value(rng)).collect::<Vec<_>>();
+    let validity = validity(length, null_density, rng);
+    Box::new(PrimitiveArray::<T>::new(
+        data_type.clone(),
+        values.into(),
+        validity,
+    ))
+}
+
+fn to_offsets<O: Offset>(lengths: impl Iterator<Item = usize>) -> Vec<O> {
+    let mut offsets = vec![O::zero()];
+    let mut offset = 0;
+    offsets.extend(lengths.map(|length| {
+        offset += length;
+        O::from_usize(offset).unwrap()
+    }));
+    offsets
+}
+
+fn binary<O: Offset, R: Rng>(
+    data_type: &DataType,
+    length: usize,
+    null_density: f64,
+    options: &DataGenOptions,
+    rng: &mut R,
+    is_utf8: bool,
+) -> Box<dyn Array> {
+    let lengths = (0..length)
+        .map(|_| options.string_length.sample(rng))
+        .collect::<Vec<_>>();
+    let values = if is_utf8 {
+        (0..lengths.iter().sum())
+            .map(|_| rng.sample(Alphanumeric))
+            .collect::<Vec<_>>()
+    } else {
+        (0..lengths.iter().sum()).map(|_| rng.gen()).collect()
+    };
+    let offsets = to_offsets::<O>(lengths.into_iter()).into();
+    let validity = validity(length, null_density, rng);
+    if is_utf8 {
+        Box::new(Utf8Array::<O>::new(
+            data_type.clone(),
+            offsets,
+            values.into(),
+            validity,
+        ))
+    } else {
+        Box::new(BinaryArray::<O>::new(
+            data_type.clone(),
+            offsets,
+            values.into(),
+            validity,
+        ))
+    }
+}
+
+fn list<O: Offset, R: Rng>(
+    data_type: &DataType,
+    child: &Field,
+    length: usize,
+    null_density: f64,
+    options: &DataGenOptions,
+    rng: &mut R,
+) -> Result<Box<dyn Array>> {
+    let lengths = (0..length)
+        .map(|_| options.list_length.sample(rng))
+        .collect::<Vec<_>>();
+    let values = array_of(child, lengths.iter().sum(), options, rng)?.into();
+    let offsets = to_offsets::<O>(lengths.into_iter());
+    let validity = validity(length, null_density, rng);
+    Ok(match data_type.to_logical_type() {
+        DataType::Map(_, _) => {
+            let offsets = offsets.into_iter().map(|x| x.to_usize() as i32);
+            Box::new(MapArray::new(
+                data_type.clone(),
+                offsets.collect::<Vec<_>>().into(),
+                values,
+                validity,
+            ))
+        }
+        _ => Box::new(ListArray::<O>::new(
+            data_type.clone(),
+            offsets.into(),
+            values,
+            validity,
+        )),
+    })
+}
+
+fn union<R: Rng>(
+    data_type: &DataType,
+    fields: &[Field],
+    ids: Option<&[i32]>,
+    mode: UnionMode,
+    length: usize,
+    options: &DataGenOptions,
+    rng: &mut R,
+) -> Result<Box<dyn Array>> {
+    let indices = (0..length)
+        .map(|_| rng.gen_range(0..fields.len()))
+        .collect::<Vec<_>>();
+    let children = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let length = match mode {
+                UnionMode::Sparse => indices.len(),
+                UnionMode::Dense => indices.iter().filter(|x| **x == i).count(),
+            };
+            array_of(field, length, options, rng).map(Arc::from)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let types = indices
+        .iter()
+        .map(|&i| ids.map(|ids| ids[i]).unwrap_or(i as i32) as i8)
+        .collect::<Vec<_>>();
+    let offsets = match mode {
+        UnionMode::Sparse => None,
+        UnionMode::Dense => {
+            let mut counts = vec![0i32; children.len()];
+            let offsets = indices
+                .iter()
+                .map(|&i| {
+                    counts[i] += 1;
+                    counts[i] - 1
+                })
+                .collect::<Vec<_>>();
+            Some(offsets.into())
+        }
+    };
+    Ok(Box::new(UnionArray::new(
+        data_type.clone(),
+        types.into(),
+        children,
+        offsets,
+    )))
+}
+
+/// The number of distinct values that keys of `key_type` can point to
+fn max_cardinality(key_type: IntegerType) -> usize {
+    match key_type {
+        IntegerType::Int8 => i8::MAX as usize + 1,
+        IntegerType::Int16 => i16::MAX as usize + 1,
+        IntegerType::UInt8 => u8::MAX as usize + 1,
+        IntegerType::UInt16 => u16::MAX as usize + 1,
+        _ => usize::MAX,
+    }
+}
+
+fn dictionary<K: DictionaryKey, R: Rng>(
+    values: &DataType,
+    cardinality: usize,
+    length: usize,
+    null_density: f64,
+    options: &DataGenOptions,
+    rng: &mut R,
+) -> Result<Box<dyn Array>> {
+    let keys = (0..length)
+        .map(|_| K::from_usize(rng.gen_range(0..cardinality)).unwrap())
+        .collect::<Vec<_>>();
+    let validity = validity(length, null_density, rng);
+    let keys = PrimitiveArray::<K>::new(K::PRIMITIVE.into(), keys.into(), validity);
+
+    // the values are the nullable ones, so that their nulls are encoded in the keys
+    let values = generate(values, cardinality, 0.0, options, rng)?;
+    Ok(Box::new(DictionaryArray::<K>::from_data(
+        keys,
+        Arc::from(values),
+    )))
+}
+
+fn generate<R: Rng>(
+    data_type: &DataType,
+    length: usize,
+    null_density: f64,
+    options: &DataGenOptions,
+    rng: &mut R,
+) -> Result<Box<dyn Array>> {
+    // up to about 1000 years from the epoch
+    const DAYS: i64 = 365_000;
+    const MS: i64 = DAYS * 86_400_000;
+
+    Ok(match data_type.to_logical_type() {
+        DataType::Null => Box::new(NullArray::new(data_type.clone(), length)),
+        DataType::Boolean => {
+            let values = (0..length)
+                .map(|_| rng.gen::<bool>())
+                .collect::<MutableBitmap>();
+            let validity = validity(length, null_density, rng);
+            Box::new(BooleanArray::new(
+                data_type.clone(),
+                values.into(),
+                validity,
+            ))
+        }
+        DataType::Decimal(precision, _) => {
+            let max = 10i128.pow(*precision as u32) - 1;
+            primitive::<i128, _, _>(data_type, length, null_density, rng, |rng| {
+                rng.gen_range(-max..=max)
+            })
+        }
+        DataType::Date32 => {
+            let days = DAYS as i32;
+            primitive::<i32, _, _>(data_type, length, null_density, rng, |rng| {
+                rng.gen_range(-days..days)
+            })
+        }
+        DataType::Date64 => primitive::<i64, _, _>(data_type, length, null_density, rng, |rng| {
+            rng.gen_range(-MS..MS)
+        }),
+        DataType::Timestamp(unit, _) => {
+            let max = match unit {
+                TimeUnit::Second => MS / 1_000,
+                TimeUnit::Millisecond => MS,
+                TimeUnit::Microsecond => MS * 1_000,
+                TimeUnit::Nanosecond => i64::MAX,
+            };
+            primitive::<i64, _, _>(data_type, length, null_density, rng, |rng| {
+                rng.gen_range(-max..max)
+            })
+        }
+        DataType::Time32(unit) => {
+            let max = match unit {
+                TimeUnit::Second => 86_400,
+                _ => 86_400_000,
+            };
+            primitive::<i32, _, _>(data_type, length, null_density, rng, |rng| {
+                rng.gen_range(0..max)
+            })
+        }
+        DataType::Time64(unit) => {
+            let max: i64 = match unit {
+                TimeUnit::Microsecond => 86_400_000_000,
+                _ => 86_400_000_000_000,
+            };
+            primitive::<i64, _, _>(data_type, length, null_density, rng, |rng| {
+                rng.gen_range(0..max)
+            })
+        }
+        DataType::Utf8 => binary::<i32, _>(data_type, length, null_density, options, rng, true),
+        DataType::LargeUtf8 => {
+            binary::<i64, _>(data_type, length, null_density, options, rng, true)
+        }
+        DataType::Binary => binary::<i32, _>(data_type, length, null_density, options, rng, false),
+        DataType::LargeBinary => {
+            binary::<i64, _>(data_type, length, null_density, options, rng, false)
+        }
+        DataType::FixedSizeBinary(size) => {
+            let values = (0..length * size).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let validity = validity(length, null_density, rng);
+            Box::new(FixedSizeBinaryArray::new(
+                data_type.clone(),
+                values.into(),
+                validity,
+            ))
+        }
+        DataType::List(child) => {
+            list::<i32, _>(data_type, child, length, null_density, options, rng)?
+        }
+        DataType::LargeList(child) => {
+            list::<i64, _>(data_type, child, length, null_density, options, rng)?
+        }
+        DataType::Map(child, _) => {
+            list::<i32, _>(data_type, child, length, null_density, options, rng)?
+        }
+        DataType::FixedSizeList(child, size) => {
+            let values = array_of(child, length * size, options, rng)?;
+            let validity = validity(length, null_density, rng);
+            Box::new(FixedSizeListArray::new(
+                data_type.clone(),
+                Arc::from(values),
+                validity,
+            ))
+        }
+        DataType::Struct(fields) => {
+            let values = fields
+                .iter()
+                .map(|field| array_of(field, length, options, rng).map(Arc::from))
+                .collect::<Result<Vec<_>>>()?;
+            let validity = validity(length, null_density, rng);
+            Box::new(StructArray::new(data_type.clone(), values, validity))
+        }
+        DataType::Union(fields, ids, mode) => union(
+            data_type,
+            fields,
+            ids.as_deref(),
+            *mode,
+            length,
+            options,
+            rng,
+        )?,
+        DataType::Dictionary(key, values, _) => {
+            if let DataType::Extension(..) = data_type {
+                return Err(ArrowError::NotYetImplemented(
+                    "Generating dictionaries of extension types is not supported".to_string(),
+                ));
+            }
+            let cardinality = options
+                .dictionary_cardinality
+                .clamp(1, max_cardinality(*key));
+            match_integer_type!(key, |$T| {
+                dictionary::<$T, _>(values, cardinality, length, null_density, options, rng)?
+            })
+        }
+        other => match other.to_physical_type() {
+            crate::datatypes::PhysicalType::Primitive(primitive_type) => {
+                with_match_primitive_type!(primitive_type, |$T| {
+                    primitive::<$T, _, _>(data_type, length, null_density, rng, $T::random)
+                })
+            }
+            _ => unreachable!(),
+        },
+    })
+}
@@ -20,3 +20,11 @@ pub use lexical::*;
 #[cfg(feature = "benchmarks")]
 #[cfg_attr(docsrs, doc(cfg(feature = "benchmarks")))]
 pub mod bench_util;
+
+#[cfg(feature = "data_gen")]
+#[cfg_attr(docsrs, doc(cfg(feature = "data_gen")))]
+pub mod data_gen;
+
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+pub mod arbitrary;
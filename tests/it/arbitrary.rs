@@ -0,0 +1,74 @@
+use std::io::Cursor;
+
+use proptest::prelude::*;
+
+use arrow2::array::Array;
+use arrow2::datatypes::DataType;
+use arrow2::io::ipc::read::{read_stream_metadata, StreamReader};
+use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+use arrow2::util::arbitrary::{any_array, chunk, data_type, ArbitraryOptions};
+
+/// Whether `data_type` contains an extension type, whose fields IPC reads with extra metadata
+fn contains_extension(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Extension(..) => true,
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => contains_extension(field.data_type()),
+        DataType::Struct(fields) | DataType::Union(fields, _, _) => {
+            fields.iter().any(|x| contains_extension(x.data_type()))
+        }
+        _ => false,
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well
+    fn array_equals_itself(array in any_array(ArbitraryOptions::default())) {
+        let array: &dyn Array = array.as_ref();
+        prop_assert_eq!(array, array);
+        let len = array.len();
+        prop_assert_eq!(array.slice(len / 2, len - len / 2).len(), len - len / 2);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well
+    fn no_nulls(array in any_array(ArbitraryOptions { null_density: 0.0, ..Default::default() })) {
+        // null arrays only have nulls
+        prop_assume!(array.data_type() != &DataType::Null);
+        prop_assert_eq!(array.null_count(), 0);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well
+    fn data_type_string_round_trip(data_type in data_type(3)) {
+        let result = data_type.to_string().parse::<DataType>().unwrap();
+        prop_assert_eq!(result, data_type);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well
+    fn ipc_round_trip((schema, chunk) in chunk(ArbitraryOptions::default())) {
+        prop_assume!(!schema.fields.iter().any(|x| contains_extension(x.data_type())));
+
+        let mut writer = StreamWriter::new(vec![], WriteOptions { compression: None });
+        writer.start(&schema, None).unwrap();
+        writer.write(&chunk, None).unwrap();
+        writer.finish().unwrap();
+        let data = writer.into_inner();
+
+        let mut reader = Cursor::new(data);
+        let metadata = read_stream_metadata(&mut reader).unwrap();
+        prop_assert_eq!(&metadata.schema, &schema);
+        let result = StreamReader::new(reader, metadata)
+            .next()
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        prop_assert_eq!(result, chunk);
+    }
+}
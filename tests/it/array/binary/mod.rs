@@ -142,3 +142,40 @@ fn debug() {
 
     assert_eq!(format!("{:?}", array), "BinaryArray[[1, 2], [], None]");
 }
+
+#[test]
+fn into_mut() {
+    let array = BinaryArray::<i32>::from([Some(b"a".as_ref()), None, Some(b"bc")]);
+    let mut mutable = array.into_mut().unwrap_right();
+    mutable.push(Some(b"d"));
+    let array: BinaryArray<i32> = mutable.into();
+    assert_eq!(
+        array,
+        BinaryArray::<i32>::from([Some(b"a".as_ref()), None, Some(b"bc"), Some(b"d")])
+    );
+
+    // shared arrays are not converted
+    let other = array.clone();
+    assert!(array.into_mut().is_left());
+    assert!(other.into_mut().is_right());
+}
+
+#[test]
+fn into_mut_sliced() {
+    let array = BinaryArray::<i32>::from_slice([b"a".as_ref(), b"bc", b"def"]);
+    let mut mutable = array.sliced(0, 2).into_mut().unwrap_right();
+    mutable.push(Some(b"g"));
+    let array: BinaryArray<i32> = mutable.into();
+    assert_eq!(
+        array,
+        BinaryArray::<i32>::from_slice([b"a".as_ref(), b"bc", b"g"])
+    );
+}
+
+#[test]
+fn values_iter_unchecked() {
+    let array = BinaryArray::<i32>::from_slice([b"a".as_ref(), b"bc", b"def"]);
+    let iter = unsafe { array.values_iter_unchecked(1, 2) };
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.collect::<Vec<_>>(), vec![b"bc".as_ref(), b"def"]);
+}
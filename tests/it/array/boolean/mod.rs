@@ -88,3 +88,14 @@ fn from_iter() {
     let a: BooleanArray = iter.collect();
     assert_eq!(a.len(), 2);
 }
+
+#[test]
+fn true_indices() {
+    let array = BooleanArray::from([Some(true), None, Some(false), Some(true), None, Some(true)]);
+    let indices = array.true_indices::<u32>().collect::<Vec<_>>();
+    assert_eq!(indices, vec![0, 3, 5]);
+
+    let array = array.slice(1, 4);
+    let indices = array.true_indices::<usize>().collect::<Vec<_>>();
+    assert_eq!(indices, vec![2]);
+}
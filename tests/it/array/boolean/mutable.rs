@@ -97,3 +97,21 @@ fn extend_trusted_len_values() {
     );
     assert_eq!(a.values(), &MutableBitmap::from([false, true, false]));
 }
+
+#[test]
+fn extend_from_slice() {
+    let mut a = MutableBooleanArray::new();
+    a.extend_from_slice(&[true, true, false]);
+    assert_eq!(a.validity(), None);
+    assert_eq!(a.values(), &MutableBitmap::from([true, true, false]));
+
+    let mut a = MutableBooleanArray::new();
+    a.push(None);
+    a.extend_from_slice(&[true; 10]);
+    let mut validity = vec![true; 11];
+    validity[0] = false;
+    assert_eq!(a.validity(), Some(&MutableBitmap::from(&validity)));
+    let mut values = vec![true; 11];
+    values[0] = false;
+    assert_eq!(a.values(), &MutableBitmap::from(&values));
+}
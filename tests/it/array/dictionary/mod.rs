@@ -1 +1,50 @@
 mod mutable;
+
+use std::sync::Arc;
+
+use arrow2::array::*;
+
+#[test]
+fn iter_typed() {
+    let keys = PrimitiveArray::<i32>::from([Some(1), None, Some(0), Some(2)]);
+    let values = Arc::new(Utf8Array::<i32>::from([Some("a"), Some("bb"), None])) as Arc<dyn Array>;
+    let array = DictionaryArray::from_data(keys, values);
+
+    let iter = array.iter_typed::<Utf8Array<i32>>().unwrap();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        vec![Some("bb"), None, Some("a"), None]
+    );
+    assert_eq!(
+        array
+            .iter_typed::<Utf8Array<i32>>()
+            .unwrap()
+            .rev()
+            .collect::<Vec<_>>(),
+        vec![None, Some("a"), None, Some("bb")]
+    );
+}
+
+#[test]
+fn iter_typed_primitive() {
+    let keys = PrimitiveArray::<u8>::from_slice([1, 0, 1]);
+    let values = Arc::new(PrimitiveArray::<i64>::from_slice([10, 20])) as Arc<dyn Array>;
+    let array = DictionaryArray::from_data(keys, values);
+
+    let values = array
+        .iter_typed::<PrimitiveArray<i64>>()
+        .unwrap()
+        .map(|x| x.copied())
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![Some(20), Some(10), Some(20)]);
+}
+
+#[test]
+fn iter_typed_wrong_type() {
+    let keys = PrimitiveArray::<i32>::from_slice([0]);
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a"])) as Arc<dyn Array>;
+    let array = DictionaryArray::from_data(keys, values);
+
+    assert!(array.iter_typed::<Utf8Array<i64>>().is_err());
+}
@@ -82,3 +82,62 @@ fn test_multi() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn merges_values() {
+    let values1 = Utf8Array::<i32>::from_slice(["a", "b", "c"]);
+    let keys1 = PrimitiveArray::from_slice([0, 2, 1]);
+    let array1 = DictionaryArray::<i32>::from_data(keys1, Arc::new(values1));
+
+    let values2 = Utf8Array::<i32>::from([Some("c"), None, Some("d"), Some("a")]);
+    let keys2 = PrimitiveArray::from([Some(3), None, Some(0), Some(2), Some(1)]);
+    let array2 = DictionaryArray::<i32>::from_data(keys2, Arc::new(values2));
+
+    let mut growable = GrowableDictionary::new(&[&array1, &array2], false, 0);
+    growable.extend(0, 1, 2);
+    growable.extend(1, 0, 5);
+    let result: DictionaryArray<i32> = growable.into();
+
+    let expected_values =
+        Utf8Array::<i32>::from([Some("a"), Some("b"), Some("c"), None, Some("d")]);
+    let expected_keys =
+        PrimitiveArray::from([Some(2), Some(1), Some(0), None, Some(2), Some(4), Some(3)]);
+    assert_eq!(result.values().as_ref(), &expected_values as &dyn Array);
+    assert_eq!(result.keys(), &expected_keys);
+}
+
+#[test]
+fn merges_primitive_values() {
+    let values1 = Int64Array::from_slice([10, 20]);
+    let array1 =
+        DictionaryArray::<u8>::from_data(PrimitiveArray::from_slice([1, 0]), Arc::new(values1));
+    let values2 = Int64Array::from_slice([20, 30, 10]).sliced(1, 2);
+    let array2 =
+        DictionaryArray::<u8>::from_data(PrimitiveArray::from_slice([0, 1, 1]), Arc::new(values2));
+
+    let mut growable = GrowableDictionary::new(&[&array1, &array2], false, 0);
+    growable.extend(1, 0, 3);
+    growable.extend(0, 0, 2);
+    let result: DictionaryArray<u8> = growable.into();
+
+    let expected_values = Int64Array::from_slice([10, 20, 30]);
+    assert_eq!(result.values().as_ref(), &expected_values as &dyn Array);
+    assert_eq!(result.keys(), &PrimitiveArray::from_slice([2, 0, 0, 1, 0]));
+}
+
+#[test]
+fn shared_values() {
+    let values: Arc<dyn Array> = Arc::new(Utf8Array::<i32>::from_slice(["a", "a", "b"]));
+    let array1 =
+        DictionaryArray::<i32>::from_data(PrimitiveArray::from_slice([0, 2]), values.clone());
+    let array2 = DictionaryArray::<i32>::from_data(PrimitiveArray::from_slice([1]), values.clone());
+
+    let mut growable = GrowableDictionary::new(&[&array1, &array2], false, 0);
+    growable.extend(0, 0, 2);
+    growable.extend(1, 0, 1);
+    let result: DictionaryArray<i32> = growable.into();
+
+    // the values are kept as is
+    assert!(Arc::ptr_eq(result.values(), &values));
+    assert_eq!(result.keys(), &PrimitiveArray::from_slice([0, 2, 1]));
+}
@@ -70,3 +70,20 @@ fn test_nested_display() {
     let expected = "ListArray[[[1, 2], [3, 4]], [[5, 6, 7], [], [8]], [[9, 10]]]";
     assert_eq!(format!("{:?}", nested), expected);
 }
+
+#[test]
+fn debug_bounded() {
+    let values = Int32Array::from_values(0..30);
+    let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from_slice([0, 25, 30]),
+        Arc::new(values),
+        None,
+    );
+
+    let expected = "ListArray[\
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, ...(5 more), 15, 16, 17, 18, 19, 20, 21, 22, 23, 24], \
+        [25, 26, 27, 28, 29]]";
+    assert_eq!(format!("{:?}", array), expected);
+}
@@ -12,7 +12,11 @@ mod struct_;
 mod union;
 mod utf8;
 
-use arrow2::array::{clone, new_empty_array, new_null_array, Array, PrimitiveArray};
+use std::sync::Arc;
+
+use arrow2::array::{
+    clone, new_empty_array, new_null_array, Array, DictionaryArray, PrimitiveArray,
+};
 use arrow2::bitmap::Bitmap;
 use arrow2::datatypes::{DataType, Field, UnionMode};
 
@@ -98,6 +102,75 @@ fn test_with_validity() {
     assert_eq!(arr_ref, &expected);
 }
 
+#[test]
+fn set_validity() {
+    let mut arr = PrimitiveArray::from_slice([1i32, 2, 3]);
+    arr.set_validity(Some(Bitmap::from([true, false, true])));
+    assert_eq!(arr, PrimitiveArray::from([Some(1i32), None, Some(3)]));
+
+    arr.set_validity(None);
+    assert_eq!(arr, PrimitiveArray::from_slice([1i32, 2, 3]));
+}
+
+#[test]
+fn dictionary_with_validity() {
+    let keys = PrimitiveArray::from_slice([0i32, 1, 0]);
+    let values = Arc::new(PrimitiveArray::from_slice([10i32, 20])) as Arc<dyn Array>;
+    let arr = DictionaryArray::from_data(keys, values.clone());
+
+    let arr = arr.with_validity(Some(Bitmap::from([true, false, true])));
+    assert_eq!(
+        arr.keys(),
+        &PrimitiveArray::from([Some(0i32), None, Some(0)])
+    );
+    assert_eq!(arr.values(), &values);
+}
+
+#[test]
+#[should_panic]
+fn set_validity_invalid_len() {
+    let mut arr = PrimitiveArray::from_slice([1i32, 2, 3]);
+    arr.set_validity(Some(Bitmap::from([true, false])));
+}
+
+#[test]
+fn try_slice() {
+    let datatypes = vec![
+        DataType::Null,
+        DataType::Int32,
+        DataType::Utf8,
+        DataType::Binary,
+        DataType::List(Box::new(Field::new("a", DataType::Binary, true))),
+        DataType::Union(
+            vec![Field::new("a", DataType::Binary, true)],
+            None,
+            UnionMode::Dense,
+        ),
+    ];
+    for data_type in datatypes {
+        let array = new_null_array(data_type, 10);
+        assert_eq!(array.try_slice(2, 8).unwrap().len(), 8);
+        assert!(array.try_slice(2, 9).is_err());
+        assert!(array.try_slice(usize::MAX, 2).is_err());
+    }
+}
+
+#[test]
+fn sliced() {
+    let array = PrimitiveArray::from([Some(1i32), None, Some(3), Some(4)]);
+    let expected = array.slice(1, 2);
+    let result = array.sliced(1, 2);
+    assert_eq!(result, expected);
+    assert_eq!(result, PrimitiveArray::from([None, Some(3)]));
+}
+
+#[test]
+#[should_panic]
+fn sliced_out_of_bounds() {
+    let array = PrimitiveArray::from([Some(1i32), None, Some(3), Some(4)]);
+    let _ = array.sliced(3, 2);
+}
+
 // check that `PartialEq` can be derived
 #[derive(PartialEq)]
 struct A {
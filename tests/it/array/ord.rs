@@ -1,7 +1,9 @@
 use std::cmp::Ordering;
+use std::sync::Arc;
 
 use arrow2::array::ord::build_compare;
 use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field, TimeUnit};
 use arrow2::error::Result;
 
 #[test]
@@ -101,3 +103,90 @@ fn dict_i32() -> Result<()> {
     assert_eq!(Ordering::Greater, (cmp)(2, 3));
     Ok(())
 }
+
+#[test]
+fn decimal() -> Result<()> {
+    let array = Int128Array::from_slice([1, -2]).to(DataType::Decimal(10, 2));
+
+    let cmp = build_compare(&array, &array)?;
+
+    assert_eq!(Ordering::Greater, (cmp)(0, 1));
+    Ok(())
+}
+
+#[test]
+fn timestamp_tz() -> Result<()> {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+    let array = Int64Array::from_slice([1, 2]).to(data_type);
+
+    let cmp = build_compare(&array, &array)?;
+
+    assert_eq!(Ordering::Less, (cmp)(0, 1));
+    Ok(())
+}
+
+#[test]
+fn list() -> Result<()> {
+    let data = vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1)]),
+        Some(vec![Some(1), None]),
+        Some(vec![]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data)?;
+    let array: ListArray<i32> = array.into();
+
+    let cmp = build_compare(&array, &array)?;
+
+    // a prefix is smaller
+    assert_eq!(Ordering::Greater, (cmp)(0, 1));
+    // nulls are smaller than values
+    assert_eq!(Ordering::Greater, (cmp)(0, 2));
+    assert_eq!(Ordering::Less, (cmp)(3, 1));
+    assert_eq!(Ordering::Equal, (cmp)(2, 2));
+    Ok(())
+}
+
+#[test]
+fn struct_() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let array = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![
+            Arc::new(Int32Array::from_slice([1, 1, 0])),
+            Arc::new(Utf8Array::<i32>::from([Some("b"), Some("a"), None])),
+        ],
+        None,
+    );
+
+    let cmp = build_compare(&array, &array)?;
+
+    assert_eq!(Ordering::Greater, (cmp)(0, 1));
+    assert_eq!(Ordering::Less, (cmp)(2, 1));
+    assert_eq!(Ordering::Equal, (cmp)(1, 1));
+
+    // slices are compared at their own offsets
+    let sliced = array.slice(1, 2);
+    let cmp = build_compare(&array, &sliced)?;
+    assert_eq!(Ordering::Equal, (cmp)(1, 0));
+    Ok(())
+}
+
+#[test]
+fn fixed_size_list() -> Result<()> {
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int32, true)), 2);
+    let array = FixedSizeListArray::from_data(
+        data_type,
+        Arc::new(Int32Array::from_slice([1, 2, 1, 3])),
+        None,
+    );
+
+    let cmp = build_compare(&array, &array)?;
+
+    assert_eq!(Ordering::Less, (cmp)(0, 1));
+    Ok(())
+}
@@ -10,6 +10,21 @@ fn debug_int32() {
     assert_eq!(format!("{:?}", array), "Int32[1, None, 2]");
 }
 
+#[test]
+fn debug_bounded() {
+    // up to 20 values are written
+    let array = Int32Array::from_values(0..20);
+    let expected = format!("Int32{:?}", (0..20).collect::<Vec<_>>());
+    assert_eq!(format!("{:?}", array), expected);
+
+    let array = Int32Array::from_iter((0..1000).map(|x| if x % 3 == 0 { None } else { Some(x) }));
+    assert_eq!(
+        format!("{:?}", array),
+        "Int32[None, 1, 2, None, 4, 5, None, 7, 8, None, ...(980 more), \
+        None, 991, 992, None, 994, 995, None, 997, 998, None]"
+    );
+}
+
 #[test]
 fn debug_date32() {
     let array = Int32Array::from(&[Some(1), None, Some(2)]).to(DataType::Date32);
@@ -200,6 +215,12 @@ fn debug_decimal1() {
     );
 }
 
+#[test]
+fn debug_decimal_small() {
+    let array = Int128Array::from(&[Some(105), None, Some(-5)]).to(DataType::Decimal(5, 2));
+    assert_eq!(format!("{:?}", array), "Decimal(5, 2)[1.05, None, -0.05]");
+}
+
 #[test]
 fn debug_interval_days_ms() {
     let array = DaysMsArray::from(&[Some(days_ms::new(1, 1)), None, Some(days_ms::new(2, 2))]);
@@ -71,6 +71,19 @@ fn from() {
     assert_eq!(array.len(), 3);
 }
 
+#[test]
+fn try_from_trusted_len_iter() {
+    let iter = vec![Some(1), None, Some(3)]
+        .into_iter()
+        .map(Result::<_, ()>::Ok);
+    let a = PrimitiveArray::<i32>::try_from_trusted_len_iter(iter).unwrap();
+    assert_eq!(a, Int32Array::from([Some(1), None, Some(3)]));
+
+    let iter = vec![Ok(Some(1)), Err("error"), Ok(None)].into_iter();
+    let result = PrimitiveArray::<i32>::try_from_trusted_len_iter(iter);
+    assert_eq!(result, Err("error"));
+}
+
 #[test]
 fn months_days_ns() {
     let data = &[
@@ -124,3 +137,24 @@ fn into_mut_3() {
     let array = PrimitiveArray::new(DataType::Int32, values, validity);
     assert!(array.into_mut().is_right());
 }
+
+#[test]
+fn into_inner() {
+    let validity = Bitmap::from([true, false, true]);
+    let array = PrimitiveArray::<i64>::from_vec(vec![1, 2, 3])
+        .with_validity(Some(validity.clone()))
+        .to(DataType::Date64);
+    let (data_type, values, new_validity) = array.into_inner();
+    assert_eq!(data_type, DataType::Date64);
+    assert_eq!(new_validity, Some(validity));
+    assert_eq!(values.into_vec(), Ok(vec![1, 2, 3]));
+}
+
+#[test]
+fn into_inner_sliced() {
+    let array = PrimitiveArray::<i32>::from_vec(vec![1, 2, 3]).slice(1, 2);
+    let (_, values, _) = array.into_inner();
+    // the values are offsetted and can't be returned as a `Vec`
+    let values = values.into_vec().unwrap_err();
+    assert_eq!(values.as_slice(), &[2, 3]);
+}
@@ -252,3 +252,27 @@ fn wrong_data_type() {
     let values = vec![1u8];
     MutablePrimitiveArray::from_data(DataType::Utf8, values, None);
 }
+
+#[test]
+fn extend_from_slice_validity() {
+    let mut a = MutablePrimitiveArray::<i32>::new();
+    a.push(Some(1));
+    a.extend_from_slice_validity(&[2, 3], Some(&Bitmap::from([false, true])));
+    a.extend_from_slice_validity(&[4], None);
+    assert_eq!(
+        a.validity(),
+        Some(&MutableBitmap::from([true, false, true, true]))
+    );
+    assert_eq!(a.values(), &Vec::<i32>::from([1, 2, 3, 4]));
+
+    let mut a = MutablePrimitiveArray::<i32>::new();
+    a.extend_from_slice_validity(&[1, 2], Some(&Bitmap::from([true, true])));
+    assert_eq!(a.validity(), None);
+}
+
+#[test]
+#[should_panic]
+fn extend_from_slice_validity_wrong_len() {
+    let mut a = MutablePrimitiveArray::<i32>::new();
+    a.extend_from_slice_validity(&[1, 2], Some(&Bitmap::from([true])));
+}
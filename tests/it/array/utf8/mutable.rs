@@ -1,4 +1,4 @@
-use arrow2::array::{MutableUtf8Array, Utf8Array};
+use arrow2::array::{MutableArray, MutableUtf8Array, Utf8Array};
 use arrow2::bitmap::Bitmap;
 use arrow2::datatypes::DataType;
 
@@ -95,3 +95,32 @@ fn test_extend_values() {
     assert_eq!(array.offsets().as_slice(), &[0, 2, 7, 12, 17]);
     assert_eq!(array.validity(), None,);
 }
+
+#[test]
+fn extend_constant() {
+    let mut a = MutableUtf8Array::<i32>::new();
+    a.push(Some("a"));
+    a.extend_constant(2, Some("bc"));
+    assert_eq!(a.validity(), None);
+    a.extend_constant(2, None::<&str>);
+
+    let a: Utf8Array<i32> = a.into();
+    assert_eq!(
+        a,
+        Utf8Array::<i32>::from([Some("a"), Some("bc"), Some("bc"), None, None])
+    );
+}
+
+#[test]
+fn extend_from_slice_validity() {
+    let mut a = MutableUtf8Array::<i32>::new();
+    a.push(Some("a"));
+    a.extend_from_slice_validity(&["b", "c"], Some(&Bitmap::from([false, true])));
+    a.extend_from_slice_validity(&["d"], None);
+
+    let a: Utf8Array<i32> = a.into();
+    assert_eq!(
+        a,
+        Utf8Array::<i32>::from([Some("a"), None, Some("c"), Some("d")])
+    );
+}
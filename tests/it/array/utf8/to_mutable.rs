@@ -12,3 +12,19 @@ fn array_to_mutable() {
     // the ref count is 2 we should not get a mutable.
     assert!(maybe_mut.is_left())
 }
+
+#[test]
+fn sliced_to_mutable() {
+    let array = Utf8Array::<i32>::from_slice(["a", "bc", "def"]);
+    let mut mutable = array.sliced(0, 2).into_mut().unwrap_right();
+    mutable.push(Some("g"));
+    let array: Utf8Array<i32> = mutable.into();
+    assert_eq!(array, Utf8Array::<i32>::from_slice(["a", "bc", "g"]));
+}
+
+#[test]
+fn values_iter_unchecked() {
+    let array = Utf8Array::<i32>::from_slice(["a", "bc", "def"]);
+    let iter = unsafe { array.values_iter_unchecked(1, 2) };
+    assert_eq!(iter.rev().collect::<Vec<_>>(), vec!["def", "bc"]);
+}
@@ -341,3 +341,33 @@ fn shrink_to_fit() {
     a.shrink_to_fit();
     assert!(a.capacity() < 1025);
 }
+
+#[test]
+fn extend_from_bool_slice() {
+    let values = (0..150)
+        .map(|x| x % 3 == 0 || x % 7 == 0)
+        .collect::<Vec<_>>();
+    for offset in 0..9 {
+        let mut bitmap = MutableBitmap::from_len_set(offset);
+        bitmap.extend_from_bool_slice(&values);
+        bitmap.push(true);
+
+        let mut expected = MutableBitmap::from_len_set(offset);
+        expected.extend_from_trusted_len_iter(values.iter().copied());
+        expected.push(true);
+        assert_eq!(bitmap, expected);
+        let length = offset + values.len() + 1;
+        assert_eq!(
+            bitmap.as_slice().len(),
+            length / 8 + (length % 8 != 0) as usize
+        );
+    }
+}
+
+#[test]
+fn extend_from_bool_slice_empty() {
+    let mut bitmap = MutableBitmap::new();
+    bitmap.extend_from_bool_slice(&[]);
+    bitmap.push(true);
+    assert_eq!(bitmap.as_slice(), &[0b00000001]);
+}
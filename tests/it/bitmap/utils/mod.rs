@@ -8,6 +8,7 @@ mod bit_chunks_exact;
 mod chunk_iter;
 mod iterator;
 mod slice_iterator;
+mod true_indices;
 mod zip_validity;
 
 #[test]
@@ -72,7 +73,45 @@ fn count_zeros_1() {
     assert_eq!(count_zeros(input, 10, 90), 60);
 }
 
+#[test]
+fn extend_aligned_basics() {
+    let input: &[u8] = &[0b11111111, 0b10101010, 0b00001111];
+
+    let mut buffer = vec![1];
+    extend_aligned(&mut buffer, input, 8, 8);
+    assert_eq!(buffer, vec![1, 0b10101010]);
+
+    let mut buffer = vec![];
+    extend_aligned(&mut buffer, input, 4, 12);
+    assert_eq!(buffer, vec![0b10101111, 0b00001010]);
+
+    let mut buffer = vec![];
+    extend_aligned(&mut buffer, input, 1, 3);
+    assert_eq!(buffer, vec![0b00000111]);
+
+    let mut buffer = vec![];
+    extend_aligned(&mut buffer, input, 5, 0);
+    assert!(buffer.is_empty());
+}
+
 proptest! {
+    /// Asserts that `extend_aligned` writes the same bytes as collecting the bits of a bitmap
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn extend_aligned_equals_iter(bitmap in bitmap_strategy()) {
+        let (slice, offset, length) = bitmap.as_slice();
+        let mut buffer = vec![];
+        extend_aligned(&mut buffer, slice, offset, length);
+
+        let expected = bitmap.iter().collect::<arrow2::bitmap::Bitmap>();
+        let (expected, _, _) = expected.as_slice();
+        let mut expected = expected.to_vec();
+        if length % 8 != 0 {
+            *expected.last_mut().unwrap() &= (1 << (length % 8)) - 1;
+        }
+        assert_eq!(buffer, expected);
+    }
+
     /// Asserts that `Bitmap::null_count` equals the number of unset bits
     #[test]
     #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
@@ -0,0 +1,84 @@
+use proptest::prelude::*;
+
+use arrow2::bitmap::utils::TrueIndicesIter;
+use arrow2::bitmap::Bitmap;
+
+use crate::bitmap::bitmap_strategy;
+
+proptest! {
+    /// Asserts that the indices are the positions of the set bits, in order, and that
+    /// the iterator reports its exact length.
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn check_invariants(bitmap in bitmap_strategy()) {
+        let iter = bitmap.set_bits_indices::<usize>();
+        assert_eq!(iter.size_hint(), (bitmap.len() - bitmap.null_count(), Some(bitmap.len() - bitmap.null_count())));
+
+        let expected = bitmap
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| *x)
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        assert_eq!(iter.collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn check_validity(bitmap in bitmap_strategy()) {
+        // the validity is the bitmap shifted by one
+        let validity = (0..bitmap.len()).map(|i| i == 0 || bitmap.get_bit(i - 1)).collect::<Bitmap>();
+
+        let iter = TrueIndicesIter::<u32>::new(&bitmap, Some(&validity));
+        let expected = bitmap
+            .iter()
+            .zip(validity.iter())
+            .enumerate()
+            .filter(|(_, (x, y))| *x && *y)
+            .map(|(i, _)| i as u32)
+            .collect::<Vec<_>>();
+        assert_eq!(iter.size_hint().0, expected.len());
+        assert_eq!(iter.collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn basics() {
+    let values = (0..130).map(|i| i % 62 == 0).collect::<Bitmap>();
+    let indices = values.set_bits_indices::<usize>().collect::<Vec<_>>();
+    assert_eq!(indices, vec![0, 62, 124]);
+}
+
+#[test]
+fn sliced() {
+    let values = (0..200).map(|i| i % 3 == 0).collect::<Bitmap>();
+    let values = values.slice(7, 70);
+    let indices = values.set_bits_indices::<u32>().collect::<Vec<_>>();
+    let expected = (0..70).filter(|i| (i + 7) % 3 == 0).collect::<Vec<_>>();
+    assert_eq!(indices, expected);
+}
+
+#[test]
+fn unset_bits_past_length() {
+    // the bits of the last byte past the length are set
+    let values = Bitmap::from_u8_vec(vec![0b11111111, 0b11111101], 10);
+    let indices = values.set_bits_indices::<usize>().collect::<Vec<_>>();
+    assert_eq!(indices, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn empty() {
+    let values = Bitmap::new();
+    assert_eq!(values.set_bits_indices::<usize>().next(), None);
+
+    let values = Bitmap::new_zeroed(100);
+    assert_eq!(values.set_bits_indices::<usize>().next(), None);
+}
+
+#[test]
+#[should_panic]
+fn different_lengths() {
+    let values = Bitmap::new_zeroed(10);
+    let validity = Bitmap::new_zeroed(9);
+    TrueIndicesIter::<usize>::new(&values, Some(&validity));
+}
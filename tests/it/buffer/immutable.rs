@@ -74,3 +74,17 @@ fn from_vec() {
     assert_eq!(buffer.len(), 3);
     assert_eq!(buffer.as_slice(), &[0, 1, 2]);
 }
+
+#[test]
+fn into_vec() {
+    let buffer = Buffer::<i32>::from(vec![0, 1, 2]);
+    assert_eq!(buffer.into_vec(), Ok(vec![0, 1, 2]));
+
+    // the first items of a buffer are not offsetted
+    let buffer = Buffer::<i32>::from(vec![0, 1, 2]).slice(0, 2);
+    assert_eq!(buffer.into_vec(), Ok(vec![0, 1]));
+
+    let buffer = Buffer::<i32>::from(vec![0, 1, 2]);
+    let shared = buffer.clone();
+    assert_eq!(buffer.into_vec(), Err(shared));
+}
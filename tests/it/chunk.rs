@@ -0,0 +1,120 @@
+use arrow2::array::{Array, BooleanArray, PrimitiveArray};
+use arrow2::chunk::{rechunk, Chunk};
+use arrow2::error::{ArrowError, Result};
+
+fn chunk() -> Chunk<Box<dyn Array>> {
+    Chunk::new(vec![
+        Box::new(PrimitiveArray::from_slice([1i32, 2, 3, 4])) as Box<dyn Array>,
+        Box::new(BooleanArray::from([Some(true), None, Some(false), None])),
+    ])
+}
+
+#[test]
+fn try_new() {
+    let result = Chunk::try_new(vec![
+        Box::new(PrimitiveArray::from_slice([1i32, 2])) as Box<dyn Array>,
+        Box::new(BooleanArray::from([Some(true)])),
+    ]);
+    assert!(matches!(
+        result,
+        Err(ArrowError::LengthMismatch {
+            expected: 2,
+            found: 1
+        })
+    ));
+}
+
+#[test]
+fn slice() {
+    let chunk = chunk();
+    let sliced = chunk.slice(1, 2);
+    assert_eq!(sliced.len(), 2);
+    assert_eq!(
+        sliced.arrays()[0].as_ref(),
+        &PrimitiveArray::from_slice([2i32, 3]) as &dyn Array
+    );
+    assert_eq!(
+        sliced.arrays()[1].as_ref(),
+        &BooleanArray::from([None, Some(false)]) as &dyn Array
+    );
+    assert_eq!(chunk.sliced(1, 2), sliced);
+}
+
+#[test]
+fn try_slice() {
+    let chunk = chunk();
+    assert!(chunk.try_slice(0, 4).is_ok());
+    assert!(chunk.try_slice(3, 2).is_err());
+}
+
+#[test]
+fn split() {
+    let chunk = chunk();
+    let chunks = chunk.split(3);
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0], chunk.slice(0, 3));
+    assert_eq!(chunks[1], chunk.slice(3, 1));
+
+    assert_eq!(chunk.split(4), vec![chunk.slice(0, 4)]);
+    assert!(chunk.slice(0, 0).split(4).is_empty());
+}
+
+#[test]
+fn rechunk_merges_and_splits() -> Result<()> {
+    let chunk = chunk();
+    let chunks = vec![
+        chunk.slice(0, 1),
+        chunk.slice(1, 1),
+        chunk.slice(0, 4),
+        chunk.slice(0, 3),
+    ];
+
+    let result = rechunk(&chunks, 3)?;
+    assert_eq!(
+        result.iter().map(|x| x.len()).collect::<Vec<_>>(),
+        vec![3, 3, 3]
+    );
+    // the first chunk merges the two single-row chunks with the first row of `chunk`
+    assert_eq!(
+        result[0].arrays()[0].as_ref(),
+        &PrimitiveArray::from_slice([1i32, 2, 1]) as &dyn Array
+    );
+    assert_eq!(
+        result[0].arrays()[1].as_ref(),
+        &BooleanArray::from([Some(true), None, Some(true)]) as &dyn Array
+    );
+    assert_eq!(result[1], chunk.slice(1, 3));
+    assert_eq!(result[2], chunk.slice(0, 3));
+
+    let result = rechunk(&chunks, 100)?;
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].len(), 9);
+    Ok(())
+}
+
+#[test]
+fn rechunk_errors() {
+    let chunk = chunk();
+    assert!(rechunk(&[chunk.slice(0, 4)], 0).is_err());
+
+    let other = Chunk::new(vec![
+        Box::new(PrimitiveArray::from_slice([1i64])) as Box<dyn Array>,
+        Box::new(BooleanArray::from([Some(true)])),
+    ]);
+    assert!(rechunk(&[chunk, other], 2).is_err());
+}
+
+#[test]
+fn debug() {
+    let chunk = chunk();
+    assert_eq!(
+        format!("{:?}", chunk),
+        "Chunk { len: 4, arrays: [Int32[1, 2, 3, 4], BooleanArray[true, None, false, None]] }"
+    );
+
+    let array = PrimitiveArray::from_values(0..100i64);
+    let chunk = Chunk::new(vec![Box::new(array) as Box<dyn Array>]);
+    let debug = format!("{:?}", chunk);
+    assert!(debug.starts_with("Chunk { len: 100, arrays: [Int64[0, 1, "));
+    assert!(debug.contains("...(80 more), 90, "));
+}
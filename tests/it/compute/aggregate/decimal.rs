@@ -0,0 +1,130 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::*;
+use arrow2::datatypes::DataType;
+use arrow2::error::ArrowError;
+use arrow2::scalar::PrimitiveScalar;
+
+fn decimal(values: &[Option<i128>], precision: usize, scale: usize) -> PrimitiveArray<i128> {
+    PrimitiveArray::from(values).to(DataType::Decimal(precision, scale))
+}
+
+#[test]
+fn sum_with_nulls() {
+    let array = decimal(&[Some(150), None, Some(-25)], 5, 2);
+    let result = sum_decimal(&array, DecimalOverflow::Error).unwrap();
+    assert_eq!(
+        result,
+        PrimitiveScalar::new(DataType::Decimal(5, 2), Some(125))
+    );
+
+    // `sum` keeps the type of the array when the sum fits in it
+    let result = sum(&array).unwrap();
+    assert_eq!(
+        result.as_any().downcast_ref::<PrimitiveScalar<i128>>(),
+        Some(&PrimitiveScalar::new(DataType::Decimal(5, 2), Some(125)))
+    );
+}
+
+#[test]
+fn sum_all_null() {
+    let array = decimal(&[None, None], 5, 2);
+    let result = sum_decimal(&array, DecimalOverflow::Widen).unwrap();
+    assert_eq!(result, PrimitiveScalar::new(DataType::Decimal(5, 2), None));
+}
+
+#[test]
+fn sum_overflow_precision() {
+    let array = decimal(&[Some(99_999), Some(1)], 5, 2);
+    assert!(matches!(
+        sum_decimal(&array, DecimalOverflow::Error),
+        Err(ArrowError::Overflow)
+    ));
+
+    let expected = PrimitiveScalar::new(DataType::Decimal(6, 2), Some(100_000));
+    let result = sum_decimal(&array, DecimalOverflow::Widen).unwrap();
+    assert_eq!(result, expected);
+
+    // `sum` keeps the precision
+    assert!(matches!(sum(&array), Err(ArrowError::Overflow)));
+}
+
+#[test]
+fn sum_precision_exceeds_i128() {
+    let array = decimal(&[Some(i128::MAX - 1), Some(1)], 40, 0);
+    let result = sum_decimal(&array, DecimalOverflow::Error).unwrap();
+    assert_eq!(
+        result,
+        PrimitiveScalar::new(DataType::Decimal(40, 0), Some(i128::MAX))
+    );
+}
+
+#[test]
+fn sum_overflow_i128() {
+    let max = 10i128.pow(38) - 1;
+    let array = decimal(&[Some(max), Some(max)], 38, 0);
+    assert!(matches!(
+        sum_decimal(&array, DecimalOverflow::Widen),
+        Err(ArrowError::Overflow)
+    ));
+    assert!(sum(&array).is_err());
+
+    // partial sums may exceed an i128 as long as the total fits
+    let array = PrimitiveArray::from_slice([i128::MAX, i128::MAX, -i128::MAX, -i128::MAX, 1])
+        .to(DataType::Decimal(38, 0));
+    let result = sum_decimal(&array, DecimalOverflow::Error).unwrap();
+    assert_eq!(result.value(), Some(1));
+}
+
+#[test]
+fn mean() {
+    // [1.00, 2.00, 2.00, None]
+    let array = decimal(&[Some(100), Some(200), Some(200), None], 5, 2);
+
+    let result = mean_decimal(&array, 2).unwrap();
+    assert_eq!(
+        result,
+        PrimitiveScalar::new(DataType::Decimal(5, 2), Some(167))
+    );
+
+    let result = mean_decimal(&array, 6).unwrap();
+    assert_eq!(
+        result,
+        PrimitiveScalar::new(DataType::Decimal(9, 6), Some(1_666_667))
+    );
+
+    let result = mean_decimal(&array, 0).unwrap();
+    assert_eq!(
+        result,
+        PrimitiveScalar::new(DataType::Decimal(3, 0), Some(2))
+    );
+}
+
+#[test]
+fn mean_rounds_half_away_from_zero() {
+    let array = decimal(&[Some(-1), Some(-2)], 3, 0);
+    let result = mean_decimal(&array, 0).unwrap();
+    assert_eq!(result.value(), Some(-2));
+
+    let result = mean_decimal(&array, 1).unwrap();
+    assert_eq!(result.value(), Some(-15));
+}
+
+#[test]
+fn mean_all_null() {
+    let array = decimal(&[None], 5, 2);
+    let result = mean_decimal(&array, 2).unwrap();
+    assert_eq!(result.value(), None);
+}
+
+#[test]
+fn mean_overflow() {
+    let array = decimal(&[Some(10i128.pow(37))], 38, 0);
+    assert!(matches!(mean_decimal(&array, 2), Err(ArrowError::Overflow)));
+}
+
+#[test]
+fn min_max() {
+    let array = decimal(&[Some(150), None, Some(-25)], 5, 2);
+    assert_eq!(min_primitive(&array), Some(-25));
+    assert_eq!(max_primitive(&array), Some(150));
+}
@@ -1,4 +1,13 @@
-use arrow2::{array::*, compute::aggregate::estimated_bytes_size};
+use std::sync::Arc;
+
+use arrow2::{
+    array::*,
+    chunk::Chunk,
+    compute::aggregate::{
+        estimated_buffers_size, estimated_bytes_size, estimated_chunk_bytes_size, BuffersSize,
+    },
+    datatypes::{DataType, Field},
+};
 
 #[test]
 fn primitive() {
@@ -17,3 +26,65 @@ fn utf8() {
     let a = Utf8Array::<i32>::from_slice(&["aaa"]);
     assert_eq!(3 + 2 * std::mem::size_of::<i32>(), estimated_bytes_size(&a));
 }
+
+#[test]
+fn breakdown() {
+    let a = Utf8Array::<i32>::from([Some("aaa"), None]);
+    assert_eq!(
+        estimated_buffers_size([&a as &dyn Array]),
+        BuffersSize {
+            validity: 1,
+            offsets: 3 * std::mem::size_of::<i32>(),
+            values: 3,
+        }
+    );
+}
+
+#[test]
+fn flat_equals_buffers() {
+    let a = Utf8Array::<i32>::from([Some("aaa"), None, Some("b")]).slice(1, 2);
+    assert_eq!(
+        estimated_bytes_size(&a),
+        estimated_buffers_size([&a as &dyn Array]).total()
+    );
+}
+
+#[test]
+fn struct_shared_field() {
+    let field = Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>;
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Int32, false),
+    ]);
+    let a = StructArray::from_data(data_type, vec![field.clone(), field], None);
+    assert_eq!(2 * std::mem::size_of::<i32>(), estimated_bytes_size(&a));
+}
+
+#[test]
+fn slices() {
+    let a = Int32Array::from_slice([1, 2, 3, 4]);
+    let lhs = a.slice(0, 3);
+    let rhs = a.slice(2, 2);
+    assert_eq!(3 * std::mem::size_of::<i32>(), estimated_bytes_size(&lhs));
+    assert_eq!(
+        4 * std::mem::size_of::<i32>(),
+        estimated_buffers_size([&lhs as &dyn Array, &rhs]).total()
+    );
+}
+
+#[test]
+fn chunk_shared_dictionary() {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["aa", "bb"])) as Arc<dyn Array>;
+    let keys_size = 3 * std::mem::size_of::<i32>();
+    let values_size = 4 + 3 * std::mem::size_of::<i32>();
+
+    let a = DictionaryArray::<i32>::from_data(Int32Array::from_slice([0, 1, 0]), values.clone());
+    let b = DictionaryArray::<i32>::from_data(Int32Array::from_slice([1, 1, 0]), values);
+    assert_eq!(estimated_bytes_size(&a), keys_size + values_size);
+
+    let chunk = Chunk::new(vec![Arc::new(a) as Arc<dyn Array>, Arc::new(b)]);
+    assert_eq!(
+        estimated_chunk_bytes_size(&chunk),
+        2 * keys_size + values_size
+    );
+}
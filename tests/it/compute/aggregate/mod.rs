@@ -1,3 +1,5 @@
+mod decimal;
 mod memory;
 mod min_max;
+mod quantile;
 mod sum;
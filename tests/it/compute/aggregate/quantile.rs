@@ -0,0 +1,70 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{
+    can_quantile, median_primitive, quantile, quantile_primitive, QuantileInterpolation,
+};
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Scalar};
+
+#[test]
+fn interpolations() {
+    let array = Int32Array::from_slice([10, 0, 40, 30, 20]);
+
+    let cases = [
+        (QuantileInterpolation::Lower, 0.3, 10.0),
+        (QuantileInterpolation::Higher, 0.3, 20.0),
+        (QuantileInterpolation::Nearest, 0.3, 10.0),
+        (QuantileInterpolation::Nearest, 0.4, 20.0),
+        (QuantileInterpolation::Linear, 0.3, 12.0),
+        (QuantileInterpolation::Linear, 0.0, 0.0),
+        (QuantileInterpolation::Linear, 1.0, 40.0),
+        (QuantileInterpolation::Higher, 0.75, 30.0),
+    ];
+    for (interpolation, q, expected) in cases {
+        let result = quantile_primitive(&array, q, interpolation).unwrap();
+        assert_eq!(result, Some(expected), "{:?} {}", interpolation, q);
+    }
+}
+
+#[test]
+fn median_with_nulls() {
+    let array = Float64Array::from([Some(3.0), None, Some(1.0), Some(2.0), None]);
+    assert_eq!(median_primitive(&array), Some(2.0));
+
+    let array = UInt8Array::from([None, Some(4), Some(1), Some(2), Some(3)]);
+    assert_eq!(median_primitive(&array), Some(2.5));
+
+    let array = Int64Array::from([None, None]);
+    assert_eq!(median_primitive(&array), None);
+
+    let array = Int64Array::from_slice([1, 2, 3, 4, 5]).slice(3, 2);
+    assert_eq!(median_primitive(&array), Some(4.5));
+}
+
+#[test]
+fn duplicates() {
+    let array = Int16Array::from_slice([5, 1, 1, 5, 1]);
+    let result = quantile_primitive(&array, 0.5, QuantileInterpolation::Linear).unwrap();
+    assert_eq!(result, Some(1.0));
+    let result = quantile_primitive(&array, 0.625, QuantileInterpolation::Linear).unwrap();
+    assert_eq!(result, Some(3.0));
+}
+
+#[test]
+fn invalid_quantile() {
+    let array = Int32Array::from_slice([1]);
+    assert!(quantile_primitive(&array, 1.5, QuantileInterpolation::Linear).is_err());
+    assert!(quantile_primitive(&array, f64::NAN, QuantileInterpolation::Linear).is_err());
+}
+
+#[test]
+fn dynamic() {
+    let array = Float32Array::from([Some(1.0), None, Some(2.0)]);
+    let result = quantile(&array, 0.5, QuantileInterpolation::Linear).unwrap();
+    let expected: Box<dyn Scalar> =
+        Box::new(PrimitiveScalar::<f64>::new(DataType::Float64, Some(1.5)));
+    assert_eq!(&result, &expected);
+
+    assert!(!can_quantile(&DataType::Utf8));
+    let array = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(quantile(&array, 0.5, QuantileInterpolation::Linear).is_err());
+}
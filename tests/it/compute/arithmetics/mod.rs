@@ -7,6 +7,7 @@ use arrow2::compute::arithmetics::*;
 use arrow2::datatypes::DataType::*;
 use arrow2::datatypes::{IntervalUnit, TimeUnit};
 use arrow2::scalar::PrimitiveScalar;
+use arrow2::types::months_days_ns;
 
 #[test]
 fn test_add() {
@@ -45,6 +46,7 @@ fn consistency() {
         Timestamp(TimeUnit::Millisecond, None),
         Timestamp(TimeUnit::Microsecond, None),
         Timestamp(TimeUnit::Nanosecond, None),
+        Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string())),
         Time64(TimeUnit::Microsecond),
         Time64(TimeUnit::Nanosecond),
         Date32,
@@ -62,7 +64,9 @@ fn consistency() {
         Interval(IntervalUnit::MonthDayNano),
     ];
 
-    let cases = datatypes.clone().into_iter().zip(datatypes.into_iter());
+    let cases = datatypes
+        .iter()
+        .flat_map(|lhs| datatypes.iter().map(move |rhs| (lhs.clone(), rhs.clone())));
 
     cases.for_each(|(lhs, rhs)| {
         let lhs_a = new_empty_array(lhs.clone());
@@ -106,3 +110,75 @@ fn test_neg_dict() {
     );
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn temporal_rules() {
+    let timestamp = PrimitiveArray::from([Some(10i64), None]).to(Timestamp(TimeUnit::Second, None));
+    let duration =
+        PrimitiveArray::from([Some(2_000i64), Some(1)]).to(Duration(TimeUnit::Millisecond));
+
+    // Duration + Timestamp is commutative
+    let expected = PrimitiveArray::from([Some(12i64), None]).to(Timestamp(TimeUnit::Second, None));
+    assert_eq!(expected, add(&duration, &timestamp).as_ref());
+    assert_eq!(expected, add(&timestamp, &duration).as_ref());
+
+    // Timestamp - Timestamp = Duration
+    let other =
+        PrimitiveArray::from([Some(4_000i64), Some(1)]).to(Timestamp(TimeUnit::Millisecond, None));
+    let expected = PrimitiveArray::from([Some(6_000i64), None]).to(Duration(TimeUnit::Millisecond));
+    assert_eq!(expected, sub(&timestamp, &other).as_ref());
+
+    // Duration + Duration = Duration in the finest unit
+    let seconds = PrimitiveArray::from([Some(1i64), Some(2)]).to(Duration(TimeUnit::Second));
+    let expected =
+        PrimitiveArray::from([Some(3_000i64), Some(2_001)]).to(Duration(TimeUnit::Millisecond));
+    assert_eq!(expected, add(&seconds, &duration).as_ref());
+
+    // Date - Date = Duration
+    let date = PrimitiveArray::from([Some(3i32), Some(1)]).to(Date32);
+    let expected = PrimitiveArray::from([Some(172_800i64), Some(0)]).to(Duration(TimeUnit::Second));
+    let other = PrimitiveArray::from([Some(1i32), Some(1)]).to(Date32);
+    assert_eq!(expected, sub(&date, &other).as_ref());
+
+    // Date + Duration = Date
+    let days = PrimitiveArray::from([Some(86_400i64), None]).to(Duration(TimeUnit::Second));
+    let expected = PrimitiveArray::from([Some(4i32), None]).to(Date32);
+    assert_eq!(expected, add(&days, &date).as_ref());
+
+    // Duration * Int64 is commutative
+    let factor = PrimitiveArray::from([Some(3i64), Some(-2)]);
+    let expected =
+        PrimitiveArray::from([Some(6_000i64), Some(-2)]).to(Duration(TimeUnit::Millisecond));
+    assert_eq!(expected, mul(&duration, &factor).as_ref());
+    assert_eq!(expected, mul(&factor, &duration).as_ref());
+
+    // Duration * scalar
+    let factor: PrimitiveScalar<i64> = Some(2i64).into();
+    let expected =
+        PrimitiveArray::from([Some(4_000i64), Some(2)]).to(Duration(TimeUnit::Millisecond));
+    assert_eq!(expected, mul_scalar(&duration, &factor).as_ref());
+}
+
+#[test]
+fn checked_temporal_errors() {
+    let timestamp = PrimitiveArray::from([Some(10i64)])
+        .to(Timestamp(TimeUnit::Second, Some("invalid".to_string())));
+    let interval = PrimitiveArray::from([Some(months_days_ns::new(0, 1, 0))])
+        .to(Interval(IntervalUnit::MonthDayNano));
+    assert!(checked_add(&timestamp, &interval).is_err());
+    assert!(checked_add(&interval, &timestamp).is_err());
+    assert!(checked_sub(&timestamp, &interval).is_err());
+
+    let timestamp = PrimitiveArray::from([Some(10i64), None]).to(Timestamp(TimeUnit::Second, None));
+    let duration = PrimitiveArray::from([Some(2i64), Some(1)]).to(Duration(TimeUnit::Second));
+    let expected = PrimitiveArray::from([Some(12i64), None]).to(Timestamp(TimeUnit::Second, None));
+    assert_eq!(
+        expected,
+        checked_add(&duration, &timestamp).unwrap().as_ref()
+    );
+    let expected = PrimitiveArray::from([Some(8i64), None]).to(Timestamp(TimeUnit::Second, None));
+    assert_eq!(
+        expected,
+        checked_sub(&timestamp, &duration).unwrap().as_ref()
+    );
+}
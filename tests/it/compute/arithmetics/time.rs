@@ -1,7 +1,8 @@
 use arrow2::array::*;
 use arrow2::compute::arithmetics::time::*;
-use arrow2::datatypes::{DataType, TimeUnit};
+use arrow2::datatypes::{DataType, IntervalUnit, TimeUnit};
 use arrow2::scalar::*;
+use arrow2::types::months_days_ns;
 
 #[test]
 fn test_adding_timestamp() {
@@ -317,3 +318,127 @@ fn test_date64() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn add_durations_normalizes_units() {
+    let seconds =
+        PrimitiveArray::from([Some(1i64), None, Some(3)]).to(DataType::Duration(TimeUnit::Second));
+    let microseconds = PrimitiveArray::from([Some(10i64), Some(20), Some(30)])
+        .to(DataType::Duration(TimeUnit::Microsecond));
+
+    let expected = PrimitiveArray::from([Some(1_000_010i64), None, Some(3_000_030)])
+        .to(DataType::Duration(TimeUnit::Microsecond));
+    assert_eq!(add_durations(&seconds, &microseconds).unwrap(), expected);
+    assert_eq!(add_durations(&microseconds, &seconds).unwrap(), expected);
+
+    let expected = PrimitiveArray::from([Some(-999_990i64), None, Some(-2_999_970)])
+        .to(DataType::Duration(TimeUnit::Microsecond));
+    assert_eq!(
+        subtract_durations(&microseconds, &seconds).unwrap(),
+        expected
+    );
+
+    assert!(add_durations(&seconds, &PrimitiveArray::from_slice([1i64, 2, 3])).is_err());
+}
+
+#[test]
+fn add_durations_overflow() {
+    let seconds =
+        PrimitiveArray::from([Some(i64::MAX), Some(1)]).to(DataType::Duration(TimeUnit::Second));
+    let milliseconds =
+        PrimitiveArray::from([Some(1i64), Some(1)]).to(DataType::Duration(TimeUnit::Millisecond));
+
+    let expected =
+        PrimitiveArray::from([None, Some(1_001i64)]).to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(add_durations(&seconds, &milliseconds).unwrap(), expected);
+}
+
+#[test]
+fn mul_duration_overflow() {
+    let duration =
+        PrimitiveArray::from([Some(i64::MAX), Some(2)]).to(DataType::Duration(TimeUnit::Second));
+    let factor = PrimitiveArray::from([Some(2i64), Some(3)]);
+
+    let expected =
+        PrimitiveArray::from([None, Some(6i64)]).to(DataType::Duration(TimeUnit::Second));
+    assert_eq!(mul_duration(&duration, &factor).unwrap(), expected);
+
+    let factor: PrimitiveScalar<i64> = Some(3i64).into();
+    assert_eq!(mul_duration_scalar(&duration, &factor).unwrap(), expected);
+}
+
+#[test]
+fn subtract_temporals_timestamps() {
+    let lhs = PrimitiveArray::from([Some(10i64), None, Some(30)]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("America/New_York".to_string()),
+    ));
+    let rhs = PrimitiveArray::from([Some(1_000i64), Some(2_000), Some(3_000)])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+
+    let result = subtract_temporals(&lhs, &rhs).unwrap();
+    let expected = PrimitiveArray::from([Some(9_000i64), None, Some(27_000)])
+        .to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn subtract_temporals_dates() {
+    let lhs = PrimitiveArray::from([Some(2i32), None]).to(DataType::Date32);
+    let rhs = PrimitiveArray::from([Some(1i32), Some(1)]).to(DataType::Date32);
+
+    let result = subtract_temporals(&lhs, &rhs).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(86_400i64), None]).to(DataType::Duration(TimeUnit::Second));
+    assert_eq!(result, expected);
+
+    let lhs = PrimitiveArray::from([Some(2_000i64)]).to(DataType::Date64);
+    let rhs = PrimitiveArray::from([Some(500i64)]).to(DataType::Date64);
+    let result = subtract_temporals(&lhs, &rhs).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(1_500i64)]).to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn subtract_temporals_times() {
+    let lhs = PrimitiveArray::from([Some(2i32)]).to(DataType::Time32(TimeUnit::Second));
+    let rhs = PrimitiveArray::from([Some(500i32)]).to(DataType::Time32(TimeUnit::Millisecond));
+
+    let result = subtract_temporals(&lhs, &rhs).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(1_500i64)]).to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(result, expected);
+
+    let date = PrimitiveArray::from([Some(1i32)]).to(DataType::Date32);
+    assert!(subtract_temporals(&lhs, &date).is_err());
+}
+
+#[test]
+fn intervals() {
+    let timestamp = PrimitiveArray::from([Some(86_400i64), None])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let interval = PrimitiveArray::from([
+        Some(months_days_ns::new(0, 1, 1_000_000_000)),
+        Some(months_days_ns::new(1, 0, 0)),
+    ])
+    .to(DataType::Interval(IntervalUnit::MonthDayNano));
+
+    let result = subtract_interval(&timestamp, &interval).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(-1i64), None]).to(DataType::Timestamp(TimeUnit::Second, None));
+    assert_eq!(result, expected);
+
+    let other = PrimitiveArray::from([Some(months_days_ns::new(1, 2, 3)), None])
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+
+    let result = add_intervals(&interval, &other);
+    let expected = PrimitiveArray::from([Some(months_days_ns::new(1, 3, 1_000_000_003)), None])
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    assert_eq!(result, expected);
+
+    let result = subtract_intervals(&interval, &other);
+    let expected = PrimitiveArray::from([Some(months_days_ns::new(-1, -1, 999_999_997)), None])
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    assert_eq!(result, expected);
+}
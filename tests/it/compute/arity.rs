@@ -0,0 +1,100 @@
+use arrow2::array::Int32Array;
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::arity::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn prep() {
+    assert_eq!(prep_validity(&[]), None);
+    assert_eq!(prep_validity(&[None, None]), None);
+
+    let a = Bitmap::from([true, false, true]);
+    let all_set = Bitmap::from([true, true, true]);
+    let all_unset = Bitmap::from([false, false, false]);
+    assert_eq!(prep_validity(&[None, Some(&all_set)]), None);
+    assert_eq!(
+        prep_validity(&[Some(&all_set), Some(&a), None]),
+        Some(a.clone())
+    );
+    assert_eq!(
+        prep_validity(&[Some(&a), Some(&all_unset), Some(&a)]),
+        Some(all_unset)
+    );
+}
+
+#[test]
+fn prep_offsets() {
+    // lengths and offsets that exercise whole words and remainders
+    let a = Bitmap::from_iter((0..300).map(|x| x % 3 != 0 && x % 7 != 1));
+    let b = Bitmap::from_iter((0..300).map(|x| x % 5 != 2));
+    let c = Bitmap::from_iter((0..300).map(|x| x % 11 != 4));
+
+    for (offset, length) in [(0, 300), (3, 200), (8, 130), (61, 64), (5, 7)] {
+        let a = a.clone().slice(offset, length);
+        let b = b.clone().slice(offset / 2, length);
+        let c = c.clone().slice(0, length);
+
+        let expected = &(&a & &b) & &c;
+        let result = prep_validity(&[Some(&a), None, Some(&b), Some(&c)]).unwrap();
+        assert_eq!(result, expected);
+        assert_eq!(result.null_count(), expected.null_count());
+    }
+}
+
+#[test]
+#[should_panic]
+fn prep_different_lengths() {
+    let a = Bitmap::from([true, false, true]);
+    let b = Bitmap::from([true, false]);
+    prep_validity(&[Some(&a), Some(&b)]);
+}
+
+#[test]
+fn unary_validity() {
+    let array = Int32Array::from(&[Some(2), Some(0), None, Some(5)]);
+    let validity = Bitmap::from([true, false, true, true]);
+
+    // dividing by the unselected zero would panic
+    let result = unary_with_validity(&array, |x| 10 / x, DataType::Int32, &validity);
+    assert_eq!(result, Int32Array::from(&[Some(5), None, None, Some(2)]));
+}
+
+#[test]
+fn unary_validity_none_selected() {
+    let array = Int32Array::from_slice([0, 0]);
+    let validity = Bitmap::from([false, false]);
+
+    let result = unary_with_validity(&array, |x| 10 / x, DataType::Int32, &validity);
+    assert_eq!(result, Int32Array::from(&[None, None]));
+}
+
+#[test]
+fn unary_validity_all_selected() {
+    let array = Int32Array::from_slice([1, 2]);
+    let validity = Bitmap::from([true, true]);
+
+    let result = unary_with_validity(&array, |x| x + 1, DataType::Int32, &validity);
+    assert_eq!(result, Int32Array::from_slice([2, 3]));
+    assert_eq!(result.validity(), None);
+}
+
+#[test]
+fn binary_validity() {
+    let lhs = Int32Array::from(&[Some(10), Some(10), Some(10), None]);
+    let rhs = Int32Array::from(&[Some(2), Some(0), None, Some(1)]);
+    let validity = Bitmap::from([true, false, true, true]);
+
+    let result =
+        binary_with_validity(&lhs, &rhs, DataType::Int32, |l, r| l / r, &validity).unwrap();
+    assert_eq!(result, Int32Array::from(&[Some(5), None, None, None]));
+}
+
+#[test]
+fn binary_validity_different_lengths() {
+    let array = Int32Array::from_slice([1, 2]);
+    let validity = Bitmap::from([true]);
+
+    assert!(
+        binary_with_validity(&array, &array, DataType::Int32, |l, r| l + r, &validity).is_err()
+    );
+}
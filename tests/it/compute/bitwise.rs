@@ -39,3 +39,39 @@ fn test_not() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_shift_left() {
+    let a = UInt8Array::from(&[Some(1), Some(0b1000_0001), None, Some(1)]);
+    let b = UInt8Array::from(&[Some(3), Some(1), Some(1), Some(8)]);
+    let result = shift_left(&a, &b);
+    let expected = UInt8Array::from(&[Some(8), Some(0b0000_0010), None, None]);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_shift_right() {
+    let a = Int32Array::from(&[Some(16), Some(-16), Some(1), Some(1)]);
+    let b = Int32Array::from(&[Some(2), Some(2), Some(-1), Some(32)]);
+    let result = shift_right(&a, &b);
+    let expected = Int32Array::from(&[Some(4), Some(-4), None, None]);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_shift_scalar() {
+    let a = Int64Array::from(&[Some(1), None, Some(-8)]);
+
+    let result = shift_left_scalar(&a, 2);
+    let expected = Int64Array::from(&[Some(4), None, Some(-32)]);
+    assert_eq!(result, expected);
+
+    let result = shift_right_scalar(&a, 2);
+    let expected = Int64Array::from(&[Some(0), None, Some(-2)]);
+    assert_eq!(result, expected);
+
+    let result = shift_left_scalar(&a, 64);
+    assert_eq!(result.null_count(), 3);
+}
@@ -1,6 +1,10 @@
 use arrow2::array::*;
-use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use arrow2::compute::cast::{
+    can_cast_types, cast, cast_iter, cast_with_report, checked_cast_with_report, date64_normalize,
+    saturating_cast, CastOptions, TemporalCastPolicy,
+};
 use arrow2::datatypes::*;
+use arrow2::temporal_conversions::MILLISECONDS_IN_DAY;
 use arrow2::types::{days_ms, months_days_ns, NativeType};
 
 #[test]
@@ -21,10 +25,7 @@ fn i32_as_f64_no_overflow() {
     let b = cast(
         &array,
         &DataType::Float64,
-        CastOptions {
-            wrapped: true,
-            ..Default::default()
-        },
+        CastOptions::default().with_wrapped(true),
     )
     .unwrap();
     let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
@@ -41,10 +42,7 @@ fn u16_as_u8_overflow() {
     let b = cast(
         &array,
         &DataType::UInt8,
-        CastOptions {
-            wrapped: true,
-            ..Default::default()
-        },
+        CastOptions::default().with_wrapped(true),
     )
     .unwrap();
     let c = b.as_any().downcast_ref::<UInt8Array>().unwrap();
@@ -59,10 +57,7 @@ fn u16_as_u8_no_overflow() {
     let b = cast(
         &array,
         &DataType::UInt8,
-        CastOptions {
-            wrapped: true,
-            ..Default::default()
-        },
+        CastOptions::default().with_wrapped(true),
     )
     .unwrap();
     let c = b.as_any().downcast_ref::<UInt8Array>().unwrap();
@@ -80,10 +75,7 @@ fn f32_as_u8_overflow() {
     let b = cast(
         &array,
         &DataType::UInt8,
-        CastOptions {
-            wrapped: true,
-            ..Default::default()
-        },
+        CastOptions::default().with_wrapped(true),
     )
     .unwrap();
     let expected = UInt8Array::from(&[Some(1), Some(255)]);
@@ -214,10 +206,7 @@ fn binary_to_i32_partial() {
     let b = cast(
         &array,
         &DataType::Int32,
-        CastOptions {
-            partial: true,
-            ..Default::default()
-        },
+        CastOptions::default().with_partial(true),
     )
     .unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
@@ -366,10 +355,7 @@ fn utf8_to_i32_partial() {
     let b = cast(
         &array,
         &DataType::Int32,
-        CastOptions {
-            partial: true,
-            ..Default::default()
-        },
+        CastOptions::default().with_partial(true),
     )
     .unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
@@ -600,7 +586,7 @@ fn timestamp_to_date64() {
     test_primitive_to_primitive(
         &[864000000005i64, 1545696000001],
         DataType::Timestamp(TimeUnit::Millisecond, Some(String::from("UTC"))),
-        &[864000000005i64, 1545696000001i64],
+        &[864000000000i64, 1545696000000i64],
         DataType::Date64,
     );
 }
@@ -803,3 +789,294 @@ fn utf8_to_date64() {
 
     assert_eq!(&expected, c);
 }
+
+#[test]
+fn report_utf8_to_int() {
+    let array = Utf8Array::<i32>::from([Some("1"), Some("x"), None, Some("99999999999")]);
+    let (result, report) =
+        cast_with_report(&array, &DataType::Int32, CastOptions::default()).unwrap();
+
+    let expected = Int32Array::from([Some(1), None, None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    assert_eq!(report.failure_count(), 2);
+    assert_eq!(
+        report.failures().unwrap().iter().collect::<Vec<_>>(),
+        vec![false, true, false, true]
+    );
+}
+
+#[test]
+fn report_overflow() {
+    let array = Int32Array::from([Some(1), Some(300), None]);
+    let (_, report) = cast_with_report(&array, &DataType::UInt8, CastOptions::default()).unwrap();
+    assert_eq!(report.failure_count(), 1);
+
+    // wrapped values are not failures
+    let options = CastOptions::default().with_wrapped(true);
+    let (_, report) = cast_with_report(&array, &DataType::UInt8, options).unwrap();
+    assert!(report.is_empty());
+}
+
+#[test]
+fn report_no_failures() {
+    let array = Int32Array::from([Some(1), None, Some(3)]);
+    let (result, report) =
+        cast_with_report(&array, &DataType::Int64, CastOptions::default()).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Int64Array::from([Some(1), None, Some(3)]) as &dyn Array
+    );
+    assert!(report.is_empty());
+    assert_eq!(report.failure_count(), 0);
+}
+
+#[test]
+fn report_dictionary() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend([Some("1"), Some("a"), None, Some("a")])
+        .unwrap();
+    let array: DictionaryArray<i32> = array.into();
+
+    let (_, report) = cast_with_report(&array, &DataType::Int32, CastOptions::default()).unwrap();
+    assert_eq!(
+        report.failures().unwrap().iter().collect::<Vec<_>>(),
+        vec![false, true, false, true]
+    );
+}
+
+fn temporal_options(temporal: TemporalCastPolicy) -> CastOptions {
+    CastOptions::default().with_temporal(temporal)
+}
+
+#[test]
+fn timestamp_unit_policies() {
+    let array = Int64Array::from([Some(1_500_000_000), Some(-1_500_000_000), None])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    let to_type = DataType::Timestamp(TimeUnit::Second, None);
+
+    // values are truncated towards zero, or floored on request
+    let truncated = Int64Array::from([Some(1), Some(-1), None]).to(to_type.clone());
+    let floored = Int64Array::from([Some(1), Some(-2), None]).to(to_type.clone());
+    for policy in [
+        TemporalCastPolicy::NullOnOverflow,
+        TemporalCastPolicy::ErrorOnOverflow,
+    ] {
+        let result = cast(&array, &to_type, temporal_options(policy)).unwrap();
+        assert_eq!(result.as_ref(), &truncated as &dyn Array);
+        let options = temporal_options(policy).with_floor_temporal(true);
+        let result = cast(&array, &to_type, options).unwrap();
+        assert_eq!(result.as_ref(), &floored as &dyn Array);
+    }
+    assert!(cast(
+        &array,
+        &to_type,
+        temporal_options(TemporalCastPolicy::Exact)
+    )
+    .is_err());
+
+    let exact = Int64Array::from([Some(2_000_000_000), None])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    let result = cast(
+        &exact,
+        &to_type,
+        temporal_options(TemporalCastPolicy::Exact),
+    )
+    .unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Int64Array::from([Some(2), None]).to(to_type) as &dyn Array
+    );
+}
+
+#[test]
+fn timestamp_unit_overflow() {
+    let array = Int64Array::from([Some(1), Some(i64::MAX / 10)])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let to_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Int64Array::from([Some(1000), None]).to(to_type.clone()) as &dyn Array
+    );
+
+    for policy in [
+        TemporalCastPolicy::ErrorOnOverflow,
+        TemporalCastPolicy::Exact,
+    ] {
+        assert!(cast(&array, &to_type, temporal_options(policy)).is_err());
+    }
+}
+
+#[test]
+fn time_unit_casts() {
+    test_primitive_to_primitive(
+        &[1i32, -1, 86_399_999],
+        DataType::Time32(TimeUnit::Millisecond),
+        &[1000i64, -1000, 86_399_999_000],
+        DataType::Time64(TimeUnit::Microsecond),
+    );
+    test_primitive_to_primitive(
+        &[1_500_000i64, 86_399_999_999],
+        DataType::Time64(TimeUnit::Microsecond),
+        &[1i32, 86_399],
+        DataType::Time32(TimeUnit::Second),
+    );
+
+    let array = Int64Array::from_slice([1_500_000]).to(DataType::Time64(TimeUnit::Microsecond));
+    let to_type = DataType::Time32(TimeUnit::Second);
+    assert!(cast(
+        &array,
+        &to_type,
+        temporal_options(TemporalCastPolicy::Exact)
+    )
+    .is_err());
+
+    // does not fit in an i32
+    let array = Int64Array::from_slice([i64::MAX]).to(DataType::Time64(TimeUnit::Nanosecond));
+    let to_type = DataType::Time32(TimeUnit::Millisecond);
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    assert_eq!(result.null_count(), 1);
+    let options = temporal_options(TemporalCastPolicy::ErrorOnOverflow);
+    assert!(cast(&array, &to_type, options).is_err());
+}
+
+#[test]
+fn date64_non_midnight() {
+    // 1970-01-02T01:00:00 and 1969-12-31T23:00:00
+    let array =
+        Int64Array::from_slice([MILLISECONDS_IN_DAY + 3_600_000, -3_600_000]).to(DataType::Date64);
+
+    // truncated towards zero by default and floored on request
+    test_primitive_to_primitive(
+        array.values(),
+        DataType::Date64,
+        &[1i32, 0],
+        DataType::Date32,
+    );
+    let result = cast(
+        &array,
+        &DataType::Date32,
+        CastOptions::default().with_floor_temporal(true),
+    )
+    .unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from_slice([1, -1]).to(DataType::Date32) as &dyn Array
+    );
+    assert!(cast(
+        &array,
+        &DataType::Date32,
+        temporal_options(TemporalCastPolicy::Exact)
+    )
+    .is_err());
+
+    assert_eq!(
+        date64_normalize(&array),
+        Int64Array::from_slice([MILLISECONDS_IN_DAY, -MILLISECONDS_IN_DAY]).to(DataType::Date64)
+    );
+
+    let to_type = DataType::Timestamp(TimeUnit::Second, None);
+    test_primitive_to_primitive(array.values(), DataType::Date64, &[86_400i64, 0], to_type);
+}
+
+#[test]
+fn date_to_timestamp() {
+    test_primitive_to_primitive(
+        &[1i32, -1],
+        DataType::Date32,
+        &[86_400_000_000_000i64, -86_400_000_000_000],
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+    );
+
+    let array = Int32Array::from_slice([i32::MAX]).to(DataType::Date32);
+    let to_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    assert_eq!(result.null_count(), 1);
+    let options = temporal_options(TemporalCastPolicy::ErrorOnOverflow);
+    assert!(cast(&array, &to_type, options).is_err());
+}
+
+#[test]
+fn saturating() {
+    let array = Int64Array::from([Some(300), Some(-300), None, Some(-1)]);
+    let result = saturating_cast(&array, &DataType::UInt8).unwrap();
+    let expected = UInt8Array::from([Some(255), Some(0), None, Some(0)]);
+    assert_eq!(expected, result.as_ref());
+
+    let array = Float64Array::from([Some(1e10), Some(-1e10), Some(f64::NAN), Some(1.5)]);
+    let result = saturating_cast(&array, &DataType::Int32).unwrap();
+    let expected = Int32Array::from([Some(i32::MAX), Some(i32::MIN), None, Some(1)]);
+    assert_eq!(expected, result.as_ref());
+
+    let array = UInt64Array::from_slice([u64::MAX, 1]);
+    let result = saturating_cast(&array, &DataType::Int64).unwrap();
+    let expected = Int64Array::from_slice([i64::MAX, 1]);
+    assert_eq!(expected, result.as_ref());
+
+    let array = Utf8Array::<i32>::from_slice(["1"]);
+    assert!(saturating_cast(&array, &DataType::Int32).is_err());
+}
+
+#[test]
+fn checked_with_report() {
+    let array = Int64Array::from([Some(1), Some(i64::MAX), None, Some(i64::MIN)]);
+    let (result, report) = checked_cast_with_report(&array, &DataType::Int32).unwrap();
+    let expected = Int32Array::from([Some(1), None, None, None]);
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(report.failure_count(), 2);
+    let failures = report.failures().unwrap();
+    assert_eq!(
+        failures.iter().collect::<Vec<_>>(),
+        [false, true, false, true]
+    );
+
+    let array = Int64Array::from_slice([1, 2]);
+    let (_, report) = checked_cast_with_report(&array, &DataType::Int32).unwrap();
+    assert!(report.is_empty());
+
+    let array = Int64Array::from_slice([1]).to(DataType::Date64);
+    assert!(checked_cast_with_report(&array, &DataType::Int32).is_err());
+}
+
+#[test]
+fn iter() {
+    let chunks: Vec<Box<dyn Array>> = vec![
+        Box::new(Int32Array::from([Some(1), None])),
+        Box::new(Int32Array::from_slice([3])),
+    ];
+    let result = cast_iter(chunks, DataType::Utf8, CastOptions::default())
+        .collect::<arrow2::error::Result<Vec<_>>>()
+        .unwrap();
+
+    let expected = [
+        Utf8Array::<i32>::from([Some("1"), None]),
+        Utf8Array::<i32>::from_slice(["3"]),
+    ];
+    assert_eq!(result.len(), 2);
+    for (result, expected) in result.iter().zip(expected.iter()) {
+        assert_eq!(result.as_ref(), expected as &dyn Array);
+    }
+}
+
+#[test]
+fn duration_to_from_utf8() {
+    let array = Int64Array::from([Some(5_400_000), None, Some(-500)])
+        .to(DataType::Duration(TimeUnit::Millisecond));
+
+    let result = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("PT1H30M"), None, Some("-PT0.5S")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = cast(&array, &DataType::LargeUtf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i64>::from([Some("PT1H30M"), None, Some("-PT0.5S")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let array = Utf8Array::<i32>::from([Some("PT1H30M"), Some("P1Y"), None, Some("PT0.5S")]);
+    let to_type = DataType::Duration(TimeUnit::Second);
+    assert!(can_cast_types(array.data_type(), &to_type));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from([Some(5_400), None, None, None]).to(to_type);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
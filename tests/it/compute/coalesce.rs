@@ -0,0 +1,93 @@
+use arrow2::array::*;
+use arrow2::compute::coalesce::*;
+use arrow2::datatypes::DataType;
+use arrow2::error::{ArrowError, Result};
+
+#[test]
+fn primitive() -> Result<()> {
+    let a = Int32Array::from([Some(1), None, None, None]);
+    let b = Int32Array::from([Some(5), Some(2), None, None]);
+    let c = Int32Array::from([None, Some(6), Some(3), None]);
+    let result = coalesce(&[&a, &b, &c])?;
+
+    let expected = Int32Array::from([Some(1), Some(2), Some(3), None]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn utf8() -> Result<()> {
+    let a = Utf8Array::<i32>::from([None, Some("b"), None]);
+    let b = Utf8Array::<i64>::from([Some("a"), Some("c"), None]);
+    let result = coalesce(&[&a, &b])?;
+
+    let expected = Utf8Array::<i64>::from([Some("a"), Some("b"), None]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn unify_numeric() -> Result<()> {
+    let a = Int8Array::from([Some(1), None, None]);
+    let b = Float32Array::from([Some(5.5), Some(2.5), None]);
+    let c = NullArray::new(DataType::Null, 3);
+    let result = coalesce(&[&a, &c, &b])?;
+
+    let expected = Float32Array::from([Some(1.0), Some(2.5), None]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn unify_unsigned_signed() -> Result<()> {
+    let a = UInt64Array::from([Some(u64::MAX), None]);
+    let b = Int64Array::from([Some(1), Some(i64::MIN)]);
+    let result = coalesce(&[&a, &b])?;
+
+    let expected = Int128Array::from([Some(u64::MAX as i128), Some(i64::MIN as i128)])
+        .to(DataType::Decimal(20, 0));
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn data_types() {
+    use DataType::*;
+    let cases = [
+        (vec![&Int8, &Int32], Some(Int32)),
+        (vec![&UInt8, &Int8], Some(Int16)),
+        (vec![&UInt64, &Int8], Some(Decimal(20, 0))),
+        (vec![&UInt64, &Int64, &Float32], Some(Float64)),
+        (vec![&UInt16, &UInt32], Some(UInt32)),
+        (vec![&Int16, &Float32], Some(Float32)),
+        (vec![&Int32, &Float32], Some(Float64)),
+        (vec![&Null, &Utf8], Some(Utf8)),
+        (vec![&Null, &Null], Some(Null)),
+        (vec![&Binary, &LargeBinary], Some(LargeBinary)),
+        (vec![&Utf8, &Int32], None),
+    ];
+    for (data_types, expected) in cases {
+        assert_eq!(expected, coalesce_data_type(&data_types));
+    }
+}
+
+#[test]
+fn errors() {
+    assert!(matches!(
+        coalesce(&[]),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    let a = Int32Array::from_slice([1]);
+    let b = Int32Array::from_slice([1, 2]);
+    assert!(matches!(
+        coalesce(&[&a, &b]),
+        Err(ArrowError::LengthMismatch { .. })
+    ));
+
+    let b = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(matches!(
+        coalesce(&[&a, &b]),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+}
@@ -327,4 +327,29 @@ mod tests {
         let out = comparison::boolean::neq_and_validity(&a, &b);
         check_mask(&out, &[false, true, true]);
     }
+
+    #[test]
+    fn compare_no_propagating_nulls_masked_values() {
+        // the values behind the nulls differ
+        let a = Int32Array::from_iter([Some(1), Some(2), Some(3), Some(4)])
+            .with_validity(Some(Bitmap::from_iter([true, false, false, true])));
+        let b = Int32Array::from_iter([Some(1), Some(5), Some(3), Some(6)])
+            .with_validity(Some(Bitmap::from_iter([true, false, true, false])));
+
+        let out = comparison::eq_and_validity(&a, &b);
+        check_mask(&out, &[true, true, false, false]);
+        let out = comparison::neq_and_validity(&a, &b);
+        check_mask(&out, &[false, false, true, true]);
+    }
+
+    #[test]
+    fn compare_no_propagating_nulls_scalar() {
+        let a = Int32Array::from_iter([Some(1), None, Some(3)]);
+
+        let null = new_scalar(&Int32Array::from_iter([None::<i32>]), 0);
+        let out = comparison::eq_scalar_and_validity(&a, null.as_ref());
+        check_mask(&out, &[false, true, false]);
+        let out = comparison::neq_scalar_and_validity(&a, null.as_ref());
+        check_mask(&out, &[true, false, true]);
+    }
 }
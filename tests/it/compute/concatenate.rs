@@ -1,6 +1,7 @@
 use arrow2::array::*;
-use arrow2::compute::concatenate::concatenate;
-use arrow2::error::Result;
+use arrow2::compute::concatenate::{concatenate, concatenate_iter};
+use arrow2::datatypes::DataType;
+use arrow2::error::{ArrowError, Result};
 
 #[test]
 fn empty_vec() {
@@ -14,7 +15,11 @@ fn incompatible_datatypes() {
         &Int64Array::from(vec![Some(-1), Some(2), None]),
         &Utf8Array::<i32>::from(&vec![Some("hello"), Some("bar"), Some("world")]),
     ]);
-    assert!(re.is_err());
+    assert!(matches!(
+        re,
+        Err(ArrowError::DataTypeMismatch { expected, found })
+            if *expected == DataType::Int64 && *found == DataType::Utf8
+    ));
 }
 
 #[test]
@@ -115,3 +120,62 @@ fn boolean_primitive_arrays() -> Result<()> {
 
     Ok(())
 }
+
+fn collect_iter(chunks: Vec<Box<dyn Array>>, chunk_size: usize) -> Result<Vec<Box<dyn Array>>> {
+    concatenate_iter(chunks, chunk_size)?.collect()
+}
+
+#[test]
+fn iter_rechunks() -> Result<()> {
+    let chunks: Vec<Box<dyn Array>> = vec![
+        Box::new(Int32Array::from([Some(1), None, Some(3)])),
+        Box::new(Int32Array::from_slice([])),
+        Box::new(Int32Array::from_slice([4])),
+        Box::new(Int32Array::from([Some(5), Some(6), None, Some(8), Some(9)])),
+    ];
+    let result = collect_iter(chunks, 2)?;
+
+    let expected = [
+        Int32Array::from([Some(1), None]),
+        Int32Array::from([Some(3), Some(4)]),
+        Int32Array::from([Some(5), Some(6)]),
+        Int32Array::from([None, Some(8)]),
+        Int32Array::from([Some(9)]),
+    ];
+    assert_eq!(result.len(), expected.len());
+    for (result, expected) in result.iter().zip(expected.iter()) {
+        assert_eq!(result.as_ref(), expected as &dyn Array);
+    }
+    Ok(())
+}
+
+#[test]
+fn iter_larger_than_input() -> Result<()> {
+    let chunks: Vec<Box<dyn Array>> = vec![
+        Box::new(Utf8Array::<i32>::from_slice(["a", "b"])),
+        Box::new(Utf8Array::<i32>::from([None, Some("c")])),
+    ];
+    let result = collect_iter(chunks, 10)?;
+
+    let expected = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("c")]);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].as_ref(), &expected as &dyn Array);
+
+    assert!(collect_iter(vec![], 10)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn iter_errors() {
+    let chunks: Vec<Box<dyn Array>> = vec![Box::new(Int32Array::from_slice([1]))];
+    assert!(concatenate_iter(chunks, 0).is_err());
+
+    let chunks: Vec<Box<dyn Array>> = vec![
+        Box::new(Int32Array::from_slice([1])),
+        Box::new(Int64Array::from_slice([2])),
+    ];
+    assert!(matches!(
+        collect_iter(chunks, 2),
+        Err(ArrowError::DataTypeMismatch { .. })
+    ));
+}
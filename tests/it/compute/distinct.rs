@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::chunk::Chunk;
+use arrow2::compute::distinct::distinct;
+use arrow2::compute::filter::filter_chunk;
+use arrow2::datatypes::DataType;
+use arrow2::error::Result;
+
+#[test]
+fn multiple_keys() -> Result<()> {
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([1, 2, 1, 2, 1])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "a", "a", "b", "b"])),
+        Arc::new(BooleanArray::from_slice([true, false, false, true, false])),
+    ]);
+
+    let first = distinct(&chunk, &[0, 1])?;
+    assert_eq!(first, Bitmap::from([true, true, false, true, true]));
+
+    let first = distinct(&chunk, &[1])?;
+    assert_eq!(first, Bitmap::from([true, false, false, true, false]));
+
+    let first = distinct(&chunk, &[2, 0])?;
+    assert_eq!(first, Bitmap::from([true, true, true, true, false]));
+    Ok(())
+}
+
+#[test]
+fn nulls_are_equal() -> Result<()> {
+    let chunk = Chunk::new(vec![
+        Arc::new(Int64Array::from([None, Some(1), None, Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i64>::from([
+            None,
+            None,
+            None,
+            Some("a"),
+            Some("a"),
+        ])),
+    ]);
+
+    let first = distinct(&chunk, &[0, 1])?;
+    assert_eq!(first, Bitmap::from([true, true, false, true, true]));
+    Ok(())
+}
+
+#[test]
+fn dictionary() -> Result<()> {
+    // the same values under different keys are equal
+    let values = Utf8Array::<i32>::from([Some("a"), Some("b"), Some("a"), None]);
+    let keys = Int8Array::from([Some(0), Some(2), Some(1), Some(3), None, Some(1)]);
+    let array = DictionaryArray::<i8>::from_data(keys, Arc::new(values));
+    let chunk = Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]);
+
+    let first = distinct(&chunk, &[0])?;
+    assert_eq!(first, Bitmap::from([true, false, true, true, false, false]));
+    Ok(())
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let array = Int32Array::from([Some(1), None, Some(2), None, Some(2)]).slice(1, 4);
+    let chunk = Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]);
+
+    let first = distinct(&chunk, &[0])?;
+    assert_eq!(first, Bitmap::from([true, true, false, false]));
+    Ok(())
+}
+
+#[test]
+fn deduplicate() -> Result<()> {
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([3, 1, 3, 1])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "a", "c"])),
+    ]);
+
+    let first = distinct(&chunk, &[0, 1])?;
+    let filter = BooleanArray::from_data(DataType::Boolean, first, None);
+    let deduplicated = filter_chunk(&chunk, &filter)?;
+    assert_eq!(
+        deduplicated,
+        Chunk::new(vec![
+            Box::new(Int32Array::from_slice([3, 1, 1])) as Box<dyn Array>,
+            Box::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+        ])
+    );
+    Ok(())
+}
+
+#[test]
+fn no_keys() -> Result<()> {
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>
+    ]);
+    assert_eq!(distinct(&chunk, &[])?, Bitmap::from([true, false, false]));
+    Ok(())
+}
+
+#[test]
+fn empty() -> Result<()> {
+    let chunk = Chunk::new(vec![Arc::new(Int32Array::from_slice([])) as Arc<dyn Array>]);
+    assert_eq!(distinct(&chunk, &[0])?, Bitmap::new());
+    Ok(())
+}
+
+#[test]
+fn invalid_keys() {
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>,
+        Arc::new(Float32Array::from_slice([1.0, 2.0])),
+    ]);
+    // out of bounds
+    assert!(distinct(&chunk, &[2]).is_err());
+    // floats can't be hashed
+    assert!(distinct(&chunk, &[1]).is_err());
+}
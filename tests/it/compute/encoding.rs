@@ -0,0 +1,80 @@
+use arrow2::array::*;
+use arrow2::compute::encoding::*;
+use arrow2::error::Result;
+
+#[test]
+fn hex() -> Result<()> {
+    let array = BinaryArray::<i32>::from([
+        Some(b"\x00\xff\x10".as_ref()),
+        None,
+        Some(b"".as_ref()),
+        Some(b"id".as_ref()),
+    ]);
+    let encoded = hex_encode(&array)?;
+    let expected = Utf8Array::<i32>::from([Some("00ff10"), None, Some(""), Some("6964")]);
+    assert_eq!(encoded, expected);
+
+    assert_eq!(hex_decode(&encoded)?, array);
+    Ok(())
+}
+
+#[test]
+fn hex_decode_case() -> Result<()> {
+    let array = Utf8Array::<i64>::from_slice(["aBcD", "ABCD"]);
+    let expected = BinaryArray::<i64>::from_slice([b"\xab\xcd", b"\xab\xcd"]);
+    assert_eq!(hex_decode(&array)?, expected);
+    Ok(())
+}
+
+#[test]
+fn hex_decode_invalid() {
+    let array = Utf8Array::<i32>::from_slice(["abc"]);
+    assert!(hex_decode(&array).is_err());
+    let array = Utf8Array::<i32>::from_slice(["0g"]);
+    assert!(hex_decode(&array).is_err());
+}
+
+#[test]
+fn hex_decode_ignores_nulls() -> Result<()> {
+    // the value of the null slot is not valid hex
+    let array =
+        Utf8Array::<i32>::from_slice(["zz", "01"]).with_validity(Some([false, true].into()));
+    let expected = BinaryArray::<i32>::from([None, Some(b"\x01".as_ref())]);
+    assert_eq!(hex_decode(&array)?, expected);
+    Ok(())
+}
+
+#[test]
+fn base64() -> Result<()> {
+    let array = BinaryArray::<i64>::from([
+        Some(b"a".as_ref()),
+        Some(b"ab".as_ref()),
+        Some(b"abc".as_ref()),
+        None,
+        Some(b"".as_ref()),
+    ]);
+    let encoded = base64_encode(&array)?;
+    let expected =
+        Utf8Array::<i64>::from([Some("YQ=="), Some("YWI="), Some("YWJj"), None, Some("")]);
+    assert_eq!(encoded, expected);
+
+    assert_eq!(base64_decode(&encoded)?, array);
+    Ok(())
+}
+
+#[test]
+fn base64_decode_invalid() {
+    let array = Utf8Array::<i32>::from_slice(["YQ=", "!!"]);
+    assert!(base64_decode(&array).is_err());
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let array = BinaryArray::<i32>::from_slice([b"\x01", b"\x02", b"\x03"]).slice(1, 2);
+    let encoded = hex_encode(&array)?;
+    assert_eq!(encoded, Utf8Array::<i32>::from_slice(["02", "03"]));
+
+    let encoded = base64_encode(&array)?;
+    assert_eq!(base64_decode(&encoded.slice(1, 1))?, array.slice(1, 1));
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::group::*;
+use arrow2::error::Result;
+use arrow2::types::days_ms;
+
+#[test]
+fn utf8() -> Result<()> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend([Some("b"), Some("a"), Some("b"), None, Some("c"), Some("a")])?;
+    let array: DictionaryArray<i32> = array.into();
+
+    let (ids, num_groups) = dictionary_group_ids(&array)?;
+    assert_eq!(ids, UInt32Array::from_slice([0, 1, 0, 2, 3, 1]));
+    assert_eq!(num_groups, 4);
+    Ok(())
+}
+
+#[test]
+fn non_unique_dictionary() -> Result<()> {
+    // "a" is repeated and the last value is null
+    let values = Utf8Array::<i32>::from([Some("a"), Some("b"), Some("a"), None]);
+    let keys = Int8Array::from([Some(2), Some(1), Some(0), Some(3), None, Some(1)]);
+    let array = DictionaryArray::<i8>::from_data(keys, Arc::new(values));
+
+    let (ids, num_groups) = dictionary_group_ids(&array)?;
+    assert_eq!(ids, UInt32Array::from_slice([0, 1, 0, 2, 2, 1]));
+    assert_eq!(num_groups, 3);
+    Ok(())
+}
+
+#[test]
+fn interval() -> Result<()> {
+    let values =
+        DaysMsArray::from_slice([days_ms::new(1, 0), days_ms::new(0, 1), days_ms::new(1, 0)]);
+    let keys = Int32Array::from_slice([0, 1, 2]);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+
+    let (ids, num_groups) = dictionary_group_ids(&array)?;
+    assert_eq!(ids, UInt32Array::from_slice([0, 1, 0]));
+    assert_eq!(num_groups, 2);
+    Ok(())
+}
+
+#[test]
+fn unused_values() -> Result<()> {
+    let values = Int64Array::from_slice([10, 20, 30, 40]);
+    let keys = UInt16Array::from_slice([3, 1, 3]);
+    let array = DictionaryArray::<u16>::from_data(keys, Arc::new(values));
+
+    let (ids, num_groups) = dictionary_group_ids(&array)?;
+    assert_eq!(ids, UInt32Array::from_slice([0, 1, 0]));
+    assert_eq!(num_groups, 2);
+    Ok(())
+}
+
+#[test]
+fn empty() -> Result<()> {
+    let values = Utf8Array::<i64>::from_slice(["a"]);
+    let array = DictionaryArray::<i32>::from_data(Int32Array::from_slice([]), Arc::new(values));
+
+    let (ids, num_groups) = dictionary_group_ids(&array)?;
+    assert_eq!(ids, UInt32Array::from_slice([]));
+    assert_eq!(num_groups, 0);
+    Ok(())
+}
+
+#[test]
+fn unsupported_values() {
+    let values = Float64Array::from_slice([1.0]);
+    let array = DictionaryArray::<i32>::from_data(Int32Array::from_slice([0]), Arc::new(values));
+    assert!(dictionary_group_ids(&array).is_err());
+}
@@ -40,6 +40,22 @@ fn utf8() {
     length_test_string::<i32>()
 }
 
+#[test]
+fn chars() {
+    let array = Utf8Array::<i32>::from([Some("héllo"), None, Some("💖"), Some("")]);
+    let result = length_chars(&array).unwrap();
+    let expected = Int32Array::from([Some(5), None, Some(1), Some(0)]);
+    assert_eq!(expected, result.as_ref());
+
+    let array = Utf8Array::<i64>::from([Some("hello"), None, Some("")]);
+    let result = length_chars(&array).unwrap();
+    let expected = Int64Array::from([Some(5), None, Some(0)]);
+    assert_eq!(expected, result.as_ref());
+
+    let array = BinaryArray::<i32>::from_slice(["a"]);
+    assert!(length_chars(&array).is_err());
+}
+
 #[test]
 fn consistency() {
     use arrow2::datatypes::DataType::*;
@@ -57,3 +57,169 @@ fn test_nlike_binary_scalar() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_like_binary_regex_chars() -> Result<()> {
+    // regex metacharacters in patterns match literally, and wildcards match new lines
+    let array = BinaryArray::<i32>::from_slice(["a.c", "abc", "(a)", "a\nc"]);
+
+    let result = like_binary_scalar(&array, b"a.c")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([true, false, false, false])
+    );
+
+    let result = like_binary_scalar(&array, b"%(_)%")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([false, false, true, false])
+    );
+
+    let patterns = BinaryArray::<i32>::from_slice(["a_c", "a.c", "_a_", "a_c"]);
+    let result = like_binary(&array, &patterns)?;
+    assert_eq!(result, BooleanArray::from_slice([true, false, true, true]));
+    Ok(())
+}
+
+#[test]
+fn test_like_binary_invalid_pattern() {
+    let array = BinaryArray::<i32>::from_slice(["a"]);
+    let patterns = BinaryArray::<i32>::from_slice([b"\xff".as_ref()]);
+    assert!(like_binary(&array, &patterns).is_err());
+    assert!(like_binary_scalar(&array, b"\xff").is_err());
+}
+
+#[test]
+fn test_like_utf8_scalar_fast_paths() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("Arrow"), None, Some("arrow2"), Some("Ar\nrow")]);
+
+    let cases = [
+        ("Arrow", [true, false, false, false]),
+        ("Ar%", [true, false, false, true]),
+        ("%row", [true, false, false, true]),
+        ("%rro%", [true, false, true, false]),
+        ("%", [true, false, true, true]),
+        ("%%", [true, false, true, true]),
+        ("Ar_r%", [false, false, false, true]),
+    ];
+    for (pattern, expected) in cases {
+        let result = like_utf8_scalar(&array, pattern)?;
+        let expected = BooleanArray::from_slice(expected).with_validity(array.validity().cloned());
+        assert_eq!(result, expected, "{}", pattern);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_like_utf8_regex_chars() -> Result<()> {
+    // regex metacharacters in patterns match literally
+    let array = Utf8Array::<i32>::from_slice(["a.c", "abc", "(a)", "a+"]);
+
+    let result = like_utf8_scalar(&array, "a.c")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([true, false, false, false])
+    );
+
+    let result = like_utf8_scalar(&array, "(_)")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([false, false, true, false])
+    );
+
+    let result = nlike_utf8_scalar(&array, "_+")?;
+    assert_eq!(result, BooleanArray::from_slice([true, true, true, false]));
+    Ok(())
+}
+
+#[test]
+fn test_ilike_utf8() -> Result<()> {
+    let strings = Utf8Array::<i32>::from_slice(["Arrow", "ARROW", "Ärrow", "ärrow"]);
+    let patterns = Utf8Array::<i32>::from_slice(["arrow", "a%w", "ä%", "_RROW"]);
+
+    let result = ilike_utf8(&strings, &patterns)?;
+    assert_eq!(result, BooleanArray::from_slice([true, true, true, true]));
+
+    let result = nilike_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([false, false, false, false])
+    );
+
+    let result = like_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([false, false, false, false])
+    );
+
+    let result = ilike_utf8_scalar(&strings, "%ROW")?;
+    assert_eq!(result, BooleanArray::from_slice([true, true, true, true]));
+
+    let result = nilike_utf8_scalar(&strings, "arrow")?;
+    assert_eq!(result, BooleanArray::from_slice([false, false, true, true]));
+    Ok(())
+}
+
+#[test]
+fn test_like_utf8_escape() -> Result<()> {
+    let array = Utf8Array::<i32>::from_slice(["100%", "100", "1_0", "a\\b", "120"]);
+    let options = LikeOptions {
+        escape: Some('\\'),
+        ..Default::default()
+    };
+
+    let cases = [
+        (r"%\%", [true, false, false, false, false]),
+        (r"1\_0", [false, false, true, false, false]),
+        (r"1_0", [false, true, true, false, true]),
+        (r"a\\b", [false, false, false, true, false]),
+        // escaping a character that is not a wildcard matches it literally
+        (r"\1%", [true, true, true, false, true]),
+    ];
+    for (pattern, expected) in cases {
+        let result = like_utf8_scalar_with(&array, pattern, &options)?;
+        assert_eq!(result, BooleanArray::from_slice(expected), "{}", pattern);
+
+        let result = nlike_utf8_scalar_with(&array, pattern, &options)?;
+        let expected = expected.map(|x| !x);
+        assert_eq!(result, BooleanArray::from_slice(expected), "{}", pattern);
+    }
+
+    let patterns = Utf8Array::<i32>::from_slice([r"%\%", r"%\%", r"1\_0", r"A\\B", r"1\_0"]);
+    let options = LikeOptions {
+        case_insensitive: true,
+        escape: Some('\\'),
+    };
+    let result = like_utf8_with(&array, &patterns, &options)?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([true, false, true, true, false])
+    );
+    let result = nlike_utf8_with(&array, &patterns, &options)?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([false, true, false, false, true])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_like_utf8_escape_errors() {
+    let array = Utf8Array::<i32>::from_slice(["a"]);
+    let options = LikeOptions {
+        escape: Some('\\'),
+        ..Default::default()
+    };
+    assert!(like_utf8_scalar_with(&array, r"a\", &options).is_err());
+
+    let patterns = Utf8Array::<i32>::from_slice([r"a\"]);
+    assert!(like_utf8_with(&array, &patterns, &options).is_err());
+}
+
+#[test]
+fn test_like_utf8_different_lengths() {
+    let strings = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let patterns = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(like_utf8(&strings, &patterns).is_err());
+    assert!(ilike_utf8(&strings, &patterns).is_err());
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::map::{keys, map_get, values};
+use arrow2::datatypes::{DataType, Field};
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+fn fields() -> Vec<Field> {
+    vec![
+        Field::new("key", DataType::Utf8, true),
+        Field::new("value", DataType::Int32, true),
+    ]
+}
+
+fn data_type() -> DataType {
+    let field = Field::new("entries", DataType::Struct(fields()), false);
+    DataType::Map(Box::new(field), false)
+}
+
+// [{a: 1, b: 2}, None, {a: null, c: 3}, {}, {null: 4, b: 5, b: 6}]
+fn map() -> MapArray {
+    let entries = StructArray::from_data(
+        DataType::Struct(fields()),
+        vec![
+            Arc::new(Utf8Array::<i32>::from([
+                Some("a"),
+                Some("b"),
+                Some("a"),
+                Some("c"),
+                None,
+                Some("b"),
+                Some("b"),
+            ])),
+            Arc::new(Int32Array::from([
+                Some(1),
+                Some(2),
+                None,
+                Some(3),
+                Some(4),
+                Some(5),
+                Some(6),
+            ])),
+        ],
+        None,
+    );
+    MapArray::from_data(
+        data_type(),
+        vec![0, 2, 2, 4, 4, 7].into(),
+        Arc::new(entries),
+        Some([true, false, true, true, true].into()),
+    )
+}
+
+#[test]
+fn project_keys() {
+    let result = keys(&map());
+
+    let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new_with_field(
+        MutableUtf8Array::new(),
+        "key",
+        true,
+    );
+    expected
+        .try_extend(vec![
+            Some(vec![Some("a"), Some("b")]),
+            None,
+            Some(vec![Some("a"), Some("c")]),
+            Some(vec![]),
+            Some(vec![None, Some("b"), Some("b")]),
+        ])
+        .unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn project_values() {
+    let result = values(&map());
+
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new_with_field(
+        MutablePrimitiveArray::new(),
+        "value",
+        true,
+    );
+    expected
+        .try_extend(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![None, Some(3)]),
+            Some(vec![]),
+            Some(vec![Some(4), Some(5), Some(6)]),
+        ])
+        .unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn get() {
+    let array = map();
+
+    let result = map_get(&array, &Utf8Scalar::<i32>::new(Some("a"))).unwrap();
+    let expected = Int32Array::from([Some(1), None, None, None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // the first entry wins
+    let result = map_get(&array, &Utf8Scalar::<i32>::new(Some("b"))).unwrap();
+    let expected = Int32Array::from([Some(2), None, None, None, Some(5)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = map_get(&array, &Utf8Scalar::<i32>::new(Some("d"))).unwrap();
+    assert_eq!(result.null_count(), 5);
+}
+
+#[test]
+fn get_null_key() {
+    let result = map_get(&map(), &Utf8Scalar::<i32>::new(None::<&str>)).unwrap();
+    assert_eq!(result.len(), 5);
+    assert_eq!(result.null_count(), 5);
+}
+
+#[test]
+fn get_sliced() {
+    let array = map().slice(2, 3);
+    let result = map_get(&array, &Utf8Scalar::<i32>::new(Some("c"))).unwrap();
+    let expected = Int32Array::from([Some(3), None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn get_wrong_type() {
+    let key = PrimitiveScalar::<i32>::new(DataType::Int32, Some(1));
+    assert!(map_get(&map(), &key).is_err());
+}
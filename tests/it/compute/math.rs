@@ -0,0 +1,66 @@
+use arrow2::array::*;
+use arrow2::compute::math::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn unary_functions() {
+    let array = Float64Array::from(&[Some(1.0), None, Some(4.0)]);
+
+    assert_eq!(
+        sqrt(&array),
+        Float64Array::from(&[Some(1.0), None, Some(2.0)])
+    );
+    assert_eq!(
+        exp(&array),
+        Float64Array::from(&[Some(1.0f64.exp()), None, Some(4.0f64.exp())])
+    );
+    assert_eq!(
+        ln(&array),
+        Float64Array::from(&[Some(0.0), None, Some(4.0f64.ln())])
+    );
+    assert_eq!(
+        log2(&array),
+        Float64Array::from(&[Some(0.0), None, Some(2.0)])
+    );
+    assert_eq!(
+        log(&array, 4.0),
+        Float64Array::from(&[Some(0.0), None, Some(1.0)])
+    );
+    assert_eq!(
+        sin(&array),
+        Float64Array::from(&[Some(1.0f64.sin()), None, Some(4.0f64.sin())])
+    );
+}
+
+#[test]
+fn keeps_data_type() {
+    let array = Float32Array::from_slice([1.0, 100.0]).to(DataType::Float32);
+    let result = log10(&array);
+    assert_eq!(result, Float32Array::from_slice([0.0, 2.0]));
+    assert_eq!(result.data_type(), &DataType::Float32);
+}
+
+#[test]
+fn out_of_domain() {
+    let array = Float64Array::from_slice([-1.0]);
+    assert!(ln(&array).value(0).is_nan());
+    assert!(sqrt(&array).value(0).is_nan());
+}
+
+#[test]
+fn binary_functions() {
+    let lhs = Float64Array::from(&[Some(2.0), None, Some(1.0)]);
+    let rhs = Float64Array::from(&[Some(10.0), Some(1.0), None]);
+    assert_eq!(
+        pow(&lhs, &rhs),
+        Float64Array::from(&[Some(1024.0), None, None])
+    );
+    assert_eq!(
+        atan2(&lhs, &rhs),
+        Float64Array::from(&[Some(2.0f64.atan2(10.0)), None, None])
+    );
+    assert_eq!(
+        pow_scalar(&lhs, 2.0),
+        Float64Array::from(&[Some(4.0), None, Some(1.0)])
+    );
+}
@@ -1,9 +1,11 @@
 use std::iter::once;
+use std::sync::Arc;
 
 use arrow2::array::*;
+use arrow2::chunk::Chunk;
 use arrow2::compute::merge_sort::*;
 use arrow2::compute::sort::sort;
-use arrow2::error::Result;
+use arrow2::error::{ArrowError, Result};
 
 #[test]
 fn merge_u32() -> Result<()> {
@@ -228,3 +230,164 @@ fn test_sort() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+fn chunks(runs: &[&[Option<i32>]]) -> Vec<Result<Chunk<Arc<dyn Array>>>> {
+    runs.iter()
+        .map(|run| {
+            let keys = Int32Array::from(run.to_vec());
+            let values = Utf8Array::<i32>::from_iter(run.iter().map(|x| x.map(|x| x.to_string())));
+            Ok(Chunk::new(vec![
+                Arc::new(keys) as Arc<dyn Array>,
+                Arc::new(values) as Arc<dyn Array>,
+            ]))
+        })
+        .collect()
+}
+
+#[test]
+fn merge_streams_nulls_first() -> Result<()> {
+    let a = chunks(&[&[None, Some(1), Some(4)], &[], &[Some(7), Some(8)]]);
+    let b = chunks(&[&[Some(2)], &[Some(3), Some(9)]]);
+    let c = chunks(&[]);
+    let d = chunks(&[&[None, Some(5), Some(6)]]);
+
+    let key = SortKey {
+        column: 0,
+        options: SortOptions {
+            descending: false,
+            nulls_first: true,
+        },
+    };
+    let merged = merge_streams(
+        vec![a.into_iter(), b.into_iter(), c.into_iter(), d.into_iter()],
+        vec![key],
+        4,
+    )
+    .collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(
+        merged.iter().map(|chunk| chunk.len()).collect::<Vec<_>>(),
+        vec![4, 4, 3]
+    );
+    let keys = merged
+        .iter()
+        .flat_map(|chunk| {
+            let array = chunk.arrays()[0]
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            array.iter().map(|x| x.copied()).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        keys,
+        vec![
+            None,
+            None,
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(6),
+            Some(7),
+            Some(8),
+            Some(9)
+        ]
+    );
+    let values = merged[2].arrays()[1]
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    assert_eq!(values, &Utf8Array::<i32>::from_slice(["7", "8", "9"]));
+    Ok(())
+}
+
+#[test]
+fn merge_streams_descending() -> Result<()> {
+    let a = chunks(&[&[Some(9), Some(3)], &[Some(1), None]]);
+    let b = chunks(&[&[Some(8), Some(3), Some(2)]]);
+
+    let key = SortKey {
+        column: 0,
+        options: SortOptions {
+            descending: true,
+            nulls_first: false,
+        },
+    };
+    let merged = merge_streams(vec![a.into_iter(), b.into_iter()], vec![key], 100)
+        .collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(merged.len(), 1);
+    let expected = Int32Array::from(&[Some(9), Some(8), Some(3), Some(3), Some(2), Some(1), None]);
+    assert_eq!(merged[0].arrays()[0].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn merge_streams_error() {
+    let a = vec![
+        Ok(chunks(&[&[Some(1)]]).pop().unwrap().unwrap()),
+        Err(ArrowError::ExternalFormat("oops".to_string())),
+    ];
+    let b = chunks(&[&[Some(2)]]);
+    let key = SortKey {
+        column: 0,
+        options: SortOptions::default(),
+    };
+    let result = merge_streams(vec![a.into_iter(), b.into_iter()], vec![key], 10)
+        .collect::<Result<Vec<_>>>();
+    assert!(result.is_err());
+}
+
+#[test]
+fn merge_streams_many() -> Result<()> {
+    // 10 streams of sorted runs of the keys `x % 7`; equal keys are taken by stream
+    let streams = (0..10)
+        .map(|stream| {
+            let mut keys = (0..20).map(|x| (x * 31 + stream) % 7).collect::<Vec<_>>();
+            keys.sort_unstable();
+            keys.chunks(3)
+                .map(|run| {
+                    let keys = Int32Array::from_slice(run);
+                    let streams = Int32Array::from_iter(run.iter().map(|_| Some(stream)));
+                    Ok(Chunk::new(vec![
+                        Arc::new(keys) as Arc<dyn Array>,
+                        Arc::new(streams) as Arc<dyn Array>,
+                    ]))
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+        })
+        .collect();
+
+    let key = SortKey {
+        column: 0,
+        options: SortOptions::default(),
+    };
+    let merged = merge_streams(streams, vec![key], 16).collect::<Result<Vec<_>>>()?;
+    assert_eq!(merged.iter().map(|x| x.len()).sum::<usize>(), 200);
+
+    let rows = merged
+        .iter()
+        .flat_map(|chunk| {
+            let keys = chunk.arrays()[0]
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            let streams = chunk.arrays()[1]
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            keys.values()
+                .iter()
+                .zip(streams.values().iter())
+                .map(|(k, s)| (*k, *s))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut expected = rows.clone();
+    expected.sort_unstable();
+    assert_eq!(rows, expected);
+    Ok(())
+}
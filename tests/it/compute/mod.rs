@@ -2,6 +2,7 @@
 mod aggregate;
 #[cfg(feature = "compute_arithmetics")]
 mod arithmetics;
+mod arity;
 #[cfg(feature = "compute_bitwise")]
 mod bitwise;
 #[cfg(feature = "compute_boolean")]
@@ -10,14 +11,22 @@ mod boolean;
 mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 mod cast;
+#[cfg(feature = "compute_coalesce")]
+mod coalesce;
 #[cfg(feature = "compute_comparison")]
 mod comparison;
 #[cfg(feature = "compute_concatenate")]
 mod concatenate;
 #[cfg(feature = "compute_contains")]
 mod contains;
+#[cfg(feature = "compute_distinct")]
+mod distinct;
+#[cfg(feature = "compute_encoding")]
+mod encoding;
 #[cfg(feature = "compute_filter")]
 mod filter;
+#[cfg(feature = "compute_group")]
+mod group;
 #[cfg(feature = "compute_hash")]
 mod hash;
 #[cfg(feature = "compute_if_then_else")]
@@ -28,12 +37,22 @@ mod length;
 mod like;
 #[cfg(feature = "compute_limit")]
 mod limit;
+#[cfg(feature = "compute_map")]
+mod map;
+#[cfg(feature = "compute_math")]
+mod math;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
+#[cfg(feature = "compute_nullif")]
+mod nullif;
+#[cfg(feature = "compute_parse")]
+mod parse;
 #[cfg(feature = "compute_partition")]
 mod partition;
 #[cfg(feature = "compute_regex_match")]
 mod regex_match;
+#[cfg(feature = "compute_selection")]
+mod selection;
 #[cfg(feature = "compute_sort")]
 mod sort;
 #[cfg(feature = "compute_substring")]
@@ -0,0 +1,53 @@
+use arrow2::array::*;
+use arrow2::compute::nullif::*;
+use arrow2::datatypes::DataType;
+use arrow2::scalar::Utf8Scalar;
+
+#[test]
+fn primitive() {
+    let lhs = Int32Array::from([None, Some(1), Some(2)]);
+    let rhs = Int32Array::from([Some(1), Some(1), Some(3)]);
+    let result = nullif(&lhs, &rhs);
+
+    let expected = Int32Array::from([None, None, Some(2)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8() {
+    let lhs = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("d")]);
+    let rhs = Utf8Array::<i32>::from([Some("a"), Some("c"), Some("c"), None]);
+    let result = nullif(&lhs, &rhs);
+
+    let expected = Utf8Array::<i32>::from([None, Some("b"), None, Some("d")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn boolean() {
+    let lhs = BooleanArray::from([Some(true), Some(false), Some(true)]);
+    let rhs = BooleanArray::from([Some(true), Some(true), None]);
+    let result = nullif(&lhs, &rhs);
+
+    let expected = BooleanArray::from([None, Some(false), Some(true)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8_scalar() {
+    let lhs = Utf8Array::<i32>::from([Some("a"), Some("b"), None]);
+    let result = nullif_scalar(&lhs, &Utf8Scalar::<i32>::new(Some("a")));
+
+    let expected = Utf8Array::<i32>::from([None, Some("b"), None]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = nullif_scalar(&lhs, &Utf8Scalar::<i32>::new(None::<&str>));
+    assert_eq!(lhs, result.as_ref());
+}
+
+#[test]
+fn can() {
+    assert!(can_nullif(&DataType::Int32, &DataType::Int32));
+    assert!(can_nullif(&DataType::Utf8, &DataType::Utf8));
+    assert!(!can_nullif(&DataType::Utf8, &DataType::Int32));
+}
@@ -0,0 +1,150 @@
+use arrow2::array::*;
+use arrow2::compute::parse::*;
+use arrow2::datatypes::DataType;
+use arrow2::error::Result;
+
+#[test]
+fn int() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("1"), Some("-20"), Some("+3"), Some("a"), None]);
+    let result = parse_int::<i32, _>(&array, ParseOptions::default())?;
+    assert_eq!(
+        result,
+        Int32Array::from([Some(1), Some(-20), Some(3), None, None])
+    );
+    Ok(())
+}
+
+#[test]
+fn int_overflow() -> Result<()> {
+    let array = Utf8Array::<i32>::from_slice(["255", "256", "-1"]);
+    let result = parse_int::<u8, _>(&array, ParseOptions::default())?;
+    assert_eq!(result, UInt8Array::from([Some(255), None, None]));
+    Ok(())
+}
+
+#[test]
+fn thousands_separator() -> Result<()> {
+    let array = Utf8Array::<i64>::from_slice(["1,000", "1,000,000", "10"]);
+    let options = ParseOptions {
+        thousands_separator: Some(','),
+        ..Default::default()
+    };
+    let result = parse_int::<i64, _>(&array, options)?;
+    assert_eq!(result, Int64Array::from_slice([1000, 1000000, 10]));
+    Ok(())
+}
+
+#[test]
+fn whitespace() -> Result<()> {
+    let array = Utf8Array::<i32>::from_slice([" 1", "2 ", "\t3\n"]);
+    let result = parse_int::<i32, _>(&array, ParseOptions::default())?;
+    assert_eq!(result, Int32Array::from([None, None, None]));
+
+    let options = ParseOptions {
+        trim: true,
+        ..Default::default()
+    };
+    let result = parse_int::<i32, _>(&array, options)?;
+    assert_eq!(result, Int32Array::from_slice([1, 2, 3]));
+    Ok(())
+}
+
+#[test]
+fn decimal_comma() -> Result<()> {
+    let array = Utf8Array::<i32>::from_slice(["1.234,5", "0,25", "1.5", "1 000,5"]);
+    let options = ParseOptions {
+        thousands_separator: Some('.'),
+        decimal_separator: ',',
+        ..Default::default()
+    };
+    let result = parse_float::<f64, _>(&array, options)?;
+    assert_eq!(
+        result,
+        Float64Array::from([Some(1234.5), Some(0.25), Some(15.0), None])
+    );
+
+    // without a thousands separator, `.` is invalid
+    let options = ParseOptions {
+        decimal_separator: ',',
+        ..Default::default()
+    };
+    let result = parse_float::<f32, _>(&array, options)?;
+    assert_eq!(result, Float32Array::from([None, Some(0.25), None, None]));
+    Ok(())
+}
+
+#[test]
+fn on_error() {
+    let array = Utf8Array::<i32>::from([Some("1"), None, Some("a")]);
+    let options = ParseOptions {
+        on_error: OnError::Error,
+        ..Default::default()
+    };
+    assert!(parse_int::<i32, _>(&array, options).is_err());
+
+    let array = Utf8Array::<i32>::from([Some("1"), None]);
+    assert_eq!(
+        parse_int::<i32, _>(&array, options).unwrap(),
+        Int32Array::from([Some(1), None])
+    );
+}
+
+#[test]
+fn same_separators() {
+    let array = Utf8Array::<i32>::from_slice(["1"]);
+    let options = ParseOptions {
+        thousands_separator: Some('.'),
+        ..Default::default()
+    };
+    assert!(parse_float::<f64, _>(&array, options).is_err());
+}
+
+#[test]
+fn decimal() -> Result<()> {
+    let array = Utf8Array::<i32>::from_slice([
+        "1", "-1.5", "+.25", "12.340", "0.125", "1000", "999.99", "-", ".", "1e2",
+    ]);
+    let result = parse_decimal(&array, 5, 2, ParseOptions::default())?;
+    let expected = Int128Array::from([
+        Some(100),
+        Some(-150),
+        Some(25),
+        Some(1234),
+        None,
+        None,
+        Some(99999),
+        None,
+        None,
+        Some(10000),
+    ])
+    .to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn decimal_invalid_scale() {
+    let array = Utf8Array::<i32>::from_slice(["1"]);
+    assert!(parse_decimal(&array, 2, 3, ParseOptions::default()).is_err());
+}
+
+#[test]
+fn dynamic() -> Result<()> {
+    let array = Utf8Array::<i64>::from([Some("1,5"), None]);
+    let options = ParseOptions {
+        decimal_separator: ',',
+        ..Default::default()
+    };
+
+    let result = parse(&array, &DataType::Float64, options)?;
+    let expected = Float64Array::from([Some(1.5), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = parse(&array, &DataType::Decimal(3, 1), options)?;
+    let expected = Int128Array::from([Some(15), None]).to(DataType::Decimal(3, 1));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    assert!(!can_parse(&DataType::LargeUtf8, &DataType::Utf8));
+    assert!(parse(&array, &DataType::Utf8, options).is_err());
+    Ok(())
+}
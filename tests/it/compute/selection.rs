@@ -0,0 +1,107 @@
+use arrow2::array::{BooleanArray, Int32Array, Int64Array};
+use arrow2::compute::selection::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn selection_vector() {
+    assert!(SelectionVector::try_new(vec![0, 2, 5]).is_ok());
+    assert!(SelectionVector::try_new(vec![0, 2, 2]).is_err());
+    assert!(SelectionVector::try_new(vec![3, 1]).is_err());
+
+    let mask = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+    let selection = SelectionVector::from_mask(&mask);
+    assert_eq!(selection.indices(), &[0, 3]);
+    assert_eq!(selection.to_bitmap(4), [true, false, false, true].into());
+
+    assert_eq!(SelectionVector::all(3).indices(), &[0, 1, 2]);
+    assert!(SelectionVector::all(0).is_empty());
+}
+
+#[test]
+fn refine() {
+    let selection = SelectionVector::try_new(vec![0, 1, 3]).unwrap();
+    // unselected rows of the mask are ignored
+    let mask = BooleanArray::from([Some(true), None, Some(true), Some(true)]);
+    assert_eq!(selection.refine(&mask).indices(), &[0, 3]);
+}
+
+#[test]
+fn arithmetic() {
+    let lhs = Int32Array::from([Some(10), Some(8), None, Some(6)]);
+    let rhs = Int32Array::from([Some(0), Some(2), Some(1), Some(3)]);
+    // row 0 is not selected and would otherwise divide by zero
+    let selection = SelectionVector::try_new(vec![1, 2, 3]).unwrap();
+
+    assert_eq!(
+        div(&lhs, &rhs, &selection),
+        Int32Array::from([None, Some(4), None, Some(2)])
+    );
+    assert_eq!(
+        add(&lhs, &rhs, &selection),
+        Int32Array::from([None, Some(10), None, Some(9)])
+    );
+    assert_eq!(
+        sub(&lhs, &rhs, &selection),
+        Int32Array::from([None, Some(6), None, Some(3)])
+    );
+    assert_eq!(
+        mul(&lhs, &rhs, &selection),
+        Int32Array::from([None, Some(16), None, Some(18)])
+    );
+}
+
+#[test]
+fn comparison() {
+    let lhs = Int32Array::from([Some(1), Some(2), None, Some(4)]);
+    let rhs = Int32Array::from([Some(1), Some(3), Some(1), Some(3)]);
+    let selection = SelectionVector::try_new(vec![0, 2, 3]).unwrap();
+
+    assert_eq!(
+        eq(&lhs, &rhs, &selection),
+        BooleanArray::from([Some(true), None, None, Some(false)])
+    );
+    assert_eq!(
+        neq(&lhs, &rhs, &selection),
+        BooleanArray::from([Some(false), None, None, Some(true)])
+    );
+    assert_eq!(
+        lt(&lhs, &rhs, &selection),
+        BooleanArray::from([Some(false), None, None, Some(false)])
+    );
+    assert_eq!(
+        lt_eq(&lhs, &rhs, &selection),
+        BooleanArray::from([Some(true), None, None, Some(false)])
+    );
+    assert_eq!(
+        gt(&lhs, &rhs, &selection),
+        BooleanArray::from([Some(false), None, None, Some(true)])
+    );
+    assert_eq!(
+        gt_eq(&lhs, &rhs, &selection),
+        BooleanArray::from([Some(true), None, None, Some(true)])
+    );
+    assert_eq!(
+        gt_scalar(&lhs, 1, &selection),
+        BooleanArray::from([Some(false), None, None, Some(true)])
+    );
+    assert_eq!(
+        eq_scalar(&lhs, 4, &selection),
+        BooleanArray::from([Some(false), None, None, Some(true)])
+    );
+}
+
+#[test]
+fn unary_selected() {
+    let array = Int32Array::from([Some(1), None, Some(3)]);
+    let selection = SelectionVector::try_new(vec![1, 2]).unwrap();
+    let result = unary(&array, &selection, |x| x as i64 * 2, DataType::Int64);
+    assert_eq!(result, Int64Array::from([None, None, Some(6)]));
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let array = Int32Array::from_slice([1, 2]);
+    let selection = SelectionVector::try_new(vec![2]).unwrap();
+    gt_scalar(&array, 1, &selection);
+}
@@ -603,3 +603,195 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn build_compare_options() {
+    use std::cmp::Ordering;
+
+    let left = Int32Array::from(&[Some(1), None, Some(3)]);
+    let right = Int32Array::from(&[None, Some(2)]);
+
+    let cmp = build_compare(&left, &right, SortOptions::default()).unwrap();
+    assert_eq!((cmp)(0, 0), Ordering::Greater);
+    assert_eq!((cmp)(1, 0), Ordering::Equal);
+    assert_eq!((cmp)(0, 1), Ordering::Less);
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let cmp = build_compare(&left, &right, options).unwrap();
+    assert_eq!((cmp)(0, 0), Ordering::Less);
+    assert_eq!((cmp)(1, 1), Ordering::Greater);
+    assert_eq!((cmp)(2, 1), Ordering::Less);
+
+    assert!(build_compare(&left, &Int64Array::from_slice([1]), options).is_err());
+}
+
+fn all_options() -> Vec<SortOptions> {
+    [(false, false), (false, true), (true, false), (true, true)]
+        .iter()
+        .map(|(descending, nulls_first)| SortOptions {
+            descending: *descending,
+            nulls_first: *nulls_first,
+        })
+        .collect()
+}
+
+/// checks that sorting with `limit` returns the first `limit` elements of the full sort
+fn check_limit(array: &dyn Array) {
+    for options in all_options() {
+        let full = sort(array, &options, None).unwrap();
+        for limit in 0..array.len() + 2 {
+            let expected = full.slice(0, limit.min(array.len()));
+
+            let result = sort(array, &options, Some(limit)).unwrap();
+            assert_eq!(result.as_ref(), expected.as_ref());
+
+            let indices = sort_to_indices::<i32>(array, &options, Some(limit)).unwrap();
+            let result = arrow2::compute::take::take(array, &indices).unwrap();
+            assert_eq!(result.as_ref(), expected.as_ref());
+        }
+    }
+}
+
+#[test]
+fn limit_primitive() {
+    check_limit(&Int32Array::from([
+        Some(3),
+        None,
+        Some(1),
+        Some(3),
+        Some(-2),
+        None,
+        Some(0),
+        Some(1),
+    ]));
+    check_limit(&Int32Array::from_slice([5, 1, 4, 1, 3, 9, 2, 6]));
+    check_limit(&Float64Array::from([
+        Some(1.5),
+        None,
+        Some(f64::INFINITY),
+        Some(-0.5),
+        Some(1.5),
+        None,
+        Some(2.0),
+    ]));
+}
+
+#[test]
+fn limit_utf8() {
+    check_limit(&Utf8Array::<i32>::from([
+        Some("c"),
+        None,
+        Some("a"),
+        Some("bb"),
+        Some("a"),
+        None,
+        Some(""),
+    ]));
+    check_limit(&Utf8Array::<i64>::from_slice(["d", "b", "a", "c", "b"]));
+}
+
+#[test]
+fn limit_boolean() {
+    let array = BooleanArray::from([None, Some(false), Some(true), Some(true), Some(false), None]);
+    check_limit(&array);
+
+    // booleans are sorted by a stable partition
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let indices = sort_to_indices::<i32>(&array, &options, Some(3)).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([2, 3, 1]));
+}
+
+#[test]
+fn dictionary_unsorted_values() {
+    let values = Utf8Array::<i32>::from_slice(["c", "a", "b"]);
+    let keys = Int32Array::from([Some(0), Some(1), Some(2), Some(0), None, Some(1)]);
+    let array = DictionaryArray::<i32>::from_data(keys, std::sync::Arc::new(values));
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let indices = sort_to_indices::<i32>(&array, &options, None).unwrap();
+    assert_eq!(
+        take_values(&array, &indices),
+        vec![Some("a"), Some("a"), Some("b"), Some("c"), Some("c"), None]
+    );
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+    let indices = sort_to_indices::<i32>(&array, &options, None).unwrap();
+    assert_eq!(
+        take_values(&array, &indices),
+        vec![None, Some("c"), Some("c"), Some("b"), Some("a"), Some("a")]
+    );
+}
+
+#[test]
+fn dictionary_primitive_values() {
+    let values = Int64Array::from([Some(10), None, Some(-3), Some(7)]);
+    let keys = UInt8Array::from([Some(0), Some(1), Some(2), Some(3), Some(2), None]);
+    let array = DictionaryArray::<u8>::from_data(keys, std::sync::Arc::new(values));
+    assert!(can_sort(array.data_type()));
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    let sorted = sort(&array, &options, None).unwrap();
+    let sorted = sorted
+        .as_any()
+        .downcast_ref::<DictionaryArray<u8>>()
+        .unwrap();
+    let sorted = (0..sorted.len())
+        .map(|i| {
+            let values = sorted
+                .values()
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .unwrap();
+            let key = sorted.keys().is_valid(i).then(|| sorted.keys().value(i));
+            key.filter(|key| values.is_valid(*key as usize))
+                .map(|key| values.value(key as usize))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sorted,
+        vec![None, None, Some(-3), Some(-3), Some(7), Some(10)]
+    );
+}
+
+#[test]
+fn limit_dictionary() {
+    let values = Utf8Array::<i32>::from_slice(["d", "b", "a", "c"]);
+    let keys = Int16Array::from([Some(0), None, Some(3), Some(1), Some(2), Some(3), None]);
+    let array = DictionaryArray::<i16>::from_data(keys, std::sync::Arc::new(values));
+    check_limit(&array);
+}
+
+/// the values of `array` gathered at `indices`
+fn take_values<'a>(array: &'a DictionaryArray<i32>, indices: &Int32Array) -> Vec<Option<&'a str>> {
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    indices
+        .values()
+        .iter()
+        .map(|i| {
+            let i = *i as usize;
+            array
+                .keys()
+                .is_valid(i)
+                .then(|| values.value(array.keys().value(i) as usize))
+        })
+        .collect()
+}
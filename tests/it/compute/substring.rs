@@ -296,6 +296,61 @@ fn without_nulls_large_binary() -> Result<()> {
     without_null_binarys::<i64>()
 }
 
+#[test]
+fn multi_byte_chars() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("héllo"), None, Some("💖ab"), Some("")]);
+
+    let cases = vec![
+        (1, None, vec![Some("éllo"), None, Some("ab"), Some("")]),
+        (0, Some(2), vec![Some("hé"), None, Some("💖a"), Some("")]),
+        (-3, None, vec![Some("llo"), None, Some("💖ab"), Some("")]),
+        (-4, Some(2), vec![Some("él"), None, Some("💖a"), Some("")]),
+    ];
+    for (start, length, expected) in cases {
+        let expected = Utf8Array::<i32>::from(&expected);
+        let result = substring_chars(&array, start, &length)?;
+        assert_eq!(expected, result.as_ref());
+        let result = substring(&array, start, &length)?;
+        assert_eq!(expected, result.as_ref());
+    }
+    Ok(())
+}
+
+#[test]
+fn ascii_chars() -> Result<()> {
+    let array = Utf8Array::<i64>::from([Some("hello"), None, Some("ab")]).sliced(1, 2);
+    assert!(array.is_ascii());
+
+    let result = substring_chars(&array, -1, &None)?;
+    let expected = Utf8Array::<i64>::from([None, Some("b")]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn bytes() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("héllo"), None, Some("ab")]);
+
+    let result = substring_bytes(&array, 3, &None)?;
+    let expected = Utf8Array::<i32>::from([Some("llo"), None, Some("")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = substring_bytes(&array, -3, &Some(2))?;
+    let expected = Utf8Array::<i32>::from([Some("ll"), None, Some("ab")]);
+    assert_eq!(expected, result.as_ref());
+
+    // "é" is two bytes long
+    assert!(substring_bytes(&array, 2, &None).is_err());
+    assert!(substring_bytes(&array, 0, &Some(2)).is_err());
+
+    let array = BinaryArray::<i64>::from_slice(["héllo"]);
+    let result = substring_bytes(&array, 2, &None)?;
+    let expected = BinaryArray::<i64>::from_slice([&"héllo".as_bytes()[2..]]);
+    assert_eq!(expected, result.as_ref());
+    assert!(substring_chars(&array, 0, &None).is_err());
+    Ok(())
+}
+
 #[test]
 fn consistency() {
     use arrow2::datatypes::DataType::*;
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use arrow2::compute::take::{can_take, take};
+use arrow2::compute::take::{can_take, take, take_ranges};
 use arrow2::datatypes::{DataType, Field, IntervalUnit};
 use arrow2::error::Result;
 use arrow2::{array::*, bitmap::MutableBitmap, types::NativeType};
@@ -313,3 +313,44 @@ fn test_nested() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn take_ranges_utf8() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("ccc"), Some("dd"), Some("e")]);
+
+    let result = take_ranges(&array, &[(2, 3), (0, 0), (0, 2)])?;
+
+    let expected = Utf8Array::<i32>::from([Some("ccc"), Some("dd"), Some("e"), Some("a"), None]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn take_ranges_sliced() -> Result<()> {
+    let array = Int32Array::from([Some(1), None, Some(3), Some(4), None]).slice(1, 4);
+
+    let result = take_ranges(&array, &[(1, 2), (3, 1)])?;
+
+    let expected = Int32Array::from([Some(3), Some(4), None]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn take_ranges_empty() -> Result<()> {
+    let array = BooleanArray::from_slice([true, false]);
+
+    let result = take_ranges(&array, &[])?;
+
+    assert_eq!(result.len(), 0);
+    assert_eq!(result.data_type(), &DataType::Boolean);
+    Ok(())
+}
+
+#[test]
+fn take_ranges_out_of_bounds() {
+    let array = Int32Array::from_slice([1, 2, 3]);
+
+    assert!(take_ranges(&array, &[(1, 3)]).is_err());
+    assert!(take_ranges(&array, &[(usize::MAX, 2)]).is_err());
+}
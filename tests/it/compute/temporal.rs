@@ -372,3 +372,82 @@ fn consistency_check<O: arrow2::types::NativeType>(
         }
     });
 }
+
+#[test]
+fn consistency_day_of_week() {
+    consistency_check(can_day_of_week, |array| {
+        day_of_week(array, WeekStart::Sunday)
+    });
+}
+
+#[test]
+fn day_of_week_week_start() {
+    // 1970-01-01 is a Thursday, 1970-01-04 a Sunday
+    let array = Int32Array::from([Some(0), None, Some(3)]).to(DataType::Date32);
+
+    let result = day_of_week(&array, WeekStart::Monday).unwrap();
+    assert_eq!(result, UInt32Array::from([Some(3), None, Some(6)]));
+
+    let result = day_of_week(&array, WeekStart::Sunday).unwrap();
+    assert_eq!(result, UInt32Array::from([Some(4), None, Some(0)]));
+}
+
+#[test]
+fn strftime_date_like() {
+    let array = Int64Array::from([Some(1_000_000_000), None])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let result = strftime(&array, "%Y-%m-%d %H:%M").unwrap();
+    assert_eq!(
+        result,
+        Utf8Array::<i32>::from([Some("2001-09-09 01:46"), None])
+    );
+
+    let array = Int64Array::from_slice([1_000_000_000]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+02:00".to_string()),
+    ));
+    let result = strftime(&array, "%H:%M %z").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from_slice(["03:46 +0200"]));
+
+    let array = Int64Array::from_slice([86_400_000]).to(DataType::Date64);
+    assert_eq!(
+        month_name(&array).unwrap(),
+        Utf8Array::<i32>::from_slice(["January"])
+    );
+    assert_eq!(
+        day_name(&array).unwrap(),
+        Utf8Array::<i32>::from_slice(["Friday"])
+    );
+}
+
+#[test]
+fn strftime_errors() {
+    let array = Int32Array::from_slice([0]).to(DataType::Date32);
+    assert!(strftime(&array, "%Y-%Q").is_err());
+    // no timezone to format
+    assert!(strftime(&array, "%z").is_err());
+
+    let array = Int32Array::from_slice([0]).to(DataType::Time32(TimeUnit::Second));
+    assert!(strftime(&array, "%H").is_err());
+}
+
+#[cfg(feature = "compute_temporal_locale")]
+#[test]
+fn strftime_localized_names() {
+    let array = Int32Array::from([Some(0), None]).to(DataType::Date32);
+
+    let result = month_name_localized(&array, "de_DE").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from([Some("Januar"), None]));
+
+    let result = day_name_localized(&array, "es_ES").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from([Some("jueves"), None]));
+
+    let array = Int64Array::from_slice([0]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+01:00".to_string()),
+    ));
+    let result = strftime_localized(&array, "%A %H:%M", "fr_FR").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from_slice(["jeudi 01:00"]));
+
+    assert!(month_name_localized(&array, "xx_XX").is_err());
+}
@@ -0,0 +1,198 @@
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::util::data_gen::{array, ChunkGenerator, DataGenOptions, LengthDistribution};
+use rand::{rngs::StdRng, SeedableRng};
+
+fn schema() -> Schema {
+    let entries = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ]);
+    Schema::from(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+        Field::new(
+            "d",
+            DataType::List(Box::new(Field::new("item", DataType::Float64, true))),
+            true,
+        ),
+        Field::new(
+            "e",
+            DataType::Struct(vec![
+                Field::new("x", DataType::Boolean, false),
+                Field::new("y", DataType::Decimal(10, 2), true),
+            ]),
+            true,
+        ),
+        Field::new(
+            "f",
+            DataType::Dictionary(IntegerType::UInt16, Box::new(DataType::LargeUtf8), false),
+            true,
+        ),
+        Field::new(
+            "g",
+            DataType::Map(Box::new(Field::new("entries", entries, false)), false),
+            true,
+        ),
+        Field::new(
+            "h",
+            DataType::Union(
+                vec![
+                    Field::new("a", DataType::Int8, true),
+                    Field::new("b", DataType::Binary, true),
+                ],
+                None,
+                UnionMode::Dense,
+            ),
+            false,
+        ),
+    ])
+}
+
+#[test]
+fn chunks() -> Result<()> {
+    let schema = schema();
+    let options = DataGenOptions {
+        num_rows: 250,
+        chunk_length: 100,
+        ..Default::default()
+    };
+
+    let generator = ChunkGenerator::new(&schema, options);
+    assert_eq!(generator.size_hint(), (3, Some(3)));
+    let chunks = generator.collect::<Result<Vec<_>>>()?;
+
+    let lengths = chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![100, 100, 50]);
+    for chunk in &chunks {
+        for (array, field) in chunk.arrays().iter().zip(schema.fields.iter()) {
+            assert_eq!(array.data_type(), field.data_type());
+            if !field.is_nullable {
+                assert_eq!(array.null_count(), 0);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn reproducible() -> Result<()> {
+    let schema = schema();
+    let options = DataGenOptions::default();
+
+    let lhs = ChunkGenerator::new(&schema, options).collect::<Result<Vec<_>>>()?;
+    let rhs = ChunkGenerator::new(&schema, options).collect::<Result<Vec<_>>>()?;
+    assert_eq!(lhs, rhs);
+
+    let options = DataGenOptions { seed: 1, ..options };
+    let other = ChunkGenerator::new(&schema, options).collect::<Result<Vec<_>>>()?;
+    assert!(lhs != other);
+    Ok(())
+}
+
+#[test]
+fn null_density() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let options = DataGenOptions {
+        null_density: 1.0,
+        ..Default::default()
+    };
+    let result = array(&DataType::Int32, 10, &options, &mut rng)?;
+    assert_eq!(result.null_count(), 10);
+
+    let options = DataGenOptions {
+        null_density: 0.0,
+        ..Default::default()
+    };
+    let result = array(&DataType::Utf8, 10, &options, &mut rng)?;
+    assert_eq!(result.null_count(), 0);
+    Ok(())
+}
+
+#[test]
+fn string_length() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let options = DataGenOptions {
+        null_density: 0.0,
+        string_length: LengthDistribution::Fixed(7),
+        ..Default::default()
+    };
+    let result = array(&DataType::Utf8, 20, &options, &mut rng)?;
+    let result = result.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    assert!(result.values_iter().all(|x| x.len() == 7));
+
+    let options = DataGenOptions {
+        string_length: LengthDistribution::Uniform(2, 4),
+        ..options
+    };
+    let result = array(&DataType::LargeBinary, 100, &options, &mut rng)?;
+    let result = result.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+    assert!(result.values_iter().all(|x| (2..=4).contains(&x.len())));
+    Ok(())
+}
+
+#[test]
+fn dictionary_cardinality() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let options = DataGenOptions {
+        dictionary_cardinality: 5,
+        ..Default::default()
+    };
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    let result = array(&data_type, 100, &options, &mut rng)?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(result.values().len(), 5);
+    assert!(result.keys().values().iter().all(|x| (0..5).contains(x)));
+
+    // the cardinality is capped by the key type
+    let options = DataGenOptions {
+        dictionary_cardinality: 1000,
+        ..options
+    };
+    let data_type = DataType::Dictionary(IntegerType::Int8, Box::new(DataType::Int64), false);
+    let result = array(&data_type, 10, &options, &mut rng)?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i8>>()
+        .unwrap();
+    assert_eq!(result.values().len(), 128);
+    Ok(())
+}
+
+#[test]
+fn extension_dictionary() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let data_type = DataType::Extension(
+        "ext".to_string(),
+        Box::new(DataType::Dictionary(
+            IntegerType::Int32,
+            Box::new(DataType::Utf8),
+            false,
+        )),
+        None,
+    );
+    assert!(array(&data_type, 10, &DataGenOptions::default(), &mut rng).is_err());
+}
+
+#[test]
+fn list_length() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(0);
+    let options = DataGenOptions {
+        null_density: 0.0,
+        list_length: LengthDistribution::Fixed(3),
+        ..Default::default()
+    };
+    let data_type = DataType::LargeList(Box::new(Field::new("item", DataType::Int16, true)));
+    let result = array(&data_type, 4, &options, &mut rng)?;
+    let result = result.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+    assert_eq!(result.offsets().as_slice(), &[0, 3, 6, 9, 12]);
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use arrow2::datatypes::*;
+
+#[test]
+fn map_of() {
+    let field = Field::map_of(
+        "a",
+        DataType::Utf8,
+        Field::new("value", DataType::Int32, true),
+        true,
+    );
+
+    let entries = Field::new(
+        "entries",
+        DataType::Struct(vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Int32, true),
+        ]),
+        false,
+    );
+    let expected = Field::new("a", DataType::Map(Box::new(entries), false), true);
+    assert_eq!(field, expected);
+}
+
+#[test]
+fn nested_fields() {
+    let field = Field::struct_of(
+        "a",
+        vec![
+            Field::list_of("b", Field::new("item", DataType::Int64, false), true),
+            Field::dictionary_of("c", IntegerType::UInt8, DataType::LargeUtf8, false),
+        ],
+        false,
+    );
+
+    let expected = Field::new(
+        "a",
+        DataType::Struct(vec![
+            Field::new(
+                "b",
+                DataType::List(Box::new(Field::new("item", DataType::Int64, false))),
+                true,
+            ),
+            Field::new(
+                "c",
+                DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::LargeUtf8), false),
+                false,
+            ),
+        ]),
+        false,
+    );
+    assert_eq!(field, expected);
+}
+
+#[test]
+fn schema_builder() {
+    let schema = Schema::builder()
+        .field(Field::new("a", DataType::Int32, true).with_metadata_entry("unit", "m"))
+        .fields(vec![
+            Field::new("b", DataType::Utf8, false),
+            Field::new("c", DataType::Boolean, true),
+        ])
+        .metadata("k1", "v1")
+        .metadata("k2", "v2")
+        .build();
+
+    let mut field_metadata = Metadata::new();
+    field_metadata.insert("unit".to_string(), "m".to_string());
+    let mut metadata = Metadata::new();
+    metadata.insert("k1".to_string(), "v1".to_string());
+    metadata.insert("k2".to_string(), "v2".to_string());
+    let expected = Schema::from(vec![
+        Field::new("a", DataType::Int32, true).with_metadata(field_metadata),
+        Field::new("b", DataType::Utf8, false),
+        Field::new("c", DataType::Boolean, true),
+    ])
+    .with_metadata(metadata);
+    assert_eq!(schema, expected);
+}
+
+#[test]
+fn from_str() {
+    let cases = [
+        ("Int32", DataType::Int32),
+        (
+            "Timestamp(ms, UTC)",
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".to_string())),
+        ),
+        (
+            "Timestamp(ns, \"+01:00\")",
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("+01:00".to_string())),
+        ),
+        ("Time64(us)", DataType::Time64(TimeUnit::Microsecond)),
+        (
+            "Interval(DayTime)",
+            DataType::Interval(IntervalUnit::DayTime),
+        ),
+        ("Decimal(10, 2)", DataType::Decimal(10, 2)),
+        (
+            "List<Struct<a: Int32>>",
+            DataType::List(Box::new(Field::new(
+                "item",
+                DataType::Struct(vec![Field::new("a", DataType::Int32, true)]),
+                true,
+            ))),
+        ),
+        (
+            "FixedSizeList(3)<values: Float32 not null>",
+            DataType::FixedSizeList(Box::new(Field::new("values", DataType::Float32, false)), 3),
+        ),
+        (
+            "Struct<\"a b\": Utf8, \"\\\"\": Boolean>",
+            DataType::Struct(vec![
+                Field::new("a b", DataType::Utf8, true),
+                Field::new("\"", DataType::Boolean, true),
+            ]),
+        ),
+        (
+            "Union(Dense, [1, 5])<a: Int8, b: Null>",
+            DataType::Union(
+                vec![
+                    Field::new("a", DataType::Int8, true),
+                    Field::new("b", DataType::Null, true),
+                ],
+                Some(vec![1, 5]),
+                UnionMode::Dense,
+            ),
+        ),
+        (
+            "Union(Sparse, [])<>",
+            DataType::Union(vec![], Some(vec![]), UnionMode::Sparse),
+        ),
+        (
+            "Dictionary(UInt8, ordered)<LargeUtf8>",
+            DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::LargeUtf8), true),
+        ),
+        (
+            "Extension(uuid, \"{}\")<FixedSizeBinary(16)>",
+            DataType::Extension(
+                "uuid".to_string(),
+                Box::new(DataType::FixedSizeBinary(16)),
+                Some("{}".to_string()),
+            ),
+        ),
+    ];
+    for (string, expected) in cases {
+        let data_type: DataType = string.parse().unwrap();
+        assert_eq!(data_type, expected);
+        assert_eq!(data_type.to_string(), string);
+    }
+
+    let data_type: DataType =
+        "Map(sorted)<entries: Struct<key: Utf8 NOT NULL, value: Int32> not null>"
+            .parse()
+            .unwrap();
+    let entries = Field::new(
+        "entries",
+        DataType::Struct(vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Int32, true),
+        ]),
+        false,
+    );
+    assert_eq!(data_type, DataType::Map(Box::new(entries), true));
+}
+
+#[test]
+fn from_str_errors() {
+    for string in [
+        "",
+        "Int",
+        "Int32 Int32",
+        "Timestamp(ms",
+        "Timestamp(m)",
+        "List<>",
+        "Struct<Int32>",
+        "Decimal(a, 1)",
+        "Utf8 not null",
+        "Extension(\"a)<Int32>",
+        "Union(Dense, 1)<a: Int8>",
+    ] {
+        assert!(string.parse::<DataType>().is_err(), "{}", string);
+    }
+
+    let nested = format!("{}Int32{}", "List<".repeat(100_000), ">".repeat(100_000));
+    assert!(nested.parse::<DataType>().is_err());
+}
@@ -0,0 +1,50 @@
+use std::error::Error;
+
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::{ArrowError, ErrorKind};
+
+#[test]
+fn kind() {
+    let error = ArrowError::LengthMismatch {
+        expected: 2,
+        found: 1,
+    };
+    assert_eq!(error.kind(), ErrorKind::LengthMismatch);
+    assert_eq!(error.to_string(), "Length mismatch: expected 2, found 1");
+
+    let error = ArrowError::DataTypeMismatch {
+        expected: Box::new(DataType::Int32),
+        found: Box::new(DataType::Utf8),
+    };
+    assert_eq!(error.kind(), ErrorKind::DataTypeMismatch);
+    assert_eq!(
+        error.to_string(),
+        "Data type mismatch: expected Int32, found Utf8"
+    );
+}
+
+#[test]
+fn source() {
+    let io = std::io::Error::new(std::io::ErrorKind::NotFound, "oh no");
+    let error = ArrowError::from(io);
+    assert_eq!(error.kind(), ErrorKind::Io);
+    assert_eq!(error.source().unwrap().to_string(), "oh no");
+
+    let error = ArrowError::from_external_error(std::fmt::Error);
+    assert!(error.source().unwrap().is::<std::fmt::Error>());
+
+    assert!(ArrowError::Overflow.source().is_none());
+}
+
+#[test]
+fn field_not_found() {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    assert_eq!(schema.index_of("b").unwrap(), 1);
+
+    let error = schema.index_of("c").unwrap_err();
+    assert!(matches!(&error, ArrowError::FieldNotFound(name) if name == "c"));
+    assert_eq!(error.to_string(), "Field \"c\" not found");
+}
@@ -1,6 +1,7 @@
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
-use arrow2::datatypes::{DataType, Field, TimeUnit};
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema, TimeUnit};
 use arrow2::{error::Result, ffi};
 use std::collections::BTreeMap;
 use std::sync::Arc;
@@ -236,3 +237,175 @@ fn extension() -> Result<()> {
     );
     test_round_trip_schema(field)
 }
+
+#[test]
+fn schema_dictionary_ordered() -> Result<()> {
+    let field = Field::new(
+        "a",
+        DataType::Dictionary(i8::KEY_TYPE, Box::new(DataType::Utf8), true),
+        true,
+    );
+    test_round_trip_schema(field)
+}
+
+#[test]
+fn extension_parameters() -> Result<()> {
+    let field = Field::new(
+        "a",
+        DataType::Extension(
+            "uuid".to_string(),
+            Box::new(DataType::FixedSizeBinary(16)),
+            Some("{\"version\": 4}".to_string()),
+        ),
+        false,
+    );
+    test_round_trip_schema(field)
+}
+
+#[test]
+fn extension_nested() -> Result<()> {
+    let mut metadata = BTreeMap::new();
+    metadata.insert("some".to_string(), "stuff".to_string());
+    let child = Field::new("b", DataType::Int32, false).with_metadata(metadata.clone());
+    let field = Field::new(
+        "a",
+        DataType::Extension(
+            "point".to_string(),
+            Box::new(DataType::Struct(vec![child.clone(), child])),
+            Some("parameters".to_string()),
+        ),
+        true,
+    )
+    .with_metadata(metadata);
+    test_round_trip_schema(field)?;
+
+    let field = Field::new(
+        "a",
+        DataType::Extension(
+            "labels".to_string(),
+            Box::new(DataType::Dictionary(
+                i32::KEY_TYPE,
+                Box::new(DataType::Utf8),
+                true,
+            )),
+            None,
+        ),
+        true,
+    );
+    test_round_trip_schema(field)
+}
+
+#[test]
+fn extension_dictionary_values() -> Result<()> {
+    let values = DataType::Extension("a".to_string(), Box::new(DataType::Utf8), None);
+    let field = Field::new(
+        "a",
+        DataType::Dictionary(u8::KEY_TYPE, Box::new(values), false),
+        true,
+    );
+    test_round_trip_schema(field)
+}
+
+#[test]
+fn schema_metadata() -> Result<()> {
+    let mut metadata = BTreeMap::new();
+    metadata.insert("some".to_string(), "stuff".to_string());
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true).with_metadata(metadata.clone()),
+        Field::new(
+            "b",
+            DataType::Dictionary(i32::KEY_TYPE, Box::new(DataType::Utf8), true),
+            false,
+        ),
+    ])
+    .with_metadata(metadata);
+
+    let schema_ptr = Box::into_raw(Box::new(ffi::ArrowSchema::empty()));
+    unsafe { ffi::export_schema_to_c(&schema, schema_ptr) };
+    let schema_ptr = unsafe { Box::from_raw(schema_ptr) };
+    let result = unsafe { ffi::import_schema_from_c(schema_ptr.as_ref())? };
+
+    assert_eq!(result, schema);
+    Ok(())
+}
+
+#[test]
+fn schema_not_struct() {
+    let field = Field::new("a", DataType::Int32, true);
+    let schema_ptr = Box::into_raw(Box::new(ffi::ArrowSchema::empty()));
+    unsafe { ffi::export_field_to_c(&field, schema_ptr) };
+    let schema_ptr = unsafe { Box::from_raw(schema_ptr) };
+    assert!(unsafe { ffi::import_schema_from_c(schema_ptr.as_ref()) }.is_err());
+}
+
+fn chunk() -> (Schema, Chunk<Arc<dyn Array>>) {
+    let mut metadata = BTreeMap::new();
+    metadata.insert("some".to_string(), "stuff".to_string());
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, false),
+    ])
+    .with_metadata(metadata);
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])) as Arc<dyn Array>,
+    ]);
+    (schema, chunk)
+}
+
+#[test]
+fn chunk_round_trip() -> Result<()> {
+    let (schema, chunk) = chunk();
+
+    let exported = ffi::export_chunk_to_c(chunk.clone(), &schema)?;
+    let (array, schema_ptr) = exported.into_inner();
+    let (result_schema, result) = unsafe { ffi::import_chunk_from_c(array, schema_ptr.as_ref())? };
+
+    assert_eq!(result_schema, schema);
+    assert_eq!(result, chunk);
+    Ok(())
+}
+
+#[test]
+fn chunk_moved_out_of_guard() -> Result<()> {
+    let (schema, chunk) = chunk();
+
+    let mut exported = ffi::export_chunk_to_c(chunk.clone(), &schema)?;
+    // a consumer moves the structs out of the pointers, leaving them released
+    let array = unsafe { std::ptr::replace(exported.array_ptr(), ffi::ArrowArray::empty()) };
+    let schema_ptr = unsafe { std::ptr::replace(exported.schema_ptr(), ffi::ArrowSchema::empty()) };
+    drop(exported);
+
+    let (_, result) = unsafe { ffi::import_chunk_from_c(Box::new(array), &schema_ptr)? };
+    assert_eq!(result, chunk);
+    Ok(())
+}
+
+#[test]
+fn chunk_invalid() {
+    let (schema, chunk) = chunk();
+    let schema = Schema::from(vec![schema.fields[1].clone(), schema.fields[0].clone()]);
+    assert!(ffi::export_chunk_to_c(chunk, &schema).is_err());
+}
+
+#[test]
+fn chunk_with_nulls() {
+    let (schema, chunk) = chunk();
+    let array = StructArray::from_data(
+        DataType::Struct(schema.fields.clone()),
+        chunk.into_arrays(),
+        Some([true, false, true].into()),
+    );
+    let field = Field::new("", array.data_type().clone(), false);
+
+    let array_ptr = Box::into_raw(Box::new(ffi::ArrowArray::empty()));
+    let schema_ptr = Box::into_raw(Box::new(ffi::ArrowSchema::empty()));
+    unsafe {
+        ffi::export_array_to_c(Arc::new(array), array_ptr);
+        ffi::export_field_to_c(&field, schema_ptr);
+    }
+    let array_ptr = unsafe { Box::from_raw(array_ptr) };
+    let schema_ptr = unsafe { Box::from_raw(schema_ptr) };
+
+    assert!(unsafe { ffi::import_chunk_from_c(array_ptr, schema_ptr.as_ref()) }.is_err());
+}
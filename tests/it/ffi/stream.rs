@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use arrow2::array::*;
-use arrow2::datatypes::Field;
-use arrow2::{error::Result, ffi};
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::{ArrowError, Result};
+use arrow2::ffi;
 
 fn _test_round_trip(arrays: Vec<Arc<dyn Array>>) -> Result<()> {
     let field = Field::new("a", arrays[0].data_type().clone(), true);
@@ -31,3 +32,82 @@ fn round_trip() -> Result<()> {
 
     _test_round_trip(vec![array.clone(), array.clone(), array])
 }
+
+/// A stream exported by [`ffi::export_iterator_send`], which can be consumed from other threads
+struct SendStream(Box<ffi::ArrowArrayStream>);
+
+// Safety: the stream only owns a `Send` iterator
+unsafe impl Send for SendStream {}
+
+impl SendStream {
+    fn new(iter: Box<dyn Iterator<Item = Result<Arc<dyn Array>>> + Send>, field: Field) -> Self {
+        let mut stream = Box::new(ffi::ArrowArrayStream::empty());
+        unsafe { ffi::export_iterator_send(iter, field, &mut *stream) }
+        Self(stream)
+    }
+
+    fn into_reader(self) -> Result<ffi::ArrowArrayStreamReader> {
+        unsafe { ffi::ArrowArrayStreamReader::try_new(self.0) }
+    }
+}
+
+#[test]
+fn round_trip_other_thread() -> Result<()> {
+    let array: Arc<dyn Array> = Arc::new(Int32Array::from([Some(2), None, Some(1)]));
+    let arrays = vec![array.clone(), array];
+    let field = Field::new("a", arrays[0].data_type().clone(), true);
+
+    let iter = Box::new(arrays.clone().into_iter().map(Ok));
+    let stream = SendStream::new(iter, field);
+
+    // consumers such as pyarrow and duckdb poll the stream from threads other than the producer's
+    let produced = std::thread::spawn(move || -> Result<Vec<Arc<dyn Array>>> {
+        let mut stream = stream.into_reader()?;
+        let mut produced = vec![];
+        while let Some(array) = unsafe { stream.next() } {
+            produced.push(array?.into());
+        }
+        Ok(produced)
+    })
+    .join()
+    .unwrap()?;
+
+    assert_eq!(produced, arrays);
+    Ok(())
+}
+
+fn first_error(iter: Box<dyn Iterator<Item = Result<Arc<dyn Array>>> + Send>) -> String {
+    let field = Field::new("a", DataType::Int32, true);
+    let stream = SendStream::new(iter, field);
+
+    std::thread::spawn(move || {
+        let mut stream = stream.into_reader().unwrap();
+        unsafe { stream.next() }.unwrap().unwrap_err().to_string()
+    })
+    .join()
+    .unwrap()
+}
+
+#[test]
+fn error_propagation() {
+    let error = std::io::Error::new(std::io::ErrorKind::BrokenPipe, "disk on fire");
+    let iter = Box::new(std::iter::once(Err(ArrowError::Io(error))));
+    let message = first_error(iter);
+    assert!(message.contains("disk on fire"));
+    assert!(message.contains("(error code 5)"));
+
+    let iter = Box::new(std::iter::once(Err(ArrowError::InvalidArgumentError(
+        "bad".to_string(),
+    ))));
+    let message = first_error(iter);
+    assert!(message.contains("bad"));
+    assert!(message.contains("(error code 22)"));
+}
+
+#[test]
+fn data_type_mismatch() {
+    let array: Arc<dyn Array> = Arc::new(Utf8Array::<i32>::from_slice(["a"]));
+    let message = first_error(Box::new(std::iter::once(Ok(array))));
+    assert!(message.contains("Data type mismatch"));
+    assert!(message.contains("(error code 22)"));
+}
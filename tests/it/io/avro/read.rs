@@ -61,7 +61,11 @@ pub(super) fn schema() -> (AvroSchema, Schema) {
         Field::new("d", DataType::Binary, false),
         Field::new("e", DataType::Float64, false),
         Field::new("f", DataType::Boolean, false),
-        Field::new("g", DataType::Utf8, true),
+        Field::new("g", DataType::Utf8, true).with_metadata(
+            [("avro::default".to_string(), "null".to_string())]
+                .into_iter()
+                .collect(),
+        ),
         Field::new(
             "h",
             DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
@@ -279,3 +283,78 @@ fn read_projected() -> Result<()> {
         true, false, false, false, false, false, false, false, false, false, false, false,
     ])
 }
+
+#[test]
+fn read_records() -> Result<()> {
+    let raw_schema = r#"
+    {
+        "type": "record",
+        "name": "test",
+        "fields": [
+            {"name": "a", "type": "long"},
+            {"name": "b", "type": ["null", "string"]},
+            {"name": "c", "type": "double"}
+        ]
+    }
+"#;
+    let avro_schema = AvroSchema::parse_str(raw_schema).unwrap();
+
+    let messages = [(1i64, Some("foo"), 1.5f64), (-2, None, 2.5)]
+        .iter()
+        .map(|(a, b, c)| {
+            let mut record = Record::new(&avro_schema).unwrap();
+            record.put("a", *a);
+            record.put("b", b.map(|b| b.to_string()));
+            record.put("c", *c);
+            let mut message = vec![0, 0, 0, 0, 42];
+            message.extend(avro_rs::to_avro_datum(&avro_schema, record).unwrap());
+            message
+        })
+        .collect::<Vec<_>>();
+
+    let (avro_schemas, schema) = read::parse_schema(raw_schema.as_bytes())?;
+    assert_eq!(
+        schema.fields,
+        vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Float64, false),
+        ]
+    );
+
+    let records = messages
+        .iter()
+        .map(|message| {
+            read::split_confluent_header(message).map(|(id, record)| {
+                assert_eq!(id, 42);
+                record
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let projection = [true, true, false];
+    let chunk = read::deserialize_records(records, &schema.fields, &avro_schemas, &projection)?;
+
+    let expected = Chunk::try_new(vec![
+        Arc::new(Int64Array::from_slice([1, -2])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("foo"), None])),
+    ])?;
+    assert_eq!(chunk, expected);
+    Ok(())
+}
+
+#[test]
+fn read_records_invalid() -> Result<()> {
+    let raw_schema =
+        r#"{"type": "record", "name": "test", "fields": [{"name": "a", "type": "long"}]}"#;
+    let (avro_schemas, schema) = read::parse_schema(raw_schema.as_bytes())?;
+
+    // 2 bytes beyond the record
+    let records = [[2u8, 2, 2].as_ref()];
+    assert!(read::deserialize_records(records, &schema.fields, &avro_schemas, &[true]).is_err());
+
+    assert!(read::split_confluent_header(&[1, 0, 0, 0, 1, 2]).is_err());
+    assert!(read::split_confluent_header(&[0, 0, 0]).is_err());
+    assert!(read::parse_schema(br#"{"type": "long"}"#).is_err());
+    Ok(())
+}
@@ -1,10 +1,12 @@
+use std::io::Cursor;
 use std::sync::Arc;
 
 use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
 use arrow2::chunk::Chunk;
 use arrow2::datatypes::*;
 use arrow2::error::Result;
-use arrow2::io::avro::{write, CompressedBlock};
+use arrow2::io::avro::{read, write, CompressedBlock};
 use arrow2::types::months_days_ns;
 
 use super::read::read_avro;
@@ -231,6 +233,125 @@ fn check_large_format() -> Result<()> {
     Ok(())
 }
 
+fn lists_schema() -> Schema {
+    let item = Box::new(Field::new("item", DataType::Int32, true));
+    Schema::from(vec![
+        Field::new("large_list", DataType::LargeList(item.clone()), true),
+        Field::new(
+            "fixed_size_list",
+            DataType::FixedSizeList(item.clone(), 2),
+            false,
+        ),
+        Field::new(
+            "fixed_size_list_nullable",
+            DataType::FixedSizeList(item.clone(), 2),
+            true,
+        ),
+        Field::new("list_sliced", DataType::List(item), false),
+    ])
+}
+
+fn lists_data() -> Chunk<Box<dyn Array>> {
+    let schema = lists_schema();
+    let data_type = |i: usize| schema.fields[i].data_type().clone();
+    let values = || {
+        Arc::new(Int32Array::from([
+            Some(1),
+            None,
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(6),
+            Some(7),
+            Some(8),
+        ])) as Arc<dyn Array>
+    };
+
+    Chunk::new(vec![
+        // the null list has values, which are not written
+        Box::new(ListArray::<i64>::new(
+            data_type(0),
+            vec![0, 2, 3, 3, 4].into(),
+            values(),
+            Some([true, false, true, true].into()),
+        )) as Box<dyn Array>,
+        Box::new(FixedSizeListArray::new(data_type(1), values(), None)),
+        Box::new(FixedSizeListArray::new(
+            data_type(2),
+            values(),
+            Some([false, true, true, false].into()),
+        )),
+        Box::new(
+            ListArray::<i32>::new(data_type(3), vec![0, 1, 3, 3, 4, 6].into(), values(), None)
+                .slice(1, 4),
+        ),
+    ])
+}
+
+fn lists_expected_data() -> Chunk<Box<dyn Array>> {
+    let data_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let list = |offsets: Vec<i32>, values: &[Option<i32>], validity: Option<Bitmap>| {
+        Box::new(ListArray::<i32>::new(
+            data_type.clone(),
+            offsets.into(),
+            Arc::new(Int32Array::from(values)),
+            validity,
+        )) as Box<dyn Array>
+    };
+
+    Chunk::new(vec![
+        list(
+            vec![0, 2, 2, 2, 3],
+            &[Some(1), None, Some(4)],
+            Some([true, false, true, true].into()),
+        ),
+        list(
+            vec![0, 2, 4, 6, 8],
+            &[
+                Some(1),
+                None,
+                Some(3),
+                Some(4),
+                Some(5),
+                Some(6),
+                Some(7),
+                Some(8),
+            ],
+            None,
+        ),
+        list(
+            vec![0, 0, 2, 4, 4],
+            &[Some(3), Some(4), Some(5), Some(6)],
+            Some([false, true, true, false].into()),
+        ),
+        list(
+            vec![0, 2, 2, 3, 5],
+            &[None, Some(3), Some(4), Some(5), Some(6)],
+            None,
+        ),
+    ])
+}
+
+#[test]
+fn lists() -> Result<()> {
+    let write_schema = lists_schema();
+    let write_data = lists_data();
+
+    let data = write_avro(&write_data, &write_schema, None)?;
+    let (result, read_schema) = read_avro(&data, None)?;
+
+    for (field, expected) in read_schema.fields.iter().zip(write_schema.fields.iter()) {
+        assert_eq!(field.is_nullable, expected.is_nullable);
+    }
+
+    let expected_data = lists_expected_data();
+    for (c1, c2) in result.columns().iter().zip(expected_data.columns().iter()) {
+        assert_eq!(c1.as_ref(), c2.as_ref());
+    }
+
+    Ok(())
+}
+
 fn struct_schema() -> Schema {
     Schema::from(vec![
         Field::new(
@@ -296,3 +417,307 @@ fn struct_() -> Result<()> {
 
     Ok(())
 }
+
+fn read_chunks(data: &[u8]) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    let mut file = data;
+    let (avro_schema, schema, codec, file_marker) = read::read_metadata(&mut file)?;
+    read::Reader::new(
+        read::Decompressor::new(read::BlockStreamIterator::new(file, file_marker), codec),
+        avro_schema,
+        schema.fields,
+        None,
+    )
+    .collect()
+}
+
+#[test]
+fn block_size_rows() -> Result<()> {
+    let expected = data();
+    let mut writer = write::FileWriter::try_new(vec![], &schema(), None)?;
+    writer.set_block_size(write::BlockSize::Rows(3));
+    writer.write(&expected)?;
+    writer.write(&expected)?;
+    let data = writer.into_inner()?;
+
+    let result = read_chunks(&data)?;
+    assert_eq!(
+        result.iter().map(|x| x.len()).collect::<Vec<_>>(),
+        vec![3, 1]
+    );
+    Ok(())
+}
+
+#[test]
+fn block_size_bytes() -> Result<()> {
+    let expected = data();
+    let mut writer = write::FileWriter::try_new(vec![], &schema(), None)?;
+    writer.set_block_size(write::BlockSize::Bytes(1));
+    writer.write(&expected)?;
+    let data = writer.into_inner()?;
+
+    // every row exceeds one byte
+    let result = read_chunks(&data)?;
+    assert_eq!(result.len(), 2);
+    for (c1, c2) in result[1].arrays().iter().zip(expected.arrays().iter()) {
+        assert_eq!(c1.as_ref(), c2.slice(1, 1).as_ref());
+    }
+    Ok(())
+}
+
+fn append(compression: Option<write::Compression>) -> Result<()> {
+    let expected = data();
+    let mut writer = write::FileWriter::try_new(Cursor::new(vec![]), &schema(), compression)?;
+    writer.write(&expected)?;
+    let file = writer.into_inner()?;
+
+    let mut writer = write::FileWriter::try_append(file, &schema())?;
+    assert_eq!(writer.compression(), compression);
+    writer.write(&expected)?;
+    let data = writer.into_inner()?.into_inner();
+
+    let result = read_chunks(&data)?;
+    assert_eq!(result.len(), 2);
+    for chunk in result {
+        for (c1, c2) in chunk.arrays().iter().zip(expected.arrays().iter()) {
+            assert_eq!(c1.as_ref(), c2.as_ref());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn append_no_compression() -> Result<()> {
+    append(None)
+}
+
+#[test]
+fn append_snappy() -> Result<()> {
+    append(Some(write::Compression::Snappy))
+}
+
+#[test]
+fn append_different_schema() -> Result<()> {
+    let mut writer = write::FileWriter::try_new(Cursor::new(vec![]), &schema(), None)?;
+    writer.write(&data())?;
+    let file = writer.into_inner()?;
+
+    let schema = Schema::from(vec![Field::new("int64", DataType::Int64, false)]);
+    assert!(write::FileWriter::try_append(file, &schema).is_err());
+    Ok(())
+}
+
+fn union_fields() -> Vec<Field> {
+    vec![
+        Field::new("", DataType::Int32, false),
+        Field::new("", DataType::Utf8, false),
+        Field::new("", DataType::Null, false),
+    ]
+}
+
+fn union_schema() -> Schema {
+    Schema::from(vec![Field::new(
+        "union",
+        DataType::Union(union_fields(), None, UnionMode::Dense),
+        false,
+    )])
+}
+
+fn union_data(mode: UnionMode) -> Chunk<Box<dyn Array>> {
+    let data_type = DataType::Union(union_fields(), None, mode);
+    let types = vec![0, 1, 2, 0, 1].into();
+    let array = if mode.is_sparse() {
+        UnionArray::from_data(
+            data_type,
+            types,
+            vec![
+                Arc::new(Int32Array::from([Some(1), None, None, Some(2), None])),
+                Arc::new(Utf8Array::<i32>::from([
+                    None,
+                    Some("a"),
+                    None,
+                    None,
+                    Some("b"),
+                ])),
+                Arc::new(NullArray::new_null(DataType::Null, 5)),
+            ],
+            None,
+        )
+    } else {
+        UnionArray::from_data(
+            data_type,
+            types,
+            vec![
+                Arc::new(Int32Array::from_slice([1, 2])),
+                Arc::new(Utf8Array::<i32>::from_slice(["a", "b"])),
+                Arc::new(NullArray::new_null(DataType::Null, 1)),
+            ],
+            Some(vec![0, 0, 0, 1, 1].into()),
+        )
+    };
+    Chunk::new(vec![Box::new(array) as Box<dyn Array>])
+}
+
+#[test]
+fn union() -> Result<()> {
+    let write_schema = union_schema();
+    let write_data = union_data(UnionMode::Dense);
+
+    let data = write_avro(&write_data, &write_schema, None)?;
+    let (result, read_schema) = read_avro(&data, None)?;
+
+    assert_eq!(read_schema, write_schema);
+    assert_eq!(result.arrays()[0].as_ref(), write_data.arrays()[0].as_ref());
+    Ok(())
+}
+
+#[test]
+fn union_sparse() -> Result<()> {
+    let write_schema = Schema::from(vec![Field::new(
+        "union",
+        DataType::Union(union_fields(), None, UnionMode::Sparse),
+        false,
+    )]);
+    let write_data = union_data(UnionMode::Sparse);
+
+    let data = write_avro(&write_data, &write_schema, None)?;
+    let (result, read_schema) = read_avro(&data, None)?;
+
+    // unions are read as dense unions
+    assert_eq!(read_schema, union_schema());
+    let expected = union_data(UnionMode::Dense);
+    assert_eq!(result.arrays()[0].as_ref(), expected.arrays()[0].as_ref());
+    Ok(())
+}
+
+#[test]
+fn union_unordered_offsets() -> Result<()> {
+    let schema = union_schema();
+    let array = UnionArray::from_data(
+        DataType::Union(union_fields(), None, UnionMode::Dense),
+        vec![0, 0, 1, 0].into(),
+        vec![
+            Arc::new(Int32Array::from_slice([1, 2])),
+            Arc::new(Utf8Array::<i32>::from_slice(["a"])),
+            Arc::new(NullArray::new_null(DataType::Null, 0)),
+        ],
+        Some(vec![1, 0, 0, 1].into()),
+    );
+    let chunk = Chunk::new(vec![Box::new(array) as Box<dyn Array>]);
+
+    let data = write_avro(&chunk, &schema, None)?;
+    let (result, _) = read_avro(&data, None)?;
+
+    let expected = UnionArray::from_data(
+        DataType::Union(union_fields(), None, UnionMode::Dense),
+        vec![0, 0, 1, 0].into(),
+        vec![
+            Arc::new(Int32Array::from_slice([2, 1, 2])),
+            Arc::new(Utf8Array::<i32>::from_slice(["a"])),
+            Arc::new(NullArray::new_null(DataType::Null, 0)),
+        ],
+        Some(vec![0, 1, 0, 2].into()),
+    );
+    assert_eq!(result.arrays()[0].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn union_nullable_field() {
+    let fields = vec![
+        Field::new("", DataType::Int32, true),
+        Field::new("", DataType::Utf8, false),
+    ];
+    let schema = Schema::from(vec![Field::new(
+        "union",
+        DataType::Union(fields, None, UnionMode::Dense),
+        false,
+    )]);
+    assert!(write::to_avro_schema(&schema).is_err());
+}
+
+fn metadata(entries: &[(&str, &str)]) -> Metadata {
+    entries
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn annotations() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int64, true).with_metadata(metadata(&[
+            ("avro::doc", "the a"),
+            ("avro::aliases", r#"["b","c"]"#),
+            ("avro::default", "null"),
+        ])),
+        Field::new(
+            "s",
+            DataType::Struct(vec![Field::new("x", DataType::Utf8, false)
+                .with_metadata(metadata(&[("avro::doc", "the x")]))]),
+            false,
+        ),
+    ];
+    let schema = Schema::from(fields).with_metadata(metadata(&[
+        ("avro::doc", "a record"),
+        ("avro::aliases", r#"["r"]"#),
+    ]));
+    let columns = Chunk::new(vec![
+        Arc::new(Int64Array::from([Some(1), None])) as Arc<dyn Array>,
+        Arc::new(StructArray::from_data(
+            schema.fields[1].data_type().clone(),
+            vec![Arc::new(Utf8Array::<i32>::from_slice(["a", "b"]))],
+            None,
+        )),
+    ]);
+
+    let mut writer = write::FileWriter::try_new(vec![], &schema, None)?;
+    writer.write(&columns)?;
+    let data = writer.into_inner()?;
+
+    let (_, read_schema, _, _) = read::read_metadata(&mut Cursor::new(&data))?;
+    assert_eq!(read_schema, schema);
+    assert_eq!(read_chunks(&data)?, vec![columns]);
+
+    // the default `null` is written as JSON's null
+    let json = String::from_utf8_lossy(&data);
+    assert!(json.contains(r#""default":null"#));
+    Ok(())
+}
+
+#[test]
+fn json_defaults() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int64, false).with_metadata(metadata(&[("avro::default", "1")])),
+        Field::new(
+            "s",
+            DataType::Struct(vec![Field::new("x", DataType::Utf8, true)
+                .with_metadata(metadata(&[("avro::default", r#""b""#)]))]),
+            true,
+        )
+        .with_metadata(metadata(&[("avro::default", r#"{"x": "c"}"#)])),
+    ];
+    let schema = Schema::from(fields);
+
+    let data = write::FileWriter::try_new(vec![], &schema, None)?.into_inner()?;
+
+    // the defaults are written as JSON values
+    let json = String::from_utf8_lossy(&data);
+    assert!(json.contains(r#""default":1"#));
+    assert!(json.contains(r#""default":"b""#));
+    assert!(json.contains(r#""default":{"x":"c"}"#));
+    Ok(())
+}
+
+#[test]
+fn invalid_annotations() {
+    let field =
+        Field::new("a", DataType::Int64, true).with_metadata(metadata(&[("avro::aliases", "b")]));
+    assert!(write::to_avro_schema(&Schema::from(vec![field])).is_err());
+}
+
+#[test]
+fn invalid_default() {
+    let field =
+        Field::new("a", DataType::Int64, true).with_metadata(metadata(&[("avro::default", "a")]));
+    assert!(write::FileWriter::try_new(vec![], &Schema::from(vec![field]), None).is_err());
+}
@@ -89,6 +89,61 @@ fn infer_ints() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn infer_boolean_tokens() -> Result<()> {
+    let file = Cursor::new("yes,1,true\nN,0,false\nno,1,false");
+    let mut reader = ReaderBuilder::new().from_reader(file);
+
+    let tokens = BooleanTokens::try_new(["yes", "y", "1"], ["no", "n", "0"])?;
+    let infer = |bytes: &[u8]| infer_with_booleans(bytes, &tokens);
+    let (fields, _) = infer_schema(&mut reader, Some(10), false, &infer)?;
+
+    assert_eq!(
+        fields,
+        vec![
+            Field::new("column_1", DataType::Boolean, true),
+            Field::new("column_2", DataType::Boolean, true),
+            Field::new("column_3", DataType::Utf8, true),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn boolean_tokens() -> Result<()> {
+    let input = "YES,1\nn,2\n,3\nmaybe,4\n";
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(Cursor::new(input));
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    let rows = &rows[..rows_read];
+
+    let options = DeserializeOptions {
+        boolean_tokens: BooleanTokens::try_new(["yes", "y"], ["no", "n"])?,
+        ..Default::default()
+    };
+    let result = deserialize_column_with(rows, 0, DataType::Boolean, 0, &options)?;
+    assert_eq!(
+        result.as_ref(),
+        &BooleanArray::from([Some(true), Some(false), None, None]) as &dyn Array
+    );
+
+    let options = DeserializeOptions {
+        strict: true,
+        ..options
+    };
+    assert!(deserialize_column_with(rows, 0, DataType::Boolean, 0, &options).is_err());
+    Ok(())
+}
+
+#[test]
+fn boolean_tokens_invalid() {
+    assert!(BooleanTokens::try_new(["yes", "1"], ["no", "1"]).is_err());
+    assert!(BooleanTokens::try_new(["Yes"], ["yes"]).is_err());
+    assert!(BooleanTokens::try_new(["", "yes"], ["no"]).is_err());
+}
+
 fn test_deserialize(input: &str, data_type: DataType) -> Result<Arc<dyn Array>> {
     let reader = std::io::Cursor::new(input);
     let mut reader = ReaderBuilder::new().has_headers(false).from_reader(reader);
@@ -218,6 +273,91 @@ fn deserialize_timestamp() -> Result<()> {
     Ok(())
 }
 
+fn test_deserialize_strict(input: &str, data_type: DataType) -> Result<Arc<dyn Array>> {
+    let reader = std::io::Cursor::new(input);
+    let mut reader = ReaderBuilder::new().has_headers(false).from_reader(reader);
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    deserialize_column_strict(&rows[..rows_read], 0, data_type, 1)
+}
+
+#[test]
+fn strict() -> Result<()> {
+    let result = test_deserialize_strict("1,\n,\n3,", DataType::Int32)?;
+    let expected = Int32Array::from([Some(1), None, Some(3)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = test_deserialize_strict("true,\n,\nFalse,", DataType::Boolean)?;
+    let expected = BooleanArray::from([Some(true), None, Some(false)]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn strict_errors() {
+    let error = test_deserialize_strict("1,\na,\n3,", DataType::Int32).unwrap_err();
+    assert!(error.to_string().contains("line 2"));
+
+    assert!(test_deserialize_strict("1.1\n1.22", DataType::Decimal(2, 1)).is_err());
+    assert!(test_deserialize_strict("true\nt", DataType::Boolean).is_err());
+    assert!(test_deserialize_strict("2020-03-15\n2020-13-15", DataType::Date32).is_err());
+}
+
+#[test]
+fn strict_utf8() -> Result<()> {
+    let input: &[u8] = b"a,\n,\n\xff,";
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(Cursor::new(input));
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    let rows = &rows[..rows_read];
+
+    let result = deserialize_column(rows, 0, DataType::Utf8, 0)?;
+    let expected = Utf8Array::<i32>::from([Some("a"), Some(""), None]);
+    assert_eq!(expected, result.as_ref());
+
+    assert!(deserialize_column_strict(rows, 0, DataType::Utf8, 0).is_err());
+    Ok(())
+}
+
+#[test]
+fn deserialize_options() -> Result<()> {
+    let input = "a,1\nb,x\n";
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(Cursor::new(input));
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    let rows = &rows[..rows_read];
+
+    let options = DeserializeOptions {
+        strict: false,
+        promote_large: true,
+        ..Default::default()
+    };
+    // values that fit in `i32` offsets are not promoted
+    let result = deserialize_column_with(rows, 0, DataType::Utf8, 0, &options)?;
+    assert_eq!(
+        result.as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b"]) as &dyn Array
+    );
+
+    let result = deserialize_column_with(rows, 1, DataType::Int32, 0, &options)?;
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from([Some(1), None]) as &dyn Array
+    );
+
+    let options = DeserializeOptions {
+        strict: true,
+        ..options
+    };
+    assert!(deserialize_column_with(rows, 1, DataType::Int32, 0, &options).is_err());
+    Ok(())
+}
+
 proptest! {
     #[test]
     #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
@@ -1,6 +1,7 @@
 use futures::io::Cursor;
 
 use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field};
 use arrow2::error::Result;
 use arrow2::io::csv::read_async::*;
 
@@ -48,3 +49,46 @@ async fn read() -> Result<()> {
     assert_eq!("Aberdeen, Aberdeen City, UK", city.value(13));
     Ok(())
 }
+
+#[tokio::test]
+async fn chunk_reader() -> Result<()> {
+    let data = "a,b,c\n1,x,1.0\n2,y,2.0\n3,z,3.0\n4,w,4.0\n5,v,5.0\n";
+    let mut reader = AsyncReaderBuilder::new().create_reader(Cursor::new(data.as_bytes()));
+    let (fields, _) = infer_schema(&mut reader, None, true, &infer).await?;
+
+    let reader = AsyncReaderBuilder::new().create_reader(Cursor::new(data.as_bytes()));
+    let mut chunks = ChunkReader::try_new(reader, fields, Some(vec![2, 0]), 2)?;
+    assert_eq!(
+        chunks
+            .fields()
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["c", "a"]
+    );
+
+    let mut lengths = vec![];
+    let mut a = vec![];
+    while let Some(chunk) = chunks.next_chunk().await? {
+        assert_eq!(chunk.arrays().len(), 2);
+        lengths.push(chunk.len());
+        let array = chunk.arrays()[1]
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        a.extend(array.values().iter().copied());
+    }
+    assert_eq!(lengths, vec![2, 2, 1]);
+    assert_eq!(a, vec![1, 2, 3, 4, 5]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn chunk_reader_invalid() {
+    let reader = AsyncReaderBuilder::new().create_reader(Cursor::new(b"a\n1\n".as_ref()));
+    let fields = vec![Field::new("a", DataType::Int64, true)];
+    assert!(ChunkReader::try_new(reader, fields.clone(), Some(vec![1]), 10).is_err());
+
+    let reader = AsyncReaderBuilder::new().create_reader(Cursor::new(b"a\n1\n".as_ref()));
+    assert!(ChunkReader::try_new(reader, fields, None, 0).is_err());
+}
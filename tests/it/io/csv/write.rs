@@ -222,6 +222,34 @@ fn data_array(column: usize) -> (Chunk<Arc<dyn Array>>, Vec<&'static str>) {
                 ],
             )
         }
+        18 => {
+            let array =
+                Int128Array::from([Some(12345), Some(-5), None]).to(DataType::Decimal(10, 2));
+            (
+                Arc::new(array) as Arc<dyn Array>,
+                vec!["123.45", "-0.05", ""],
+            )
+        }
+        19 => {
+            let array = FixedSizeBinaryArray::from_data(
+                DataType::FixedSizeBinary(2),
+                vec![0x0a, 0xff, 0, 1, 2, 3].into(),
+                Some([true, false, true].into()),
+            );
+            (Arc::new(array) as Arc<dyn Array>, vec!["0aff", "", "0203"])
+        }
+        20 => {
+            let keys = Int8Array::from([Some(1), None, Some(0), Some(2)]);
+            let values = Arc::new(Int32Array::from([Some(10), Some(20), None])) as Arc<dyn Array>;
+            let array = DictionaryArray::from_data(keys, values);
+            (Arc::new(array) as Arc<dyn Array>, vec!["20", "", "10", ""])
+        }
+        21 => {
+            let keys = UInt16Array::from_slice([1, 0, 1]);
+            let values = Arc::new(Utf8Array::<i32>::from_slice(["a,b", "c"])) as Arc<dyn Array>;
+            let array = DictionaryArray::from_data(keys, values);
+            (Arc::new(array) as Arc<dyn Array>, vec!["c", "\"a,b\"", "c"])
+        }
         _ => todo!(),
     };
 
@@ -262,6 +290,28 @@ fn write_each() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn write_fixed_size_binary_and_dictionaries() -> Result<()> {
+    for i in 19..=21 {
+        write_single(i)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn write_unsupported() {
+    let array = Arc::new(ListArray::<i32>::new_empty(DataType::List(Box::new(
+        Field::new("item", DataType::Int32, true),
+    )))) as Arc<dyn Array>;
+    let mut writer = Cursor::new(Vec::<u8>::new());
+    let result = write_chunk(
+        &mut writer,
+        &Chunk::new(vec![array]),
+        &SerializeOptions::default(),
+    );
+    assert!(result.is_err());
+}
+
 #[test]
 #[cfg(feature = "chrono-tz")]
 fn write_tz_timezone() -> Result<()> {
@@ -0,0 +1,289 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema, TimeUnit};
+use arrow2::error::{ArrowError, Result};
+use arrow2::io::feather::read::{read, read_metadata};
+
+/// A field of a flatbuffer table
+enum Value {
+    U8(u8),
+    I64(i64),
+    Str(&'static str),
+    Table(Vec<Option<Value>>),
+    Tables(Vec<Vec<Option<Value>>>),
+}
+
+fn patch(buf: &mut [u8], position: usize, target: usize) {
+    let offset = (target - position) as u32;
+    buf[position..position + 4].copy_from_slice(&offset.to_le_bytes());
+}
+
+/// Writes a flatbuffer table to `buf`, with its vtable before it and its children after it,
+/// and returns its position.
+fn write_table(buf: &mut Vec<u8>, fields: &[Option<Value>]) -> usize {
+    let vtable = buf.len();
+    buf.extend_from_slice(&((4 + 2 * fields.len()) as u16).to_le_bytes());
+    buf.extend_from_slice(&[0, 0]);
+    buf.resize(vtable + 4 + 2 * fields.len(), 0);
+
+    let table = buf.len();
+    buf.extend_from_slice(&((table - vtable) as i32).to_le_bytes());
+    let mut children = vec![];
+    for (i, field) in fields.iter().enumerate() {
+        let field = match field {
+            Some(field) => field,
+            None => continue,
+        };
+        let position = buf.len();
+        buf[vtable + 4 + 2 * i..vtable + 6 + 2 * i]
+            .copy_from_slice(&((position - table) as u16).to_le_bytes());
+        match field {
+            Value::U8(value) => buf.push(*value),
+            Value::I64(value) => buf.extend_from_slice(&value.to_le_bytes()),
+            _ => {
+                buf.extend_from_slice(&[0; 4]);
+                children.push((position, field));
+            }
+        }
+    }
+    let length = (buf.len() - table) as u16;
+    buf[vtable + 2..vtable + 4].copy_from_slice(&length.to_le_bytes());
+
+    for (position, field) in children {
+        match field {
+            Value::Str(value) => {
+                let string = buf.len();
+                patch(buf, position, string);
+                buf.extend_from_slice(&(value.len() as u32).to_le_bytes());
+                buf.extend_from_slice(value.as_bytes());
+                buf.push(0);
+            }
+            Value::Table(fields) => {
+                let child = write_table(buf, fields);
+                patch(buf, position, child);
+            }
+            Value::Tables(tables) => {
+                let vector = buf.len();
+                patch(buf, position, vector);
+                buf.extend_from_slice(&(tables.len() as u32).to_le_bytes());
+                buf.resize(vector + 4 + 4 * tables.len(), 0);
+                for (i, fields) in tables.iter().enumerate() {
+                    let child = write_table(buf, fields);
+                    patch(buf, vector + 4 + 4 * i, child);
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+    table
+}
+
+/// Appends `buffers` (padded to 8 bytes) to the body and returns the corresponding
+/// `PrimitiveArray` table.
+fn array(
+    body: &mut Vec<u8>,
+    type_: u8,
+    length: i64,
+    null_count: i64,
+    buffers: &[&[u8]],
+) -> Vec<Option<Value>> {
+    let offset = body.len();
+    for buffer in buffers {
+        body.extend_from_slice(buffer);
+        body.resize(body.len() + (8 - body.len() % 8) % 8, 0);
+    }
+    vec![
+        Some(Value::U8(type_)),
+        None,
+        Some(Value::I64(offset as i64)),
+        Some(Value::I64(length)),
+        Some(Value::I64(null_count)),
+        Some(Value::I64((body.len() - offset) as i64)),
+    ]
+}
+
+fn column(
+    name: &'static str,
+    values: Vec<Option<Value>>,
+    metadata: Option<(u8, Vec<Option<Value>>)>,
+) -> Vec<Option<Value>> {
+    let (metadata_type, metadata) = metadata
+        .map(|(type_, metadata)| (Some(Value::U8(type_)), Some(Value::Table(metadata))))
+        .unwrap_or((None, None));
+    vec![
+        Some(Value::Str(name)),
+        Some(Value::Table(values)),
+        metadata_type,
+        metadata,
+    ]
+}
+
+/// Returns a Feather v1 file
+fn write_file(mut body: Vec<u8>, num_rows: i64, columns: Vec<Vec<Option<Value>>>) -> Vec<u8> {
+    let mut metadata = vec![0; 4];
+    let root = write_table(
+        &mut metadata,
+        &[
+            Some(Value::Str("a description")),
+            Some(Value::I64(num_rows)),
+            Some(Value::Tables(columns)),
+        ],
+    );
+    patch(&mut metadata, 0, root);
+
+    body.extend_from_slice(&metadata);
+    body.extend_from_slice(&(metadata.len() as u32).to_le_bytes());
+    body.extend_from_slice(b"FEA1");
+    body
+}
+
+fn file() -> Vec<u8> {
+    let mut body = b"FEA1\0\0\0\0".to_vec();
+
+    let int32 = array(
+        &mut body,
+        3,
+        3,
+        1,
+        &[&[0b101], &[1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0]],
+    );
+    let utf8 = array(
+        &mut body,
+        11,
+        3,
+        0,
+        &[&[0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 3, 0, 0, 0], b"abb"],
+    );
+    let indices = array(&mut body, 1, 3, 0, &[&[1, 0, 1]]);
+    let levels = array(
+        &mut body,
+        11,
+        2,
+        0,
+        &[&[0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0], b"xy"],
+    );
+    let timestamp = array(
+        &mut body,
+        14,
+        3,
+        0,
+        &[&[
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
+        ]],
+    );
+
+    let columns = vec![
+        column("int32", int32, None),
+        column("utf8", utf8, None),
+        column(
+            "category",
+            indices,
+            Some((1, vec![Some(Value::Table(levels))])),
+        ),
+        column(
+            "timestamp",
+            timestamp,
+            Some((2, vec![Some(Value::U8(1)), Some(Value::Str("+01:00"))])),
+        ),
+    ];
+    write_file(body, 3, columns)
+}
+
+#[test]
+fn read_metadata_v1() -> Result<()> {
+    let metadata = read_metadata(&mut Cursor::new(file()))?;
+
+    let expected = Schema::from(vec![
+        Field::new("int32", DataType::Int32, true),
+        Field::new("utf8", DataType::Utf8, true),
+        Field::new(
+            "category",
+            DataType::Dictionary(
+                arrow2::datatypes::IntegerType::Int8,
+                Box::new(DataType::Utf8),
+                false,
+            ),
+            true,
+        ),
+        Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string())),
+            true,
+        ),
+    ]);
+    assert_eq!(expected, metadata.schema);
+    assert_eq!(3, metadata.num_rows);
+    assert_eq!(Some("a description".to_string()), metadata.description);
+    Ok(())
+}
+
+#[test]
+fn read_v1() -> Result<()> {
+    let mut reader = Cursor::new(file());
+    let metadata = read_metadata(&mut reader)?;
+    let chunk = read(&mut reader, &metadata, None)?;
+
+    let keys = PrimitiveArray::<i8>::from_slice([1, 0, 1]);
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["x", "y"]));
+    let expected = Chunk::new(vec![
+        Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "bb", ""])),
+        Arc::new(DictionaryArray::<i8>::from_data(keys, values)),
+        Arc::new(Int64Array::from_slice([1, 2, 3]).to(DataType::Timestamp(
+            TimeUnit::Millisecond,
+            Some("+01:00".to_string()),
+        ))),
+    ]);
+    assert_eq!(expected, chunk);
+    Ok(())
+}
+
+#[test]
+fn read_projection() -> Result<()> {
+    let mut reader = Cursor::new(file());
+    let metadata = read_metadata(&mut reader)?;
+    let chunk = read(&mut reader, &metadata, Some(&[1]))?;
+
+    let expected = Chunk::new(vec![
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "bb", ""])) as Arc<dyn Array>,
+    ]);
+    assert_eq!(expected, chunk);
+
+    // columns are returned in the order of the file
+    let chunk = read(&mut reader, &metadata, Some(&[1, 0]))?;
+    let expected = Chunk::new(vec![
+        Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "bb", ""])),
+    ]);
+    assert_eq!(expected, chunk);
+    Ok(())
+}
+
+#[test]
+fn out_of_bounds_buffer() -> Result<()> {
+    let mut body = b"FEA1\0\0\0\0".to_vec();
+    let mut values = array(&mut body, 3, 3, 0, &[&[1, 0, 0, 0]]);
+    values[5] = Some(Value::I64(1024));
+    let data = write_file(body, 3, vec![column("int32", values, None)]);
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    assert!(matches!(
+        read(&mut reader, &metadata, None),
+        Err(ArrowError::OutOfSpec(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn v2_is_ipc() {
+    let mut data = b"ARROW1\0\0".to_vec();
+    data.extend_from_slice(&[0; 8]);
+    assert!(matches!(
+        read_metadata(&mut Cursor::new(data)),
+        Err(ArrowError::ExternalFormat(_))
+    ));
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::flight::*;
+use arrow2::io::ipc::read::Dictionaries;
+use arrow2::io::ipc::write::{default_ipc_fields, WriteOptions};
+
+fn data() -> Result<(Schema, Chunk<Arc<dyn Array>>)> {
+    let a = Int64Array::from_values(0..1000);
+    let b =
+        Utf8Array::<i32>::from_iter((0..1000).map(|x| Some(x.to_string()).filter(|_| x % 3 > 0)));
+    let mut c = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    c.try_extend((0..1000).map(|x| Some(format!("value {}", x % 10))))?;
+    let c: DictionaryArray<i32> = c.into();
+
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), false),
+        Field::new("b", b.data_type().clone(), true),
+        Field::new("c", c.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::try_new(vec![
+        Arc::new(a) as Arc<dyn Array>,
+        Arc::new(b),
+        Arc::new(c),
+    ])?;
+    Ok((schema, chunk))
+}
+
+fn round_trip(max_message_size: usize) -> Result<usize> {
+    let (schema, chunk) = data()?;
+    let ipc_fields = default_ipc_fields(&schema.fields);
+    let options = WriteOptions { compression: None };
+
+    let (dictionaries, batches) =
+        serialize_batch_with_limit(&chunk, &ipc_fields, &options, max_message_size)?;
+    assert_eq!(dictionaries.len(), 1);
+    for message in dictionaries.iter().chain(batches.iter()) {
+        assert!(message.data_header.len() + message.data_body.len() <= max_message_size);
+    }
+
+    let (schema, ipc_schema) = deserialize_schemas(&serialize_schema(&schema, None).data_header)?;
+    let mut dictionaries_ = Dictionaries::new();
+    let result = deserialize_batches(
+        dictionaries.iter().chain(batches.iter()),
+        &schema.fields,
+        &ipc_schema,
+        &mut dictionaries_,
+    )?;
+    assert_eq!(result, chunk);
+    Ok(batches.len())
+}
+
+#[test]
+fn single_message() -> Result<()> {
+    assert_eq!(round_trip(1_000_000)?, 1);
+    Ok(())
+}
+
+#[test]
+fn split_messages() -> Result<()> {
+    assert!(round_trip(2_000)? > 1);
+    Ok(())
+}
+
+#[test]
+fn row_too_large() -> Result<()> {
+    let (schema, chunk) = data()?;
+    let ipc_fields = default_ipc_fields(&schema.fields);
+    let options = WriteOptions { compression: None };
+    assert!(serialize_batch_with_limit(&chunk, &ipc_fields, &options, 100).is_err());
+    Ok(())
+}
+
+#[test]
+fn no_batches() -> Result<()> {
+    let (schema, chunk) = data()?;
+    let (schema, ipc_schema) = deserialize_schemas(&serialize_schema(&schema, None).data_header)?;
+    let result = deserialize_batches([], &schema.fields, &ipc_schema, &mut Dictionaries::new())?;
+    assert!(result.is_empty());
+    assert_eq!(result.arrays().len(), chunk.arrays().len());
+    Ok(())
+}
+
+fn dictionary_chunk(values: &[&str]) -> Result<Chunk<Arc<dyn Array>>> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(values.iter().map(Some))?;
+    let array: DictionaryArray<i32> = array.into();
+    Chunk::try_new(vec![Arc::new(array) as Arc<dyn Array>])
+}
+
+#[test]
+fn decoder() -> Result<()> {
+    let (schema, chunk) = data()?;
+    let ipc_fields = default_ipc_fields(&schema.fields);
+    let options = WriteOptions { compression: None };
+    let (dictionaries, batches) = serialize_batch_with_limit(&chunk, &ipc_fields, &options, 2_000)?;
+
+    let mut decoder = FlightDecoder::new();
+    assert!(decoder.decode(&serialize_schema(&schema, None))?.is_none());
+    assert_eq!(decoder.schema(), Some(&schema));
+    for message in &dictionaries {
+        assert!(decoder.decode(message)?.is_none());
+    }
+    let mut rows = 0;
+    for message in &batches {
+        let result = decoder.decode(message)?.unwrap();
+        let expected = chunk.clone().sliced(rows, result.len());
+        for (result, expected) in result.arrays().iter().zip(expected.arrays()) {
+            assert_eq!(result.as_ref(), expected.as_ref());
+        }
+        rows += result.len();
+    }
+    assert_eq!(rows, chunk.len());
+    Ok(())
+}
+
+#[test]
+fn decoder_replaced_dictionary() -> Result<()> {
+    let first = dictionary_chunk(&["a", "b", "a"])?;
+    let second = dictionary_chunk(&["c", "d"])?;
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        first.arrays()[0].data_type().clone(),
+        true,
+    )]);
+    let ipc_fields = default_ipc_fields(&schema.fields);
+    let options = WriteOptions { compression: None };
+
+    let (schema, ipc_schema) = deserialize_schemas(&serialize_schema(&schema, None).data_header)?;
+    let mut decoder = FlightDecoder::with_schema(schema, ipc_schema);
+    for expected in [first, second] {
+        let (dictionaries, batch) = serialize_batch(&expected, &ipc_fields, &options);
+        for message in &dictionaries {
+            assert!(decoder.decode(message)?.is_none());
+        }
+        assert_eq!(decoder.decode(&batch)?, Some(expected));
+    }
+    Ok(())
+}
+
+#[test]
+fn decoder_invalid_order() -> Result<()> {
+    let chunk = dictionary_chunk(&["a"])?;
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        chunk.arrays()[0].data_type().clone(),
+        true,
+    )]);
+    let ipc_fields = default_ipc_fields(&schema.fields);
+    let options = WriteOptions { compression: None };
+    let (dictionaries, batch) = serialize_batch(&chunk, &ipc_fields, &options);
+    let schema = serialize_schema(&schema, None);
+
+    // messages before the schema
+    let mut decoder = FlightDecoder::new();
+    assert!(decoder.decode(&dictionaries[0]).is_err());
+    assert!(decoder.decode(&batch).is_err());
+
+    // schema after the start of the stream
+    decoder.decode(&schema)?;
+    assert!(decoder.decode(&schema).is_err());
+
+    // batch before its dictionary
+    assert!(decoder.decode(&batch).is_err());
+    decoder.decode(&dictionaries[0])?;
+    assert_eq!(decoder.decode(&batch)?, Some(chunk));
+    Ok(())
+}
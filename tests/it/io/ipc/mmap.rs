@@ -0,0 +1,239 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema, UnionMode};
+use arrow2::error::Result;
+use arrow2::io::ipc::read::mmap::{mmap_dictionaries_unchecked, mmap_unchecked};
+use arrow2::io::ipc::read::{read_file_metadata, read_file_metadata_only, FileReader};
+use arrow2::io::ipc::write::{FileWriter, WriteOptions};
+
+fn write(chunks: &[Chunk<Arc<dyn Array>>], schema: &Schema) -> Result<Vec<u8>> {
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_new(vec![], schema, None, options)?;
+    for chunk in chunks {
+        writer.write(chunk, None)?;
+    }
+    writer.finish()?;
+    Ok(writer.into_inner())
+}
+
+fn round_trip(array: Arc<dyn Array>) -> Result<()> {
+    let sliced = array.slice(1, array.len() - 1).into();
+    round_trip_chunks(&[Chunk::new(vec![array]), Chunk::new(vec![sliced])])
+}
+
+fn round_trip_chunks(chunks: &[Chunk<Arc<dyn Array>>]) -> Result<()> {
+    let data_type = chunks[0].arrays()[0].data_type().clone();
+    let schema = Schema::from(vec![Field::new("a", data_type, true)]);
+    let data = Arc::new(write(chunks, &schema)?);
+
+    let metadata = read_file_metadata_only(&mut Cursor::new(data.as_ref()))?;
+    let dictionaries = unsafe { mmap_dictionaries_unchecked(&metadata, data.clone())? };
+
+    for (i, expected) in chunks.iter().enumerate() {
+        let chunk = unsafe { mmap_unchecked(&metadata, &dictionaries, data.clone(), i)? };
+        assert_eq!(&chunk, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn primitive() -> Result<()> {
+    round_trip(Arc::new(Int32Array::from([Some(1), None, Some(3)])))?;
+    round_trip(Arc::new(Float64Array::from_slice([1.0, 2.0, 3.0])))
+}
+
+#[test]
+fn boolean() -> Result<()> {
+    round_trip(Arc::new(BooleanArray::from([
+        Some(true),
+        None,
+        Some(false),
+    ])))
+}
+
+#[test]
+fn null() -> Result<()> {
+    round_trip(Arc::new(NullArray::new(DataType::Null, 3)))
+}
+
+#[test]
+fn utf8() -> Result<()> {
+    round_trip(Arc::new(Utf8Array::<i32>::from([
+        Some("a"),
+        None,
+        Some("bbb"),
+    ])))?;
+    round_trip(Arc::new(BinaryArray::<i64>::from([
+        Some(b"a".as_ref()),
+        None,
+        Some(b"bb"),
+    ])))
+}
+
+#[test]
+fn fixed_size_binary() -> Result<()> {
+    round_trip(Arc::new(FixedSizeBinaryArray::new(
+        DataType::FixedSizeBinary(2),
+        vec![1, 2, 0, 0, 3, 4].into(),
+        Some([true, false, true].into()),
+    )))
+}
+
+#[test]
+fn list() -> Result<()> {
+    let data = vec![Some(vec![Some(1i32), None]), None, Some(vec![Some(3)])];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data)?;
+    let array: ListArray<i32> = array.into();
+    round_trip(Arc::new(array))
+}
+
+#[test]
+fn fixed_size_list() -> Result<()> {
+    let values = Arc::new(Int32Array::from([
+        Some(1),
+        None,
+        Some(3),
+        Some(4),
+        None,
+        None,
+    ]));
+    let data_type = FixedSizeListArray::default_datatype(DataType::Int32, 2);
+    let array = FixedSizeListArray::new(data_type, values, None);
+    round_trip(Arc::new(array))
+}
+
+#[test]
+fn struct_() -> Result<()> {
+    let fields = vec![
+        Field::new("b", DataType::Boolean, true),
+        Field::new("c", DataType::Utf8, true),
+    ];
+    let array = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![
+            Arc::new(BooleanArray::from([Some(true), None, Some(false)])),
+            Arc::new(Utf8Array::<i32>::from([Some("a"), Some("b"), None])),
+        ],
+        Some([true, false, true].into()),
+    );
+    round_trip(Arc::new(array))
+}
+
+#[test]
+fn union() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let types = vec![0, 1, 1].into();
+    let values = vec![
+        Arc::new(Int32Array::from([Some(1), None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("a"), Some("b"), None])),
+    ];
+    let data_type = DataType::Union(fields.clone(), None, UnionMode::Sparse);
+    let array = UnionArray::from_data(data_type, types, values, None);
+    round_trip_chunks(&[Chunk::new(vec![Arc::new(array) as _])])?;
+
+    let types = vec![0, 1, 1].into();
+    let values = vec![
+        Arc::new(Int32Array::from([Some(1)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("a"), None])),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let offsets = Some(vec![0, 0, 1].into());
+    let array = UnionArray::from_data(data_type, types, values, offsets);
+    round_trip_chunks(&[Chunk::new(vec![Arc::new(array) as _])])
+}
+
+#[test]
+fn dictionary() -> Result<()> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend([Some("a"), None, Some("b"), Some("a")])?;
+    let array: DictionaryArray<i32> = array.into();
+    round_trip(Arc::new(array))
+}
+
+#[test]
+fn aliases_the_data() -> Result<()> {
+    let array = Int64Array::from_slice([1, 2, 3]);
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), false)]);
+    let data = Arc::new(write(&[Chunk::new(vec![Arc::new(array) as _])], &schema)?);
+
+    let metadata = read_file_metadata_only(&mut Cursor::new(data.as_ref()))?;
+    let chunk = unsafe { mmap_unchecked(&metadata, &Default::default(), data.clone(), 0)? };
+    // the arrays own a reference to the data
+    assert_eq!(Arc::strong_count(&data), 2);
+
+    let array = chunk.arrays()[0]
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    let range = data.as_ptr_range();
+    let ptr = array.values().as_ptr() as *const u8;
+    assert!(range.contains(&ptr));
+
+    drop(chunk);
+    assert_eq!(Arc::strong_count(&data), 1);
+    Ok(())
+}
+
+#[test]
+fn same_as_read() -> Result<()> {
+    let array = Utf8Array::<i64>::from([Some("a"), None, Some("ccc")]);
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let data = Arc::new(write(&[Chunk::new(vec![Arc::new(array) as _])], &schema)?);
+
+    let mut reader = Cursor::new(data.as_ref().clone());
+    let metadata = read_file_metadata(&mut reader)?;
+    let chunk = unsafe { mmap_unchecked(&metadata, metadata.dictionaries(), data, 0)? };
+    let expected = FileReader::new(reader, metadata, None).next().unwrap()?;
+    assert_eq!(chunk, expected);
+    Ok(())
+}
+
+#[test]
+fn out_of_bounds_chunk() -> Result<()> {
+    let array = Int32Array::from_slice([1]);
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), false)]);
+    let data = Arc::new(write(&[Chunk::new(vec![Arc::new(array) as _])], &schema)?);
+
+    let metadata = read_file_metadata_only(&mut Cursor::new(data.as_ref()))?;
+    assert!(unsafe { mmap_unchecked(&metadata, &Default::default(), data, 1) }.is_err());
+    Ok(())
+}
+
+#[test]
+fn truncated() -> Result<()> {
+    let array = Int32Array::from_slice([1, 2, 3]);
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), false)]);
+    let data = write(&[Chunk::new(vec![Arc::new(array) as _])], &schema)?;
+
+    let metadata = read_file_metadata_only(&mut Cursor::new(&data))?;
+    let block = metadata.blocks()[0];
+    let end = (block.offset + block.meta_data_length as i64 + block.body_length) as usize;
+    let data = Arc::new(data[..end - 1].to_vec());
+    assert!(unsafe { mmap_unchecked(&metadata, &Default::default(), data, 0) }.is_err());
+    Ok(())
+}
+
+#[cfg(feature = "io_ipc_compression")]
+#[test]
+fn compressed() -> Result<()> {
+    let array = Int32Array::from_slice([1, 2, 3]);
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), false)]);
+    let options = WriteOptions {
+        compression: Some(arrow2::io::ipc::write::Compression::LZ4),
+    };
+    let mut writer = FileWriter::try_new(vec![], &schema, None, options)?;
+    writer.write(&Chunk::new(vec![Arc::new(array) as _]), None)?;
+    writer.finish()?;
+    let data = Arc::new(writer.into_inner());
+
+    let metadata = read_file_metadata_only(&mut Cursor::new(data.as_ref()))?;
+    assert!(unsafe { mmap_unchecked(&metadata, &Default::default(), data, 0) }.is_err());
+    Ok(())
+}
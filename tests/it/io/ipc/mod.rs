@@ -1,5 +1,7 @@
 mod common;
+mod mmap;
 mod read;
+mod transport;
 mod write;
 
 pub use common::read_gzip_json;
@@ -0,0 +1,199 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::error::Result;
+use arrow2::io::ipc::read::*;
+use arrow_format::ipc;
+use arrow_format::ipc::planus::Builder;
+
+fn int32_field(name: &str) -> ipc::Field {
+    ipc::Field {
+        name: Some(name.to_string()),
+        nullable: true,
+        type_: Some(ipc::Type::Int(Box::new(ipc::Int {
+            bit_width: 32,
+            is_signed: true,
+        }))),
+        dictionary: None,
+        children: None,
+        custom_metadata: None,
+    }
+}
+
+fn serialize_message(header: ipc::MessageHeader, body_length: i64) -> Vec<u8> {
+    let message = ipc::Message {
+        version: ipc::MetadataVersion::V5,
+        header: Some(header),
+        body_length,
+        custom_metadata: None,
+    };
+    let mut builder = Builder::new();
+    builder.finish(&message, None).to_vec()
+}
+
+fn serialize_schema(type_: ipc::Type, children: Vec<ipc::Field>) -> Vec<u8> {
+    let field = ipc::Field {
+        name: Some("a".to_string()),
+        nullable: true,
+        type_: Some(type_),
+        dictionary: None,
+        children: Some(children),
+        custom_metadata: None,
+    };
+    let schema = ipc::Schema {
+        endianness: ipc::Endianness::Little,
+        fields: Some(vec![field]),
+        custom_metadata: None,
+        features: None,
+    };
+    serialize_message(ipc::MessageHeader::Schema(Box::new(schema)), 0)
+}
+
+/// Returns a schema message with a single field "a" whose type has the tag `tag`, which
+/// arrow-format can't write: the message is serialized with two placeholder types whose
+/// tables are identical and the only byte that differs (the tag) is replaced.
+fn schema_with_tag(tag: u8, children: Vec<ipc::Field>) -> Vec<u8> {
+    let null = ipc::Type::Null(Box::new(ipc::Null {}));
+    let mut message = serialize_schema(null, children.clone());
+    let other = serialize_schema(ipc::Type::Bool(Box::new(ipc::Bool {})), children);
+    assert_eq!(message.len(), other.len());
+    let positions = message
+        .iter()
+        .zip(other.iter())
+        .enumerate()
+        .filter(|(_, (lhs, rhs))| lhs != rhs)
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    assert_eq!(positions.len(), 1);
+    message[positions[0]] = tag;
+    message
+}
+
+fn write_message(stream: &mut Vec<u8>, message: &[u8]) {
+    let padding = (8 - message.len() % 8) % 8;
+    stream.extend_from_slice(&[0xff; 4]);
+    stream.extend_from_slice(&((message.len() + padding) as i32).to_le_bytes());
+    stream.extend_from_slice(message);
+    stream.extend_from_slice(&vec![0; padding]);
+}
+
+/// Returns a stream with the schema `schema` and a single record batch
+fn stream(schema: Vec<u8>, length: i64, nodes: &[(i64, i64)], buffers: &[&[i32]]) -> Vec<u8> {
+    let mut body = vec![];
+    let mut ipc_buffers = vec![];
+    for buffer in buffers {
+        let offset = body.len() as i64;
+        buffer
+            .iter()
+            .for_each(|x| body.extend_from_slice(&x.to_le_bytes()));
+        ipc_buffers.push(ipc::Buffer {
+            offset,
+            length: body.len() as i64 - offset,
+        });
+        body.extend_from_slice(&vec![0; (8 - body.len() % 8) % 8]);
+    }
+    let batch = ipc::RecordBatch {
+        length,
+        nodes: Some(
+            nodes
+                .iter()
+                .map(|(length, null_count)| ipc::FieldNode {
+                    length: *length,
+                    null_count: *null_count,
+                })
+                .collect(),
+        ),
+        buffers: Some(ipc_buffers),
+        compression: None,
+    };
+    let batch = serialize_message(
+        ipc::MessageHeader::RecordBatch(Box::new(batch)),
+        body.len() as i64,
+    );
+
+    let mut stream = vec![];
+    write_message(&mut stream, &schema);
+    write_message(&mut stream, &batch);
+    stream.extend_from_slice(&body);
+    stream.extend_from_slice(&[0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0]);
+    stream
+}
+
+fn run_end_encoded() -> Vec<u8> {
+    let schema = schema_with_tag(22, vec![int32_field("run_ends"), int32_field("values")]);
+    stream(
+        schema,
+        5,
+        &[(5, 0), (2, 0), (2, 0)],
+        &[&[], &[2, 5], &[], &[10, 20]],
+    )
+}
+
+fn list_view() -> Vec<u8> {
+    let schema = schema_with_tag(25, vec![int32_field("item")]);
+    stream(
+        schema,
+        3,
+        &[(3, 0), (4, 0)],
+        &[&[], &[2, 0, 1], &[2, 1, 0], &[], &[1, 2, 3, 4]],
+    )
+}
+
+fn read_single(data: Vec<u8>) -> Result<(StreamMetadata, Arc<dyn Array>)> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_stream_metadata_with_layouts(&mut reader, UnsupportedLayouts::Convert)?;
+    let mut reader = StreamReader::new(reader, metadata.clone());
+    let chunk = reader.next().unwrap()?.unwrap();
+    assert!(reader.next().is_none());
+    Ok((metadata, chunk.into_arrays().pop().unwrap()))
+}
+
+#[test]
+fn unsupported_layout_errors_by_default() {
+    let mut reader = Cursor::new(run_end_encoded());
+    assert!(read_stream_metadata(&mut reader).is_err());
+    assert_eq!(UnsupportedLayouts::default(), UnsupportedLayouts::Error);
+}
+
+#[test]
+fn run_end_encoded_to_values() -> Result<()> {
+    let (metadata, array) = read_single(run_end_encoded())?;
+
+    let expected = Int32Array::from_slice([10, 10, 20, 20, 20]);
+    assert_eq!(array.as_ref(), &expected as &dyn Array);
+    assert_eq!(
+        metadata.schema.metadata.get(CONVERTED_FIELDS_KEY),
+        Some(&"a: RunEndEncoded<Int32>".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn list_view_to_list() -> Result<()> {
+    let (metadata, array) = read_single(list_view())?;
+
+    let data = vec![
+        Some(vec![Some(3), Some(4)]),
+        Some(vec![Some(1)]),
+        Some(vec![]),
+    ];
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new_with_field(
+        MutablePrimitiveArray::new(),
+        "item",
+        true,
+    );
+    expected.try_extend(data)?;
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(array.as_ref(), &expected as &dyn Array);
+    assert!(metadata.schema.metadata.contains_key(CONVERTED_FIELDS_KEY));
+    Ok(())
+}
+
+#[test]
+fn unsupported_conversion_errors() {
+    // BinaryView can't be converted
+    let schema = schema_with_tag(23, vec![]);
+    let mut reader = Cursor::new(stream(schema, 0, &[(0, 0)], &[]));
+    assert!(read_stream_metadata_with_layouts(&mut reader, UnsupportedLayouts::Convert).is_err());
+}
@@ -1,7 +1,13 @@
 use std::fs::File;
+use std::io::Cursor;
+use std::sync::Arc;
 
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::*;
+use arrow2::io::ipc::write::{FileWriter, WriteOptions};
 
 use super::super::common::read_gzip_json;
 
@@ -182,3 +188,194 @@ fn read_projected() -> Result<()> {
     test_projection("1.0.0-littleendian", "generated_dictionary", 2)?;
     test_projection("1.0.0-littleendian", "generated_nested", 0)
 }
+
+fn nested_schema() -> Schema {
+    let d = DataType::Struct(vec![
+        Field::new("e", DataType::Int64, true),
+        Field::new("f", DataType::Boolean, true),
+    ]);
+    let b = DataType::Struct(vec![
+        Field::new("c", DataType::Utf8, true),
+        Field::new("d", d, true),
+    ]);
+    Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", b, true),
+        Field::new("g.h", DataType::Utf8, true),
+    ])
+}
+
+fn nested_chunk(schema: &Schema) -> Chunk<Arc<dyn Array>> {
+    let (d, b) = match &schema.fields[1].data_type {
+        DataType::Struct(fields) => (fields[1].data_type.clone(), schema.fields[1].data_type()),
+        _ => unreachable!(),
+    };
+    let d = StructArray::from_data(
+        d,
+        vec![
+            Arc::new(Int64Array::from([Some(1), None, Some(3)])),
+            Arc::new(BooleanArray::from([Some(true), Some(false), None])),
+        ],
+        Some([true, true, false].into()),
+    );
+    let b = StructArray::from_data(
+        b.clone(),
+        vec![
+            Arc::new(Utf8Array::<i32>::from([Some("x"), None, Some("z")])),
+            Arc::new(d),
+        ],
+        Some([true, false, true].into()),
+    );
+    Chunk::new(vec![
+        Arc::new(Int32Array::from([Some(1), Some(2), None])) as Arc<dyn Array>,
+        Arc::new(b),
+        Arc::new(Utf8Array::<i32>::from([Some("u"), Some("v"), None])),
+    ])
+}
+
+fn read_names(names: &[&str]) -> Result<FileReader<Cursor<Vec<u8>>>> {
+    let schema = nested_schema();
+    let chunk = nested_chunk(&schema);
+
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_new(vec![], &schema, None, options)?;
+    writer.write(&chunk, None)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    let mut reader = Cursor::new(writer.into_inner());
+
+    let metadata = read_file_metadata(&mut reader)?;
+    FileReader::try_new_with_names(reader, metadata, names)
+}
+
+#[test]
+fn read_projected_names() -> Result<()> {
+    let expected_schema = nested_schema();
+    let expected = nested_chunk(&expected_schema);
+
+    // in the order of the schema
+    let reader = read_names(&["g.h", "a"])?;
+    let schema = reader.schema().clone();
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    let names = schema
+        .fields
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["a", "g.h"]);
+    for chunk in chunks {
+        assert_eq!(chunk.arrays()[0], expected.arrays()[0]);
+        assert_eq!(chunk.arrays()[1], expected.arrays()[2]);
+    }
+    Ok(())
+}
+
+#[test]
+fn read_projected_nested_names() -> Result<()> {
+    let reader = read_names(&["b.d.f", "b.c"])?;
+    let schema = reader.schema().clone();
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+
+    let d = DataType::Struct(vec![Field::new("f", DataType::Boolean, true)]);
+    let b = DataType::Struct(vec![
+        Field::new("c", DataType::Utf8, true),
+        Field::new("d", d.clone(), true),
+    ]);
+    assert_eq!(schema, Schema::from(vec![Field::new("b", b.clone(), true)]));
+
+    let d = StructArray::from_data(
+        d,
+        vec![Arc::new(BooleanArray::from([
+            Some(true),
+            Some(false),
+            None,
+        ]))],
+        Some([true, true, false].into()),
+    );
+    let b = StructArray::from_data(
+        b,
+        vec![
+            Arc::new(Utf8Array::<i32>::from([Some("x"), None, Some("z")])),
+            Arc::new(d),
+        ],
+        Some([true, false, true].into()),
+    );
+    assert_eq!(chunks.len(), 2);
+    for chunk in chunks {
+        assert_eq!(
+            chunk,
+            Chunk::new(vec![Arc::new(b.clone()) as Arc<dyn Array>])
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn read_projected_whole_struct() -> Result<()> {
+    let expected_schema = nested_schema();
+    let expected = nested_chunk(&expected_schema);
+
+    for names in [["b.d.e", "b"], ["b", "b.d.e"]] {
+        let reader = read_names(&names)?;
+        assert_eq!(
+            reader.schema().fields,
+            vec![expected_schema.fields[1].clone()]
+        );
+        for chunk in reader {
+            let chunk = chunk?;
+            assert_eq!(chunk.arrays()[0], expected.arrays()[1]);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn read_projected_invalid_names() {
+    assert!(read_names(&[]).is_err());
+    assert!(read_names(&["x"]).is_err());
+    assert!(read_names(&["b.x"]).is_err());
+    // `a` is not a struct
+    assert!(read_names(&["a.x"]).is_err());
+}
+
+#[test]
+fn read_at() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let chunks = (0..3)
+        .map(|i| {
+            Chunk::new(vec![
+                Arc::new(Int32Array::from([Some(i), None])) as Arc<dyn Array>,
+                Arc::new(Utf8Array::<i32>::from([None, Some(i.to_string())])),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_new(vec![], &schema, None, options)?;
+    for chunk in &chunks {
+        writer.write(chunk, None)?;
+    }
+    writer.finish()?;
+    let mut reader = Cursor::new(writer.into_inner());
+
+    let metadata = read_file_metadata(&mut reader)?;
+    let chunk = read_batch(&mut reader, &metadata, Some(&[1]), 1, &mut vec![])?;
+    assert_eq!(chunk.arrays(), &chunks[1].arrays()[1..]);
+
+    let mut reader = FileReader::new(reader, metadata, Some(vec![1]));
+    assert_eq!(reader.blocks().len(), 3);
+
+    // in any order, and without affecting the iteration
+    for i in [2, 0, 1, 2] {
+        let chunk = reader.read_at(i)?;
+        assert_eq!(chunk.arrays(), &chunks[i].arrays()[1..]);
+    }
+    assert!(reader.read_at(3).is_err());
+
+    let chunk = reader.next().unwrap()?;
+    assert_eq!(chunk.arrays(), &chunks[0].arrays()[1..]);
+    Ok(())
+}
@@ -1,2 +1,3 @@
+mod compat;
 mod file;
 mod stream;
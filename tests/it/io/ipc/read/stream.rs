@@ -1,7 +1,10 @@
 use std::fs::File;
+use std::io::Cursor;
 
+use arrow2::datatypes::{DataType, Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::*;
+use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
 
 use crate::io::ipc::common::read_gzip_json;
 
@@ -100,3 +103,39 @@ fn read_generated_200_compression_lz4() -> Result<()> {
 fn read_generated_200_compression_zstd() -> Result<()> {
     test_file("2.0.0-compression", "generated_zstd")
 }
+
+#[test]
+fn try_read_metadata() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Utf8, true)]);
+    let mut writer = StreamWriter::new(vec![], WriteOptions { compression: None });
+    writer.start(&schema, None)?;
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    let mut length = 0;
+    let consumed = loop {
+        match try_read_stream_metadata(&data[..length])? {
+            StreamMetadataState::NeedMoreData(additional) => {
+                assert!(additional > 0);
+                length += additional;
+                assert!(length <= data.len());
+            }
+            StreamMetadataState::Ready(metadata, consumed) => {
+                assert_eq!(metadata.schema, schema);
+                break consumed;
+            }
+        }
+    };
+    assert_eq!(consumed, length);
+
+    let metadata = read_stream_metadata(&mut Cursor::new(&data))?;
+    let mut reader = StreamReader::new(Cursor::new(&data[consumed..]), metadata);
+    assert!(reader.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn try_read_metadata_invalid() {
+    let data = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+    assert!(try_read_stream_metadata(&data).is_err());
+}
@@ -43,3 +43,39 @@ async fn test_file(version: &str, file_name: &str) -> Result<()> {
 async fn write_async() -> Result<()> {
     test_file("1.0.0-littleendian", "generated_primitive").await
 }
+
+#[tokio::test]
+async fn read_custom_metadata_and_dictionaries() -> Result<()> {
+    use std::sync::Arc;
+
+    use arrow2::array::*;
+    use arrow2::chunk::Chunk;
+    use arrow2::datatypes::{Field, Metadata, Schema};
+    use arrow2::io::ipc::write::{FileWriter, WriteOptions};
+
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend([Some("a"), None, Some("a")])?;
+    let array: DictionaryArray<i32> = array.into();
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let chunk = Chunk::try_new(vec![Arc::new(array) as Arc<dyn Array>])?;
+
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_new(vec![], &schema, None, options)?;
+    writer.write(&chunk, None)?;
+    let custom_metadata = Metadata::from([("key".to_string(), "value".to_string())]);
+    writer.set_custom_metadata(custom_metadata.clone());
+    writer.finish()?;
+
+    let mut reader = futures::io::Cursor::new(writer.into_inner());
+    let metadata = read_file_metadata_async(&mut reader).await?;
+    assert_eq!(metadata.custom_metadata, custom_metadata);
+    assert_eq!(metadata.blocks().len(), 1);
+
+    let mut stream = FileStream::new(reader, metadata, None);
+    let mut chunks = vec![];
+    while let Some(item) = stream.next().await {
+        chunks.push(item?)
+    }
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use std::io::Cursor;
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::ipc::transport::{StreamReceiver, StreamSender};
+use arrow2::io::ipc::write::WriteOptions;
+
+fn schema() -> Schema {
+    Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ])
+}
+
+fn chunk(len: usize) -> Chunk<Arc<dyn Array>> {
+    let a = Int32Array::from_iter((0..len as i32).map(Some));
+    let b = Utf8Array::<i32>::from_iter((0..len).map(|x| Some(x.to_string())));
+    Chunk::new(vec![Arc::new(a) as Arc<dyn Array>, Arc::new(b)])
+}
+
+#[test]
+fn keep_alive_is_skipped() -> Result<()> {
+    let mut sender = StreamSender::try_new(vec![], &schema(), WriteOptions { compression: None })?;
+    sender.keep_alive()?;
+    sender.send(&chunk(3))?;
+    sender.keep_alive()?;
+    sender.send(&chunk(2))?;
+    let data = sender.finish()?;
+
+    let receiver = StreamReceiver::try_new(Cursor::new(data))?;
+    assert_eq!(receiver.metadata().schema, schema());
+    let result = receiver.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, vec![chunk(3), chunk(2)]);
+    Ok(())
+}
+
+#[test]
+fn empty_chunks_are_received() -> Result<()> {
+    let mut sender = StreamSender::try_new(vec![], &schema(), WriteOptions { compression: None })?;
+    sender.send(&chunk(0))?;
+    sender.keep_alive()?;
+    sender.send(&chunk(2))?;
+    let data = sender.finish()?;
+
+    let receiver = StreamReceiver::try_new(Cursor::new(data))?;
+    let result = receiver.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, vec![chunk(0), chunk(2)]);
+    Ok(())
+}
+
+#[test]
+fn closed_without_end_of_stream() -> Result<()> {
+    let mut data = vec![];
+    let mut sender =
+        StreamSender::try_new(&mut data, &schema(), WriteOptions { compression: None })?;
+    sender.send(&chunk(3))?;
+    // dropped without `finish`, e.g. the connection was closed by the sender
+    drop(sender);
+
+    let receiver = StreamReceiver::try_new(Cursor::new(data))?;
+    let result = receiver.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, vec![chunk(3)]);
+    Ok(())
+}
+
+#[test]
+fn tcp() -> Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?;
+
+    let handle = std::thread::spawn(move || -> Result<()> {
+        let stream = TcpStream::connect(address)?;
+        let mut sender =
+            StreamSender::try_new(stream, &schema(), WriteOptions { compression: None })?;
+        for i in 0..10 {
+            sender.send(&chunk(i + 1))?;
+            sender.keep_alive()?;
+        }
+        sender.finish()?;
+        Ok(())
+    });
+
+    let (stream, _) = listener.accept()?;
+    let result = StreamReceiver::try_new(stream)?.collect::<Result<Vec<_>>>()?;
+    handle.join().unwrap()?;
+
+    assert_eq!(result, (0..10).map(|i| chunk(i + 1)).collect::<Vec<_>>());
+    Ok(())
+}
@@ -1,11 +1,16 @@
+use std::collections::HashMap;
 use std::io::Cursor;
 use std::sync::Arc;
 
 use arrow2::array::*;
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::{Field, Schema};
+use arrow2::compute::aggregate::estimated_buffers_size;
+use arrow2::datatypes::{DataType, Field, Metadata, Schema};
 use arrow2::error::Result;
-use arrow2::io::ipc::read::{read_file_metadata, FileReader};
+use arrow2::io::ipc::read::{
+    read_file_dictionaries, read_file_metadata, read_file_metadata_only,
+    read_file_metadata_with_dictionaries, Dictionaries, FileReader,
+};
 use arrow2::io::ipc::{write::*, IpcField};
 
 use crate::io::ipc::common::read_gzip_json;
@@ -349,6 +354,17 @@ fn write_sliced_utf8() -> Result<()> {
     round_trip(columns, schema, None, Some(Compression::ZSTD))
 }
 
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_sliced_bitmaps() -> Result<()> {
+    let values = (0..100).map(|x| if x % 3 == 0 { None } else { Some(x % 2 == 0) });
+    let array = Arc::new(BooleanArray::from_iter(values).slice(3, 90)) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![array])?;
+    round_trip(columns.clone(), schema.clone(), None, None)?;
+    round_trip(columns, schema, None, Some(Compression::LZ4))
+}
+
 #[test]
 fn write_sliced_list() -> Result<()> {
     let data = vec![
@@ -365,3 +381,454 @@ fn write_sliced_list() -> Result<()> {
     let columns = Chunk::try_new(vec![array])?;
     round_trip(columns, schema, None, None)
 }
+
+fn two_dictionaries() -> Result<(Schema, Chunk<Arc<dyn Array>>)> {
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    a.try_extend([Some("aaaa"), Some("bbbb"), None])?;
+    let a: DictionaryArray<i32> = a.into();
+    let mut b = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    b.try_extend([Some("aaaa"), Some("aaaa"), Some("bbbb")])?;
+    let b: DictionaryArray<i32> = b.into();
+
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), true),
+        Field::new("b", b.data_type().clone(), true),
+    ]);
+    let columns = Chunk::try_new(vec![Arc::new(a) as Arc<dyn Array>, Arc::new(b) as _])?;
+    Ok((schema, columns))
+}
+
+#[test]
+fn dictionaries_deduplicate() -> Result<()> {
+    let (schema, columns) = two_dictionaries()?;
+    let data = write_(std::slice::from_ref(&columns), &schema, None, None)?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let usage = metadata.dictionaries().memory_usage();
+
+    reader.set_position(0);
+    let mut dictionaries = Dictionaries::new();
+    dictionaries.set_deduplicate(true);
+    let metadata = read_file_metadata_with_dictionaries(&mut reader, dictionaries)?;
+    let dictionaries = metadata.dictionaries();
+    assert_eq!(dictionaries.len(), 2);
+    assert!(Arc::ptr_eq(
+        dictionaries.get(&0).unwrap(),
+        dictionaries.get(&1).unwrap()
+    ));
+    assert_eq!(dictionaries.memory_usage() * 2, usage);
+
+    let reader = FileReader::new(reader, metadata, None);
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks, vec![columns]);
+    Ok(())
+}
+
+#[test]
+fn dictionaries_memory_limit() -> Result<()> {
+    let (schema, columns) = two_dictionaries()?;
+    let data = write_(&[columns], &schema, None, None)?;
+
+    let mut reader = Cursor::new(data);
+    let usage = read_file_metadata(&mut reader)?
+        .dictionaries()
+        .memory_usage();
+
+    reader.set_position(0);
+    let mut dictionaries = Dictionaries::new();
+    dictionaries.set_memory_limit(Some(usage - 1));
+    let error = read_file_metadata_with_dictionaries(&mut reader, dictionaries.clone());
+    assert!(error.is_err());
+
+    // shared dictionaries are accounted for once
+    reader.set_position(0);
+    dictionaries.set_deduplicate(true);
+    let metadata = read_file_metadata_with_dictionaries(&mut reader, dictionaries)?;
+    assert_eq!(metadata.dictionaries().memory_usage(), usage / 2);
+    Ok(())
+}
+
+#[test]
+fn dictionaries_replace() -> Result<()> {
+    let a = Arc::new(Utf8Array::<i32>::from_slice(["aaaa", "bbbb"])) as Arc<dyn Array>;
+    let b = Arc::new(Utf8Array::<i32>::from_slice(["cccc"])) as Arc<dyn Array>;
+    let a_usage = estimated_buffers_size(std::iter::once(a.as_ref())).total();
+    let b_usage = estimated_buffers_size(std::iter::once(b.as_ref())).total();
+
+    let mut dictionaries = Dictionaries::new();
+    dictionaries.set_deduplicate(true);
+    dictionaries.insert(0, a.clone())?;
+    // an equal dictionary shares the existing values
+    dictionaries.insert(1, Arc::new(Utf8Array::<i32>::from_slice(["aaaa", "bbbb"])))?;
+    assert_eq!(dictionaries.memory_usage(), a_usage);
+
+    // the values of 0 are still used by 1
+    dictionaries.insert(0, b.clone())?;
+    assert_eq!(dictionaries.memory_usage(), a_usage + b_usage);
+    dictionaries.insert(1, b)?;
+    assert_eq!(dictionaries.memory_usage(), b_usage);
+
+    dictionaries.set_memory_limit(Some(b_usage));
+    assert!(dictionaries.insert(2, a).is_err());
+    assert_eq!(dictionaries.len(), 2);
+    assert_eq!(dictionaries.memory_usage(), b_usage);
+    Ok(())
+}
+
+#[test]
+fn dictionaries_hash_map() -> Result<()> {
+    let a = Arc::new(Utf8Array::<i32>::from_slice(["aaaa", "bbbb"])) as Arc<dyn Array>;
+    let map = HashMap::from([(0, a.clone()), (1, a)]);
+
+    let dictionaries = Dictionaries::from(map.clone());
+    assert_eq!(dictionaries.len(), 2);
+    assert_eq!(dictionaries[&0], map[&0]);
+    // shared values are accounted for once
+    assert_eq!(
+        dictionaries.memory_usage(),
+        estimated_buffers_size(std::iter::once(map[&0].as_ref())).total()
+    );
+
+    let back: HashMap<i64, Arc<dyn Array>> = dictionaries.into();
+    assert_eq!(back, map);
+    Ok(())
+}
+
+fn dictionary(values: &[Option<&str>]) -> Result<Arc<dyn Array>> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(values.iter().copied())?;
+    Ok(array.into_arc())
+}
+
+fn list(values: Arc<dyn Array>, offsets: Vec<i32>) -> Arc<dyn Array> {
+    let data_type = ListArray::<i32>::default_datatype(values.data_type().clone());
+    Arc::new(ListArray::<i32>::from_data(
+        data_type,
+        offsets.into(),
+        values,
+        None,
+    ))
+}
+
+fn struct_(values: Vec<Arc<dyn Array>>) -> Arc<dyn Array> {
+    let fields = values
+        .iter()
+        .enumerate()
+        .map(|(i, x)| Field::new(format!("f{}", i), x.data_type().clone(), true))
+        .collect();
+    Arc::new(StructArray::from_data(
+        DataType::Struct(fields),
+        values,
+        None,
+    ))
+}
+
+pub(super) fn nested_dictionaries() -> Result<Vec<Arc<dyn Array>>> {
+    let a = dictionary(&[Some("a"), None, Some("b"), Some("a")])?;
+    let b = dictionary(&[Some("c"), Some("d"), Some("c"), None])?;
+    let c = dictionary(&[Some("e"), Some("f")])?;
+
+    let list_of_dict = list(a.clone(), vec![0, 2, 2, 4]);
+    let struct_of_dicts = struct_(vec![a.slice(0, 3).into(), b.slice(1, 3).into()]);
+    let list_of_struct = list(struct_(vec![b, a.clone()]), vec![0, 1, 3, 4]);
+    let list_of_list = list(list(c.clone(), vec![0, 1, 2]), vec![0, 2, 2, 2]);
+
+    let map_type = DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", c.data_type().clone(), false),
+                Field::new("value", c.data_type().clone(), true),
+            ]),
+            false,
+        )),
+        false,
+    );
+    let entries = Arc::new(StructArray::from_data(
+        match &map_type {
+            DataType::Map(field, _) => field.data_type().clone(),
+            _ => unreachable!(),
+        },
+        vec![c.clone(), c.clone()],
+        None,
+    ));
+    let map = Arc::new(MapArray::from_data(
+        map_type,
+        vec![0, 1, 1, 2].into(),
+        entries,
+        None,
+    )) as Arc<dyn Array>;
+
+    // dictionaries whose values contain dictionaries
+    let keys = Int32Array::from([Some(1), None, Some(0)]);
+    let dict_of_struct = Arc::new(DictionaryArray::<i32>::from_data(
+        keys.clone(),
+        struct_(vec![c.clone(), c.clone()]),
+    )) as Arc<dyn Array>;
+    let dict_of_list = Arc::new(DictionaryArray::<i32>::from_data(
+        keys,
+        list(c.clone(), vec![0, 1, 2]),
+    )) as Arc<dyn Array>;
+
+    Ok(vec![
+        list_of_dict,
+        struct_of_dicts,
+        list_of_struct,
+        list_of_list,
+        map,
+        dict_of_struct,
+        dict_of_list,
+    ])
+}
+
+#[test]
+fn write_nested_dictionaries() -> Result<()> {
+    let arrays = nested_dictionaries()?;
+    let fields = arrays
+        .iter()
+        .enumerate()
+        .map(|(i, x)| Field::new(format!("c{}", i), x.data_type().clone(), true))
+        .collect::<Vec<_>>();
+
+    // every dictionary is assigned its own id
+    let ipc_fields = default_ipc_fields(&fields);
+    let ids = |field: &IpcField| {
+        fn ids(field: &IpcField, result: &mut Vec<i64>) {
+            result.extend(field.dictionary_id);
+            field.fields.iter().for_each(|x| ids(x, result));
+        }
+        let mut result = vec![];
+        ids(field, &mut result);
+        result
+    };
+    let ids = ipc_fields.iter().flat_map(ids).collect::<Vec<_>>();
+    assert_eq!(ids, (0..13).collect::<Vec<_>>());
+
+    let schema = Schema::from(fields);
+    let columns = Chunk::try_new(arrays)?;
+    round_trip(columns, schema, None, None)
+}
+
+#[test]
+fn append() -> Result<()> {
+    let dict = dictionary(&[Some("a"), None, Some("b")])?;
+    let int = Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![
+        Field::new("a", int.data_type().clone(), true),
+        Field::new("b", dict.data_type().clone(), true),
+    ]);
+    let first = Chunk::try_new(vec![int.clone(), dict.clone()])?;
+    let second = Chunk::try_new(vec![int.slice(1, 2).into(), dict.slice(1, 2).into()])?;
+
+    let options = WriteOptions { compression: None };
+    let data = write_(std::slice::from_ref(&first), &schema, None, None)?;
+
+    let mut writer = FileWriter::try_append(Cursor::new(data), options)?;
+    writer.write(&second, None)?;
+    writer.write(&first, None)?;
+    writer.finish()?;
+    let data = writer.into_inner().into_inner();
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    assert_eq!(metadata.schema, schema);
+    let chunks = FileReader::new(reader, metadata, None).collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks, vec![first.clone(), second, first]);
+    Ok(())
+}
+
+#[test]
+fn append_empty() -> Result<()> {
+    let int = Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a", int.data_type().clone(), true)]);
+    let chunk = Chunk::try_new(vec![int])?;
+
+    let data = write_(&[], &schema, None, None)?;
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_append(Cursor::new(data), options)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+
+    let mut reader = Cursor::new(writer.into_inner().into_inner());
+    let metadata = read_file_metadata(&mut reader)?;
+    let chunks = FileReader::new(reader, metadata, None).collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn append_replaced_dictionary() -> Result<()> {
+    let dict = dictionary(&[Some("a"), Some("b")])?;
+    let schema = Schema::from(vec![Field::new("a", dict.data_type().clone(), true)]);
+    let data = write_(&[Chunk::try_new(vec![dict])?], &schema, None, None)?;
+
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_append(Cursor::new(data), options)?;
+    let other = Chunk::try_new(vec![dictionary(&[Some("c")])?])?;
+    assert!(writer.write(&other, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn replaced_dictionary() -> Result<()> {
+    let dict = dictionary(&[Some("a"), Some("b")])?;
+    let schema = Schema::from(vec![Field::new("a", dict.data_type().clone(), true)]);
+    let first = Chunk::try_new(vec![dict])?;
+
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_new(vec![], &schema, None, options)?;
+    writer.write(&first, None)?;
+
+    let other = Chunk::try_new(vec![dictionary(&[Some("c")])?])?;
+    let error = writer.write(&other, None).unwrap_err().to_string();
+    assert!(error.contains("Dictionary replacement detected"));
+    assert!(error.contains("dictionary with id 0"));
+
+    // a rejected chunk is not written, so the file remains valid
+    writer.write(&first, None)?;
+    writer.finish()?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_file_metadata(&mut reader)?;
+    let chunks = FileReader::new(reader, metadata, None).collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks, vec![first.clone(), first]);
+    Ok(())
+}
+
+#[test]
+fn replaced_nested_dictionary() -> Result<()> {
+    let first = list(dictionary(&[Some("a"), Some("b")])?, vec![0, 1, 2]);
+    let other = list(dictionary(&[Some("c")])?, vec![0, 1]);
+    let schema = Schema::from(vec![Field::new("a", first.data_type().clone(), true)]);
+
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_new(vec![], &schema, None, options)?;
+    writer.write(&Chunk::try_new(vec![first])?, None)?;
+    assert!(writer.write(&Chunk::try_new(vec![other])?, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn append_invalid() {
+    let options = WriteOptions { compression: None };
+    assert!(FileWriter::try_append(Cursor::new(vec![0u8; 20]), options).is_err());
+}
+
+#[test]
+fn footer_custom_metadata() -> Result<()> {
+    let int = Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a", int.data_type().clone(), true)]);
+    let chunk = Chunk::try_new(vec![int])?;
+
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_new(vec![], &schema, None, options)?;
+    writer.write(&chunk, None)?;
+    let custom_metadata = Metadata::from([("rows".to_string(), "2".to_string())]);
+    writer.set_custom_metadata(custom_metadata.clone());
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    let metadata = read_file_metadata(&mut Cursor::new(&data))?;
+    assert_eq!(metadata.custom_metadata, custom_metadata);
+    assert!(metadata.schema.metadata.is_empty());
+
+    // appending preserves the footer's metadata
+    let mut writer = FileWriter::try_append(Cursor::new(data), options)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    let metadata = read_file_metadata(&mut Cursor::new(writer.into_inner().into_inner()))?;
+    assert_eq!(metadata.custom_metadata, custom_metadata);
+    Ok(())
+}
+
+#[test]
+fn metadata_only() -> Result<()> {
+    let dict = dictionary(&[Some("a"), None, Some("b")])?;
+    let schema = Schema::from(vec![Field::new("a", dict.data_type().clone(), true)]);
+    let first = Chunk::try_new(vec![dict.clone()])?;
+    let second = Chunk::try_new(vec![dict.slice(1, 2).into()])?;
+    let data = write_(&[first.clone(), second.clone()], &schema, None, None)?;
+
+    let mut reader = Cursor::new(data);
+    let mut metadata = read_file_metadata_only(&mut reader)?;
+    assert_eq!(metadata.schema, schema);
+    assert!(metadata.custom_metadata.is_empty());
+    assert!(metadata.dictionaries().is_empty());
+    assert_eq!(metadata.blocks().len(), 2);
+    assert_eq!(metadata.dictionary_blocks().len(), 1);
+    assert!(metadata
+        .blocks()
+        .iter()
+        .all(|block| block.meta_data_length > 0 && block.body_length > 0));
+    // blocks are contiguous
+    let first_block = metadata.blocks()[0];
+    assert_eq!(
+        first_block.offset + first_block.meta_data_length as i64 + first_block.body_length,
+        metadata.blocks()[1].offset
+    );
+
+    read_file_dictionaries(&mut reader, &mut metadata)?;
+    assert_eq!(metadata.dictionaries().len(), 1);
+    let chunks = FileReader::new(reader, metadata, None).collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks, vec![first, second]);
+    Ok(())
+}
+
+fn schema_of(chunk: &Chunk<Arc<dyn Array>>) -> Schema {
+    Schema::from(
+        chunk
+            .arrays()
+            .iter()
+            .enumerate()
+            .map(|(i, x)| Field::new(format!("c{}", i), x.data_type().clone(), true))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// returns two chunks with the same data, the first of which is sliced
+fn deterministic_chunks() -> Result<[Chunk<Arc<dyn Array>>; 2]> {
+    let int = Int32Array::from([Some(1), None, Some(3), None, Some(5), None, Some(7)]);
+    let boolean = BooleanArray::from([Some(true), None, Some(false), Some(true), Some(true)]);
+    let dict = dictionary(&[Some("a"), None, Some("b"), Some("a")])?;
+
+    // the sliced arrays' bitmaps have bits beyond their length set
+    let sliced = Chunk::try_new(vec![
+        Arc::new(int.slice(0, 3)) as Arc<dyn Array>,
+        Arc::new(boolean.slice(0, 3)),
+        dict.slice(0, 3).into(),
+    ])?;
+    let fresh = Chunk::try_new(vec![
+        Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>,
+        Arc::new(BooleanArray::from([Some(true), None, Some(false)])),
+        dictionary(&[Some("a"), None, Some("b")])?,
+    ])?;
+
+    Ok([sliced, fresh])
+}
+
+#[test]
+fn deterministic() -> Result<()> {
+    let [sliced, fresh] = deterministic_chunks()?;
+    let schema = schema_of(&fresh);
+
+    let expected = write_(std::slice::from_ref(&fresh), &schema, None, None)?;
+    assert_eq!(
+        write_(std::slice::from_ref(&fresh), &schema, None, None)?,
+        expected
+    );
+    assert_eq!(write_(&[sliced], &schema, None, None)?, expected);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn deterministic_compressed() -> Result<()> {
+    let [sliced, fresh] = deterministic_chunks()?;
+    let schema = schema_of(&fresh);
+
+    let compression = Some(Compression::LZ4);
+    let expected = write_(&[fresh], &schema, None, compression)?;
+    assert_eq!(write_(&[sliced], &schema, None, compression)?, expected);
+    Ok(())
+}
@@ -147,3 +147,110 @@ fn write_100_custom_metadata() {
 fn write_100_decimal() {
     test_file("1.0.0-littleendian", "generated_decimal");
 }
+
+#[test]
+fn dictionaries_memory_limit() -> Result<()> {
+    use arrow2::array::{DictionaryArray, MutableDictionaryArray, MutableUtf8Array, TryExtend};
+    use arrow2::datatypes::Field;
+    use arrow2::io::ipc::read::Dictionaries;
+
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend((0..100).map(|x| Some(x.to_string())))?;
+    let array: DictionaryArray<i32> = array.into();
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]);
+    let data = write_(&schema, None, &[columns]);
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let mut dictionaries = Dictionaries::new();
+    dictionaries.set_memory_limit(Some(100));
+    let mut reader = StreamReader::with_dictionaries(reader, metadata, dictionaries);
+    assert!(reader.next().unwrap().is_err());
+    Ok(())
+}
+
+#[test]
+fn write_nested_dictionaries() -> Result<()> {
+    use arrow2::datatypes::Field;
+
+    let arrays = super::file::nested_dictionaries()?;
+    let schema = Schema::from(
+        arrays
+            .iter()
+            .enumerate()
+            .map(|(i, x)| Field::new(format!("c{}", i), x.data_type().clone(), true))
+            .collect::<Vec<_>>(),
+    );
+    let columns = Chunk::new(arrays);
+    let batches = vec![columns.clone(), columns];
+    let data = write_(&schema, None, &batches);
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    assert_eq!(metadata.schema, schema);
+    let reader = StreamReader::new(reader, metadata);
+    let result = reader
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, batches);
+    Ok(())
+}
+
+fn dictionary_chunk(values: &[&str], keys: &[i32]) -> Chunk<Arc<dyn Array>> {
+    use arrow2::array::{DictionaryArray, Int32Array, Utf8Array};
+
+    let values = Utf8Array::<i32>::from_slice(values);
+    let keys = Int32Array::from_slice(keys);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+    Chunk::new(vec![Arc::new(array) as Arc<dyn Array>])
+}
+
+fn write_dictionaries(batches: &[Chunk<Arc<dyn Array>>], deltas: bool) -> Result<Vec<u8>> {
+    use arrow2::datatypes::Field;
+
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        batches[0].arrays()[0].data_type().clone(),
+        false,
+    )]);
+
+    let mut result = vec![];
+    let mut writer = StreamWriter::new(&mut result, WriteOptions { compression: None });
+    writer.set_dictionary_deltas(deltas);
+    writer.start(&schema, None)?;
+    for batch in batches {
+        writer.write(batch, None)?;
+    }
+    writer.finish()?;
+    Ok(result)
+}
+
+#[test]
+fn write_dictionary_deltas() -> Result<()> {
+    let batches = vec![
+        dictionary_chunk(&["aaaaaaaa", "bbbbbbbb"], &[0, 1, 0]),
+        // appends values => delta
+        dictionary_chunk(&["aaaaaaaa", "bbbbbbbb", "cccccccc"], &[2, 1, 0]),
+        // same values => no dictionary batch
+        dictionary_chunk(&["aaaaaaaa", "bbbbbbbb", "cccccccc"], &[2, 2, 2]),
+        // different values => replacement
+        dictionary_chunk(&["dddddddd"], &[0, 0, 0]),
+    ];
+
+    let with_deltas = write_dictionaries(&batches, true)?;
+    let without_deltas = write_dictionaries(&batches, false)?;
+    // the delta only contains the appended value
+    assert!(with_deltas.len() < without_deltas.len());
+
+    for data in [with_deltas, without_deltas] {
+        let mut reader = Cursor::new(data);
+        let metadata = read_stream_metadata(&mut reader)?;
+        let reader = StreamReader::new(reader, metadata);
+        let result = reader
+            .map(|x| x.map(|x| x.unwrap()))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(result, batches);
+    }
+    Ok(())
+}
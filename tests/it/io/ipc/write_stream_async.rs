@@ -1,16 +1,18 @@
 use std::io::Cursor;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::{Context, Poll};
 
-use arrow2::array::Array;
+use arrow2::array::{Array, Int32Array};
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::Schema;
+use arrow2::datatypes::{DataType, Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read;
 use arrow2::io::ipc::write::stream_async;
 use arrow2::io::ipc::write::stream_async::StreamSink;
 use arrow2::io::ipc::IpcField;
 use futures::io::Cursor as AsyncCursor;
-use futures::SinkExt;
+use futures::{AsyncWrite, SinkExt, StreamExt};
 
 use crate::io::ipc::common::read_arrow_stream;
 use crate::io::ipc::common::read_gzip_json;
@@ -63,3 +65,98 @@ async fn test_file(version: &str, file_name: &str) -> Result<()> {
 async fn write_async() -> Result<()> {
     test_file("1.0.0-littleendian", "generated_primitive").await
 }
+
+fn chunks() -> (Schema, Vec<Chunk<Arc<dyn Array>>>) {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let chunks = (0..3)
+        .map(|i| {
+            Chunk::new(vec![
+                Arc::new(Int32Array::from([Some(i), None])) as Arc<dyn Array>
+            ])
+        })
+        .collect();
+    (schema, chunks)
+}
+
+fn read_stream(data: Vec<u8>) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    let mut reader = Cursor::new(data);
+    let metadata = read::read_stream_metadata(&mut reader)?;
+    read::StreamReader::new(reader, metadata)
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect()
+}
+
+#[tokio::test]
+async fn forward() -> Result<()> {
+    let (schema, expected) = chunks();
+
+    let mut sink = StreamSink::new(AsyncCursor::new(vec![]), &schema, None, Default::default());
+    futures::stream::iter(expected.clone())
+        .map(|chunk| Ok(chunk.into()))
+        .forward(&mut sink)
+        .await?;
+
+    let data = sink.into_inner().unwrap().into_inner();
+    assert_eq!(read_stream(data)?, expected);
+    Ok(())
+}
+
+/// A writer that is pending every other time it is polled
+struct SlowWriter {
+    data: Vec<u8>,
+    pending: bool,
+}
+
+impl AsyncWrite for SlowWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.pending = !self.pending;
+        if self.pending {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        self.data.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[tokio::test]
+async fn backpressure() -> Result<()> {
+    let (schema, expected) = chunks();
+    let writer = SlowWriter {
+        data: vec![],
+        pending: false,
+    };
+    let mut sink = StreamSink::new(writer, &schema, None, Default::default());
+
+    let waker = futures::task::noop_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    // the header is being written
+    assert!(sink.poll_ready_unpin(&mut cx).is_pending());
+    futures::future::poll_fn(|cx| sink.poll_ready_unpin(cx)).await?;
+
+    sink.start_send_unpin(expected[0].clone().into())?;
+    // the chunk is being written
+    assert!(sink.poll_ready_unpin(&mut cx).is_pending());
+    for chunk in expected.iter().skip(1) {
+        sink.send(chunk.clone().into()).await?;
+    }
+    sink.close().await?;
+    assert!(sink.send(expected[0].clone().into()).await.is_err());
+
+    let data = sink.into_inner().unwrap().data;
+    assert_eq!(read_stream(data)?, expected);
+    Ok(())
+}
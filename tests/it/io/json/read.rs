@@ -35,3 +35,20 @@ fn read_json() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn read_decimal() -> Result<()> {
+    let data = r#"[1.05, "-2.5", 3, null, "a", 1.055]"#;
+
+    let json = serde_json::from_slice(data.as_bytes())?;
+
+    let data_type = DataType::List(Box::new(Field::new("item", DataType::Decimal(5, 2), true)));
+    let result = read::deserialize(&json, data_type)?;
+
+    let expected = Int128Array::from([Some(105), Some(-250), Some(300), None, None, None])
+        .to(DataType::Decimal(5, 2));
+
+    assert_eq!(expected, result.as_ref());
+
+    Ok(())
+}
@@ -249,6 +249,59 @@ fn list_of_struct() -> Result<()> {
     test!(array, expected)
 }
 
+#[test]
+fn large_list() -> Result<()> {
+    let data_type = DataType::LargeList(Box::new(Field::new("item", DataType::Int32, true)));
+    let array = ListArray::<i64>::from_data(
+        data_type,
+        Buffer::from_slice([0, 2, 3, 3, 4]),
+        Arc::new(Int32Array::from([Some(1), None, Some(3), Some(4)])),
+        Some(Bitmap::from([true, false, true, true])),
+    );
+
+    // the value of the null list is not written
+    let expected = r#"[[1,null],null,[],[4]]"#;
+
+    test!(array, expected)
+}
+
+#[test]
+fn sliced_list() -> Result<()> {
+    let data_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from_slice([0, 2, 3, 5]),
+        Arc::new(Int32Array::from_slice([1, 2, 3, 4, 5])),
+        None,
+    )
+    .slice(1, 2);
+
+    let expected = r#"[[3],[4,5]]"#;
+
+    test!(array, expected)
+}
+
+#[test]
+fn fixed_size_list() -> Result<()> {
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    let array = FixedSizeListArray::from_data(
+        data_type,
+        Arc::new(Int32Array::from([
+            Some(1),
+            None,
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(6),
+        ])),
+        Some(Bitmap::from([true, false, true])),
+    );
+
+    let expected = r#"[[1,null],null,[5,6]]"#;
+
+    test!(array, expected)
+}
+
 #[test]
 fn escaped_end_of_line_in_utf8() -> Result<()> {
     let array = Utf8Array::<i32>::from(&vec![Some("a\na"), None]);
@@ -289,3 +342,110 @@ fn write_timestamp() -> Result<()> {
 
     test!(array, expected)
 }
+
+#[test]
+fn write_decimal() -> Result<()> {
+    let array = Int128Array::from([Some(12345), Some(-5), None]).to(DataType::Decimal(10, 2));
+
+    let expected = r#"[123.45,-0.05,null]"#;
+
+    test!(array, expected)
+}
+
+fn write_stream(arrays: &[&dyn Array], options: json_write::WriteOptions) -> Result<String> {
+    let mut writer = json_write::StreamWriter::new(vec![], options);
+    writer.start()?;
+    for array in arrays {
+        writer.write(*array)?;
+    }
+    writer.finish()?;
+    Ok(String::from_utf8(writer.into_inner()).unwrap())
+}
+
+fn records() -> StructArray {
+    let list = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Int32),
+        Buffer::from_slice([0, 2, 2]),
+        Arc::new(Int32Array::from_slice([1, 2])),
+        None,
+    );
+    let fields = vec![
+        Field::new("c", DataType::Utf8, true),
+        Field::new("a", list.data_type().clone(), true),
+        Field::new("b", DataType::Boolean, true),
+    ];
+    StructArray::from_data(
+        DataType::Struct(fields),
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["x: {y}, [z]", "\"q\""])),
+            Arc::new(list),
+            Arc::new(BooleanArray::from([Some(true), None])),
+        ],
+        None,
+    )
+}
+
+#[test]
+fn stream_writer() -> Result<()> {
+    let array = records();
+    let result = write_stream(&[&array, &array.slice(1, 1)], Default::default())?;
+
+    let expected = r#"[{"c":"x: {y}, [z]","a":[1,2],"b":true},{"c":"\"q\"","a":[],"b":null},{"c":"\"q\"","a":[],"b":null}]"#;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn stream_writer_empty() -> Result<()> {
+    let options = json_write::WriteOptions {
+        pretty: true,
+        ..Default::default()
+    };
+    let array = Int32Array::from_slice([]);
+    assert_eq!(write_stream(&[&array], options)?, "[]");
+    Ok(())
+}
+
+#[test]
+fn stream_writer_pretty() -> Result<()> {
+    let options = json_write::WriteOptions {
+        pretty: true,
+        field_order: json_write::FieldOrder::Sorted,
+    };
+    let result = write_stream(&[&records()], options)?;
+
+    let expected = r#"[
+  {
+    "a": [
+      1,
+      2
+    ],
+    "b": true,
+    "c": "x: {y}, [z]"
+  },
+  {
+    "a": [],
+    "b": null,
+    "c": "\"q\""
+  }
+]"#;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn stream_writer_custom_order() -> Result<()> {
+    let options = json_write::WriteOptions {
+        field_order: json_write::FieldOrder::Custom(vec![
+            "b".to_string(),
+            "d".to_string(),
+            "b".to_string(),
+        ]),
+        ..Default::default()
+    };
+    let result = write_stream(&[&records().slice(0, 1)], options)?;
+
+    let expected = r#"[{"b":true,"c":"x: {y}, [z]","a":[1,2]}]"#;
+    assert_eq!(result, expected);
+    Ok(())
+}
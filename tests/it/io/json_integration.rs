@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+use arrow2::io::ipc::write::default_ipc_fields;
+use arrow2::io::json_integration::{read, write};
+use serde_json::Value;
+
+fn round_trip(array: Arc<dyn Array>) -> Result<()> {
+    let fields = vec![Field::new("a", array.data_type().clone(), true)];
+    let ipc_fields = default_ipc_fields(&fields);
+
+    let column = write::serialize_array(array.as_ref(), "a".to_string());
+    let result = read::to_array(
+        array.data_type().clone(),
+        &ipc_fields[0],
+        &column,
+        &HashMap::new(),
+    )?;
+    assert_eq!(array.as_ref(), result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn primitive() -> Result<()> {
+    round_trip(Arc::new(Int32Array::from([Some(1), None, Some(3)])))?;
+    round_trip(Arc::new(Float64Array::from([Some(1.5), None, Some(-3.0)])))?;
+    round_trip(Arc::new(BooleanArray::from([
+        Some(true),
+        None,
+        Some(false),
+    ])))
+}
+
+#[test]
+fn large_integers() -> Result<()> {
+    round_trip(Arc::new(Int64Array::from([
+        Some(i64::MAX),
+        None,
+        Some(i64::MIN),
+    ])))?;
+
+    let array = Int64Array::from_slice([i64::MAX]);
+    let column = write::serialize_array(&array, "a".to_string());
+    assert_eq!(Some(vec![Value::String(i64::MAX.to_string())]), column.data);
+    Ok(())
+}
+
+#[test]
+fn non_finite_floats() {
+    let array = Float64Array::from_slice([f64::NAN, f64::INFINITY, 1.0]);
+    let column = write::serialize_array(&array, "a".to_string());
+    assert_eq!(
+        Some(vec![
+            Value::String("NaN".to_string()),
+            Value::String("inf".to_string()),
+            Value::from(1.0),
+        ]),
+        column.data
+    );
+}
+
+#[test]
+fn utf8_sliced() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("bb"), Some("ccc")]).slice(2, 2);
+    let column = write::serialize_array(&array, "a".to_string());
+    assert_eq!(
+        Some(vec![Value::from(0), Value::from(2), Value::from(5)]),
+        column.offset
+    );
+    round_trip(Arc::new(array))?;
+    round_trip(Arc::new(Utf8Array::<i64>::from([Some("a"), None])))?;
+    round_trip(Arc::new(BinaryArray::<i32>::from([Some(b"ab"), None])))
+}
+
+#[test]
+fn list_sliced() -> Result<()> {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4), None, Some(6)]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data)?;
+    let array: ListArray<i32> = array.into();
+
+    round_trip(Arc::new(array.slice(1, 2)))
+}
+
+#[test]
+fn struct_() -> Result<()> {
+    let fields = vec![
+        Field::new("b", DataType::Boolean, true),
+        Field::new("c", DataType::Int32, true),
+    ];
+    let array = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![
+            Arc::new(BooleanArray::from_slice([false, true])),
+            Arc::new(Int32Array::from([Some(1), None])),
+        ],
+        None,
+    );
+    round_trip(Arc::new(array))
+}
+
+#[test]
+fn dictionary_values_as_child() {
+    let keys = PrimitiveArray::<i32>::from([Some(1), None, Some(0)]);
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b"]));
+    let array = DictionaryArray::<i32>::from_data(keys, values);
+
+    let column = write::serialize_array(&array, "a".to_string());
+    assert_eq!(Some(vec![1, 0, 1]), column.validity);
+    let children = column.children.unwrap();
+    assert_eq!(
+        Some(vec![Value::from("a"), Value::from("b")]),
+        children[0].data
+    );
+}
+
+#[test]
+fn write_chunk_lines() -> Result<()> {
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("a"), Some("bb")])),
+    ]);
+
+    let mut buf = vec![];
+    write::write_chunk(&mut buf, &chunk, &["a", "b"])?;
+    write::write_chunk(&mut buf, &chunk, &["a", "b"])?;
+
+    let lines = String::from_utf8(buf).unwrap();
+    let lines = lines.lines().collect::<Vec<_>>();
+    assert_eq!(2, lines.len());
+
+    let expected = r#"{"count":2,"columns":[{"name":"a","count":2,"VALIDITY":[1,0],"DATA":[1,0],"OFFSET":null,"TYPE_ID":null,"children":null},{"name":"b","count":2,"VALIDITY":[1,1],"DATA":["a","bb"],"OFFSET":[0,1,3],"TYPE_ID":null,"children":null}]}"#;
+    assert_eq!(expected, lines[0]);
+    Ok(())
+}
@@ -7,9 +7,21 @@ mod json;
 #[cfg(feature = "io_json")]
 mod ndjson;
 
+#[cfg(feature = "io_json_integration")]
+mod json_integration;
+
+#[cfg(feature = "io_serde")]
+mod serde;
+
 #[cfg(feature = "io_ipc")]
 mod ipc;
 
+#[cfg(feature = "io_feather")]
+mod feather;
+
+#[cfg(feature = "io_flight")]
+mod flight;
+
 #[cfg(feature = "io_parquet")]
 mod parquet;
 
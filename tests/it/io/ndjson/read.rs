@@ -2,7 +2,7 @@ use std::io::Cursor;
 use std::sync::Arc;
 
 use arrow2::array::*;
-use arrow2::datatypes::{DataType, Field};
+use arrow2::datatypes::{DataType, Field, IntegerType};
 use arrow2::error::Result;
 use arrow2::io::ndjson::read as ndjson_read;
 use arrow2::io::ndjson::read::FallibleStreamingIterator;
@@ -244,3 +244,179 @@ fn skip_empty_lines() -> Result<()> {
     assert_eq!(3, arrays[0].len());
     Ok(())
 }
+
+fn evolving_rows() -> Vec<String> {
+    vec![
+        r#"{"a": 1, "b": "x"}"#.to_string(),
+        r#"{"a": 2}"#.to_string(),
+        r#"{"a": 3, "b": "y", "c": [1, 2], "d": null}"#.to_string(),
+        r#"null"#.to_string(),
+    ]
+}
+
+#[test]
+fn policy_null_fill() -> Result<()> {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let rows = evolving_rows();
+
+    let result = ndjson_read::deserialize_with_policy(
+        &rows,
+        data_type.clone(),
+        &ndjson_read::RecordPolicy::NullFill,
+    )?;
+    assert_eq!(result, ndjson_read::deserialize(&rows, data_type)?);
+
+    let expected = StructArray::from_data(
+        result.data_type().clone(),
+        vec![
+            Arc::new(Int64Array::from([Some(1), Some(2), Some(3), None])),
+            Arc::new(Utf8Array::<i32>::from([Some("x"), None, Some("y"), None])),
+        ],
+        None,
+    );
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn policy_error() -> Result<()> {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let policy = ndjson_read::RecordPolicy::Error;
+
+    let rows = evolving_rows();
+    assert!(ndjson_read::deserialize_with_policy(&rows[..2], data_type.clone(), &policy).is_ok());
+
+    let error = ndjson_read::deserialize_with_policy(&rows[..3], data_type.clone(), &policy);
+    assert!(error.unwrap_err().to_string().contains("\"c\""));
+
+    let error = ndjson_read::deserialize_with_policy(&rows[3..], data_type.clone(), &policy);
+    assert!(error.is_err());
+
+    // missing non-nullable field
+    let rows = vec![r#"{"b": "x"}"#.to_string()];
+    let error = ndjson_read::deserialize_with_policy(&rows, data_type, &policy);
+    assert!(error.unwrap_err().to_string().contains("\"a\""));
+    Ok(())
+}
+
+#[test]
+fn policy_collect_extras() -> Result<()> {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int64, true),
+        ndjson_read::extras_field("_extras"),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let policy = ndjson_read::RecordPolicy::CollectExtras("_extras".to_string());
+
+    let result = ndjson_read::deserialize_with_policy(&evolving_rows(), data_type, &policy)?;
+    let result = result.as_any().downcast_ref::<StructArray>().unwrap();
+
+    assert_eq!(
+        result.values()[0].as_ref(),
+        &Int64Array::from([Some(1), Some(2), Some(3), None]) as &dyn Array
+    );
+    assert_eq!(
+        result.values()[2].as_ref(),
+        &Utf8Array::<i32>::from([Some("x"), None, Some("y"), None]) as &dyn Array
+    );
+
+    let extras = result.values()[1]
+        .as_any()
+        .downcast_ref::<MapArray>()
+        .unwrap();
+    assert_eq!(extras.offsets().as_slice(), &[0, 0, 0, 2, 2]);
+    assert_eq!(extras.validity(), Some(&[true, true, true, false].into()));
+    let entries = extras
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(
+        entries.values()[0].as_ref(),
+        &Utf8Array::<i32>::from_slice(["c", "d"]) as &dyn Array
+    );
+    assert_eq!(
+        entries.values()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(["[1,2]", "null"]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn policy_invalid() {
+    let rows = evolving_rows();
+    let policy = ndjson_read::RecordPolicy::CollectExtras("_extras".to_string());
+
+    // not a struct
+    assert!(ndjson_read::deserialize_with_policy(&rows, DataType::Int64, &policy).is_err());
+
+    // the extras field is missing
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int64, true)]);
+    assert!(ndjson_read::deserialize_with_policy(&rows, data_type, &policy).is_err());
+
+    // the extras field has an invalid data type
+    let data_type = DataType::Struct(vec![Field::new("_extras", DataType::Utf8, true)]);
+    assert!(ndjson_read::deserialize_with_policy(&rows, data_type, &policy).is_err());
+}
+
+#[test]
+fn dictionary_fields() -> Result<()> {
+    let ndjson = r#"{"id": 1, "country": "pt", "tags": ["a", "b"]}
+    {"id": 2, "country": "de", "tags": ["b"]}
+    {"id": 3, "country": "pt", "tags": null}
+    {"id": 4, "country": null, "tags": ["a", null]}
+    {"id": 5, "country": 10, "tags": []}
+    "#;
+    let data_type = infer(ndjson)?;
+    let data_type =
+        ndjson_read::with_dictionary_fields(data_type, &["country", "tags"], IntegerType::UInt8)?;
+
+    let mut arrays = read_and_deserialize(ndjson, &data_type, 1000)?;
+    let array = arrays.pop().unwrap();
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+
+    let mut expected = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+    expected.try_extend(vec![Some("pt"), Some("de"), Some("pt"), None, Some("10")])?;
+    let expected: DictionaryArray<u8> = expected.into();
+    let country = array.values()[1]
+        .as_any()
+        .downcast_ref::<DictionaryArray<u8>>()
+        .unwrap();
+    assert_eq!(country, &expected);
+    // each distinct string is stored once
+    assert_eq!(country.values().len(), 3);
+
+    let mut expected =
+        MutableListArray::<i32, MutableDictionaryArray<u8, MutableUtf8Array<i32>>>::new();
+    expected.try_extend(vec![
+        Some(vec![Some("a"), Some("b")]),
+        Some(vec![Some("b")]),
+        None,
+        Some(vec![Some("a"), None]),
+        Some(vec![]),
+    ])?;
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(array.values()[2].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn dictionary_fields_invalid() {
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int64, true)]);
+    // not a string
+    assert!(
+        ndjson_read::with_dictionary_fields(data_type.clone(), &["a"], IntegerType::Int32).is_err()
+    );
+    // not a field
+    assert!(ndjson_read::with_dictionary_fields(data_type, &["b"], IntegerType::Int32).is_err());
+    // not a struct
+    assert!(
+        ndjson_read::with_dictionary_fields(DataType::Utf8, &["a"], IntegerType::Int32).is_err()
+    );
+}
@@ -9,6 +9,7 @@ use arrow2::{
 use crate::io::ipc::read_gzip_json;
 
 mod read;
+mod remote;
 mod write;
 mod write_async;
 
@@ -687,6 +688,26 @@ pub fn pyarrow_struct_statistics(column: &str) -> Option<Box<dyn Statistics>> {
     }
 }
 
+/// Writes the row groups of `row_groups` to an in-memory parquet file of `schema`.
+fn write_row_groups<A, I>(
+    row_groups: RowGroupIterator<A, I>,
+    schema: &Schema,
+    options: WriteOptions,
+) -> Result<Vec<u8>>
+where
+    A: AsRef<dyn Array> + 'static + Send + Sync,
+    I: Iterator<Item = Result<Chunk<A>>>,
+{
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_size, writer) = writer.end(None)?;
+    Ok(writer.into_inner())
+}
+
 /// Round-trip with parquet using the same integration files used for IPC integration tests.
 fn integration_write(schema: &Schema, batches: &[Chunk<Arc<dyn Array>>]) -> Result<Vec<u8>> {
     let options = WriteOptions {
@@ -699,7 +720,7 @@ fn integration_write(schema: &Schema, batches: &[Chunk<Arc<dyn Array>>]) -> Resu
         .fields
         .iter()
         .map(|x| {
-            if let DataType::Dictionary(..) = x.data_type() {
+            if can_encode(x.data_type(), Encoding::RleDictionary) {
                 Encoding::RleDictionary
             } else {
                 Encoding::Plain
@@ -710,18 +731,7 @@ fn integration_write(schema: &Schema, batches: &[Chunk<Arc<dyn Array>>]) -> Resu
     let row_groups =
         RowGroupIterator::try_new(batches.iter().cloned().map(Ok), schema, options, encodings)?;
 
-    let writer = Cursor::new(vec![]);
-
-    let mut writer = FileWriter::try_new(writer, schema.clone(), options)?;
-
-    writer.start()?;
-    for group in row_groups {
-        let (group, len) = group?;
-        writer.write(group, len)?;
-    }
-    let (_size, writer) = writer.end(None)?;
-
-    Ok(writer.into_inner())
+    write_row_groups(row_groups, schema, options)
 }
 
 type IntegrationRead = (Schema, Vec<Chunk<Arc<dyn Array>>>);
@@ -819,3 +829,454 @@ fn arrow_type() -> Result<()> {
     assert_eq!(new_batches, vec![batch]);
     Ok(())
 }
+
+/// Tests that logical types without a parquet equivalent and the schema's metadata roundtrip
+/// via the arrow schema stored in parquet's key-value metadata.
+#[test]
+fn arrow_schema_metadata() -> Result<()> {
+    let dt1 = DataType::Timestamp(TimeUnit::Microsecond, Some("America/New_York".to_string()));
+    let array1 = PrimitiveArray::<i64>::from([Some(1), None]).to(dt1.clone());
+    let dt2 = DataType::Extension(
+        "uuid".to_string(),
+        Box::new(DataType::FixedSizeBinary(2)),
+        Some("metadata".to_string()),
+    );
+    let array2 = FixedSizeBinaryArray::from_data(dt2.clone(), vec![1, 2, 3, 4].into(), None);
+
+    let mut metadata = Metadata::new();
+    metadata.insert("key".to_string(), "value".to_string());
+    let schema = Schema::from(vec![
+        Field::new("a1", dt1, true),
+        Field::new("a2", dt2, false),
+    ])
+    .with_metadata(metadata);
+    let batch = Chunk::try_new(vec![Arc::new(array1) as Arc<dyn Array>, Arc::new(array2)])?;
+
+    let r = integration_write(&schema, std::slice::from_ref(&batch))?;
+
+    let (new_schema, new_batches) = integration_read(&r)?;
+
+    assert_eq!(new_schema, schema);
+    assert_eq!(new_batches, vec![batch]);
+    Ok(())
+}
+
+/// Tests that the validity of arrays sliced at offsets that are not multiple of 8 roundtrips.
+#[test]
+fn sliced_validity() -> Result<()> {
+    let values = (0..100).map(|x| if x % 3 == 0 { None } else { Some(x) });
+    let array1 = Int32Array::from_iter(values.clone()).slice(3, 90);
+    let array2 = BooleanArray::from_iter(values.map(|x| x.map(|x| x % 2 == 0))).slice(5, 90);
+
+    let schema = Schema::from(vec![
+        Field::new("a1", array1.data_type().clone(), true),
+        Field::new("a2", array2.data_type().clone(), true),
+    ]);
+    let batch = Chunk::try_new(vec![Arc::new(array1) as Arc<dyn Array>, Arc::new(array2)])?;
+
+    let r = integration_write(&schema, std::slice::from_ref(&batch))?;
+
+    let (new_schema, new_batches) = integration_read(&r)?;
+
+    assert_eq!(new_schema, schema);
+    assert_eq!(new_batches, vec![batch]);
+    Ok(())
+}
+
+#[test]
+fn read_pages_into_reuses_arrays() -> Result<()> {
+    let lengths = [30, 20, 10];
+    let batches = lengths
+        .iter()
+        .map(|&length| {
+            let ints = Int32Array::from_iter((0..length).map(|x| (x % 3 != 0).then_some(x)));
+            let strings = Utf8Array::<i32>::from_iter(
+                (0..length).map(|x| (x % 4 != 0).then(|| format!("value {}", x))),
+            );
+            Chunk::try_new(vec![Arc::new(ints) as Arc<dyn Array>, Arc::new(strings)])
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let schema = Schema::from(vec![
+        Field::new("a1", DataType::Int32, true),
+        Field::new("a2", DataType::Utf8, true),
+    ]);
+
+    let data = integration_write(&schema, &batches)?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    assert_eq!(metadata.row_groups.len(), lengths.len());
+
+    let mut ints = MutablePrimitiveArray::<i32>::new();
+    let mut strings = MutableUtf8Array::<i32>::new();
+    let mut pointers = None;
+    for (row_group, batch) in metadata.row_groups.iter().zip(batches.iter()) {
+        let pages = get_page_iterator(&row_group.columns()[0], &mut reader, None, vec![])?;
+        read_pages_into(BasicDecompressor::new(pages, vec![]), &mut ints)?;
+        let pages = get_page_iterator(&row_group.columns()[1], &mut reader, None, vec![])?;
+        read_pages_into(BasicDecompressor::new(pages, vec![]), &mut strings)?;
+
+        let validity = ints.validity().unwrap().iter();
+        let result = ints
+            .values()
+            .iter()
+            .zip(validity)
+            .map(|(x, is_valid)| is_valid.then_some(*x))
+            .collect::<Vec<_>>();
+        let expected = batch.arrays()[0]
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(
+            result,
+            expected.iter().map(|x| x.copied()).collect::<Vec<_>>()
+        );
+
+        let validity = strings.validity().unwrap().iter();
+        let result = strings
+            .offsets()
+            .windows(2)
+            .zip(validity)
+            .map(|(w, is_valid)| is_valid.then(|| &strings.values()[w[0] as usize..w[1] as usize]))
+            .collect::<Vec<_>>();
+        let expected = batch.arrays()[1]
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .unwrap();
+        assert_eq!(
+            result,
+            expected
+                .iter()
+                .map(|x| x.map(|x| x.as_bytes()))
+                .collect::<Vec<_>>()
+        );
+
+        // the row groups are decreasing in size, so the allocations are reused
+        let new_pointers = (ints.values().as_ptr(), strings.values().as_ptr());
+        if let Some(pointers) = pointers {
+            assert_eq!(pointers, new_pointers);
+        }
+        pointers = Some(new_pointers);
+    }
+    Ok(())
+}
+
+#[test]
+fn read_pages_into_unsupported() -> Result<()> {
+    let array = Int32Array::from_slice([1, 2, 3]);
+    let schema = Schema::from(vec![Field::new("a1", DataType::Int32, false)]);
+    let batch = Chunk::try_new(vec![Arc::new(array) as Arc<dyn Array>])?;
+
+    let data = integration_write(&schema, &[batch])?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+
+    let pages = get_page_iterator(
+        &metadata.row_groups[0].columns()[0],
+        &mut reader,
+        None,
+        vec![],
+    )?;
+    let mut array = MutableFixedSizeBinaryArray::new(4);
+    assert!(read_pages_into(BasicDecompressor::new(pages, vec![]), &mut array).is_err());
+    Ok(())
+}
+
+fn read_with_limits(data: &[u8], limits: BinaryLimits) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    let mut reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    reader.set_binary_limits(limits);
+    reader.collect()
+}
+
+#[test]
+fn binary_limits() -> Result<()> {
+    let strings = Utf8Array::<i32>::from([Some("aa"), None, Some("bbbb"), Some("aa")]);
+    let binaries = BinaryArray::<i64>::from_slice(["cc", "dddddd", "", "cc"]);
+    let schema = Schema::from(vec![
+        Field::new("a1", DataType::Utf8, true),
+        Field::new("a2", DataType::LargeBinary, false),
+    ]);
+    let batch = Chunk::try_new(vec![
+        Arc::new(strings) as Arc<dyn Array>,
+        Arc::new(binaries) as Arc<dyn Array>,
+    ])?;
+    let data = integration_write(&schema, std::slice::from_ref(&batch))?;
+
+    let limits = BinaryLimits {
+        max_value_length: 6,
+        max_total_bytes: 10,
+        ..Default::default()
+    };
+    assert_eq!(read_with_limits(&data, limits)?, vec![batch]);
+
+    let limits = BinaryLimits {
+        max_value_length: 5,
+        ..Default::default()
+    };
+    assert!(read_with_limits(&data, limits).is_err());
+
+    let limits = BinaryLimits {
+        max_total_bytes: 9,
+        ..Default::default()
+    };
+    assert!(read_with_limits(&data, limits).is_err());
+    Ok(())
+}
+
+#[test]
+fn binary_limits_dictionary() -> Result<()> {
+    let values = Utf8Array::<i32>::from_slice(["aaaa", "b"]);
+    let keys = PrimitiveArray::<i32>::from_slice([0, 0, 1, 0]);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), false)]);
+    let batch = Chunk::try_new(vec![Arc::new(array) as Arc<dyn Array>])?;
+    let data = integration_write(&schema, &[batch])?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let row_group = &metadata.row_groups[0];
+    let field = Field::new("a1", DataType::Utf8, false);
+
+    // the dictionary is small, but the column chunk decodes to 13 bytes
+    let limits = BinaryLimits {
+        max_value_length: 4,
+        max_total_bytes: 13,
+        ..Default::default()
+    };
+    let mut columns =
+        read_columns_many_with_limits(&mut reader, row_group, vec![field.clone()], None, limits)?;
+    let array = columns.pop().unwrap().next().unwrap()?;
+    let expected = Utf8Array::<i32>::from_slice(["aaaa", "aaaa", "b", "aaaa"]);
+    assert_eq!(array.as_ref(), &expected as &dyn Array);
+
+    let limits = BinaryLimits {
+        max_total_bytes: 12,
+        ..Default::default()
+    };
+    let mut columns =
+        read_columns_many_with_limits(&mut reader, row_group, vec![field], None, limits)?;
+    assert!(columns.pop().unwrap().next().unwrap().is_err());
+
+    // read as a dictionary, the limits apply to its values
+    let field = Field::new("a1", schema.fields[0].data_type().clone(), false);
+    let limits = BinaryLimits {
+        max_value_length: 3,
+        ..Default::default()
+    };
+    let mut columns =
+        read_columns_many_with_limits(&mut reader, row_group, vec![field], None, limits)?;
+    assert!(columns.pop().unwrap().next().unwrap().is_err());
+    Ok(())
+}
+
+fn read_utf8(
+    data: &[u8],
+    data_type: DataType,
+    validation: Utf8Validation,
+) -> Result<Arc<dyn Array>> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let field = Field::new("a1", data_type, true);
+    let limits = BinaryLimits {
+        utf8_validation: validation,
+        ..Default::default()
+    };
+    let mut columns = read_columns_many_with_limits(
+        &mut reader,
+        &metadata.row_groups[0],
+        vec![field],
+        None,
+        limits,
+    )?;
+    columns.pop().unwrap().next().unwrap()
+}
+
+#[test]
+fn utf8_validation() -> Result<()> {
+    let array = BinaryArray::<i32>::from([Some(b"a".as_ref()), Some(b"b\xffc"), None, Some(b"d")]);
+    let schema = Schema::from(vec![Field::new("a1", DataType::Binary, true)]);
+    let batch = Chunk::try_new(vec![Arc::new(array) as Arc<dyn Array>])?;
+    let data = integration_write(&schema, &[batch])?;
+
+    assert!(read_utf8(&data, DataType::Utf8, Utf8Validation::Validate).is_err());
+    assert!(read_utf8(&data, DataType::Utf8, Utf8Validation::default()).is_err());
+
+    let array = read_utf8(&data, DataType::Utf8, Utf8Validation::Skip)?;
+    let expected = Utf8Array::<i32>::from([Some("a"), None, None, Some("d")]);
+    assert_eq!(array.as_ref(), &expected as &dyn Array);
+
+    let array = read_utf8(&data, DataType::LargeUtf8, Utf8Validation::Lossy)?;
+    let expected = Utf8Array::<i64>::from([Some("a"), Some("b\u{FFFD}c"), None, Some("d")]);
+    assert_eq!(array.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn utf8_validation_dictionary() -> Result<()> {
+    let values = BinaryArray::<i32>::from_slice([b"a".as_ref(), b"\xc3"]);
+    let keys = PrimitiveArray::<i32>::from([Some(0), Some(1), None, Some(0)]);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let batch = Chunk::try_new(vec![Arc::new(array) as Arc<dyn Array>])?;
+    let data = integration_write(&schema, &[batch])?;
+
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    assert!(read_utf8(&data, data_type.clone(), Utf8Validation::Validate).is_err());
+
+    let array = read_utf8(&data, data_type.clone(), Utf8Validation::Skip)?;
+    let array = array
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    let values = Utf8Array::<i32>::from([Some("a"), None]);
+    assert_eq!(array.values().as_ref(), &values as &dyn Array);
+
+    let array = read_utf8(&data, data_type, Utf8Validation::Lossy)?;
+    let array = array
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    let values = Utf8Array::<i32>::from_slice(["a", "\u{FFFD}"]);
+    assert_eq!(array.values().as_ref(), &values as &dyn Array);
+
+    // materialized into a utf8 array, invalid values are validated as well
+    let array = read_utf8(&data, DataType::Utf8, Utf8Validation::Skip)?;
+    let expected = Utf8Array::<i32>::from([Some("a"), None, None, Some("a")]);
+    assert_eq!(array.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn binary_invalid_length_prefix() -> Result<()> {
+    let value = "a".repeat(20);
+    let array = Utf8Array::<i32>::from_slice([value.as_str()]);
+    let schema = Schema::from(vec![Field::new("a1", DataType::Utf8, false)]);
+    let batch = Chunk::try_new(vec![Arc::new(array) as Arc<dyn Array>])?;
+    let mut data = integration_write(&schema, &[batch])?;
+
+    // corrupt the length prefix of the (plain-encoded) value
+    let mut prefixed = 20u32.to_le_bytes().to_vec();
+    prefixed.extend_from_slice(value.as_bytes());
+    let position = data
+        .windows(prefixed.len())
+        .position(|x| x == prefixed.as_slice())
+        .unwrap();
+    data[position..position + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+
+    // length prefixes are only validated when a limit is set
+    let limits = BinaryLimits {
+        max_total_bytes: 1 << 30,
+        ..Default::default()
+    };
+    assert!(read_with_limits(&data, limits).is_err());
+    Ok(())
+}
+
+fn list_of_dictionary<K: DictionaryKey>(values: Arc<dyn Array>) -> ListArray<i32> {
+    // [[0, 1], None, [None], [0, 2], []]
+    let keys = PrimitiveArray::<K>::from([
+        Some(K::from_usize(0).unwrap()),
+        Some(K::from_usize(1).unwrap()),
+        None,
+        Some(K::from_usize(0).unwrap()),
+        Some(K::from_usize(2).unwrap()),
+    ]);
+    let values = DictionaryArray::<K>::from_data(keys, values);
+    let data_type = ListArray::<i32>::default_datatype(values.data_type().clone());
+    ListArray::<i32>::new(
+        data_type,
+        Buffer::from(vec![0, 2, 2, 3, 5, 5]),
+        Arc::new(values),
+        Some(Bitmap::from([true, false, true, true, true])),
+    )
+}
+
+#[test]
+fn list_of_dictionary_roundtrip() -> Result<()> {
+    let utf8 = list_of_dictionary::<i32>(Arc::new(Utf8Array::<i32>::from_slice(["a", "bb", "c"])));
+    let int64 = list_of_dictionary::<u8>(Arc::new(Int64Array::from_slice([10, 20, 30])));
+    let schema = Schema::from(vec![
+        Field::new("a", utf8.data_type().clone(), true),
+        Field::new("b", int64.data_type().clone(), true),
+    ]);
+    let batch = Chunk::try_new(vec![Arc::new(utf8) as Arc<dyn Array>, Arc::new(int64) as _])?;
+    let data = integration_write(&schema, std::slice::from_ref(&batch))?;
+
+    let (read_schema, read_batches) = integration_read(&data)?;
+    assert_eq!(read_schema, schema);
+    assert_eq!(read_batches, vec![batch]);
+
+    // the dictionary is preserved instead of being densified
+    let array = read_batches[0].arrays()[0]
+        .as_any()
+        .downcast_ref::<ListArray<i32>>()
+        .unwrap();
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(values.values().len(), 3);
+    Ok(())
+}
+
+#[test]
+fn extension_types() -> Result<()> {
+    let extension = |data_type: DataType| {
+        DataType::Extension(
+            "ext".to_string(),
+            Box::new(data_type),
+            Some("metadata".to_string()),
+        )
+    };
+    let values =
+        PrimitiveArray::<i64>::from([Some(1), None, Some(3)]).to(extension(DataType::Int64));
+    let utf8 = Utf8Array::<i32>::from_data(
+        extension(DataType::Utf8),
+        vec![0, 1, 1, 3].into(),
+        b"acc".to_vec().into(),
+        Some([true, false, true].into()),
+    );
+
+    // a list of extension values
+    let items = PrimitiveArray::<i64>::from([Some(1), None, Some(3), Some(4)])
+        .to(extension(DataType::Int64));
+    let dt = DataType::List(Box::new(Field::new(
+        "item",
+        items.data_type().clone(),
+        true,
+    )));
+    let list = ListArray::<i32>::from_data(
+        dt.clone(),
+        vec![0, 2, 3, 4].into(),
+        Arc::new(items.clone()),
+        None,
+    );
+    // an extension whose storage is a list
+    let ext_list = ListArray::<i32>::from_data(
+        extension(dt),
+        vec![0, 1, 2, 4].into(),
+        Arc::new(items),
+        None,
+    );
+
+    let schema = Schema::from(vec![
+        Field::new("a1", values.data_type().clone(), true),
+        Field::new("a2", utf8.data_type().clone(), true),
+        Field::new("a3", list.data_type().clone(), true),
+        Field::new("a4", ext_list.data_type().clone(), true),
+    ]);
+    let batch = Chunk::try_new(vec![
+        Arc::new(values) as Arc<dyn Array>,
+        Arc::new(utf8),
+        Arc::new(list),
+        Arc::new(ext_list),
+    ])?;
+
+    let r = integration_write(&schema, std::slice::from_ref(&batch))?;
+
+    let (new_schema, new_batches) = integration_read(&r)?;
+
+    assert_eq!(new_schema, schema);
+    assert_eq!(new_batches, vec![batch]);
+    Ok(())
+}
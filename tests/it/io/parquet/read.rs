@@ -546,3 +546,330 @@ fn invalid_utf8() {
         error
     );
 }
+
+/// Writes `chunk` to parquet with the parquet schema `message`, i.e. without logical types
+/// nor the arrow schema, as legacy writers do.
+fn write_legacy(
+    chunk: Chunk<Arc<dyn Array>>,
+    message: &str,
+    key_value_metadata: Option<Vec<KeyValue>>,
+) -> Result<Vec<u8>> {
+    let parquet_schema = SchemaDescriptor::try_from_message(message)?;
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let encodings = vec![Encoding::Plain; chunk.arrays().len()];
+    let num_rows = chunk.len();
+    let row_group = row_group_iter(chunk, encodings, parquet_schema.columns().to_vec(), options);
+
+    let mut writer =
+        parquet2::write::FileWriter::new(Cursor::new(vec![]), parquet_schema, options, None);
+    writer.start()?;
+    writer.write(row_group, num_rows)?;
+    let (_, writer) = writer.end(key_value_metadata)?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn legacy_converted_types() -> Result<()> {
+    let message = "
+    message schema {
+        REQUIRED INT32 time (TIME_MILLIS);
+        REQUIRED INT32 uint8 (UINT_8);
+        REQUIRED INT32 uint16 (UINT_16);
+        REQUIRED INT64 timestamp (TIMESTAMP_MILLIS);
+    }
+    ";
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([1, 2]).to(DataType::Time32(TimeUnit::Millisecond)))
+            as Arc<dyn Array>,
+        Arc::new(UInt8Array::from_slice([1, 255])),
+        Arc::new(UInt16Array::from_slice([1, 65535])),
+        Arc::new(
+            Int64Array::from_slice([1, 2]).to(DataType::Timestamp(TimeUnit::Millisecond, None)),
+        ),
+    ]);
+    let data = write_legacy(chunk.clone(), message, None)?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let schema = infer_schema(&metadata)?;
+    let data_types = schema
+        .fields
+        .iter()
+        .map(|x| x.data_type().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        data_types,
+        chunk
+            .arrays()
+            .iter()
+            .map(|x| x.data_type().clone())
+            .collect::<Vec<_>>()
+    );
+
+    let result = FileReader::try_new(&mut reader, None, None, None, None)?
+        .next()
+        .unwrap()?;
+    assert_eq!(result, chunk);
+
+    // the legacy unit is converted to the requested unit
+    let field = Field::new(
+        "timestamp",
+        DataType::Timestamp(TimeUnit::Microsecond, None),
+        false,
+    );
+    let row_group = &metadata.row_groups[0];
+    let mut columns = read_columns_many(&mut reader, row_group, vec![field.clone()], None)?;
+    let array = columns.pop().unwrap().next().unwrap()?;
+    let expected =
+        Int64Array::from_slice([1000, 2000]).to(DataType::Timestamp(TimeUnit::Microsecond, None));
+    assert_eq!(array.as_ref(), &expected as &dyn Array);
+
+    let statistics = deserialize_statistics(&field, row_group.columns())?
+        .pop()
+        .unwrap()
+        .unwrap();
+    let statistics = statistics
+        .as_any()
+        .downcast_ref::<PrimitiveStatistics<i64>>()
+        .unwrap();
+    assert_eq!(statistics.min_value, Some(1000));
+    assert_eq!(statistics.max_value, Some(2000));
+    Ok(())
+}
+
+/// Returns the metadata key with `schema` encoded in one of the formats used by arrow
+/// implementations over time.
+fn arrow_schema_key(schema: &Schema, prefix: &[u8]) -> KeyValue {
+    use arrow2::io::ipc::write::{default_ipc_fields, schema_to_bytes};
+
+    let message = schema_to_bytes(schema, &default_ipc_fields(&schema.fields));
+    let mut bytes = prefix.to_vec();
+    if !prefix.is_empty() {
+        bytes.extend_from_slice(&(message.len() as u32).to_le_bytes());
+    }
+    bytes.extend_from_slice(&message);
+    KeyValue {
+        key: "ARROW:schema".to_string(),
+        value: Some(base64::encode(&bytes)),
+    }
+}
+
+#[test]
+fn arrow_schema_encodings() -> Result<()> {
+    let message = "
+    message schema {
+        OPTIONAL INT64 a;
+    }
+    ";
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+    let chunk = Chunk::new(vec![
+        Arc::new(Int64Array::from([Some(1), None]).to(data_type.clone())) as Arc<dyn Array>,
+    ]);
+    let schema = Schema::from(vec![Field::new("a", data_type, true)]);
+
+    // current format (with continuation) and no prefix
+    for prefix in [&[255u8, 255, 255, 255] as &[u8], &[]] {
+        let key_value = arrow_schema_key(&schema, prefix);
+        let data = write_legacy(chunk.clone(), message, Some(vec![key_value]))?;
+
+        let (read_schema, chunks) = super::integration_read(&data)?;
+        assert_eq!(read_schema, schema);
+        assert_eq!(chunks, vec![chunk.clone()]);
+    }
+    Ok(())
+}
+
+#[test]
+fn arrow_schema_legacy_length_prefix() -> Result<()> {
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        DataType::Duration(TimeUnit::Second),
+        true,
+    )]);
+    let mut key_value = arrow_schema_key(&schema, &[]);
+    // the legacy format: a length prefix without continuation
+    let bytes = base64::decode(key_value.value.unwrap()).unwrap();
+    let mut legacy = (bytes.len() as u32).to_le_bytes().to_vec();
+    legacy.extend_from_slice(&bytes);
+    // followed by padding
+    legacy.extend_from_slice(&[0; 4]);
+    key_value.value = Some(base64::encode(&legacy));
+
+    let chunk = Chunk::new(vec![Arc::new(
+        Int64Array::from([Some(1), None]).to(DataType::Duration(TimeUnit::Second)),
+    ) as Arc<dyn Array>]);
+    let message = "message schema { OPTIONAL INT64 a; }";
+    let data = write_legacy(chunk.clone(), message, Some(vec![key_value]))?;
+
+    let (read_schema, chunks) = super::integration_read(&data)?;
+    assert_eq!(read_schema, schema);
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn arrow_schema_incompatible() -> Result<()> {
+    // the stored arrow schema does not match the parquet schema and is ignored
+    let schema = Schema::from(vec![Field::new("b", DataType::Utf8, true)]);
+    let key_values = vec![
+        arrow_schema_key(&schema, &[255, 255, 255, 255]),
+        KeyValue {
+            key: "key".to_string(),
+            value: Some("value".to_string()),
+        },
+    ];
+    let chunk = Chunk::new(vec![
+        Arc::new(Int64Array::from([Some(1), None])) as Arc<dyn Array>
+    ]);
+    let message = "message schema { OPTIONAL INT64 a; }";
+    let data = write_legacy(chunk.clone(), message, Some(key_values))?;
+
+    let (read_schema, chunks) = super::integration_read(&data)?;
+    assert_eq!(
+        read_schema.fields,
+        vec![Field::new("a", DataType::Int64, true)]
+    );
+    assert_eq!(read_schema.metadata.get("key"), Some(&"value".to_string()));
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn arrow_schema_invalid() -> Result<()> {
+    let key_value = KeyValue {
+        key: "ARROW:schema".to_string(),
+        value: Some(base64::encode([255, 255, 255, 255, 100, 0, 0, 0, 1])),
+    };
+    let chunk = Chunk::new(vec![
+        Arc::new(Int64Array::from([Some(1), None])) as Arc<dyn Array>
+    ]);
+    let message = "message schema { OPTIONAL INT64 a; }";
+    let data = write_legacy(chunk, message, Some(vec![key_value]))?;
+
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    assert!(infer_schema(&metadata).is_err());
+    Ok(())
+}
+
+type PagedFile = (Schema, Chunk<Arc<dyn Array>>, Vec<u8>);
+
+fn paged_file() -> Result<PagedFile> {
+    let a = Int32Array::from_iter((0..100).map(|x| if x % 7 == 3 { None } else { Some(x) }));
+    let b = Utf8Array::<i32>::from_iter((0..100).map(|x| Some(format!("v{}", x % 13))));
+    let mut c = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    c.try_extend((0..100).map(|x| Some(format!("d{}", x % 5))))?;
+    let c: DictionaryArray<i32> = c.into();
+    let chunk = Chunk::try_new(vec![
+        Arc::new(a) as Arc<dyn Array>,
+        Arc::new(b) as Arc<dyn Array>,
+        Arc::new(c) as Arc<dyn Array>,
+    ])?;
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", chunk.arrays()[2].data_type().clone(), true),
+    ]);
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Snappy,
+        version: Version::V1,
+    };
+    let mut row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain, Encoding::Plain, Encoding::RleDictionary],
+    )?;
+    row_groups.set_row_group_size(RowGroupSize::Rows(40));
+    row_groups.set_page_size(PageSize::Rows(10));
+
+    let data = write_row_groups(row_groups, &schema, options)?;
+    Ok((schema, chunk, data))
+}
+
+#[test]
+fn read_row_ranges() -> Result<()> {
+    let (schema, chunk, data) = paged_file()?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    assert_eq!(metadata.row_groups.len(), 3);
+
+    // within a page, across pages, across row groups, empty and all rows
+    for (start, end) in [(3, 5), (15, 32), (35, 85), (50, 50), (0, 100), (99, 100)] {
+        let result = read_rows(&mut reader, &metadata, &schema.fields, start..end)?;
+        assert_eq!(result.len(), end - start);
+        for (result, expected) in result.arrays().iter().zip(chunk.arrays()) {
+            assert_eq!(result.as_ref(), expected.slice(start, end - start).as_ref());
+        }
+    }
+
+    // projection
+    let fields = [schema.fields[1].clone()];
+    let result = read_rows(&mut reader, &metadata, &fields, 41..42)?;
+    assert_eq!(result.arrays().len(), 1);
+    assert_eq!(
+        result.arrays()[0].as_ref(),
+        chunk.arrays()[1].slice(41, 1).as_ref()
+    );
+    Ok(())
+}
+
+#[test]
+fn read_row_ranges_invalid() -> Result<()> {
+    let (schema, _, data) = paged_file()?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    assert!(read_rows(&mut reader, &metadata, &schema.fields, 90..101).is_err());
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 5..3;
+    assert!(read_rows(&mut reader, &metadata, &schema.fields, reversed).is_err());
+    Ok(())
+}
+
+#[test]
+fn read_row_groups_subset() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a1", DataType::Int64, true),
+        Field::new("a2", DataType::Utf8, true),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+
+    let a1 = Int64Array::from_iter((0..12).map(|x| if x % 3 == 0 { None } else { Some(x) }));
+    let a2 = Utf8Array::<i32>::from_iter((0..12).map(|x| Some(x.to_string())));
+    let chunk = Chunk::new(vec![Arc::new(a1) as Arc<dyn Array>, Arc::new(a2)]);
+    let mut row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain, Encoding::Plain],
+    )?;
+    row_groups.set_row_group_size(RowGroupSize::Rows(4));
+
+    let data = write_row_groups(row_groups, &schema, options)?;
+
+    let reader = FileReader::try_new(Cursor::new(data.clone()), None, None, None, None)?
+        .with_row_groups(vec![2, 0])?;
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result.len(), 2);
+    assert_eq!(
+        result[0].arrays()[0].as_ref(),
+        chunk.arrays()[0].slice(8, 4).as_ref()
+    );
+    assert_eq!(
+        result[1].arrays()[1].as_ref(),
+        chunk.arrays()[1].slice(0, 4).as_ref()
+    );
+
+    let reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    assert!(reader.with_row_groups(vec![3]).is_err());
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::io::parquet::read::*;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+use super::integration_write;
+
+struct InMemoryObject {
+    data: Vec<u8>,
+    requests: AtomicUsize,
+}
+
+impl InMemoryObject {
+    fn new(data: Vec<u8>) -> Self {
+        Self {
+            data,
+            requests: AtomicUsize::new(0),
+        }
+    }
+
+    fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+impl RangeReader for InMemoryObject {
+    fn read_suffix(&self, length: usize) -> BoxFuture<'_, std::io::Result<Vec<u8>>> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        let start = self.data.len().saturating_sub(length);
+        let data = self.data[start..].to_vec();
+        async move { Ok(data) }.boxed()
+    }
+
+    fn read_range(&self, start: u64, length: usize) -> BoxFuture<'_, std::io::Result<Vec<u8>>> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        let start = start as usize;
+        let data = self.data[start..start + length].to_vec();
+        async move { Ok(data) }.boxed()
+    }
+}
+
+fn file() -> Result<(Schema, Vec<u8>)> {
+    let a = Int32Array::from([Some(1), None, Some(3)]);
+    let b = Utf8Array::<i32>::from([Some("a"), Some("bb"), None]);
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), true),
+        Field::new("b", b.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::try_new(vec![
+        Arc::new(a) as Arc<dyn Array>,
+        Arc::new(b) as Arc<dyn Array>,
+    ])?;
+    let data = integration_write(&schema, &[chunk.clone(), chunk])?;
+    Ok((schema, data))
+}
+
+#[tokio::test]
+async fn read_metadata_single_request() -> Result<()> {
+    let (schema, data) = file()?;
+    let expected = read_metadata(&mut std::io::Cursor::new(&data))?;
+    let object = InMemoryObject::new(data);
+
+    let metadata = read_metadata_ranged(&object, DEFAULT_FOOTER_PREFETCH).await?;
+    assert_eq!(object.requests(), 1);
+    assert_eq!(infer_schema(&metadata)?, schema);
+    assert_eq!(
+        format!("{:?}", metadata.row_groups),
+        format!("{:?}", expected.row_groups)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_metadata_small_prefetch() -> Result<()> {
+    let (_, data) = file()?;
+    let expected = read_metadata(&mut std::io::Cursor::new(&data))?;
+    let object = InMemoryObject::new(data);
+
+    // the footer does not fit in the prefetch => a second request
+    let metadata = read_metadata_ranged(&object, 16).await?;
+    assert_eq!(object.requests(), 2);
+    assert_eq!(
+        format!("{:?}", metadata.row_groups),
+        format!("{:?}", expected.row_groups)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_metadata_invalid() {
+    let object = InMemoryObject::new(vec![1, 2, 3]);
+    assert!(read_metadata_ranged(&object, 16).await.is_err());
+
+    let object = InMemoryObject::new(b"0123456789".to_vec());
+    assert!(read_metadata_ranged(&object, 16).await.is_err());
+
+    // metadata length larger than the file
+    let mut data = vec![0, 0, 0, 0];
+    data.extend_from_slice(&100i32.to_le_bytes());
+    data.extend_from_slice(b"PAR1");
+    let object = InMemoryObject::new(data);
+    assert!(read_metadata_ranged(&object, 16).await.is_err());
+}
+
+#[tokio::test]
+async fn read_metadata_from_cache() -> Result<()> {
+    let (_, data) = file()?;
+    let object = InMemoryObject::new(data);
+    let cache = InMemoryMetadataCache::default();
+
+    let first = read_metadata_cached(&object, &cache, "file.parquet/v1", 1024).await?;
+    let second = read_metadata_cached(&object, &cache, "file.parquet/v1", 1024).await?;
+    assert_eq!(object.requests(), 1);
+    assert!(Arc::ptr_eq(&first, &second));
+
+    // another version of the file is fetched again
+    read_metadata_cached(&object, &cache, "file.parquet/v2", 1024).await?;
+    assert_eq!(object.requests(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn prefetch_column_ranges() -> Result<()> {
+    let (_, data) = file()?;
+    let object = InMemoryObject::new(data);
+    let metadata = read_metadata_ranged(&object, DEFAULT_FOOTER_PREFETCH).await?;
+
+    let row_group = &metadata.row_groups[1];
+    let ranges = column_byte_ranges(row_group, &["b"]);
+    assert_eq!(ranges, vec![row_group.columns()[1].byte_range()]);
+
+    let ranges = metadata
+        .row_groups
+        .iter()
+        .flat_map(|row_group| column_byte_ranges(row_group, &["a", "b"]))
+        .collect::<Vec<_>>();
+    assert_eq!(ranges.len(), 4);
+    // column chunks are separated by their (small) metadata
+    assert_eq!(merge_byte_ranges(&ranges, 0).len(), 4);
+    let merged = merge_byte_ranges(&ranges, 1024);
+    assert_eq!(merged.len(), 1);
+
+    // the prefetched bytes are the column chunks, fetched in a single request
+    let requests = object.requests();
+    let bytes = read_byte_ranges(&object, &ranges, 1024).await?;
+    assert_eq!(object.requests(), requests + 1);
+    assert_eq!(bytes.len(), ranges.len());
+    for ((start, length), bytes) in ranges.iter().zip(bytes.iter()) {
+        let start = *start as usize;
+        assert_eq!(bytes, &object.data[start..start + *length as usize]);
+    }
+    Ok(())
+}
+
+#[test]
+fn merge_ranges() {
+    let ranges = [(100, 10), (0, 10), (12, 8), (50, 5), (105, 1)];
+    assert_eq!(
+        merge_byte_ranges(&ranges, 2),
+        vec![(0, 20), (50, 5), (100, 10)]
+    );
+    assert_eq!(
+        merge_byte_ranges(&ranges, 0),
+        vec![(0, 10), (12, 8), (50, 5), (100, 10)]
+    );
+    assert!(merge_byte_ranges(&[], 10).is_empty());
+}
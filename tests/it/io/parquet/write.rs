@@ -1,6 +1,6 @@
 use std::io::Cursor;
 
-use arrow2::error::Result;
+use arrow2::error::{ErrorKind, Result};
 use arrow2::io::parquet::write::*;
 
 use super::*;
@@ -519,3 +519,625 @@ fn decimal_26_required_v2() -> Result<()> {
         Encoding::Plain,
     )
 }
+
+fn write_with_row_group_size(
+    chunks: Vec<Chunk<Arc<dyn Array>>>,
+    row_group_size: RowGroupSize,
+) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    let schema = Schema::from(vec![
+        Field::new("a1", DataType::Int64, true),
+        Field::new("a2", DataType::Utf8, true),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+
+    let mut row_groups = RowGroupIterator::try_new(
+        chunks.into_iter().map(Ok),
+        &schema,
+        options,
+        vec![Encoding::Plain, Encoding::Plain],
+    )?;
+    row_groups.set_row_group_size(row_group_size);
+
+    let data = write_row_groups(row_groups, &schema, options)?;
+
+    let reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    reader.collect()
+}
+
+fn row_group_size_chunk(len: usize) -> Chunk<Arc<dyn Array>> {
+    let a1 =
+        Int64Array::from_iter((0..len as i64).map(|x| if x % 3 == 0 { None } else { Some(x) }));
+    let a2 = Utf8Array::<i32>::from_iter((0..len).map(|x| Some(x.to_string())));
+    Chunk::new(vec![Arc::new(a1) as Arc<dyn Array>, Arc::new(a2)])
+}
+
+#[test]
+fn row_group_size_rows() -> Result<()> {
+    let chunk = row_group_size_chunk(10);
+    let chunks = vec![chunk.clone(), row_group_size_chunk(3)];
+
+    let result = write_with_row_group_size(chunks, RowGroupSize::Rows(4))?;
+
+    assert_eq!(
+        result.iter().map(|x| x.len()).collect::<Vec<_>>(),
+        vec![4, 4, 4, 1]
+    );
+    assert_eq!(
+        result[0].arrays()[0].as_ref(),
+        chunk.arrays()[0].slice(0, 4).as_ref()
+    );
+    assert_eq!(
+        result[1].arrays()[1].as_ref(),
+        chunk.arrays()[1].slice(4, 4).as_ref()
+    );
+    Ok(())
+}
+
+#[test]
+fn file_writer_row_group_size() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a1", DataType::Int64, true),
+        Field::new("a2", DataType::Utf8, true),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let chunks = vec![row_group_size_chunk(10), row_group_size_chunk(3)];
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    writer.write_chunks(
+        chunks.into_iter().map(Ok),
+        vec![Encoding::Plain, Encoding::Plain],
+        RowGroupSize::Rows(4),
+    )?;
+    let (_size, writer) = writer.end(None)?;
+
+    let reader = FileReader::try_new(Cursor::new(writer.into_inner()), None, None, None, None)?;
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        result.iter().map(|x| x.len()).collect::<Vec<_>>(),
+        vec![4, 4, 4, 1]
+    );
+    Ok(())
+}
+
+#[test]
+fn row_group_size_bytes() -> Result<()> {
+    let chunks = vec![row_group_size_chunk(5), row_group_size_chunk(100)];
+    let total_rows = 105;
+
+    let result = write_with_row_group_size(chunks, RowGroupSize::Bytes(300))?;
+
+    assert_eq!(result.iter().map(|x| x.len()).sum::<usize>(), total_rows);
+    assert!(result.len() > 1);
+    // all row groups but the last are approximately of the same size
+    let lengths = result.iter().map(|x| x.len()).collect::<Vec<_>>();
+    assert!(lengths[1..lengths.len() - 1]
+        .iter()
+        .all(|x| (*x as i64 - lengths[1] as i64).abs() <= 1));
+    Ok(())
+}
+
+#[test]
+fn row_group_size_bytes_at_least_one_row() -> Result<()> {
+    let chunks = vec![row_group_size_chunk(3)];
+
+    let result = write_with_row_group_size(chunks, RowGroupSize::Bytes(1))?;
+
+    assert_eq!(
+        result.iter().map(|x| x.len()).collect::<Vec<_>>(),
+        vec![1, 1, 1]
+    );
+    Ok(())
+}
+
+#[test]
+fn file_info() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a1", DataType::Int64, true),
+        Field::new("a2", DataType::Utf8, true),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+
+    let chunks = vec![row_group_size_chunk(10), row_group_size_chunk(3)];
+    let row_groups = RowGroupIterator::try_new(
+        chunks.into_iter().map(Ok),
+        &schema,
+        options,
+        vec![Encoding::Plain, Encoding::Plain],
+    )?;
+
+    let data = write_row_groups(row_groups, &schema, options)?;
+
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    let info = ParquetFileInfo::try_new(&metadata)?;
+
+    assert_eq!(info.schema, schema);
+    assert_eq!(info.num_rows, 13);
+    assert_eq!(info.num_row_groups, 2);
+
+    let a1 = info.field("a1").unwrap();
+    assert_eq!(a1.columns.len(), 2);
+    assert!(a1.columns.iter().all(|x| x.len() == 1));
+    // 0, 3, 6, 9 and 0 are null
+    assert_eq!(a1.null_count, Some(5));
+    assert!(a1.encodings.contains(&Encoding::Plain));
+    assert_eq!(
+        a1.compressed_size,
+        a1.columns
+            .iter()
+            .flatten()
+            .map(|x| x.compressed_size() as u64)
+            .sum::<u64>()
+    );
+
+    let a2 = info.field("a2").unwrap();
+    assert_eq!(a2.null_count, Some(0));
+    assert!(a2.uncompressed_size > 0);
+    assert!(info.field("a3").is_none());
+    Ok(())
+}
+
+#[test]
+fn error_context() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Utf8, false)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+
+    let array = Utf8Array::<i32>::from_slice(["aaaaaaa1", "aaaaaaa2", "zzzzzzzz", "zzzzzzzz"]);
+    let chunk = Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]);
+    let mut row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk)].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain],
+    )?;
+    row_groups.set_row_group_size(RowGroupSize::Rows(2));
+
+    let mut data = write_row_groups(row_groups, &schema, options)?;
+
+    // corrupt the values of the second row group with invalid utf8
+    let pattern = b"zzzzzzzz";
+    let mut i = 0;
+    while i + pattern.len() <= data.len() {
+        if &data[i..i + pattern.len()] == pattern {
+            data[i..i + pattern.len()].copy_from_slice(&[0xff; 8]);
+        }
+        i += 1;
+    }
+
+    let reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let error = reader.collect::<Result<Vec<_>>>().unwrap_err();
+    // the context is added to the error without changing its kind
+    assert_eq!(error.kind(), ErrorKind::External);
+    let message = error.to_string();
+    assert!(message.contains("row group 1"), "{}", message);
+    assert!(
+        message.contains("column \"a\", page 0, encoding Plain"),
+        "{}",
+        message
+    );
+    Ok(())
+}
+
+type ChunkAndFile = (Chunk<Arc<dyn Array>>, Vec<u8>);
+
+fn write_with_page_size(version: Version, page_size: PageSize) -> Result<ChunkAndFile> {
+    let int = Int32Array::from_iter((0..10).map(|x| if x % 3 == 0 { None } else { Some(x) }));
+    let utf8 = Utf8Array::<i32>::from_iter_values((0..10).map(|x| x.to_string()));
+    let mut dict = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dict.try_extend((0..10).map(|x| Some((x % 2).to_string())))?;
+    let dict: DictionaryArray<i32> = dict.into();
+
+    let schema = Schema::from(vec![
+        Field::new("int", int.data_type().clone(), true),
+        Field::new("utf8", utf8.data_type().clone(), false),
+        Field::new("dict", dict.data_type().clone(), false),
+    ]);
+    let chunk = Chunk::new(vec![
+        Arc::new(int) as Arc<dyn Array>,
+        Arc::new(utf8) as Arc<dyn Array>,
+        Arc::new(dict) as Arc<dyn Array>,
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version,
+    };
+
+    let mut row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain, Encoding::Plain, Encoding::RleDictionary],
+    )?;
+    row_groups.set_page_size(page_size);
+
+    let data = write_row_groups(row_groups, &schema, options)?;
+    Ok((chunk, data))
+}
+
+/// Returns the number of data pages of each column of the first row group
+fn data_pages_per_column(data: &[u8]) -> Result<Vec<usize>> {
+    use arrow2::io::parquet::read::{get_page_iterator, read_metadata};
+
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    metadata.row_groups[0]
+        .columns()
+        .iter()
+        .map(|column| {
+            let pages = get_page_iterator(column, Cursor::new(data), None, vec![])?;
+            Ok(pages.collect::<std::result::Result<Vec<_>, _>>()?.len())
+        })
+        .collect()
+}
+
+fn page_size(version: Version) -> Result<()> {
+    let (chunk, data) = write_with_page_size(version, PageSize::Rows(4))?;
+    assert_eq!(data_pages_per_column(&data)?, vec![3, 3, 3]);
+
+    let reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks, vec![chunk]);
+
+    // the int32 column uses ~5 bytes per row (4 of values plus validity)
+    let (chunk, data) = write_with_page_size(version, PageSize::Bytes(10))?;
+    assert_eq!(data_pages_per_column(&data)?[0], 5);
+
+    let reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn page_size_v1() -> Result<()> {
+    page_size(Version::V1)
+}
+
+#[test]
+fn page_size_v2() -> Result<()> {
+    page_size(Version::V2)
+}
+
+#[test]
+fn page_size_list() -> Result<()> {
+    let data = vec![
+        Some(vec![Some(1i64), Some(2), Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4), None, Some(6)]),
+        Some(vec![Some(7)]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i64>>::new();
+    array.try_extend(data)?;
+    let array: ListArray<i32> = array.into();
+    let schema = Schema::from(vec![Field::new("l", array.data_type().clone(), true)]);
+    let chunk = Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]);
+    for version in [Version::V1, Version::V2] {
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: Compression::Uncompressed,
+            version,
+        };
+        let mut row_groups = RowGroupIterator::try_new(
+            vec![Ok(chunk.clone())].into_iter(),
+            &schema,
+            options,
+            vec![Encoding::Plain],
+        )?;
+        row_groups.set_page_size(PageSize::Rows(2));
+        let data = write_row_groups(row_groups, &schema, options)?;
+        let reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+        let chunks = reader.collect::<Result<Vec<_>>>()?;
+        assert_eq!(chunks, vec![chunk.clone()]);
+    }
+    Ok(())
+}
+
+#[test]
+fn v2_page_headers() -> Result<()> {
+    use arrow2::io::parquet::read::{get_page_iterator, read_metadata};
+    use parquet2::page::DataPageHeader;
+
+    let data = vec![
+        Some(vec![Some(1i64), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4)]),
+    ];
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i64>>::new();
+    list.try_extend(data)?;
+    let list: ListArray<i32> = list.into();
+    let mut dict = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dict.try_extend([Some("a"), None, Some("b"), Some("a")])?;
+    let dict: DictionaryArray<i32> = dict.into();
+
+    let schema = Schema::from(vec![
+        Field::new("list", list.data_type().clone(), true),
+        Field::new("dict", dict.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::new(vec![
+        Arc::new(list) as Arc<dyn Array>,
+        Arc::new(dict) as Arc<dyn Array>,
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version: Version::V2,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain, Encoding::RleDictionary],
+    )?;
+    let data = write_row_groups(row_groups, &schema, options)?;
+
+    // (num_values, num_rows, num_nulls) of each column
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let headers = metadata.row_groups[0]
+        .columns()
+        .iter()
+        .map(|column| {
+            let mut pages = get_page_iterator(column, Cursor::new(&data), None, vec![])?;
+            let page = pages.next().unwrap()?;
+            match page.header() {
+                DataPageHeader::V2(header) => {
+                    Ok((header.num_values, header.num_rows, header.num_nulls))
+                }
+                DataPageHeader::V1(_) => panic!("expected a v2 page"),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(headers, vec![(5, 4, 3), (4, 4, 1)]);
+
+    let reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}
+
+fn write_statistics(array: Arc<dyn Array>) -> Result<Option<Box<dyn Statistics>>> {
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array.clone()])];
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options, vec![Encoding::Plain])?;
+
+    let data = write_row_groups(row_groups, &schema, options)?;
+
+    let (result, stats) = read_column(&mut Cursor::new(data), 0, "a1")?;
+    assert_eq!(array.as_ref(), result.as_ref());
+    Ok(stats)
+}
+
+#[test]
+fn decimal_26_statistics_are_signed() -> Result<()> {
+    let data_type = DataType::Decimal(26, 2);
+    let array = Int128Array::from([Some(-10), Some(3), None, Some(-1_000_000), Some(2)])
+        .to(data_type.clone());
+
+    let statistics = write_statistics(Arc::new(array))?;
+
+    let expected = PrimitiveStatistics::<i128> {
+        data_type,
+        distinct_count: None,
+        null_count: Some(1),
+        min_value: Some(-1_000_000),
+        max_value: Some(3),
+    };
+    assert_eq!(
+        statistics.as_deref().unwrap().as_any().downcast_ref(),
+        Some(&expected)
+    );
+    Ok(())
+}
+
+#[test]
+fn decimal_18_statistics_are_signed() -> Result<()> {
+    let data_type = DataType::Decimal(18, 0);
+    let array = Int128Array::from([Some(-10), None, Some(7)]).to(data_type.clone());
+
+    let statistics = write_statistics(Arc::new(array))?;
+
+    let expected = PrimitiveStatistics::<i128> {
+        data_type,
+        distinct_count: None,
+        null_count: Some(1),
+        min_value: Some(-10),
+        max_value: Some(7),
+    };
+    assert_eq!(
+        statistics.as_deref().unwrap().as_any().downcast_ref(),
+        Some(&expected)
+    );
+    Ok(())
+}
+
+#[test]
+fn timestamp_statistics() -> Result<()> {
+    for time_unit in [
+        TimeUnit::Second,
+        TimeUnit::Millisecond,
+        TimeUnit::Microsecond,
+        TimeUnit::Nanosecond,
+    ] {
+        let data_type = DataType::Timestamp(time_unit, Some("+01:00".to_string()));
+        let array = Int64Array::from([Some(10), Some(-5), None, Some(1)]).to(data_type.clone());
+
+        let statistics = write_statistics(Arc::new(array))?;
+
+        let expected = PrimitiveStatistics::<i64> {
+            data_type,
+            distinct_count: None,
+            null_count: Some(1),
+            min_value: Some(-5),
+            max_value: Some(10),
+        };
+        assert_eq!(
+            statistics.as_deref().unwrap().as_any().downcast_ref(),
+            Some(&expected)
+        );
+    }
+    Ok(())
+}
+
+fn write_lz4(
+    chunk: Chunk<Arc<dyn Array>>,
+    version: Version,
+    lz4_format: Lz4Format,
+    encoding: Encoding,
+) -> Result<Vec<u8>> {
+    let schema = Schema::from(
+        chunk
+            .arrays()
+            .iter()
+            .enumerate()
+            .map(|(i, array)| Field::new(format!("c{}", i), array.data_type().clone(), true))
+            .collect::<Vec<_>>(),
+    );
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Lz4,
+        version,
+    };
+
+    let encodings = vec![encoding; schema.fields.len()];
+    let mut row_groups =
+        RowGroupIterator::try_new(vec![Ok(chunk)].into_iter(), &schema, options, encodings)?;
+    row_groups.set_lz4_format(lz4_format);
+
+    let data = write_row_groups(row_groups, &schema, options)?;
+    Ok(data)
+}
+
+#[test]
+fn lz4_hadoop_round_trip() -> Result<()> {
+    let values = (0..1000).map(|x| if x % 7 != 0 { Some(x % 13) } else { None });
+    let chunk = Chunk::new(vec![
+        Arc::new(Int64Array::from_iter(values.clone())) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_iter(
+            values.map(|x| x.map(|x| format!("value {}", x))),
+        )),
+    ]);
+
+    for version in [Version::V1, Version::V2] {
+        for lz4_format in [Lz4Format::Frame, Lz4Format::Hadoop] {
+            let data = write_lz4(chunk.clone(), version, lz4_format, Encoding::Plain)?;
+
+            let (_, chunks) = integration_read(&data)?;
+            assert_eq!(chunks, vec![chunk.clone()]);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn lz4_hadoop_dictionary_is_not_supported() {
+    let mut array = MutableDictionaryArray::<u32, MutableUtf8Array<i32>>::new();
+    array.try_extend([Some("a"), None, Some("b")]).unwrap();
+    let array: DictionaryArray<u32> = array.into();
+    let chunk = Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]);
+
+    let result = write_lz4(
+        chunk,
+        Version::V1,
+        Lz4Format::Hadoop,
+        Encoding::RleDictionary,
+    );
+    assert!(result.is_err());
+}
+
+fn write_spawned(
+    chunks: Vec<Chunk<Arc<dyn Array>>>,
+    encoding: Encoding,
+    spawn: Spawn,
+) -> Result<Vec<u8>> {
+    let schema = Schema::from(
+        chunks[0]
+            .arrays()
+            .iter()
+            .enumerate()
+            .map(|(i, array)| Field::new(format!("c{}", i), array.data_type().clone(), true))
+            .collect::<Vec<_>>(),
+    );
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version: Version::V2,
+    };
+
+    let encodings = vec![encoding; schema.fields.len()];
+    let mut row_groups =
+        RowGroupIterator::try_new(chunks.into_iter().map(Ok), &schema, options, encodings)?;
+    row_groups.set_spawn(spawn);
+
+    let data = write_row_groups(row_groups, &schema, options)?;
+    Ok(data)
+}
+
+#[test]
+fn spawn() -> Result<()> {
+    let chunks = (0..3)
+        .map(|i| {
+            let values = (0..100).map(|x| if x % 7 != i { Some(x * i) } else { None });
+            Chunk::new(vec![
+                Arc::new(Int64Array::from_iter(values.clone())) as Arc<dyn Array>,
+                Arc::new(Utf8Array::<i32>::from_iter(
+                    values.clone().map(|x| x.map(|x| format!("value {}", x))),
+                )),
+                Arc::new(BooleanArray::from_iter(
+                    values.map(|x| x.map(|x| x % 2 == 0)),
+                )),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let tasks = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let spawned_tasks = tasks.clone();
+    let spawn: Spawn = Arc::new(move |task| {
+        spawned_tasks.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        std::thread::spawn(task);
+    });
+
+    let data = write_spawned(chunks.clone(), Encoding::Plain, spawn)?;
+    // one task per column per row group
+    assert_eq!(tasks.load(std::sync::atomic::Ordering::Relaxed), 9);
+
+    let (_, result) = integration_read(&data)?;
+    assert_eq!(result, chunks);
+    Ok(())
+}
+
+#[test]
+fn spawn_error() {
+    let chunk = Chunk::new(vec![
+        Arc::new(Int64Array::from_slice([1, 2])) as Arc<dyn Array>
+    ]);
+    let spawn: Spawn = Arc::new(|task| {
+        std::thread::spawn(task);
+    });
+    // the error of encoding a column is returned when writing it
+    let result = write_spawned(vec![chunk], Encoding::DeltaLengthByteArray, spawn);
+    assert!(result.is_err());
+}
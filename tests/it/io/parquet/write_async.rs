@@ -6,7 +6,10 @@ use arrow2::{
     datatypes::{DataType, Field, Schema},
     error::Result,
     io::parquet::{
-        read::{infer_schema, read_columns_many_async, read_metadata_async, RowGroupDeserializer},
+        read::{
+            infer_schema, read_columns_many_async, read_metadata_async, read_row_groups_async,
+            RowGroupDeserializer,
+        },
         write::Encoding,
     },
 };
@@ -79,4 +82,26 @@ async fn test_parquet_async_roundtrip() {
     for i in 0..5 {
         assert_eq!(data[i], out[i]);
     }
+
+    let row_groups = read_row_groups_async(
+        factory,
+        &metadata.row_groups,
+        &[3, 1],
+        schema.fields.clone(),
+        None,
+    )
+    .await
+    .unwrap();
+    let out = row_groups
+        .into_iter()
+        .flatten()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(out, vec![data[3].clone(), data[1].clone()]);
+
+    assert!(
+        read_row_groups_async(factory, &metadata.row_groups, &[5], schema.fields, None)
+            .await
+            .is_err()
+    );
 }
@@ -0,0 +1,150 @@
+use serde_derive::{Deserialize, Serialize};
+
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+use arrow2::io::serde::{deserialize, infer, serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    a: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: i64,
+    value: f64,
+    name: Option<String>,
+    tags: Vec<String>,
+    inner: Inner,
+    nothing: Option<i32>,
+}
+
+fn records() -> Vec<Record> {
+    vec![
+        Record {
+            id: 1,
+            value: 0.5,
+            name: Some("a".to_string()),
+            tags: vec!["x".to_string(), "y".to_string()],
+            inner: Inner { a: true },
+            nothing: None,
+        },
+        Record {
+            id: 2,
+            value: 1.5,
+            name: None,
+            tags: vec![],
+            inner: Inner { a: false },
+            nothing: None,
+        },
+    ]
+}
+
+#[test]
+fn infer_fields() -> Result<()> {
+    let fields = infer(&records())?;
+
+    let expected = vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("value", DataType::Float64, false),
+        Field::new("name", DataType::Utf8, true),
+        Field::new(
+            "tags",
+            DataType::List(Box::new(Field::new("item", DataType::Utf8, true))),
+            false,
+        ),
+        Field::new(
+            "inner",
+            DataType::Struct(vec![Field::new("a", DataType::Boolean, true)]),
+            false,
+        ),
+        Field::new("nothing", DataType::Null, true),
+    ];
+    assert_eq!(fields, expected);
+    Ok(())
+}
+
+#[test]
+fn round_trip() -> Result<()> {
+    let records = records();
+    let fields = infer(&records)?;
+
+    let chunk = serialize(&records, &fields)?;
+    assert_eq!(chunk.len(), 2);
+    assert_eq!(
+        chunk.arrays()[0].as_ref(),
+        &Int64Array::from_slice([1, 2]) as &dyn Array
+    );
+    assert_eq!(
+        chunk.arrays()[2].as_ref(),
+        &Utf8Array::<i32>::from([Some("a"), None]) as &dyn Array
+    );
+
+    let result: Vec<Record> = deserialize(&chunk, &fields)?;
+    assert_eq!(result, records);
+    Ok(())
+}
+
+#[test]
+fn with_fields() -> Result<()> {
+    let records = records();
+    // a subset of the fields with a narrower type
+    let fields = vec![Field::new("id", DataType::Int32, false)];
+
+    let chunk = serialize(&records, &fields)?;
+    assert_eq!(
+        chunk.arrays()[0].as_ref(),
+        &Int32Array::from_slice([1, 2]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn not_a_map() {
+    assert!(infer(&[1i32, 2]).is_err());
+    assert!(serialize(&[1i32, 2], &[]).is_err());
+}
+
+#[test]
+fn wrong_number_of_fields() -> Result<()> {
+    let records = records();
+    let fields = infer(&records)?;
+    let chunk = serialize(&records, &fields)?;
+
+    assert!(deserialize::<Record, _>(&chunk, &fields[..1]).is_err());
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+enum Integer {
+    I8(i8),
+    U8(u8),
+    U64(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct IntegerRecord {
+    value: Integer,
+}
+
+fn infer_integers(values: &[Integer]) -> Result<DataType> {
+    let records = values
+        .iter()
+        .map(|value| IntegerRecord {
+            value: value.clone(),
+        })
+        .collect::<Vec<_>>();
+    Ok(infer(&records)?[0].data_type().clone())
+}
+
+#[test]
+fn infer_integers_widening() -> Result<()> {
+    use Integer::*;
+    assert_eq!(infer_integers(&[I8(-1), U8(200)])?, DataType::Int16);
+    assert_eq!(infer_integers(&[U8(1), U64(u64::MAX)])?, DataType::UInt64);
+    // no integer holds both `u64::MAX` and `-1`
+    assert!(infer_integers(&[I8(-1), U64(u64::MAX)]).is_err());
+    Ok(())
+}
@@ -1,11 +1,20 @@
+#[cfg(all(feature = "arbitrary", feature = "io_ipc"))]
+mod arbitrary;
 mod array;
 mod bitmap;
 mod buffer;
+mod chunk;
+mod datatypes;
+mod error;
 mod ffi;
 mod scalar;
 mod temporal_conversions;
+mod types;
 
 mod io;
 mod test_util;
 
 mod compute;
+
+#[cfg(feature = "data_gen")]
+mod data_gen;
@@ -139,3 +139,66 @@ fn add_interval_timezone() {
     let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
     assert_eq!("2020-10-29 01:00:00 WET", format!("{}", r));
 }
+
+#[test]
+fn duration_to_iso8601() {
+    let cases = [
+        (0, TimeUnit::Second, "PT0S"),
+        (3_723, TimeUnit::Second, "PT1H2M3S"),
+        (90_000, TimeUnit::Second, "PT25H"),
+        (-60_500, TimeUnit::Millisecond, "-PT1M0.5S"),
+        (1, TimeUnit::Nanosecond, "PT0.000000001S"),
+        (
+            i64::MIN,
+            TimeUnit::Nanosecond,
+            "-PT2562047H47M16.854775808S",
+        ),
+    ];
+    for (value, unit, expected) in cases {
+        assert_eq!(
+            temporal_conversions::duration_to_iso8601(value, unit),
+            expected
+        );
+        assert_eq!(
+            temporal_conversions::iso8601_to_duration(expected, unit),
+            Some(value)
+        );
+    }
+}
+
+#[test]
+fn iso8601_to_duration() {
+    let cases = [
+        ("P1W", TimeUnit::Second, Some(604_800)),
+        ("P1DT1S", TimeUnit::Second, Some(86_401)),
+        ("+PT1.5M", TimeUnit::Second, Some(90)),
+        ("PT0,25S", TimeUnit::Millisecond, Some(250)),
+        ("-P2D", TimeUnit::Second, Some(-172_800)),
+        // not a whole number of units
+        ("PT0.5S", TimeUnit::Second, None),
+        // years and months have no fixed length
+        ("P1Y", TimeUnit::Second, None),
+        ("P1M", TimeUnit::Second, None),
+        // fractions only on the last component
+        ("PT1.5H2M", TimeUnit::Second, None),
+        // wrong order, missing or invalid components
+        ("PT1S1M", TimeUnit::Second, None),
+        ("P", TimeUnit::Second, None),
+        ("PT", TimeUnit::Second, None),
+        ("P1DT", TimeUnit::Second, None),
+        ("PTS", TimeUnit::Second, None),
+        ("PT1", TimeUnit::Second, None),
+        ("PT1é", TimeUnit::Second, None),
+        ("1S", TimeUnit::Second, None),
+        // overflow
+        ("PT9223372036854775808S", TimeUnit::Second, None),
+    ];
+    for (value, unit, expected) in cases {
+        assert_eq!(
+            temporal_conversions::iso8601_to_duration(value, unit),
+            expected,
+            "{}",
+            value
+        );
+    }
+}
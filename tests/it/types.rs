@@ -0,0 +1,93 @@
+use arrow2::types::decimal::*;
+
+#[test]
+fn parse() {
+    let cases: &[(&str, Option<i128>)] = &[
+        ("1", Some(100)),
+        ("-1.5", Some(-150)),
+        ("+.25", Some(25)),
+        ("12.", Some(1200)),
+        ("0.010", Some(1)),
+        ("1e2", Some(10000)),
+        ("-1.5E-1", Some(-15)),
+        ("25e-4", None),
+        ("1000", None),
+        ("999.99", Some(99999)),
+        ("", None),
+        ("-", None),
+        (".", None),
+        ("1e", None),
+        ("1.2.3", None),
+        ("a", None),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(
+            parse_decimal(value.as_bytes(), 5, 2, RoundingMode::Reject),
+            *expected,
+            "{}",
+            value
+        );
+    }
+}
+
+#[test]
+fn parse_rounding() {
+    let cases: &[(&str, i128, i128, i128)] = &[
+        // value, truncate, half up, half even
+        ("1.234", 123, 123, 123),
+        ("1.235", 123, 124, 124),
+        ("1.245", 124, 125, 124),
+        ("1.2451", 124, 125, 125),
+        ("-1.235", -123, -124, -124),
+        ("0.005", 0, 1, 0),
+        ("0.0001", 0, 0, 0),
+        ("0.9999", 99, 100, 100),
+    ];
+    for (value, truncate, half_up, half_even) in cases {
+        let parse = |rounding| parse_decimal(value.as_bytes(), 5, 2, rounding);
+        assert_eq!(parse(RoundingMode::Truncate), Some(*truncate), "{}", value);
+        assert_eq!(parse(RoundingMode::HalfUp), Some(*half_up), "{}", value);
+        assert_eq!(parse(RoundingMode::HalfEven), Some(*half_even), "{}", value);
+    }
+    // rounding up may exceed the precision
+    assert_eq!(parse_decimal(b"999.995", 5, 2, RoundingMode::HalfUp), None);
+}
+
+#[test]
+fn parse_overflow() {
+    assert_eq!(parse_decimal(b"1e40", 38, 0, RoundingMode::Reject), None);
+    assert_eq!(
+        parse_decimal(
+            b"99999999999999999999999999999999999999",
+            38,
+            0,
+            RoundingMode::Reject
+        ),
+        Some(99999999999999999999999999999999999999)
+    );
+    assert_eq!(parse_decimal(b"0e100", 5, 2, RoundingMode::Reject), Some(0));
+}
+
+#[test]
+fn format() {
+    assert_eq!(format_decimal(12345, 2), "123.45");
+    assert_eq!(format_decimal(-105, 2), "-1.05");
+    assert_eq!(format_decimal(5, 3), "0.005");
+    assert_eq!(format_decimal(0, 2), "0.00");
+    assert_eq!(format_decimal(-5, 0), "-5");
+    assert_eq!(
+        format_decimal(i128::MIN, 2),
+        "-1701411834604692317316873037158841057.28"
+    );
+}
+
+#[test]
+fn roundtrip() {
+    for value in [0, 1, -1, 105, -99999, 12345678] {
+        let formatted = format_decimal(value, 3);
+        assert_eq!(
+            parse_decimal(formatted.as_bytes(), 10, 3, RoundingMode::Reject),
+            Some(value)
+        );
+    }
+}